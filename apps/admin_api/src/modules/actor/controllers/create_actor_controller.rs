@@ -0,0 +1,60 @@
+use crate::modules::actor::{
+    dto::{
+        io::actor_output_dto::ActorOutputDTO,
+        request::create_actor_request_dto::CreateActorRequestDTO,
+    },
+    services::create_actor_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    payload: Json<CreateActorRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<ActorOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match create_actor_service::execute(payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::CREATED,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Ator criado com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                create_actor_service::CreateActorError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                create_actor_service::CreateActorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao criar ator".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
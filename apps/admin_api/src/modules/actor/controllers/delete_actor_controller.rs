@@ -0,0 +1,51 @@
+use crate::modules::actor::{
+    dto::route_params::actor_route_params_dto::ActorRouteParamsDTO, services::delete_actor_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<ActorRouteParamsDTO>,
+) -> Result<(StatusCode, Json<ResponseInterface<()>>), (StatusCode, Json<ValidationErrorResponse>)>
+{
+    match delete_actor_service::execute(params, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Ator removido com sucesso".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                delete_actor_service::DeleteActorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                delete_actor_service::DeleteActorError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao deletar ator".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
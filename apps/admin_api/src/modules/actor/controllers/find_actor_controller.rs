@@ -0,0 +1,55 @@
+use crate::modules::actor::{
+    dto::{
+        io::actor_output_dto::ActorOutputDTO,
+        route_params::actor_route_params_dto::ActorRouteParamsDTO,
+    },
+    services::find_actor_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<ActorRouteParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<ActorOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_actor_service::execute(params, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_actor_service::FindActorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_actor_service::FindActorError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar ator".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create_actor_controller;
+pub mod delete_actor_controller;
+pub mod find_actor_controller;
+pub mod find_many_actor_controller;
+pub mod update_actor_controller;
@@ -0,0 +1,65 @@
+use crate::modules::actor::{
+    dto::{
+        io::actor_output_dto::ActorOutputDTO,
+        request::update_actor_request_dto::UpdateActorRequestDTO,
+        route_params::actor_route_params_dto::ActorRouteParamsDTO,
+    },
+    services::update_actor_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<ActorRouteParamsDTO>,
+    payload: Json<UpdateActorRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<ActorOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match update_actor_service::execute(params, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Ator atualizado com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                update_actor_service::UpdateActorError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                update_actor_service::UpdateActorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                update_actor_service::UpdateActorError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar ator".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
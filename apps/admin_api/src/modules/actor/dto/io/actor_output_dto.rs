@@ -0,0 +1,29 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Serialize;
+use shared::modules::database::repositories::images_repository::CatalogImageUrls;
+use shared::modules::database::schema::actors::Model as ActorModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActorOutputDTO {
+    pub id: i32,
+    pub name: String,
+    pub biography: Option<String>,
+    pub birth_date: Option<NaiveDate>,
+    pub profile_picture_url: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub images: CatalogImageUrls,
+}
+
+impl From<ActorModel> for ActorOutputDTO {
+    fn from(actor: ActorModel) -> Self {
+        Self {
+            id: actor.id,
+            name: actor.name,
+            biography: actor.biography,
+            birth_date: actor.birth_date.map(|date| date.date()),
+            profile_picture_url: actor.profile_picture_url,
+            created_at: actor.created_at,
+            images: CatalogImageUrls::new(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::modules::actor::dto::query_params::find_many_actor_query_params_dto::FindManyActorQueryParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindManyActorInputDTO {
+    pub name: Option<String>,
+    pub skip: Option<i32>,
+    pub take: Option<i32>,
+}
+
+impl From<FindManyActorQueryParamsDTO> for FindManyActorInputDTO {
+    fn from(query: FindManyActorQueryParamsDTO) -> Self {
+        Self {
+            name: query.name,
+            skip: query.skip,
+            take: query.take,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod actor_output_dto;
+pub mod find_many_actor_input_dto;
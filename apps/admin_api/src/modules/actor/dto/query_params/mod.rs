@@ -0,0 +1 @@
+pub mod find_many_actor_query_params_dto;
@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct CreateActorRequestDTO {
+    #[validate(length(
+        min = 2,
+        max = 150,
+        message = "O nome deve ter entre 2 e 150 caracteres"
+    ))]
+    pub name: String,
+
+    #[validate(length(
+        max = 2000,
+        message = "A biografia não pode ter mais de 2000 caracteres"
+    ))]
+    pub biography: Option<String>,
+
+    pub birth_date: Option<NaiveDate>,
+
+    #[validate(url(message = "A foto deve ser uma URL válida"))]
+    pub profile_picture_url: Option<String>,
+}
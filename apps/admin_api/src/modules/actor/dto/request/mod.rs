@@ -0,0 +1,2 @@
+pub mod create_actor_request_dto;
+pub mod update_actor_request_dto;
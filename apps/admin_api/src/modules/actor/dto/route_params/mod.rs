@@ -0,0 +1 @@
+pub mod actor_route_params_dto;
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::modules::{
+    app_state::AppState, database::repositories::actors_repository::CreateActorRequest, search,
+};
+
+use crate::modules::actor::dto::{
+    io::actor_output_dto::ActorOutputDTO, request::create_actor_request_dto::CreateActorRequestDTO,
+};
+
+pub enum CreateActorError {
+    Validation(String),
+    Database(String),
+}
+
+pub async fn execute(
+    payload: CreateActorRequestDTO,
+    state: Arc<AppState>,
+) -> Result<ActorOutputDTO, CreateActorError> {
+    if payload
+        .birth_date
+        .is_some_and(|date| date > Utc::now().date_naive())
+    {
+        return Err(CreateActorError::Validation(
+            "A data de nascimento não pode estar no futuro".to_string(),
+        ));
+    }
+
+    let actor = state
+        .actor_repo
+        .create(CreateActorRequest {
+            name: payload.name.trim().to_string(),
+            biography: payload.biography,
+            birth_date: payload
+                .birth_date
+                .map(|date| date.and_time(Default::default())),
+            profile_picture_url: payload.profile_picture_url,
+        })
+        .await
+        .map_err(|e| CreateActorError::Database(format!("Erro ao criar: {}", e)))?;
+
+    search::invalidate_suggestions(&state).await;
+
+    Ok(actor.into())
+}
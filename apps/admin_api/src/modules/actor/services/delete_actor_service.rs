@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::images_repository::image_storage_keys;
+use shared::modules::database::repositories::search_repository::IndexScope;
+use shared::modules::search;
+
+use crate::modules::actor::dto::route_params::actor_route_params_dto::ActorRouteParamsDTO;
+
+pub enum DeleteActorError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Remove o ator, suas participações nos vídeos e suas fotos.
+pub async fn execute(
+    params: ActorRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteActorError> {
+    let video_ids = search::index::affected_videos(&state, IndexScope::Actor(params.id)).await;
+
+    let deleted = state
+        .actor_repo
+        .delete(params.id)
+        .await
+        .map_err(|e| DeleteActorError::Database(format!("Erro ao deletar ator: {}", e)))?;
+
+    if !deleted {
+        return Err(DeleteActorError::NotFound(format!(
+            "Ator com id {} não encontrado",
+            params.id
+        )));
+    }
+
+    let images = state
+        .image_repo
+        .delete_by_owner(ImageOwnerEnum::ACTOR, params.id)
+        .await
+        .map_err(|e| DeleteActorError::Database(format!("Erro ao deletar fotos: {}", e)))?;
+
+    for blob_name in images.iter().flat_map(image_storage_keys) {
+        if let Err(e) = state.image_storage_service.delete_image(&blob_name).await {
+            tracing::warn!("Falha ao deletar imagem '{}': {:?}", blob_name, e);
+        }
+    }
+
+    search::invalidate_suggestions(&state).await;
+    search::index::sync_videos(&state, &video_ids).await;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::images_repository::catalog_image_urls;
+
+use crate::modules::actor::dto::{
+    io::actor_output_dto::ActorOutputDTO, route_params::actor_route_params_dto::ActorRouteParamsDTO,
+};
+
+pub enum FindActorError {
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    params: ActorRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<ActorOutputDTO, FindActorError> {
+    let actor = state
+        .actor_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| FindActorError::Database(format!("Erro ao buscar ator: {}", e)))?
+        .ok_or_else(|| {
+            FindActorError::NotFound(format!("Ator com id {} não encontrado", params.id))
+        })?;
+
+    let images = state
+        .image_repo
+        .find_by_owner(ImageOwnerEnum::ACTOR, actor.id)
+        .await
+        .map(catalog_image_urls)
+        .map_err(|e| FindActorError::Database(format!("Erro ao buscar imagens: {}", e)))?;
+
+    let mut actor: ActorOutputDTO = actor.into();
+    if let Some(urls) = images.into_values().next() {
+        actor.images = urls;
+    }
+
+    Ok(actor)
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::images_repository::catalog_image_urls;
+
+use crate::modules::actor::dto::io::{
+    actor_output_dto::ActorOutputDTO, find_many_actor_input_dto::FindManyActorInputDTO,
+};
+
+pub enum FindManyActorError {
+    Database(String),
+}
+
+pub async fn execute(
+    FindManyActorInputDTO { name, skip, take }: FindManyActorInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<ActorOutputDTO>, FindManyActorError> {
+    let mut actors = state
+        .actor_repo
+        .find_many(
+            name.as_deref(),
+            skip.map(|s| s as u64),
+            Some(take.unwrap_or(20) as u64),
+        )
+        .await
+        .map_err(|e| FindManyActorError::Database(format!("Erro ao buscar atores: {}", e)))?
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<ActorOutputDTO>>();
+
+    let actor_ids: Vec<i32> = actors.iter().map(|actor| actor.id).collect();
+
+    let mut images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::ACTOR, &actor_ids)
+        .await
+        .map(catalog_image_urls)
+        .map_err(|e| FindManyActorError::Database(format!("Erro ao buscar imagens: {}", e)))?;
+
+    for actor in &mut actors {
+        if let Some(urls) = images.remove(&actor.id) {
+            actor.images = urls;
+        }
+    }
+
+    Ok(actors)
+}
@@ -0,0 +1,5 @@
+pub mod create_actor_service;
+pub mod delete_actor_service;
+pub mod find_actor_service;
+pub mod find_many_actor_service;
+pub mod update_actor_service;
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::modules::{
+    app_state::AppState,
+    database::repositories::{
+        actors_repository::UpdateActorRequest, search_repository::IndexScope,
+    },
+    search,
+};
+
+use crate::modules::actor::dto::{
+    io::actor_output_dto::ActorOutputDTO, request::update_actor_request_dto::UpdateActorRequestDTO,
+    route_params::actor_route_params_dto::ActorRouteParamsDTO,
+};
+
+pub enum UpdateActorError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    params: ActorRouteParamsDTO,
+    payload: UpdateActorRequestDTO,
+    state: Arc<AppState>,
+) -> Result<ActorOutputDTO, UpdateActorError> {
+    if payload
+        .birth_date
+        .is_some_and(|date| date > Utc::now().date_naive())
+    {
+        return Err(UpdateActorError::Validation(
+            "A data de nascimento não pode estar no futuro".to_string(),
+        ));
+    }
+
+    let actor = state
+        .actor_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| UpdateActorError::Database(format!("Erro ao buscar ator: {}", e)))?;
+
+    if actor.is_none() {
+        return Err(UpdateActorError::NotFound(format!(
+            "Ator com id {} não encontrado",
+            params.id
+        )));
+    }
+
+    let updated = state
+        .actor_repo
+        .update(
+            params.id,
+            UpdateActorRequest {
+                name: payload.name.map(|name| name.trim().to_string()),
+                biography: payload.biography,
+                birth_date: payload
+                    .birth_date
+                    .map(|date| date.and_time(Default::default())),
+                profile_picture_url: payload.profile_picture_url,
+            },
+        )
+        .await
+        .map_err(|e| UpdateActorError::Database(format!("Erro ao atualizar: {}", e)))?;
+
+    search::invalidate_suggestions(&state).await;
+    search::index::sync_scope(&state, IndexScope::Actor(params.id)).await;
+
+    Ok(updated.into())
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+
+use crate::modules::analytics::{
+    dto::{
+        io::trending_analytics_output_dto::TrendingAnalyticsOutputDTO,
+        query_params::trending_analytics_query_params_dto::TrendingAnalyticsQueryParamsDTO,
+    },
+    services::trending_analytics_service,
+};
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<TrendingAnalyticsQueryParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<TrendingAnalyticsOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match trending_analytics_service::execute(query.into(), state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                trending_analytics_service::TrendingAnalyticsError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                trending_analytics_service::TrendingAnalyticsError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar os títulos em alta".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod trending_analytics_input_dto;
+pub mod trending_analytics_output_dto;
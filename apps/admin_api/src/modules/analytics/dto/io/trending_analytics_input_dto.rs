@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::modules::analytics::dto::query_params::trending_analytics_query_params_dto::TrendingAnalyticsQueryParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct TrendingAnalyticsInputDTO {
+    pub window: Option<String>,
+    pub kind: Option<String>,
+    pub limit: Option<i32>,
+}
+
+impl From<TrendingAnalyticsQueryParamsDTO> for TrendingAnalyticsInputDTO {
+    fn from(query: TrendingAnalyticsQueryParamsDTO) -> Self {
+        Self {
+            window: query.window,
+            kind: query.kind,
+            limit: query.limit,
+        }
+    }
+}
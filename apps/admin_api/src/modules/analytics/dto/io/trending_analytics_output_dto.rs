@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::enums::trending_kind_enum::TrendingKindEnum;
+use shared::enums::trending_window_enum::TrendingWindowEnum;
+use shared::modules::database::repositories::trending_repository::TrendStats;
+
+#[derive(Debug, Serialize)]
+pub struct TrendingAnalyticsItemOutputDTO {
+    pub position: usize,
+    pub id: i32,
+    pub title: String,
+    /// Calculado agora, direto no banco.
+    pub score: f64,
+    /// Score na lista servida aos espectadores; `None` se o item não está
+    /// nela ou não há cálculo no Redis.
+    pub cached_score: Option<f64>,
+    pub views: i64,
+    pub viewers: i64,
+    pub completions: i64,
+    pub watched_seconds: i64,
+}
+
+impl From<(usize, TrendStats)> for TrendingAnalyticsItemOutputDTO {
+    fn from((position, stats): (usize, TrendStats)) -> Self {
+        Self {
+            position,
+            id: stats.id,
+            title: stats.title,
+            score: stats.score,
+            cached_score: None,
+            views: stats.views,
+            viewers: stats.viewers,
+            completions: stats.completions,
+            watched_seconds: stats.watched_seconds,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrendingAnalyticsOutputDTO {
+    pub window: TrendingWindowEnum,
+    #[serde(rename = "type")]
+    pub kind: TrendingKindEnum,
+    pub generated_at: NaiveDateTime,
+    /// Último cálculo gravado no Redis pelo media_worker.
+    pub computed_at: Option<NaiveDateTime>,
+    pub items: Vec<TrendingAnalyticsItemOutputDTO>,
+}
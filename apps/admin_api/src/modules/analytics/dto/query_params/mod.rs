@@ -0,0 +1 @@
+pub mod trending_analytics_query_params_dto;
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct TrendingAnalyticsQueryParamsDTO {
+    /// `24h`, `7d` ou `30d`.
+    pub window: Option<String>,
+
+    /// `video` ou `serie`.
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+
+    #[validate(range(min = 1, max = 200, message = "O limite deve estar entre 1 e 200"))]
+    pub limit: Option<i32>,
+}
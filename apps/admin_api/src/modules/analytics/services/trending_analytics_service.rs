@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::{
+    enums::{trending_kind_enum::TrendingKindEnum, trending_window_enum::TrendingWindowEnum},
+    modules::{
+        app_state::AppState,
+        trending::{self, TrendingScope, RANKING_SIZE},
+    },
+};
+
+use crate::modules::analytics::dto::io::{
+    trending_analytics_input_dto::TrendingAnalyticsInputDTO,
+    trending_analytics_output_dto::{TrendingAnalyticsItemOutputDTO, TrendingAnalyticsOutputDTO},
+};
+
+pub enum TrendingAnalyticsError {
+    Validation(String),
+    Database(String),
+}
+
+/// Audiência da janela por vídeo ou por série, calculada na hora pelo banco,
+/// ao lado do score que os espectadores estão vendo. Inclui itens fora do ar.
+pub async fn execute(
+    TrendingAnalyticsInputDTO {
+        window,
+        kind,
+        limit,
+    }: TrendingAnalyticsInputDTO,
+    state: Arc<AppState>,
+) -> Result<TrendingAnalyticsOutputDTO, TrendingAnalyticsError> {
+    let window = window
+        .map(|value| TrendingWindowEnum::try_from(value.as_str()))
+        .transpose()
+        .map_err(TrendingAnalyticsError::Validation)?
+        .unwrap_or(TrendingWindowEnum::DAY);
+    let kind = kind
+        .map(|value| TrendingKindEnum::try_from(value.as_str()))
+        .transpose()
+        .map_err(TrendingAnalyticsError::Validation)?
+        .unwrap_or(TrendingKindEnum::VIDEO);
+    let limit = limit.unwrap_or(50).clamp(1, RANKING_SIZE as i32) as u64;
+
+    let map_err =
+        |e| TrendingAnalyticsError::Database(format!("Erro ao calcular os títulos em alta: {}", e));
+
+    let generated_at = Utc::now().naive_utc();
+    let stats = state
+        .trending_repo
+        .find_stats(kind, window, generated_at, limit)
+        .await
+        .map_err(map_err)?;
+
+    let computed_at = trending::computed_at(&state).await;
+    let cached: HashMap<i32, f64> = match computed_at {
+        Some(_) => {
+            let scope = match kind {
+                TrendingKindEnum::VIDEO => TrendingScope::Videos,
+                TrendingKindEnum::SERIE => TrendingScope::Series,
+            };
+            trending::ranking(&state, window, scope, RANKING_SIZE)
+                .await
+                .map_err(map_err)?
+                .into_iter()
+                .map(|entry| (entry.id, entry.score))
+                .collect()
+        }
+        None => HashMap::new(),
+    };
+
+    let items = stats
+        .into_iter()
+        .enumerate()
+        .map(|(index, stats)| {
+            let mut item = TrendingAnalyticsItemOutputDTO::from((index + 1, stats));
+            item.cached_score = cached.get(&item.id).copied();
+            item
+        })
+        .collect();
+
+    Ok(TrendingAnalyticsOutputDTO {
+        window,
+        kind,
+        generated_at,
+        computed_at,
+        items,
+    })
+}
@@ -0,0 +1,70 @@
+use crate::modules::availability::{
+    dto::{
+        io::{
+            availability_input_dto::AvailabilityInputDTO,
+            availability_output_dto::AvailabilityOutputDTO,
+        },
+        route_params::availability_route_params_dto::AvailabilityRouteParamsDTO,
+    },
+    services::find_availability_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<AvailabilityRouteParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<AvailabilityOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input = AvailabilityInputDTO::try_from(params).map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ValidationErrorResponse {
+                message: "Erro ao buscar disponibilidade".to_string(),
+                errors: json!([msg]),
+            }),
+        )
+    })?;
+
+    match find_availability_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_availability_service::FindAvailabilityError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_availability_service::FindAvailabilityError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar disponibilidade".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod find_availability_controller;
+pub mod preview_availability_controller;
+pub mod update_availability_controller;
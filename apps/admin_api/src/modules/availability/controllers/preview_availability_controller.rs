@@ -0,0 +1,70 @@
+use crate::modules::availability::{
+    dto::{
+        io::{
+            preview_availability_input_dto::PreviewAvailabilityInputDTO,
+            preview_availability_output_dto::PreviewAvailabilityOutputDTO,
+        },
+        query_params::preview_availability_query_params_dto::PreviewAvailabilityQueryParamsDTO,
+    },
+    services::preview_availability_service,
+};
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<PreviewAvailabilityQueryParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<PreviewAvailabilityOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input = PreviewAvailabilityInputDTO::try_from(query).map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ValidationErrorResponse {
+                message: "Erro ao calcular a prévia".to_string(),
+                errors: json!([msg]),
+            }),
+        )
+    })?;
+
+    match preview_availability_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                preview_availability_service::PreviewAvailabilityError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao calcular a prévia".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::modules::availability::{
+    dto::{
+        io::{
+            availability_output_dto::AvailabilityOutputDTO,
+            update_availability_input_dto::UpdateAvailabilityInputDTO,
+        },
+        request::update_availability_request_dto::UpdateAvailabilityRequestDTO,
+        route_params::availability_route_params_dto::AvailabilityRouteParamsDTO,
+    },
+    services::update_availability_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<AvailabilityRouteParamsDTO>,
+    payload: Json<UpdateAvailabilityRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<AvailabilityOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    let input = UpdateAvailabilityInputDTO::try_from((payload, params)).map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ValidationErrorResponse {
+                message: "Erro ao atualizar disponibilidade".to_string(),
+                errors: json!([msg]),
+            }),
+        )
+    })?;
+
+    match update_availability_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Disponibilidade atualizada com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                update_availability_service::UpdateAvailabilityError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                update_availability_service::UpdateAvailabilityError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                update_availability_service::UpdateAvailabilityError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar disponibilidade".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::modules::availability::dto::route_params::availability_route_params_dto::AvailabilityRouteParamsDTO;
+use shared::enums::availability_owner_enum::AvailabilityOwnerEnum;
+
+/// Item identificado pela rota, com o tipo já validado.
+#[derive(Debug, Clone, Copy)]
+pub struct AvailabilityInputDTO {
+    pub owner: AvailabilityOwnerEnum,
+    pub id: i32,
+}
+
+impl TryFrom<AvailabilityRouteParamsDTO> for AvailabilityInputDTO {
+    type Error = String;
+
+    fn try_from(params: AvailabilityRouteParamsDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            owner: AvailabilityOwnerEnum::try_from(params.item_type.as_str())?,
+            id: params.id,
+        })
+    }
+}
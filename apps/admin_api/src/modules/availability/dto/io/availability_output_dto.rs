@@ -0,0 +1,37 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::enums::availability_owner_enum::AvailabilityOwnerEnum;
+use shared::modules::database::schema::availability_windows::Model as AvailabilityWindowModel;
+
+#[derive(Debug, Serialize)]
+pub struct AvailabilityRegionOutputDTO {
+    pub region: String,
+    pub available_from: Option<NaiveDateTime>,
+    pub available_until: Option<NaiveDateTime>,
+    pub release_state: String,
+}
+
+impl From<AvailabilityWindowModel> for AvailabilityRegionOutputDTO {
+    fn from(window: AvailabilityWindowModel) -> Self {
+        Self {
+            region: window.region,
+            available_from: window.available_from,
+            available_until: window.available_until,
+            release_state: window.release_state,
+        }
+    }
+}
+
+/// Janelas do item. `release_state` é o último estado gravado pelo
+/// agendador, não o calculado na hora.
+#[derive(Debug, Serialize)]
+pub struct AvailabilityOutputDTO {
+    #[serde(rename = "type")]
+    pub item_type: AvailabilityOwnerEnum,
+    pub id: i32,
+    pub title: String,
+    pub available_from: Option<NaiveDateTime>,
+    pub available_until: Option<NaiveDateTime>,
+    pub release_state: String,
+    pub regions: Vec<AvailabilityRegionOutputDTO>,
+}
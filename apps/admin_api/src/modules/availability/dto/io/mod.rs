@@ -0,0 +1,5 @@
+pub mod availability_input_dto;
+pub mod availability_output_dto;
+pub mod preview_availability_input_dto;
+pub mod preview_availability_output_dto;
+pub mod update_availability_input_dto;
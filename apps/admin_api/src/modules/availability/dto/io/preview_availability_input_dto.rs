@@ -0,0 +1,21 @@
+use chrono::{NaiveDateTime, Utc};
+use shared::modules::availability::normalize_region;
+
+use crate::modules::availability::dto::query_params::preview_availability_query_params_dto::PreviewAvailabilityQueryParamsDTO;
+
+#[derive(Debug)]
+pub struct PreviewAvailabilityInputDTO {
+    pub at: NaiveDateTime,
+    pub region: Option<String>,
+}
+
+impl TryFrom<PreviewAvailabilityQueryParamsDTO> for PreviewAvailabilityInputDTO {
+    type Error = String;
+
+    fn try_from(query: PreviewAvailabilityQueryParamsDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            at: query.at.unwrap_or_else(|| Utc::now().naive_utc()),
+            region: query.region.as_deref().map(normalize_region).transpose()?,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::schema::series::Model as SerieModel;
+use shared::modules::database::schema::videos::Model as VideoModel;
+
+#[derive(Debug, Serialize)]
+pub struct PreviewItemOutputDTO {
+    pub id: i32,
+    pub title: String,
+    pub series_id: Option<i32>,
+    pub season_number: Option<i32>,
+    pub episode_number: Option<i32>,
+}
+
+impl From<VideoModel> for PreviewItemOutputDTO {
+    fn from(video: VideoModel) -> Self {
+        Self {
+            id: video.id,
+            title: video.title,
+            series_id: video.series_id,
+            season_number: video.season_number,
+            episode_number: video.episode_number,
+        }
+    }
+}
+
+impl From<SerieModel> for PreviewItemOutputDTO {
+    fn from(serie: SerieModel) -> Self {
+        Self {
+            id: serie.id,
+            title: serie.title,
+            series_id: None,
+            season_number: None,
+            episode_number: None,
+        }
+    }
+}
+
+/// O que estará no ar no instante consultado.
+#[derive(Debug, Serialize)]
+pub struct PreviewAvailabilityOutputDTO {
+    pub at: NaiveDateTime,
+    pub region: Option<String>,
+    pub series: Vec<PreviewItemOutputDTO>,
+    pub videos: Vec<PreviewItemOutputDTO>,
+}
@@ -0,0 +1,50 @@
+use crate::modules::availability::dto::{
+    request::update_availability_request_dto::UpdateAvailabilityRequestDTO,
+    route_params::availability_route_params_dto::AvailabilityRouteParamsDTO,
+};
+use shared::{
+    enums::availability_owner_enum::AvailabilityOwnerEnum,
+    modules::{
+        availability::normalize_region,
+        database::repositories::availability_repository::{RegionWindow, SetAvailabilityRequest},
+    },
+};
+
+#[derive(Debug)]
+pub struct UpdateAvailabilityInputDTO {
+    pub owner: AvailabilityOwnerEnum,
+    pub id: i32,
+    pub availability: SetAvailabilityRequest,
+}
+
+impl TryFrom<(UpdateAvailabilityRequestDTO, AvailabilityRouteParamsDTO)>
+    for UpdateAvailabilityInputDTO
+{
+    type Error = String;
+
+    fn try_from(
+        (body, params): (UpdateAvailabilityRequestDTO, AvailabilityRouteParamsDTO),
+    ) -> Result<Self, Self::Error> {
+        let regions = body
+            .regions
+            .into_iter()
+            .map(|window| {
+                Ok(RegionWindow {
+                    region: normalize_region(&window.region)?,
+                    available_from: window.available_from,
+                    available_until: window.available_until,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            owner: AvailabilityOwnerEnum::try_from(params.item_type.as_str())?,
+            id: params.id,
+            availability: SetAvailabilityRequest {
+                available_from: body.available_from,
+                available_until: body.available_until,
+                regions,
+            },
+        })
+    }
+}
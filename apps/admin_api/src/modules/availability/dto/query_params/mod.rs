@@ -0,0 +1 @@
+pub mod preview_availability_query_params_dto;
@@ -0,0 +1,12 @@
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct PreviewAvailabilityQueryParamsDTO {
+    /// Instante consultado (UTC); sem ele vale o momento atual.
+    pub at: Option<NaiveDateTime>,
+
+    #[validate(length(equal = 2, message = "A região deve ter duas letras"))]
+    pub region: Option<String>,
+}
@@ -0,0 +1 @@
+pub mod update_availability_request_dto;
@@ -0,0 +1,26 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, Default)]
+pub struct AvailabilityRegionRequestDTO {
+    #[validate(length(equal = 2, message = "A região deve ter duas letras"))]
+    pub region: String,
+
+    pub available_from: Option<NaiveDateTime>,
+
+    pub available_until: Option<NaiveDateTime>,
+}
+
+/// Substitui a janela global e todas as regionais. Limites omitidos ficam
+/// abertos; uma lista vazia de regiões remove as janelas regionais.
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct UpdateAvailabilityRequestDTO {
+    pub available_from: Option<NaiveDateTime>,
+
+    pub available_until: Option<NaiveDateTime>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub regions: Vec<AvailabilityRegionRequestDTO>,
+}
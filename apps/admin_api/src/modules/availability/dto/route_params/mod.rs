@@ -0,0 +1 @@
+pub mod availability_route_params_dto;
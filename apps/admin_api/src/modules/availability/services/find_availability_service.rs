@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use shared::{enums::availability_owner_enum::AvailabilityOwnerEnum, modules::app_state::AppState};
+
+use crate::modules::availability::dto::io::{
+    availability_input_dto::AvailabilityInputDTO, availability_output_dto::AvailabilityOutputDTO,
+};
+
+pub enum FindAvailabilityError {
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    input: AvailabilityInputDTO,
+    state: Arc<AppState>,
+) -> Result<AvailabilityOutputDTO, FindAvailabilityError> {
+    let map_err =
+        |e| FindAvailabilityError::Database(format!("Erro ao buscar disponibilidade: {}", e));
+
+    let (title, available_from, available_until, release_state) = match input.owner {
+        AvailabilityOwnerEnum::VIDEO => {
+            let video = state
+                .video_repo
+                .find_by_id(input.id)
+                .await
+                .map_err(map_err)?
+                .ok_or_else(|| not_found(input))?;
+            (
+                video.title,
+                video.available_from,
+                video.available_until,
+                video.release_state,
+            )
+        }
+        AvailabilityOwnerEnum::SERIE => {
+            let serie = state
+                .serie_repo
+                .find_by_id(input.id)
+                .await
+                .map_err(map_err)?
+                .ok_or_else(|| not_found(input))?;
+            (
+                serie.title,
+                serie.available_from,
+                serie.available_until,
+                serie.release_state,
+            )
+        }
+    };
+
+    let regions = state
+        .availability_repo
+        .find_by_owner(input.owner, input.id)
+        .await
+        .map_err(map_err)?;
+
+    Ok(AvailabilityOutputDTO {
+        item_type: input.owner,
+        id: input.id,
+        title,
+        available_from,
+        available_until,
+        release_state,
+        regions: regions.into_iter().map(Into::into).collect(),
+    })
+}
+
+fn not_found(input: AvailabilityInputDTO) -> FindAvailabilityError {
+    FindAvailabilityError::NotFound(format!(
+        "{} com id {} não encontrado",
+        input.owner.as_str(),
+        input.id
+    ))
+}
@@ -0,0 +1,3 @@
+pub mod find_availability_service;
+pub mod preview_availability_service;
+pub mod update_availability_service;
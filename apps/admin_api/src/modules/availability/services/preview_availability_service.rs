@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::availability::dto::io::{
+    preview_availability_input_dto::PreviewAvailabilityInputDTO,
+    preview_availability_output_dto::PreviewAvailabilityOutputDTO,
+};
+
+pub enum PreviewAvailabilityError {
+    Database(String),
+}
+
+/// Séries e vídeos que o espectador verá no instante e região informados,
+/// com as mesmas regras aplicadas pela viewer_api.
+pub async fn execute(
+    PreviewAvailabilityInputDTO { at, region }: PreviewAvailabilityInputDTO,
+    state: Arc<AppState>,
+) -> Result<PreviewAvailabilityOutputDTO, PreviewAvailabilityError> {
+    let map_err =
+        |e| PreviewAvailabilityError::Database(format!("Erro ao calcular a prévia: {}", e));
+
+    let series = state
+        .availability_repo
+        .find_live_series(at, region.as_deref())
+        .await
+        .map_err(map_err)?;
+    let videos = state
+        .availability_repo
+        .find_live_videos(at, region.as_deref())
+        .await
+        .map_err(map_err)?;
+
+    Ok(PreviewAvailabilityOutputDTO {
+        at,
+        region,
+        series: series.into_iter().map(Into::into).collect(),
+        videos: videos.into_iter().map(Into::into).collect(),
+    })
+}
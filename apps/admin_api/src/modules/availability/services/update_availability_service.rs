@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use shared::{
+    enums::availability_owner_enum::AvailabilityOwnerEnum,
+    modules::{
+        app_state::AppState, availability, database::repositories::search_repository::IndexScope,
+        search,
+    },
+};
+
+use crate::modules::availability::{
+    dto::io::{
+        availability_input_dto::AvailabilityInputDTO,
+        availability_output_dto::AvailabilityOutputDTO,
+        update_availability_input_dto::UpdateAvailabilityInputDTO,
+    },
+    services::find_availability_service::{self, FindAvailabilityError},
+};
+
+pub enum UpdateAvailabilityError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+/// Troca as janelas do item, limpa o cache do catálogo e atualiza o índice
+/// de busca. O aviso de
+/// lançamento sai do agendador quando a janela abre.
+pub async fn execute(
+    input: UpdateAvailabilityInputDTO,
+    state: Arc<AppState>,
+) -> Result<AvailabilityOutputDTO, UpdateAvailabilityError> {
+    let availability = &input.availability;
+
+    check_interval(availability.available_from, availability.available_until)
+        .map_err(UpdateAvailabilityError::Validation)?;
+
+    let mut regions = HashSet::new();
+    for window in &availability.regions {
+        if !regions.insert(window.region.as_str()) {
+            return Err(UpdateAvailabilityError::Validation(format!(
+                "Região {} informada mais de uma vez",
+                window.region
+            )));
+        }
+        check_interval(window.available_from, window.available_until).map_err(|msg| {
+            UpdateAvailabilityError::Validation(format!("{}: {}", window.region, msg))
+        })?;
+    }
+
+    let item = AvailabilityInputDTO {
+        owner: input.owner,
+        id: input.id,
+    };
+
+    let replaced = state
+        .availability_repo
+        .replace(input.owner, input.id, input.availability)
+        .await
+        .map_err(|e| {
+            UpdateAvailabilityError::Database(format!("Erro ao atualizar disponibilidade: {}", e))
+        })?;
+
+    if !replaced {
+        return Err(UpdateAvailabilityError::NotFound(format!(
+            "{} com id {} não encontrado",
+            input.owner.as_str(),
+            input.id
+        )));
+    }
+
+    availability::invalidate_catalog_cache(&state).await;
+    match input.owner {
+        AvailabilityOwnerEnum::VIDEO => search::index::sync_videos(&state, &[input.id]).await,
+        AvailabilityOwnerEnum::SERIE => {
+            search::index::sync_scope(&state, IndexScope::Serie(input.id)).await
+        }
+    }
+
+    find_availability_service::execute(item, state)
+        .await
+        .map_err(|err| match err {
+            FindAvailabilityError::Database(msg) => UpdateAvailabilityError::Database(msg),
+            FindAvailabilityError::NotFound(msg) => UpdateAvailabilityError::NotFound(msg),
+        })
+}
+
+fn check_interval(from: Option<NaiveDateTime>, until: Option<NaiveDateTime>) -> Result<(), String> {
+    match (from, until) {
+        (Some(from), Some(until)) if until <= from => {
+            Err("available_until deve ser posterior a available_from".to_string())
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,91 @@
+use crate::modules::catalog::{
+    dto::{
+        io::{
+            export_catalog_input_dto::ExportCatalogInputDTO,
+            export_catalog_output_dto::ExportCatalogOutputDTO,
+        },
+        query_params::export_catalog_query_params_dto::ExportCatalogQueryParamsDTO,
+    },
+    services::export_catalog_service,
+};
+use axum::{
+    extract::Query,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::{access_group_enum::AccessGroupEnum, catalog_format_enum::CatalogFormatEnum},
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<ExportCatalogQueryParamsDTO>,
+) -> Result<Response, (StatusCode, Json<ValidationErrorResponse>)> {
+    let input = ExportCatalogInputDTO::try_from(query).map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ValidationErrorResponse {
+                message: "Erro ao exportar o catálogo".to_string(),
+                errors: json!([msg]),
+            }),
+        )
+    })?;
+
+    match export_catalog_service::execute(input, state).await {
+        Ok(ExportCatalogOutputDTO::Json(document)) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(document),
+                message: None,
+            }),
+        )
+            .into_response()),
+        Ok(ExportCatalogOutputDTO::Csv { entity, body }) => Ok((
+            StatusCode::OK,
+            [
+                (
+                    header::CONTENT_TYPE,
+                    CatalogFormatEnum::CSV.mime_type().to_string(),
+                ),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"catalog-{}.csv\"",
+                        entity.as_str().to_lowercase()
+                    ),
+                ),
+            ],
+            body,
+        )
+            .into_response()),
+        Err(err) => {
+            let (status, msg) = match err {
+                export_catalog_service::ExportCatalogError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                export_catalog_service::ExportCatalogError::Serialization(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao exportar o catálogo".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
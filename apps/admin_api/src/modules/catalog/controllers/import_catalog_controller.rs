@@ -0,0 +1,80 @@
+use crate::modules::catalog::{
+    dto::{
+        io::import_catalog_input_dto::ImportCatalogInputDTO,
+        query_params::import_catalog_query_params_dto::ImportCatalogQueryParamsDTO,
+    },
+    services::import_catalog_service,
+};
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser, catalog::import::ImportReport,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<ImportCatalogQueryParamsDTO>,
+    body: String,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<ImportReport>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input = ImportCatalogInputDTO::try_from((query, body)).map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ValidationErrorResponse {
+                message: "Erro ao importar o catálogo".to_string(),
+                errors: json!([msg]),
+            }),
+        )
+    })?;
+
+    match import_catalog_service::execute(input, state).await {
+        Ok(report) => {
+            let message = match (report.dry_run, report.errors.len()) {
+                (true, 0) => "Simulação concluída sem erros".to_string(),
+                (true, errors) => format!("Simulação concluída: {} linhas com erro", errors),
+                (false, 0) => "Catálogo importado com sucesso!".to_string(),
+                (false, errors) => format!(
+                    "Catálogo importado; {} linhas com erro ficaram de fora",
+                    errors
+                ),
+            };
+
+            Ok((
+                StatusCode::OK,
+                Json(ResponseInterface {
+                    result: Some(report),
+                    message: Some(message),
+                }),
+            ))
+        }
+        Err(err) => {
+            let (status, msg) = match err {
+                import_catalog_service::ImportCatalogError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                import_catalog_service::ImportCatalogError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao importar o catálogo".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
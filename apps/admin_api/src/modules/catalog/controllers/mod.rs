@@ -0,0 +1,2 @@
+pub mod export_catalog_controller;
+pub mod import_catalog_controller;
@@ -0,0 +1,34 @@
+use crate::modules::catalog::dto::query_params::export_catalog_query_params_dto::ExportCatalogQueryParamsDTO;
+use shared::enums::catalog_entity_enum::CatalogEntityEnum;
+use shared::enums::catalog_format_enum::CatalogFormatEnum;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExportCatalogInputDTO {
+    pub format: CatalogFormatEnum,
+    /// Sempre presente no CSV.
+    pub entity: Option<CatalogEntityEnum>,
+}
+
+impl TryFrom<ExportCatalogQueryParamsDTO> for ExportCatalogInputDTO {
+    type Error = String;
+
+    fn try_from(query: ExportCatalogQueryParamsDTO) -> Result<Self, Self::Error> {
+        let format = query
+            .format
+            .as_deref()
+            .map(CatalogFormatEnum::try_from)
+            .transpose()?
+            .unwrap_or_default();
+        let entity = query
+            .entity
+            .as_deref()
+            .map(CatalogEntityEnum::try_from)
+            .transpose()?;
+
+        if format == CatalogFormatEnum::CSV && entity.is_none() {
+            return Err("Informe a entidade do CSV em entity".to_string());
+        }
+
+        Ok(Self { format, entity })
+    }
+}
@@ -0,0 +1,10 @@
+use shared::enums::catalog_entity_enum::CatalogEntityEnum;
+use shared::modules::catalog::rows::CatalogDocument;
+
+pub enum ExportCatalogOutputDTO {
+    Json(CatalogDocument),
+    Csv {
+        entity: CatalogEntityEnum,
+        body: String,
+    },
+}
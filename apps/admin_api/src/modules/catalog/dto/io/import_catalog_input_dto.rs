@@ -0,0 +1,41 @@
+use crate::modules::catalog::dto::query_params::import_catalog_query_params_dto::ImportCatalogQueryParamsDTO;
+use shared::enums::catalog_entity_enum::CatalogEntityEnum;
+use shared::enums::catalog_format_enum::CatalogFormatEnum;
+
+#[derive(Debug)]
+pub struct ImportCatalogInputDTO {
+    pub format: CatalogFormatEnum,
+    /// Sempre presente no CSV.
+    pub entity: Option<CatalogEntityEnum>,
+    pub dry_run: bool,
+    pub body: String,
+}
+
+impl TryFrom<(ImportCatalogQueryParamsDTO, String)> for ImportCatalogInputDTO {
+    type Error = String;
+
+    fn try_from((query, body): (ImportCatalogQueryParamsDTO, String)) -> Result<Self, Self::Error> {
+        let format = query
+            .format
+            .as_deref()
+            .map(CatalogFormatEnum::try_from)
+            .transpose()?
+            .unwrap_or_default();
+        let entity = query
+            .entity
+            .as_deref()
+            .map(CatalogEntityEnum::try_from)
+            .transpose()?;
+
+        if format == CatalogFormatEnum::CSV && entity.is_none() {
+            return Err("Informe a entidade do CSV em entity".to_string());
+        }
+
+        Ok(Self {
+            format,
+            entity,
+            dry_run: query.dry_run,
+            body,
+        })
+    }
+}
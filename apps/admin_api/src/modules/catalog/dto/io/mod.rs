@@ -0,0 +1,3 @@
+pub mod export_catalog_input_dto;
+pub mod export_catalog_output_dto;
+pub mod import_catalog_input_dto;
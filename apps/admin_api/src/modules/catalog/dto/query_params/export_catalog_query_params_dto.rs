@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct ExportCatalogQueryParamsDTO {
+    /// `json` (padrão) ou `csv`.
+    pub format: Option<String>,
+
+    /// Entidade exportada; obrigatória no CSV.
+    pub entity: Option<String>,
+}
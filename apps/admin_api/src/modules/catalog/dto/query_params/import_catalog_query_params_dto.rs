@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct ImportCatalogQueryParamsDTO {
+    /// `json` (padrão) ou `csv`.
+    pub format: Option<String>,
+
+    /// Entidade do arquivo; obrigatória no CSV.
+    pub entity: Option<String>,
+
+    #[serde(default)]
+    pub dry_run: bool,
+}
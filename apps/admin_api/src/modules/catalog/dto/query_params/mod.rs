@@ -0,0 +1,2 @@
+pub mod export_catalog_query_params_dto;
+pub mod import_catalog_query_params_dto;
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use shared::enums::catalog_format_enum::CatalogFormatEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::catalog;
+
+use crate::modules::catalog::dto::io::{
+    export_catalog_input_dto::ExportCatalogInputDTO,
+    export_catalog_output_dto::ExportCatalogOutputDTO,
+};
+
+pub enum ExportCatalogError {
+    Database(String),
+    Serialization(String),
+}
+
+pub async fn execute(
+    input: ExportCatalogInputDTO,
+    state: Arc<AppState>,
+) -> Result<ExportCatalogOutputDTO, ExportCatalogError> {
+    let document = catalog::export::run(&state)
+        .await
+        .map_err(|e| ExportCatalogError::Database(format!("Erro ao exportar o catálogo: {}", e)))?;
+
+    match (input.format, input.entity) {
+        (CatalogFormatEnum::CSV, Some(entity)) => Ok(ExportCatalogOutputDTO::Csv {
+            entity,
+            body: catalog::csv::write(entity, &document)
+                .map_err(ExportCatalogError::Serialization)?,
+        }),
+        _ => Ok(ExportCatalogOutputDTO::Json(document)),
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use shared::enums::catalog_format_enum::CatalogFormatEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::catalog::{self, import::ImportReport, rows::CatalogDocument};
+use shared::modules::search;
+
+use crate::modules::catalog::dto::io::import_catalog_input_dto::ImportCatalogInputDTO;
+
+pub enum ImportCatalogError {
+    Validation(String),
+    Database(String),
+}
+
+/// Lê o arquivo e importa as linhas válidas; com `dry_run`, só valida e
+/// informa o que seria criado ou atualizado.
+pub async fn execute(
+    input: ImportCatalogInputDTO,
+    state: Arc<AppState>,
+) -> Result<ImportReport, ImportCatalogError> {
+    let document = match (input.format, input.entity) {
+        (CatalogFormatEnum::CSV, Some(entity)) => {
+            catalog::csv::read(entity, &input.body).map_err(ImportCatalogError::Validation)?
+        }
+        _ => serde_json::from_str::<CatalogDocument>(&input.body)
+            .map_err(|e| ImportCatalogError::Validation(format!("JSON inválido: {}", e)))?,
+    };
+
+    let report = catalog::import::run(&state, &document, input.dry_run)
+        .await
+        .map_err(|e| ImportCatalogError::Database(format!("Erro ao importar o catálogo: {}", e)))?;
+
+    if !input.dry_run {
+        search::invalidate_suggestions(&state).await;
+
+        // a importação pode mexer em boa parte do catálogo; mais simples reconstruir
+        if let Err(e) = search::index::reindex(&state).await {
+            tracing::warn!("Falha ao reindexar a busca após a importação: {:?}", e);
+        }
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,2 @@
+pub mod export_catalog_service;
+pub mod import_catalog_service;
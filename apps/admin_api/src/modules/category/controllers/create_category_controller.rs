@@ -0,0 +1,60 @@
+use crate::modules::category::{
+    dto::{
+        io::category_output_dto::CategoryOutputDTO,
+        request::create_category_request_dto::CreateCategoryRequestDTO,
+    },
+    services::create_category_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    payload: Json<CreateCategoryRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<CategoryOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match create_category_service::execute(payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::CREATED,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Categoria criada com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                create_category_service::CreateCategoryError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                create_category_service::CreateCategoryError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao criar categoria".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create_category_controller;
+pub mod delete_category_controller;
+pub mod find_category_controller;
+pub mod find_many_category_controller;
+pub mod update_category_controller;
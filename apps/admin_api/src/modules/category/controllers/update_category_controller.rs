@@ -0,0 +1,65 @@
+use crate::modules::category::{
+    dto::{
+        io::category_output_dto::CategoryOutputDTO,
+        request::update_category_request_dto::UpdateCategoryRequestDTO,
+        route_params::category_route_params_dto::CategoryRouteParamsDTO,
+    },
+    services::update_category_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<CategoryRouteParamsDTO>,
+    payload: Json<UpdateCategoryRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<CategoryOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match update_category_service::execute(params, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Categoria atualizada com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                update_category_service::UpdateCategoryError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                update_category_service::UpdateCategoryError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                update_category_service::UpdateCategoryError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar categoria".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::schema::categories::Model as CategoryModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CategoryOutputDTO {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<CategoryModel> for CategoryOutputDTO {
+    fn from(category: CategoryModel) -> Self {
+        Self {
+            id: category.id,
+            name: category.name,
+            description: category.description,
+            created_at: category.created_at,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::modules::category::dto::query_params::find_many_category_query_params_dto::FindManyCategoryQueryParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindManyCategoryInputDTO {
+    pub name: Option<String>,
+    pub skip: Option<i32>,
+    pub take: Option<i32>,
+}
+
+impl From<FindManyCategoryQueryParamsDTO> for FindManyCategoryInputDTO {
+    fn from(query: FindManyCategoryQueryParamsDTO) -> Self {
+        Self {
+            name: query.name,
+            skip: query.skip,
+            take: query.take,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod category_output_dto;
+pub mod find_many_category_input_dto;
@@ -0,0 +1 @@
+pub mod find_many_category_query_params_dto;
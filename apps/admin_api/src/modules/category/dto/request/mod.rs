@@ -0,0 +1,2 @@
+pub mod create_category_request_dto;
+pub mod update_category_request_dto;
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct UpdateCategoryRequestDTO {
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "O nome deve ter entre 2 e 100 caracteres"
+    ))]
+    pub name: Option<String>,
+
+    #[validate(length(max = 500, message = "A descrição não pode ter mais de 500 caracteres"))]
+    pub description: Option<String>,
+}
@@ -0,0 +1 @@
+pub mod category_route_params_dto;
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use shared::modules::{
+    app_state::AppState, database::repositories::categories_repository::CreateCategoryRequest,
+};
+
+use crate::modules::category::dto::{
+    io::category_output_dto::CategoryOutputDTO,
+    request::create_category_request_dto::CreateCategoryRequestDTO,
+};
+
+pub enum CreateCategoryError {
+    Database(String),
+    Conflict(String),
+}
+
+pub async fn execute(
+    payload: CreateCategoryRequestDTO,
+    state: Arc<AppState>,
+) -> Result<CategoryOutputDTO, CreateCategoryError> {
+    let name = payload.name.trim().to_string();
+
+    let existing =
+        state.category_repo.find_by_name(&name).await.map_err(|e| {
+            CreateCategoryError::Database(format!("Erro ao buscar categoria: {}", e))
+        })?;
+
+    if let Some(existing) = existing {
+        return Err(CreateCategoryError::Conflict(format!(
+            "Já existe a categoria '{}' (id {})",
+            existing.name, existing.id
+        )));
+    }
+
+    let category = state
+        .category_repo
+        .create(CreateCategoryRequest {
+            name,
+            description: payload.description,
+        })
+        .await
+        .map_err(|e| CreateCategoryError::Database(format!("Erro ao criar: {}", e)))?;
+
+    Ok(category.into())
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::search_repository::IndexScope;
+use shared::modules::search;
+
+use crate::modules::category::dto::route_params::category_route_params_dto::CategoryRouteParamsDTO;
+
+pub enum DeleteCategoryError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Remove a categoria e a desassocia de todos os vídeos.
+pub async fn execute(
+    params: CategoryRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteCategoryError> {
+    let video_ids = search::index::affected_videos(&state, IndexScope::Category(params.id)).await;
+
+    let deleted =
+        state.category_repo.delete(params.id).await.map_err(|e| {
+            DeleteCategoryError::Database(format!("Erro ao deletar categoria: {}", e))
+        })?;
+
+    if !deleted {
+        return Err(DeleteCategoryError::NotFound(format!(
+            "Categoria com id {} não encontrada",
+            params.id
+        )));
+    }
+
+    search::index::sync_videos(&state, &video_ids).await;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::category::dto::{
+    io::category_output_dto::CategoryOutputDTO,
+    route_params::category_route_params_dto::CategoryRouteParamsDTO,
+};
+
+pub enum FindCategoryError {
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    params: CategoryRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<CategoryOutputDTO, FindCategoryError> {
+    let category = state
+        .category_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| FindCategoryError::Database(format!("Erro ao buscar categoria: {}", e)))?
+        .ok_or_else(|| {
+            FindCategoryError::NotFound(format!("Categoria com id {} não encontrada", params.id))
+        })?;
+
+    Ok(category.into())
+}
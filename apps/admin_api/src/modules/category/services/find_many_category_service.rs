@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::category::dto::io::{
+    category_output_dto::CategoryOutputDTO, find_many_category_input_dto::FindManyCategoryInputDTO,
+};
+
+pub enum FindManyCategoryError {
+    Database(String),
+}
+
+pub async fn execute(
+    FindManyCategoryInputDTO { name, skip, take }: FindManyCategoryInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<CategoryOutputDTO>, FindManyCategoryError> {
+    let categories = state
+        .category_repo
+        .find_many(
+            name.as_deref(),
+            skip.map(|s| s as u64),
+            Some(take.unwrap_or(20) as u64),
+        )
+        .await
+        .map_err(|e| FindManyCategoryError::Database(format!("Erro ao buscar categorias: {}", e)))?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(categories)
+}
@@ -0,0 +1,5 @@
+pub mod create_category_service;
+pub mod delete_category_service;
+pub mod find_category_service;
+pub mod find_many_category_service;
+pub mod update_category_service;
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use shared::modules::{
+    app_state::AppState, database::repositories::categories_repository::UpdateCategoryRequest,
+};
+
+use crate::modules::category::dto::{
+    io::category_output_dto::CategoryOutputDTO,
+    request::update_category_request_dto::UpdateCategoryRequestDTO,
+    route_params::category_route_params_dto::CategoryRouteParamsDTO,
+};
+
+pub enum UpdateCategoryError {
+    Database(String),
+    NotFound(String),
+    Conflict(String),
+}
+
+pub async fn execute(
+    params: CategoryRouteParamsDTO,
+    payload: UpdateCategoryRequestDTO,
+    state: Arc<AppState>,
+) -> Result<CategoryOutputDTO, UpdateCategoryError> {
+    let map_err = |e| UpdateCategoryError::Database(format!("Erro ao buscar categoria: {}", e));
+
+    let category = state
+        .category_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(map_err)?;
+
+    if category.is_none() {
+        return Err(UpdateCategoryError::NotFound(format!(
+            "Categoria com id {} não encontrada",
+            params.id
+        )));
+    }
+
+    let name = payload.name.map(|name| name.trim().to_string());
+
+    if let Some(name) = &name {
+        let existing = state
+            .category_repo
+            .find_by_name(name)
+            .await
+            .map_err(map_err)?;
+
+        if let Some(existing) = existing.filter(|existing| existing.id != params.id) {
+            return Err(UpdateCategoryError::Conflict(format!(
+                "Já existe a categoria '{}' (id {})",
+                existing.name, existing.id
+            )));
+        }
+    }
+
+    let updated = state
+        .category_repo
+        .update(
+            params.id,
+            UpdateCategoryRequest {
+                name,
+                description: payload.description,
+            },
+        )
+        .await
+        .map_err(|e| UpdateCategoryError::Database(format!("Erro ao atualizar: {}", e)))?;
+
+    Ok(updated.into())
+}
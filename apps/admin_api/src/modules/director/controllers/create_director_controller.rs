@@ -0,0 +1,60 @@
+use crate::modules::director::{
+    dto::{
+        io::director_output_dto::DirectorOutputDTO,
+        request::create_director_request_dto::CreateDirectorRequestDTO,
+    },
+    services::create_director_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    payload: Json<CreateDirectorRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<DirectorOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match create_director_service::execute(payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::CREATED,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Diretor criado com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                create_director_service::CreateDirectorError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                create_director_service::CreateDirectorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao criar diretor".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::modules::director::{
+    dto::route_params::director_route_params_dto::DirectorRouteParamsDTO,
+    services::delete_director_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<DirectorRouteParamsDTO>,
+) -> Result<(StatusCode, Json<ResponseInterface<()>>), (StatusCode, Json<ValidationErrorResponse>)>
+{
+    match delete_director_service::execute(params, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Diretor removido com sucesso".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                delete_director_service::DeleteDirectorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                delete_director_service::DeleteDirectorError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao deletar diretor".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::modules::director::{
+    dto::{
+        io::director_output_dto::DirectorOutputDTO,
+        route_params::director_route_params_dto::DirectorRouteParamsDTO,
+    },
+    services::find_director_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<DirectorRouteParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<DirectorOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_director_service::execute(params, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_director_service::FindDirectorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_director_service::FindDirectorError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar diretor".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::modules::director::{
+    dto::{
+        io::director_output_dto::DirectorOutputDTO,
+        query_params::find_many_director_query_params_dto::FindManyDirectorQueryParamsDTO,
+    },
+    services::find_many_director_service,
+};
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<FindManyDirectorQueryParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<Vec<DirectorOutputDTO>>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_many_director_service::execute(query.into(), state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_many_director_service::FindManyDirectorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar diretores".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
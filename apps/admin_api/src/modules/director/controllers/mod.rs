@@ -0,0 +1,5 @@
+pub mod create_director_controller;
+pub mod delete_director_controller;
+pub mod find_director_controller;
+pub mod find_many_director_controller;
+pub mod update_director_controller;
@@ -0,0 +1,65 @@
+use crate::modules::director::{
+    dto::{
+        io::director_output_dto::DirectorOutputDTO,
+        request::update_director_request_dto::UpdateDirectorRequestDTO,
+        route_params::director_route_params_dto::DirectorRouteParamsDTO,
+    },
+    services::update_director_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<DirectorRouteParamsDTO>,
+    payload: Json<UpdateDirectorRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<DirectorOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match update_director_service::execute(params, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Diretor atualizado com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                update_director_service::UpdateDirectorError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                update_director_service::UpdateDirectorError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                update_director_service::UpdateDirectorError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar diretor".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Serialize;
+use shared::modules::database::repositories::images_repository::CatalogImageUrls;
+use shared::modules::database::schema::directors::Model as DirectorModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DirectorOutputDTO {
+    pub id: i32,
+    pub name: String,
+    pub biography: Option<String>,
+    pub birth_date: Option<NaiveDate>,
+    pub profile_picture_url: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub images: CatalogImageUrls,
+}
+
+impl From<DirectorModel> for DirectorOutputDTO {
+    fn from(director: DirectorModel) -> Self {
+        Self {
+            id: director.id,
+            name: director.name,
+            biography: director.biography,
+            birth_date: director.birth_date.map(|date| date.date()),
+            profile_picture_url: director.profile_picture_url,
+            created_at: director.created_at,
+            images: CatalogImageUrls::new(),
+        }
+    }
+}
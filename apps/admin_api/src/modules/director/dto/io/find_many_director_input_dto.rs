@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::modules::director::dto::query_params::find_many_director_query_params_dto::FindManyDirectorQueryParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindManyDirectorInputDTO {
+    pub name: Option<String>,
+    pub skip: Option<i32>,
+    pub take: Option<i32>,
+}
+
+impl From<FindManyDirectorQueryParamsDTO> for FindManyDirectorInputDTO {
+    fn from(query: FindManyDirectorQueryParamsDTO) -> Self {
+        Self {
+            name: query.name,
+            skip: query.skip,
+            take: query.take,
+        }
+    }
+}
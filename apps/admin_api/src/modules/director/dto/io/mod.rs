@@ -0,0 +1,2 @@
+pub mod director_output_dto;
+pub mod find_many_director_input_dto;
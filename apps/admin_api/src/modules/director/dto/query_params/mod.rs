@@ -0,0 +1 @@
+pub mod find_many_director_query_params_dto;
@@ -0,0 +1,2 @@
+pub mod create_director_request_dto;
+pub mod update_director_request_dto;
@@ -0,0 +1 @@
+pub mod director_route_params_dto;
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::modules::{
+    app_state::AppState, database::repositories::directors_repository::CreateDirectorRequest,
+    search,
+};
+
+use crate::modules::director::dto::{
+    io::director_output_dto::DirectorOutputDTO,
+    request::create_director_request_dto::CreateDirectorRequestDTO,
+};
+
+pub enum CreateDirectorError {
+    Validation(String),
+    Database(String),
+}
+
+pub async fn execute(
+    payload: CreateDirectorRequestDTO,
+    state: Arc<AppState>,
+) -> Result<DirectorOutputDTO, CreateDirectorError> {
+    if payload
+        .birth_date
+        .is_some_and(|date| date > Utc::now().date_naive())
+    {
+        return Err(CreateDirectorError::Validation(
+            "A data de nascimento não pode estar no futuro".to_string(),
+        ));
+    }
+
+    let director = state
+        .director_repo
+        .create(CreateDirectorRequest {
+            name: payload.name.trim().to_string(),
+            biography: payload.biography,
+            birth_date: payload
+                .birth_date
+                .map(|date| date.and_time(Default::default())),
+            profile_picture_url: payload.profile_picture_url,
+        })
+        .await
+        .map_err(|e| CreateDirectorError::Database(format!("Erro ao criar: {}", e)))?;
+
+    search::invalidate_suggestions(&state).await;
+
+    Ok(director.into())
+}
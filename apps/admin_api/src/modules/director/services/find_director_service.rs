@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::images_repository::catalog_image_urls;
+
+use crate::modules::director::dto::{
+    io::director_output_dto::DirectorOutputDTO,
+    route_params::director_route_params_dto::DirectorRouteParamsDTO,
+};
+
+pub enum FindDirectorError {
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    params: DirectorRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<DirectorOutputDTO, FindDirectorError> {
+    let director = state
+        .director_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| FindDirectorError::Database(format!("Erro ao buscar diretor: {}", e)))?
+        .ok_or_else(|| {
+            FindDirectorError::NotFound(format!("Diretor com id {} não encontrado", params.id))
+        })?;
+
+    let images = state
+        .image_repo
+        .find_by_owner(ImageOwnerEnum::DIRECTOR, director.id)
+        .await
+        .map(catalog_image_urls)
+        .map_err(|e| FindDirectorError::Database(format!("Erro ao buscar imagens: {}", e)))?;
+
+    let mut director: DirectorOutputDTO = director.into();
+    if let Some(urls) = images.into_values().next() {
+        director.images = urls;
+    }
+
+    Ok(director)
+}
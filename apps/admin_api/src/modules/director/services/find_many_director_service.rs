@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::images_repository::catalog_image_urls;
+
+use crate::modules::director::dto::io::{
+    director_output_dto::DirectorOutputDTO, find_many_director_input_dto::FindManyDirectorInputDTO,
+};
+
+pub enum FindManyDirectorError {
+    Database(String),
+}
+
+pub async fn execute(
+    FindManyDirectorInputDTO { name, skip, take }: FindManyDirectorInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<DirectorOutputDTO>, FindManyDirectorError> {
+    let mut directors = state
+        .director_repo
+        .find_many(
+            name.as_deref(),
+            skip.map(|s| s as u64),
+            Some(take.unwrap_or(20) as u64),
+        )
+        .await
+        .map_err(|e| FindManyDirectorError::Database(format!("Erro ao buscar diretores: {}", e)))?
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<DirectorOutputDTO>>();
+
+    let director_ids: Vec<i32> = directors.iter().map(|director| director.id).collect();
+
+    let mut images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::DIRECTOR, &director_ids)
+        .await
+        .map(catalog_image_urls)
+        .map_err(|e| FindManyDirectorError::Database(format!("Erro ao buscar imagens: {}", e)))?;
+
+    for director in &mut directors {
+        if let Some(urls) = images.remove(&director.id) {
+            director.images = urls;
+        }
+    }
+
+    Ok(directors)
+}
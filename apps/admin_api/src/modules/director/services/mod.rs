@@ -0,0 +1,5 @@
+pub mod create_director_service;
+pub mod delete_director_service;
+pub mod find_director_service;
+pub mod find_many_director_service;
+pub mod update_director_service;
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::modules::{
+    app_state::AppState,
+    database::repositories::{
+        directors_repository::UpdateDirectorRequest, search_repository::IndexScope,
+    },
+    search,
+};
+
+use crate::modules::director::dto::{
+    io::director_output_dto::DirectorOutputDTO,
+    request::update_director_request_dto::UpdateDirectorRequestDTO,
+    route_params::director_route_params_dto::DirectorRouteParamsDTO,
+};
+
+pub enum UpdateDirectorError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    params: DirectorRouteParamsDTO,
+    payload: UpdateDirectorRequestDTO,
+    state: Arc<AppState>,
+) -> Result<DirectorOutputDTO, UpdateDirectorError> {
+    if payload
+        .birth_date
+        .is_some_and(|date| date > Utc::now().date_naive())
+    {
+        return Err(UpdateDirectorError::Validation(
+            "A data de nascimento não pode estar no futuro".to_string(),
+        ));
+    }
+
+    let director = state
+        .director_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| UpdateDirectorError::Database(format!("Erro ao buscar diretor: {}", e)))?;
+
+    if director.is_none() {
+        return Err(UpdateDirectorError::NotFound(format!(
+            "Diretor com id {} não encontrado",
+            params.id
+        )));
+    }
+
+    let updated = state
+        .director_repo
+        .update(
+            params.id,
+            UpdateDirectorRequest {
+                name: payload.name.map(|name| name.trim().to_string()),
+                biography: payload.biography,
+                birth_date: payload
+                    .birth_date
+                    .map(|date| date.and_time(Default::default())),
+                profile_picture_url: payload.profile_picture_url,
+            },
+        )
+        .await
+        .map_err(|e| UpdateDirectorError::Database(format!("Erro ao atualizar: {}", e)))?;
+
+    search::invalidate_suggestions(&state).await;
+    search::index::sync_scope(&state, IndexScope::Director(params.id)).await;
+
+    Ok(updated.into())
+}
@@ -0,0 +1,63 @@
+use crate::modules::home_row::{
+    dto::{
+        io::home_row_output_dto::HomeRowOutputDTO,
+        request::create_home_row_request_dto::CreateHomeRowRequestDTO,
+    },
+    services::create_home_row_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    payload: Json<CreateHomeRowRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<HomeRowOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match create_home_row_service::execute(payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::CREATED,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Linha criada com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                create_home_row_service::CreateHomeRowError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                create_home_row_service::CreateHomeRowError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                create_home_row_service::CreateHomeRowError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao criar a linha da home".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::modules::home_row::{
+    dto::{
+        io::home_row_output_dto::HomeRowOutputDTO,
+        route_params::home_row_route_params_dto::HomeRowRouteParamsDTO,
+    },
+    services::find_home_row_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<HomeRowRouteParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<HomeRowOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_home_row_service::execute(params, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_home_row_service::FindHomeRowError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_home_row_service::FindHomeRowError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar a linha da home".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::modules::home_row::{
+    dto::io::home_row_output_dto::HomeRowOutputDTO, services::find_many_home_row_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<Vec<HomeRowOutputDTO>>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_many_home_row_service::execute(state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_many_home_row_service::FindManyHomeRowError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar as linhas da home".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create_home_row_controller;
+pub mod delete_home_row_controller;
+pub mod find_home_row_controller;
+pub mod find_many_home_row_controller;
+pub mod update_home_row_controller;
@@ -0,0 +1,65 @@
+use crate::modules::home_row::{
+    dto::{
+        io::home_row_output_dto::HomeRowOutputDTO,
+        request::update_home_row_request_dto::UpdateHomeRowRequestDTO,
+        route_params::home_row_route_params_dto::HomeRowRouteParamsDTO,
+    },
+    services::update_home_row_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<HomeRowRouteParamsDTO>,
+    payload: Json<UpdateHomeRowRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<HomeRowOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match update_home_row_service::execute(params, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Linha atualizada com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                update_home_row_service::UpdateHomeRowError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                update_home_row_service::UpdateHomeRowError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                update_home_row_service::UpdateHomeRowError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar a linha da home".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::schema::home_rows::Model as HomeRowModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HomeRowOutputDTO {
+    pub id: i32,
+    pub kind: String,
+    pub title: String,
+    pub position: i32,
+    pub category_id: Option<i32>,
+    pub trending_window: Option<String>,
+    pub item_limit: i32,
+    pub cache_ttl_secs: i32,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl From<HomeRowModel> for HomeRowOutputDTO {
+    fn from(row: HomeRowModel) -> Self {
+        Self {
+            id: row.id,
+            kind: row.kind,
+            title: row.title,
+            position: row.position,
+            category_id: row.category_id,
+            trending_window: row.trending_window,
+            item_limit: row.item_limit,
+            cache_ttl_secs: row.cache_ttl_secs,
+            is_active: row.is_active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod home_row_output_dto;
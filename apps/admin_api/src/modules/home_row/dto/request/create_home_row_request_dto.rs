@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct CreateHomeRowRequestDTO {
+    /// `featured_series`, `continue_watching`, `my_list`, `trending`,
+    /// `new_releases`, `category` ou `recommendations`.
+    pub kind: String,
+
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "O título deve ter entre 2 e 100 caracteres"
+    ))]
+    pub title: String,
+
+    /// Sem posição, a linha vai para o fim.
+    #[validate(range(min = 1, message = "A posição deve ser positiva"))]
+    pub position: Option<i32>,
+
+    #[validate(range(min = 1, message = "O ID da categoria deve ser positivo"))]
+    pub category_id: Option<i32>,
+
+    /// `24h`, `7d` ou `30d`, só para `trending`.
+    pub trending_window: Option<String>,
+
+    #[validate(range(min = 1, max = 50, message = "O limite deve estar entre 1 e 50"))]
+    pub item_limit: Option<i32>,
+
+    #[validate(range(
+        min = 0,
+        max = 86400,
+        message = "O cache deve estar entre 0 e 86400 segundos"
+    ))]
+    pub cache_ttl_secs: Option<i32>,
+
+    pub is_active: Option<bool>,
+}
@@ -0,0 +1,2 @@
+pub mod create_home_row_request_dto;
+pub mod update_home_row_request_dto;
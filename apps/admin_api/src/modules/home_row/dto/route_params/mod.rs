@@ -0,0 +1 @@
+pub mod home_row_route_params_dto;
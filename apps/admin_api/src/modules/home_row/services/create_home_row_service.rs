@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::home_row::{
+    dto::{
+        io::home_row_output_dto::HomeRowOutputDTO,
+        request::create_home_row_request_dto::CreateHomeRowRequestDTO,
+    },
+    services::home_row_definition_service::{
+        self, HomeRowDefinitionError, HomeRowDraft, DEFAULT_CACHE_TTL_SECS, DEFAULT_ITEM_LIMIT,
+    },
+};
+
+pub enum CreateHomeRowError {
+    Validation(String),
+    NotFound(String),
+    Database(String),
+}
+
+impl From<HomeRowDefinitionError> for CreateHomeRowError {
+    fn from(err: HomeRowDefinitionError) -> Self {
+        match err {
+            HomeRowDefinitionError::Validation(msg) => CreateHomeRowError::Validation(msg),
+            HomeRowDefinitionError::NotFound(msg) => CreateHomeRowError::NotFound(msg),
+            HomeRowDefinitionError::Database(msg) => CreateHomeRowError::Database(msg),
+        }
+    }
+}
+
+pub async fn execute(
+    payload: CreateHomeRowRequestDTO,
+    state: Arc<AppState>,
+) -> Result<HomeRowOutputDTO, CreateHomeRowError> {
+    let map_err = |e| CreateHomeRowError::Database(format!("Erro ao criar: {}", e));
+
+    let position = match payload.position {
+        Some(position) => position,
+        None => state.home_row_repo.next_position().await.map_err(map_err)?,
+    };
+
+    let request = home_row_definition_service::build(
+        &state,
+        HomeRowDraft {
+            kind: payload.kind,
+            title: payload.title,
+            position,
+            category_id: payload.category_id,
+            trending_window: payload.trending_window,
+            item_limit: payload.item_limit.unwrap_or(DEFAULT_ITEM_LIMIT),
+            cache_ttl_secs: payload.cache_ttl_secs.unwrap_or(DEFAULT_CACHE_TTL_SECS),
+            is_active: payload.is_active.unwrap_or(true),
+        },
+    )
+    .await?;
+
+    let row = state.home_row_repo.create(request).await.map_err(map_err)?;
+
+    Ok(row.into())
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use shared::modules::{app_state::AppState, home};
+
+use crate::modules::home_row::dto::route_params::home_row_route_params_dto::HomeRowRouteParamsDTO;
+
+pub enum DeleteHomeRowError {
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    params: HomeRowRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteHomeRowError> {
+    let deleted = state
+        .home_row_repo
+        .delete(params.id)
+        .await
+        .map_err(|e| DeleteHomeRowError::Database(format!("Erro ao deletar a linha: {}", e)))?;
+
+    if !deleted {
+        return Err(DeleteHomeRowError::NotFound(format!(
+            "Linha com id {} não encontrada",
+            params.id
+        )));
+    }
+
+    home::invalidate_row(&state, params.id).await;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::home_row::dto::{
+    io::home_row_output_dto::HomeRowOutputDTO,
+    route_params::home_row_route_params_dto::HomeRowRouteParamsDTO,
+};
+
+pub enum FindHomeRowError {
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    params: HomeRowRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<HomeRowOutputDTO, FindHomeRowError> {
+    let row = state
+        .home_row_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| FindHomeRowError::Database(format!("Erro ao buscar a linha: {}", e)))?
+        .ok_or_else(|| {
+            FindHomeRowError::NotFound(format!("Linha com id {} não encontrada", params.id))
+        })?;
+
+    Ok(row.into())
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::home_row::dto::io::home_row_output_dto::HomeRowOutputDTO;
+
+pub enum FindManyHomeRowError {
+    Database(String),
+}
+
+/// Todas as linhas, inclusive as inativas, na ordem de exibição.
+pub async fn execute(state: Arc<AppState>) -> Result<Vec<HomeRowOutputDTO>, FindManyHomeRowError> {
+    let rows =
+        state.home_row_repo.find_all(false).await.map_err(|e| {
+            FindManyHomeRowError::Database(format!("Erro ao buscar as linhas: {}", e))
+        })?;
+
+    Ok(rows.into_iter().map(Into::into).collect())
+}
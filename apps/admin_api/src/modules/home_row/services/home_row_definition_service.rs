@@ -0,0 +1,94 @@
+use shared::{
+    enums::{home_row_kind_enum::HomeRowKindEnum, trending_window_enum::TrendingWindowEnum},
+    modules::{app_state::AppState, database::repositories::home_rows_repository::HomeRowRequest},
+};
+
+pub const DEFAULT_ITEM_LIMIT: i32 = 20;
+pub const DEFAULT_CACHE_TTL_SECS: i32 = 300;
+
+pub enum HomeRowDefinitionError {
+    Validation(String),
+    NotFound(String),
+    Database(String),
+}
+
+/// Campos da linha antes da validação, já com os valores atuais quando é
+/// uma edição.
+pub struct HomeRowDraft {
+    pub kind: String,
+    pub title: String,
+    pub position: i32,
+    pub category_id: Option<i32>,
+    pub trending_window: Option<String>,
+    pub item_limit: i32,
+    pub cache_ttl_secs: i32,
+    pub is_active: bool,
+}
+
+/// Valida a combinação de tipo, categoria e janela. `category` exige uma
+/// categoria; `trending` aceita uma para se limitar a ela; os demais tipos
+/// não usam nenhuma das duas.
+pub async fn build(
+    state: &AppState,
+    draft: HomeRowDraft,
+) -> Result<HomeRowRequest, HomeRowDefinitionError> {
+    let kind = HomeRowKindEnum::try_from(draft.kind.as_str())
+        .map_err(HomeRowDefinitionError::Validation)?;
+
+    match (kind, draft.category_id) {
+        (HomeRowKindEnum::CATEGORY, None) => {
+            return Err(HomeRowDefinitionError::Validation(
+                "Linhas do tipo category precisam de category_id".to_string(),
+            ));
+        }
+        (HomeRowKindEnum::CATEGORY | HomeRowKindEnum::TRENDING, _) | (_, None) => {}
+        (_, Some(_)) => {
+            return Err(HomeRowDefinitionError::Validation(format!(
+                "Linhas do tipo {} não usam category_id",
+                kind.as_str().to_lowercase()
+            )));
+        }
+    }
+
+    let trending_window = match (kind, draft.trending_window) {
+        (HomeRowKindEnum::TRENDING, Some(window)) => Some(
+            TrendingWindowEnum::try_from(window.as_str())
+                .map_err(HomeRowDefinitionError::Validation)?,
+        ),
+        (HomeRowKindEnum::TRENDING, None) => Some(TrendingWindowEnum::DAY),
+        (_, Some(_)) => {
+            return Err(HomeRowDefinitionError::Validation(
+                "Só linhas do tipo trending usam trending_window".to_string(),
+            ));
+        }
+        (_, None) => None,
+    };
+
+    if let Some(category_id) = draft.category_id {
+        let category = state
+            .category_repo
+            .find_by_id(category_id)
+            .await
+            .map_err(|e| {
+                HomeRowDefinitionError::Database(format!("Erro ao buscar categoria: {}", e))
+            })?;
+
+        if category.is_none() {
+            return Err(HomeRowDefinitionError::NotFound(format!(
+                "Categoria com id {} não encontrada",
+                category_id
+            )));
+        }
+    }
+
+    Ok(HomeRowRequest {
+        kind,
+        title: draft.title.trim().to_string(),
+        position: draft.position,
+        category_id: draft.category_id,
+        trending_window,
+        item_limit: draft.item_limit,
+        cache_ttl_secs: draft.cache_ttl_secs,
+        is_active: draft.is_active,
+    })
+}
@@ -0,0 +1,6 @@
+pub mod create_home_row_service;
+pub mod delete_home_row_service;
+pub mod find_home_row_service;
+pub mod find_many_home_row_service;
+pub mod home_row_definition_service;
+pub mod update_home_row_service;
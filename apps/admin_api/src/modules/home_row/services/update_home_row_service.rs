@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use shared::modules::{app_state::AppState, home};
+
+use crate::modules::home_row::{
+    dto::{
+        io::home_row_output_dto::HomeRowOutputDTO,
+        request::update_home_row_request_dto::UpdateHomeRowRequestDTO,
+        route_params::home_row_route_params_dto::HomeRowRouteParamsDTO,
+    },
+    services::home_row_definition_service::{self, HomeRowDefinitionError, HomeRowDraft},
+};
+
+pub enum UpdateHomeRowError {
+    Validation(String),
+    NotFound(String),
+    Database(String),
+}
+
+impl From<HomeRowDefinitionError> for UpdateHomeRowError {
+    fn from(err: HomeRowDefinitionError) -> Self {
+        match err {
+            HomeRowDefinitionError::Validation(msg) => UpdateHomeRowError::Validation(msg),
+            HomeRowDefinitionError::NotFound(msg) => UpdateHomeRowError::NotFound(msg),
+            HomeRowDefinitionError::Database(msg) => UpdateHomeRowError::Database(msg),
+        }
+    }
+}
+
+/// Altera a linha e descarta o que dela estiver no cache. Quando o tipo muda,
+/// categoria e janela atuais não são aproveitadas.
+pub async fn execute(
+    params: HomeRowRouteParamsDTO,
+    payload: UpdateHomeRowRequestDTO,
+    state: Arc<AppState>,
+) -> Result<HomeRowOutputDTO, UpdateHomeRowError> {
+    let row = state
+        .home_row_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| UpdateHomeRowError::Database(format!("Erro ao buscar a linha: {}", e)))?
+        .ok_or_else(|| {
+            UpdateHomeRowError::NotFound(format!("Linha com id {} não encontrada", params.id))
+        })?;
+
+    let kind_changed = payload
+        .kind
+        .as_ref()
+        .is_some_and(|kind| !kind.eq_ignore_ascii_case(&row.kind));
+    let (category_id, trending_window) = if kind_changed {
+        (payload.category_id, payload.trending_window)
+    } else {
+        (
+            payload.category_id.or(row.category_id),
+            payload.trending_window.or(row.trending_window),
+        )
+    };
+
+    let request = home_row_definition_service::build(
+        &state,
+        HomeRowDraft {
+            kind: payload.kind.unwrap_or(row.kind),
+            title: payload.title.unwrap_or(row.title),
+            position: payload.position.unwrap_or(row.position),
+            category_id,
+            trending_window,
+            item_limit: payload.item_limit.unwrap_or(row.item_limit),
+            cache_ttl_secs: payload.cache_ttl_secs.unwrap_or(row.cache_ttl_secs),
+            is_active: payload.is_active.unwrap_or(row.is_active),
+        },
+    )
+    .await?;
+
+    let updated = state
+        .home_row_repo
+        .update(params.id, request)
+        .await
+        .map_err(|e| UpdateHomeRowError::Database(format!("Erro ao atualizar: {}", e)))?;
+
+    home::invalidate_row(&state, params.id).await;
+
+    Ok(updated.into())
+}
@@ -0,0 +1,65 @@
+use crate::modules::image::{
+    dto::{
+        io::image_target_input_dto::ImageTargetInputDTO,
+        route_params::image_route_params_dto::ImageRouteParamsDTO,
+    },
+    services::delete_image_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<ImageRouteParamsDTO>,
+) -> Result<(StatusCode, Json<ResponseInterface<()>>), (StatusCode, Json<ValidationErrorResponse>)>
+{
+    let target = ImageTargetInputDTO::try_from(params).map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ValidationErrorResponse {
+                message: "Erro ao remover imagem".to_string(),
+                errors: json!([msg]),
+            }),
+        )
+    })?;
+
+    match delete_image_service::execute(target, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                message: Some("Imagem removida com sucesso".to_string()),
+                result: Some(result),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                delete_image_service::DeleteImageError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                delete_image_service::DeleteImageError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao remover imagem".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::modules::image::{
+    dto::{
+        io::image_output_dto::ImageOutputDTO,
+        route_params::owner_images_route_params_dto::OwnerImagesRouteParamsDTO,
+    },
+    services::find_images_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<OwnerImagesRouteParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<Vec<ImageOutputDTO>>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_images_service::execute(params, state).await {
+        Ok(images) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                message: Some("Imagens encontradas".to_string()),
+                result: Some(images),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_images_service::FindImagesError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                find_images_service::FindImagesError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_images_service::FindImagesError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar imagens".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod delete_image_controller;
+pub mod find_images_controller;
+pub mod upload_image_controller;
@@ -0,0 +1,65 @@
+use crate::app_state::AppState;
+use crate::modules::image::dto::io::image_output_dto::ImageOutputDTO;
+use crate::modules::image::dto::io::image_target_input_dto::ImageTargetInputDTO;
+use crate::modules::image::dto::route_params::image_route_params_dto::ImageRouteParamsDTO;
+use crate::modules::image::services::upload_image_service;
+use axum::extract::Path;
+use axum::{extract::Multipart, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::enums::access_group_enum::AccessGroupEnum;
+use shared::modules::auth::jwt_extractor::AuthenticatedUser;
+use shared::modules::response_interface::ResponseInterface;
+use shared::modules::validation::validation_layer::ValidationErrorResponse;
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<ImageRouteParamsDTO>,
+    multipart: Multipart,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<ImageOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let result = match ImageTargetInputDTO::try_from(params) {
+        Ok(target) => upload_image_service::execute(multipart, target, state).await,
+        Err(msg) => Err(upload_image_service::UploadImageError::Validation(msg)),
+    };
+
+    match result {
+        Ok(image) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(image),
+                message: Some("Imagem enviada com sucesso".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                upload_image_service::UploadImageError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                upload_image_service::UploadImageError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                upload_image_service::UploadImageError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                upload_image_service::UploadImageError::Storage(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao processar imagem".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
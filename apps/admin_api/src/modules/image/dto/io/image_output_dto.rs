@@ -0,0 +1,57 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::repositories::images_repository::image_variants;
+use shared::modules::database::schema::images::Model as ImageModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageVariantOutputDTO {
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    pub format: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageOutputDTO {
+    pub id: i32,
+    pub owner: String,
+    pub owner_id: i32,
+    pub kind: String,
+    pub format: String,
+    pub width: i32,
+    pub height: i32,
+    pub size_bytes: i64,
+    pub original_url: String,
+    pub variants: Vec<ImageVariantOutputDTO>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<ImageModel> for ImageOutputDTO {
+    fn from(image: ImageModel) -> Self {
+        let variants = image_variants(&image)
+            .into_iter()
+            .map(|variant| ImageVariantOutputDTO {
+                name: variant.name,
+                width: variant.width,
+                height: variant.height,
+                format: variant.format.as_str().to_string(),
+                url: variant.url,
+            })
+            .collect();
+
+        Self {
+            id: image.id,
+            owner: image.owner_type,
+            owner_id: image.owner_id,
+            kind: image.kind,
+            format: image.format,
+            width: image.width,
+            height: image.height,
+            size_bytes: image.size_bytes,
+            original_url: image.original_url,
+            variants,
+            created_at: image.created_at,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::modules::image::dto::route_params::image_route_params_dto::ImageRouteParamsDTO;
+use shared::enums::{image_kind_enum::ImageKindEnum, image_owner_enum::ImageOwnerEnum};
+
+/// Dono e tipo da imagem, já validados a partir da rota.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageTargetInputDTO {
+    pub owner: ImageOwnerEnum,
+    pub owner_id: i32,
+    pub kind: ImageKindEnum,
+}
+
+impl TryFrom<ImageRouteParamsDTO> for ImageTargetInputDTO {
+    type Error = String;
+
+    fn try_from(params: ImageRouteParamsDTO) -> Result<Self, Self::Error> {
+        let owner = ImageOwnerEnum::try_from(params.owner.as_str())?;
+        let kind = ImageKindEnum::try_from(params.kind.as_str())?;
+
+        if !owner.accepts(kind) {
+            return Err(format!(
+                "{} não aceita imagens do tipo {}",
+                owner.as_str(),
+                kind.as_str()
+            ));
+        }
+
+        Ok(Self {
+            owner,
+            owner_id: params.id,
+            kind,
+        })
+    }
+}
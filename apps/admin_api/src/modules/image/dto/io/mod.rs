@@ -0,0 +1,2 @@
+pub mod image_output_dto;
+pub mod image_target_input_dto;
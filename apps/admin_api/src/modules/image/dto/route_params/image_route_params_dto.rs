@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct ImageRouteParamsDTO {
+    pub owner: String,
+
+    #[validate(range(min = 1, message = "O ID deve ser positivo"))]
+    pub id: i32,
+
+    pub kind: String,
+}
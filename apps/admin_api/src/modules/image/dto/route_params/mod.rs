@@ -0,0 +1,2 @@
+pub mod image_route_params_dto;
+pub mod owner_images_route_params_dto;
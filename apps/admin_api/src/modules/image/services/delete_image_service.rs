@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::images_repository::image_storage_keys;
+
+use crate::modules::image::dto::io::image_target_input_dto::ImageTargetInputDTO;
+
+pub enum DeleteImageError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Remove a imagem e todas as variantes do storage.
+pub async fn execute(
+    target: ImageTargetInputDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteImageError> {
+    let removed = state
+        .image_repo
+        .delete(target.owner, target.owner_id, target.kind)
+        .await
+        .map_err(|e| DeleteImageError::Database(format!("Erro ao deletar imagem: {}", e)))?
+        .ok_or_else(|| {
+            DeleteImageError::NotFound(format!(
+                "{} de {} {} não encontrada",
+                target.kind.as_str(),
+                target.owner.as_str(),
+                target.owner_id
+            ))
+        })?;
+
+    for blob_name in image_storage_keys(&removed) {
+        if let Err(e) = state.image_storage_service.delete_image(&blob_name).await {
+            tracing::warn!("Falha ao deletar imagem '{}': {:?}", blob_name, e);
+        }
+    }
+
+    Ok(())
+}
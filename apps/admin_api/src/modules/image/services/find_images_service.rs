@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::app_state::AppState;
+
+use crate::modules::image::dto::{
+    io::image_output_dto::ImageOutputDTO,
+    route_params::owner_images_route_params_dto::OwnerImagesRouteParamsDTO,
+};
+
+pub enum FindImagesError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    params: OwnerImagesRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<ImageOutputDTO>, FindImagesError> {
+    let owner =
+        ImageOwnerEnum::try_from(params.owner.as_str()).map_err(FindImagesError::Validation)?;
+
+    let exists = state
+        .image_repo
+        .owner_exists(owner, params.id)
+        .await
+        .map_err(|e| FindImagesError::Database(format!("Erro ao buscar dono: {}", e)))?;
+
+    if !exists {
+        return Err(FindImagesError::NotFound(format!(
+            "{} com id {} não encontrado",
+            owner.as_str(),
+            params.id
+        )));
+    }
+
+    let images = state
+        .image_repo
+        .find_by_owner(owner, params.id)
+        .await
+        .map_err(|e| FindImagesError::Database(format!("Erro ao buscar imagens: {}", e)))?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(images)
+}
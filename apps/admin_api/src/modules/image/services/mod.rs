@@ -0,0 +1,3 @@
+pub mod delete_image_service;
+pub mod find_images_service;
+pub mod upload_image_service;
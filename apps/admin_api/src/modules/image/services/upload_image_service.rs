@@ -0,0 +1,161 @@
+use crate::app_state::AppState;
+use crate::modules::image::dto::io::{
+    image_output_dto::ImageOutputDTO, image_target_input_dto::ImageTargetInputDTO,
+};
+use axum::extract::Multipart;
+use shared::modules::azure_storage::services::image_storage_service::ImageStorageService;
+use shared::modules::database::repositories::images_repository::{
+    image_storage_keys, CreateImageRequest, ImageVariant,
+};
+use shared::modules::image_processing::{self, MAX_IMAGE_SIZE};
+use std::sync::Arc;
+
+pub enum UploadImageError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+    Storage(String),
+}
+
+/// Recebe a imagem (campo `file`), valida o formato pelos bytes iniciais,
+/// gera as variantes e grava tudo no container de imagens, substituindo a
+/// imagem anterior do mesmo tipo.
+pub async fn execute(
+    mut multipart: Multipart,
+    target: ImageTargetInputDTO,
+    state: Arc<AppState>,
+) -> Result<ImageOutputDTO, UploadImageError> {
+    let exists = state
+        .image_repo
+        .owner_exists(target.owner, target.owner_id)
+        .await
+        .map_err(|e| UploadImageError::Database(format!("Erro ao buscar dono: {}", e)))?;
+
+    if !exists {
+        return Err(UploadImageError::NotFound(format!(
+            "{} com id {} não encontrado",
+            target.owner.as_str(),
+            target.owner_id
+        )));
+    }
+
+    let mut file: Option<Vec<u8>> = None;
+
+    while let Some(mut field) = multipart.next_field().await.map_err(|e| {
+        UploadImageError::Validation(format!("Erro ao processar campo multipart: {}", e))
+    })? {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let mut data = Vec::new();
+
+        while let Some(chunk) = field
+            .chunk()
+            .await
+            .map_err(|e| UploadImageError::Validation(format!("Erro ao ler o arquivo: {}", e)))?
+        {
+            if data.len() + chunk.len() > MAX_IMAGE_SIZE {
+                return Err(UploadImageError::Validation(format!(
+                    "O arquivo de imagem excede {} bytes",
+                    MAX_IMAGE_SIZE
+                )));
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        file = Some(data);
+    }
+
+    let data = file.ok_or_else(|| {
+        UploadImageError::Validation("Envie a imagem no campo 'file'".to_string())
+    })?;
+
+    let original = data.clone();
+    let processed = tokio::task::spawn_blocking(move || image_processing::process(&data))
+        .await
+        .map_err(|e| UploadImageError::Storage(format!("Erro ao processar imagem: {}", e)))?
+        .map_err(|e| UploadImageError::Validation(e.to_string()))?;
+
+    let storage = state.image_storage_service.clone();
+    let prefix =
+        storage.generate_image_prefix(target.owner.as_str(), target.owner_id, target.kind.as_str());
+
+    let mut uploaded: Vec<String> = Vec::new();
+
+    let saved_original = storage
+        .save_image(original, &prefix, "original", processed.format)
+        .await
+        .map_err(|e| UploadImageError::Storage(format!("Erro ao salvar imagem: {}", e)))?;
+    uploaded.push(saved_original.blob_name.clone());
+
+    let mut variants = Vec::with_capacity(processed.variants.len());
+
+    for variant in processed.variants {
+        match storage
+            .save_image(variant.data, &prefix, variant.name, variant.format)
+            .await
+        {
+            Ok(saved) => {
+                uploaded.push(saved.blob_name.clone());
+                variants.push(ImageVariant {
+                    name: variant.name.to_string(),
+                    width: variant.width as i32,
+                    height: variant.height as i32,
+                    format: variant.format,
+                    storage_key: saved.blob_name,
+                    url: saved.url,
+                });
+            }
+            Err(e) => {
+                discard_blobs(&storage, &uploaded).await;
+                return Err(UploadImageError::Storage(format!(
+                    "Erro ao salvar variante '{}': {}",
+                    variant.name, e
+                )));
+            }
+        }
+    }
+
+    let replaced = match state
+        .image_repo
+        .replace(CreateImageRequest {
+            owner: target.owner,
+            owner_id: target.owner_id,
+            kind: target.kind,
+            container: storage.container_name().to_string(),
+            format: processed.format,
+            width: processed.width as i32,
+            height: processed.height as i32,
+            size_bytes: saved_original.size as i64,
+            sha256: saved_original.sha256,
+            original_key: saved_original.blob_name,
+            original_url: saved_original.url,
+            variants,
+        })
+        .await
+    {
+        Ok(replaced) => replaced,
+        Err(e) => {
+            discard_blobs(&storage, &uploaded).await;
+            return Err(UploadImageError::Database(format!(
+                "Erro ao registrar imagem: {}",
+                e
+            )));
+        }
+    };
+
+    if let Some(previous) = &replaced.previous {
+        discard_blobs(&storage, &image_storage_keys(previous)).await;
+    }
+
+    Ok(replaced.image.into())
+}
+
+async fn discard_blobs(storage: &ImageStorageService, blob_names: &[String]) {
+    for blob_name in blob_names {
+        if let Err(e) = storage.delete_image(blob_name).await {
+            tracing::warn!("Falha ao descartar imagem '{}': {:?}", blob_name, e);
+        }
+    }
+}
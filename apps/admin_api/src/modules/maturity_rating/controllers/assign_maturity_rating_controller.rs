@@ -0,0 +1,76 @@
+use crate::modules::maturity_rating::{
+    dto::{
+        io::{
+            assign_maturity_rating_input_dto::AssignMaturityRatingInputDTO,
+            assign_maturity_rating_output_dto::AssignMaturityRatingOutputDTO,
+        },
+        request::assign_maturity_rating_request_dto::AssignMaturityRatingRequestDTO,
+    },
+    services::assign_maturity_rating_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    payload: Json<AssignMaturityRatingRequestDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<AssignMaturityRatingOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    let input = AssignMaturityRatingInputDTO::try_from(payload).map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ValidationErrorResponse {
+                message: "Erro ao atualizar a classificação".to_string(),
+                errors: json!([msg]),
+            }),
+        )
+    })?;
+
+    match assign_maturity_rating_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Classificação atualizada com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                assign_maturity_rating_service::AssignMaturityRatingError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                assign_maturity_rating_service::AssignMaturityRatingError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar a classificação".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
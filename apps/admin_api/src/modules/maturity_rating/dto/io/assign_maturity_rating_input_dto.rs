@@ -0,0 +1,52 @@
+use crate::modules::maturity_rating::dto::request::assign_maturity_rating_request_dto::AssignMaturityRatingRequestDTO;
+use shared::{
+    enums::{
+        content_descriptor_enum::ContentDescriptorEnum, maturity_rating_enum::MaturityRatingEnum,
+    },
+    modules::database::repositories::maturity_ratings_repository::AssignMaturityRating,
+};
+
+#[derive(Debug)]
+pub struct AssignMaturityRatingInputDTO {
+    pub assignment: AssignMaturityRating,
+}
+
+impl TryFrom<AssignMaturityRatingRequestDTO> for AssignMaturityRatingInputDTO {
+    type Error = String;
+
+    fn try_from(body: AssignMaturityRatingRequestDTO) -> Result<Self, Self::Error> {
+        if body.video_ids.is_empty() && body.serie_ids.is_empty() {
+            return Err("Informe ao menos um vídeo ou uma série".to_string());
+        }
+
+        let content_descriptors = body
+            .content_descriptors
+            .map(|values| {
+                let mut descriptors = Vec::with_capacity(values.len());
+                for value in values {
+                    let descriptor = ContentDescriptorEnum::try_from(value.as_str())?;
+                    if !descriptors.contains(&descriptor) {
+                        descriptors.push(descriptor);
+                    }
+                }
+                Ok::<_, String>(descriptors)
+            })
+            .transpose()?;
+
+        let mut video_ids = body.video_ids;
+        video_ids.sort_unstable();
+        video_ids.dedup();
+        let mut serie_ids = body.serie_ids;
+        serie_ids.sort_unstable();
+        serie_ids.dedup();
+
+        Ok(Self {
+            assignment: AssignMaturityRating {
+                video_ids,
+                serie_ids,
+                maturity_rating: MaturityRatingEnum::try_from(body.maturity_rating.as_str())?,
+                content_descriptors,
+            },
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use serde::Serialize;
+use shared::enums::content_descriptor_enum::ContentDescriptorEnum;
+
+#[derive(Debug, Serialize)]
+pub struct AssignMaturityRatingOutputDTO {
+    /// `L`, `10`, `12`, `14`, `16` ou `18`.
+    pub maturity_rating: String,
+    /// `None` quando os descritores de cada item foram mantidos.
+    pub content_descriptors: Option<Vec<ContentDescriptorEnum>>,
+    pub video_ids: Vec<i32>,
+    pub serie_ids: Vec<i32>,
+    /// Ids que não existem ou estão na lixeira; nada foi gravado para eles.
+    pub missing_video_ids: Vec<i32>,
+    pub missing_serie_ids: Vec<i32>,
+}
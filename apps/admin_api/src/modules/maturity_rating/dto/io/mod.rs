@@ -0,0 +1,2 @@
+pub mod assign_maturity_rating_input_dto;
+pub mod assign_maturity_rating_output_dto;
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// Aplica a mesma classificação a vários vídeos e séries. Sem
+/// `content_descriptors`, os descritores de cada item são mantidos; uma lista
+/// vazia os remove.
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct AssignMaturityRatingRequestDTO {
+    #[serde(default)]
+    #[validate(length(max = 500, message = "Informe no máximo 500 vídeos"))]
+    pub video_ids: Vec<i32>,
+
+    #[serde(default)]
+    #[validate(length(max = 500, message = "Informe no máximo 500 séries"))]
+    pub serie_ids: Vec<i32>,
+
+    /// `L`, `10`, `12`, `14`, `16` ou `18`.
+    pub maturity_rating: String,
+
+    pub content_descriptors: Option<Vec<String>>,
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::maturity_rating::dto::io::{
+    assign_maturity_rating_input_dto::AssignMaturityRatingInputDTO,
+    assign_maturity_rating_output_dto::AssignMaturityRatingOutputDTO,
+};
+
+pub enum AssignMaturityRatingError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Grava a classificação em todos os itens encontrados. O cache do catálogo e
+/// o índice de busca são atualizados pelos assinantes do `RatingChanged`. Ids
+/// inexistentes voltam na resposta; se nenhum existir, nada é gravado.
+pub async fn execute(
+    input: AssignMaturityRatingInputDTO,
+    state: Arc<AppState>,
+) -> Result<AssignMaturityRatingOutputDTO, AssignMaturityRatingError> {
+    let request = input.assignment;
+    let requested_videos = request.video_ids.clone();
+    let requested_series = request.serie_ids.clone();
+    let maturity_rating = request.maturity_rating.as_str().to_string();
+    let content_descriptors = request.content_descriptors.clone();
+
+    let assigned = state
+        .maturity_rating_repo
+        .assign(request)
+        .await
+        .map_err(|e| {
+            AssignMaturityRatingError::Database(format!("Erro ao gravar a classificação: {}", e))
+        })?;
+
+    if assigned.video_ids.is_empty() && assigned.serie_ids.is_empty() {
+        return Err(AssignMaturityRatingError::NotFound(
+            "Nenhum dos vídeos ou séries informados foi encontrado".to_string(),
+        ));
+    }
+
+    let missing = |requested: Vec<i32>, found: &[i32]| -> Vec<i32> {
+        requested
+            .into_iter()
+            .filter(|id| !found.contains(id))
+            .collect()
+    };
+
+    Ok(AssignMaturityRatingOutputDTO {
+        maturity_rating,
+        content_descriptors,
+        missing_video_ids: missing(requested_videos, &assigned.video_ids),
+        missing_serie_ids: missing(requested_series, &assigned.serie_ids),
+        video_ids: assigned.video_ids,
+        serie_ids: assigned.serie_ids,
+    })
+}
@@ -0,0 +1 @@
+pub mod assign_maturity_rating_service;
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+
+use crate::modules::media_asset::{
+    dto::{
+        io::find_many_media_asset_output_dto::FindManyMediaAssetOutputDTO,
+        query_params::find_many_media_asset_query_params_dto::FindManyMediaAssetQueryParamsDTO,
+    },
+    services::find_many_media_asset_service,
+};
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<FindManyMediaAssetQueryParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<Vec<FindManyMediaAssetOutputDTO>>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_many_media_asset_service::execute(query.into(), state).await {
+        Ok(assets) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(assets),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_many_media_asset_service::FindManyMediaAssetError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                find_many_media_asset_service::FindManyMediaAssetError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar os media assets".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
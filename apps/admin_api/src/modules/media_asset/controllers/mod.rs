@@ -0,0 +1,2 @@
+pub mod find_many_media_asset_controller;
+pub mod verify_media_asset_controller;
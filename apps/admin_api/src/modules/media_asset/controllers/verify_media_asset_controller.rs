@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+
+use crate::modules::media_asset::{
+    dto::{
+        io::verify_media_asset_output_dto::VerifyMediaAssetOutputDTO,
+        request::verify_media_asset_request_dto::VerifyMediaAssetRequestDTO,
+    },
+    services::verify_media_asset_service,
+};
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    payload: Json<VerifyMediaAssetRequestDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<VerifyMediaAssetOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match verify_media_asset_service::execute(payload.into(), state).await {
+        Ok(report) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(report),
+                message: Some("Verificação concluída".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                verify_media_asset_service::VerifyMediaAssetError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao verificar os media assets".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::modules::media_asset::dto::query_params::find_many_media_asset_query_params_dto::FindManyMediaAssetQueryParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindManyMediaAssetInputDTO {
+    pub status: Option<String>,
+    pub skip: Option<i32>,
+    pub take: Option<i32>,
+}
+
+impl From<FindManyMediaAssetQueryParamsDTO> for FindManyMediaAssetInputDTO {
+    fn from(query: FindManyMediaAssetQueryParamsDTO) -> Self {
+        Self {
+            status: query.status,
+            skip: query.skip,
+            take: query.take,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::schema::media_assets::Model as MediaAssetModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FindManyMediaAssetOutputDTO {
+    pub id: i32,
+    pub container: String,
+    pub storage_key: String,
+    pub url: String,
+    pub original_filename: Option<String>,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub status: String,
+    pub last_verified_at: Option<NaiveDateTime>,
+    pub container_format: Option<String>,
+    pub duration_ms: Option<i64>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    pub faststart: Option<bool>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<MediaAssetModel> for FindManyMediaAssetOutputDTO {
+    fn from(asset: MediaAssetModel) -> Self {
+        Self {
+            id: asset.id,
+            container: asset.container,
+            storage_key: asset.storage_key,
+            url: asset.url,
+            original_filename: asset.original_filename,
+            content_type: asset.content_type,
+            size_bytes: asset.size_bytes,
+            sha256: asset.sha256,
+            status: asset.status,
+            last_verified_at: asset.last_verified_at,
+            container_format: asset.container_format,
+            duration_ms: asset.duration_ms,
+            width: asset.width,
+            height: asset.height,
+            video_codec: asset.video_codec,
+            audio_codec: asset.audio_codec,
+            faststart: asset.faststart,
+            created_at: asset.created_at,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod find_many_media_asset_input_dto;
+pub mod find_many_media_asset_output_dto;
+pub mod verify_media_asset_input_dto;
+pub mod verify_media_asset_output_dto;
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use crate::modules::media_asset::dto::request::verify_media_asset_request_dto::VerifyMediaAssetRequestDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct VerifyMediaAssetInputDTO {
+    pub limit: u64,
+    pub asset_ids: Option<Vec<i32>>,
+}
+
+impl From<VerifyMediaAssetRequestDTO> for VerifyMediaAssetInputDTO {
+    fn from(body: VerifyMediaAssetRequestDTO) -> Self {
+        Self {
+            limit: body.limit.unwrap_or(50),
+            asset_ids: body.asset_ids,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct VerifyMediaAssetOutputDTO {
+    pub checked: usize,
+    pub available: usize,
+    pub corrupted: Vec<i32>,
+    pub missing: Vec<i32>,
+    pub skipped: Vec<i32>,
+}
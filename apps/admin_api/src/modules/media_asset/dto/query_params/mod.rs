@@ -0,0 +1 @@
+pub mod find_many_media_asset_query_params_dto;
@@ -0,0 +1 @@
+pub mod verify_media_asset_request_dto;
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct VerifyMediaAssetRequestDTO {
+    #[validate(range(min = 1, max = 500, message = "O limite deve estar entre 1 e 500"))]
+    pub limit: Option<u64>,
+
+    #[validate(length(min = 1, max = 500, message = "Informe entre 1 e 500 ids"))]
+    pub asset_ids: Option<Vec<i32>>,
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use shared::{enums::media_asset_status_enum::MediaAssetStatusEnum, modules::app_state::AppState};
+
+use crate::modules::media_asset::dto::io::{
+    find_many_media_asset_input_dto::FindManyMediaAssetInputDTO,
+    find_many_media_asset_output_dto::FindManyMediaAssetOutputDTO,
+};
+
+pub enum FindManyMediaAssetError {
+    Validation(String),
+    Database(String),
+}
+
+pub async fn execute(
+    FindManyMediaAssetInputDTO { status, skip, take }: FindManyMediaAssetInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<FindManyMediaAssetOutputDTO>, FindManyMediaAssetError> {
+    let status = match status {
+        Some(status) => match status.to_uppercase().as_str() {
+            value @ ("AVAILABLE" | "CORRUPTED" | "MISSING") => {
+                Some(MediaAssetStatusEnum::from(value))
+            }
+            _ => {
+                return Err(FindManyMediaAssetError::Validation(format!(
+                    "Status '{}' inválido",
+                    status
+                )))
+            }
+        },
+        None => None,
+    };
+
+    let assets = state
+        .media_asset_repo
+        .find_many(
+            status,
+            skip.map(|s| s as u64),
+            Some(take.unwrap_or(20) as u64),
+        )
+        .await
+        .map_err(|e| {
+            FindManyMediaAssetError::Database(format!("Erro ao buscar media assets: {}", e))
+        })?
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<FindManyMediaAssetOutputDTO>>();
+
+    Ok(assets)
+}
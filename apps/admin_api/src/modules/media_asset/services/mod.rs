@@ -0,0 +1,2 @@
+pub mod find_many_media_asset_service;
+pub mod verify_media_asset_service;
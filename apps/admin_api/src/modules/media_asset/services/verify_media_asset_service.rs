@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use shared::{enums::media_asset_status_enum::MediaAssetStatusEnum, modules::app_state::AppState};
+
+use crate::modules::media_asset::dto::io::{
+    verify_media_asset_input_dto::VerifyMediaAssetInputDTO,
+    verify_media_asset_output_dto::VerifyMediaAssetOutputDTO,
+};
+
+pub enum VerifyMediaAssetError {
+    Database(String),
+}
+
+/// Relê cada asset do storage e compara tamanho e SHA-256 com o registrado no upload.
+///
+/// Falhas de leitura pontuais não interrompem a verificação: o asset é apenas
+/// marcado como ignorado e volta a ser elegível na próxima execução.
+pub async fn execute(
+    VerifyMediaAssetInputDTO { limit, asset_ids }: VerifyMediaAssetInputDTO,
+    state: Arc<AppState>,
+) -> Result<VerifyMediaAssetOutputDTO, VerifyMediaAssetError> {
+    let assets = match asset_ids {
+        Some(ids) => state.media_asset_repo.find_by_ids(&ids).await,
+        None => state.media_asset_repo.find_for_verification(limit).await,
+    }
+    .map_err(|e| VerifyMediaAssetError::Database(format!("Erro ao buscar media assets: {}", e)))?;
+
+    let storage = state.video_storage_service.clone();
+    let mut report = VerifyMediaAssetOutputDTO::default();
+
+    for asset in assets {
+        if asset.container != storage.container_name() {
+            report.skipped.push(asset.id);
+            continue;
+        }
+
+        let status = match storage.video_exists(&asset.storage_key).await {
+            Ok(false) => MediaAssetStatusEnum::MISSING,
+            Ok(true) => match storage.compute_video_checksum(&asset.storage_key).await {
+                Ok((sha256, size)) if sha256 == asset.sha256 && size == asset.size_bytes as u64 => {
+                    MediaAssetStatusEnum::AVAILABLE
+                }
+                Ok(_) => MediaAssetStatusEnum::CORRUPTED,
+                Err(e) => {
+                    tracing::warn!("Falha ao ler media asset {}: {:?}", asset.id, e);
+                    report.skipped.push(asset.id);
+                    continue;
+                }
+            },
+            Err(e) => {
+                tracing::warn!("Falha ao consultar media asset {}: {:?}", asset.id, e);
+                report.skipped.push(asset.id);
+                continue;
+            }
+        };
+
+        state
+            .media_asset_repo
+            .update_verification(asset.id, status)
+            .await
+            .map_err(|e| {
+                VerifyMediaAssetError::Database(format!("Erro ao atualizar media asset: {}", e))
+            })?;
+
+        report.checked += 1;
+        match status {
+            MediaAssetStatusEnum::AVAILABLE => report.available += 1,
+            MediaAssetStatusEnum::CORRUPTED => report.corrupted.push(asset.id),
+            MediaAssetStatusEnum::MISSING => report.missing.push(asset.id),
+        }
+    }
+
+    Ok(report)
+}
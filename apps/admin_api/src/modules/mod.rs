@@ -1,2 +1,17 @@
+pub mod actor;
+pub mod analytics;
+pub mod availability;
+pub mod catalog;
+pub mod category;
+pub mod director;
+pub mod home_row;
+pub mod image;
+pub mod maturity_rating;
+pub mod media_asset;
 pub mod serie;
+pub mod subtitle;
+pub mod transcode_job;
+pub mod trash;
+pub mod upload_session;
 pub mod video;
+pub mod video_asset;
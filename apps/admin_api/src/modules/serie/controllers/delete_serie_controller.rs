@@ -0,0 +1,56 @@
+use crate::app_state::AppState;
+use crate::modules::serie::dto::delete_serie_query_params_dto::DeleteSerieQueryParamsDTO;
+use crate::modules::serie::dto::serie_route_params_dto::SerieRouteParamsDTO;
+use crate::modules::serie::services::delete_serie_service;
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Extension, Json,
+};
+use macros::require_access;
+use serde_json::json;
+use shared::enums::access_group_enum::AccessGroupEnum;
+use shared::modules::auth::jwt_extractor::AuthenticatedUser;
+use shared::modules::response_interface::ResponseInterface;
+use shared::modules::validation::validation_layer::ValidationErrorResponse;
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<SerieRouteParamsDTO>,
+    Query(query): Query<DeleteSerieQueryParamsDTO>,
+) -> Result<(StatusCode, Json<ResponseInterface<()>>), (StatusCode, Json<ValidationErrorResponse>)>
+{
+    match delete_serie_service::execute(params, query, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Série movida para a lixeira".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                delete_serie_service::DeleteSerieError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                delete_serie_service::DeleteSerieError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                delete_serie_service::DeleteSerieError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao deletar série".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
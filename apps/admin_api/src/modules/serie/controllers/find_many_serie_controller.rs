@@ -0,0 +1,47 @@
+use crate::app_state::AppState;
+use crate::modules::serie::dto::find_many_serie_query_params_dto::FindManySerieQueryParamsDTO;
+use crate::modules::serie::dto::serie_output_dto::SerieOutputDTO;
+use crate::modules::serie::services::find_many_serie_service;
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::enums::access_group_enum::AccessGroupEnum;
+use shared::modules::auth::jwt_extractor::AuthenticatedUser;
+use shared::modules::response_interface::ResponseInterface;
+use shared::modules::validation::validation_layer::ValidationErrorResponse;
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<FindManySerieQueryParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<Vec<SerieOutputDTO>>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_many_serie_service::execute(query.into(), state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_many_serie_service::FindManySerieError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar séries".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::app_state::AppState;
+use crate::modules::serie::dto::serie_detail_output_dto::SerieDetailOutputDTO;
+use crate::modules::serie::dto::serie_route_params_dto::SerieRouteParamsDTO;
+use crate::modules::serie::services::find_serie_service;
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::enums::access_group_enum::AccessGroupEnum;
+use shared::modules::auth::jwt_extractor::AuthenticatedUser;
+use shared::modules::response_interface::ResponseInterface;
+use shared::modules::validation::validation_layer::ValidationErrorResponse;
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<SerieRouteParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<SerieDetailOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_serie_service::execute(params, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_serie_service::FindSerieError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_serie_service::FindSerieError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar série".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -1 +1,6 @@
 pub mod create_serie_controller;
+pub mod delete_serie_controller;
+pub mod find_many_serie_controller;
+pub mod find_serie_controller;
+pub mod reorder_serie_episodes_controller;
+pub mod update_serie_controller;
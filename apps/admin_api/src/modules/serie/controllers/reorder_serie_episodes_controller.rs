@@ -0,0 +1,65 @@
+use crate::app_state::AppState;
+use crate::modules::serie::dto::reorder_serie_episodes_input_dto::ReorderSerieEpisodesInputDTO;
+use crate::modules::serie::dto::serie_detail_output_dto::SerieEpisodeOutputDTO;
+use crate::modules::serie::dto::serie_route_params_dto::SerieRouteParamsDTO;
+use crate::modules::serie::services::reorder_serie_episodes_service;
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::enums::access_group_enum::AccessGroupEnum;
+use shared::modules::auth::jwt_extractor::AuthenticatedUser;
+use shared::modules::response_interface::ResponseInterface;
+use shared::modules::validation::validation_layer::{
+    validate_json, ValidatedJson, ValidationErrorResponse,
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<SerieRouteParamsDTO>,
+    payload: Json<ReorderSerieEpisodesInputDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<Vec<SerieEpisodeOutputDTO>>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match reorder_serie_episodes_service::execute(params, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Episódios renumerados com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                reorder_serie_episodes_service::ReorderSerieEpisodesError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                reorder_serie_episodes_service::ReorderSerieEpisodesError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                reorder_serie_episodes_service::ReorderSerieEpisodesError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                reorder_serie_episodes_service::ReorderSerieEpisodesError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao renumerar episódios".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
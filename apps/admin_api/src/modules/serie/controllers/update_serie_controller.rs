@@ -0,0 +1,59 @@
+use crate::app_state::AppState;
+use crate::modules::serie::dto::serie_output_dto::SerieOutputDTO;
+use crate::modules::serie::dto::serie_route_params_dto::SerieRouteParamsDTO;
+use crate::modules::serie::dto::update_serie_input_dto::UpdateSerieInputDTO;
+use crate::modules::serie::services::update_serie_service;
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::enums::access_group_enum::AccessGroupEnum;
+use shared::modules::auth::jwt_extractor::AuthenticatedUser;
+use shared::modules::response_interface::ResponseInterface;
+use shared::modules::validation::validation_layer::{
+    validate_json, ValidatedJson, ValidationErrorResponse,
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<SerieRouteParamsDTO>,
+    payload: Json<UpdateSerieInputDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<SerieOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match update_serie_service::execute(params, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Série atualizada com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                update_serie_service::UpdateSerieError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                update_serie_service::UpdateSerieError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                update_serie_service::UpdateSerieError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar série".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
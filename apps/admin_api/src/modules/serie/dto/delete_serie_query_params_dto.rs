@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct DeleteSerieQueryParamsDTO {
+    /// `trash` (padrão) leva os episódios para a lixeira; `detach` os mantém
+    /// no catálogo como vídeos avulsos.
+    pub episodes: Option<String>,
+}
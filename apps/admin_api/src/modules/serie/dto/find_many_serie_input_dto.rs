@@ -0,0 +1,21 @@
+use crate::modules::serie::dto::find_many_serie_query_params_dto::FindManySerieQueryParamsDTO;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindManySerieInputDTO {
+    pub title: Option<String>,
+    pub is_featured: Option<bool>,
+    pub skip: Option<i32>,
+    pub take: Option<i32>,
+}
+
+impl From<FindManySerieQueryParamsDTO> for FindManySerieInputDTO {
+    fn from(query: FindManySerieQueryParamsDTO) -> Self {
+        Self {
+            title: query.title,
+            is_featured: query.is_featured,
+            skip: query.skip,
+            take: query.take,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct FindManySerieQueryParamsDTO {
+    #[validate(length(min = 1, message = "O título não pode ser vazio"))]
+    pub title: Option<String>,
+
+    pub is_featured: Option<bool>,
+
+    #[validate(range(min = 0, message = "Skip não pode ser negativo"))]
+    pub skip: Option<i32>,
+
+    #[validate(range(min = 1, max = 100, message = "Take deve estar entre 1 e 100"))]
+    pub take: Option<i32>,
+}
@@ -1,2 +1,10 @@
 pub mod create_serie_input_dto;
 pub mod create_serie_output_dto;
+pub mod delete_serie_query_params_dto;
+pub mod find_many_serie_input_dto;
+pub mod find_many_serie_query_params_dto;
+pub mod reorder_serie_episodes_input_dto;
+pub mod serie_detail_output_dto;
+pub mod serie_output_dto;
+pub mod serie_route_params_dto;
+pub mod update_serie_input_dto;
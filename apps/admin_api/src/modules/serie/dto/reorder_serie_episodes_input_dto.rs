@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, Default)]
+pub struct EpisodeNumberingInputDTO {
+    #[validate(range(min = 1, message = "O ID do vídeo deve ser positivo"))]
+    pub video_id: i32,
+
+    #[validate(range(min = 1, message = "O número da temporada deve ser positivo"))]
+    pub season_number: Option<i32>,
+
+    #[validate(range(min = 1, message = "O número do episódio deve ser positivo"))]
+    pub episode_number: i32,
+}
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct ReorderSerieEpisodesInputDTO {
+    #[validate(length(min = 1, message = "Informe ao menos um episódio"), nested)]
+    pub episodes: Vec<EpisodeNumberingInputDTO>,
+}
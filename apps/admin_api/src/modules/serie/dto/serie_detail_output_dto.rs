@@ -0,0 +1,35 @@
+use crate::modules::serie::dto::serie_output_dto::SerieOutputDTO;
+use serde::Serialize;
+use shared::modules::database::schema::videos::Model as VideoModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SerieEpisodeOutputDTO {
+    pub id: i32,
+    pub title: String,
+    pub season_number: Option<i32>,
+    pub episode_number: Option<i32>,
+    pub duration_seconds: i32,
+    pub is_available: bool,
+    pub thumbnail_url: Option<String>,
+}
+
+impl From<VideoModel> for SerieEpisodeOutputDTO {
+    fn from(video: VideoModel) -> Self {
+        Self {
+            id: video.id,
+            title: video.title,
+            season_number: video.season_number,
+            episode_number: video.episode_number,
+            duration_seconds: video.duration_seconds,
+            is_available: video.is_available,
+            thumbnail_url: video.thumbnail_url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SerieDetailOutputDTO {
+    #[serde(flatten)]
+    pub serie: SerieOutputDTO,
+    pub episodes: Vec<SerieEpisodeOutputDTO>,
+}
@@ -0,0 +1,38 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::repositories::images_repository::CatalogImageUrls;
+use shared::modules::database::schema::series::Model as SerieModel;
+use shared::modules::maturity;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SerieOutputDTO {
+    pub id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub release_year: Option<i32>,
+    pub thumbnail_url: Option<String>,
+    pub is_featured: bool,
+    pub maturity_rating: Option<String>,
+    pub content_descriptors: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub images: CatalogImageUrls,
+}
+
+impl From<SerieModel> for SerieOutputDTO {
+    fn from(serie: SerieModel) -> Self {
+        Self {
+            id: serie.id,
+            title: serie.title,
+            description: serie.description,
+            release_year: serie.release_year,
+            thumbnail_url: serie.thumbnail_url,
+            is_featured: serie.is_featured,
+            content_descriptors: maturity::descriptors(&serie.content_descriptors),
+            maturity_rating: serie.maturity_rating,
+            created_at: serie.created_at,
+            updated_at: serie.updated_at,
+            images: CatalogImageUrls::new(),
+        }
+    }
+}
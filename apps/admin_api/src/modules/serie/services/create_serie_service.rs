@@ -1,7 +1,9 @@
 use crate::modules::serie::dto::create_serie_input_dto::CreateSerieInputDTO;
 use crate::modules::serie::dto::create_serie_output_dto::CreateSerieOutputDTO;
 use crate::AppState;
+use shared::modules::database::repositories::search_repository::IndexScope;
 use shared::modules::database::repositories::serie_repository::CreateSerieRequest;
+use shared::modules::search;
 use shared::modules::validation::validate_release_year::validate_release_year;
 use std::sync::Arc;
 
@@ -33,5 +35,8 @@ pub async fn execute(
         .await
         .map_err(|e| CreateSerieError::Database(format!("Erro ao criar: {}", e)))?;
 
+    search::invalidate_suggestions(&state).await;
+    search::index::sync_scope(&state, IndexScope::Serie(response.id)).await;
+
     Ok(CreateSerieOutputDTO { id: response.id })
 }
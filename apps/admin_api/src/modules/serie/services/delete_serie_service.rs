@@ -0,0 +1,49 @@
+use crate::modules::serie::dto::delete_serie_query_params_dto::DeleteSerieQueryParamsDTO;
+use crate::modules::serie::dto::serie_route_params_dto::SerieRouteParamsDTO;
+use crate::AppState;
+use shared::enums::serie_episodes_action_enum::SerieEpisodesActionEnum;
+use shared::modules::database::repositories::search_repository::IndexScope;
+use shared::modules::search;
+use std::sync::Arc;
+
+pub enum DeleteSerieError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+/// Move a série para a lixeira. Por padrão os episódios vão junto e voltam
+/// quando a série for restaurada; com `episodes=detach` eles continuam no
+/// catálogo como vídeos avulsos.
+pub async fn execute(
+    params: SerieRouteParamsDTO,
+    query: DeleteSerieQueryParamsDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteSerieError> {
+    let episodes = query
+        .episodes
+        .map(|value| SerieEpisodesActionEnum::try_from(value.as_str()))
+        .transpose()
+        .map_err(DeleteSerieError::Validation)?
+        .unwrap_or_default();
+
+    let episode_ids = search::index::affected_videos(&state, IndexScope::Serie(params.id)).await;
+
+    let trashed = state
+        .serie_repo
+        .soft_delete(params.id, episodes)
+        .await
+        .map_err(|e| DeleteSerieError::Database(format!("Erro ao deletar série: {}", e)))?;
+
+    if !trashed {
+        return Err(DeleteSerieError::NotFound(format!(
+            "Série com id {} não encontrada",
+            params.id
+        )));
+    }
+
+    search::invalidate_suggestions(&state).await;
+    search::index::sync_videos(&state, &episode_ids).await;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::modules::serie::dto::find_many_serie_input_dto::FindManySerieInputDTO;
+use crate::modules::serie::dto::serie_output_dto::SerieOutputDTO;
+use crate::AppState;
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::database::repositories::images_repository::catalog_image_urls;
+use std::sync::Arc;
+
+pub enum FindManySerieError {
+    Database(String),
+}
+
+pub async fn execute(
+    FindManySerieInputDTO {
+        title,
+        is_featured,
+        skip,
+        take,
+    }: FindManySerieInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<SerieOutputDTO>, FindManySerieError> {
+    let mut series = state
+        .serie_repo
+        .find_many(
+            title.as_deref(),
+            is_featured,
+            skip.map(|s| s as u64),
+            Some(take.unwrap_or(20) as u64),
+        )
+        .await
+        .map_err(|e| FindManySerieError::Database(format!("Erro ao buscar séries: {}", e)))?
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<SerieOutputDTO>>();
+
+    let serie_ids: Vec<i32> = series.iter().map(|serie| serie.id).collect();
+
+    let mut images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::SERIE, &serie_ids)
+        .await
+        .map(catalog_image_urls)
+        .map_err(|e| FindManySerieError::Database(format!("Erro ao buscar imagens: {}", e)))?;
+
+    for serie in &mut series {
+        if let Some(urls) = images.remove(&serie.id) {
+            serie.images = urls;
+        }
+    }
+
+    Ok(series)
+}
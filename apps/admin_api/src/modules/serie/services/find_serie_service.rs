@@ -0,0 +1,50 @@
+use crate::modules::serie::dto::serie_detail_output_dto::SerieDetailOutputDTO;
+use crate::modules::serie::dto::serie_output_dto::SerieOutputDTO;
+use crate::modules::serie::dto::serie_route_params_dto::SerieRouteParamsDTO;
+use crate::AppState;
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::database::repositories::images_repository::catalog_image_urls;
+use std::sync::Arc;
+
+pub enum FindSerieError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Série com os episódios visíveis, ordenados por temporada e episódio.
+pub async fn execute(
+    params: SerieRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<SerieDetailOutputDTO, FindSerieError> {
+    let serie = state
+        .serie_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| FindSerieError::Database(format!("Erro ao buscar série: {}", e)))?
+        .ok_or_else(|| {
+            FindSerieError::NotFound(format!("Série com id {} não encontrada", params.id))
+        })?;
+
+    let episodes = state
+        .video_repo
+        .find_episodes(serie.id)
+        .await
+        .map_err(|e| FindSerieError::Database(format!("Erro ao buscar episódios: {}", e)))?;
+
+    let images = state
+        .image_repo
+        .find_by_owner(ImageOwnerEnum::SERIE, serie.id)
+        .await
+        .map(catalog_image_urls)
+        .map_err(|e| FindSerieError::Database(format!("Erro ao buscar imagens: {}", e)))?;
+
+    let mut serie: SerieOutputDTO = serie.into();
+    if let Some(urls) = images.into_values().next() {
+        serie.images = urls;
+    }
+
+    Ok(SerieDetailOutputDTO {
+        serie,
+        episodes: episodes.into_iter().map(Into::into).collect(),
+    })
+}
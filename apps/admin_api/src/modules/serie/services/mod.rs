@@ -1 +1,6 @@
 pub mod create_serie_service;
+pub mod delete_serie_service;
+pub mod find_many_serie_service;
+pub mod find_serie_service;
+pub mod reorder_serie_episodes_service;
+pub mod update_serie_service;
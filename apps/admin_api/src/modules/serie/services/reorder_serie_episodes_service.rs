@@ -0,0 +1,118 @@
+use crate::modules::serie::dto::reorder_serie_episodes_input_dto::ReorderSerieEpisodesInputDTO;
+use crate::modules::serie::dto::serie_detail_output_dto::SerieEpisodeOutputDTO;
+use crate::modules::serie::dto::serie_route_params_dto::SerieRouteParamsDTO;
+use crate::AppState;
+use shared::modules::database::repositories::search_repository::IndexScope;
+use shared::modules::database::repositories::serie_repository::EpisodeNumbering;
+use shared::modules::database::repositories::videos_repository::episode_label;
+use shared::modules::search;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+pub enum ReorderSerieEpisodesError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+    Conflict(String),
+}
+
+/// Define temporada e número de vários episódios em uma única transação.
+/// Episódios fora da lista mantêm a posição atual e também contam na
+/// verificação de duplicidade.
+pub async fn execute(
+    params: SerieRouteParamsDTO,
+    payload: ReorderSerieEpisodesInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<SerieEpisodeOutputDTO>, ReorderSerieEpisodesError> {
+    let serie = state
+        .serie_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| ReorderSerieEpisodesError::Database(format!("Erro ao buscar série: {}", e)))?
+        .ok_or_else(|| {
+            ReorderSerieEpisodesError::NotFound(format!(
+                "Série com id {} não encontrada",
+                params.id
+            ))
+        })?;
+
+    let episodes = state
+        .video_repo
+        .find_episodes(serie.id)
+        .await
+        .map_err(|e| {
+            ReorderSerieEpisodesError::Database(format!("Erro ao buscar episódios: {}", e))
+        })?;
+
+    let mut seen_videos = HashSet::new();
+    for episode in &payload.episodes {
+        if !seen_videos.insert(episode.video_id) {
+            return Err(ReorderSerieEpisodesError::Validation(format!(
+                "O vídeo {} aparece mais de uma vez",
+                episode.video_id
+            )));
+        }
+
+        if !episodes
+            .iter()
+            .any(|current| current.id == episode.video_id)
+        {
+            return Err(ReorderSerieEpisodesError::Validation(format!(
+                "O vídeo {} não é episódio da série {}",
+                episode.video_id, serie.id
+            )));
+        }
+    }
+
+    let numbering: Vec<EpisodeNumbering> = payload
+        .episodes
+        .iter()
+        .map(|episode| EpisodeNumbering {
+            video_id: episode.video_id,
+            season_number: episode.season_number,
+            episode_number: episode.episode_number,
+        })
+        .collect();
+
+    // posição final de cada episódio: a nova, ou a atual para quem não foi enviado
+    let mut positions: HashMap<(Option<i32>, i32), i32> = HashMap::new();
+    let final_positions = episodes.iter().filter_map(|current| {
+        match numbering.iter().find(|n| n.video_id == current.id) {
+            Some(n) => Some((n.season_number, n.episode_number, current.id)),
+            None => current
+                .episode_number
+                .map(|episode_number| (current.season_number, episode_number, current.id)),
+        }
+    });
+
+    for (season_number, episode_number, video_id) in final_positions {
+        if let Some(other) = positions.insert((season_number, episode_number), video_id) {
+            return Err(ReorderSerieEpisodesError::Conflict(format!(
+                "Os vídeos {} e {} ficariam na mesma posição {}",
+                other,
+                video_id,
+                episode_label(season_number, episode_number)
+            )));
+        }
+    }
+
+    state
+        .serie_repo
+        .reorder_episodes(serie.id, &numbering)
+        .await
+        .map_err(|e| {
+            ReorderSerieEpisodesError::Database(format!("Erro ao renumerar episódios: {}", e))
+        })?;
+
+    search::index::sync_scope(&state, IndexScope::Serie(serie.id)).await;
+
+    let episodes = state
+        .video_repo
+        .find_episodes(serie.id)
+        .await
+        .map_err(|e| {
+            ReorderSerieEpisodesError::Database(format!("Erro ao buscar episódios: {}", e))
+        })?;
+
+    Ok(episodes.into_iter().map(Into::into).collect())
+}
@@ -0,0 +1,69 @@
+use crate::modules::serie::dto::serie_output_dto::SerieOutputDTO;
+use crate::modules::serie::dto::serie_route_params_dto::SerieRouteParamsDTO;
+use crate::modules::serie::dto::update_serie_input_dto::UpdateSerieInputDTO;
+use crate::AppState;
+use sea_orm::DbErr;
+use shared::modules::database::repositories::search_repository::IndexScope;
+use shared::modules::database::repositories::serie_repository::UpdateSerieRequest;
+use shared::modules::search;
+use shared::modules::validation::validate_release_year::validate_release_year;
+use std::sync::Arc;
+
+pub enum UpdateSerieError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+/// Atualiza apenas os campos enviados.
+pub async fn execute(
+    params: SerieRouteParamsDTO,
+    payload: UpdateSerieInputDTO,
+    state: Arc<AppState>,
+) -> Result<SerieOutputDTO, UpdateSerieError> {
+    if let Err(err) = validate_release_year(&payload.release_year) {
+        return Err(UpdateSerieError::Validation(format!(
+            "Ano de lançamento inválido: {}",
+            err.message.unwrap_or_default()
+        )));
+    };
+
+    let serie = state
+        .serie_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| UpdateSerieError::Database(format!("Erro ao buscar série: {}", e)))?;
+
+    if serie.is_none() {
+        return Err(UpdateSerieError::NotFound(format!(
+            "Série com id {} não encontrada",
+            params.id
+        )));
+    }
+
+    let updated = state
+        .serie_repo
+        .update(
+            params.id,
+            UpdateSerieRequest {
+                title: payload.title,
+                description: payload.description,
+                release_year: payload.release_year,
+                thumbnail_url: payload.thumbnail_url,
+                is_featured: payload.is_featured,
+            },
+        )
+        .await
+        .map_err(|e| match e {
+            // foi para a lixeira entre a busca e a gravação
+            DbErr::RecordNotUpdated => {
+                UpdateSerieError::NotFound(format!("Série com id {} não encontrada", params.id))
+            }
+            e => UpdateSerieError::Database(format!("Erro ao atualizar: {}", e)),
+        })?;
+
+    search::invalidate_suggestions(&state).await;
+    search::index::sync_scope(&state, IndexScope::Serie(params.id)).await;
+
+    Ok(updated.into())
+}
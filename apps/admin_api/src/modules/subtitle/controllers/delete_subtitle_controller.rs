@@ -0,0 +1,60 @@
+use crate::modules::subtitle::{
+    dto::{
+        io::delete_subtitle_input_dto::DeleteSubtitleInputDTO,
+        route_params::subtitle_route_params_dto::SubtitleRouteParamsDTO,
+    },
+    services::delete_subtitle_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<SubtitleRouteParamsDTO>,
+) -> Result<(StatusCode, Json<ResponseInterface<()>>), (StatusCode, Json<ValidationErrorResponse>)>
+{
+    let input: DeleteSubtitleInputDTO = params.into();
+
+    match delete_subtitle_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                message: Some("Legenda removida com sucesso".to_string()),
+                result: Some(result),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                delete_subtitle_service::DeleteSubtitleError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                delete_subtitle_service::DeleteSubtitleError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                delete_subtitle_service::DeleteSubtitleError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao remover legenda".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
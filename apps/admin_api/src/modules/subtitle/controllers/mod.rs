@@ -0,0 +1,2 @@
+pub mod delete_subtitle_controller;
+pub mod upload_subtitle_controller;
@@ -0,0 +1,59 @@
+use crate::app_state::AppState;
+use crate::modules::subtitle::dto::io::subtitle_output_dto::SubtitleOutputDTO;
+use crate::modules::subtitle::dto::route_params::upload_subtitle_route_params_dto::UploadSubtitleRouteParamsDTO;
+use crate::modules::subtitle::services::upload_subtitle_service;
+use axum::extract::Path;
+use axum::{extract::Multipart, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::enums::access_group_enum::AccessGroupEnum;
+use shared::modules::auth::jwt_extractor::AuthenticatedUser;
+use shared::modules::response_interface::ResponseInterface;
+use shared::modules::validation::validation_layer::ValidationErrorResponse;
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<UploadSubtitleRouteParamsDTO>,
+    multipart: Multipart,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<SubtitleOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match upload_subtitle_service::execute(multipart, params, state).await {
+        Ok(subtitle) => Ok((
+            StatusCode::CREATED,
+            Json(ResponseInterface {
+                result: Some(subtitle),
+                message: Some("Legenda enviada com sucesso".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                upload_subtitle_service::UploadSubtitleError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                upload_subtitle_service::UploadSubtitleError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                upload_subtitle_service::UploadSubtitleError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                upload_subtitle_service::UploadSubtitleError::Storage(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao processar legenda".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::modules::subtitle::dto::route_params::subtitle_route_params_dto::SubtitleRouteParamsDTO;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct DeleteSubtitleInputDTO {
+    pub video_id: i32,
+    pub language: String,
+}
+
+impl From<SubtitleRouteParamsDTO> for DeleteSubtitleInputDTO {
+    fn from(params: SubtitleRouteParamsDTO) -> Self {
+        Self {
+            video_id: params.id,
+            language: params.language,
+        }
+    }
+}
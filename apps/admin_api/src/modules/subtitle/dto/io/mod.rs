@@ -0,0 +1,2 @@
+pub mod delete_subtitle_input_dto;
+pub mod subtitle_output_dto;
@@ -0,0 +1,15 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SubtitleOutputDTO {
+    pub video_asset_id: i32,
+    pub media_asset_id: i32,
+    pub language: String,
+    pub format: String,
+    pub cue_count: usize,
+    pub duration_ms: i64,
+    pub size_bytes: i64,
+    pub url: String,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,2 @@
+pub mod subtitle_route_params_dto;
+pub mod upload_subtitle_route_params_dto;
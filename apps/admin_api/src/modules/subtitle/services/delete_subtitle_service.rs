@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use shared::modules::{app_state::AppState, subtitles};
+
+use crate::modules::subtitle::dto::io::delete_subtitle_input_dto::DeleteSubtitleInputDTO;
+
+pub enum DeleteSubtitleError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+/// Remove a legenda do idioma, incluindo o arquivo no storage.
+pub async fn execute(
+    input: DeleteSubtitleInputDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteSubtitleError> {
+    let language = subtitles::normalize_language(&input.language).ok_or_else(|| {
+        DeleteSubtitleError::Validation(format!("Idioma '{}' inválido", input.language))
+    })?;
+
+    let removed = state
+        .video_asset_repo
+        .delete_subtitle(input.video_id, &language)
+        .await
+        .map_err(|e| DeleteSubtitleError::Database(format!("Erro ao deletar legenda: {}", e)))?;
+
+    if removed.is_empty() {
+        return Err(DeleteSubtitleError::NotFound(format!(
+            "Legenda '{}' não encontrada para o vídeo {}",
+            language, input.video_id
+        )));
+    }
+
+    for asset in &removed {
+        if let Err(e) = state.video_storage_service.delete_asset_blob(asset).await {
+            tracing::warn!("Falha ao deletar legenda '{}': {:?}", asset.storage_key, e);
+        }
+    }
+
+    Ok(())
+}
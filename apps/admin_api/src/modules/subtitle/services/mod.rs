@@ -0,0 +1,2 @@
+pub mod delete_subtitle_service;
+pub mod upload_subtitle_service;
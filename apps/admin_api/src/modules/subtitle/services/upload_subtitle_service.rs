@@ -0,0 +1,157 @@
+use crate::app_state::AppState;
+use crate::modules::subtitle::dto::{
+    io::subtitle_output_dto::SubtitleOutputDTO,
+    route_params::upload_subtitle_route_params_dto::UploadSubtitleRouteParamsDTO,
+};
+use axum::extract::Multipart;
+use shared::modules::database::repositories::media_assets_repository::CreateMediaAssetRequest;
+use shared::modules::subtitles::{self, MAX_SUBTITLE_SIZE, WEBVTT_CONTENT_TYPE};
+use std::sync::Arc;
+
+pub enum UploadSubtitleError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+    Storage(String),
+}
+
+/// Recebe o arquivo (campo `file`) e o idioma (campo `language`), valida o
+/// SRT/WebVTT e grava a versão normalizada em WebVTT.
+pub async fn execute(
+    mut multipart: Multipart,
+    params: UploadSubtitleRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<SubtitleOutputDTO, UploadSubtitleError> {
+    let video = state
+        .video_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(|e| UploadSubtitleError::Database(format!("Erro ao buscar vídeo: {}", e)))?
+        .ok_or_else(|| {
+            UploadSubtitleError::NotFound(format!("Vídeo com id {} não encontrado", params.id))
+        })?;
+
+    let mut language: Option<String> = None;
+    let mut file: Option<(String, Vec<u8>)> = None;
+
+    while let Some(mut field) = multipart.next_field().await.map_err(|e| {
+        UploadSubtitleError::Validation(format!("Erro ao processar campo multipart: {}", e))
+    })? {
+        match field.name() {
+            Some("language") => {
+                let value = field.text().await.map_err(|e| {
+                    UploadSubtitleError::Validation(format!("Erro ao ler o idioma: {}", e))
+                })?;
+                language = Some(value);
+            }
+            Some("file") => {
+                let file_name = field.file_name().unwrap_or("legenda.srt").to_string();
+                let mut data = Vec::new();
+
+                while let Some(chunk) = field.chunk().await.map_err(|e| {
+                    UploadSubtitleError::Validation(format!("Erro ao ler o arquivo: {}", e))
+                })? {
+                    if data.len() + chunk.len() > MAX_SUBTITLE_SIZE {
+                        return Err(UploadSubtitleError::Validation(format!(
+                            "O arquivo de legenda excede {} bytes",
+                            MAX_SUBTITLE_SIZE
+                        )));
+                    }
+                    data.extend_from_slice(&chunk);
+                }
+
+                file = Some((file_name, data));
+            }
+            _ => {}
+        }
+    }
+
+    let language = language
+        .as_deref()
+        .and_then(subtitles::normalize_language)
+        .ok_or_else(|| {
+            UploadSubtitleError::Validation(
+                "Informe um idioma válido no campo 'language' (ex.: pt-BR)".to_string(),
+            )
+        })?;
+
+    let (file_name, data) = file.ok_or_else(|| {
+        UploadSubtitleError::Validation("Envie o arquivo de legenda no campo 'file'".to_string())
+    })?;
+
+    let format = if subtitles::webvtt::is_webvtt(
+        String::from_utf8_lossy(&data).trim_start_matches('\u{feff}'),
+    ) {
+        "WEBVTT"
+    } else {
+        "SRT"
+    };
+
+    let normalized =
+        subtitles::normalize(&data).map_err(|e| UploadSubtitleError::Validation(e.to_string()))?;
+
+    let storage = state.video_storage_service.clone();
+    let blob_file_name = format!("video-{}-{}.vtt", video.id, language);
+
+    let saved = storage
+        .save_video_text_file(normalized.webvtt, &blob_file_name, WEBVTT_CONTENT_TYPE)
+        .await
+        .map_err(|e| UploadSubtitleError::Storage(format!("Erro ao salvar legenda: {}", e)))?;
+
+    let replaced = match state
+        .video_asset_repo
+        .replace_subtitle(
+            video.id,
+            &language,
+            CreateMediaAssetRequest {
+                container: storage.container_name().to_string(),
+                storage_key: saved.blob_name.clone(),
+                url: saved.url,
+                original_filename: Some(file_name),
+                content_type: WEBVTT_CONTENT_TYPE.to_string(),
+                size_bytes: saved.size as i64,
+                sha256: saved.sha256,
+                probe: None,
+            },
+            normalized.duration_ms as i64,
+        )
+        .await
+    {
+        Ok(replaced) => replaced,
+        Err(e) => {
+            if let Err(delete_err) = storage.delete_video(&saved.blob_name).await {
+                tracing::warn!(
+                    "Falha ao descartar legenda '{}': {:?}",
+                    saved.blob_name,
+                    delete_err
+                );
+            }
+            return Err(UploadSubtitleError::Database(format!(
+                "Erro ao registrar legenda: {}",
+                e
+            )));
+        }
+    };
+
+    for previous in &replaced.previous {
+        if let Err(e) = storage.delete_asset_blob(previous).await {
+            tracing::warn!(
+                "Falha ao deletar legenda antiga '{}': {:?}",
+                previous.storage_key,
+                e
+            );
+        }
+    }
+
+    Ok(SubtitleOutputDTO {
+        video_asset_id: replaced.video_asset.id,
+        media_asset_id: replaced.asset.id,
+        language,
+        format: format.to_string(),
+        cue_count: normalized.cue_count,
+        duration_ms: normalized.duration_ms as i64,
+        size_bytes: replaced.asset.size_bytes,
+        url: replaced.asset.url,
+        created_at: replaced.asset.created_at,
+    })
+}
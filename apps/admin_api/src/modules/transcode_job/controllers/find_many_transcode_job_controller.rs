@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+
+use crate::modules::transcode_job::{
+    dto::{
+        io::transcode_job_output_dto::TranscodeJobOutputDTO,
+        query_params::find_many_transcode_job_query_params_dto::FindManyTranscodeJobQueryParamsDTO,
+    },
+    services::find_many_transcode_job_service,
+};
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<FindManyTranscodeJobQueryParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<Vec<TranscodeJobOutputDTO>>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_many_transcode_job_service::execute(query.into(), state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_many_transcode_job_service::FindManyTranscodeJobError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                find_many_transcode_job_service::FindManyTranscodeJobError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar os jobs de transcodificação".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
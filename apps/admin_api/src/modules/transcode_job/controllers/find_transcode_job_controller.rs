@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+
+use crate::modules::transcode_job::{
+    dto::{
+        io::transcode_job_output_dto::TranscodeJobOutputDTO,
+        route_params::transcode_job_route_params_dto::TranscodeJobRouteParamsDTO,
+    },
+    services::find_transcode_job_service,
+};
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<TranscodeJobRouteParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<TranscodeJobOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_transcode_job_service::execute(params.into(), state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_transcode_job_service::FindTranscodeJobError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_transcode_job_service::FindTranscodeJobError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar o job de transcodificação".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
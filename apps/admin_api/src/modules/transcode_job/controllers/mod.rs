@@ -0,0 +1,4 @@
+pub mod create_transcode_job_controller;
+pub mod find_many_transcode_job_controller;
+pub mod find_transcode_job_controller;
+pub mod retry_transcode_job_controller;
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use crate::modules::transcode_job::dto::route_params::video_transcode_route_params_dto::VideoTranscodeRouteParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct CreateTranscodeJobInputDTO {
+    pub video_id: i32,
+}
+
+impl From<VideoTranscodeRouteParamsDTO> for CreateTranscodeJobInputDTO {
+    fn from(params: VideoTranscodeRouteParamsDTO) -> Self {
+        Self {
+            video_id: params.id,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use crate::modules::transcode_job::dto::query_params::find_many_transcode_job_query_params_dto::FindManyTranscodeJobQueryParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindManyTranscodeJobInputDTO {
+    pub status: Option<String>,
+    pub video_id: Option<i32>,
+    pub skip: Option<i32>,
+    pub take: Option<i32>,
+}
+
+impl From<FindManyTranscodeJobQueryParamsDTO> for FindManyTranscodeJobInputDTO {
+    fn from(query: FindManyTranscodeJobQueryParamsDTO) -> Self {
+        Self {
+            status: query.status,
+            video_id: query.video_id,
+            skip: query.skip,
+            take: query.take,
+        }
+    }
+}
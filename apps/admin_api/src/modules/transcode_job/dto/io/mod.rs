@@ -0,0 +1,4 @@
+pub mod create_transcode_job_input_dto;
+pub mod find_many_transcode_job_input_dto;
+pub mod transcode_job_input_dto;
+pub mod transcode_job_output_dto;
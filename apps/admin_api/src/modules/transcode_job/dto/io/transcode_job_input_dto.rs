@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+use crate::modules::transcode_job::dto::route_params::transcode_job_route_params_dto::TranscodeJobRouteParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct TranscodeJobInputDTO {
+    pub id: i32,
+}
+
+impl From<TranscodeJobRouteParamsDTO> for TranscodeJobInputDTO {
+    fn from(params: TranscodeJobRouteParamsDTO) -> Self {
+        Self { id: params.id }
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::schema::transcode_jobs::Model as TranscodeJobModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscodeJobOutputDTO {
+    pub id: i32,
+    pub video_id: i32,
+    pub source_media_asset_id: i32,
+    pub status: String,
+    pub progress: i32,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub last_error: Option<String>,
+    pub locked_by: Option<String>,
+    pub run_after: NaiveDateTime,
+    pub started_at: Option<NaiveDateTime>,
+    pub finished_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl From<TranscodeJobModel> for TranscodeJobOutputDTO {
+    fn from(job: TranscodeJobModel) -> Self {
+        Self {
+            id: job.id,
+            video_id: job.video_id,
+            source_media_asset_id: job.source_media_asset_id,
+            status: job.status,
+            progress: job.progress,
+            attempts: job.attempts,
+            max_attempts: job.max_attempts,
+            last_error: job.last_error,
+            locked_by: job.locked_by,
+            run_after: job.run_after,
+            started_at: job.started_at,
+            finished_at: job.finished_at,
+            created_at: job.created_at,
+            updated_at: job.updated_at,
+        }
+    }
+}
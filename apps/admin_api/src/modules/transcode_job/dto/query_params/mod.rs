@@ -0,0 +1 @@
+pub mod find_many_transcode_job_query_params_dto;
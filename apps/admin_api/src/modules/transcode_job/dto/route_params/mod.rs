@@ -0,0 +1,2 @@
+pub mod transcode_job_route_params_dto;
+pub mod video_transcode_route_params_dto;
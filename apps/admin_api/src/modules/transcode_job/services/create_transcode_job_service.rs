@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::{
+        transcode_job_status_enum::TranscodeJobStatusEnum,
+        video_asset_kind_enum::VideoAssetKindEnum,
+    },
+    modules::app_state::AppState,
+};
+
+use crate::modules::transcode_job::dto::io::{
+    create_transcode_job_input_dto::CreateTranscodeJobInputDTO,
+    transcode_job_output_dto::TranscodeJobOutputDTO,
+};
+
+pub enum CreateTranscodeJobError {
+    Database(String),
+    NotFound(String),
+    Conflict(String),
+}
+
+/// Enfileira a geração de renditions a partir do arquivo original atual do vídeo.
+pub async fn execute(
+    input: CreateTranscodeJobInputDTO,
+    state: Arc<AppState>,
+) -> Result<TranscodeJobOutputDTO, CreateTranscodeJobError> {
+    state
+        .video_repo
+        .find_by_id(input.video_id)
+        .await
+        .map_err(|e| CreateTranscodeJobError::Database(format!("Erro ao buscar vídeo: {}", e)))?
+        .ok_or_else(|| {
+            CreateTranscodeJobError::NotFound(format!(
+                "Vídeo com id {} não encontrado",
+                input.video_id
+            ))
+        })?;
+
+    let source = state
+        .video_asset_repo
+        .find_by_video(input.video_id, Some(VideoAssetKindEnum::SOURCE))
+        .await
+        .map_err(|e| CreateTranscodeJobError::Database(format!("Erro ao buscar assets: {}", e)))?
+        .into_iter()
+        .find_map(|(_, media_asset)| media_asset)
+        .ok_or_else(|| {
+            CreateTranscodeJobError::Conflict(
+                "O vídeo não possui arquivo original para transcodificar".to_string(),
+            )
+        })?;
+
+    for status in [
+        TranscodeJobStatusEnum::PENDING,
+        TranscodeJobStatusEnum::RUNNING,
+    ] {
+        let active = state
+            .transcode_job_repo
+            .find_many(Some(status), Some(input.video_id), None, Some(1))
+            .await
+            .map_err(|e| {
+                CreateTranscodeJobError::Database(format!("Erro ao buscar jobs: {}", e))
+            })?;
+
+        if let Some(job) = active.first() {
+            return Err(CreateTranscodeJobError::Conflict(format!(
+                "O vídeo já possui o job {} com status {}",
+                job.id, job.status
+            )));
+        }
+    }
+
+    let job = state
+        .transcode_job_repo
+        .enqueue(input.video_id, source.id)
+        .await
+        .map_err(|e| CreateTranscodeJobError::Database(format!("Erro ao criar job: {}", e)))?;
+
+    Ok(job.into())
+}
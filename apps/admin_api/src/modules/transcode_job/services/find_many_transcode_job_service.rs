@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::transcode_job_status_enum::TranscodeJobStatusEnum, modules::app_state::AppState,
+};
+
+use crate::modules::transcode_job::dto::io::{
+    find_many_transcode_job_input_dto::FindManyTranscodeJobInputDTO,
+    transcode_job_output_dto::TranscodeJobOutputDTO,
+};
+
+pub enum FindManyTranscodeJobError {
+    Validation(String),
+    Database(String),
+}
+
+pub async fn execute(
+    FindManyTranscodeJobInputDTO {
+        status,
+        video_id,
+        skip,
+        take,
+    }: FindManyTranscodeJobInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<TranscodeJobOutputDTO>, FindManyTranscodeJobError> {
+    let status = match status {
+        Some(status) => match status.to_uppercase().as_str() {
+            value @ ("PENDING" | "RUNNING" | "SUCCEEDED" | "FAILED") => {
+                Some(TranscodeJobStatusEnum::from(value))
+            }
+            _ => {
+                return Err(FindManyTranscodeJobError::Validation(format!(
+                    "Status '{}' inválido",
+                    status
+                )))
+            }
+        },
+        None => None,
+    };
+
+    let jobs = state
+        .transcode_job_repo
+        .find_many(
+            status,
+            video_id,
+            skip.map(|s| s as u64),
+            Some(take.unwrap_or(20) as u64),
+        )
+        .await
+        .map_err(|e| FindManyTranscodeJobError::Database(format!("Erro ao buscar jobs: {}", e)))?
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<TranscodeJobOutputDTO>>();
+
+    Ok(jobs)
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::transcode_job::dto::io::{
+    transcode_job_input_dto::TranscodeJobInputDTO, transcode_job_output_dto::TranscodeJobOutputDTO,
+};
+
+pub enum FindTranscodeJobError {
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    input: TranscodeJobInputDTO,
+    state: Arc<AppState>,
+) -> Result<TranscodeJobOutputDTO, FindTranscodeJobError> {
+    let job = state
+        .transcode_job_repo
+        .find_by_id(input.id)
+        .await
+        .map_err(|e| FindTranscodeJobError::Database(format!("Erro ao buscar job: {}", e)))?
+        .ok_or_else(|| {
+            FindTranscodeJobError::NotFound(format!("Job com id {} não encontrado", input.id))
+        })?;
+
+    Ok(job.into())
+}
@@ -0,0 +1,4 @@
+pub mod create_transcode_job_service;
+pub mod find_many_transcode_job_service;
+pub mod find_transcode_job_service;
+pub mod retry_transcode_job_service;
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::transcode_job_status_enum::TranscodeJobStatusEnum, modules::app_state::AppState,
+};
+
+use crate::modules::transcode_job::dto::io::{
+    transcode_job_input_dto::TranscodeJobInputDTO, transcode_job_output_dto::TranscodeJobOutputDTO,
+};
+
+pub enum RetryTranscodeJobError {
+    Database(String),
+    NotFound(String),
+    Conflict(String),
+}
+
+/// Recoloca na fila um job que esgotou as tentativas automáticas.
+pub async fn execute(
+    input: TranscodeJobInputDTO,
+    state: Arc<AppState>,
+) -> Result<TranscodeJobOutputDTO, RetryTranscodeJobError> {
+    let job = state
+        .transcode_job_repo
+        .find_by_id(input.id)
+        .await
+        .map_err(|e| RetryTranscodeJobError::Database(format!("Erro ao buscar job: {}", e)))?
+        .ok_or_else(|| {
+            RetryTranscodeJobError::NotFound(format!("Job com id {} não encontrado", input.id))
+        })?;
+
+    if TranscodeJobStatusEnum::from(job.status.as_str()) != TranscodeJobStatusEnum::FAILED {
+        return Err(RetryTranscodeJobError::Conflict(format!(
+            "Apenas jobs FAILED podem ser reprocessados (status atual: {})",
+            job.status
+        )));
+    }
+
+    let requeued = state
+        .transcode_job_repo
+        .retry(input.id)
+        .await
+        .map_err(|e| RetryTranscodeJobError::Database(format!("Erro ao reprocessar job: {}", e)))?;
+
+    if !requeued {
+        return Err(RetryTranscodeJobError::Conflict(
+            "O job mudou de status durante a operação".to_string(),
+        ));
+    }
+
+    let job = state
+        .transcode_job_repo
+        .find_by_id(input.id)
+        .await
+        .map_err(|e| RetryTranscodeJobError::Database(format!("Erro ao buscar job: {}", e)))?
+        .ok_or_else(|| {
+            RetryTranscodeJobError::NotFound(format!("Job com id {} não encontrado", input.id))
+        })?;
+
+    Ok(job.into())
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+
+use crate::modules::trash::{
+    dto::{
+        io::trash_item_output_dto::TrashItemOutputDTO,
+        query_params::find_many_trash_query_params_dto::FindManyTrashQueryParamsDTO,
+    },
+    services::find_many_trash_service,
+};
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Query(query): Query<FindManyTrashQueryParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<Vec<TrashItemOutputDTO>>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_many_trash_service::execute(query.into(), state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_many_trash_service::FindManyTrashError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                find_many_trash_service::FindManyTrashError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar a lixeira".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
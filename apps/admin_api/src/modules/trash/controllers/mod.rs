@@ -0,0 +1,3 @@
+pub mod find_many_trash_controller;
+pub mod purge_trash_item_controller;
+pub mod restore_trash_item_controller;
@@ -0,0 +1,65 @@
+use crate::modules::trash::{
+    dto::{
+        io::trash_item_input_dto::TrashItemInputDTO,
+        route_params::trash_item_route_params_dto::TrashItemRouteParamsDTO,
+    },
+    services::purge_trash_item_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<TrashItemRouteParamsDTO>,
+) -> Result<(StatusCode, Json<ResponseInterface<()>>), (StatusCode, Json<ValidationErrorResponse>)>
+{
+    let input = TrashItemInputDTO::try_from(params).map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ValidationErrorResponse {
+                message: "Erro ao remover item".to_string(),
+                errors: json!([msg]),
+            }),
+        )
+    })?;
+
+    match purge_trash_item_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                message: Some("Item removido definitivamente".to_string()),
+                result: Some(result),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                purge_trash_item_service::PurgeTrashItemError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                purge_trash_item_service::PurgeTrashItemError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao remover item".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::modules::trash::dto::query_params::find_many_trash_query_params_dto::FindManyTrashQueryParamsDTO;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindManyTrashInputDTO {
+    pub item_type: Option<String>,
+    pub skip: Option<i32>,
+    pub take: Option<i32>,
+}
+
+impl From<FindManyTrashQueryParamsDTO> for FindManyTrashInputDTO {
+    fn from(query: FindManyTrashQueryParamsDTO) -> Self {
+        Self {
+            item_type: query.item_type,
+            skip: query.skip,
+            take: query.take,
+        }
+    }
+}
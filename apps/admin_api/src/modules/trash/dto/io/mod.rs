@@ -0,0 +1,3 @@
+pub mod find_many_trash_input_dto;
+pub mod trash_item_input_dto;
+pub mod trash_item_output_dto;
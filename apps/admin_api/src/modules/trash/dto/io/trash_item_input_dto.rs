@@ -0,0 +1,20 @@
+use crate::modules::trash::dto::route_params::trash_item_route_params_dto::TrashItemRouteParamsDTO;
+use shared::enums::trash_item_type_enum::TrashItemTypeEnum;
+
+/// Item da lixeira identificado pela rota, com o tipo já validado.
+#[derive(Debug, Clone, Copy)]
+pub struct TrashItemInputDTO {
+    pub item_type: TrashItemTypeEnum,
+    pub id: i32,
+}
+
+impl TryFrom<TrashItemRouteParamsDTO> for TrashItemInputDTO {
+    type Error = String;
+
+    fn try_from(params: TrashItemRouteParamsDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            item_type: TrashItemTypeEnum::try_from(params.item_type.as_str())?,
+            id: params.id,
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::enums::trash_item_type_enum::TrashItemTypeEnum;
+use shared::modules::database::schema::series::Model as SerieModel;
+use shared::modules::database::schema::videos::Model as VideoModel;
+
+#[derive(Debug, Serialize)]
+pub struct TrashItemOutputDTO {
+    #[serde(rename = "type")]
+    pub item_type: TrashItemTypeEnum,
+    pub id: i32,
+    pub title: String,
+    pub series_id: Option<i32>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+impl From<VideoModel> for TrashItemOutputDTO {
+    fn from(video: VideoModel) -> Self {
+        Self {
+            item_type: TrashItemTypeEnum::VIDEO,
+            id: video.id,
+            title: video.title,
+            series_id: video.series_id,
+            deleted_at: video.deleted_at,
+        }
+    }
+}
+
+impl From<SerieModel> for TrashItemOutputDTO {
+    fn from(serie: SerieModel) -> Self {
+        Self {
+            item_type: TrashItemTypeEnum::SERIE,
+            id: serie.id,
+            title: serie.title,
+            series_id: None,
+            deleted_at: serie.deleted_at,
+        }
+    }
+}
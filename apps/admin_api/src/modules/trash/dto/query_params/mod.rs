@@ -0,0 +1 @@
+pub mod find_many_trash_query_params_dto;
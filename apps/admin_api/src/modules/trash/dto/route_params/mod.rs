@@ -0,0 +1 @@
+pub mod trash_item_route_params_dto;
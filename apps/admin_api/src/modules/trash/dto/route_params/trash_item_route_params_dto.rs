@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct TrashItemRouteParamsDTO {
+    #[serde(rename = "type")]
+    pub item_type: String,
+
+    #[validate(range(min = 1, message = "O ID deve ser positivo"))]
+    pub id: i32,
+}
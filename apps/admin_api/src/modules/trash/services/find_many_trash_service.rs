@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use shared::{enums::trash_item_type_enum::TrashItemTypeEnum, modules::app_state::AppState};
+
+use crate::modules::trash::dto::io::{
+    find_many_trash_input_dto::FindManyTrashInputDTO, trash_item_output_dto::TrashItemOutputDTO,
+};
+
+pub enum FindManyTrashError {
+    Validation(String),
+    Database(String),
+}
+
+/// Lista a lixeira, os itens apagados mais recentemente primeiro. Sem `type`,
+/// vídeos e séries são intercalados pela data de remoção.
+pub async fn execute(
+    FindManyTrashInputDTO {
+        item_type,
+        skip,
+        take,
+    }: FindManyTrashInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<TrashItemOutputDTO>, FindManyTrashError> {
+    let item_type = item_type
+        .map(|value| TrashItemTypeEnum::try_from(value.as_str()))
+        .transpose()
+        .map_err(FindManyTrashError::Validation)?;
+
+    let skip = skip.unwrap_or(0) as u64;
+    let take = take.unwrap_or(20) as u64;
+
+    let map_err = |e| FindManyTrashError::Database(format!("Erro ao buscar a lixeira: {}", e));
+
+    let items = match item_type {
+        Some(TrashItemTypeEnum::VIDEO) => state
+            .video_repo
+            .find_trashed(Some(skip), Some(take))
+            .await
+            .map_err(map_err)?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        Some(TrashItemTypeEnum::SERIE) => state
+            .serie_repo
+            .find_trashed(Some(skip), Some(take))
+            .await
+            .map_err(map_err)?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        None => {
+            // Cada lista já vem ordenada; basta buscar o suficiente das duas
+            // para cobrir a página depois de intercalar.
+            let videos = state
+                .video_repo
+                .find_trashed(None, Some(skip + take))
+                .await
+                .map_err(map_err)?;
+            let series = state
+                .serie_repo
+                .find_trashed(None, Some(skip + take))
+                .await
+                .map_err(map_err)?;
+
+            let mut items: Vec<TrashItemOutputDTO> = videos
+                .into_iter()
+                .map(Into::into)
+                .chain(series.into_iter().map(Into::into))
+                .collect();
+            items.sort_by_key(|item| std::cmp::Reverse(item.deleted_at));
+
+            items
+                .into_iter()
+                .skip(skip as usize)
+                .take(take as usize)
+                .collect()
+        }
+    };
+
+    Ok(items)
+}
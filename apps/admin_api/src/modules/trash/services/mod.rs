@@ -0,0 +1,3 @@
+pub mod find_many_trash_service;
+pub mod purge_trash_item_service;
+pub mod restore_trash_item_service;
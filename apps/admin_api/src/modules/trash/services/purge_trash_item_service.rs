@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::trash_item_type_enum::TrashItemTypeEnum,
+    modules::{app_state::AppState, trash},
+};
+
+use crate::modules::trash::dto::io::trash_item_input_dto::TrashItemInputDTO;
+
+pub enum PurgeTrashItemError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Remove definitivamente um item que está na lixeira, junto com seus
+/// arquivos no storage. Itens fora da lixeira não podem ser removidos aqui.
+pub async fn execute(
+    input: TrashItemInputDTO,
+    state: Arc<AppState>,
+) -> Result<(), PurgeTrashItemError> {
+    let map_err = |e| PurgeTrashItemError::Database(format!("Erro ao remover: {}", e));
+
+    match input.item_type {
+        TrashItemTypeEnum::VIDEO => {
+            let video = state
+                .video_repo
+                .find_trashed_by_id(input.id)
+                .await
+                .map_err(map_err)?
+                .ok_or_else(|| not_found(input))?;
+
+            trash::purge_video(&state, &video).await.map_err(map_err)?;
+        }
+        TrashItemTypeEnum::SERIE => {
+            let serie = state
+                .serie_repo
+                .find_trashed_by_id(input.id)
+                .await
+                .map_err(map_err)?
+                .ok_or_else(|| not_found(input))?;
+
+            trash::purge_serie(&state, &serie).await.map_err(map_err)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn not_found(input: TrashItemInputDTO) -> PurgeTrashItemError {
+    PurgeTrashItemError::NotFound(format!(
+        "{} com id {} não está na lixeira",
+        input.item_type.as_str(),
+        input.id
+    ))
+}
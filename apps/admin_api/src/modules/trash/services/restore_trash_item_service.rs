@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::trash_item_type_enum::TrashItemTypeEnum,
+    modules::{
+        app_state::AppState,
+        database::repositories::{search_repository::IndexScope, videos_repository::episode_label},
+        search,
+    },
+};
+
+use crate::modules::trash::dto::io::trash_item_input_dto::TrashItemInputDTO;
+
+pub enum RestoreTrashItemError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+    Conflict(String),
+}
+
+/// Tira o item da lixeira. Restaurar uma série traz de volta os episódios
+/// apagados junto com ela; um episódio só volta depois da sua série.
+pub async fn execute(
+    input: TrashItemInputDTO,
+    state: Arc<AppState>,
+) -> Result<(), RestoreTrashItemError> {
+    let map_err = |e| RestoreTrashItemError::Database(format!("Erro ao restaurar: {}", e));
+
+    let restored = match input.item_type {
+        TrashItemTypeEnum::VIDEO => {
+            let video = state
+                .video_repo
+                .find_trashed_by_id(input.id)
+                .await
+                .map_err(map_err)?
+                .ok_or_else(|| not_found(input))?;
+
+            if let Some(series_id) = video.series_id {
+                let serie_trashed = state
+                    .serie_repo
+                    .find_trashed_by_id(series_id)
+                    .await
+                    .map_err(map_err)?
+                    .is_some();
+
+                if serie_trashed {
+                    return Err(RestoreTrashItemError::Validation(format!(
+                        "A série {} do vídeo está na lixeira. Restaure a série primeiro",
+                        series_id
+                    )));
+                }
+
+                if let Some(episode_number) = video.episode_number {
+                    let taken = state
+                        .video_repo
+                        .find_episode(series_id, video.season_number, episode_number)
+                        .await
+                        .map_err(map_err)?;
+
+                    if let Some(taken) = taken {
+                        return Err(RestoreTrashItemError::Conflict(format!(
+                            "O episódio {} da série {} agora é o vídeo {}. Renumere antes de restaurar",
+                            episode_label(video.season_number, episode_number),
+                            series_id,
+                            taken.id
+                        )));
+                    }
+                }
+            }
+
+            state.video_repo.restore(input.id).await.map_err(map_err)?
+        }
+        TrashItemTypeEnum::SERIE => state.serie_repo.restore(input.id).await.map_err(map_err)?,
+    };
+
+    if !restored {
+        return Err(not_found(input));
+    }
+
+    search::invalidate_suggestions(&state).await;
+    match input.item_type {
+        TrashItemTypeEnum::VIDEO => search::index::sync_videos(&state, &[input.id]).await,
+        TrashItemTypeEnum::SERIE => {
+            search::index::sync_scope(&state, IndexScope::Serie(input.id)).await
+        }
+    }
+
+    Ok(())
+}
+
+fn not_found(input: TrashItemInputDTO) -> RestoreTrashItemError {
+    RestoreTrashItemError::NotFound(format!(
+        "{} com id {} não está na lixeira",
+        input.item_type.as_str(),
+        input.id
+    ))
+}
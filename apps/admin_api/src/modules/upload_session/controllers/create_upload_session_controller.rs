@@ -0,0 +1,73 @@
+use crate::modules::upload_session::{
+    dto::{
+        io::{
+            create_upload_session_input_dto::CreateUploadSessionInputDTO,
+            create_upload_session_output_dto::CreateUploadSessionOutputDTO,
+        },
+        request::create_upload_session_request_dto::CreateUploadSessionRequestDTO,
+        route_params::create_upload_session_route_params_dto::CreateUploadSessionRouteParamsDTO,
+    },
+    services::create_upload_session_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<CreateUploadSessionRouteParamsDTO>,
+    payload: Json<CreateUploadSessionRequestDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<CreateUploadSessionOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    let input: CreateUploadSessionInputDTO = (payload, params, user.id).into();
+
+    match create_upload_session_service::execute(input, state).await {
+        Ok(session) => Ok((
+            StatusCode::CREATED,
+            Json(ResponseInterface {
+                result: Some(session),
+                message: Some("Sessão de upload criada com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                create_upload_session_service::CreateUploadSessionError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                create_upload_session_service::CreateUploadSessionError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                create_upload_session_service::CreateUploadSessionError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao criar sessão de upload".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
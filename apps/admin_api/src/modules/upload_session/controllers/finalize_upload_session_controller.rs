@@ -0,0 +1,82 @@
+use crate::modules::upload_session::{
+    dto::{
+        io::{
+            finalize_upload_session_input_dto::FinalizeUploadSessionInputDTO,
+            finalize_upload_session_output_dto::FinalizeUploadSessionOutputDTO,
+        },
+        request::finalize_upload_session_request_dto::FinalizeUploadSessionRequestDTO,
+        route_params::upload_session_route_params_dto::UploadSessionRouteParamsDTO,
+    },
+    services::finalize_upload_session_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<UploadSessionRouteParamsDTO>,
+    payload: Json<FinalizeUploadSessionRequestDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<FinalizeUploadSessionOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    let input: FinalizeUploadSessionInputDTO = (payload, params).into();
+
+    match finalize_upload_session_service::execute(input, state).await {
+        Ok(video) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(video),
+                message: Some("Upload finalizado com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                finalize_upload_session_service::FinalizeUploadSessionError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                finalize_upload_session_service::FinalizeUploadSessionError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                finalize_upload_session_service::FinalizeUploadSessionError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                finalize_upload_session_service::FinalizeUploadSessionError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+                finalize_upload_session_service::FinalizeUploadSessionError::Expired(msg) => {
+                    (StatusCode::GONE, msg)
+                }
+                finalize_upload_session_service::FinalizeUploadSessionError::Storage(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao finalizar upload".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use crate::modules::upload_session::{
+    dto::{
+        io::find_upload_session_output_dto::FindUploadSessionOutputDTO,
+        route_params::upload_session_route_params_dto::UploadSessionRouteParamsDTO,
+    },
+    services::find_upload_session_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<UploadSessionRouteParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<FindUploadSessionOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_upload_session_service::execute(params, state).await {
+        Ok(session) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(session),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_upload_session_service::FindUploadSessionError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_upload_session_service::FindUploadSessionError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                find_upload_session_service::FindUploadSessionError::Storage(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar sessão de upload".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
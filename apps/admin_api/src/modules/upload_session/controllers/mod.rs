@@ -0,0 +1,4 @@
+pub mod create_upload_session_controller;
+pub mod finalize_upload_session_controller;
+pub mod find_upload_session_controller;
+pub mod upload_block_controller;
@@ -0,0 +1,68 @@
+use crate::modules::upload_session::{
+    dto::{
+        io::upload_block_output_dto::UploadBlockOutputDTO,
+        route_params::upload_block_route_params_dto::UploadBlockRouteParamsDTO,
+    },
+    services::upload_block_service,
+};
+use axum::{body::Body, extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<UploadBlockRouteParamsDTO>,
+    body: Body,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<UploadBlockOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match upload_block_service::execute(body, params, state).await {
+        Ok(block) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(block),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                upload_block_service::UploadBlockError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                upload_block_service::UploadBlockError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                upload_block_service::UploadBlockError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                upload_block_service::UploadBlockError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+                upload_block_service::UploadBlockError::Expired(msg) => (StatusCode::GONE, msg),
+                upload_block_service::UploadBlockError::Storage(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao enviar bloco".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
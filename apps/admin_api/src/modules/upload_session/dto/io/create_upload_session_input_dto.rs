@@ -0,0 +1,40 @@
+use crate::modules::upload_session::dto::{
+    request::create_upload_session_request_dto::CreateUploadSessionRequestDTO,
+    route_params::create_upload_session_route_params_dto::CreateUploadSessionRouteParamsDTO,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct CreateUploadSessionInputDTO {
+    pub video_id: i32,
+    pub file_name: String,
+    pub content_type: String,
+    pub total_size: i64,
+    pub sha256: Option<String>,
+    pub created_by: Option<String>,
+}
+
+impl
+    From<(
+        CreateUploadSessionRequestDTO,
+        CreateUploadSessionRouteParamsDTO,
+        String,
+    )> for CreateUploadSessionInputDTO
+{
+    fn from(
+        (body, params, user_id): (
+            CreateUploadSessionRequestDTO,
+            CreateUploadSessionRouteParamsDTO,
+            String,
+        ),
+    ) -> Self {
+        Self {
+            video_id: params.id,
+            file_name: body.file_name,
+            content_type: body.content_type,
+            total_size: body.total_size,
+            sha256: body.sha256.map(|s| s.to_lowercase()),
+            created_by: Some(user_id),
+        }
+    }
+}
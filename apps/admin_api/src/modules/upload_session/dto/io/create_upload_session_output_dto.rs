@@ -0,0 +1,10 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateUploadSessionOutputDTO {
+    pub session_id: String,
+    pub block_size: i64,
+    pub total_blocks: i32,
+    pub expires_at: NaiveDateTime,
+}
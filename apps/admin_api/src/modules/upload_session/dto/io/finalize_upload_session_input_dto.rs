@@ -0,0 +1,24 @@
+use crate::modules::upload_session::dto::{
+    request::finalize_upload_session_request_dto::FinalizeUploadSessionRequestDTO,
+    route_params::upload_session_route_params_dto::UploadSessionRouteParamsDTO,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FinalizeUploadSessionInputDTO {
+    pub session_id: String,
+    pub sha256: Option<String>,
+}
+
+impl From<(FinalizeUploadSessionRequestDTO, UploadSessionRouteParamsDTO)>
+    for FinalizeUploadSessionInputDTO
+{
+    fn from(
+        (body, params): (FinalizeUploadSessionRequestDTO, UploadSessionRouteParamsDTO),
+    ) -> Self {
+        Self {
+            session_id: params.id,
+            sha256: body.sha256.map(|s| s.to_lowercase()),
+        }
+    }
+}
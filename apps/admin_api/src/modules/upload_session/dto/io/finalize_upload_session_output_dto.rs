@@ -0,0 +1,12 @@
+use serde::Serialize;
+use shared::modules::media_probe::ProbeResult;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FinalizeUploadSessionOutputDTO {
+    pub video_id: i32,
+    pub media_asset_id: i32,
+    pub video_url: String,
+    pub size: u64,
+    pub sha256: String,
+    pub probe: ProbeResult,
+}
@@ -0,0 +1,17 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FindUploadSessionOutputDTO {
+    pub session_id: String,
+    pub video_id: i32,
+    pub status: String,
+    pub file_name: String,
+    pub total_size: i64,
+    pub block_size: i64,
+    pub total_blocks: i32,
+    pub received_blocks: Vec<i32>,
+    pub missing_blocks: Vec<i32>,
+    pub received_bytes: u64,
+    pub expires_at: NaiveDateTime,
+}
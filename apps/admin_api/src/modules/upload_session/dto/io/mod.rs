@@ -0,0 +1,6 @@
+pub mod create_upload_session_input_dto;
+pub mod create_upload_session_output_dto;
+pub mod finalize_upload_session_input_dto;
+pub mod finalize_upload_session_output_dto;
+pub mod find_upload_session_output_dto;
+pub mod upload_block_output_dto;
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UploadBlockOutputDTO {
+    pub block_number: i32,
+    pub size: usize,
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct CreateUploadSessionRequestDTO {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "O nome do arquivo deve ter entre 1 e 255 caracteres"
+    ))]
+    pub file_name: String,
+
+    #[validate(length(min = 1, message = "O tipo de conteúdo é obrigatório"))]
+    pub content_type: String,
+
+    #[validate(range(min = 1, message = "O tamanho do arquivo deve ser positivo"))]
+    pub total_size: i64,
+
+    #[validate(length(equal = 64, message = "O SHA-256 deve ter 64 caracteres hexadecimais"))]
+    pub sha256: Option<String>,
+}
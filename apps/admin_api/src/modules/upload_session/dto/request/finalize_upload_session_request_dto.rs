@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct FinalizeUploadSessionRequestDTO {
+    #[validate(length(equal = 64, message = "O SHA-256 deve ter 64 caracteres hexadecimais"))]
+    pub sha256: Option<String>,
+}
@@ -0,0 +1,2 @@
+pub mod create_upload_session_request_dto;
+pub mod finalize_upload_session_request_dto;
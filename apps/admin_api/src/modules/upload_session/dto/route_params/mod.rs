@@ -0,0 +1,3 @@
+pub mod create_upload_session_route_params_dto;
+pub mod upload_block_route_params_dto;
+pub mod upload_session_route_params_dto;
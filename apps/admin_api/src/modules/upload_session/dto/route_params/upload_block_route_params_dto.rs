@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct UploadBlockRouteParamsDTO {
+    #[validate(length(equal = 36, message = "O ID da sessão deve ser um UUID"))]
+    pub id: String,
+
+    #[validate(range(min = 0, message = "O número do bloco não pode ser negativo"))]
+    pub block_number: i32,
+}
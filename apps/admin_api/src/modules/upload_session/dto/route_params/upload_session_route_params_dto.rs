@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct UploadSessionRouteParamsDTO {
+    #[validate(length(equal = 36, message = "O ID da sessão deve ser um UUID"))]
+    pub id: String,
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use shared::modules::{
+    app_state::AppState,
+    database::repositories::upload_sessions_repository::CreateUploadSessionRequest,
+};
+
+use crate::modules::upload_session::dto::io::{
+    create_upload_session_input_dto::CreateUploadSessionInputDTO,
+    create_upload_session_output_dto::CreateUploadSessionOutputDTO,
+};
+
+pub enum CreateUploadSessionError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    input: CreateUploadSessionInputDTO,
+    state: Arc<AppState>,
+) -> Result<CreateUploadSessionOutputDTO, CreateUploadSessionError> {
+    if !input.content_type.starts_with("video/") {
+        return Err(CreateUploadSessionError::Validation(format!(
+            "Tipo de conteúdo '{}' não é um vídeo",
+            input.content_type
+        )));
+    }
+
+    if let Some(sha256) = &input.sha256 {
+        if !sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(CreateUploadSessionError::Validation(
+                "O SHA-256 deve conter apenas caracteres hexadecimais".to_string(),
+            ));
+        }
+    }
+
+    let storage = state.video_storage_service.clone();
+
+    let total_size = input.total_size as u64;
+    if total_size > storage.upload_max_file_size() {
+        return Err(CreateUploadSessionError::Validation(format!(
+            "O arquivo excede o tamanho máximo de {} bytes",
+            storage.upload_max_file_size()
+        )));
+    }
+
+    state
+        .video_repo
+        .find_by_id(input.video_id)
+        .await
+        .map_err(|e| CreateUploadSessionError::Database(format!("Erro ao buscar vídeo: {}", e)))?
+        .ok_or_else(|| {
+            CreateUploadSessionError::NotFound(format!(
+                "Vídeo com id {} não encontrado",
+                input.video_id
+            ))
+        })?;
+
+    let block_size = storage.upload_block_size();
+    let total_blocks = total_size.div_ceil(block_size);
+
+    let expires_at =
+        Utc::now().naive_utc() + Duration::hours(storage.upload_session_expiry_hours() as i64);
+
+    let session = state
+        .upload_session_repo
+        .create(CreateUploadSessionRequest {
+            video_id: input.video_id,
+            blob_name: storage.generate_video_blob_path(&input.file_name),
+            file_name: input.file_name,
+            content_type: input.content_type,
+            total_size: input.total_size,
+            block_size: block_size as i64,
+            total_blocks: total_blocks as i32,
+            expected_sha256: input.sha256,
+            created_by: input.created_by,
+            expires_at,
+        })
+        .await
+        .map_err(|e| CreateUploadSessionError::Database(format!("Erro ao criar sessão: {}", e)))?;
+
+    Ok(CreateUploadSessionOutputDTO {
+        session_id: session.id,
+        block_size: session.block_size,
+        total_blocks: session.total_blocks,
+        expires_at: session.expires_at,
+    })
+}
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::{
+    enums::upload_session_status_enum::UploadSessionStatusEnum,
+    modules::{
+        app_state::AppState,
+        database::repositories::media_assets_repository::CreateMediaAssetRequest,
+        media_probe::ProbeError,
+    },
+};
+
+use crate::modules::upload_session::dto::io::{
+    finalize_upload_session_input_dto::FinalizeUploadSessionInputDTO,
+    finalize_upload_session_output_dto::FinalizeUploadSessionOutputDTO,
+};
+use crate::modules::upload_session::services::upload_block_service::expected_block_size;
+
+pub enum FinalizeUploadSessionError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+    Conflict(String),
+    Expired(String),
+    Storage(String),
+}
+
+pub async fn execute(
+    input: FinalizeUploadSessionInputDTO,
+    state: Arc<AppState>,
+) -> Result<FinalizeUploadSessionOutputDTO, FinalizeUploadSessionError> {
+    let session = state
+        .upload_session_repo
+        .find_by_id(&input.session_id)
+        .await
+        .map_err(|e| FinalizeUploadSessionError::Database(format!("Erro ao buscar sessão: {}", e)))?
+        .ok_or_else(|| {
+            FinalizeUploadSessionError::NotFound(format!(
+                "Sessão de upload {} não encontrada",
+                input.session_id
+            ))
+        })?;
+
+    if UploadSessionStatusEnum::from(session.status.as_str()) != UploadSessionStatusEnum::PENDING {
+        return Err(FinalizeUploadSessionError::Conflict(format!(
+            "A sessão de upload está com status {}",
+            session.status
+        )));
+    }
+
+    if session.expires_at < Utc::now().naive_utc() {
+        return Err(FinalizeUploadSessionError::Expired(
+            "A sessão de upload expirou".to_string(),
+        ));
+    }
+
+    if let Some(sha256) = &input.sha256 {
+        if !sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(FinalizeUploadSessionError::Validation(
+                "O SHA-256 deve conter apenas caracteres hexadecimais".to_string(),
+            ));
+        }
+    }
+
+    // hexadecimal não diferencia maiúsculas, então a comparação também não
+    let expected_sha256 = match (&session.expected_sha256, &input.sha256) {
+        (Some(declared), Some(informed)) if !declared.eq_ignore_ascii_case(informed) => {
+            return Err(FinalizeUploadSessionError::Validation(
+                "O SHA-256 informado difere do declarado na criação da sessão".to_string(),
+            ));
+        }
+        (declared, informed) => informed.clone().or_else(|| declared.clone()),
+    };
+
+    let storage = state.video_storage_service.clone();
+
+    let staged: HashMap<i32, u64> = storage
+        .list_video_blocks(&session.blob_name)
+        .await
+        .map_err(|e| FinalizeUploadSessionError::Storage(format!("Erro ao listar blocos: {}", e)))?
+        .into_iter()
+        .filter(|block| !block.committed)
+        .filter_map(|block| {
+            storage
+                .block_number_from_id(&block.block_id)
+                .map(|n| (n, block.size))
+        })
+        .collect();
+
+    let missing: Vec<i32> = (0..session.total_blocks)
+        .filter(|n| staged.get(n).copied() != Some(expected_block_size(&session, *n) as u64))
+        .collect();
+
+    if !missing.is_empty() {
+        return Err(FinalizeUploadSessionError::Validation(format!(
+            "Blocos ausentes ou incompletos: {:?}",
+            missing
+        )));
+    }
+
+    let video_url = storage
+        .commit_video_blocks(
+            &session.blob_name,
+            session.total_blocks,
+            &session.content_type,
+        )
+        .await
+        .map_err(|e| {
+            FinalizeUploadSessionError::Storage(format!("Erro ao consolidar blocos: {}", e))
+        })?;
+
+    let (sha256, size) = storage
+        .compute_video_checksum(&session.blob_name)
+        .await
+        .map_err(|e| {
+            FinalizeUploadSessionError::Storage(format!("Erro ao calcular checksum: {}", e))
+        })?;
+
+    let integrity_error = if size != session.total_size as u64 {
+        Some(format!(
+            "Tamanho final {} difere do declarado {}",
+            size, session.total_size
+        ))
+    } else {
+        match &expected_sha256 {
+            Some(expected) if !expected.eq_ignore_ascii_case(&sha256) => Some(format!(
+                "SHA-256 calculado {} difere do esperado {}",
+                sha256, expected
+            )),
+            _ => None,
+        }
+    };
+
+    if let Some(msg) = integrity_error {
+        discard_upload(&state, &session.id, &session.blob_name).await;
+        return Err(FinalizeUploadSessionError::Validation(msg));
+    }
+
+    let probe = match storage.probe_video(&session.blob_name, size).await {
+        Ok(probe) => probe,
+        Err(ProbeError::Io(e)) => {
+            discard_upload(&state, &session.id, &session.blob_name).await;
+            return Err(FinalizeUploadSessionError::Storage(format!(
+                "Erro ao inspecionar vídeo: {}",
+                e
+            )));
+        }
+        Err(e) => {
+            discard_upload(&state, &session.id, &session.blob_name).await;
+            return Err(FinalizeUploadSessionError::Validation(e.to_string()));
+        }
+    };
+
+    let video = state
+        .video_repo
+        .find_by_id(session.video_id)
+        .await
+        .map_err(|e| FinalizeUploadSessionError::Database(format!("Erro ao buscar vídeo: {}", e)))?
+        .ok_or_else(|| {
+            FinalizeUploadSessionError::NotFound(format!(
+                "Vídeo com id {} não encontrado",
+                session.video_id
+            ))
+        })?;
+
+    let asset = CreateMediaAssetRequest {
+        container: storage.container_name().to_string(),
+        storage_key: session.blob_name.clone(),
+        url: video_url.clone(),
+        original_filename: Some(session.file_name.clone()),
+        content_type: probe.format.mime_type().to_string(),
+        size_bytes: size as i64,
+        sha256: sha256.clone(),
+        probe: Some(probe.clone()),
+    };
+
+    let Some(replaced) = state
+        .upload_session_repo
+        .complete_and_swap_video_url(
+            &session.id,
+            video.id,
+            video.video_url.clone(),
+            video_url.clone(),
+            asset,
+        )
+        .await
+        .map_err(|e| {
+            FinalizeUploadSessionError::Database(format!("Erro ao atualizar vídeo: {}", e))
+        })?
+    else {
+        discard_upload(&state, &session.id, &session.blob_name).await;
+        return Err(FinalizeUploadSessionError::Conflict(
+            "O vídeo foi alterado por outro upload durante a finalização".to_string(),
+        ));
+    };
+
+    for previous in &replaced.previous {
+        if let Err(e) = storage.delete_asset_blob(previous).await {
+            tracing::warn!(
+                "Falha ao deletar vídeo antigo '{}': {:?}",
+                previous.storage_key,
+                e
+            );
+        }
+    }
+
+    // Vídeos enviados antes dos media assets só têm a URL como referência.
+    if replaced.previous.is_empty() {
+        if let Some(old_url) = &video.video_url {
+            if let Ok(old_blob_name) = storage.get_blob_name_from_url(old_url) {
+                if let Err(e) = storage.delete_video(&old_blob_name).await {
+                    tracing::warn!("Falha ao deletar vídeo antigo '{}': {:?}", old_blob_name, e);
+                }
+            }
+        }
+    }
+
+    // a duração do arquivo já foi gravada no vídeo pelo `replace_source_asset`,
+    // na mesma transação da troca do original
+    if let Some(actual) = replaced.duration_seconds {
+        if actual != video.duration_seconds {
+            tracing::info!(
+                "Duração do vídeo {} ajustada de {}s para {}s conforme o arquivo",
+                video.id,
+                video.duration_seconds,
+                actual
+            );
+        }
+    }
+
+    Ok(FinalizeUploadSessionOutputDTO {
+        video_id: video.id,
+        media_asset_id: replaced.asset.id,
+        video_url,
+        size,
+        sha256,
+        probe,
+    })
+}
+
+async fn discard_upload(state: &AppState, session_id: &str, blob_name: &str) {
+    if let Err(e) = state.video_storage_service.delete_video(blob_name).await {
+        tracing::warn!("Falha ao descartar blob '{}': {:?}", blob_name, e);
+    }
+
+    if let Err(e) = state.upload_session_repo.mark_failed(session_id).await {
+        tracing::warn!(
+            "Falha ao marcar sessão '{}' como falha: {:?}",
+            session_id,
+            e
+        );
+    }
+}
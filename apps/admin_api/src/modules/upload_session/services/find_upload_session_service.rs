@@ -0,0 +1,75 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::upload_session::dto::{
+    io::find_upload_session_output_dto::FindUploadSessionOutputDTO,
+    route_params::upload_session_route_params_dto::UploadSessionRouteParamsDTO,
+};
+use crate::modules::upload_session::services::upload_block_service::expected_block_size;
+
+pub enum FindUploadSessionError {
+    Database(String),
+    NotFound(String),
+    Storage(String),
+}
+
+pub async fn execute(
+    params: UploadSessionRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<FindUploadSessionOutputDTO, FindUploadSessionError> {
+    let session = state
+        .upload_session_repo
+        .find_by_id(&params.id)
+        .await
+        .map_err(|e| FindUploadSessionError::Database(format!("Erro ao buscar sessão: {}", e)))?
+        .ok_or_else(|| {
+            FindUploadSessionError::NotFound(format!(
+                "Sessão de upload {} não encontrada",
+                params.id
+            ))
+        })?;
+
+    let storage = state.video_storage_service.clone();
+
+    let blocks = storage
+        .list_video_blocks(&session.blob_name)
+        .await
+        .map_err(|e| FindUploadSessionError::Storage(format!("Erro ao listar blocos: {}", e)))?;
+
+    // Blocos com tamanho diferente do esperado são tratados como ausentes
+    // para que o cliente os reenvie.
+    let received: BTreeSet<i32> = blocks
+        .iter()
+        .filter_map(|block| {
+            storage
+                .block_number_from_id(&block.block_id)
+                .filter(|n| *n >= 0 && *n < session.total_blocks)
+                .filter(|n| block.size as i64 == expected_block_size(&session, *n))
+        })
+        .collect();
+
+    let received_bytes = received
+        .iter()
+        .map(|n| expected_block_size(&session, *n) as u64)
+        .sum();
+
+    let missing_blocks = (0..session.total_blocks)
+        .filter(|n| !received.contains(n))
+        .collect();
+
+    Ok(FindUploadSessionOutputDTO {
+        session_id: session.id,
+        video_id: session.video_id,
+        status: session.status,
+        file_name: session.file_name,
+        total_size: session.total_size,
+        block_size: session.block_size,
+        total_blocks: session.total_blocks,
+        received_blocks: received.into_iter().collect(),
+        missing_blocks,
+        received_bytes,
+        expires_at: session.expires_at,
+    })
+}
@@ -0,0 +1,4 @@
+pub mod create_upload_session_service;
+pub mod finalize_upload_session_service;
+pub mod find_upload_session_service;
+pub mod upload_block_service;
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use axum::body::{to_bytes, Body};
+use chrono::Utc;
+use shared::{
+    enums::upload_session_status_enum::UploadSessionStatusEnum,
+    modules::{
+        app_state::AppState, database::schema::upload_sessions::Model as UploadSessionModel,
+        media_probe,
+    },
+};
+
+use crate::modules::upload_session::dto::{
+    io::upload_block_output_dto::UploadBlockOutputDTO,
+    route_params::upload_block_route_params_dto::UploadBlockRouteParamsDTO,
+};
+
+pub enum UploadBlockError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+    Conflict(String),
+    Expired(String),
+    Storage(String),
+}
+
+/// Todos os blocos têm `block_size` bytes, exceto o último, que leva o restante.
+pub fn expected_block_size(session: &UploadSessionModel, block_number: i32) -> i64 {
+    if block_number == session.total_blocks - 1 {
+        session.total_size - session.block_size * (session.total_blocks as i64 - 1)
+    } else {
+        session.block_size
+    }
+}
+
+pub async fn execute(
+    body: Body,
+    params: UploadBlockRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<UploadBlockOutputDTO, UploadBlockError> {
+    let session = state
+        .upload_session_repo
+        .find_by_id(&params.id)
+        .await
+        .map_err(|e| UploadBlockError::Database(format!("Erro ao buscar sessão: {}", e)))?
+        .ok_or_else(|| {
+            UploadBlockError::NotFound(format!("Sessão de upload {} não encontrada", params.id))
+        })?;
+
+    if UploadSessionStatusEnum::from(session.status.as_str()) != UploadSessionStatusEnum::PENDING {
+        return Err(UploadBlockError::Conflict(format!(
+            "A sessão de upload está com status {}",
+            session.status
+        )));
+    }
+
+    if session.expires_at < Utc::now().naive_utc() {
+        return Err(UploadBlockError::Expired(
+            "A sessão de upload expirou".to_string(),
+        ));
+    }
+
+    if params.block_number < 0 || params.block_number >= session.total_blocks {
+        return Err(UploadBlockError::Validation(format!(
+            "O número do bloco deve estar entre 0 e {}",
+            session.total_blocks - 1
+        )));
+    }
+
+    let expected_size = expected_block_size(&session, params.block_number) as usize;
+
+    let data = to_bytes(body, expected_size).await.map_err(|_| {
+        UploadBlockError::Validation(format!(
+            "O bloco {} excede o tamanho de {} bytes",
+            params.block_number, expected_size
+        ))
+    })?;
+
+    if data.len() != expected_size {
+        return Err(UploadBlockError::Validation(format!(
+            "O bloco {} deve ter {} bytes, recebido {}",
+            params.block_number,
+            expected_size,
+            data.len()
+        )));
+    }
+
+    // Rejeita cedo arquivos que não são vídeo, sem esperar o upload completo.
+    if params.block_number == 0 && media_probe::detect_format(&data).is_none() {
+        return Err(UploadBlockError::Validation(
+            "O arquivo não é um vídeo MP4, Matroska ou WebM".to_string(),
+        ));
+    }
+
+    let size = data.len();
+
+    state
+        .video_storage_service
+        .stage_video_block(&session.blob_name, params.block_number, data.to_vec())
+        .await
+        .map_err(|e| UploadBlockError::Storage(format!("Erro ao salvar bloco: {}", e)))?;
+
+    Ok(UploadBlockOutputDTO {
+        block_number: params.block_number,
+        size,
+    })
+}
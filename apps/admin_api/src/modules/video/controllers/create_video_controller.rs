@@ -55,6 +55,9 @@ pub async fn handler(
                 create_video_service::CreateVideoError::NotFound(msg) => {
                     (StatusCode::NOT_FOUND, msg)
                 }
+                create_video_service::CreateVideoError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
             };
             Err((
                 status,
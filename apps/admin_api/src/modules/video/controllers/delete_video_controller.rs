@@ -32,15 +32,12 @@ pub async fn handler(
         Ok(video) => Ok((
             StatusCode::OK,
             Json(ResponseInterface {
-                message: Some("Vídeo deletado com sucesso".to_string()),
+                message: Some("Vídeo movido para a lixeira".to_string()),
                 result: Some(video),
             }),
         )),
         Err(err) => {
             let (status, msg) = match err {
-                delete_video_service::DeleteVideoError::Storage(msg) => {
-                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
-                }
                 delete_video_service::DeleteVideoError::Database(msg) => {
                     (StatusCode::INTERNAL_SERVER_ERROR, msg)
                 }
@@ -0,0 +1,71 @@
+use crate::modules::video::{
+    dto::{
+        io::{
+            find_video_input_dto::FindVideoInputDTO, video_detail_output_dto::VideoDetailOutputDTO,
+        },
+        route_params::find_video_route_params_dto::FindVideoRouteParamsDTO,
+    },
+    services::find_video_service,
+};
+use axum::{
+    extract::Path,
+    http::{header, StatusCode},
+    Extension, Json,
+};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser, etag,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<FindVideoRouteParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        [(header::HeaderName, String); 1],
+        Json<ResponseInterface<VideoDetailOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input: FindVideoInputDTO = params.into();
+
+    match find_video_service::execute(input, state).await {
+        Ok(video) => Ok((
+            StatusCode::OK,
+            [(
+                header::ETAG,
+                etag::from_updated_at(video.id, video.updated_at),
+            )],
+            Json(ResponseInterface {
+                result: Some(video),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_video_service::FindVideoError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_video_service::FindVideoError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar vídeo".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod create_video_controller;
 pub mod delete_video_controller;
 pub mod find_many_video_controller;
+pub mod find_video_controller;
 pub mod update_video_controller;
+pub mod update_video_credits_controller;
 pub mod upload_video_controller;
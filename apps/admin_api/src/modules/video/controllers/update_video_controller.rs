@@ -5,11 +5,15 @@ use crate::modules::video::{
             update_video_output_dto::UpdateVideoOutputDTO,
         },
         request::update_video_request_dto::UpdateVideoRequestDTO,
-        route_params::upload_video_route_params_dto::UploadVideoRouteParamsDTO,
+        route_params::update_video_route_params_dto::UpdateVideoRouteParamsDTO,
     },
     services::update_video_service,
 };
-use axum::{extract::Path, http::StatusCode, Extension, Json};
+use axum::{
+    extract::Path,
+    http::{header, HeaderMap, StatusCode},
+    Extension, Json,
+};
 use macros::require_access;
 use serde_json::json;
 use shared::{
@@ -17,6 +21,7 @@ use shared::{
     modules::{
         app_state::AppState,
         auth::jwt_extractor::AuthenticatedUser,
+        etag,
         response_interface::ResponseInterface,
         validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
     },
@@ -28,22 +33,36 @@ use std::sync::Arc;
 pub async fn handler(
     Extension(state): Extension<Arc<AppState>>,
     AuthenticatedUser(user): AuthenticatedUser,
-    Path(params): Path<UploadVideoRouteParamsDTO>,
+    Path(params): Path<UpdateVideoRouteParamsDTO>,
+    headers: HeaderMap,
     payload: Json<UpdateVideoRequestDTO>,
 ) -> Result<
-    (StatusCode, Json<ResponseInterface<UpdateVideoOutputDTO>>),
+    (
+        StatusCode,
+        [(header::HeaderName, String); 1],
+        Json<ResponseInterface<UpdateVideoOutputDTO>>,
+    ),
     (StatusCode, Json<ValidationErrorResponse>),
 > {
     let ValidatedJson(payload) = validate_json(payload).await?;
 
-    let input: UpdateVideoInputDTO = (payload, params).into();
+    let if_match = headers
+        .get(header::IF_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let input: UpdateVideoInputDTO = (payload, params, if_match).into();
 
     match update_video_service::execute(input, state).await {
         Ok(video) => Ok((
-            StatusCode::CREATED,
+            StatusCode::OK,
+            [(
+                header::ETAG,
+                etag::from_updated_at(video.id, video.updated_at),
+            )],
             Json(ResponseInterface {
                 result: Some(video),
-                message: None,
+                message: Some("Vídeo atualizado com sucesso!".to_string()),
             }),
         )),
         Err(err) => {
@@ -57,6 +76,12 @@ pub async fn handler(
                 update_video_service::UpdateVideoError::NotFound(msg) => {
                     (StatusCode::NOT_FOUND, msg)
                 }
+                update_video_service::UpdateVideoError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+                update_video_service::UpdateVideoError::PreconditionFailed(msg) => {
+                    (StatusCode::PRECONDITION_FAILED, msg)
+                }
             };
             Err((
                 status,
@@ -0,0 +1,92 @@
+use crate::modules::video::{
+    dto::{
+        io::{
+            update_video_credits_input_dto::UpdateVideoCreditsInputDTO,
+            video_detail_output_dto::VideoDetailOutputDTO,
+        },
+        request::update_video_credits_request_dto::UpdateVideoCreditsRequestDTO,
+        route_params::update_video_credits_route_params_dto::UpdateVideoCreditsRouteParamsDTO,
+    },
+    services::update_video_credits_service,
+};
+use axum::{
+    extract::Path,
+    http::{header, HeaderMap, StatusCode},
+    Extension, Json,
+};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        etag,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<UpdateVideoCreditsRouteParamsDTO>,
+    headers: HeaderMap,
+    payload: Json<UpdateVideoCreditsRequestDTO>,
+) -> Result<
+    (
+        StatusCode,
+        [(header::HeaderName, String); 1],
+        Json<ResponseInterface<VideoDetailOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    let if_match = headers
+        .get(header::IF_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let input: UpdateVideoCreditsInputDTO = (payload, params, if_match).into();
+
+    match update_video_credits_service::execute(input, state).await {
+        Ok(video) => Ok((
+            StatusCode::OK,
+            [(
+                header::ETAG,
+                etag::from_updated_at(video.id, video.updated_at),
+            )],
+            Json(ResponseInterface {
+                result: Some(video),
+                message: Some("Créditos do vídeo atualizados com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                update_video_credits_service::UpdateVideoCreditsError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                update_video_credits_service::UpdateVideoCreditsError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                update_video_credits_service::UpdateVideoCreditsError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                update_video_credits_service::UpdateVideoCreditsError::PreconditionFailed(msg) => {
+                    (StatusCode::PRECONDITION_FAILED, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar créditos".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -5,7 +5,7 @@ use serde::Deserialize;
 pub struct CreateVideoInputDTO {
     pub title: String,
     pub description: Option<String>,
-    pub duration_seconds: i32,
+    pub duration_seconds: Option<i32>,
     pub release_year: Option<i32>,
 
     #[serde(default)]
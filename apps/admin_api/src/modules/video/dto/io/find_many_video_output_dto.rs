@@ -1,5 +1,10 @@
 use serde::Serialize;
 use shared::modules::database::repositories::dto::find_many_video_output_dto::FindManyVideoOutputDTO as RepoVideoDTO;
+use shared::modules::database::repositories::images_repository::CatalogImageUrls;
+
+use crate::modules::video::dto::io::video_detail_output_dto::{
+    VideoActorOutputDTO, VideoCategoryOutputDTO, VideoDirectorOutputDTO,
+};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct FindManyVideoOutputDTO {
@@ -13,6 +18,11 @@ pub struct FindManyVideoOutputDTO {
     pub episode_number: Option<i32>,
     pub season_number: Option<i32>,
     pub release_year: Option<i32>,
+    pub thumbnail_url: Option<String>,
+    pub categories: Vec<VideoCategoryOutputDTO>,
+    pub actors: Vec<VideoActorOutputDTO>,
+    pub directors: Vec<VideoDirectorOutputDTO>,
+    pub images: CatalogImageUrls,
 }
 
 impl From<RepoVideoDTO> for FindManyVideoOutputDTO {
@@ -28,6 +38,11 @@ impl From<RepoVideoDTO> for FindManyVideoOutputDTO {
             episode_number: v.episode_number,
             season_number: v.season_number,
             release_year: v.release_year,
+            thumbnail_url: v.thumbnail_url,
+            categories: Vec::new(),
+            actors: Vec::new(),
+            directors: Vec::new(),
+            images: CatalogImageUrls::new(),
         }
     }
 }
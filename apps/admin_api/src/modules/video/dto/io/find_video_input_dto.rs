@@ -0,0 +1,13 @@
+use crate::modules::video::dto::route_params::find_video_route_params_dto::FindVideoRouteParamsDTO;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindVideoInputDTO {
+    pub id: i32,
+}
+
+impl From<FindVideoRouteParamsDTO> for FindVideoInputDTO {
+    fn from(params: FindVideoRouteParamsDTO) -> Self {
+        Self { id: params.id }
+    }
+}
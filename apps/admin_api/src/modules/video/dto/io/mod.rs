@@ -3,5 +3,8 @@ pub mod create_video_output_dto;
 pub mod delete_video_input_dto;
 pub mod find_many_video_input_dto;
 pub mod find_many_video_output_dto;
+pub mod find_video_input_dto;
+pub mod update_video_credits_input_dto;
 pub mod update_video_input_dto;
 pub mod update_video_output_dto;
+pub mod video_detail_output_dto;
@@ -0,0 +1,40 @@
+use crate::modules::video::dto::request::update_video_credits_request_dto::{
+    UpdateVideoCreditsRequestDTO, VideoCastMemberRequestDTO,
+};
+use crate::modules::video::dto::route_params::update_video_credits_route_params_dto::UpdateVideoCreditsRouteParamsDTO;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct UpdateVideoCreditsInputDTO {
+    pub id: i32,
+    pub category_ids: Option<Vec<i32>>,
+    pub cast: Option<Vec<VideoCastMemberRequestDTO>>,
+    pub director_ids: Option<Vec<i32>>,
+
+    /// Valor do cabeçalho `If-Match`, quando enviado.
+    pub if_match: Option<String>,
+}
+
+impl
+    From<(
+        UpdateVideoCreditsRequestDTO,
+        UpdateVideoCreditsRouteParamsDTO,
+        Option<String>,
+    )> for UpdateVideoCreditsInputDTO
+{
+    fn from(
+        (body, params, if_match): (
+            UpdateVideoCreditsRequestDTO,
+            UpdateVideoCreditsRouteParamsDTO,
+            Option<String>,
+        ),
+    ) -> Self {
+        Self {
+            id: params.id,
+            category_ids: body.category_ids,
+            cast: body.cast,
+            director_ids: body.director_ids,
+            if_match,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::modules::video::dto::request::update_video_request_dto::UpdateVideoRequestDTO;
-use crate::modules::video::dto::route_params::upload_video_route_params_dto::UploadVideoRouteParamsDTO;
+use crate::modules::video::dto::route_params::update_video_route_params_dto::UpdateVideoRouteParamsDTO;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize, Default)]
@@ -10,7 +10,6 @@ pub struct UpdateVideoInputDTO {
     pub release_year: Option<i32>,
 
     #[serde(default)]
-    #[allow(dead_code)]
     pub thumbnail_url: Option<String>,
 
     #[serde(default)]
@@ -23,10 +22,25 @@ pub struct UpdateVideoInputDTO {
     pub season_number: Option<i32>,
 
     pub id: i32,
+
+    /// Valor do cabeçalho `If-Match`, quando enviado.
+    pub if_match: Option<String>,
 }
 
-impl From<(UpdateVideoRequestDTO, UploadVideoRouteParamsDTO)> for UpdateVideoInputDTO {
-    fn from((body, params): (UpdateVideoRequestDTO, UploadVideoRouteParamsDTO)) -> Self {
+impl
+    From<(
+        UpdateVideoRequestDTO,
+        UpdateVideoRouteParamsDTO,
+        Option<String>,
+    )> for UpdateVideoInputDTO
+{
+    fn from(
+        (body, params, if_match): (
+            UpdateVideoRequestDTO,
+            UpdateVideoRouteParamsDTO,
+            Option<String>,
+        ),
+    ) -> Self {
         Self {
             title: body.title,
             description: body.description,
@@ -38,6 +52,7 @@ impl From<(UpdateVideoRequestDTO, UploadVideoRouteParamsDTO)> for UpdateVideoInp
             episode_number: body.episode_number,
             season_number: body.season_number,
             id: params.id,
+            if_match,
         }
     }
 }
@@ -1,6 +1,8 @@
+use chrono::NaiveDateTime;
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct UpdateVideoOutputDTO {
     pub id: i32,
+    pub updated_at: NaiveDateTime,
 }
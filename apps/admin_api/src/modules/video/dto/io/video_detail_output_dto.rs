@@ -0,0 +1,132 @@
+use crate::modules::video_asset::dto::io::video_asset_output_dto::VideoAssetOutputDTO;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::repositories::images_repository::CatalogImageUrls;
+use shared::modules::database::schema::{
+    actors::Model as ActorModel, categories::Model as CategoryModel,
+    directors::Model as DirectorModel, series::Model as SerieModel,
+    video_actors::Model as VideoActorModel, videos::Model as VideoModel,
+};
+use shared::modules::maturity;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VideoSerieOutputDTO {
+    pub id: i32,
+    pub title: String,
+}
+
+impl From<SerieModel> for VideoSerieOutputDTO {
+    fn from(serie: SerieModel) -> Self {
+        Self {
+            id: serie.id,
+            title: serie.title,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VideoCategoryOutputDTO {
+    pub id: i32,
+    pub name: String,
+}
+
+impl From<CategoryModel> for VideoCategoryOutputDTO {
+    fn from(category: CategoryModel) -> Self {
+        Self {
+            id: category.id,
+            name: category.name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VideoActorOutputDTO {
+    pub id: i32,
+    pub name: String,
+    pub role_name: Option<String>,
+    pub is_lead: bool,
+}
+
+impl From<(VideoActorModel, ActorModel)> for VideoActorOutputDTO {
+    fn from((role, actor): (VideoActorModel, ActorModel)) -> Self {
+        Self {
+            id: actor.id,
+            name: actor.name,
+            role_name: role.role_name,
+            is_lead: role.is_lead,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VideoDirectorOutputDTO {
+    pub id: i32,
+    pub name: String,
+}
+
+impl From<DirectorModel> for VideoDirectorOutputDTO {
+    fn from(director: DirectorModel) -> Self {
+        Self {
+            id: director.id,
+            name: director.name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VideoDetailOutputDTO {
+    pub id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub duration_seconds: i32,
+    pub video_url: Option<String>,
+    pub trailer_url: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub is_available: bool,
+    pub rating: f64,
+    pub series_id: Option<i32>,
+    pub episode_number: Option<i32>,
+    pub season_number: Option<i32>,
+    pub release_year: Option<i32>,
+    /// Classificação própria; sem ela, episódios seguem a da série.
+    pub maturity_rating: Option<String>,
+    pub content_descriptors: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub serie: Option<VideoSerieOutputDTO>,
+    pub categories: Vec<VideoCategoryOutputDTO>,
+    pub actors: Vec<VideoActorOutputDTO>,
+    pub directors: Vec<VideoDirectorOutputDTO>,
+    pub assets: Vec<VideoAssetOutputDTO>,
+    pub images: CatalogImageUrls,
+}
+
+impl From<VideoModel> for VideoDetailOutputDTO {
+    fn from(video: VideoModel) -> Self {
+        Self {
+            id: video.id,
+            title: video.title,
+            description: video.description,
+            duration_seconds: video.duration_seconds,
+            video_url: video.video_url,
+            trailer_url: video.trailer_url,
+            thumbnail_url: video.thumbnail_url,
+            is_available: video.is_available,
+            rating: video.rating,
+            series_id: video.series_id,
+            episode_number: video.episode_number,
+            season_number: video.season_number,
+            release_year: video.release_year,
+            content_descriptors: maturity::descriptors(&video.content_descriptors),
+            maturity_rating: video.maturity_rating,
+            created_at: video.created_at,
+            updated_at: video.updated_at,
+            serie: None,
+            categories: Vec::new(),
+            actors: Vec::new(),
+            directors: Vec::new(),
+            assets: Vec::new(),
+            images: CatalogImageUrls::new(),
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod create_video_request_dto;
+pub mod update_video_credits_request_dto;
 pub mod update_video_request_dto;
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, Default)]
+pub struct VideoCastMemberRequestDTO {
+    #[validate(range(min = 1, message = "O ID do ator deve ser positivo"))]
+    pub actor_id: i32,
+
+    #[validate(length(max = 200, message = "O papel não pode ter mais de 200 caracteres"))]
+    pub role_name: Option<String>,
+
+    #[serde(default)]
+    pub is_lead: bool,
+}
+
+/// Listas omitidas ficam como estão; uma lista vazia remove todos os vínculos.
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct UpdateVideoCreditsRequestDTO {
+    pub category_ids: Option<Vec<i32>>,
+
+    #[validate(nested)]
+    pub cast: Option<Vec<VideoCastMemberRequestDTO>>,
+
+    pub director_ids: Option<Vec<i32>>,
+}
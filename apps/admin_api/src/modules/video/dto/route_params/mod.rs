@@ -1,2 +1,5 @@
 pub mod delete_video_route_params_dto;
+pub mod find_video_route_params_dto;
+pub mod update_video_credits_route_params_dto;
+pub mod update_video_route_params_dto;
 pub mod upload_video_route_params_dto;
@@ -1,7 +1,10 @@
 use crate::modules::video::dto::io::create_video_input_dto::CreateVideoInputDTO;
 use crate::modules::video::dto::io::create_video_output_dto::CreateVideoOutputDTO;
 use crate::AppState;
-use shared::modules::database::repositories::videos_repository::CreateVideoRequest;
+use shared::modules::database::repositories::videos_repository::{
+    episode_label, is_episode_taken, CreateVideoRequest,
+};
+use shared::modules::search;
 use shared::modules::validation::validate_release_year::validate_release_year;
 use std::sync::Arc;
 
@@ -9,6 +12,7 @@ pub enum CreateVideoError {
     Validation(String),
     Database(String),
     NotFound(String),
+    Conflict(String),
 }
 
 pub async fn execute(
@@ -35,8 +39,38 @@ pub async fn execute(
                 series_id
             )));
         }
+
+        if let Some(episode_number) = payload.episode_number {
+            let taken = state
+                .video_repo
+                .find_episode(series_id, payload.season_number, episode_number)
+                .await
+                .map_err(|e| {
+                    CreateVideoError::Database(format!("Erro ao buscar episódio: {}", e))
+                })?;
+
+            if let Some(taken) = taken {
+                return Err(CreateVideoError::Conflict(format!(
+                    "A série {} já tem o episódio {} (vídeo {})",
+                    series_id,
+                    episode_label(payload.season_number, episode_number),
+                    taken.id
+                )));
+            }
+        }
     }
 
+    let position =
+        payload
+            .series_id
+            .zip(payload.episode_number)
+            .map(|(series_id, episode_number)| {
+                (
+                    series_id,
+                    episode_label(payload.season_number, episode_number),
+                )
+            });
+
     let response = state
         .video_repo
         .create(CreateVideoRequest {
@@ -46,13 +80,22 @@ pub async fn execute(
             duration_seconds: payload.duration_seconds,
             is_available: payload.is_available,
             trailer_url: payload.trailer_url,
+            thumbnail_url: payload.thumbnail_url,
             series_id: payload.series_id,
             episode_number: payload.episode_number,
             season_number: payload.season_number,
             video_url: None,
         })
         .await
-        .map_err(|e| CreateVideoError::Database(format!("Erro ao criar: {}", e)))?;
+        .map_err(|e| match position {
+            // outro cadastro levou a posição depois da checagem acima
+            Some((series_id, label)) if is_episode_taken(&e) => CreateVideoError::Conflict(
+                format!("A série {} já tem o episódio {}", series_id, label),
+            ),
+            _ => CreateVideoError::Database(format!("Erro ao criar: {}", e)),
+        })?;
+
+    search::invalidate_suggestions(&state).await;
 
     Ok(CreateVideoOutputDTO { id: response.id })
 }
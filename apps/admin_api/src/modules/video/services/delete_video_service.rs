@@ -1,48 +1,37 @@
 use std::sync::Arc;
 
 use shared::modules::app_state::AppState;
+use shared::modules::search;
 
 use crate::modules::video::dto::io::delete_video_input_dto::DeleteVideoInputDTO;
 
 pub enum DeleteVideoError {
     Database(String),
     NotFound(String),
-    Storage(String),
 }
 
+/// Move o vídeo para a lixeira. Arquivos, histórico e avaliações só são
+/// removidos na limpeza definitiva (`DELETE /admin/trash/video/{id}` ou ao
+/// fim da retenção).
 pub async fn execute(
     input: DeleteVideoInputDTO,
     state: Arc<AppState>,
 ) -> Result<(), DeleteVideoError> {
-    let video = state
+    let trashed = state
         .video_repo
-        .find_by_id(input.id)
-        .await
-        .map_err(|e| DeleteVideoError::Database(format!("Erro ao buscar vídeo: {}", e)))?
-        .ok_or_else(|| {
-            DeleteVideoError::NotFound(format!("Vídeo com id {} não encontrado", input.id))
-        })?;
-
-    state
-        .video_repo
-        .delete(input.id)
+        .soft_delete(input.id)
         .await
         .map_err(|e| DeleteVideoError::Database(format!("Erro ao deletar vídeo: {}", e)))?;
 
-    if let Some(old_url) = &video.video_url {
-        if let Ok(old_blob_name) = state.video_storage_service.get_blob_name_from_url(old_url) {
-            if let Err(e) = state
-                .video_storage_service
-                .delete_video(&old_blob_name)
-                .await
-            {
-                DeleteVideoError::Storage(format!(
-                    "Falha ao deletar vídeo {} antigo '{}': {:?}",
-                    video.title, old_blob_name, e
-                ));
-            }
-        }
+    if !trashed {
+        return Err(DeleteVideoError::NotFound(format!(
+            "Vídeo com id {} não encontrado",
+            input.id
+        )));
     }
 
+    search::invalidate_suggestions(&state).await;
+    search::index::sync_videos(&state, &[input.id]).await;
+
     Ok(())
 }
@@ -1,6 +1,9 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use shared::enums::image_owner_enum::ImageOwnerEnum;
 use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::images_repository::catalog_image_urls;
 
 use crate::modules::video::dto::io::{
     find_many_video_input_dto::FindManyVideoInputDTO,
@@ -20,7 +23,7 @@ pub async fn execute(
     }: FindManyVideoInputDTO,
     state: Arc<AppState>,
 ) -> Result<Vec<FindManyVideoOutputDTO>, FindManyVideoError> {
-    let videos = state
+    let mut videos = state
         .video_repo
         .find_many_videos(name.as_deref(), serie_id, skip, take)
         .await
@@ -29,5 +32,60 @@ pub async fn execute(
         .map(Into::into)
         .collect::<Vec<FindManyVideoOutputDTO>>();
 
+    let video_ids: Vec<i32> = videos.iter().map(|video| video.id).collect();
+
+    let mut images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::VIDEO, &video_ids)
+        .await
+        .map(catalog_image_urls)
+        .map_err(|e| FindManyVideoError::Database(format!("Erro ao buscar imagens: {}", e)))?;
+
+    let map_err = |e| FindManyVideoError::Database(format!("Erro ao buscar créditos: {}", e));
+
+    let categories = state
+        .category_repo
+        .find_by_videos(&video_ids)
+        .await
+        .map_err(map_err)?;
+    let actors = state
+        .actor_repo
+        .find_by_videos(&video_ids)
+        .await
+        .map_err(map_err)?;
+    let directors = state
+        .director_repo
+        .find_by_videos(&video_ids)
+        .await
+        .map_err(map_err)?;
+
+    let positions: HashMap<i32, usize> = videos
+        .iter()
+        .enumerate()
+        .map(|(position, video)| (video.id, position))
+        .collect();
+
+    for (video_id, category) in categories {
+        if let Some(&position) = positions.get(&video_id) {
+            videos[position].categories.push(category.into());
+        }
+    }
+    for (video_actor, actor) in actors {
+        if let Some(&position) = positions.get(&video_actor.video_id) {
+            videos[position].actors.push((video_actor, actor).into());
+        }
+    }
+    for (video_id, director) in directors {
+        if let Some(&position) = positions.get(&video_id) {
+            videos[position].directors.push(director.into());
+        }
+    }
+
+    for video in &mut videos {
+        if let Some(urls) = images.remove(&video.id) {
+            video.images = urls;
+        }
+    }
+
     Ok(videos)
 }
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use shared::enums::image_owner_enum::ImageOwnerEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::database::repositories::images_repository::catalog_image_urls;
+
+use crate::modules::video::dto::io::{
+    find_video_input_dto::FindVideoInputDTO, video_detail_output_dto::VideoDetailOutputDTO,
+};
+
+pub enum FindVideoError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Vídeo completo para edição: série, categorias, elenco, direção, arquivos
+/// e imagens.
+pub async fn execute(
+    input: FindVideoInputDTO,
+    state: Arc<AppState>,
+) -> Result<VideoDetailOutputDTO, FindVideoError> {
+    let map_err = |e| FindVideoError::Database(format!("Erro ao buscar vídeo: {}", e));
+
+    let video = state
+        .video_repo
+        .find_by_id(input.id)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| {
+            FindVideoError::NotFound(format!("Vídeo com id {} não encontrado", input.id))
+        })?;
+
+    let serie = match video.series_id {
+        Some(series_id) => state
+            .serie_repo
+            .find_by_id(series_id)
+            .await
+            .map_err(map_err)?,
+        None => None,
+    };
+
+    let categories = state
+        .category_repo
+        .find_by_videos(&[video.id])
+        .await
+        .map_err(map_err)?;
+    let actors = state
+        .actor_repo
+        .find_by_videos(&[video.id])
+        .await
+        .map_err(map_err)?;
+    let directors = state
+        .director_repo
+        .find_by_videos(&[video.id])
+        .await
+        .map_err(map_err)?;
+    let assets = state
+        .video_asset_repo
+        .find_by_video(video.id, None)
+        .await
+        .map_err(map_err)?;
+    let images = state
+        .image_repo
+        .find_by_owner(ImageOwnerEnum::VIDEO, video.id)
+        .await
+        .map(catalog_image_urls)
+        .map_err(map_err)?;
+
+    let mut detail: VideoDetailOutputDTO = video.into();
+    detail.serie = serie.map(Into::into);
+    detail.categories = categories.into_iter().map(|(_, c)| c.into()).collect();
+    detail.actors = actors.into_iter().map(Into::into).collect();
+    detail.directors = directors.into_iter().map(|(_, d)| d.into()).collect();
+    detail.assets = assets.into_iter().map(Into::into).collect();
+    if let Some(urls) = images.into_values().next() {
+        detail.images = urls;
+    }
+
+    Ok(detail)
+}
@@ -1,5 +1,7 @@
 pub mod create_video_service;
 pub mod delete_video_service;
 pub mod find_many_video_service;
+pub mod find_video_service;
+pub mod update_video_credits_service;
 pub mod update_video_service;
 pub mod upload_video_service;
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use shared::modules::{
+    app_state::AppState,
+    database::repositories::videos_repository::{SetVideoCreditsRequest, VideoCastMember},
+    etag, search,
+};
+
+use crate::modules::video::dto::io::{
+    find_video_input_dto::FindVideoInputDTO,
+    update_video_credits_input_dto::UpdateVideoCreditsInputDTO,
+    video_detail_output_dto::VideoDetailOutputDTO,
+};
+use crate::modules::video::services::find_video_service::{self, FindVideoError};
+
+pub enum UpdateVideoCreditsError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+    PreconditionFailed(String),
+}
+
+/// Ids repetidos na mesma lista, na ordem em que aparecem.
+fn duplicated_ids(ids: impl IntoIterator<Item = i32>) -> Vec<i32> {
+    let mut seen = HashSet::new();
+    let mut duplicated = Vec::new();
+
+    for id in ids {
+        if !seen.insert(id) && !duplicated.contains(&id) {
+            duplicated.push(id);
+        }
+    }
+
+    duplicated
+}
+
+/// Ids enviados que não existem no banco.
+fn missing_ids(requested: &[i32], found: impl IntoIterator<Item = i32>) -> Vec<i32> {
+    let found: HashSet<i32> = found.into_iter().collect();
+
+    requested
+        .iter()
+        .copied()
+        .filter(|id| !found.contains(id))
+        .collect()
+}
+
+/// Substitui categorias, elenco e direção do vídeo numa única transação.
+/// Cada lista enviada troca todos os vínculos daquele tipo; as omitidas
+/// ficam como estão.
+pub async fn execute(
+    input: UpdateVideoCreditsInputDTO,
+    state: Arc<AppState>,
+) -> Result<VideoDetailOutputDTO, UpdateVideoCreditsError> {
+    let map_err = |e| UpdateVideoCreditsError::Database(format!("Erro ao buscar créditos: {}", e));
+
+    let video = state
+        .video_repo
+        .find_by_id(input.id)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| {
+            UpdateVideoCreditsError::NotFound(format!("Vídeo com id {} não encontrado", input.id))
+        })?;
+
+    if let Some(if_match) = &input.if_match {
+        if !etag::if_match_satisfied(if_match, &etag::from_updated_at(video.id, video.updated_at)) {
+            return Err(UpdateVideoCreditsError::PreconditionFailed(
+                "O vídeo foi alterado por outra pessoa. Recarregue e aplique as mudanças novamente"
+                    .to_string(),
+            ));
+        }
+    }
+
+    if let Some(category_ids) = &input.category_ids {
+        let duplicated = duplicated_ids(category_ids.iter().copied());
+        if !duplicated.is_empty() {
+            return Err(UpdateVideoCreditsError::Validation(format!(
+                "Categorias repetidas: {:?}",
+                duplicated
+            )));
+        }
+
+        let found = state
+            .category_repo
+            .find_by_ids(category_ids)
+            .await
+            .map_err(map_err)?;
+        let missing = missing_ids(category_ids, found.iter().map(|c| c.id));
+        if !missing.is_empty() {
+            return Err(UpdateVideoCreditsError::NotFound(format!(
+                "Categorias não encontradas: {:?}",
+                missing
+            )));
+        }
+    }
+
+    if let Some(cast) = &input.cast {
+        let actor_ids: Vec<i32> = cast.iter().map(|member| member.actor_id).collect();
+
+        let duplicated = duplicated_ids(actor_ids.iter().copied());
+        if !duplicated.is_empty() {
+            return Err(UpdateVideoCreditsError::Validation(format!(
+                "Atores repetidos no elenco: {:?}",
+                duplicated
+            )));
+        }
+
+        let found = state
+            .actor_repo
+            .find_by_ids(&actor_ids)
+            .await
+            .map_err(map_err)?;
+        let missing = missing_ids(&actor_ids, found.iter().map(|a| a.id));
+        if !missing.is_empty() {
+            return Err(UpdateVideoCreditsError::NotFound(format!(
+                "Atores não encontrados: {:?}",
+                missing
+            )));
+        }
+    }
+
+    if let Some(director_ids) = &input.director_ids {
+        let duplicated = duplicated_ids(director_ids.iter().copied());
+        if !duplicated.is_empty() {
+            return Err(UpdateVideoCreditsError::Validation(format!(
+                "Diretores repetidos: {:?}",
+                duplicated
+            )));
+        }
+
+        let found = state
+            .director_repo
+            .find_by_ids(director_ids)
+            .await
+            .map_err(map_err)?;
+        let missing = missing_ids(director_ids, found.iter().map(|d| d.id));
+        if !missing.is_empty() {
+            return Err(UpdateVideoCreditsError::NotFound(format!(
+                "Diretores não encontrados: {:?}",
+                missing
+            )));
+        }
+    }
+
+    state
+        .video_repo
+        .set_credits(
+            video.id,
+            SetVideoCreditsRequest {
+                category_ids: input.category_ids,
+                cast: input.cast.map(|cast| {
+                    cast.into_iter()
+                        .map(|member| VideoCastMember {
+                            actor_id: member.actor_id,
+                            role_name: member.role_name,
+                            is_lead: member.is_lead,
+                        })
+                        .collect()
+                }),
+                director_ids: input.director_ids,
+            },
+        )
+        .await
+        .map_err(|e| {
+            UpdateVideoCreditsError::Database(format!("Erro ao atualizar créditos: {}", e))
+        })?;
+
+    search::index::sync_videos(&state, &[video.id]).await;
+
+    find_video_service::execute(FindVideoInputDTO { id: video.id }, state)
+        .await
+        .map_err(|err| match err {
+            FindVideoError::Database(msg) => UpdateVideoCreditsError::Database(msg),
+            FindVideoError::NotFound(msg) => UpdateVideoCreditsError::NotFound(msg),
+        })
+}
@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
 use shared::modules::{
-    app_state::AppState, database::repositories::videos_repository::UpdateVideoRequest,
+    app_state::AppState,
+    database::repositories::videos_repository::{
+        episode_label, is_episode_taken, UpdateVideoRequest,
+    },
+    etag, search,
     validation::validate_release_year::validate_release_year,
 };
 
@@ -13,8 +17,16 @@ pub enum UpdateVideoError {
     Validation(String),
     Database(String),
     NotFound(String),
+    Conflict(String),
+    PreconditionFailed(String),
 }
 
+const CONCURRENT_EDIT_MESSAGE: &str =
+    "O vídeo foi alterado por outra pessoa. Recarregue e aplique as mudanças novamente";
+
+/// Atualiza apenas os campos enviados. Com `If-Match`, a edição só é aceita
+/// se o vídeo ainda estiver na versão lida pelo cliente; sem ele, vale a
+/// versão lida aqui, o que ainda impede sobrescrever uma gravação simultânea.
 pub async fn execute(
     input: UpdateVideoInputDTO,
     state: Arc<AppState>,
@@ -32,17 +44,48 @@ pub async fn execute(
         .await
         .map_err(|e| UpdateVideoError::Database(format!("Erro ao buscar vídeo: {}", e)))?;
 
-    if video.is_none() {
+    let Some(video) = video else {
         return Err(UpdateVideoError::NotFound(format!(
             "Vídeo com id {} não encontrada",
             input.id
         )));
+    };
+
+    if let Some(if_match) = &input.if_match {
+        if !etag::if_match_satisfied(if_match, &etag::from_updated_at(video.id, video.updated_at)) {
+            return Err(UpdateVideoError::PreconditionFailed(
+                CONCURRENT_EDIT_MESSAGE.to_string(),
+            ));
+        }
+    }
+
+    let season_number = input.season_number.or(video.season_number);
+    let position = video
+        .series_id
+        .zip(input.episode_number.or(video.episode_number));
+
+    if let Some((series_id, episode_number)) = position {
+        let taken = state
+            .video_repo
+            .find_episode(series_id, season_number, episode_number)
+            .await
+            .map_err(|e| UpdateVideoError::Database(format!("Erro ao buscar episódio: {}", e)))?;
+
+        if let Some(taken) = taken.filter(|taken| taken.id != video.id) {
+            return Err(UpdateVideoError::Conflict(format!(
+                "A série {} já tem o episódio {} (vídeo {})",
+                series_id,
+                episode_label(season_number, episode_number),
+                taken.id
+            )));
+        }
     }
 
-    let video_id = state
+    let updated = state
         .video_repo
-        .update(
+        .update_if_unmodified(
             input.id,
+            video.updated_at,
             UpdateVideoRequest {
                 title: input.title,
                 duration_seconds: input.duration_seconds,
@@ -52,13 +95,31 @@ pub async fn execute(
                 release_year: input.release_year,
                 description: input.description,
                 trailer_url: input.trailer_url,
+                thumbnail_url: input.thumbnail_url,
                 series_id: None,
                 video_url: None,
                 rating: None,
             },
         )
         .await
-        .map_err(|e| UpdateVideoError::Database(format!("Erro ao atualizar {}", e)))?;
+        .map_err(|e| match position {
+            // outro vídeo levou a posição depois da checagem acima
+            Some((series_id, episode_number)) if is_episode_taken(&e) => {
+                UpdateVideoError::Conflict(format!(
+                    "A série {} já tem o episódio {}",
+                    series_id,
+                    episode_label(season_number, episode_number)
+                ))
+            }
+            _ => UpdateVideoError::Database(format!("Erro ao atualizar {}", e)),
+        })?
+        .ok_or_else(|| UpdateVideoError::PreconditionFailed(CONCURRENT_EDIT_MESSAGE.to_string()))?;
+
+    search::invalidate_suggestions(&state).await;
+    search::index::sync_videos(&state, &[updated.id]).await;
 
-    Ok(UpdateVideoOutputDTO { id: video_id })
+    Ok(UpdateVideoOutputDTO {
+        id: updated.id,
+        updated_at: updated.updated_at,
+    })
 }
@@ -3,7 +3,9 @@ use crate::modules::video::dto::route_params::upload_video_route_params_dto::Upl
 use axum::extract::Multipart;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use shared::modules::database::repositories::media_assets_repository::CreateMediaAssetRequest;
 use shared::modules::database::repositories::videos_repository::UpdateVideoRequest;
+use shared::modules::media_probe::ProbeError;
 use std::sync::Arc;
 use tokio_util::io::StreamReader;
 
@@ -44,31 +46,80 @@ pub async fn execute(
         let max_file_size = 50 * 1024 * 1024;
         let storage = state.video_storage_service.clone();
 
-        let (blob_url, _size) = storage
+        let saved = storage
             .save_video_file(reader, &file_name, &content_type, max_file_size)
             .await
             .map_err(|e| UploadVideoError::Database(format!("Erro ao salvar arquivo: {}", e)))?;
 
-        if let Some(old_url) = &video.video_url {
-            if let Ok(old_blob_name) = storage.get_blob_name_from_url(old_url) {
-                if let Err(e) = storage.delete_video(&old_blob_name).await {
-                    eprintln!("Falha ao deletar vídeo antigo '{}': {:?}", old_blob_name, e);
+        let probe = match storage.probe_video(&saved.blob_name, saved.size).await {
+            Ok(probe) => probe,
+            Err(e) => {
+                if let Err(delete_err) = storage.delete_video(&saved.blob_name).await {
+                    tracing::warn!(
+                        "Falha ao descartar arquivo inválido '{}': {:?}",
+                        saved.blob_name,
+                        delete_err
+                    );
                 }
+                return Err(match e {
+                    ProbeError::Io(e) => {
+                        UploadVideoError::Database(format!("Erro ao inspecionar vídeo: {}", e))
+                    }
+                    e => UploadVideoError::Validation(e.to_string()),
+                });
             }
-        }
+        };
+
+        // O media asset é registrado primeiro: se falhar, o blob recém-enviado
+        // ainda não é referenciado por nada e pode ser descartado. O
+        // `VideoSourceReplaced` gravado junto leva o vídeo ao índice de busca e
+        // à fila de transcodificação, como no upload em sessões.
+        let replaced = match state
+            .video_asset_repo
+            .replace_source(
+                video.id,
+                CreateMediaAssetRequest {
+                    container: storage.container_name().to_string(),
+                    storage_key: saved.blob_name.clone(),
+                    url: saved.url.clone(),
+                    original_filename: Some(file_name),
+                    content_type: probe.format.mime_type().to_string(),
+                    size_bytes: saved.size as i64,
+                    sha256: saved.sha256,
+                    probe: Some(probe),
+                },
+            )
+            .await
+        {
+            Ok(replaced) => replaced,
+            Err(e) => {
+                if let Err(delete_err) = storage.delete_video(&saved.blob_name).await {
+                    tracing::warn!(
+                        "Falha ao descartar arquivo '{}': {:?}",
+                        saved.blob_name,
+                        delete_err
+                    );
+                }
+                return Err(UploadVideoError::Database(format!(
+                    "Erro ao registrar media asset: {}",
+                    e
+                )));
+            }
+        };
 
         state
             .video_repo
             .update(
                 video.id,
                 UpdateVideoRequest {
-                    video_url: Some(blob_url),
+                    video_url: Some(saved.url),
                     title: None,
                     description: None,
                     duration_seconds: None,
                     release_year: None,
                     rating: None,
                     trailer_url: None,
+                    thumbnail_url: None,
                     is_available: None,
                     series_id: None,
                     episode_number: None,
@@ -79,6 +130,31 @@ pub async fn execute(
             .map_err(|e| {
                 UploadVideoError::Database(format!("Erro ao atualizar vídeo no banco: {}", e))
             })?;
+
+        for previous in &replaced.previous {
+            if let Err(e) = storage.delete_asset_blob(previous).await {
+                tracing::warn!(
+                    "Falha ao deletar vídeo antigo '{}': {:?}",
+                    previous.storage_key,
+                    e
+                );
+            }
+        }
+
+        // Vídeos enviados antes dos media assets só têm a URL como referência.
+        if replaced.previous.is_empty() {
+            if let Some(old_url) = &video.video_url {
+                if let Ok(old_blob_name) = storage.get_blob_name_from_url(old_url) {
+                    if let Err(e) = storage.delete_video(&old_blob_name).await {
+                        tracing::warn!(
+                            "Falha ao deletar vídeo antigo '{}': {:?}",
+                            old_blob_name,
+                            e
+                        );
+                    }
+                }
+            }
+        }
     }
 
     Ok(())
@@ -0,0 +1,73 @@
+use crate::modules::video_asset::{
+    dto::{
+        io::{
+            create_video_asset_input_dto::CreateVideoAssetInputDTO,
+            video_asset_output_dto::VideoAssetOutputDTO,
+        },
+        request::create_video_asset_request_dto::CreateVideoAssetRequestDTO,
+        route_params::video_assets_route_params_dto::VideoAssetsRouteParamsDTO,
+    },
+    services::create_video_asset_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<VideoAssetsRouteParamsDTO>,
+    payload: Json<CreateVideoAssetRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<VideoAssetOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    let input: CreateVideoAssetInputDTO = (payload, params).into();
+
+    match create_video_asset_service::execute(input, state).await {
+        Ok(asset) => Ok((
+            StatusCode::CREATED,
+            Json(ResponseInterface {
+                result: Some(asset),
+                message: Some("Asset vinculado com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                create_video_asset_service::CreateVideoAssetError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                create_video_asset_service::CreateVideoAssetError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                create_video_asset_service::CreateVideoAssetError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                create_video_asset_service::CreateVideoAssetError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao vincular asset".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
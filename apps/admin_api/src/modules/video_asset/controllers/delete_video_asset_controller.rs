@@ -0,0 +1,57 @@
+use crate::modules::video_asset::{
+    dto::{
+        io::delete_video_asset_input_dto::DeleteVideoAssetInputDTO,
+        route_params::video_asset_route_params_dto::VideoAssetRouteParamsDTO,
+    },
+    services::delete_video_asset_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<VideoAssetRouteParamsDTO>,
+) -> Result<(StatusCode, Json<ResponseInterface<()>>), (StatusCode, Json<ValidationErrorResponse>)>
+{
+    let input: DeleteVideoAssetInputDTO = params.into();
+
+    match delete_video_asset_service::execute(input, state).await {
+        Ok(asset) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                message: Some("Asset desvinculado com sucesso".to_string()),
+                result: Some(asset),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                delete_video_asset_service::DeleteVideoAssetError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                delete_video_asset_service::DeleteVideoAssetError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao desvincular asset".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::modules::video_asset::{
+    dto::{
+        io::{
+            find_video_assets_input_dto::FindVideoAssetsInputDTO,
+            video_asset_output_dto::VideoAssetOutputDTO,
+        },
+        query_params::find_video_assets_query_params_dto::FindVideoAssetsQueryParamsDTO,
+        route_params::video_assets_route_params_dto::VideoAssetsRouteParamsDTO,
+    },
+    services::find_video_assets_service,
+};
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Extension, Json,
+};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(AccessGroupEnum::ADMIN, AccessGroupEnum::SUPER_ADMIN)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<VideoAssetsRouteParamsDTO>,
+    Query(query): Query<FindVideoAssetsQueryParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<Vec<VideoAssetOutputDTO>>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input: FindVideoAssetsInputDTO = (query, params).into();
+
+    match find_video_assets_service::execute(input, state).await {
+        Ok(assets) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(assets),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_video_assets_service::FindVideoAssetsError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                find_video_assets_service::FindVideoAssetsError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_video_assets_service::FindVideoAssetsError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar os assets do vídeo".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::modules::video_asset::dto::{
+    request::create_video_asset_request_dto::CreateVideoAssetRequestDTO,
+    route_params::video_assets_route_params_dto::VideoAssetsRouteParamsDTO,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct CreateVideoAssetInputDTO {
+    pub video_id: i32,
+    pub media_asset_id: i32,
+    pub kind: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub bitrate_kbps: Option<i32>,
+    pub codec: Option<String>,
+    pub language: Option<String>,
+}
+
+impl From<(CreateVideoAssetRequestDTO, VideoAssetsRouteParamsDTO)> for CreateVideoAssetInputDTO {
+    fn from((body, params): (CreateVideoAssetRequestDTO, VideoAssetsRouteParamsDTO)) -> Self {
+        Self {
+            video_id: params.id,
+            media_asset_id: body.media_asset_id,
+            kind: body.kind,
+            width: body.width,
+            height: body.height,
+            bitrate_kbps: body.bitrate_kbps,
+            codec: body.codec,
+            language: body.language,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::modules::video_asset::dto::route_params::video_asset_route_params_dto::VideoAssetRouteParamsDTO;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct DeleteVideoAssetInputDTO {
+    pub id: i32,
+}
+
+impl From<VideoAssetRouteParamsDTO> for DeleteVideoAssetInputDTO {
+    fn from(params: VideoAssetRouteParamsDTO) -> Self {
+        Self { id: params.id }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::modules::video_asset::dto::{
+    query_params::find_video_assets_query_params_dto::FindVideoAssetsQueryParamsDTO,
+    route_params::video_assets_route_params_dto::VideoAssetsRouteParamsDTO,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FindVideoAssetsInputDTO {
+    pub video_id: i32,
+    pub kind: Option<String>,
+}
+
+impl From<(FindVideoAssetsQueryParamsDTO, VideoAssetsRouteParamsDTO)> for FindVideoAssetsInputDTO {
+    fn from((query, params): (FindVideoAssetsQueryParamsDTO, VideoAssetsRouteParamsDTO)) -> Self {
+        Self {
+            video_id: params.id,
+            kind: query.kind,
+        }
+    }
+}
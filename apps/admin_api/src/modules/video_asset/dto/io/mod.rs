@@ -0,0 +1,4 @@
+pub mod create_video_asset_input_dto;
+pub mod delete_video_asset_input_dto;
+pub mod find_video_assets_input_dto;
+pub mod video_asset_output_dto;
@@ -0,0 +1,41 @@
+use serde::Serialize;
+use shared::modules::database::schema::{
+    media_assets::Model as MediaAssetModel, video_assets::Model as VideoAssetModel,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VideoAssetOutputDTO {
+    pub id: i32,
+    pub video_id: i32,
+    pub media_asset_id: i32,
+    pub kind: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub bitrate_kbps: Option<i32>,
+    pub codec: Option<String>,
+    pub language: Option<String>,
+    pub storage_key: Option<String>,
+    pub content_type: Option<String>,
+    pub size_bytes: Option<i64>,
+    pub status: Option<String>,
+}
+
+impl From<(VideoAssetModel, Option<MediaAssetModel>)> for VideoAssetOutputDTO {
+    fn from((asset, media): (VideoAssetModel, Option<MediaAssetModel>)) -> Self {
+        Self {
+            id: asset.id,
+            video_id: asset.video_id,
+            media_asset_id: asset.media_asset_id,
+            kind: asset.kind,
+            width: asset.width,
+            height: asset.height,
+            bitrate_kbps: asset.bitrate_kbps,
+            codec: asset.codec,
+            language: asset.language,
+            storage_key: media.as_ref().map(|m| m.storage_key.clone()),
+            content_type: media.as_ref().map(|m| m.content_type.clone()),
+            size_bytes: media.as_ref().map(|m| m.size_bytes),
+            status: media.map(|m| m.status),
+        }
+    }
+}
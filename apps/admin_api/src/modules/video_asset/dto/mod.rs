@@ -0,0 +1,4 @@
+pub mod io;
+pub mod query_params;
+pub mod request;
+pub mod route_params;
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct FindVideoAssetsQueryParamsDTO {
+    #[validate(length(min = 1, message = "O tipo não pode ser vazio"))]
+    pub kind: Option<String>,
+}
@@ -0,0 +1 @@
+pub mod find_video_assets_query_params_dto;
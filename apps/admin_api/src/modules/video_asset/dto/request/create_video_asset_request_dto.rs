@@ -0,0 +1,34 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct CreateVideoAssetRequestDTO {
+    #[validate(range(min = 1, message = "O ID do media asset deve ser positivo"))]
+    pub media_asset_id: i32,
+
+    #[validate(length(min = 1, message = "O tipo do asset é obrigatório"))]
+    pub kind: String,
+
+    #[validate(range(min = 1, message = "A largura deve ser positiva"))]
+    pub width: Option<i32>,
+
+    #[validate(range(min = 1, message = "A altura deve ser positiva"))]
+    pub height: Option<i32>,
+
+    #[validate(range(min = 1, message = "O bitrate deve ser positivo"))]
+    pub bitrate_kbps: Option<i32>,
+
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "O codec deve ter entre 1 e 50 caracteres"
+    ))]
+    pub codec: Option<String>,
+
+    #[validate(length(
+        min = 2,
+        max = 35,
+        message = "O idioma deve ser uma tag BCP 47 (ex: pt-BR)"
+    ))]
+    pub language: Option<String>,
+}
@@ -0,0 +1 @@
+pub mod create_video_asset_request_dto;
@@ -0,0 +1,2 @@
+pub mod video_asset_route_params_dto;
+pub mod video_assets_route_params_dto;
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::video_asset_kind_enum::VideoAssetKindEnum,
+    modules::{
+        app_state::AppState,
+        database::repositories::video_assets_repository::CreateVideoAssetRequest, search,
+    },
+};
+
+use crate::modules::video_asset::dto::io::{
+    create_video_asset_input_dto::CreateVideoAssetInputDTO,
+    video_asset_output_dto::VideoAssetOutputDTO,
+};
+
+pub enum CreateVideoAssetError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+    Conflict(String),
+}
+
+pub async fn execute(
+    input: CreateVideoAssetInputDTO,
+    state: Arc<AppState>,
+) -> Result<VideoAssetOutputDTO, CreateVideoAssetError> {
+    let kind = VideoAssetKindEnum::try_from(input.kind.as_str())
+        .map_err(CreateVideoAssetError::Validation)?;
+
+    if kind == VideoAssetKindEnum::SOURCE {
+        return Err(CreateVideoAssetError::Validation(
+            "O arquivo original é definido pelo upload do vídeo".to_string(),
+        ));
+    }
+
+    if kind == VideoAssetKindEnum::SUBTITLE && input.language.is_none() {
+        return Err(CreateVideoAssetError::Validation(
+            "Legendas precisam informar o idioma".to_string(),
+        ));
+    }
+
+    state
+        .video_repo
+        .find_by_id(input.video_id)
+        .await
+        .map_err(|e| CreateVideoAssetError::Database(format!("Erro ao buscar vídeo: {}", e)))?
+        .ok_or_else(|| {
+            CreateVideoAssetError::NotFound(format!(
+                "Vídeo com id {} não encontrado",
+                input.video_id
+            ))
+        })?;
+
+    let media_asset = state
+        .media_asset_repo
+        .find_by_id(input.media_asset_id)
+        .await
+        .map_err(|e| CreateVideoAssetError::Database(format!("Erro ao buscar media asset: {}", e)))?
+        .ok_or_else(|| {
+            CreateVideoAssetError::NotFound(format!(
+                "Media asset com id {} não encontrado",
+                input.media_asset_id
+            ))
+        })?;
+
+    let already_linked = state
+        .video_asset_repo
+        .find_by_video(input.video_id, None)
+        .await
+        .map_err(|e| CreateVideoAssetError::Database(format!("Erro ao buscar assets: {}", e)))?
+        .iter()
+        .any(|(asset, _)| asset.media_asset_id == media_asset.id);
+
+    if already_linked {
+        return Err(CreateVideoAssetError::Conflict(format!(
+            "Media asset {} já está vinculado ao vídeo {}",
+            media_asset.id, input.video_id
+        )));
+    }
+
+    let asset = state
+        .video_asset_repo
+        .create(CreateVideoAssetRequest {
+            video_id: input.video_id,
+            media_asset_id: media_asset.id,
+            kind,
+            width: input.width,
+            height: input.height,
+            bitrate_kbps: input.bitrate_kbps,
+            codec: input.codec,
+            language: input.language,
+        })
+        .await
+        .map_err(|e| CreateVideoAssetError::Database(format!("Erro ao criar asset: {}", e)))?;
+
+    // novas renditions podem mudar o filtro de plano premium
+    search::index::sync_videos(&state, &[input.video_id]).await;
+
+    Ok((asset, Some(media_asset)).into())
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use shared::modules::{app_state::AppState, search};
+
+use crate::modules::video_asset::dto::io::delete_video_asset_input_dto::DeleteVideoAssetInputDTO;
+
+pub enum DeleteVideoAssetError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Remove apenas o vínculo com o vídeo; o media asset continua registrado.
+pub async fn execute(
+    input: DeleteVideoAssetInputDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteVideoAssetError> {
+    let (asset, _) = state
+        .video_asset_repo
+        .find_by_id(input.id)
+        .await
+        .map_err(|e| DeleteVideoAssetError::Database(format!("Erro ao buscar asset: {}", e)))?
+        .ok_or_else(|| {
+            DeleteVideoAssetError::NotFound(format!("Asset com id {} não encontrado", input.id))
+        })?;
+
+    state
+        .video_asset_repo
+        .delete(input.id)
+        .await
+        .map_err(|e| DeleteVideoAssetError::Database(format!("Erro ao deletar asset: {}", e)))?;
+
+    search::index::sync_videos(&state, &[asset.video_id]).await;
+
+    Ok(())
+}
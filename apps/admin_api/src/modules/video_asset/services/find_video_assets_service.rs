@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use shared::{enums::video_asset_kind_enum::VideoAssetKindEnum, modules::app_state::AppState};
+
+use crate::modules::video_asset::dto::io::{
+    find_video_assets_input_dto::FindVideoAssetsInputDTO,
+    video_asset_output_dto::VideoAssetOutputDTO,
+};
+
+pub enum FindVideoAssetsError {
+    Validation(String),
+    Database(String),
+    NotFound(String),
+}
+
+pub async fn execute(
+    input: FindVideoAssetsInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<VideoAssetOutputDTO>, FindVideoAssetsError> {
+    let kind = input
+        .kind
+        .as_deref()
+        .map(VideoAssetKindEnum::try_from)
+        .transpose()
+        .map_err(FindVideoAssetsError::Validation)?;
+
+    state
+        .video_repo
+        .find_by_id(input.video_id)
+        .await
+        .map_err(|e| FindVideoAssetsError::Database(format!("Erro ao buscar vídeo: {}", e)))?
+        .ok_or_else(|| {
+            FindVideoAssetsError::NotFound(format!(
+                "Vídeo com id {} não encontrado",
+                input.video_id
+            ))
+        })?;
+
+    let assets = state
+        .video_asset_repo
+        .find_by_video(input.video_id, kind)
+        .await
+        .map_err(|e| FindVideoAssetsError::Database(format!("Erro ao buscar assets: {}", e)))?
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<VideoAssetOutputDTO>>();
+
+    Ok(assets)
+}
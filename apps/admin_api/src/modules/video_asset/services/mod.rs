@@ -0,0 +1,3 @@
+pub mod create_video_asset_service;
+pub mod delete_video_asset_service;
+pub mod find_video_assets_service;
@@ -1,10 +1,62 @@
-use crate::modules::serie::controllers::create_serie_controller;
+use crate::modules::actor::controllers::{
+    create_actor_controller, delete_actor_controller, find_actor_controller,
+    find_many_actor_controller, update_actor_controller,
+};
+use crate::modules::analytics::controllers::trending_analytics_controller;
+use crate::modules::availability::controllers::{
+    find_availability_controller, preview_availability_controller, update_availability_controller,
+};
+use crate::modules::catalog::controllers::{export_catalog_controller, import_catalog_controller};
+use crate::modules::category::controllers::{
+    create_category_controller, delete_category_controller, find_category_controller,
+    find_many_category_controller, update_category_controller,
+};
+use crate::modules::director::controllers::{
+    create_director_controller, delete_director_controller, find_director_controller,
+    find_many_director_controller, update_director_controller,
+};
+use crate::modules::home_row::controllers::{
+    create_home_row_controller, delete_home_row_controller, find_home_row_controller,
+    find_many_home_row_controller, update_home_row_controller,
+};
+use crate::modules::image::controllers::{
+    delete_image_controller, find_images_controller, upload_image_controller,
+};
+use crate::modules::maturity_rating::controllers::assign_maturity_rating_controller;
+use crate::modules::media_asset::controllers::{
+    find_many_media_asset_controller, verify_media_asset_controller,
+};
+use crate::modules::serie::controllers::{
+    create_serie_controller, delete_serie_controller, find_many_serie_controller,
+    find_serie_controller, reorder_serie_episodes_controller, update_serie_controller,
+};
+use crate::modules::subtitle::controllers::{
+    delete_subtitle_controller, upload_subtitle_controller,
+};
+use crate::modules::transcode_job::controllers::{
+    create_transcode_job_controller, find_many_transcode_job_controller,
+    find_transcode_job_controller, retry_transcode_job_controller,
+};
+use crate::modules::trash::controllers::{
+    find_many_trash_controller, purge_trash_item_controller, restore_trash_item_controller,
+};
+use crate::modules::upload_session::controllers::{
+    create_upload_session_controller, finalize_upload_session_controller,
+    find_upload_session_controller, upload_block_controller,
+};
 use crate::modules::video::controllers::{
     create_video_controller, delete_video_controller, find_many_video_controller,
+    find_video_controller, update_video_controller, update_video_credits_controller,
     upload_video_controller,
 };
-use axum::routing::{delete, get};
+use crate::modules::video_asset::controllers::{
+    create_video_asset_controller, delete_video_asset_controller, find_video_assets_controller,
+};
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{delete, get, put};
 use axum::{routing::post, Router};
+use shared::modules::catalog::MAX_IMPORT_SIZE;
+use shared::modules::image_processing::MAX_IMAGE_SIZE;
 
 pub fn create_router() -> Router {
     Router::new()
@@ -13,11 +65,191 @@ pub fn create_router() -> Router {
             Router::new()
                 .route("/", post(create_video_controller::handler))
                 .route("/upload/{id}", post(upload_video_controller::handler))
-                .route("/{id}", delete(delete_video_controller::handler))
+                .route(
+                    "/{id}/upload-session",
+                    post(create_upload_session_controller::handler),
+                )
+                .route(
+                    "/{id}/asset",
+                    get(find_video_assets_controller::handler)
+                        .post(create_video_asset_controller::handler),
+                )
+                .route("/{id}/subtitle", post(upload_subtitle_controller::handler))
+                .route(
+                    "/{id}/subtitle/{language}",
+                    delete(delete_subtitle_controller::handler),
+                )
+                .route(
+                    "/{id}/transcode",
+                    post(create_transcode_job_controller::handler),
+                )
+                .route(
+                    "/{id}",
+                    get(find_video_controller::handler)
+                        .patch(update_video_controller::handler)
+                        .delete(delete_video_controller::handler),
+                )
+                .route(
+                    "/{id}/credits",
+                    put(update_video_credits_controller::handler),
+                )
                 .route("/", get(find_many_video_controller::handler)),
         )
+        .nest(
+            "/upload-session",
+            Router::new()
+                .route("/{id}", get(find_upload_session_controller::handler))
+                .route(
+                    "/{id}/block/{block_number}",
+                    put(upload_block_controller::handler),
+                )
+                .route(
+                    "/{id}/finalize",
+                    post(finalize_upload_session_controller::handler),
+                ),
+        )
+        .nest(
+            "/video-asset",
+            Router::new().route("/{id}", delete(delete_video_asset_controller::handler)),
+        )
+        .nest(
+            "/media-asset",
+            Router::new()
+                .route("/", get(find_many_media_asset_controller::handler))
+                .route("/verify", post(verify_media_asset_controller::handler)),
+        )
+        .nest(
+            "/transcode-job",
+            Router::new()
+                .route("/", get(find_many_transcode_job_controller::handler))
+                .route("/{id}", get(find_transcode_job_controller::handler))
+                .route("/{id}/retry", post(retry_transcode_job_controller::handler)),
+        )
+        .nest(
+            "/image",
+            Router::new()
+                .route("/{owner}/{id}", get(find_images_controller::handler))
+                .route(
+                    "/{owner}/{id}/{kind}",
+                    put(upload_image_controller::handler)
+                        .delete(delete_image_controller::handler)
+                        // folga para os cabeçalhos do multipart
+                        .layer(DefaultBodyLimit::max(MAX_IMAGE_SIZE + 64 * 1024)),
+                ),
+        )
         .nest(
             "/serie",
-            Router::new().route("/", post(create_serie_controller::handler)),
+            Router::new()
+                .route(
+                    "/",
+                    post(create_serie_controller::handler).get(find_many_serie_controller::handler),
+                )
+                .route(
+                    "/{id}",
+                    get(find_serie_controller::handler)
+                        .patch(update_serie_controller::handler)
+                        .delete(delete_serie_controller::handler),
+                )
+                .route(
+                    "/{id}/episodes/order",
+                    put(reorder_serie_episodes_controller::handler),
+                ),
+        )
+        .nest(
+            "/category",
+            Router::new()
+                .route(
+                    "/",
+                    post(create_category_controller::handler)
+                        .get(find_many_category_controller::handler),
+                )
+                .route(
+                    "/{id}",
+                    get(find_category_controller::handler)
+                        .patch(update_category_controller::handler)
+                        .delete(delete_category_controller::handler),
+                ),
+        )
+        .nest(
+            "/actor",
+            Router::new()
+                .route(
+                    "/",
+                    post(create_actor_controller::handler).get(find_many_actor_controller::handler),
+                )
+                .route(
+                    "/{id}",
+                    get(find_actor_controller::handler)
+                        .patch(update_actor_controller::handler)
+                        .delete(delete_actor_controller::handler),
+                ),
+        )
+        .nest(
+            "/director",
+            Router::new()
+                .route(
+                    "/",
+                    post(create_director_controller::handler)
+                        .get(find_many_director_controller::handler),
+                )
+                .route(
+                    "/{id}",
+                    get(find_director_controller::handler)
+                        .patch(update_director_controller::handler)
+                        .delete(delete_director_controller::handler),
+                ),
+        )
+        .nest(
+            "/catalog",
+            Router::new()
+                .route(
+                    "/import",
+                    post(import_catalog_controller::handler)
+                        .layer(DefaultBodyLimit::max(MAX_IMPORT_SIZE)),
+                )
+                .route("/export", get(export_catalog_controller::handler)),
+        )
+        .nest(
+            "/availability",
+            Router::new()
+                .route("/preview", get(preview_availability_controller::handler))
+                .route(
+                    "/{type}/{id}",
+                    get(find_availability_controller::handler)
+                        .put(update_availability_controller::handler),
+                ),
+        )
+        .nest(
+            "/maturity-rating",
+            Router::new().route("/", put(assign_maturity_rating_controller::handler)),
+        )
+        .nest(
+            "/home-row",
+            Router::new()
+                .route(
+                    "/",
+                    post(create_home_row_controller::handler)
+                        .get(find_many_home_row_controller::handler),
+                )
+                .route(
+                    "/{id}",
+                    get(find_home_row_controller::handler)
+                        .patch(update_home_row_controller::handler)
+                        .delete(delete_home_row_controller::handler),
+                ),
+        )
+        .nest(
+            "/analytics",
+            Router::new().route("/trending", get(trending_analytics_controller::handler)),
+        )
+        .nest(
+            "/trash",
+            Router::new()
+                .route("/", get(find_many_trash_controller::handler))
+                .route("/{type}/{id}", delete(purge_trash_item_controller::handler))
+                .route(
+                    "/{type}/{id}/restore",
+                    post(restore_trash_item_controller::handler),
+                ),
         )
 }
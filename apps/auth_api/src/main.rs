@@ -39,6 +39,13 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("   - POST  /auth/users/login");
     tracing::info!("   - POST  /auth/users/register");
     tracing::info!("   - POST  /auth/users/refresh-token");
+    tracing::info!("   - GET   /auth/profiles");
+    tracing::info!("   - POST  /auth/profiles");
+    tracing::info!("   - PATCH /auth/profiles/{{id}}");
+    tracing::info!("   - DELETE /auth/profiles/{{id}}");
+    tracing::info!("   - POST  /auth/profiles/{{id}}/select");
+    tracing::info!("   - GET   /auth/sessions");
+    tracing::info!("   - DELETE /auth/sessions/{{session_id}}");
 
     // TCP socket
     let socket = Socket::new(Domain::IPV4, Type::STREAM, Some(Protocol::TCP))?;
@@ -1 +1,3 @@
 pub mod auth;
+pub mod profile;
+pub mod session;
@@ -0,0 +1,71 @@
+use crate::modules::profile::{
+    dto::{
+        io::profile_output_dto::ProfileOutputDTO,
+        request::create_profile_request_dto::CreateProfileRequestDTO,
+    },
+    services::create_profile_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    payload: Json<CreateProfileRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<ProfileOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match create_profile_service::execute(user, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::CREATED,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Perfil criado com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                create_profile_service::CreateProfileError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                create_profile_service::CreateProfileError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+                create_profile_service::CreateProfileError::Forbidden(msg) => {
+                    (StatusCode::FORBIDDEN, msg)
+                }
+                create_profile_service::CreateProfileError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao criar o perfil".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::modules::profile::{
+    dto::io::profile_output_dto::ProfileListOutputDTO, services::find_many_profile_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<ProfileListOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    match find_many_profile_service::execute(user, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_many_profile_service::FindManyProfileError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar os perfis".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
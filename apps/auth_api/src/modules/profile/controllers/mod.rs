@@ -0,0 +1,5 @@
+pub mod create_profile_controller;
+pub mod delete_profile_controller;
+pub mod find_many_profile_controller;
+pub mod select_profile_controller;
+pub mod update_profile_controller;
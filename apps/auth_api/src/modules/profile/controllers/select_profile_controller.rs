@@ -0,0 +1,73 @@
+use crate::modules::profile::{
+    dto::{
+        io::select_profile_output_dto::SelectProfileOutputDTO,
+        request::profile_pin_request_dto::ProfilePinRequestDTO,
+        route_params::profile_route_params_dto::ProfileRouteParamsDTO,
+    },
+    services::select_profile_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<ProfileRouteParamsDTO>,
+    payload: Option<Json<ProfilePinRequestDTO>>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<SelectProfileOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    // o corpo só é preciso em perfis com PIN
+    let payload = payload.map(|Json(payload)| payload).unwrap_or_default();
+
+    match select_profile_service::execute(user, params, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                select_profile_service::SelectProfileError::Forbidden(msg) => {
+                    (StatusCode::FORBIDDEN, msg)
+                }
+                select_profile_service::SelectProfileError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                select_profile_service::SelectProfileError::TooManyAttempts(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, msg)
+                }
+                select_profile_service::SelectProfileError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao entrar no perfil".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
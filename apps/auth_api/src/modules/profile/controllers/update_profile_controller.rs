@@ -0,0 +1,79 @@
+use crate::modules::profile::{
+    dto::{
+        io::profile_output_dto::ProfileOutputDTO,
+        request::update_profile_request_dto::UpdateProfileRequestDTO,
+        route_params::profile_route_params_dto::ProfileRouteParamsDTO,
+    },
+    services::update_profile_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(params): Path<ProfileRouteParamsDTO>,
+    payload: Json<UpdateProfileRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<ProfileOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match update_profile_service::execute(user, params, payload, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Perfil atualizado com sucesso!".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                update_profile_service::UpdateProfileError::Validation(msg) => {
+                    (StatusCode::BAD_REQUEST, msg)
+                }
+                update_profile_service::UpdateProfileError::Conflict(msg) => {
+                    (StatusCode::CONFLICT, msg)
+                }
+                update_profile_service::UpdateProfileError::Forbidden(msg) => {
+                    (StatusCode::FORBIDDEN, msg)
+                }
+                update_profile_service::UpdateProfileError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                update_profile_service::UpdateProfileError::TooManyAttempts(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, msg)
+                }
+                update_profile_service::UpdateProfileError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao atualizar o perfil".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
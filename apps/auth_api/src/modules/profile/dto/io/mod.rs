@@ -0,0 +1,2 @@
+pub mod profile_output_dto;
+pub mod select_profile_output_dto;
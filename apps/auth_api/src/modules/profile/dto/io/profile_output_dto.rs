@@ -0,0 +1,40 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::modules::database::schema::profiles::Model as ProfileModel;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileOutputDTO {
+    pub id: i32,
+    pub name: String,
+    pub avatar_url: Option<String>,
+    pub maturity_level: String,
+    pub is_kids: bool,
+    pub is_default: bool,
+    pub has_pin: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl From<ProfileModel> for ProfileOutputDTO {
+    fn from(profile: ProfileModel) -> Self {
+        Self {
+            id: profile.id,
+            name: profile.name,
+            avatar_url: profile.avatar_url,
+            maturity_level: profile.maturity_level,
+            is_kids: profile.is_kids,
+            is_default: profile.is_default,
+            has_pin: profile.pin_hash.is_some(),
+            created_at: profile.created_at,
+            updated_at: profile.updated_at,
+        }
+    }
+}
+
+/// Perfis da conta, com o limite do plano e o perfil em uso pelo token.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileListOutputDTO {
+    pub max_profiles: u64,
+    pub active_profile_id: Option<i32>,
+    pub items: Vec<ProfileOutputDTO>,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+use crate::modules::profile::dto::io::profile_output_dto::ProfileOutputDTO;
+
+/// Tokens com o perfil escolhido.
+#[derive(Debug, Clone, Serialize)]
+pub struct SelectProfileOutputDTO {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub profile: ProfileOutputDTO,
+}
@@ -0,0 +1,3 @@
+pub mod io;
+pub mod request;
+pub mod route_params;
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateProfileRequestDTO {
+    #[validate(length(min = 1, max = 50, message = "O nome deve ter entre 1 e 50 caracteres"))]
+    pub name: String,
+
+    #[validate(url(message = "URL do avatar inválida"))]
+    pub avatar_url: Option<String>,
+
+    /// Classificação máxima: `L`, `10`, `12`, `14`, `16` ou `18`. Sem ela,
+    /// perfis infantis ficam em 12 e os demais em 18.
+    pub maturity_level: Option<String>,
+
+    pub is_kids: Option<bool>,
+
+    /// PIN de 4 dígitos pedido para entrar no perfil.
+    #[validate(length(equal = 4, message = "O PIN deve ter 4 dígitos"))]
+    pub pin: Option<String>,
+}
@@ -0,0 +1,3 @@
+pub mod create_profile_request_dto;
+pub mod profile_pin_request_dto;
+pub mod update_profile_request_dto;
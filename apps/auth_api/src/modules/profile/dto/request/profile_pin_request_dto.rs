@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// Corpo de quem entra ou apaga um perfil; o PIN só é pedido nos protegidos.
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct ProfilePinRequestDTO {
+    /// PIN do perfil, ou o do perfil principal ao sair de um perfil infantil
+    /// para um sem PIN.
+    pub pin: Option<String>,
+}
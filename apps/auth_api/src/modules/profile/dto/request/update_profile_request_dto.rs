@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct UpdateProfileRequestDTO {
+    #[validate(length(min = 1, max = 50, message = "O nome deve ter entre 1 e 50 caracteres"))]
+    pub name: Option<String>,
+
+    /// Vazio remove o avatar.
+    pub avatar_url: Option<String>,
+
+    pub maturity_level: Option<String>,
+
+    pub is_kids: Option<bool>,
+
+    /// Novo PIN de 4 dígitos.
+    #[validate(length(equal = 4, message = "O PIN deve ter 4 dígitos"))]
+    pub pin: Option<String>,
+
+    pub remove_pin: Option<bool>,
+
+    /// PIN atual, obrigatório para alterar um perfil protegido.
+    pub current_pin: Option<String>,
+}
@@ -0,0 +1 @@
+pub mod profile_route_params_dto;
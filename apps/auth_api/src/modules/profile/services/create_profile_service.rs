@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{app_state::AppState, auth::User, profile::active_profile},
+};
+
+use crate::modules::profile::{
+    dto::{
+        io::profile_output_dto::ProfileOutputDTO,
+        request::create_profile_request_dto::CreateProfileRequestDTO,
+    },
+    services::profile_definition_service::{self, ProfileDefinitionError, ProfileDraft},
+};
+
+pub enum CreateProfileError {
+    Validation(String),
+    Conflict(String),
+    Forbidden(String),
+    Database(String),
+}
+
+impl From<ProfileDefinitionError> for CreateProfileError {
+    fn from(err: ProfileDefinitionError) -> Self {
+        match err {
+            ProfileDefinitionError::Validation(msg) => CreateProfileError::Validation(msg),
+            ProfileDefinitionError::Conflict(msg) => CreateProfileError::Conflict(msg),
+            ProfileDefinitionError::Forbidden(msg) | ProfileDefinitionError::Locked(msg) => {
+                CreateProfileError::Forbidden(msg)
+            }
+            ProfileDefinitionError::Database(msg) => CreateProfileError::Database(msg),
+        }
+    }
+}
+
+/// Cria um perfil na conta, respeitando o limite do plano.
+pub async fn execute(
+    user: User,
+    payload: CreateProfileRequestDTO,
+    state: Arc<AppState>,
+) -> Result<ProfileOutputDTO, CreateProfileError> {
+    let map_err = |e| CreateProfileError::Database(format!("Erro ao criar o perfil: {}", e));
+
+    let active = active_profile(&state, &user).await.map_err(map_err)?;
+    profile_definition_service::ensure_can_manage(active.as_ref())?;
+
+    let pin_hash = payload
+        .pin
+        .as_deref()
+        .map(profile_definition_service::hash_pin)
+        .transpose()?;
+
+    let request = profile_definition_service::build(
+        &state,
+        &user.id,
+        None,
+        ProfileDraft {
+            name: payload.name,
+            avatar_url: payload.avatar_url,
+            maturity_level: payload.maturity_level,
+            is_kids: payload.is_kids.unwrap_or(false),
+            pin_hash,
+        },
+    )
+    .await?;
+
+    // o limite é conferido na mesma transação do insert
+    let limit = AccessGroupEnum::profile_limit(&user.access_groups);
+    let profile = state
+        .profile_repo
+        .create_within_limit(&user.id, request, limit)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| {
+            CreateProfileError::Forbidden(format!("Seu plano permite até {} perfis", limit))
+        })?;
+
+    Ok(profile.into())
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use shared::modules::{app_state::AppState, auth::User, profile::active_profile};
+
+use crate::modules::profile::{
+    dto::{
+        request::profile_pin_request_dto::ProfilePinRequestDTO,
+        route_params::profile_route_params_dto::ProfileRouteParamsDTO,
+    },
+    services::profile_definition_service::{self, ProfileDefinitionError},
+};
+
+pub enum DeleteProfileError {
+    Validation(String),
+    Forbidden(String),
+    NotFound(String),
+    TooManyAttempts(String),
+    Database(String),
+}
+
+impl From<ProfileDefinitionError> for DeleteProfileError {
+    fn from(err: ProfileDefinitionError) -> Self {
+        match err {
+            ProfileDefinitionError::Validation(msg) | ProfileDefinitionError::Conflict(msg) => {
+                DeleteProfileError::Validation(msg)
+            }
+            ProfileDefinitionError::Forbidden(msg) => DeleteProfileError::Forbidden(msg),
+            ProfileDefinitionError::Locked(msg) => DeleteProfileError::TooManyAttempts(msg),
+            ProfileDefinitionError::Database(msg) => DeleteProfileError::Database(msg),
+        }
+    }
+}
+
+/// Apaga o perfil junto com o histórico, a lista e as avaliações dele. O
+/// perfil principal fica enquanto a conta existir.
+pub async fn execute(
+    user: User,
+    params: ProfileRouteParamsDTO,
+    payload: ProfilePinRequestDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteProfileError> {
+    let map_err = |e| DeleteProfileError::Database(format!("Erro ao deletar o perfil: {}", e));
+
+    let active = active_profile(&state, &user).await.map_err(map_err)?;
+    profile_definition_service::ensure_can_manage(active.as_ref())?;
+
+    let profile = state
+        .profile_repo
+        .find_owned(&user.id, params.id)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| {
+            DeleteProfileError::NotFound(format!("Perfil com id {} não encontrado", params.id))
+        })?;
+
+    if profile.is_default {
+        return Err(DeleteProfileError::Validation(
+            "O perfil principal não pode ser removido".to_string(),
+        ));
+    }
+
+    profile_definition_service::unlock_profile(&state, &profile, payload.pin.as_deref()).await?;
+
+    state
+        .profile_repo
+        .delete(profile.id)
+        .await
+        .map_err(map_err)?;
+
+    Ok(())
+}
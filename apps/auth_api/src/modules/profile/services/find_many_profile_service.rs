@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{app_state::AppState, auth::User},
+};
+
+use crate::modules::profile::dto::io::profile_output_dto::ProfileListOutputDTO;
+
+pub enum FindManyProfileError {
+    Database(String),
+}
+
+pub async fn execute(
+    user: User,
+    state: Arc<AppState>,
+) -> Result<ProfileListOutputDTO, FindManyProfileError> {
+    let profiles = state
+        .profile_repo
+        .find_by_user(&user.id)
+        .await
+        .map_err(|e| FindManyProfileError::Database(format!("Erro ao buscar os perfis: {}", e)))?;
+
+    // tokens sem perfil valem pelo principal
+    let active_profile_id = user.profile_id.or_else(|| {
+        profiles
+            .iter()
+            .find(|profile| profile.is_default)
+            .map(|profile| profile.id)
+    });
+
+    Ok(ProfileListOutputDTO {
+        max_profiles: AccessGroupEnum::profile_limit(&user.access_groups),
+        active_profile_id,
+        items: profiles.into_iter().map(Into::into).collect(),
+    })
+}
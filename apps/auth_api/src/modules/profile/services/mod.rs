@@ -0,0 +1,6 @@
+pub mod create_profile_service;
+pub mod delete_profile_service;
+pub mod find_many_profile_service;
+pub mod profile_definition_service;
+pub mod select_profile_service;
+pub mod update_profile_service;
@@ -0,0 +1,351 @@
+use bcrypt::{hash, verify, DEFAULT_COST};
+use shared::{
+    enums::maturity_rating_enum::MaturityRatingEnum,
+    modules::{
+        app_state::AppState,
+        database::{
+            repositories::profiles_repository::ProfileRequest,
+            schema::profiles::Model as ProfileModel,
+        },
+        profile::{limit_pin_attempts, pin_locked_message, PinAttempt},
+    },
+};
+
+pub enum ProfileDefinitionError {
+    Validation(String),
+    Conflict(String),
+    Forbidden(String),
+    /// PIN bloqueado por excesso de tentativas.
+    Locked(String),
+    Database(String),
+}
+
+/// Campos do perfil antes da validação, já com os valores atuais quando é
+/// uma edição. `pin_hash` já vem pronto de `hash_pin`.
+pub struct ProfileDraft {
+    pub name: String,
+    pub avatar_url: Option<String>,
+    pub maturity_level: Option<String>,
+    pub is_kids: bool,
+    pub pin_hash: Option<String>,
+}
+
+/// Valida nome e classificação do perfil de `user_id`. Perfis infantis não
+/// passam de `MaturityRatingEnum::KIDS_MAX`, e o principal, que é o dono da
+/// conta, não pode ser infantil.
+pub async fn build(
+    state: &AppState,
+    user_id: &str,
+    current: Option<&ProfileModel>,
+    draft: ProfileDraft,
+) -> Result<ProfileRequest, ProfileDefinitionError> {
+    let name = draft.name.trim().to_string();
+    if name.is_empty() {
+        return Err(ProfileDefinitionError::Validation(
+            "O nome do perfil não pode ser vazio".to_string(),
+        ));
+    }
+
+    if current.is_some_and(|profile| profile.is_default) && draft.is_kids {
+        return Err(ProfileDefinitionError::Validation(
+            "O perfil principal não pode ser infantil".to_string(),
+        ));
+    }
+
+    let maturity_level = match draft.maturity_level {
+        Some(level) => MaturityRatingEnum::try_from(level.as_str())
+            .map_err(ProfileDefinitionError::Validation)?,
+        None if draft.is_kids => MaturityRatingEnum::KIDS_MAX,
+        None => MaturityRatingEnum::A18,
+    };
+
+    if draft.is_kids && maturity_level > MaturityRatingEnum::KIDS_MAX {
+        return Err(ProfileDefinitionError::Validation(format!(
+            "Perfis infantis vão até a classificação {}",
+            MaturityRatingEnum::KIDS_MAX.as_str()
+        )));
+    }
+
+    let taken = state
+        .profile_repo
+        .name_taken(user_id, &name, current.map(|profile| profile.id))
+        .await
+        .map_err(|e| {
+            ProfileDefinitionError::Database(format!("Erro ao buscar os perfis: {}", e))
+        })?;
+    if taken {
+        return Err(ProfileDefinitionError::Conflict(format!(
+            "Já existe um perfil chamado '{}'",
+            name
+        )));
+    }
+
+    Ok(ProfileRequest {
+        name,
+        avatar_url: draft.avatar_url.filter(|url| !url.trim().is_empty()),
+        maturity_level,
+        is_kids: draft.is_kids,
+        pin_hash: draft.pin_hash,
+    })
+}
+
+/// Confere o formato do PIN e devolve o bcrypt dele.
+pub fn hash_pin(pin: &str) -> Result<String, ProfileDefinitionError> {
+    if pin.len() != 4 || !pin.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ProfileDefinitionError::Validation(
+            "O PIN deve ter 4 dígitos".to_string(),
+        ));
+    }
+
+    hash(pin.as_bytes(), DEFAULT_COST)
+        .map_err(|_| ProfileDefinitionError::Database("Erro ao criptografar o PIN".to_string()))
+}
+
+/// Perfis infantis não criam, alteram nem apagam perfis; é o que impede um
+/// perfil infantil de afrouxar os próprios limites ou abrir um adulto sem PIN.
+/// Token cujo perfil foi apagado também fica de fora até escolher outro.
+pub fn ensure_can_manage(active: Option<&ProfileModel>) -> Result<(), ProfileDefinitionError> {
+    match active {
+        Some(profile) if !profile.is_kids => Ok(()),
+        Some(_) => Err(ProfileDefinitionError::Forbidden(
+            "Perfis infantis não podem gerenciar perfis".to_string(),
+        )),
+        None => Err(ProfileDefinitionError::Forbidden(
+            "O perfil do token não existe mais. Escolha um perfil".to_string(),
+        )),
+    }
+}
+
+/// PIN a conferir antes de entrar em um perfil.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SelectPin {
+    None,
+    /// O PIN do próprio perfil escolhido.
+    Profile,
+    /// O PIN do perfil principal, que é o da conta.
+    Account,
+}
+
+/// O perfil protegido pede o próprio PIN. Sem PIN, sair de um perfil infantil
+/// para um com mais conteúdo pede o PIN da conta.
+pub fn select_pin(active: Option<&ProfileModel>, target: &ProfileModel) -> SelectPin {
+    if target.pin_hash.is_some() {
+        return SelectPin::Profile;
+    }
+
+    let relaxes = match active {
+        Some(active) if active.is_kids => {
+            !target.is_kids
+                || maturity_of(target, MaturityRatingEnum::A18)
+                    > maturity_of(active, MaturityRatingEnum::L)
+        }
+        Some(_) => false,
+        None => true,
+    };
+
+    if relaxes {
+        SelectPin::Account
+    } else {
+        SelectPin::None
+    }
+}
+
+// classificação inválida no banco cai no lado seguro: `fallback`
+fn maturity_of(profile: &ProfileModel, fallback: MaturityRatingEnum) -> MaturityRatingEnum {
+    MaturityRatingEnum::try_from(profile.maturity_level.as_str()).unwrap_or(fallback)
+}
+
+/// Confere o PIN do perfil principal. Sem PIN cadastrado nele não há como
+/// liberar, e o responsável precisa entrar de novo na conta.
+fn check_account_pin(
+    owner: Option<&ProfileModel>,
+    pin: Option<&str>,
+) -> Result<(), ProfileDefinitionError> {
+    let Some(pin_hash) = owner.and_then(|owner| owner.pin_hash.as_deref()) else {
+        return Err(ProfileDefinitionError::Forbidden(
+            "Cadastre um PIN no perfil principal para sair do perfil infantil".to_string(),
+        ));
+    };
+
+    let pin = pin.ok_or_else(|| {
+        ProfileDefinitionError::Forbidden("Informe o PIN do perfil principal".to_string())
+    })?;
+
+    verify_pin(pin, pin_hash)
+}
+
+/// Libera o perfil sem PIN, ou com o PIN certo.
+fn check_pin(profile: &ProfileModel, pin: Option<&str>) -> Result<(), ProfileDefinitionError> {
+    let Some(pin_hash) = profile.pin_hash.as_deref() else {
+        return Ok(());
+    };
+
+    let pin = pin.ok_or_else(|| {
+        ProfileDefinitionError::Forbidden(format!("O perfil '{}' pede o PIN", profile.name))
+    })?;
+
+    verify_pin(pin, pin_hash)
+}
+
+/// [`check_pin`] com limite de tentativas: cada PIN conferido conta uma
+/// tentativa do perfil, e o acerto zera a contagem.
+pub async fn unlock_profile(
+    state: &AppState,
+    profile: &ProfileModel,
+    pin: Option<&str>,
+) -> Result<(), ProfileDefinitionError> {
+    let guarded = Some(profile).filter(|profile| profile.pin_hash.is_some() && pin.is_some());
+    limit_attempts(state, guarded, || check_pin(profile, pin)).await
+}
+
+/// [`check_account_pin`] com limite de tentativas, contadas no perfil principal.
+pub async fn unlock_with_account_pin(
+    state: &AppState,
+    owner: Option<&ProfileModel>,
+    pin: Option<&str>,
+) -> Result<(), ProfileDefinitionError> {
+    let guarded = owner.filter(|owner| owner.pin_hash.is_some() && pin.is_some());
+    limit_attempts(state, guarded, || check_account_pin(owner, pin)).await
+}
+
+async fn limit_attempts(
+    state: &AppState,
+    guarded: Option<&ProfileModel>,
+    check: impl FnOnce() -> Result<(), ProfileDefinitionError>,
+) -> Result<(), ProfileDefinitionError> {
+    let Some(profile) = guarded else {
+        return check();
+    };
+
+    match limit_pin_attempts(state, profile, check).await {
+        Ok(PinAttempt::Checked(result)) => result,
+        Ok(PinAttempt::Locked) => Err(ProfileDefinitionError::Locked(pin_locked_message())),
+        Err(e) => Err(ProfileDefinitionError::Database(format!(
+            "Erro ao conferir o PIN: {}",
+            e
+        ))),
+    }
+}
+
+fn verify_pin(pin: &str, pin_hash: &str) -> Result<(), ProfileDefinitionError> {
+    match verify(pin, pin_hash) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(ProfileDefinitionError::Forbidden(
+            "PIN incorreto".to_string(),
+        )),
+        Err(_) => Err(ProfileDefinitionError::Database(
+            "Erro ao verificar o PIN".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+
+    fn profile(id: i32, is_kids: bool, maturity_level: &str, pin: Option<&str>) -> ProfileModel {
+        ProfileModel {
+            id,
+            user_id: "user".to_string(),
+            name: format!("perfil {}", id),
+            avatar_url: None,
+            maturity_level: maturity_level.to_string(),
+            is_kids,
+            pin_hash: pin.map(|pin| hash(pin, 4).unwrap()),
+            is_default: id == 1,
+            pin_failed_attempts: 0,
+            pin_locked_until: None,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    #[test]
+    fn kids_profile_cannot_manage_profiles() {
+        let kids = profile(2, true, "12", None);
+        assert!(matches!(
+            ensure_can_manage(Some(&kids)),
+            Err(ProfileDefinitionError::Forbidden(_))
+        ));
+    }
+
+    #[test]
+    fn missing_active_profile_cannot_manage_profiles() {
+        assert!(matches!(
+            ensure_can_manage(None),
+            Err(ProfileDefinitionError::Forbidden(_))
+        ));
+    }
+
+    #[test]
+    fn adult_profile_can_manage_profiles() {
+        let adult = profile(1, false, "18", None);
+        assert!(ensure_can_manage(Some(&adult)).is_ok());
+    }
+
+    #[test]
+    fn kids_selecting_adult_without_pin_needs_account_pin() {
+        let kids = profile(2, true, "12", None);
+        let adult = profile(3, false, "18", None);
+        assert_eq!(select_pin(Some(&kids), &adult), SelectPin::Account);
+    }
+
+    #[test]
+    fn kids_selecting_higher_rated_kids_profile_needs_account_pin() {
+        let kids = profile(2, true, "10", None);
+        let older = profile(3, true, "12", None);
+        assert_eq!(select_pin(Some(&kids), &older), SelectPin::Account);
+    }
+
+    #[test]
+    fn kids_selecting_equal_or_stricter_profile_needs_no_pin() {
+        let kids = profile(2, true, "12", None);
+        let younger = profile(3, true, "L", None);
+        assert_eq!(select_pin(Some(&kids), &younger), SelectPin::None);
+    }
+
+    #[test]
+    fn protected_profile_always_asks_its_own_pin() {
+        let kids = profile(2, true, "12", None);
+        let adult = profile(3, false, "18", Some("1234"));
+        assert_eq!(select_pin(Some(&kids), &adult), SelectPin::Profile);
+    }
+
+    #[test]
+    fn adult_selecting_unprotected_profile_needs_no_pin() {
+        let adult = profile(1, false, "18", None);
+        let other = profile(3, false, "18", None);
+        assert_eq!(select_pin(Some(&adult), &other), SelectPin::None);
+    }
+
+    #[test]
+    fn invalid_rating_on_target_counts_as_relaxing() {
+        let kids = profile(2, true, "12", None);
+        let broken = profile(3, true, "??", None);
+        assert_eq!(select_pin(Some(&kids), &broken), SelectPin::Account);
+    }
+
+    #[test]
+    fn account_pin_is_required_and_checked() {
+        let owner = profile(1, false, "18", Some("4321"));
+        assert!(check_account_pin(Some(&owner), Some("4321")).is_ok());
+        assert!(matches!(
+            check_account_pin(Some(&owner), Some("0000")),
+            Err(ProfileDefinitionError::Forbidden(_))
+        ));
+        assert!(matches!(
+            check_account_pin(Some(&owner), None),
+            Err(ProfileDefinitionError::Forbidden(_))
+        ));
+    }
+
+    #[test]
+    fn owner_without_pin_cannot_unlock() {
+        let owner = profile(1, false, "18", None);
+        assert!(matches!(
+            check_account_pin(Some(&owner), Some("1234")),
+            Err(ProfileDefinitionError::Forbidden(_))
+        ));
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use shared::modules::{app_state::AppState, auth::User, profile::active_profile};
+
+use crate::modules::profile::{
+    dto::{
+        io::select_profile_output_dto::SelectProfileOutputDTO,
+        request::profile_pin_request_dto::ProfilePinRequestDTO,
+        route_params::profile_route_params_dto::ProfileRouteParamsDTO,
+    },
+    services::profile_definition_service::{self, ProfileDefinitionError, SelectPin},
+};
+
+pub enum SelectProfileError {
+    Forbidden(String),
+    NotFound(String),
+    TooManyAttempts(String),
+    Database(String),
+}
+
+impl From<ProfileDefinitionError> for SelectProfileError {
+    fn from(err: ProfileDefinitionError) -> Self {
+        match err {
+            ProfileDefinitionError::Forbidden(msg)
+            | ProfileDefinitionError::Validation(msg)
+            | ProfileDefinitionError::Conflict(msg) => SelectProfileError::Forbidden(msg),
+            ProfileDefinitionError::Locked(msg) => SelectProfileError::TooManyAttempts(msg),
+            ProfileDefinitionError::Database(msg) => SelectProfileError::Database(msg),
+        }
+    }
+}
+
+/// Entra no perfil: gera tokens novos que levam o perfil escolhido, e a
+/// viewer_api passa a usar o histórico, a lista e as avaliações dele.
+pub async fn execute(
+    mut user: User,
+    params: ProfileRouteParamsDTO,
+    payload: ProfilePinRequestDTO,
+    state: Arc<AppState>,
+) -> Result<SelectProfileOutputDTO, SelectProfileError> {
+    let map_err = |e| SelectProfileError::Database(format!("Erro ao buscar o perfil: {}", e));
+
+    let profile = state
+        .profile_repo
+        .find_owned(&user.id, params.id)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| {
+            SelectProfileError::NotFound(format!("Perfil com id {} não encontrado", params.id))
+        })?;
+
+    let active = active_profile(&state, &user).await.map_err(map_err)?;
+    match profile_definition_service::select_pin(active.as_ref(), &profile) {
+        SelectPin::None => {}
+        SelectPin::Profile => {
+            profile_definition_service::unlock_profile(&state, &profile, payload.pin.as_deref())
+                .await?
+        }
+        SelectPin::Account => {
+            let owner = state
+                .profile_repo
+                .find_default(&user.id)
+                .await
+                .map_err(map_err)?;
+            profile_definition_service::unlock_with_account_pin(
+                &state,
+                owner.as_ref(),
+                payload.pin.as_deref(),
+            )
+            .await?
+        }
+    }
+
+    user.profile_id = Some(profile.id);
+    let auth_response = state
+        .auth_service
+        .issue_tokens(&user)
+        .map_err(SelectProfileError::Database)?;
+
+    Ok(SelectProfileOutputDTO {
+        access_token: auth_response.access_token,
+        refresh_token: auth_response.refresh_token,
+        profile: profile.into(),
+    })
+}
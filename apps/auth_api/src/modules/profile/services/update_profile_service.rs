@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use shared::modules::{app_state::AppState, auth::User, profile::active_profile};
+
+use crate::modules::profile::{
+    dto::{
+        io::profile_output_dto::ProfileOutputDTO,
+        request::update_profile_request_dto::UpdateProfileRequestDTO,
+        route_params::profile_route_params_dto::ProfileRouteParamsDTO,
+    },
+    services::profile_definition_service::{self, ProfileDefinitionError, ProfileDraft},
+};
+
+pub enum UpdateProfileError {
+    Validation(String),
+    Conflict(String),
+    Forbidden(String),
+    NotFound(String),
+    TooManyAttempts(String),
+    Database(String),
+}
+
+impl From<ProfileDefinitionError> for UpdateProfileError {
+    fn from(err: ProfileDefinitionError) -> Self {
+        match err {
+            ProfileDefinitionError::Validation(msg) => UpdateProfileError::Validation(msg),
+            ProfileDefinitionError::Conflict(msg) => UpdateProfileError::Conflict(msg),
+            ProfileDefinitionError::Forbidden(msg) => UpdateProfileError::Forbidden(msg),
+            ProfileDefinitionError::Locked(msg) => UpdateProfileError::TooManyAttempts(msg),
+            ProfileDefinitionError::Database(msg) => UpdateProfileError::Database(msg),
+        }
+    }
+}
+
+/// Altera um perfil da conta. Perfis com PIN só mudam com `current_pin`, e
+/// só um perfil adulto altera perfis, para que o infantil não tire o próprio
+/// `is_kids` nem suba a classificação.
+pub async fn execute(
+    user: User,
+    params: ProfileRouteParamsDTO,
+    payload: UpdateProfileRequestDTO,
+    state: Arc<AppState>,
+) -> Result<ProfileOutputDTO, UpdateProfileError> {
+    let map_err = |e| UpdateProfileError::Database(format!("Erro ao atualizar o perfil: {}", e));
+
+    let active = active_profile(&state, &user).await.map_err(map_err)?;
+    profile_definition_service::ensure_can_manage(active.as_ref())?;
+
+    let profile = state
+        .profile_repo
+        .find_owned(&user.id, params.id)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| {
+            UpdateProfileError::NotFound(format!("Perfil com id {} não encontrado", params.id))
+        })?;
+
+    profile_definition_service::unlock_profile(&state, &profile, payload.current_pin.as_deref())
+        .await?;
+
+    let pin_hash = match (payload.pin.as_deref(), payload.remove_pin.unwrap_or(false)) {
+        (Some(_), true) => {
+            return Err(UpdateProfileError::Validation(
+                "Informe pin ou remove_pin, não os dois".to_string(),
+            ));
+        }
+        (Some(pin), false) => Some(profile_definition_service::hash_pin(pin)?),
+        (None, true) => None,
+        (None, false) => profile.pin_hash.clone(),
+    };
+
+    // mudar só o is_kids reaplica a classificação padrão do tipo de perfil
+    let is_kids = payload.is_kids.unwrap_or(profile.is_kids);
+    let maturity_level = payload
+        .maturity_level
+        .or_else(|| (is_kids == profile.is_kids).then(|| profile.maturity_level.clone()));
+
+    let request = profile_definition_service::build(
+        &state,
+        &user.id,
+        Some(&profile),
+        ProfileDraft {
+            name: payload.name.unwrap_or_else(|| profile.name.clone()),
+            avatar_url: payload.avatar_url.or_else(|| profile.avatar_url.clone()),
+            maturity_level,
+            is_kids,
+            pin_hash,
+        },
+    )
+    .await?;
+
+    let updated = state
+        .profile_repo
+        .update(profile.id, request)
+        .await
+        .map_err(map_err)?;
+
+    Ok(updated.into())
+}
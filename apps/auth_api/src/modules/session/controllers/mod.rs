@@ -0,0 +1,2 @@
+pub mod delete_session_controller;
+pub mod find_many_session_controller;
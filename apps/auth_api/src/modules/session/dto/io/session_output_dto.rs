@@ -0,0 +1,34 @@
+use chrono::{DateTime, NaiveDateTime};
+use serde::Serialize;
+use shared::modules::playback_session::PlaybackSession;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionOutputDTO {
+    /// Sessão de login do dispositivo, usada para desconectá-lo.
+    pub session_id: String,
+    pub device_name: Option<String>,
+    pub profile_id: i32,
+    pub video_id: i32,
+    pub last_seen: NaiveDateTime,
+}
+
+impl From<PlaybackSession> for SessionOutputDTO {
+    fn from(session: PlaybackSession) -> Self {
+        Self {
+            session_id: session.device_id,
+            device_name: session.device_name,
+            profile_id: session.profile_id,
+            video_id: session.video_id,
+            last_seen: DateTime::from_timestamp(session.last_seen, 0)
+                .unwrap_or_default()
+                .naive_utc(),
+        }
+    }
+}
+
+/// Telas em uso pela conta, com o limite do plano.
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionListOutputDTO {
+    pub max_streams: usize,
+    pub items: Vec<SessionOutputDTO>,
+}
@@ -0,0 +1 @@
+pub mod session_route_params_dto;
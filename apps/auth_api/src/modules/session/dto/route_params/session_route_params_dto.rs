@@ -0,0 +1,6 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SessionRouteParamsDTO {
+    pub session_id: String,
+}
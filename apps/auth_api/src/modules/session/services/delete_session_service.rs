@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use shared::modules::{app_state::AppState, auth::User, playback_session};
+
+use crate::modules::session::dto::route_params::session_route_params_dto::SessionRouteParamsDTO;
+
+pub enum DeleteSessionError {
+    NotFound(String),
+    Database(String),
+    Cache(String),
+}
+
+/// Desconecta o dispositivo: revoga a sessão de login, para que o refresh
+/// token dele pare de valer, e libera a tela que ele ocupava.
+pub async fn execute(
+    user: User,
+    params: SessionRouteParamsDTO,
+    state: Arc<AppState>,
+) -> Result<(), DeleteSessionError> {
+    let revoked = state
+        .auth_session_repo
+        .revoke(&user.id, &params.session_id)
+        .await
+        .map_err(|e| DeleteSessionError::Database(format!("Erro ao revogar a sessão: {}", e)))?;
+
+    if !revoked {
+        return Err(DeleteSessionError::NotFound(format!(
+            "Sessão '{}' não encontrada",
+            params.session_id
+        )));
+    }
+
+    playback_session::kick(&state, &user.id, &params.session_id)
+        .await
+        .map_err(|e| DeleteSessionError::Cache(format!("Erro ao encerrar a reprodução: {}", e)))?;
+
+    Ok(())
+}
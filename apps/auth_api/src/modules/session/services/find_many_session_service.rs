@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{app_state::AppState, auth::User, playback_session},
+};
+
+use crate::modules::session::dto::io::session_output_dto::SessionListOutputDTO;
+
+pub enum FindManySessionError {
+    Cache(String),
+}
+
+pub async fn execute(
+    user: User,
+    state: Arc<AppState>,
+) -> Result<SessionListOutputDTO, FindManySessionError> {
+    let sessions = playback_session::active_sessions(&state, &user.id)
+        .await
+        .map_err(|e| FindManySessionError::Cache(format!("Erro ao buscar as sessões: {}", e)))?;
+
+    Ok(SessionListOutputDTO {
+        max_streams: AccessGroupEnum::stream_limit(&user.access_groups),
+        items: sessions.into_iter().map(Into::into).collect(),
+    })
+}
@@ -1,7 +1,17 @@
 use crate::modules::auth::controllers::{
     login_controller, refresh_token_controller, register_controller,
 };
-use axum::{routing::post, Router};
+use crate::modules::profile::controllers::{
+    create_profile_controller, delete_profile_controller, find_many_profile_controller,
+    select_profile_controller, update_profile_controller,
+};
+use crate::modules::session::controllers::{
+    delete_session_controller, find_many_session_controller,
+};
+use axum::{
+    routing::{delete, get, patch, post},
+    Router,
+};
 
 pub fn create_router() -> Router {
     Router::new()
@@ -11,4 +21,21 @@ pub fn create_router() -> Router {
             "/users/refresh-token",
             post(refresh_token_controller::handler),
         )
+        .route(
+            "/profiles",
+            post(create_profile_controller::handler).get(find_many_profile_controller::handler),
+        )
+        .route(
+            "/profiles/{id}",
+            patch(update_profile_controller::handler).delete(delete_profile_controller::handler),
+        )
+        .route(
+            "/profiles/{id}/select",
+            post(select_profile_controller::handler),
+        )
+        .route("/sessions", get(find_many_session_controller::handler))
+        .route(
+            "/sessions/{session_id}",
+            delete(delete_session_controller::handler),
+        )
 }
@@ -0,0 +1,175 @@
+use anyhow::{bail, Context, Result};
+
+use shared::enums::catalog_entity_enum::CatalogEntityEnum;
+use shared::enums::catalog_format_enum::CatalogFormatEnum;
+use shared::modules::app_state::AppState;
+use shared::modules::catalog::{self, import::ImportReport, rows::CatalogDocument};
+use shared::modules::search::index;
+
+pub const IMPORT_USAGE: &str = "uso: media_worker import-catalog <arquivo> [--format json|csv] [--entity <entidade>] [--dry-run]";
+pub const EXPORT_USAGE: &str =
+    "uso: media_worker export-catalog [--format json|csv] [--entity <entidade>] --output <arquivo>";
+
+pub struct ImportOptions {
+    pub path: String,
+    pub format: CatalogFormatEnum,
+    pub entity: Option<CatalogEntityEnum>,
+    pub dry_run: bool,
+}
+
+pub struct ExportOptions {
+    pub format: CatalogFormatEnum,
+    pub entity: Option<CatalogEntityEnum>,
+    pub output: String,
+}
+
+fn parse_format(value: Option<&String>, usage: &str) -> Result<CatalogFormatEnum> {
+    match value.map(|value| CatalogFormatEnum::try_from(value.as_str())) {
+        Some(Ok(format)) => Ok(format),
+        Some(Err(err)) => bail!("{}\n{}", err, usage),
+        None => bail!("--format espera json ou csv\n{}", usage),
+    }
+}
+
+fn parse_entity(value: Option<&String>, usage: &str) -> Result<CatalogEntityEnum> {
+    match value.map(|value| CatalogEntityEnum::try_from(value.as_str())) {
+        Some(Ok(entity)) => Ok(entity),
+        Some(Err(err)) => bail!("{}\n{}", err, usage),
+        None => bail!("--entity espera o nome da entidade\n{}", usage),
+    }
+}
+
+/// Opções do subcomando `import-catalog`; sem `--format`, vale a extensão
+/// do arquivo.
+pub fn parse_import_args(args: &[String]) -> Result<ImportOptions> {
+    let mut path = None;
+    let mut format = None;
+    let mut entity = None;
+    let mut dry_run = false;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" => format = Some(parse_format(iter.next(), IMPORT_USAGE)?),
+            "--entity" => entity = Some(parse_entity(iter.next(), IMPORT_USAGE)?),
+            "--dry-run" => dry_run = true,
+            other if other.starts_with("--") => {
+                bail!("Argumento desconhecido '{}'\n{}", other, IMPORT_USAGE)
+            }
+            other if path.is_none() => path = Some(other.to_string()),
+            other => bail!("Argumento desconhecido '{}'\n{}", other, IMPORT_USAGE),
+        }
+    }
+
+    let Some(path) = path else {
+        bail!("Informe o arquivo a importar\n{}", IMPORT_USAGE);
+    };
+    let format = format
+        .or_else(|| CatalogFormatEnum::from_path(&path))
+        .unwrap_or_default();
+    if format == CatalogFormatEnum::CSV && entity.is_none() {
+        bail!("Informe a entidade do CSV com --entity\n{}", IMPORT_USAGE);
+    }
+
+    Ok(ImportOptions {
+        path,
+        format,
+        entity,
+        dry_run,
+    })
+}
+
+pub fn parse_export_args(args: &[String]) -> Result<ExportOptions> {
+    let mut format = None;
+    let mut entity = None;
+    let mut output = None;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" => format = Some(parse_format(iter.next(), EXPORT_USAGE)?),
+            "--entity" => entity = Some(parse_entity(iter.next(), EXPORT_USAGE)?),
+            "--output" => match iter.next() {
+                Some(path) => output = Some(path.clone()),
+                None => bail!("--output espera o caminho do arquivo\n{}", EXPORT_USAGE),
+            },
+            other => bail!("Argumento desconhecido '{}'\n{}", other, EXPORT_USAGE),
+        }
+    }
+
+    // o log também vai para a saída padrão, então o catálogo vai para arquivo
+    let Some(output) = output else {
+        bail!(
+            "Informe o arquivo de destino com --output\n{}",
+            EXPORT_USAGE
+        );
+    };
+    let format = format
+        .or_else(|| CatalogFormatEnum::from_path(&output))
+        .unwrap_or_default();
+    if format == CatalogFormatEnum::CSV && entity.is_none() {
+        bail!("Informe a entidade do CSV com --entity\n{}", EXPORT_USAGE);
+    }
+
+    Ok(ExportOptions {
+        format,
+        entity,
+        output,
+    })
+}
+
+/// Importa o arquivo e imprime o relatório em JSON na saída padrão.
+pub async fn run_import(state: &AppState, options: &ImportOptions) -> Result<ImportReport> {
+    let data = tokio::fs::read_to_string(&options.path)
+        .await
+        .with_context(|| format!("Falha ao ler '{}'", options.path))?;
+
+    let document = match (options.format, options.entity) {
+        (CatalogFormatEnum::CSV, Some(entity)) => {
+            catalog::csv::read(entity, &data).map_err(anyhow::Error::msg)?
+        }
+        _ => serde_json::from_str::<CatalogDocument>(&data).context("JSON inválido")?,
+    };
+
+    let report = catalog::import::run(state, &document, options.dry_run).await?;
+    if !options.dry_run {
+        index::reindex(state).await?;
+    }
+    println!("{}", serde_json::to_string_pretty(&report)?);
+
+    tracing::info!(
+        "Catálogo{}: {} linhas criadas, {} atualizadas, {} com erro",
+        if options.dry_run {
+            " (simulação)"
+        } else {
+            ""
+        },
+        count(&report.created),
+        count(&report.updated),
+        report.errors.len()
+    );
+
+    Ok(report)
+}
+
+pub async fn run_export(state: &AppState, options: &ExportOptions) -> Result<()> {
+    let document = catalog::export::run(state).await?;
+
+    let data = match (options.format, options.entity) {
+        (CatalogFormatEnum::CSV, Some(entity)) => {
+            catalog::csv::write(entity, &document).map_err(anyhow::Error::msg)?
+        }
+        _ => serde_json::to_string_pretty(&document)?,
+    };
+
+    tokio::fs::write(&options.output, data)
+        .await
+        .with_context(|| format!("Falha ao gravar '{}'", options.output))?;
+    tracing::info!("Catálogo exportado para '{}'", options.output);
+
+    Ok(())
+}
+
+fn count(counts: &catalog::import::ImportCounts) -> usize {
+    counts.categories + counts.actors + counts.directors + counts.series + counts.videos
+}
@@ -0,0 +1,73 @@
+use anyhow::{bail, Result};
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use shared::modules::app_state::AppState;
+use shared::modules::config::Config;
+use shared::modules::events::{self, RelayReport};
+
+pub const USAGE: &str = "uso: media_worker relay-events";
+
+pub fn parse_args(args: &[String]) -> Result<()> {
+    if let Some(other) = args.first() {
+        bail!("Argumento desconhecido '{}'\n{}", other, USAGE);
+    }
+
+    Ok(())
+}
+
+/// Uma rodada do relay com os assinantes padrão.
+pub async fn run_once(state: &AppState) -> Result<RelayReport> {
+    let bus = events::default_bus();
+    let report = events::relay_once(state, &bus, Utc::now().naive_utc()).await?;
+
+    if report.claimed() > 0 {
+        tracing::info!(
+            "Eventos: {} entregues, {} reagendados, {} na dead letter",
+            report.published,
+            report.retried,
+            report.dead_lettered
+        );
+    }
+
+    Ok(report)
+}
+
+/// Entrega o outbox a cada `EVENT_RELAY_INTERVAL_SECS` até `shutdown`. Rodadas
+/// com o lote cheio emendam na seguinte sem esperar o intervalo.
+pub async fn run_scheduled(
+    state: Arc<AppState>,
+    config: &Config,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    if config.event_relay_interval_secs == 0 {
+        tracing::info!("Relay de eventos desativado");
+        return;
+    }
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.event_relay_interval_secs));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.changed() => return,
+        }
+
+        loop {
+            if *shutdown.borrow() {
+                return;
+            }
+
+            match run_once(&state).await {
+                Ok(report) if report.claimed() as u64 >= events::RELAY_BATCH_SIZE => continue,
+                Ok(_) => break,
+                Err(e) => {
+                    tracing::error!("Falha no relay de eventos: {:?}", e);
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use anyhow::{bail, Result};
+use chrono::Duration as ChronoDuration;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use shared::modules::app_state::AppState;
+use shared::modules::blob_gc::{self, BlobGcOptions, BlobGcReport};
+use shared::modules::config::Config;
+
+pub const USAGE: &str = "uso: media_worker gc [--dry-run] [--grace-hours <horas>]";
+
+/// Opções do subcomando `gc`; o que não for informado vem da configuração.
+pub fn parse_args(args: &[String], config: &Config) -> Result<BlobGcOptions> {
+    let mut options = options_from_config(config);
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--dry-run" => options.dry_run = true,
+            "--grace-hours" => {
+                let hours: i64 = match iter.next().map(|value| value.parse()) {
+                    Some(Ok(hours)) if hours >= 0 => hours,
+                    _ => bail!("--grace-hours espera um número de horas\n{}", USAGE),
+                };
+                options.grace_period = ChronoDuration::hours(hours);
+            }
+            other => bail!("Argumento desconhecido '{}'\n{}", other, USAGE),
+        }
+    }
+
+    Ok(options)
+}
+
+pub fn options_from_config(config: &Config) -> BlobGcOptions {
+    BlobGcOptions {
+        dry_run: config.blob_gc_dry_run,
+        grace_period: ChronoDuration::hours(config.blob_gc_grace_period_hours as i64),
+    }
+}
+
+pub async fn run_once(state: &AppState, options: &BlobGcOptions) -> Result<BlobGcReport> {
+    tracing::info!(
+        "Limpeza de blobs órfãos iniciada (carência: {}h{})",
+        options.grace_period.num_hours(),
+        if options.dry_run { ", dry-run" } else { "" }
+    );
+
+    let report = blob_gc::collect(state, options).await?;
+    log_report(&report);
+
+    Ok(report)
+}
+
+/// Repete a limpeza a cada `BLOB_GC_INTERVAL_HOURS` até `shutdown`. A primeira
+/// execução acontece logo na inicialização.
+pub async fn run_scheduled(
+    state: Arc<AppState>,
+    config: &Config,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    if config.blob_gc_interval_hours == 0 {
+        tracing::info!("Limpeza agendada de blobs órfãos desativada");
+        return;
+    }
+
+    let options = options_from_config(config);
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(config.blob_gc_interval_hours * 60 * 60));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.changed() => return,
+        }
+
+        if *shutdown.borrow() {
+            return;
+        }
+
+        if let Err(e) = run_once(&state, &options).await {
+            tracing::error!("Falha na limpeza de blobs órfãos: {:?}", e);
+        }
+    }
+}
+
+fn log_report(report: &BlobGcReport) {
+    for container in &report.containers {
+        for orphan in &container.orphans {
+            tracing::info!(
+                "[{}] órfão '{}' ({} bytes, modificado em {}){}",
+                container.container,
+                orphan.name,
+                orphan.size,
+                orphan.last_modified,
+                if orphan.deleted { " apagado" } else { "" }
+            );
+        }
+
+        tracing::info!(
+            "[{}] {} blobs: {} referenciados, {} em carência, {} órfãos ({} bytes), {} apagados, {} falhas",
+            container.container,
+            container.scanned,
+            container.referenced,
+            container.within_grace_period,
+            container.orphans.len(),
+            container.orphan_bytes(),
+            container.deleted(),
+            container.failed
+        );
+    }
+
+    if report.dry_run {
+        tracing::info!("Dry-run: nenhum blob foi apagado");
+    }
+}
@@ -0,0 +1,173 @@
+use tokio::signal;
+use tokio::sync::watch;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt, EnvFilter};
+
+use shared::modules::app_state::AppState;
+use shared::modules::config::Config;
+
+mod catalog;
+mod events;
+mod gc;
+mod recommendations;
+mod releases;
+mod search;
+mod transcoder;
+mod trash;
+mod trending;
+mod worker;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // Config
+    let config = Config::from_env()?;
+
+    // Logging
+    std::env::set_var("RUST_LOG", &config.log_level);
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    let app_state = AppState::init(&config).await?;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => {}
+        Some("gc") => {
+            let options = gc::parse_args(&args[1..], &config)?;
+            let report = gc::run_once(&app_state, &options).await?;
+            let failed: usize = report.containers.iter().map(|c| c.failed).sum();
+            if failed > 0 {
+                anyhow::bail!("{} blobs órfãos não puderam ser apagados", failed);
+            }
+            return Ok(());
+        }
+        Some("apply-releases") => {
+            releases::parse_args(&args[1..])?;
+            releases::run_once(&app_state).await?;
+            return Ok(());
+        }
+        Some("import-catalog") => {
+            let options = catalog::parse_import_args(&args[1..])?;
+            let report = catalog::run_import(&app_state, &options).await?;
+            if report.has_errors() {
+                anyhow::bail!("{} linhas do catálogo têm erros", report.errors.len());
+            }
+            return Ok(());
+        }
+        Some("export-catalog") => {
+            let options = catalog::parse_export_args(&args[1..])?;
+            catalog::run_export(&app_state, &options).await?;
+            return Ok(());
+        }
+        Some("compute-similarities") => {
+            recommendations::parse_args(&args[1..])?;
+            recommendations::run_once(&app_state).await?;
+            return Ok(());
+        }
+        Some("refresh-trending") => {
+            trending::parse_args(&args[1..])?;
+            trending::run_once(&app_state, &config).await?;
+            return Ok(());
+        }
+        Some("reindex-search") => {
+            search::parse_args(&args[1..])?;
+            search::run_once(&app_state).await?;
+            return Ok(());
+        }
+        Some("relay-events") => {
+            events::parse_args(&args[1..])?;
+            events::run_once(&app_state).await?;
+            return Ok(());
+        }
+        Some("purge-trash") => {
+            let retention = trash::parse_args(&args[1..], &config)?;
+            trash::run_once(&app_state, retention).await?;
+            return Ok(());
+        }
+        Some(other) => anyhow::bail!(
+            "Subcomando desconhecido '{}'\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            other,
+            gc::USAGE,
+            trash::USAGE,
+            releases::USAGE,
+            catalog::IMPORT_USAGE,
+            catalog::EXPORT_USAGE,
+            search::USAGE,
+            recommendations::USAGE,
+            trending::USAGE,
+            events::USAGE
+        ),
+    }
+
+    let transcoder = transcoder::build_transcoder(&config)?;
+
+    let worker = worker::Worker::new(&config, app_state.clone(), transcoder.clone());
+    tracing::info!(
+        "Media Worker {} iniciado (transcoder: {})",
+        worker.worker_id(),
+        transcoder.name()
+    );
+
+    // Shutdown
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        signal::ctrl_c().await.expect("Falha ao capturar Ctrl+C");
+        tracing::info!("Ctrl+C detectado! Finalizando o job em andamento...");
+        let _ = shutdown_tx.send(true);
+    });
+
+    let gc_task = tokio::spawn({
+        let state = app_state.clone();
+        let config = config.clone();
+        let shutdown_rx = shutdown_rx.clone();
+        async move { gc::run_scheduled(state, &config, shutdown_rx).await }
+    });
+
+    let trash_task = tokio::spawn({
+        let state = app_state.clone();
+        let config = config.clone();
+        let shutdown_rx = shutdown_rx.clone();
+        async move { trash::run_scheduled(state, &config, shutdown_rx).await }
+    });
+
+    let release_task = tokio::spawn({
+        let state = app_state.clone();
+        let config = config.clone();
+        let shutdown_rx = shutdown_rx.clone();
+        async move { releases::run_scheduled(state, &config, shutdown_rx).await }
+    });
+
+    let similarity_task = tokio::spawn({
+        let state = app_state.clone();
+        let config = config.clone();
+        let shutdown_rx = shutdown_rx.clone();
+        async move { recommendations::run_scheduled(state, &config, shutdown_rx).await }
+    });
+
+    let trending_task = tokio::spawn({
+        let state = app_state.clone();
+        let config = config.clone();
+        let shutdown_rx = shutdown_rx.clone();
+        async move { trending::run_scheduled(state, &config, shutdown_rx).await }
+    });
+
+    let event_task = tokio::spawn({
+        let state = app_state.clone();
+        let config = config.clone();
+        let shutdown_rx = shutdown_rx.clone();
+        async move { events::run_scheduled(state, &config, shutdown_rx).await }
+    });
+
+    worker.run(shutdown_rx).await;
+    let _ = gc_task.await;
+    let _ = trash_task.await;
+    let _ = release_task.await;
+    let _ = similarity_task.await;
+    let _ = trending_task.await;
+    let _ = event_task.await;
+
+    tracing::info!("Media Worker encerrado.");
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anyhow::{bail, Result};
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use shared::modules::app_state::AppState;
+use shared::modules::config::Config;
+use shared::modules::database::repositories::recommendations_repository::SimilarityReport;
+
+pub const USAGE: &str = "uso: media_worker compute-similarities";
+
+pub fn parse_args(args: &[String]) -> Result<()> {
+    if let Some(other) = args.first() {
+        bail!("Argumento desconhecido '{}'\n{}", other, USAGE);
+    }
+
+    Ok(())
+}
+
+pub async fn run_once(state: &AppState) -> Result<SimilarityReport> {
+    tracing::info!("Cálculo de vídeos parecidos iniciado");
+
+    let report = state
+        .recommendation_repo
+        .rebuild_similarities(Utc::now().naive_utc())
+        .await?;
+    tracing::info!(
+        "Recomendações: {} pares de vídeos parecidos gravados",
+        report.pairs
+    );
+
+    Ok(report)
+}
+
+/// Recalcula os vídeos parecidos a cada `SIMILARITY_INTERVAL_HOURS` até
+/// `shutdown`. A primeira execução acontece logo na inicialização.
+pub async fn run_scheduled(
+    state: Arc<AppState>,
+    config: &Config,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    if config.similarity_interval_hours == 0 {
+        tracing::info!("Cálculo agendado de vídeos parecidos desativado");
+        return;
+    }
+
+    let mut interval = tokio::time::interval(Duration::from_secs(
+        config.similarity_interval_hours * 60 * 60,
+    ));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.changed() => return,
+        }
+
+        if *shutdown.borrow() {
+            return;
+        }
+
+        if let Err(e) = run_once(&state).await {
+            tracing::error!("Falha no cálculo de vídeos parecidos: {:?}", e);
+        }
+    }
+}
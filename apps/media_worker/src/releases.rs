@@ -0,0 +1,66 @@
+use anyhow::{bail, Result};
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use shared::modules::app_state::AppState;
+use shared::modules::availability::{self, ReleaseReport};
+use shared::modules::config::Config;
+
+pub const USAGE: &str = "uso: media_worker apply-releases";
+
+pub fn parse_args(args: &[String]) -> Result<()> {
+    if let Some(other) = args.first() {
+        bail!("Argumento desconhecido '{}'\n{}", other, USAGE);
+    }
+
+    Ok(())
+}
+
+pub async fn run_once(state: &AppState) -> Result<ReleaseReport> {
+    let report = availability::apply_release_states(state, Utc::now().naive_utc()).await?;
+
+    if report.transitions > 0 {
+        tracing::info!(
+            "Agendamento: {} janelas mudaram de estado, {} lançamentos avisados",
+            report.transitions,
+            report.releases
+        );
+    }
+
+    Ok(report)
+}
+
+/// Confere as janelas de disponibilidade a cada
+/// `RELEASE_SCHEDULER_INTERVAL_SECS` até `shutdown`. Um lançamento é avisado
+/// com até um intervalo de atraso; a API já respeita a janela na hora da
+/// requisição.
+pub async fn run_scheduled(
+    state: Arc<AppState>,
+    config: &Config,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    if config.release_scheduler_interval_secs == 0 {
+        tracing::info!("Agendador de lançamentos desativado");
+        return;
+    }
+
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(config.release_scheduler_interval_secs));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.changed() => return,
+        }
+
+        if *shutdown.borrow() {
+            return;
+        }
+
+        if let Err(e) = run_once(&state).await {
+            tracing::error!("Falha no agendador de lançamentos: {:?}", e);
+        }
+    }
+}
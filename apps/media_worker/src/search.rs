@@ -0,0 +1,27 @@
+use anyhow::{bail, Result};
+
+use shared::modules::app_state::AppState;
+use shared::modules::search::index;
+
+pub const USAGE: &str = "uso: media_worker reindex-search";
+
+pub fn parse_args(args: &[String]) -> Result<()> {
+    if let Some(other) = args.first() {
+        bail!("Argumento desconhecido '{}'\n{}", other, USAGE);
+    }
+
+    Ok(())
+}
+
+/// Reconstrói o índice de busca do backend configurado a partir do banco.
+pub async fn run_once(state: &AppState) -> Result<usize> {
+    let count = index::reindex(state).await?;
+
+    tracing::info!(
+        "Índice de busca ({}) reconstruído com {} vídeos",
+        state.search_index.name(),
+        count
+    );
+
+    Ok(count)
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use tokio::sync::watch;
+
+use super::{TranscodeRequest, TranscodedRendition, Transcoder};
+
+/// Copia o arquivo de entrada para cada degrau. Serve para desenvolvimento
+/// e ambientes sem ffmpeg; as renditions ficam com os metadados do original.
+pub struct FakeTranscoder;
+
+#[async_trait]
+impl Transcoder for FakeTranscoder {
+    fn name(&self) -> &'static str {
+        "fake"
+    }
+
+    async fn transcode(
+        &self,
+        request: TranscodeRequest<'_>,
+        progress: watch::Sender<i32>,
+    ) -> Result<Vec<TranscodedRendition>> {
+        let mut outputs = Vec::with_capacity(request.targets.len());
+        let total = request.targets.len().max(1) as i32;
+
+        for (index, target) in request.targets.iter().enumerate() {
+            let path = request.output_dir.join(format!("{}.mp4", target.name));
+            tokio::fs::copy(request.input, &path).await?;
+
+            outputs.push(TranscodedRendition {
+                target: target.clone(),
+                path,
+            });
+
+            let _ = progress.send((index as i32 + 1) * 100 / total);
+        }
+
+        Ok(outputs)
+    }
+}
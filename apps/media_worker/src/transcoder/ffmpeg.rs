@@ -0,0 +1,166 @@
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::watch;
+
+use super::{RenditionTarget, TranscodeRequest, TranscodedRendition, Transcoder};
+
+/// Duração alvo de cada segmento HLS/DASH.
+const SEGMENT_SECONDS: u32 = 4;
+
+/// Quantidade de bytes do stderr do ffmpeg preservada na mensagem de erro.
+const STDERR_TAIL_BYTES: usize = 2048;
+
+pub struct FfmpegTranscoder {
+    ffmpeg_path: String,
+}
+
+impl FfmpegTranscoder {
+    pub fn new(ffmpeg_path: String) -> Self {
+        Self { ffmpeg_path }
+    }
+
+    /// Uma única execução do ffmpeg gera todas as renditions, decodificando o
+    /// original uma vez só.
+    fn build_args(&self, request: &TranscodeRequest<'_>) -> Vec<String> {
+        let mut args: Vec<String> = vec![
+            "-hide_banner".into(),
+            "-nostdin".into(),
+            "-y".into(),
+            "-i".into(),
+            request.input.to_string_lossy().to_string(),
+            "-progress".into(),
+            "pipe:1".into(),
+            "-nostats".into(),
+        ];
+
+        for target in request.targets {
+            args.extend(Self::target_args(target));
+            args.push(
+                request
+                    .output_dir
+                    .join(format!("{}.mp4", target.name))
+                    .to_string_lossy()
+                    .to_string(),
+            );
+        }
+
+        args
+    }
+
+    fn target_args(target: &RenditionTarget) -> Vec<String> {
+        vec![
+            "-map".into(),
+            "0:v:0".into(),
+            "-map".into(),
+            "0:a:0?".into(),
+            "-vf".into(),
+            format!("scale=-2:{}", target.height),
+            "-c:v".into(),
+            "libx264".into(),
+            "-preset".into(),
+            "veryfast".into(),
+            "-b:v".into(),
+            format!("{}k", target.video_bitrate_kbps),
+            "-maxrate".into(),
+            format!("{}k", target.video_bitrate_kbps * 107 / 100),
+            "-bufsize".into(),
+            format!("{}k", target.video_bitrate_kbps * 2),
+            "-c:a".into(),
+            "aac".into(),
+            "-b:a".into(),
+            format!("{}k", target.audio_bitrate_kbps),
+            // MP4 fragmentado com keyframes a cada SEGMENT_SECONDS e `sidx` global:
+            // o mesmo arquivo serve download progressivo e segmentos HLS/DASH.
+            "-force_key_frames".into(),
+            format!("expr:gte(t,n_forced*{})", SEGMENT_SECONDS),
+            "-sc_threshold".into(),
+            "0".into(),
+            "-movflags".into(),
+            "+frag_keyframe+empty_moov+default_base_moof+global_sidx".into(),
+        ]
+    }
+}
+
+#[async_trait]
+impl Transcoder for FfmpegTranscoder {
+    fn name(&self) -> &'static str {
+        "ffmpeg"
+    }
+
+    async fn transcode(
+        &self,
+        request: TranscodeRequest<'_>,
+        progress: watch::Sender<i32>,
+    ) -> Result<Vec<TranscodedRendition>> {
+        let mut child = Command::new(&self.ffmpeg_path)
+            .args(self.build_args(&request))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| anyhow!("Falha ao executar '{}': {}", self.ffmpeg_path, e))?;
+
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| anyhow!("stdout do ffmpeg indisponível"))?;
+        let mut stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| anyhow!("stderr do ffmpeg indisponível"))?;
+
+        // O stderr precisa ser drenado em paralelo para o ffmpeg não travar com o pipe cheio.
+        let stderr_task = tokio::spawn(async move {
+            let mut buffer = Vec::new();
+            let _ = stderr.read_to_end(&mut buffer).await;
+            buffer
+        });
+
+        let duration_us = request.duration_ms.map(|ms| ms * 1000);
+        let mut lines = BufReader::new(stdout).lines();
+
+        while let Some(line) = lines.next_line().await? {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+
+            match (key, duration_us) {
+                ("out_time_us", Some(total)) if total > 0 => {
+                    if let Ok(out_time) = value.trim().parse::<u64>() {
+                        let percent = (out_time.saturating_mul(100) / total).min(99) as i32;
+                        let _ = progress.send(percent);
+                    }
+                }
+                ("progress", _) if value == "end" => {
+                    let _ = progress.send(100);
+                }
+                _ => {}
+            }
+        }
+
+        let status = child.wait().await?;
+        let stderr = stderr_task.await.unwrap_or_default();
+
+        if !status.success() {
+            let tail_start = stderr.len().saturating_sub(STDERR_TAIL_BYTES);
+            bail!(
+                "ffmpeg terminou com {}: {}",
+                status,
+                String::from_utf8_lossy(&stderr[tail_start..]).trim()
+            );
+        }
+
+        Ok(request
+            .targets
+            .iter()
+            .map(|target| TranscodedRendition {
+                target: target.clone(),
+                path: request.output_dir.join(format!("{}.mp4", target.name)),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,102 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::watch;
+
+use shared::modules::config::Config;
+
+pub mod fake;
+pub mod ffmpeg;
+
+/// Degrau da escada de renditions gerada para cada vídeo.
+#[derive(Debug, Clone)]
+pub struct RenditionTarget {
+    pub name: &'static str,
+    pub height: i32,
+    pub video_bitrate_kbps: i32,
+    pub audio_bitrate_kbps: i32,
+}
+
+pub const RENDITION_LADDER: &[RenditionTarget] = &[
+    RenditionTarget {
+        name: "1080p",
+        height: 1080,
+        video_bitrate_kbps: 5000,
+        audio_bitrate_kbps: 192,
+    },
+    RenditionTarget {
+        name: "720p",
+        height: 720,
+        video_bitrate_kbps: 2800,
+        audio_bitrate_kbps: 128,
+    },
+    RenditionTarget {
+        name: "480p",
+        height: 480,
+        video_bitrate_kbps: 1400,
+        audio_bitrate_kbps: 128,
+    },
+    RenditionTarget {
+        name: "360p",
+        height: 360,
+        video_bitrate_kbps: 800,
+        audio_bitrate_kbps: 96,
+    },
+];
+
+/// Seleciona os degraus que não ampliam o original. Fontes menores que o menor
+/// degrau ainda recebem uma rendition, para que todo vídeo tenha ao menos uma.
+pub fn ladder_for(source_height: Option<i32>) -> Vec<RenditionTarget> {
+    let Some(source_height) = source_height else {
+        return RENDITION_LADDER.to_vec();
+    };
+
+    let targets: Vec<RenditionTarget> = RENDITION_LADDER
+        .iter()
+        .filter(|target| target.height <= source_height)
+        .cloned()
+        .collect();
+
+    if targets.is_empty() {
+        RENDITION_LADDER.last().cloned().into_iter().collect()
+    } else {
+        targets
+    }
+}
+
+#[derive(Debug)]
+pub struct TranscodeRequest<'a> {
+    pub input: &'a Path,
+    pub output_dir: &'a Path,
+    pub targets: &'a [RenditionTarget],
+    pub duration_ms: Option<u64>,
+}
+
+#[derive(Debug)]
+pub struct TranscodedRendition {
+    pub target: RenditionTarget,
+    pub path: PathBuf,
+}
+
+/// Backend de transcodificação. O progresso (0 a 100) é publicado em `progress`.
+#[async_trait]
+pub trait Transcoder: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn transcode(
+        &self,
+        request: TranscodeRequest<'_>,
+        progress: watch::Sender<i32>,
+    ) -> Result<Vec<TranscodedRendition>>;
+}
+
+pub fn build_transcoder(config: &Config) -> Result<Arc<dyn Transcoder>> {
+    match config.transcoder.as_str() {
+        "ffmpeg" => Ok(Arc::new(ffmpeg::FfmpegTranscoder::new(
+            config.ffmpeg_path.clone(),
+        ))),
+        "fake" => Ok(Arc::new(fake::FakeTranscoder)),
+        other => bail!("Transcoder desconhecido: '{}'", other),
+    }
+}
@@ -0,0 +1,85 @@
+use anyhow::{bail, Result};
+use chrono::Duration as ChronoDuration;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use shared::modules::app_state::AppState;
+use shared::modules::config::Config;
+use shared::modules::trash::{self, PurgeReport};
+
+pub const USAGE: &str = "uso: media_worker purge-trash [--retention-days <dias>]";
+
+/// Retenção do subcomando `purge-trash`; sem argumento vem da configuração.
+pub fn parse_args(args: &[String], config: &Config) -> Result<ChronoDuration> {
+    let mut retention = retention_from_config(config);
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--retention-days" => {
+                let days: i64 = match iter.next().map(|value| value.parse()) {
+                    Some(Ok(days)) if days >= 0 => days,
+                    _ => bail!("--retention-days espera um número de dias\n{}", USAGE),
+                };
+                retention = ChronoDuration::days(days);
+            }
+            other => bail!("Argumento desconhecido '{}'\n{}", other, USAGE),
+        }
+    }
+
+    Ok(retention)
+}
+
+pub fn retention_from_config(config: &Config) -> ChronoDuration {
+    ChronoDuration::days(config.trash_retention_days as i64)
+}
+
+pub async fn run_once(state: &AppState, retention: ChronoDuration) -> Result<PurgeReport> {
+    tracing::info!(
+        "Limpeza da lixeira iniciada (retenção: {} dias)",
+        retention.num_days()
+    );
+
+    let report = trash::purge_expired(state, retention).await?;
+    tracing::info!(
+        "Lixeira: {} séries e {} vídeos removidos definitivamente",
+        report.series,
+        report.videos
+    );
+
+    Ok(report)
+}
+
+/// Repete a limpeza a cada `TRASH_PURGE_INTERVAL_HOURS` até `shutdown`. A
+/// primeira execução acontece logo na inicialização.
+pub async fn run_scheduled(
+    state: Arc<AppState>,
+    config: &Config,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    if config.trash_purge_interval_hours == 0 {
+        tracing::info!("Limpeza agendada da lixeira desativada");
+        return;
+    }
+
+    let retention = retention_from_config(config);
+    let mut interval = tokio::time::interval(Duration::from_secs(
+        config.trash_purge_interval_hours * 60 * 60,
+    ));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.changed() => return,
+        }
+
+        if *shutdown.borrow() {
+            return;
+        }
+
+        if let Err(e) = run_once(&state, retention).await {
+            tracing::error!("Falha na limpeza da lixeira: {:?}", e);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::{bail, Result};
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use shared::modules::app_state::AppState;
+use shared::modules::config::Config;
+use shared::modules::trending::{self, TrendingReport};
+
+pub const USAGE: &str = "uso: media_worker refresh-trending";
+
+/// Validade das listas sem o agendador ligado.
+const DEFAULT_TTL_SECS: u64 = 60 * 60;
+
+pub fn parse_args(args: &[String]) -> Result<()> {
+    if let Some(other) = args.first() {
+        bail!("Argumento desconhecido '{}'\n{}", other, USAGE);
+    }
+
+    Ok(())
+}
+
+/// As listas valem três rodadas do agendador: se ele parar, o Redis esvazia e
+/// a leitura volta a calcular pelo banco em vez de servir dados velhos.
+fn ttl_secs(config: &Config) -> u64 {
+    match config.trending_interval_secs {
+        0 => DEFAULT_TTL_SECS,
+        interval => interval * 3,
+    }
+}
+
+pub async fn run_once(state: &AppState, config: &Config) -> Result<TrendingReport> {
+    let report = trending::refresh(state, Utc::now().naive_utc(), ttl_secs(config)).await?;
+    tracing::info!(
+        "Em alta: {} listas gravadas no Redis ({})",
+        report.rankings,
+        report.computed_at
+    );
+
+    Ok(report)
+}
+
+/// Recalcula as listas de em alta a cada `TRENDING_INTERVAL_SECS` até
+/// `shutdown`. A primeira execução acontece logo na inicialização.
+pub async fn run_scheduled(
+    state: Arc<AppState>,
+    config: &Config,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    if config.trending_interval_secs == 0 {
+        tracing::info!("Cálculo agendado de em alta desativado");
+        return;
+    }
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.trending_interval_secs));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.changed() => return,
+        }
+
+        if *shutdown.borrow() {
+            return;
+        }
+
+        if let Err(e) = run_once(&state, config).await {
+            tracing::error!("Falha no cálculo de em alta: {:?}", e);
+        }
+    }
+}
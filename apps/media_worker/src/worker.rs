@@ -0,0 +1,686 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{Duration as ChronoDuration, Utc};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use shared::modules::app_state::AppState;
+use shared::modules::config::Config;
+use shared::modules::database::repositories::media_assets_repository::CreateMediaAssetRequest;
+use shared::modules::database::repositories::transcode_jobs_repository::TranscodeJobsRepository;
+use shared::modules::database::repositories::video_assets_repository::CreateRenditionRequest;
+use shared::modules::database::schema::transcode_jobs::Model as TranscodeJobModel;
+use shared::modules::search;
+
+use crate::transcoder::{ladder_for, TranscodeRequest, Transcoder};
+
+/// Maior atraso entre tentativas, independente de quantas já falharam.
+const MAX_RETRY_DELAY_SECS: u64 = 60 * 60;
+
+pub struct Worker {
+    state: Arc<AppState>,
+    transcoder: Arc<dyn Transcoder>,
+    worker_id: String,
+    tmp_dir: PathBuf,
+    poll_interval: Duration,
+    job_timeout: Duration,
+    retry_base_delay_secs: u64,
+}
+
+impl Worker {
+    pub fn new(config: &Config, state: Arc<AppState>, transcoder: Arc<dyn Transcoder>) -> Self {
+        let worker_id = format!(
+            "{}-{}",
+            std::env::var("HOSTNAME").unwrap_or_else(|_| "media-worker".to_string()),
+            &uuid::Uuid::new_v4().to_string()[..8]
+        );
+
+        Self {
+            state,
+            transcoder,
+            worker_id,
+            tmp_dir: PathBuf::from(&config.media_worker_tmp_dir),
+            poll_interval: Duration::from_secs(config.media_worker_poll_interval_secs.max(1)),
+            job_timeout: Duration::from_secs(config.transcode_job_timeout_minutes.max(1) * 60),
+            retry_base_delay_secs: config.transcode_retry_base_delay_secs,
+        }
+    }
+
+    pub fn worker_id(&self) -> &str {
+        &self.worker_id
+    }
+
+    /// Consome a fila até `shutdown` ser sinalizado. O job em andamento é
+    /// concluído antes de sair.
+    pub async fn run(&self, mut shutdown: watch::Receiver<bool>) {
+        while !*shutdown.borrow() {
+            if let Err(e) = self.requeue_stale_jobs().await {
+                tracing::error!("Falha ao recolocar jobs travados na fila: {:?}", e);
+            }
+
+            let claimed = match self
+                .state
+                .transcode_job_repo
+                .claim_next(&self.worker_id)
+                .await
+            {
+                Ok(job) => job,
+                Err(e) => {
+                    tracing::error!("Falha ao buscar próximo job: {:?}", e);
+                    None
+                }
+            };
+
+            match claimed {
+                Some(job) => self.handle(job).await,
+                None => {
+                    tokio::select! {
+                        _ = tokio::time::sleep(self.poll_interval) => {}
+                        _ = shutdown.changed() => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// Jobs `RUNNING` há mais tempo que o timeout pertencem a um worker que caiu.
+    async fn requeue_stale_jobs(&self) -> Result<()> {
+        let grace = ChronoDuration::from_std(self.job_timeout * 2)?;
+        let stale = self
+            .state
+            .transcode_job_repo
+            .requeue_stale(Utc::now().naive_utc() - grace)
+            .await?;
+
+        if stale.requeued > 0 {
+            tracing::warn!("{} job(s) travado(s) devolvido(s) à fila", stale.requeued);
+        }
+        if stale.failed > 0 {
+            tracing::error!(
+                "{} job(s) travado(s) sem tentativas restantes marcado(s) como FAILED",
+                stale.failed
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn handle(&self, job: TranscodeJobModel) {
+        tracing::info!(
+            "Job {} iniciado (vídeo {}, tentativa {}/{})",
+            job.id,
+            job.video_id,
+            job.attempts,
+            job.max_attempts
+        );
+
+        let job_dir = self.tmp_dir.join(format!("transcode-job-{}", job.id));
+
+        // o timeout vale para o job inteiro: download, transcodificação,
+        // upload e troca das renditions
+        let result = tokio::time::timeout(self.job_timeout, self.process(&job, &job_dir))
+            .await
+            .unwrap_or_else(|_| Err(anyhow!("Job excedeu {:?}", self.job_timeout)));
+
+        if let Err(e) = tokio::fs::remove_dir_all(&job_dir).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                tracing::warn!("Falha ao limpar {}: {:?}", job_dir.display(), e);
+            }
+        }
+
+        finish_job(
+            &self.state.transcode_job_repo,
+            &self.worker_id,
+            self.retry_base_delay_secs,
+            &job,
+            result,
+        )
+        .await;
+    }
+
+    async fn process(&self, job: &TranscodeJobModel, job_dir: &Path) -> Result<()> {
+        let storage = &self.state.video_storage_service;
+
+        let source = self
+            .state
+            .media_asset_repo
+            .find_by_id(job.source_media_asset_id)
+            .await?
+            .ok_or_else(|| anyhow!("Media asset {} não encontrado", job.source_media_asset_id))?;
+
+        tokio::fs::create_dir_all(job_dir).await?;
+        let input_path = job_dir.join("source");
+
+        let mut input = tokio::fs::File::create(&input_path).await?;
+        storage
+            .download_video(&source.storage_key, &mut input)
+            .await
+            .context("Falha ao baixar o arquivo original")?;
+        drop(input);
+
+        let targets = ladder_for(source.height);
+        let (progress_tx, progress_rx) = watch::channel(0);
+        let reporter = tokio::spawn(report_progress(self.state.clone(), job.id, progress_rx));
+
+        let transcoded = self
+            .transcoder
+            .transcode(
+                TranscodeRequest {
+                    input: &input_path,
+                    output_dir: job_dir,
+                    targets: &targets,
+                    duration_ms: source.duration_ms.map(|ms| ms as u64),
+                },
+                progress_tx,
+            )
+            .await;
+
+        let _ = reporter.await;
+
+        let transcoded = transcoded?;
+
+        let mut renditions = Vec::with_capacity(transcoded.len());
+
+        for output in transcoded {
+            match self
+                .upload_rendition(job, &output.path, output.target.name)
+                .await
+            {
+                Ok(rendition) => renditions.push(rendition),
+                Err(e) => {
+                    self.discard_uploaded(&renditions).await;
+                    return Err(e);
+                }
+            }
+        }
+
+        let previous = self
+            .state
+            .video_asset_repo
+            .replace_renditions(job.video_id, renditions)
+            .await?;
+
+        // as novas qualidades podem mudar o filtro de plano premium
+        search::index::sync_videos(&self.state, &[job.video_id]).await;
+
+        for asset in previous {
+            if let Err(e) = storage.delete_asset_blob(&asset).await {
+                tracing::warn!(
+                    "Falha ao apagar a rendition antiga {}: {:?}",
+                    asset.storage_key,
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn upload_rendition(
+        &self,
+        job: &TranscodeJobModel,
+        path: &Path,
+        name: &str,
+    ) -> Result<CreateRenditionRequest> {
+        let storage = &self.state.video_storage_service;
+        let file_name = format!("video-{}-{}.mp4", job.video_id, name);
+
+        let file = tokio::fs::File::open(path)
+            .await
+            .with_context(|| format!("Rendition {} não gerada", name))?;
+
+        let saved = storage
+            .save_video_file(
+                file,
+                &file_name,
+                "video/mp4",
+                storage.upload_max_file_size(),
+            )
+            .await?;
+
+        let probe = match storage.probe_video(&saved.blob_name, saved.size).await {
+            Ok(probe) => probe,
+            Err(e) => {
+                let _ = storage.delete_video(&saved.blob_name).await;
+                return Err(anyhow!("Rendition {} inválida: {}", name, e));
+            }
+        };
+
+        let bitrate_kbps = probe
+            .duration_ms
+            .filter(|ms| *ms > 0)
+            .map(|ms| (saved.size as i64 * 8 / ms) as i32);
+
+        Ok(CreateRenditionRequest {
+            width: probe.width,
+            height: probe.height,
+            bitrate_kbps,
+            codec: probe.video_codec.clone(),
+            asset: CreateMediaAssetRequest {
+                container: storage.container_name().to_string(),
+                storage_key: saved.blob_name,
+                url: saved.url,
+                original_filename: Some(file_name),
+                content_type: probe.format.mime_type().to_string(),
+                size_bytes: saved.size as i64,
+                sha256: saved.sha256,
+                probe: Some(probe),
+            },
+        })
+    }
+
+    async fn discard_uploaded(&self, renditions: &[CreateRenditionRequest]) {
+        for rendition in renditions {
+            if let Err(e) = self
+                .state
+                .video_storage_service
+                .delete_video(&rendition.asset.storage_key)
+                .await
+            {
+                tracing::warn!(
+                    "Falha ao descartar a rendition {}: {:?}",
+                    rendition.asset.storage_key,
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// Grava o resultado do job. Na falha, volta para a fila com espera
+/// exponencial enquanto houver tentativas. Se o lock foi retomado por outro
+/// worker (o job travou e foi devolvido à fila), nada é gravado.
+async fn finish_job(
+    repo: &TranscodeJobsRepository,
+    worker_id: &str,
+    retry_base_delay_secs: u64,
+    job: &TranscodeJobModel,
+    result: Result<()>,
+) {
+    let outcome = match result {
+        Ok(()) => {
+            tracing::info!("Job {} concluído", job.id);
+            repo.mark_succeeded(job.id, worker_id).await
+        }
+        Err(e) => {
+            let message = format!("{:#}", e);
+            let retry_at = (job.attempts < job.max_attempts).then(|| {
+                Utc::now().naive_utc()
+                    + ChronoDuration::seconds(
+                        retry_delay_secs(retry_base_delay_secs, job.attempts) as i64
+                    )
+            });
+
+            match retry_at {
+                Some(at) => tracing::warn!(
+                    "Job {} falhou, nova tentativa em {}: {}",
+                    job.id,
+                    at,
+                    message
+                ),
+                None => tracing::error!(
+                    "Job {} falhou após {} tentativa(s): {}",
+                    job.id,
+                    job.attempts,
+                    message
+                ),
+            }
+
+            repo.mark_failed(job.id, worker_id, &message, retry_at)
+                .await
+        }
+    };
+
+    match outcome {
+        Ok(true) => {}
+        Ok(false) => tracing::warn!(
+            "Job {} não pertence mais a {}; resultado descartado",
+            job.id,
+            worker_id
+        ),
+        Err(e) => tracing::error!("Falha ao atualizar o job {}: {:?}", job.id, e),
+    }
+}
+
+fn retry_delay_secs(base_delay_secs: u64, attempts: i32) -> u64 {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    base_delay_secs
+        .saturating_mul(2u64.pow(exponent))
+        .min(MAX_RETRY_DELAY_SECS)
+}
+
+/// Persiste o progresso publicado pelo transcoder até o canal ser fechado.
+async fn report_progress(state: Arc<AppState>, job_id: i32, mut progress: watch::Receiver<i32>) {
+    let mut last_reported = 0;
+
+    while progress.changed().await.is_ok() {
+        let current = *progress.borrow_and_update();
+
+        // Grava só a cada 5% para não martelar o banco.
+        if current < 100 && current - last_reported < 5 {
+            continue;
+        }
+
+        last_reported = current;
+
+        if let Err(e) = state
+            .transcode_job_repo
+            .update_progress(job_id, current)
+            .await
+        {
+            tracing::warn!("Falha ao registrar progresso do job {}: {:?}", job_id, e);
+        }
+    }
+}
+
+/// Os testes com banco ficam com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcoder::fake::FakeTranscoder;
+    use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+    use shared::enums::transcode_job_status_enum::TranscodeJobStatusEnum;
+    use shared::modules::database::repositories::transcode_jobs_repository::STALE_JOB_ERROR;
+    use shared::modules::database::test_support::test_db;
+    use tokio::sync::Mutex;
+
+    /// `claim_next` pega qualquer job pendente, então os testes com banco não
+    /// podem rodar ao mesmo tempo.
+    static DB_LOCK: Mutex<()> = Mutex::const_new(());
+
+    async fn exec(db: &DatabaseConnection, sql: &str, values: Vec<sea_orm::Value>) {
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await
+        .expect(sql);
+    }
+
+    /// Vídeo, arquivo original e um job pendente à frente de qualquer outro
+    /// na fila.
+    async fn fixture(
+        db: &DatabaseConnection,
+        repo: &TranscodeJobsRepository,
+        max_attempts: i32,
+    ) -> TranscodeJobModel {
+        let ids = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                r#"WITH video AS (
+                       INSERT INTO videos (title, duration_seconds, is_available, rating,
+                                           created_at, updated_at)
+                       VALUES ('worker test', 60, false, 0, now(), now())
+                       RETURNING id
+                   ), asset AS (
+                       INSERT INTO media_assets (container, storage_key, url, content_type,
+                                                 size_bytes, sha256, status, created_at,
+                                                 updated_at)
+                       VALUES ('videos', 'worker-test', 'http://localhost/worker-test',
+                               'video/mp4', 1, 'sha', 'READY', now(), now())
+                       RETURNING id
+                   )
+                   SELECT video.id AS video_id, asset.id AS asset_id FROM video, asset"#
+                    .to_string(),
+            ))
+            .await
+            .expect("fixture")
+            .expect("fixture");
+        let video_id: i32 = ids.try_get("", "video_id").unwrap();
+        let asset_id: i32 = ids.try_get("", "asset_id").unwrap();
+
+        let job = repo.enqueue(video_id, asset_id).await.expect("enqueue");
+        exec(
+            db,
+            "UPDATE transcode_jobs SET run_after = '2000-01-01', max_attempts = $1 WHERE id = $2",
+            vec![max_attempts.into(), job.id.into()],
+        )
+        .await;
+
+        job
+    }
+
+    async fn cleanup(db: &DatabaseConnection, job: &TranscodeJobModel) {
+        exec(
+            db,
+            "DELETE FROM transcode_jobs WHERE id = $1",
+            vec![job.id.into()],
+        )
+        .await;
+        exec(
+            db,
+            "DELETE FROM media_assets WHERE id = $1",
+            vec![job.source_media_asset_id.into()],
+        )
+        .await;
+        exec(
+            db,
+            "DELETE FROM videos WHERE id = $1",
+            vec![job.video_id.into()],
+        )
+        .await;
+    }
+
+    async fn reload(repo: &TranscodeJobsRepository, job: &TranscodeJobModel) -> TranscodeJobModel {
+        repo.find_by_id(job.id).await.unwrap().expect("job")
+    }
+
+    async fn claim(
+        repo: &TranscodeJobsRepository,
+        worker_id: &str,
+        job: &TranscodeJobModel,
+    ) -> TranscodeJobModel {
+        let claimed = repo
+            .claim_next(worker_id)
+            .await
+            .unwrap()
+            .expect("job pendente");
+        assert_eq!(claimed.id, job.id);
+        claimed
+    }
+
+    /// Roda o `FakeTranscoder` num diretório temporário; sem `input` o
+    /// arquivo de entrada não existe e a transcodificação falha.
+    async fn fake_transcode(input: Option<&[u8]>) -> Result<Vec<String>> {
+        let dir = std::env::temp_dir().join(format!("worker-test-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&dir).await?;
+        let input_path = dir.join("source");
+        if let Some(data) = input {
+            tokio::fs::write(&input_path, data).await?;
+        }
+
+        let targets = ladder_for(Some(720));
+        let (progress_tx, progress_rx) = watch::channel(0);
+        let result = FakeTranscoder
+            .transcode(
+                TranscodeRequest {
+                    input: &input_path,
+                    output_dir: &dir,
+                    targets: &targets,
+                    duration_ms: None,
+                },
+                progress_tx,
+            )
+            .await;
+
+        let outputs = result.map(|outputs| {
+            assert_eq!(*progress_rx.borrow(), 100);
+            outputs
+                .iter()
+                .map(|output| {
+                    assert!(output.path.exists());
+                    output.target.name.to_string()
+                })
+                .collect()
+        });
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        outputs
+    }
+
+    #[test]
+    fn retry_delay_grows_and_is_capped() {
+        assert_eq!(retry_delay_secs(30, 1), 30);
+        assert_eq!(retry_delay_secs(30, 2), 60);
+        assert_eq!(retry_delay_secs(30, 3), 120);
+        assert_eq!(retry_delay_secs(30, 40), MAX_RETRY_DELAY_SECS);
+    }
+
+    #[tokio::test]
+    async fn fake_transcoder_writes_one_file_per_target() {
+        let outputs = fake_transcode(Some(b"mp4")).await.unwrap();
+        assert_eq!(outputs, vec!["720p", "480p", "360p"]);
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn claim_locks_job_for_worker() {
+        let db = test_db().await;
+        let _guard = DB_LOCK.lock().await;
+        let repo = TranscodeJobsRepository::new(db.clone());
+        let job = fixture(&db, &repo, 3).await;
+
+        let claimed = claim(&repo, "worker-a", &job).await;
+        assert_eq!(claimed.status, TranscodeJobStatusEnum::RUNNING.as_str());
+        assert_eq!(claimed.locked_by.as_deref(), Some("worker-a"));
+        assert_eq!(claimed.attempts, 1);
+        assert!(claimed.locked_at.is_some());
+
+        cleanup(&db, &job).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn successful_job_is_marked_succeeded() {
+        let db = test_db().await;
+        let _guard = DB_LOCK.lock().await;
+        let repo = TranscodeJobsRepository::new(db.clone());
+        let job = fixture(&db, &repo, 3).await;
+
+        let claimed = claim(&repo, "worker-a", &job).await;
+        let result = fake_transcode(Some(b"mp4")).await.map(|_| ());
+        finish_job(&repo, "worker-a", 30, &claimed, result).await;
+
+        let done = reload(&repo, &job).await;
+        assert_eq!(done.status, TranscodeJobStatusEnum::SUCCEEDED.as_str());
+        assert_eq!(done.progress, 100);
+        assert_eq!(done.locked_by, None);
+        assert!(done.finished_at.is_some());
+
+        cleanup(&db, &job).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn failed_job_goes_back_to_queue_with_backoff() {
+        let db = test_db().await;
+        let _guard = DB_LOCK.lock().await;
+        let repo = TranscodeJobsRepository::new(db.clone());
+        let job = fixture(&db, &repo, 3).await;
+
+        let claimed = claim(&repo, "worker-a", &job).await;
+        let result = fake_transcode(None).await.map(|_| ());
+        assert!(result.is_err());
+        finish_job(&repo, "worker-a", 30, &claimed, result).await;
+
+        let failed = reload(&repo, &job).await;
+        assert_eq!(failed.status, TranscodeJobStatusEnum::PENDING.as_str());
+        assert_eq!(failed.locked_by, None);
+        assert!(failed.last_error.is_some());
+        assert!(failed.run_after > Utc::now().naive_utc());
+
+        cleanup(&db, &job).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn last_attempt_fails_job_and_admin_retry_requeues_it() {
+        let db = test_db().await;
+        let _guard = DB_LOCK.lock().await;
+        let repo = TranscodeJobsRepository::new(db.clone());
+        let job = fixture(&db, &repo, 1).await;
+
+        let claimed = claim(&repo, "worker-a", &job).await;
+        finish_job(
+            &repo,
+            "worker-a",
+            30,
+            &claimed,
+            Err(anyhow!("ffmpeg saiu com 1")),
+        )
+        .await;
+
+        let failed = reload(&repo, &job).await;
+        assert_eq!(failed.status, TranscodeJobStatusEnum::FAILED.as_str());
+        assert_eq!(failed.last_error.as_deref(), Some("ffmpeg saiu com 1"));
+
+        assert!(repo.retry(job.id).await.unwrap());
+        let retried = reload(&repo, &job).await;
+        assert_eq!(retried.status, TranscodeJobStatusEnum::PENDING.as_str());
+        assert_eq!(retried.attempts, 0);
+
+        cleanup(&db, &job).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn stale_job_is_requeued_and_old_worker_loses_lock() {
+        let db = test_db().await;
+        let _guard = DB_LOCK.lock().await;
+        let repo = TranscodeJobsRepository::new(db.clone());
+        let job = fixture(&db, &repo, 3).await;
+
+        let stale = claim(&repo, "worker-a", &job).await;
+        let stale_before = stale.locked_at.unwrap() + ChronoDuration::seconds(1);
+        let requeued = repo.requeue_stale(stale_before).await.unwrap();
+        assert!(requeued.requeued >= 1);
+        assert_eq!(
+            reload(&repo, &job).await.status,
+            TranscodeJobStatusEnum::PENDING.as_str()
+        );
+
+        exec(
+            &db,
+            "UPDATE transcode_jobs SET run_after = '2000-01-01' WHERE id = $1",
+            vec![job.id.into()],
+        )
+        .await;
+        claim(&repo, "worker-b", &job).await;
+
+        // o worker antigo termina depois e não pode sobrescrever o novo dono
+        finish_job(&repo, "worker-a", 30, &stale, Ok(())).await;
+        assert!(!repo
+            .mark_failed(job.id, "worker-a", "tarde", None)
+            .await
+            .unwrap());
+
+        let current = reload(&repo, &job).await;
+        assert_eq!(current.status, TranscodeJobStatusEnum::RUNNING.as_str());
+        assert_eq!(current.locked_by.as_deref(), Some("worker-b"));
+        assert_eq!(current.attempts, 2);
+
+        cleanup(&db, &job).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn stale_job_without_attempts_left_is_failed() {
+        let db = test_db().await;
+        let _guard = DB_LOCK.lock().await;
+        let repo = TranscodeJobsRepository::new(db.clone());
+        let job = fixture(&db, &repo, 1).await;
+
+        let claimed = claim(&repo, "worker-a", &job).await;
+        let stale_before = claimed.locked_at.unwrap() + ChronoDuration::seconds(1);
+        let stale = repo.requeue_stale(stale_before).await.unwrap();
+        assert!(stale.failed >= 1);
+
+        let failed = reload(&repo, &job).await;
+        assert_eq!(failed.status, TranscodeJobStatusEnum::FAILED.as_str());
+        assert_eq!(failed.last_error.as_deref(), Some(STALE_JOB_ERROR));
+        assert_eq!(failed.locked_by, None);
+
+        cleanup(&db, &job).await;
+    }
+}
@@ -9,7 +9,7 @@ use shared::modules::app_state::AppState;
 use shared::modules::config::Config;
 use shared::modules::interceptors::transform_middleware::transform_middleware;
 
-// mod modules;
+mod modules;
 mod routes;
 
 #[tokio::main]
@@ -28,7 +28,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Router
     let app = Router::new()
-        // .nest("/viewer", routes::create_router())
+        .nest("/viewer", routes::create_router())
         .route("/", get(|| async { "Viewer API - Running" }))
         .layer(axum::middleware::from_fn(transform_middleware))
         .layer(Extension(app_state.clone()));
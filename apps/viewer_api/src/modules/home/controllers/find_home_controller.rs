@@ -0,0 +1,60 @@
+use crate::modules::home::{
+    dto::io::{home_input_dto::HomeInputDTO, home_output_dto::HomeOutputDTO},
+    services::find_home_service,
+};
+use axum::{http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion, maturity::ParentalControl,
+        profile::profile_extractor::ViewerProfile, response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<HomeOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input = HomeInputDTO::from((ParentalControl::from(&profile), region));
+
+    match find_home_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_home_service::FindHomeError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao montar a home".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
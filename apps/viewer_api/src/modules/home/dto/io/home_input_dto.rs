@@ -0,0 +1,21 @@
+use shared::enums::maturity_rating_enum::MaturityRatingEnum;
+use shared::modules::maturity::ParentalControl;
+
+#[derive(Debug, Clone)]
+pub struct HomeInputDTO {
+    pub profile_id: i32,
+    /// Classificação máxima do perfil; `None` libera tudo.
+    pub max_rating: Option<MaturityRatingEnum>,
+    /// Região do espectador, para as janelas de disponibilidade.
+    pub region: Option<String>,
+}
+
+impl From<(ParentalControl, Option<String>)> for HomeInputDTO {
+    fn from((control, region): (ParentalControl, Option<String>)) -> Self {
+        Self {
+            profile_id: control.profile_id,
+            max_rating: control.max_rating,
+            region,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use shared::enums::home_row_kind_enum::HomeRowKindEnum;
+use shared::enums::trending_kind_enum::TrendingKindEnum;
+use shared::modules::database::repositories::images_repository::CatalogImageUrls;
+use shared::modules::database::schema::series::Model as SerieModel;
+use shared::modules::database::schema::videos::Model as VideoModel;
+use shared::modules::maturity;
+
+use crate::modules::recommendation::dto::io::recommended_video_output_dto::RecommendedVideoOutputDTO;
+
+/// Filme, episódio ou série de uma linha. Vai para o cache da linha, por
+/// isso também é `Deserialize`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HomeItemOutputDTO {
+    #[serde(rename = "type")]
+    pub kind: TrendingKindEnum,
+    pub id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub release_year: Option<i32>,
+    pub thumbnail_url: Option<String>,
+    pub images: CatalogImageUrls,
+    pub series_id: Option<i32>,
+    pub season_number: Option<i32>,
+    pub episode_number: Option<i32>,
+    pub duration_seconds: Option<i32>,
+    pub maturity_rating: Option<String>,
+    pub content_descriptors: Vec<String>,
+    /// Só em "continue assistindo".
+    pub watched_seconds: Option<i32>,
+}
+
+impl From<VideoModel> for HomeItemOutputDTO {
+    fn from(video: VideoModel) -> Self {
+        Self {
+            kind: TrendingKindEnum::VIDEO,
+            id: video.id,
+            title: video.title,
+            description: video.description,
+            release_year: video.release_year,
+            thumbnail_url: video.thumbnail_url,
+            images: CatalogImageUrls::new(),
+            series_id: video.series_id,
+            season_number: video.season_number,
+            episode_number: video.episode_number,
+            duration_seconds: Some(video.duration_seconds),
+            content_descriptors: maturity::descriptors(&video.content_descriptors),
+            maturity_rating: video.maturity_rating,
+            watched_seconds: None,
+        }
+    }
+}
+
+impl From<SerieModel> for HomeItemOutputDTO {
+    fn from(serie: SerieModel) -> Self {
+        Self {
+            kind: TrendingKindEnum::SERIE,
+            id: serie.id,
+            title: serie.title,
+            description: serie.description,
+            release_year: serie.release_year,
+            thumbnail_url: serie.thumbnail_url,
+            images: CatalogImageUrls::new(),
+            series_id: None,
+            season_number: None,
+            episode_number: None,
+            duration_seconds: None,
+            content_descriptors: maturity::descriptors(&serie.content_descriptors),
+            maturity_rating: serie.maturity_rating,
+            watched_seconds: None,
+        }
+    }
+}
+
+impl From<RecommendedVideoOutputDTO> for HomeItemOutputDTO {
+    fn from(video: RecommendedVideoOutputDTO) -> Self {
+        Self {
+            kind: TrendingKindEnum::VIDEO,
+            id: video.id,
+            title: video.title,
+            description: video.description,
+            release_year: video.release_year,
+            thumbnail_url: video.thumbnail_url,
+            images: video.images,
+            series_id: video.series_id,
+            season_number: video.season_number,
+            episode_number: video.episode_number,
+            duration_seconds: Some(video.duration_seconds),
+            maturity_rating: video.maturity_rating,
+            content_descriptors: video.content_descriptors,
+            watched_seconds: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct HomeRowOutputDTO {
+    pub id: i32,
+    pub kind: HomeRowKindEnum,
+    pub title: String,
+    pub category_id: Option<i32>,
+    pub items: Vec<HomeItemOutputDTO>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HomeOutputDTO {
+    /// Linhas com itens, na ordem definida pelo admin.
+    pub rows: Vec<HomeRowOutputDTO>,
+    /// `true` quando alguma linha falhou e ficou de fora.
+    pub partial: bool,
+    pub failed_row_ids: Vec<i32>,
+}
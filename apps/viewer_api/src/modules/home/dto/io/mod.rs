@@ -0,0 +1,2 @@
+pub mod home_input_dto;
+pub mod home_output_dto;
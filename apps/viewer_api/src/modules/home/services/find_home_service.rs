@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use futures::future::join_all;
+use shared::{
+    enums::home_row_kind_enum::HomeRowKindEnum,
+    modules::{app_state::AppState, database::schema::home_rows::Model as HomeRowModel, home},
+};
+
+use crate::modules::home::{
+    dto::io::{
+        home_input_dto::HomeInputDTO,
+        home_output_dto::{HomeItemOutputDTO, HomeOutputDTO, HomeRowOutputDTO},
+    },
+    services::home_row_service,
+};
+
+pub enum FindHomeError {
+    Database(String),
+}
+
+/// Monta a home com as linhas ativas, buscadas em paralelo. Uma linha que
+/// falha fica de fora e a resposta sai com `partial`; linhas vazias são
+/// omitidas.
+pub async fn execute(
+    input: HomeInputDTO,
+    state: Arc<AppState>,
+) -> Result<HomeOutputDTO, FindHomeError> {
+    let rows =
+        state.home_row_repo.find_all(true).await.map_err(|e| {
+            FindHomeError::Database(format!("Erro ao buscar as linhas da home: {}", e))
+        })?;
+
+    let results = join_all(rows.iter().map(|row| load_row(&state, row, &input))).await;
+
+    let mut output = HomeOutputDTO {
+        rows: Vec::new(),
+        partial: false,
+        failed_row_ids: Vec::new(),
+    };
+
+    for (row, result) in rows.into_iter().zip(results) {
+        match result {
+            Ok(items) if items.is_empty() => {}
+            Ok(items) => {
+                let Ok(kind) = HomeRowKindEnum::try_from(row.kind.as_str()) else {
+                    continue;
+                };
+                output.rows.push(HomeRowOutputDTO {
+                    id: row.id,
+                    kind,
+                    title: row.title,
+                    category_id: row.category_id,
+                    items,
+                });
+            }
+            Err(msg) => {
+                tracing::warn!("Falha ao montar a linha {} da home: {}", row.id, msg);
+                output.partial = true;
+                output.failed_row_ids.push(row.id);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Itens da linha, do cache quando houver. Falhas no Redis só vão para o log.
+async fn load_row(
+    state: &Arc<AppState>,
+    row: &HomeRowModel,
+    input: &HomeInputDTO,
+) -> Result<Vec<HomeItemOutputDTO>, String> {
+    let ttl = row.cache_ttl_secs.max(0) as u64;
+    let personal = HomeRowKindEnum::try_from(row.kind.as_str())?.is_personal();
+    let key = home::row_cache_key(
+        row.id,
+        input.region.as_deref(),
+        input.max_rating,
+        personal.then_some(input.profile_id),
+    );
+
+    if ttl > 0 {
+        match state.cache.get::<_, Vec<HomeItemOutputDTO>>(&key).await {
+            Ok(Some(items)) => return Ok(items),
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Falha ao ler a linha {} da home do cache: {:?}", row.id, e),
+        }
+    }
+
+    let items = home_row_service::load(state, row, input).await?;
+
+    if ttl > 0 {
+        if let Err(e) = state.cache.set_with_ttl(&key, &items, ttl).await {
+            tracing::warn!(
+                "Falha ao gravar a linha {} da home no cache: {:?}",
+                row.id,
+                e
+            );
+        }
+    }
+
+    Ok(items)
+}
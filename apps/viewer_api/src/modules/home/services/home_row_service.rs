@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use sea_orm::DbErr;
+use shared::{
+    enums::{
+        home_row_kind_enum::HomeRowKindEnum, image_owner_enum::ImageOwnerEnum,
+        trending_kind_enum::TrendingKindEnum, trending_window_enum::TrendingWindowEnum,
+    },
+    modules::{
+        app_state::AppState,
+        database::{
+            repositories::{
+                home_rows_repository::HomeRowItem, images_repository::catalog_image_urls,
+                search_repository::LiveFilter,
+            },
+            schema::home_rows::Model as HomeRowModel,
+        },
+    },
+};
+
+use crate::modules::{
+    home::dto::io::{home_input_dto::HomeInputDTO, home_output_dto::HomeItemOutputDTO},
+    recommendation::{
+        dto::io::recommendations_input_dto::RecommendationsInputDTO,
+        services::find_recommendations_service::{self, FindRecommendationsError},
+    },
+    trending::{
+        dto::io::trending_input_dto::TrendingInputDTO,
+        services::find_trending_service::{self, FindTrendingError},
+    },
+};
+
+/// Busca os itens de uma linha na fonte indicada pelo `kind`, só com o que
+/// está no ar para o espectador.
+pub async fn load(
+    state: &Arc<AppState>,
+    row: &HomeRowModel,
+    input: &HomeInputDTO,
+) -> Result<Vec<HomeItemOutputDTO>, String> {
+    let map_err = |e: DbErr| e.to_string();
+
+    let kind = HomeRowKindEnum::try_from(row.kind.as_str())?;
+    let limit = row.item_limit.max(1) as u64;
+    let live = LiveFilter {
+        at: Utc::now().naive_utc(),
+        region: input.region.clone(),
+        max_rating: input.max_rating,
+    };
+    let repo = &state.home_row_repo;
+
+    let refs = match kind {
+        HomeRowKindEnum::FEATURED_SERIES => repo
+            .find_featured_series(&live, limit)
+            .await
+            .map_err(map_err)?,
+        HomeRowKindEnum::CONTINUE_WATCHING => repo
+            .find_continue_watching(input.profile_id, &live, limit)
+            .await
+            .map_err(map_err)?,
+        HomeRowKindEnum::MY_LIST => repo
+            .find_favorites(input.profile_id, &live, limit)
+            .await
+            .map_err(map_err)?,
+        HomeRowKindEnum::NEW_RELEASES => repo
+            .find_titles(None, &live, limit)
+            .await
+            .map_err(map_err)?,
+        HomeRowKindEnum::CATEGORY => {
+            let category_id = row
+                .category_id
+                .ok_or_else(|| "Linha de categoria sem categoria".to_string())?;
+            repo.find_titles(Some(category_id), &live, limit)
+                .await
+                .map_err(map_err)?
+        }
+        HomeRowKindEnum::TRENDING => {
+            let window = row
+                .trending_window
+                .as_deref()
+                .map(TrendingWindowEnum::try_from)
+                .transpose()?
+                .unwrap_or(TrendingWindowEnum::DAY);
+            let trending_input = TrendingInputDTO {
+                window,
+                category_id: row.category_id,
+                limit: limit as usize,
+                region: input.region.clone(),
+                max_rating: input.max_rating,
+            };
+
+            find_trending_service::find_live_entries(state, &trending_input)
+                .await
+                .map_err(|err| match err {
+                    FindTrendingError::Database(msg) | FindTrendingError::NotFound(msg) => msg,
+                })?
+                .into_iter()
+                .map(|entry| HomeRowItem {
+                    item_type: entry.kind.as_str().to_string(),
+                    id: entry.id,
+                    watched_seconds: None,
+                })
+                .collect()
+        }
+        HomeRowKindEnum::RECOMMENDATIONS => {
+            let recommendations = find_recommendations_service::execute(
+                RecommendationsInputDTO {
+                    profile_id: input.profile_id,
+                    max_rating: input.max_rating,
+                    limit,
+                    region: input.region.clone(),
+                },
+                state.clone(),
+            )
+            .await
+            .map_err(|err| match err {
+                FindRecommendationsError::Database(msg) => msg,
+            })?;
+
+            return Ok(recommendations.items.into_iter().map(Into::into).collect());
+        }
+    };
+
+    load_items(state, refs).await.map_err(map_err)
+}
+
+/// Carrega vídeos, séries e imagens dos itens, mantendo a ordem recebida.
+async fn load_items(
+    state: &AppState,
+    refs: Vec<HomeRowItem>,
+) -> Result<Vec<HomeItemOutputDTO>, DbErr> {
+    let ids_of = |kind: TrendingKindEnum| -> Vec<i32> {
+        refs.iter()
+            .filter(|item| item.item_type == kind.as_str())
+            .map(|item| item.id)
+            .collect()
+    };
+    let video_ids = ids_of(TrendingKindEnum::VIDEO);
+    let serie_ids = ids_of(TrendingKindEnum::SERIE);
+
+    let mut videos: HashMap<i32, _> = state
+        .video_repo
+        .find_by_ids(&video_ids)
+        .await?
+        .into_iter()
+        .map(|video| (video.id, video))
+        .collect();
+    let mut series: HashMap<i32, _> = state
+        .serie_repo
+        .find_by_ids(&serie_ids)
+        .await?
+        .into_iter()
+        .map(|serie| (serie.id, serie))
+        .collect();
+
+    let mut video_images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::VIDEO, &video_ids)
+        .await
+        .map(catalog_image_urls)?;
+    let mut serie_images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::SERIE, &serie_ids)
+        .await
+        .map(catalog_image_urls)?;
+
+    let mut items = Vec::with_capacity(refs.len());
+    for item_ref in refs {
+        let (mut item, images) = match TrendingKindEnum::try_from(item_ref.item_type.as_str()) {
+            Ok(TrendingKindEnum::VIDEO) => match videos.remove(&item_ref.id) {
+                Some(video) => (
+                    HomeItemOutputDTO::from(video),
+                    video_images.remove(&item_ref.id),
+                ),
+                None => continue,
+            },
+            Ok(TrendingKindEnum::SERIE) => match series.remove(&item_ref.id) {
+                Some(serie) => (
+                    HomeItemOutputDTO::from(serie),
+                    serie_images.remove(&item_ref.id),
+                ),
+                None => continue,
+            },
+            Err(_) => continue,
+        };
+
+        item.images = images.unwrap_or_default();
+        item.watched_seconds = item_ref.watched_seconds;
+        items.push(item);
+    }
+
+    Ok(items)
+}
@@ -0,0 +1,2 @@
+pub mod find_home_service;
+pub mod home_row_service;
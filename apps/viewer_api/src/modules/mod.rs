@@ -0,0 +1,6 @@
+pub mod home;
+pub mod playback;
+pub mod recommendation;
+pub mod search;
+pub mod trending;
+pub mod video;
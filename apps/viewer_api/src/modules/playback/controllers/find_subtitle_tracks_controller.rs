@@ -0,0 +1,57 @@
+use crate::modules::playback::{
+    controllers::error_response,
+    dto::{
+        io::{
+            subtitle_track_output_dto::SubtitleTrackOutputDTO,
+            subtitle_tracks_input_dto::SubtitleTracksInputDTO,
+        },
+        route_params::playback_route_params_dto::PlaybackRouteParamsDTO,
+    },
+    services::find_subtitle_tracks_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion, maturity::ParentalControl,
+        profile::profile_extractor::ViewerProfile, response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    Path(params): Path<PlaybackRouteParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<Vec<SubtitleTrackOutputDTO>>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input: SubtitleTracksInputDTO = (params, ParentalControl::from(&profile), region).into();
+
+    match find_subtitle_tracks_service::execute(input, state).await {
+        Ok(tracks) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(tracks),
+                message: None,
+            }),
+        )),
+        Err(err) => Err(error_response(err, "Erro ao buscar legendas")),
+    }
+}
@@ -0,0 +1,74 @@
+use crate::modules::playback::{
+    controllers::{error_response, MANIFEST_CACHE_CONTROL},
+    dto::{
+        io::rendition_playlist_input_dto::RenditionPlaylistInputDTO,
+        route_params::rendition_playlist_route_params_dto::RenditionPlaylistRouteParamsDTO,
+    },
+    services::hls_media_playlist_service,
+};
+use axum::{
+    body::Body,
+    extract::Path,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use macros::require_access;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion,
+        maturity::ParentalControl,
+        playback_session::{device_extractor::ViewerDevice, StreamClaim},
+        profile::profile_extractor::ViewerProfile,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    ViewerDevice(device): ViewerDevice,
+    Path(params): Path<RenditionPlaylistRouteParamsDTO>,
+) -> Result<Response, (StatusCode, Json<ValidationErrorResponse>)> {
+    let height_limit = AccessGroupEnum::playback_height_limit(&user.access_groups);
+    let claim = StreamClaim {
+        limit: AccessGroupEnum::stream_limit(&user.access_groups),
+        user_id: user.id,
+        profile_id: profile.id,
+        device,
+    };
+    let input: RenditionPlaylistInputDTO = (
+        params,
+        height_limit,
+        ParentalControl::from(&profile),
+        region,
+        claim,
+    )
+        .into();
+
+    match hls_media_playlist_service::execute(input, state).await {
+        Ok(output) => Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, output.content_type),
+                (header::CACHE_CONTROL, MANIFEST_CACHE_CONTROL),
+            ],
+            Body::from(output.body),
+        )
+            .into_response()),
+        Err(err) => Err(error_response(err, "Erro ao gerar a playlist HLS")),
+    }
+}
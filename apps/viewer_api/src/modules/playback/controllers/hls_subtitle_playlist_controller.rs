@@ -0,0 +1,56 @@
+use crate::modules::playback::{
+    controllers::{error_response, SUBTITLE_CACHE_CONTROL},
+    dto::{
+        io::subtitle_input_dto::SubtitleInputDTO,
+        route_params::subtitle_route_params_dto::SubtitleRouteParamsDTO,
+    },
+    services::hls_subtitle_playlist_service,
+};
+use axum::{
+    body::Body,
+    extract::Path,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use macros::require_access;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion, maturity::ParentalControl,
+        profile::profile_extractor::ViewerProfile,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    Path(params): Path<SubtitleRouteParamsDTO>,
+) -> Result<Response, (StatusCode, Json<ValidationErrorResponse>)> {
+    let input: SubtitleInputDTO = (params, ParentalControl::from(&profile), region).into();
+
+    match hls_subtitle_playlist_service::execute(input, state).await {
+        Ok(output) => Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, output.content_type),
+                (header::CACHE_CONTROL, SUBTITLE_CACHE_CONTROL),
+            ],
+            Body::from(output.body),
+        )
+            .into_response()),
+        Err(err) => Err(error_response(err, "Erro ao gerar a playlist de legenda")),
+    }
+}
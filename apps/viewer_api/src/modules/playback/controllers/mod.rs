@@ -0,0 +1,58 @@
+use axum::{http::StatusCode, Json};
+use serde_json::json;
+use shared::modules::validation::validation_layer::ValidationErrorResponse;
+
+use crate::modules::playback::services::entitled_renditions_service::PlaybackError;
+
+pub mod dash_manifest_controller;
+pub mod find_subtitle_tracks_controller;
+pub mod hls_master_playlist_controller;
+pub mod hls_media_playlist_controller;
+pub mod hls_subtitle_playlist_controller;
+pub mod playback_segment_controller;
+pub mod subtitle_file_controller;
+
+/// Manifestos variam com o plano do usuário e com novas renditions.
+pub const MANIFEST_CACHE_CONTROL: &str = "private, max-age=30";
+
+/// Legendas podem ser substituídas a qualquer momento no admin.
+pub const SUBTITLE_CACHE_CONTROL: &str = "private, max-age=300";
+
+/// Um media asset nunca muda de conteúdo; renditions novas ganham outro id.
+pub const SEGMENT_CACHE_CONTROL: &str = "private, max-age=31536000, immutable";
+
+/// Resposta de erro comum a manifestos, segmentos e legendas. No limite de
+/// telas, `errors` traz os dispositivos em uso, como no stream.
+pub fn error_response(
+    err: PlaybackError,
+    message: &str,
+) -> (StatusCode, Json<ValidationErrorResponse>) {
+    let (status, msg) = match err {
+        PlaybackError::Database(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        PlaybackError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+        PlaybackError::Restricted(msg) => (StatusCode::FORBIDDEN, msg),
+        PlaybackError::Kicked(msg) => (StatusCode::FORBIDDEN, msg),
+        PlaybackError::StreamLimit(limit, sessions) => {
+            return (
+                StatusCode::CONFLICT,
+                Json(ValidationErrorResponse {
+                    message: format!(
+                        "Limite de {} tela(s) simultânea(s) atingido. Encerre a reprodução em outro dispositivo",
+                        limit
+                    ),
+                    errors: json!(sessions),
+                }),
+            );
+        }
+        PlaybackError::Unavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
+        PlaybackError::Storage(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+    };
+
+    (
+        status,
+        Json(ValidationErrorResponse {
+            message: message.to_string(),
+            errors: json!([msg]),
+        }),
+    )
+}
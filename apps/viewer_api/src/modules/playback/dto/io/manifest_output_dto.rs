@@ -0,0 +1,5 @@
+#[derive(Debug)]
+pub struct ManifestOutputDTO {
+    pub body: String,
+    pub content_type: &'static str,
+}
@@ -0,0 +1,8 @@
+pub mod manifest_output_dto;
+pub mod playback_input_dto;
+pub mod playback_segment_input_dto;
+pub mod playback_segment_output_dto;
+pub mod rendition_playlist_input_dto;
+pub mod subtitle_input_dto;
+pub mod subtitle_track_output_dto;
+pub mod subtitle_tracks_input_dto;
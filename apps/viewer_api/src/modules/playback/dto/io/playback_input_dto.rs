@@ -0,0 +1,43 @@
+use shared::modules::{maturity::ParentalControl, playback_session::StreamClaim};
+
+use crate::modules::playback::dto::route_params::playback_route_params_dto::PlaybackRouteParamsDTO;
+
+#[derive(Debug)]
+pub struct PlaybackInputDTO {
+    pub video_id: i32,
+    /// Altura máxima liberada pelo plano do usuário.
+    pub height_limit: Option<i32>,
+    pub parental: ParentalControl,
+    /// Região do espectador, para as janelas de disponibilidade.
+    pub region: Option<String>,
+    /// Tela ocupada pelo dispositivo enquanto assiste.
+    pub claim: StreamClaim,
+}
+
+impl
+    From<(
+        PlaybackRouteParamsDTO,
+        Option<i32>,
+        ParentalControl,
+        Option<String>,
+        StreamClaim,
+    )> for PlaybackInputDTO
+{
+    fn from(
+        (params, height_limit, parental, region, claim): (
+            PlaybackRouteParamsDTO,
+            Option<i32>,
+            ParentalControl,
+            Option<String>,
+            StreamClaim,
+        ),
+    ) -> Self {
+        Self {
+            video_id: params.id,
+            height_limit,
+            parental,
+            region,
+            claim,
+        }
+    }
+}
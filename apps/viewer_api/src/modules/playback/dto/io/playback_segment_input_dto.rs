@@ -0,0 +1,45 @@
+use shared::modules::{maturity::ParentalControl, playback_session::StreamClaim};
+
+use crate::modules::playback::dto::route_params::playback_segment_route_params_dto::PlaybackSegmentRouteParamsDTO;
+
+#[derive(Debug)]
+pub struct PlaybackSegmentInputDTO {
+    pub video_id: i32,
+    pub media_asset_id: i32,
+    pub segment: String,
+    pub height_limit: Option<i32>,
+    pub parental: ParentalControl,
+    pub region: Option<String>,
+    /// Tela ocupada pelo dispositivo enquanto assiste.
+    pub claim: StreamClaim,
+}
+
+impl
+    From<(
+        PlaybackSegmentRouteParamsDTO,
+        Option<i32>,
+        ParentalControl,
+        Option<String>,
+        StreamClaim,
+    )> for PlaybackSegmentInputDTO
+{
+    fn from(
+        (params, height_limit, parental, region, claim): (
+            PlaybackSegmentRouteParamsDTO,
+            Option<i32>,
+            ParentalControl,
+            Option<String>,
+            StreamClaim,
+        ),
+    ) -> Self {
+        Self {
+            video_id: params.id,
+            media_asset_id: params.asset_id,
+            segment: params.segment,
+            height_limit,
+            parental,
+            region,
+            claim,
+        }
+    }
+}
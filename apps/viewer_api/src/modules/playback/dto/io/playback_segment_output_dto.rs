@@ -0,0 +1,5 @@
+#[derive(Debug)]
+pub struct PlaybackSegmentOutputDTO {
+    pub data: Vec<u8>,
+    pub content_type: &'static str,
+}
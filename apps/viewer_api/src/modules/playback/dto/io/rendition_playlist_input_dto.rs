@@ -0,0 +1,43 @@
+use shared::modules::{maturity::ParentalControl, playback_session::StreamClaim};
+
+use crate::modules::playback::dto::route_params::rendition_playlist_route_params_dto::RenditionPlaylistRouteParamsDTO;
+
+#[derive(Debug)]
+pub struct RenditionPlaylistInputDTO {
+    pub video_id: i32,
+    pub media_asset_id: i32,
+    pub height_limit: Option<i32>,
+    pub parental: ParentalControl,
+    pub region: Option<String>,
+    /// Tela ocupada pelo dispositivo enquanto assiste.
+    pub claim: StreamClaim,
+}
+
+impl
+    From<(
+        RenditionPlaylistRouteParamsDTO,
+        Option<i32>,
+        ParentalControl,
+        Option<String>,
+        StreamClaim,
+    )> for RenditionPlaylistInputDTO
+{
+    fn from(
+        (params, height_limit, parental, region, claim): (
+            RenditionPlaylistRouteParamsDTO,
+            Option<i32>,
+            ParentalControl,
+            Option<String>,
+            StreamClaim,
+        ),
+    ) -> Self {
+        Self {
+            video_id: params.id,
+            media_asset_id: params.asset_id,
+            height_limit,
+            parental,
+            region,
+            claim,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use shared::modules::maturity::ParentalControl;
+
+use crate::modules::playback::dto::route_params::subtitle_route_params_dto::SubtitleRouteParamsDTO;
+
+#[derive(Debug)]
+pub struct SubtitleInputDTO {
+    pub video_id: i32,
+    pub language: String,
+    pub parental: ParentalControl,
+    pub region: Option<String>,
+}
+
+impl From<(SubtitleRouteParamsDTO, ParentalControl, Option<String>)> for SubtitleInputDTO {
+    fn from(
+        (params, parental, region): (SubtitleRouteParamsDTO, ParentalControl, Option<String>),
+    ) -> Self {
+        Self {
+            video_id: params.id,
+            language: params.language,
+            parental,
+            region,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SubtitleTrackOutputDTO {
+    pub language: String,
+    pub url: String,
+    pub duration_ms: Option<i64>,
+}
@@ -0,0 +1,22 @@
+use shared::modules::maturity::ParentalControl;
+
+use crate::modules::playback::dto::route_params::playback_route_params_dto::PlaybackRouteParamsDTO;
+
+#[derive(Debug)]
+pub struct SubtitleTracksInputDTO {
+    pub video_id: i32,
+    pub parental: ParentalControl,
+    pub region: Option<String>,
+}
+
+impl From<(PlaybackRouteParamsDTO, ParentalControl, Option<String>)> for SubtitleTracksInputDTO {
+    fn from(
+        (params, parental, region): (PlaybackRouteParamsDTO, ParentalControl, Option<String>),
+    ) -> Self {
+        Self {
+            video_id: params.id,
+            parental,
+            region,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod playback_route_params_dto;
+pub mod playback_segment_route_params_dto;
+pub mod rendition_playlist_route_params_dto;
+pub mod subtitle_route_params_dto;
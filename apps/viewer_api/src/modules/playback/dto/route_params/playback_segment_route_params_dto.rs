@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct PlaybackSegmentRouteParamsDTO {
+    #[validate(range(min = 1, message = "O ID deve ser positivo"))]
+    pub id: i32,
+
+    #[validate(range(min = 1, message = "O ID da rendition deve ser positivo"))]
+    pub asset_id: i32,
+
+    /// `init.mp4` ou `{número}.m4s`.
+    pub segment: String,
+}
@@ -0,0 +1,201 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::playback::{
+    dto::io::{manifest_output_dto::ManifestOutputDTO, playback_input_dto::PlaybackInputDTO},
+    services::{
+        entitled_renditions_service::{self, EntitledRendition, PlaybackError},
+        subtitle_tracks_service::{self, SubtitleTrack},
+    },
+};
+
+pub const DASH_CONTENT_TYPE: &str = "application/dash+xml";
+
+/// MPD estático com as renditions como representações de um único
+/// AdaptationSet (áudio e vídeo multiplexados, com `codecs` quando o índice
+/// os traz) e um AdaptationSet de texto por
+/// legenda. As URLs são relativas a `/viewer/video/{id}/dash/manifest.mpd`.
+pub async fn execute(
+    input: PlaybackInputDTO,
+    state: Arc<AppState>,
+) -> Result<ManifestOutputDTO, PlaybackError> {
+    let renditions = entitled_renditions_service::execute(
+        input.video_id,
+        input.height_limit,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    entitled_renditions_service::claim_screen(&state, &input.claim, input.video_id).await?;
+
+    let subtitles = subtitle_tracks_service::execute(
+        input.video_id,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    Ok(ManifestOutputDTO {
+        body: manifest(&renditions, &subtitles),
+        content_type: DASH_CONTENT_TYPE,
+    })
+}
+
+fn manifest(renditions: &[EntitledRendition], subtitles: &[SubtitleTrack]) -> String {
+    let duration_ms = renditions
+        .iter()
+        .map(|rendition| rendition.index.duration_ms())
+        .max()
+        .unwrap_or(0);
+
+    let mut body = String::new();
+    let _ = write!(
+        body,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <MPD xmlns=\"urn:mpeg:dash:schema:mpd:2011\" profiles=\"urn:mpeg:dash:profile:isoff-main:2011\" \
+         type=\"static\" mediaPresentationDuration=\"{}\" minBufferTime=\"PT2S\">\n\
+         <Period id=\"0\" start=\"PT0S\">\n\
+         <AdaptationSet mimeType=\"video/mp4\" segmentAlignment=\"true\" startWithSAP=\"1\">\n",
+        iso_duration(duration_ms)
+    );
+
+    for rendition in renditions {
+        let index = &rendition.index;
+        let segment_base = format!("../segment/{}", rendition.media_asset.id);
+
+        let _ = write!(
+            body,
+            "<Representation id=\"{}\" bandwidth=\"{}\"",
+            rendition.media_asset.id,
+            rendition.peak_bandwidth()
+        );
+        if let Some(codecs) = &rendition.index.codecs {
+            let _ = write!(body, " codecs=\"{}\"", codecs);
+        }
+        if let (Some(width), Some(height)) =
+            (rendition.video_asset.width, rendition.video_asset.height)
+        {
+            let _ = write!(body, " width=\"{}\" height=\"{}\"", width, height);
+        }
+        let _ = write!(
+            body,
+            ">\n<SegmentList timescale=\"{}\">\n<Initialization sourceURL=\"{}/init.mp4\"/>\n<SegmentTimeline>\n",
+            index.timescale, segment_base
+        );
+
+        for (number, segment) in index.segments.iter().enumerate() {
+            if number == 0 {
+                let _ = writeln!(
+                    body,
+                    "<S t=\"{}\" d=\"{}\"/>",
+                    index.earliest_presentation_time, segment.duration
+                );
+            } else {
+                let _ = writeln!(body, "<S d=\"{}\"/>", segment.duration);
+            }
+        }
+
+        body.push_str("</SegmentTimeline>\n");
+
+        for number in 0..index.segments.len() {
+            let _ = writeln!(
+                body,
+                "<SegmentURL media=\"{}/{}.m4s\"/>",
+                segment_base, number
+            );
+        }
+
+        body.push_str("</SegmentList>\n</Representation>\n");
+    }
+
+    body.push_str("</AdaptationSet>\n");
+
+    for track in subtitles {
+        let _ = write!(
+            body,
+            "<AdaptationSet contentType=\"text\" mimeType=\"text/vtt\" lang=\"{}\">\n\
+             <Representation id=\"subtitle-{}\" bandwidth=\"256\">\n\
+             <BaseURL>../subtitle/{}</BaseURL>\n\
+             </Representation>\n</AdaptationSet>\n",
+            track.language, track.language, track.language
+        );
+    }
+
+    body.push_str("</Period>\n</MPD>\n");
+
+    body
+}
+
+/// Duração no formato ISO 8601 usado pelo MPD (`PT12.345S`).
+fn iso_duration(duration_ms: i64) -> String {
+    format!("PT{}.{:03}S", duration_ms / 1000, duration_ms % 1000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::playback::services::entitled_renditions_service::fixtures::{
+        rendition, subtitle,
+    };
+
+    #[test]
+    fn manifest_lists_segments_codecs_and_subtitles() {
+        let renditions = [
+            rendition(
+                11,
+                (1920, 1080),
+                &[(250_000, 2000), (200_000, 1500)],
+                Some("avc1.640028,mp4a.40.2"),
+            ),
+            rendition(12, (1280, 720), &[(100_000, 2000)], None),
+        ];
+        let subtitles = [subtitle(21, "pt-BR")];
+
+        assert_eq!(
+            manifest(&renditions, &subtitles),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <MPD xmlns=\"urn:mpeg:dash:schema:mpd:2011\" profiles=\"urn:mpeg:dash:profile:isoff-main:2011\" type=\"static\" mediaPresentationDuration=\"PT3.500S\" minBufferTime=\"PT2S\">\n\
+             <Period id=\"0\" start=\"PT0S\">\n\
+             <AdaptationSet mimeType=\"video/mp4\" segmentAlignment=\"true\" startWithSAP=\"1\">\n\
+             <Representation id=\"11\" bandwidth=\"1066667\" codecs=\"avc1.640028,mp4a.40.2\" width=\"1920\" height=\"1080\">\n\
+             <SegmentList timescale=\"1000\">\n\
+             <Initialization sourceURL=\"../segment/11/init.mp4\"/>\n\
+             <SegmentTimeline>\n\
+             <S t=\"0\" d=\"2000\"/>\n\
+             <S d=\"1500\"/>\n\
+             </SegmentTimeline>\n\
+             <SegmentURL media=\"../segment/11/0.m4s\"/>\n\
+             <SegmentURL media=\"../segment/11/1.m4s\"/>\n\
+             </SegmentList>\n\
+             </Representation>\n\
+             <Representation id=\"12\" bandwidth=\"400000\" width=\"1280\" height=\"720\">\n\
+             <SegmentList timescale=\"1000\">\n\
+             <Initialization sourceURL=\"../segment/12/init.mp4\"/>\n\
+             <SegmentTimeline>\n\
+             <S t=\"0\" d=\"2000\"/>\n\
+             </SegmentTimeline>\n\
+             <SegmentURL media=\"../segment/12/0.m4s\"/>\n\
+             </SegmentList>\n\
+             </Representation>\n\
+             </AdaptationSet>\n\
+             <AdaptationSet contentType=\"text\" mimeType=\"text/vtt\" lang=\"pt-BR\">\n\
+             <Representation id=\"subtitle-pt-BR\" bandwidth=\"256\">\n\
+             <BaseURL>../subtitle/pt-BR</BaseURL>\n\
+             </Representation>\n\
+             </AdaptationSet>\n\
+             </Period>\n\
+             </MPD>\n"
+        );
+    }
+
+    #[test]
+    fn iso_duration_keeps_milliseconds() {
+        assert_eq!(iso_duration(0), "PT0.000S");
+        assert_eq!(iso_duration(90_005), "PT90.005S");
+    }
+}
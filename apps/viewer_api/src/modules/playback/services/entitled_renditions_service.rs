@@ -0,0 +1,284 @@
+use chrono::Utc;
+use shared::{
+    enums::video_asset_kind_enum::VideoAssetKindEnum,
+    modules::{
+        app_state::AppState,
+        availability,
+        database::schema::{
+            media_assets::Model as MediaAssetModel, video_assets::Model as VideoAssetModel,
+            videos::Model as VideoModel,
+        },
+        maturity::{self, ParentalControl},
+        media_probe::SegmentIndex,
+        playback_session::{self, PlaybackSession, ScreenDenied, StreamClaim},
+    },
+};
+
+pub enum PlaybackError {
+    Database(String),
+    NotFound(String),
+    /// Acima da classificação do perfil e sem liberação com PIN.
+    Restricted(String),
+    /// Dispositivo desconectado pelo dono da conta.
+    Kicked(String),
+    /// Todas as telas do plano em uso; traz o limite e os dispositivos.
+    StreamLimit(usize, Vec<PlaybackSession>),
+    /// Não foi possível contar as telas da conta.
+    Unavailable(String),
+    Storage(String),
+}
+
+/// Rendition segmentada que o usuário pode assistir.
+pub struct EntitledRendition {
+    pub video_asset: VideoAssetModel,
+    pub media_asset: MediaAssetModel,
+    pub index: SegmentIndex,
+}
+
+impl EntitledRendition {
+    /// Pico de bits por segundo entre os segmentos (atributo `BANDWIDTH`).
+    pub fn peak_bandwidth(&self) -> u64 {
+        self.index
+            .segments
+            .iter()
+            .filter(|segment| segment.duration > 0)
+            .map(|segment| (segment.size * 8) as f64 / self.index.segment_seconds(segment))
+            .fold(0.0, f64::max)
+            .ceil() as u64
+    }
+
+    /// Média de bits por segundo do arquivo inteiro.
+    pub fn average_bandwidth(&self) -> u64 {
+        let seconds = self.index.duration_ms() as f64 / 1000.0;
+        if seconds <= 0.0 {
+            return 0;
+        }
+
+        let bytes: u64 = self.index.segments.iter().map(|segment| segment.size).sum();
+        ((bytes * 8) as f64 / seconds).ceil() as u64
+    }
+}
+
+/// Vídeo no ar para a região do espectador neste instante. Fora da janela de
+/// disponibilidade ele é tratado como inexistente; acima da classificação do
+/// perfil, sem liberação com PIN, é recusado.
+pub async fn find_live_video(
+    video_id: i32,
+    region: Option<&str>,
+    parental: &ParentalControl,
+    state: &AppState,
+) -> Result<VideoModel, PlaybackError> {
+    let map_err = |e| PlaybackError::Database(format!("Erro ao buscar vídeo: {}", e));
+
+    let video = state
+        .video_repo
+        .find_by_id(video_id)
+        .await
+        .map_err(map_err)?;
+
+    if let Some(video) = video {
+        let now = Utc::now().naive_utc();
+        if availability::is_video_live(state, &video, region, now)
+            .await
+            .map_err(map_err)?
+        {
+            if !maturity::can_watch(state, parental, &video)
+                .await
+                .map_err(map_err)?
+            {
+                return Err(PlaybackError::Restricted(format!(
+                    "O vídeo {} está acima da classificação do perfil",
+                    video_id
+                )));
+            }
+            return Ok(video);
+        }
+    }
+
+    Err(PlaybackError::NotFound(format!(
+        "Vídeo com id {} não encontrado",
+        video_id
+    )))
+}
+
+/// Ocupa ou renova a tela do dispositivo. Manifestos e segmentos contam como
+/// o stream, senão um player HLS/DASH passaria por fora do limite do plano.
+pub async fn claim_screen(
+    state: &AppState,
+    claim: &StreamClaim,
+    video_id: i32,
+) -> Result<(), PlaybackError> {
+    playback_session::claim_screen(state, claim, video_id)
+        .await
+        .map_err(|denied| match denied {
+            ScreenDenied::Kicked => {
+                PlaybackError::Kicked("Este dispositivo foi desconectado da conta".to_string())
+            }
+            ScreenDenied::LimitReached(limit, sessions) => {
+                PlaybackError::StreamLimit(limit, sessions)
+            }
+            ScreenDenied::Unavailable => PlaybackError::Unavailable(
+                "Não foi possível verificar as telas em uso; tente novamente".to_string(),
+            ),
+        })
+}
+
+/// Renditions disponíveis do vídeo que cabem no plano do usuário, da maior
+/// para a menor. Arquivos sem índice de segmentos não entram nos manifestos.
+pub async fn execute(
+    video_id: i32,
+    height_limit: Option<i32>,
+    region: Option<&str>,
+    parental: &ParentalControl,
+    state: &AppState,
+) -> Result<Vec<EntitledRendition>, PlaybackError> {
+    let video = find_live_video(video_id, region, parental, state).await?;
+
+    let renditions: Vec<EntitledRendition> = state
+        .video_asset_repo
+        .find_playable(video.id, &[VideoAssetKindEnum::RENDITION])
+        .await
+        .map_err(|e| PlaybackError::Database(format!("Erro ao buscar renditions: {}", e)))?
+        .into_iter()
+        .filter(|(asset, _)| match (height_limit, asset.height) {
+            (Some(max), Some(height)) => height <= max,
+            _ => true,
+        })
+        .filter_map(|(video_asset, media_asset)| {
+            let index = media_asset
+                .segment_index
+                .clone()
+                .and_then(|value| serde_json::from_value::<SegmentIndex>(value).ok())
+                .filter(|index| !index.segments.is_empty())?;
+
+            Some(EntitledRendition {
+                video_asset,
+                media_asset,
+                index,
+            })
+        })
+        .collect();
+
+    if renditions.is_empty() {
+        return Err(PlaybackError::NotFound(format!(
+            "Nenhuma rendition segmentada disponível para o vídeo {}",
+            video.id
+        )));
+    }
+
+    Ok(renditions)
+}
+
+/// Busca uma rendition específica entre as liberadas para o usuário.
+pub async fn find_one(
+    video_id: i32,
+    media_asset_id: i32,
+    height_limit: Option<i32>,
+    region: Option<&str>,
+    parental: &ParentalControl,
+    state: &AppState,
+) -> Result<EntitledRendition, PlaybackError> {
+    execute(video_id, height_limit, region, parental, state)
+        .await?
+        .into_iter()
+        .find(|rendition| rendition.media_asset.id == media_asset_id)
+        .ok_or_else(|| {
+            PlaybackError::NotFound(format!(
+                "Rendition {} não encontrada para o vídeo {}",
+                media_asset_id, video_id
+            ))
+        })
+}
+
+/// Renditions e legendas montadas em memória para os testes dos manifestos.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use chrono::NaiveDate;
+    use shared::modules::media_probe::MediaSegment;
+
+    use super::*;
+    use crate::modules::playback::services::subtitle_tracks_service::SubtitleTrack;
+
+    pub fn media_asset(id: i32) -> MediaAssetModel {
+        let created_at = NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        MediaAssetModel {
+            id,
+            container: "media".to_string(),
+            storage_key: format!("renditions/{}.mp4", id),
+            url: format!("https://blob.example/renditions/{}.mp4", id),
+            original_filename: None,
+            content_type: "video/mp4".to_string(),
+            size_bytes: 0,
+            sha256: String::new(),
+            status: "ACTIVE".to_string(),
+            last_verified_at: None,
+            container_format: Some("MP4".to_string()),
+            duration_ms: None,
+            width: None,
+            height: None,
+            video_codec: None,
+            audio_codec: None,
+            faststart: None,
+            segment_index: None,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    /// Rendition com segmentos de `(bytes, milissegundos)` em timescale 1000.
+    pub fn rendition(
+        media_asset_id: i32,
+        size: (i32, i32),
+        segments: &[(u64, u64)],
+        codecs: Option<&str>,
+    ) -> EntitledRendition {
+        let media_asset = media_asset(media_asset_id);
+        let mut offset = 1_000;
+        let segments = segments
+            .iter()
+            .map(|&(size, duration)| {
+                let segment = MediaSegment {
+                    offset,
+                    size,
+                    duration,
+                };
+                offset += size;
+                segment
+            })
+            .collect();
+
+        EntitledRendition {
+            video_asset: VideoAssetModel {
+                id: media_asset_id,
+                video_id: 1,
+                media_asset_id,
+                kind: VideoAssetKindEnum::RENDITION.as_str().to_string(),
+                width: Some(size.0),
+                height: Some(size.1),
+                bitrate_kbps: None,
+                codec: Some("h264".to_string()),
+                language: None,
+                created_at: media_asset.created_at,
+                updated_at: media_asset.updated_at,
+            },
+            media_asset,
+            index: SegmentIndex {
+                init_size: 1_000,
+                timescale: 1000,
+                earliest_presentation_time: 0,
+                segments,
+                codecs: codecs.map(str::to_string),
+            },
+        }
+    }
+
+    pub fn subtitle(media_asset_id: i32, language: &str) -> SubtitleTrack {
+        SubtitleTrack {
+            language: language.to_string(),
+            media_asset: media_asset(media_asset_id),
+        }
+    }
+}
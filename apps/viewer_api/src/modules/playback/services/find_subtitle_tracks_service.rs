@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::playback::{
+    dto::io::{
+        subtitle_track_output_dto::SubtitleTrackOutputDTO,
+        subtitle_tracks_input_dto::SubtitleTracksInputDTO,
+    },
+    services::{entitled_renditions_service::PlaybackError, subtitle_tracks_service},
+};
+
+pub async fn execute(
+    input: SubtitleTracksInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<SubtitleTrackOutputDTO>, PlaybackError> {
+    let tracks = subtitle_tracks_service::execute(
+        input.video_id,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    Ok(tracks
+        .into_iter()
+        .map(|track| SubtitleTrackOutputDTO {
+            url: format!(
+                "/viewer/video/{}/subtitle/{}",
+                input.video_id, track.language
+            ),
+            language: track.language,
+            duration_ms: track.media_asset.duration_ms,
+        })
+        .collect())
+}
@@ -0,0 +1,138 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::playback::{
+    dto::io::{manifest_output_dto::ManifestOutputDTO, playback_input_dto::PlaybackInputDTO},
+    services::{
+        entitled_renditions_service::{self, EntitledRendition, PlaybackError},
+        subtitle_tracks_service::{self, SubtitleTrack},
+    },
+};
+
+pub const HLS_CONTENT_TYPE: &str = "application/vnd.apple.mpegurl";
+
+const SUBTITLES_GROUP_ID: &str = "subs";
+
+/// Playlist master com uma variante por rendition (com `CODECS` quando o
+/// índice os traz) e as legendas como grupo `SUBTITLES`; as URIs são relativas a `/viewer/video/{id}/hls/master.m3u8`.
+pub async fn execute(
+    input: PlaybackInputDTO,
+    state: Arc<AppState>,
+) -> Result<ManifestOutputDTO, PlaybackError> {
+    let renditions = entitled_renditions_service::execute(
+        input.video_id,
+        input.height_limit,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    entitled_renditions_service::claim_screen(&state, &input.claim, input.video_id).await?;
+
+    let subtitles = subtitle_tracks_service::execute(
+        input.video_id,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    Ok(ManifestOutputDTO {
+        body: master_playlist(&renditions, &subtitles),
+        content_type: HLS_CONTENT_TYPE,
+    })
+}
+
+fn master_playlist(renditions: &[EntitledRendition], subtitles: &[SubtitleTrack]) -> String {
+    let mut body = String::from("#EXTM3U\n#EXT-X-VERSION:7\n#EXT-X-INDEPENDENT-SEGMENTS\n");
+
+    for track in subtitles {
+        let _ = writeln!(
+            body,
+            "#EXT-X-MEDIA:TYPE=SUBTITLES,GROUP-ID=\"{}\",NAME=\"{}\",LANGUAGE=\"{}\",\
+             DEFAULT=NO,AUTOSELECT=YES,URI=\"subtitle/{}/playlist.m3u8\"",
+            SUBTITLES_GROUP_ID, track.language, track.language, track.language
+        );
+    }
+
+    for rendition in renditions {
+        let _ = write!(
+            body,
+            "#EXT-X-STREAM-INF:BANDWIDTH={},AVERAGE-BANDWIDTH={}",
+            rendition.peak_bandwidth(),
+            rendition.average_bandwidth()
+        );
+        // índices gravados antes dos codecs ficam sem o atributo
+        if let Some(codecs) = &rendition.index.codecs {
+            let _ = write!(body, ",CODECS=\"{}\"", codecs);
+        }
+        if let (Some(width), Some(height)) =
+            (rendition.video_asset.width, rendition.video_asset.height)
+        {
+            let _ = write!(body, ",RESOLUTION={}x{}", width, height);
+        }
+        if !subtitles.is_empty() {
+            let _ = write!(body, ",SUBTITLES=\"{}\"", SUBTITLES_GROUP_ID);
+        }
+        let _ = write!(body, "\n{}/media.m3u8\n", rendition.media_asset.id);
+    }
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::playback::services::entitled_renditions_service::fixtures::{
+        rendition, subtitle,
+    };
+
+    #[test]
+    fn master_playlist_lists_codecs_resolution_and_subtitles() {
+        let renditions = [
+            rendition(
+                11,
+                (1920, 1080),
+                &[(250_000, 2000), (200_000, 1000)],
+                Some("avc1.640028,mp4a.40.2"),
+            ),
+            rendition(12, (1280, 720), &[(100_000, 2000)], None),
+        ];
+        let subtitles = [subtitle(21, "en"), subtitle(22, "pt-BR")];
+
+        assert_eq!(
+            master_playlist(&renditions, &subtitles),
+            "#EXTM3U\n\
+             #EXT-X-VERSION:7\n\
+             #EXT-X-INDEPENDENT-SEGMENTS\n\
+             #EXT-X-MEDIA:TYPE=SUBTITLES,GROUP-ID=\"subs\",NAME=\"en\",LANGUAGE=\"en\",DEFAULT=NO,AUTOSELECT=YES,URI=\"subtitle/en/playlist.m3u8\"\n\
+             #EXT-X-MEDIA:TYPE=SUBTITLES,GROUP-ID=\"subs\",NAME=\"pt-BR\",LANGUAGE=\"pt-BR\",DEFAULT=NO,AUTOSELECT=YES,URI=\"subtitle/pt-BR/playlist.m3u8\"\n\
+             #EXT-X-STREAM-INF:BANDWIDTH=1600000,AVERAGE-BANDWIDTH=1200000,CODECS=\"avc1.640028,mp4a.40.2\",RESOLUTION=1920x1080,SUBTITLES=\"subs\"\n\
+             11/media.m3u8\n\
+             #EXT-X-STREAM-INF:BANDWIDTH=400000,AVERAGE-BANDWIDTH=400000,RESOLUTION=1280x720,SUBTITLES=\"subs\"\n\
+             12/media.m3u8\n"
+        );
+    }
+
+    #[test]
+    fn master_playlist_without_subtitles_has_no_group() {
+        let renditions = [rendition(
+            11,
+            (640, 360),
+            &[(50_000, 1000)],
+            Some("avc1.42c01e,mp4a.40.2"),
+        )];
+
+        assert_eq!(
+            master_playlist(&renditions, &[]),
+            "#EXTM3U\n\
+             #EXT-X-VERSION:7\n\
+             #EXT-X-INDEPENDENT-SEGMENTS\n\
+             #EXT-X-STREAM-INF:BANDWIDTH=400000,AVERAGE-BANDWIDTH=400000,CODECS=\"avc1.42c01e,mp4a.40.2\",RESOLUTION=640x360\n\
+             11/media.m3u8\n"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::playback::{
+    dto::io::{
+        manifest_output_dto::ManifestOutputDTO,
+        rendition_playlist_input_dto::RenditionPlaylistInputDTO,
+    },
+    services::{
+        entitled_renditions_service::{self, PlaybackError},
+        hls_master_playlist_service::HLS_CONTENT_TYPE,
+    },
+};
+
+/// Playlist VOD de uma rendition, com segmentos fMP4 servidos por
+/// `/viewer/video/{id}/segment/{asset_id}/...`.
+pub async fn execute(
+    input: RenditionPlaylistInputDTO,
+    state: Arc<AppState>,
+) -> Result<ManifestOutputDTO, PlaybackError> {
+    let rendition = entitled_renditions_service::find_one(
+        input.video_id,
+        input.media_asset_id,
+        input.height_limit,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    entitled_renditions_service::claim_screen(&state, &input.claim, input.video_id).await?;
+
+    let index = &rendition.index;
+    let segment_base = format!("../../segment/{}", rendition.media_asset.id);
+
+    let target_duration = index
+        .segments
+        .iter()
+        .map(|segment| index.segment_seconds(segment).ceil() as u64)
+        .max()
+        .unwrap_or(1)
+        .max(1);
+
+    let mut body = String::new();
+    let _ = write!(
+        body,
+        "#EXTM3U\n#EXT-X-VERSION:7\n#EXT-X-TARGETDURATION:{}\n#EXT-X-MEDIA-SEQUENCE:0\n\
+         #EXT-X-PLAYLIST-TYPE:VOD\n#EXT-X-INDEPENDENT-SEGMENTS\n#EXT-X-MAP:URI=\"{}/init.mp4\"\n",
+        target_duration, segment_base
+    );
+
+    for (number, segment) in index.segments.iter().enumerate() {
+        let _ = write!(
+            body,
+            "#EXTINF:{:.3},\n{}/{}.m4s\n",
+            index.segment_seconds(segment),
+            segment_base,
+            number
+        );
+    }
+
+    body.push_str("#EXT-X-ENDLIST\n");
+
+    Ok(ManifestOutputDTO {
+        body,
+        content_type: HLS_CONTENT_TYPE,
+    })
+}
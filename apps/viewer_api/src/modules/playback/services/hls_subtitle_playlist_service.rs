@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::playback::{
+    dto::io::{manifest_output_dto::ManifestOutputDTO, subtitle_input_dto::SubtitleInputDTO},
+    services::{
+        entitled_renditions_service::PlaybackError, hls_master_playlist_service::HLS_CONTENT_TYPE,
+        subtitle_tracks_service,
+    },
+};
+
+/// Playlist de legenda com o WebVTT inteiro como segmento único.
+pub async fn execute(
+    input: SubtitleInputDTO,
+    state: Arc<AppState>,
+) -> Result<ManifestOutputDTO, PlaybackError> {
+    let track = subtitle_tracks_service::find_one(
+        input.video_id,
+        &input.language,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    // a duração da legenda é o fim da última fala, gravado no upload
+    let seconds = track.media_asset.duration_ms.unwrap_or(0).max(1000) as f64 / 1000.0;
+
+    let body = format!(
+        "#EXTM3U\n#EXT-X-VERSION:3\n#EXT-X-TARGETDURATION:{}\n#EXT-X-MEDIA-SEQUENCE:0\n\
+         #EXT-X-PLAYLIST-TYPE:VOD\n#EXTINF:{:.3},\n../../../subtitle/{}\n#EXT-X-ENDLIST\n",
+        seconds.ceil() as u64,
+        seconds,
+        track.language
+    );
+
+    Ok(ManifestOutputDTO {
+        body,
+        content_type: HLS_CONTENT_TYPE,
+    })
+}
@@ -0,0 +1,9 @@
+pub mod dash_manifest_service;
+pub mod entitled_renditions_service;
+pub mod find_subtitle_tracks_service;
+pub mod hls_master_playlist_service;
+pub mod hls_media_playlist_service;
+pub mod hls_subtitle_playlist_service;
+pub mod playback_segment_service;
+pub mod subtitle_file_service;
+pub mod subtitle_tracks_service;
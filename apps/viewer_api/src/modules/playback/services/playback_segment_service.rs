@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::playback::{
+    dto::io::{
+        playback_segment_input_dto::PlaybackSegmentInputDTO,
+        playback_segment_output_dto::PlaybackSegmentOutputDTO,
+    },
+    services::entitled_renditions_service::{self, PlaybackError},
+};
+
+/// Lê do storage apenas o trecho do arquivo correspondente ao segmento pedido.
+pub async fn execute(
+    input: PlaybackSegmentInputDTO,
+    state: Arc<AppState>,
+) -> Result<PlaybackSegmentOutputDTO, PlaybackError> {
+    let rendition = entitled_renditions_service::find_one(
+        input.video_id,
+        input.media_asset_id,
+        input.height_limit,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    entitled_renditions_service::claim_screen(&state, &input.claim, input.video_id).await?;
+
+    let not_found =
+        || PlaybackError::NotFound(format!("Segmento '{}' não encontrado", input.segment));
+
+    let (range, content_type) = match input.segment.as_str() {
+        "init.mp4" => (0..rendition.index.init_size, "video/mp4"),
+        name => {
+            let number: usize = name
+                .strip_suffix(".m4s")
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(not_found)?;
+            let segment = rendition.index.segments.get(number).ok_or_else(not_found)?;
+            (
+                segment.offset..segment.offset + segment.size,
+                "video/iso.segment",
+            )
+        }
+    };
+
+    let data = state
+        .video_storage_service
+        .stream_video(&rendition.media_asset.storage_key, Some(range))
+        .await
+        .map_err(|e| PlaybackError::Storage(format!("Erro ao ler segmento: {}", e)))?;
+
+    Ok(PlaybackSegmentOutputDTO { data, content_type })
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use shared::modules::app_state::AppState;
+
+use crate::modules::playback::{
+    dto::io::{manifest_output_dto::ManifestOutputDTO, subtitle_input_dto::SubtitleInputDTO},
+    services::{entitled_renditions_service::PlaybackError, subtitle_tracks_service},
+};
+
+pub const WEBVTT_CONTENT_TYPE: &str = "text/vtt; charset=utf-8";
+
+pub async fn execute(
+    input: SubtitleInputDTO,
+    state: Arc<AppState>,
+) -> Result<ManifestOutputDTO, PlaybackError> {
+    let track = subtitle_tracks_service::find_one(
+        input.video_id,
+        &input.language,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    let data = state
+        .video_storage_service
+        .get_blob_video_parsed(&track.media_asset.storage_key)
+        .await
+        .map_err(|e| PlaybackError::Storage(format!("Erro ao ler legenda: {}", e)))?;
+
+    Ok(ManifestOutputDTO {
+        body: String::from_utf8_lossy(&data).to_string(),
+        content_type: WEBVTT_CONTENT_TYPE,
+    })
+}
@@ -0,0 +1,64 @@
+use shared::{
+    enums::video_asset_kind_enum::VideoAssetKindEnum,
+    modules::{
+        app_state::AppState, database::schema::media_assets::Model as MediaAssetModel,
+        maturity::ParentalControl,
+    },
+};
+
+use crate::modules::playback::services::entitled_renditions_service::{
+    find_live_video, PlaybackError,
+};
+
+pub struct SubtitleTrack {
+    pub language: String,
+    pub media_asset: MediaAssetModel,
+}
+
+/// Legendas disponíveis do vídeo, ordenadas por idioma.
+pub async fn execute(
+    video_id: i32,
+    region: Option<&str>,
+    parental: &ParentalControl,
+    state: &AppState,
+) -> Result<Vec<SubtitleTrack>, PlaybackError> {
+    let video = find_live_video(video_id, region, parental, state).await?;
+
+    let mut tracks: Vec<SubtitleTrack> = state
+        .video_asset_repo
+        .find_playable(video.id, &[VideoAssetKindEnum::SUBTITLE])
+        .await
+        .map_err(|e| PlaybackError::Database(format!("Erro ao buscar legendas: {}", e)))?
+        .into_iter()
+        .filter_map(|(video_asset, media_asset)| {
+            Some(SubtitleTrack {
+                language: video_asset.language?,
+                media_asset,
+            })
+        })
+        .collect();
+
+    tracks.sort_by(|a, b| a.language.cmp(&b.language));
+
+    Ok(tracks)
+}
+
+/// Busca a legenda de um idioma específico.
+pub async fn find_one(
+    video_id: i32,
+    language: &str,
+    region: Option<&str>,
+    parental: &ParentalControl,
+    state: &AppState,
+) -> Result<SubtitleTrack, PlaybackError> {
+    execute(video_id, region, parental, state)
+        .await?
+        .into_iter()
+        .find(|track| track.language.eq_ignore_ascii_case(language))
+        .ok_or_else(|| {
+            PlaybackError::NotFound(format!(
+                "Legenda '{}' não encontrada para o vídeo {}",
+                language, video_id
+            ))
+        })
+}
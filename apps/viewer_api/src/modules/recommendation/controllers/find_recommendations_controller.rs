@@ -0,0 +1,79 @@
+use crate::modules::recommendation::{
+    dto::{
+        io::{
+            recommendations_input_dto::RecommendationsInputDTO,
+            recommendations_output_dto::RecommendationsOutputDTO,
+        },
+        query_params::recommendations_query_params_dto::RecommendationsQueryParamsDTO,
+    },
+    services::find_recommendations_service,
+};
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion, maturity::ParentalControl,
+        profile::profile_extractor::ViewerProfile, response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    Query(query): Query<RecommendationsQueryParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<RecommendationsOutputDTO>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input = RecommendationsInputDTO::try_from((query, ParentalControl::from(&profile), region))
+        .map_err(|msg| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar recomendações".to_string(),
+                    errors: json!([msg]),
+                }),
+            )
+        })?;
+
+    match find_recommendations_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_recommendations_service::FindRecommendationsError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar recomendações".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
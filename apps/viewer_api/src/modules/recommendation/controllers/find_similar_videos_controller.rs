@@ -0,0 +1,89 @@
+use crate::modules::recommendation::{
+    dto::{
+        io::{
+            recommended_video_output_dto::RecommendedVideoOutputDTO,
+            similar_videos_input_dto::SimilarVideosInputDTO,
+        },
+        query_params::recommendations_query_params_dto::RecommendationsQueryParamsDTO,
+        route_params::similar_videos_route_params_dto::SimilarVideosRouteParamsDTO,
+    },
+    services::find_similar_videos_service,
+};
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Extension, Json,
+};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion, maturity::ParentalControl,
+        profile::profile_extractor::ViewerProfile, response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    Path(params): Path<SimilarVideosRouteParamsDTO>,
+    Query(query): Query<RecommendationsQueryParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<Vec<RecommendedVideoOutputDTO>>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input =
+        SimilarVideosInputDTO::try_from((params, query, ParentalControl::from(&profile), region))
+            .map_err(|msg| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar vídeos parecidos".to_string(),
+                    errors: json!([msg]),
+                }),
+            )
+        })?;
+
+    match find_similar_videos_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_similar_videos_service::FindSimilarVideosError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_similar_videos_service::FindSimilarVideosError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar vídeos parecidos".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod find_recommendations_controller;
+pub mod find_similar_videos_controller;
@@ -0,0 +1,4 @@
+pub mod recommendations_input_dto;
+pub mod recommendations_output_dto;
+pub mod recommended_video_output_dto;
+pub mod similar_videos_input_dto;
@@ -0,0 +1,52 @@
+use shared::enums::maturity_rating_enum::MaturityRatingEnum;
+use shared::modules::maturity::ParentalControl;
+use validator::Validate;
+
+use crate::modules::recommendation::dto::query_params::recommendations_query_params_dto::RecommendationsQueryParamsDTO;
+
+const DEFAULT_LIMIT: i32 = 20;
+
+#[derive(Debug)]
+pub struct RecommendationsInputDTO {
+    pub profile_id: i32,
+    /// Classificação máxima do perfil; `None` libera tudo.
+    pub max_rating: Option<MaturityRatingEnum>,
+    pub limit: u64,
+    /// Região do espectador, para as janelas de disponibilidade.
+    pub region: Option<String>,
+}
+
+impl
+    TryFrom<(
+        RecommendationsQueryParamsDTO,
+        ParentalControl,
+        Option<String>,
+    )> for RecommendationsInputDTO
+{
+    type Error = String;
+
+    fn try_from(
+        (query, control, region): (
+            RecommendationsQueryParamsDTO,
+            ParentalControl,
+            Option<String>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        if let Err(errors) = query.validate() {
+            let message = errors
+                .field_errors()
+                .values()
+                .flat_map(|errors| errors.iter())
+                .find_map(|error| error.message.as_ref().map(|msg| msg.to_string()))
+                .unwrap_or_else(|| "Parâmetros inválidos".to_string());
+            return Err(message);
+        }
+
+        Ok(Self {
+            profile_id: control.profile_id,
+            max_rating: control.max_rating,
+            limit: query.limit.unwrap_or(DEFAULT_LIMIT) as u64,
+            region,
+        })
+    }
+}
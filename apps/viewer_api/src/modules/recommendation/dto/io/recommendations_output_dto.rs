@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+use crate::modules::recommendation::dto::io::recommended_video_output_dto::RecommendedVideoOutputDTO;
+
+#[derive(Debug, Serialize)]
+pub struct RecommendationsOutputDTO {
+    /// `false` quando o espectador ainda não tem histórico e a linha é só
+    /// de populares.
+    pub personalized: bool,
+    pub items: Vec<RecommendedVideoOutputDTO>,
+}
@@ -0,0 +1,64 @@
+use serde::Serialize;
+use shared::enums::recommendation_reason_enum::RecommendationReasonEnum;
+use shared::enums::video_type_enum::VideoTypeEnum;
+use shared::modules::database::repositories::images_repository::CatalogImageUrls;
+use shared::modules::database::schema::videos::Model as VideoModel;
+use shared::modules::maturity;
+
+/// Item do histórico que motivou a recomendação.
+#[derive(Debug, Serialize)]
+pub struct BecauseWatchedOutputDTO {
+    pub id: i32,
+    pub title: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecommendedVideoOutputDTO {
+    pub id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    pub video_type: VideoTypeEnum,
+    pub duration_seconds: i32,
+    pub rating: f64,
+    pub release_year: Option<i32>,
+    pub series_id: Option<i32>,
+    pub season_number: Option<i32>,
+    pub episode_number: Option<i32>,
+    pub thumbnail_url: Option<String>,
+    pub images: CatalogImageUrls,
+    pub maturity_rating: Option<String>,
+    pub content_descriptors: Vec<String>,
+    /// Similaridade, ou audiência recente nos itens populares.
+    pub score: f64,
+    /// Só na linha personalizada.
+    pub reason: Option<RecommendationReasonEnum>,
+    pub because: Option<BecauseWatchedOutputDTO>,
+}
+
+impl From<(VideoModel, f64)> for RecommendedVideoOutputDTO {
+    fn from((video, score): (VideoModel, f64)) -> Self {
+        Self {
+            id: video.id,
+            title: video.title,
+            description: video.description,
+            video_type: match video.series_id {
+                Some(_) => VideoTypeEnum::EPISODE,
+                None => VideoTypeEnum::MOVIE,
+            },
+            duration_seconds: video.duration_seconds,
+            rating: video.rating,
+            release_year: video.release_year,
+            series_id: video.series_id,
+            season_number: video.season_number,
+            episode_number: video.episode_number,
+            thumbnail_url: video.thumbnail_url,
+            images: CatalogImageUrls::new(),
+            content_descriptors: maturity::descriptors(&video.content_descriptors),
+            maturity_rating: video.maturity_rating,
+            score,
+            reason: None,
+            because: None,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use shared::modules::maturity::ParentalControl;
+use validator::Validate;
+
+use crate::modules::recommendation::dto::{
+    query_params::recommendations_query_params_dto::RecommendationsQueryParamsDTO,
+    route_params::similar_videos_route_params_dto::SimilarVideosRouteParamsDTO,
+};
+
+const DEFAULT_LIMIT: i32 = 12;
+
+#[derive(Debug)]
+pub struct SimilarVideosInputDTO {
+    pub video_id: i32,
+    pub limit: u64,
+    pub parental: ParentalControl,
+    /// Região do espectador, para as janelas de disponibilidade.
+    pub region: Option<String>,
+}
+
+impl
+    TryFrom<(
+        SimilarVideosRouteParamsDTO,
+        RecommendationsQueryParamsDTO,
+        ParentalControl,
+        Option<String>,
+    )> for SimilarVideosInputDTO
+{
+    type Error = String;
+
+    fn try_from(
+        (params, query, parental, region): (
+            SimilarVideosRouteParamsDTO,
+            RecommendationsQueryParamsDTO,
+            ParentalControl,
+            Option<String>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let validation = params.validate().and(query.validate());
+        if let Err(errors) = validation {
+            let message = errors
+                .field_errors()
+                .values()
+                .flat_map(|errors| errors.iter())
+                .find_map(|error| error.message.as_ref().map(|msg| msg.to_string()))
+                .unwrap_or_else(|| "Parâmetros inválidos".to_string());
+            return Err(message);
+        }
+
+        Ok(Self {
+            video_id: params.id,
+            limit: query.limit.unwrap_or(DEFAULT_LIMIT) as u64,
+            parental,
+            region,
+        })
+    }
+}
@@ -0,0 +1,3 @@
+pub mod io;
+pub mod query_params;
+pub mod route_params;
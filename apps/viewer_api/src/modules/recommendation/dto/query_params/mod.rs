@@ -0,0 +1 @@
+pub mod recommendations_query_params_dto;
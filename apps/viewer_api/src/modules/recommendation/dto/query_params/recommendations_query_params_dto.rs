@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct RecommendationsQueryParamsDTO {
+    #[validate(range(min = 1, max = 50, message = "O limite deve estar entre 1 e 50"))]
+    pub limit: Option<i32>,
+}
@@ -0,0 +1 @@
+pub mod similar_videos_route_params_dto;
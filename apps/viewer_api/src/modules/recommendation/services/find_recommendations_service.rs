@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use shared::{
+    enums::recommendation_reason_enum::RecommendationReasonEnum,
+    modules::{
+        app_state::AppState,
+        database::repositories::{
+            recommendations_repository::{PersonalQuery, PopularQuery},
+            search_repository::LiveFilter,
+        },
+    },
+};
+
+use crate::modules::recommendation::{
+    dto::io::{
+        recommendations_input_dto::RecommendationsInputDTO,
+        recommendations_output_dto::RecommendationsOutputDTO,
+    },
+    services::recommended_videos_service,
+};
+
+/// Janela da audiência usada para os populares.
+const POPULAR_WINDOW_DAYS: i64 = 30;
+
+pub enum FindRecommendationsError {
+    Database(String),
+}
+
+/// Linha "porque você assistiu" da home. Sem histórico suficiente, ou quando
+/// os parecidos não enchem a linha, completa com os mais assistidos que o
+/// perfil ainda não concluiu.
+pub async fn execute(
+    input: RecommendationsInputDTO,
+    state: Arc<AppState>,
+) -> Result<RecommendationsOutputDTO, FindRecommendationsError> {
+    let map_err =
+        |e| FindRecommendationsError::Database(format!("Erro ao buscar recomendações: {}", e));
+
+    let now = Utc::now().naive_utc();
+    let live = LiveFilter {
+        at: now,
+        region: input.region,
+        max_rating: input.max_rating,
+    };
+
+    let personal = state
+        .recommendation_repo
+        .find_personal(&PersonalQuery {
+            profile_id: input.profile_id,
+            live: live.clone(),
+            limit: input.limit,
+        })
+        .await
+        .map_err(map_err)?;
+
+    let personalized = !personal.is_empty();
+    let missing = input.limit.saturating_sub(personal.len() as u64);
+
+    let popular = if missing > 0 {
+        state
+            .recommendation_repo
+            .find_popular(&PopularQuery {
+                profile_id: Some(input.profile_id),
+                live,
+                since: now - Duration::days(POPULAR_WINDOW_DAYS),
+                exclude: personal.iter().map(|rec| rec.video_id).collect(),
+                limit: missing,
+            })
+            .await
+            .map_err(map_err)?
+    } else {
+        Vec::new()
+    };
+
+    let mut items = recommended_videos_service::load(&state, personal)
+        .await
+        .map_err(map_err)?;
+
+    let mut popular_items = recommended_videos_service::load(&state, popular)
+        .await
+        .map_err(map_err)?;
+    for item in &mut popular_items {
+        item.reason = Some(RecommendationReasonEnum::POPULAR);
+    }
+    items.extend(popular_items);
+
+    Ok(RecommendationsOutputDTO {
+        personalized,
+        items,
+    })
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::modules::{
+    app_state::AppState, availability, database::repositories::search_repository::LiveFilter,
+    maturity,
+};
+
+use crate::modules::recommendation::{
+    dto::io::{
+        recommended_video_output_dto::RecommendedVideoOutputDTO,
+        similar_videos_input_dto::SimilarVideosInputDTO,
+    },
+    services::recommended_videos_service,
+};
+
+pub enum FindSimilarVideosError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Vídeos no ar mais parecidos com o informado, segundo a última execução
+/// de `media_worker compute-similarities`.
+pub async fn execute(
+    input: SimilarVideosInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<RecommendedVideoOutputDTO>, FindSimilarVideosError> {
+    let map_err =
+        |e| FindSimilarVideosError::Database(format!("Erro ao buscar recomendações: {}", e));
+    let not_found = || {
+        FindSimilarVideosError::NotFound(format!("Vídeo com id {} não encontrado", input.video_id))
+    };
+
+    let video = state
+        .video_repo
+        .find_by_id(input.video_id)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(not_found)?;
+
+    let now = Utc::now().naive_utc();
+    if !availability::is_video_live(&state, &video, input.region.as_deref(), now)
+        .await
+        .map_err(map_err)?
+    {
+        return Err(not_found());
+    }
+
+    // acima da classificação do perfil o vídeo não aparece, nem os parecidos
+    if !maturity::can_watch(&state, &input.parental, &video)
+        .await
+        .map_err(map_err)?
+    {
+        return Err(not_found());
+    }
+
+    let live = LiveFilter {
+        at: now,
+        region: input.region,
+        max_rating: input.parental.max_rating,
+    };
+
+    let similar = state
+        .recommendation_repo
+        .find_similar(video.id, &live, input.limit)
+        .await
+        .map_err(map_err)?;
+
+    recommended_videos_service::load(&state, similar)
+        .await
+        .map_err(map_err)
+}
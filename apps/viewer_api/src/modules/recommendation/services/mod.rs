@@ -0,0 +1,3 @@
+pub mod find_recommendations_service;
+pub mod find_similar_videos_service;
+pub mod recommended_videos_service;
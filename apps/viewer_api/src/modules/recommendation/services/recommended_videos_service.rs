@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use sea_orm::DbErr;
+use shared::{
+    enums::{
+        image_owner_enum::ImageOwnerEnum, recommendation_reason_enum::RecommendationReasonEnum,
+    },
+    modules::{
+        app_state::AppState,
+        database::repositories::{
+            images_repository::catalog_image_urls, recommendations_repository::Recommendation,
+        },
+    },
+};
+
+use crate::modules::recommendation::dto::io::recommended_video_output_dto::{
+    BecauseWatchedOutputDTO, RecommendedVideoOutputDTO,
+};
+
+/// Carrega vídeos, imagens e o título do item que motivou cada
+/// recomendação, mantendo a ordem recebida.
+pub async fn load(
+    state: &AppState,
+    recommendations: Vec<Recommendation>,
+) -> Result<Vec<RecommendedVideoOutputDTO>, DbErr> {
+    let mut video_ids: Vec<i32> = recommendations.iter().map(|rec| rec.video_id).collect();
+    video_ids.extend(
+        recommendations
+            .iter()
+            .filter_map(|rec| rec.because_video_id),
+    );
+
+    let videos: HashMap<i32, _> = state
+        .video_repo
+        .find_by_ids(&video_ids)
+        .await?
+        .into_iter()
+        .map(|video| (video.id, video))
+        .collect();
+
+    let mut items: Vec<RecommendedVideoOutputDTO> = recommendations
+        .into_iter()
+        .filter_map(|rec| {
+            let video = videos.get(&rec.video_id)?.clone();
+            let mut item = RecommendedVideoOutputDTO::from((video, rec.score));
+
+            if let Some(because_id) = rec.because_video_id {
+                item.reason = Some(RecommendationReasonEnum::BECAUSE_YOU_WATCHED);
+                item.because = videos
+                    .get(&because_id)
+                    .map(|because| BecauseWatchedOutputDTO {
+                        id: because.id,
+                        title: because.title.clone(),
+                    });
+            }
+
+            Some(item)
+        })
+        .collect();
+
+    let item_ids: Vec<i32> = items.iter().map(|item| item.id).collect();
+    let mut images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::VIDEO, &item_ids)
+        .await
+        .map(catalog_image_urls)?;
+
+    for item in &mut items {
+        if let Some(urls) = images.remove(&item.id) {
+            item.images = urls;
+        }
+    }
+
+    Ok(items)
+}
@@ -0,0 +1,2 @@
+pub mod search_videos_controller;
+pub mod suggest_controller;
@@ -0,0 +1,80 @@
+use crate::modules::search::{
+    dto::{
+        io::{
+            search_videos_input_dto::SearchVideosInputDTO,
+            search_videos_output_dto::SearchVideosOutputDTO,
+        },
+        query_params::search_videos_query_params_dto::SearchVideosQueryParamsDTO,
+    },
+    services::search_videos_service,
+};
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion, maturity::ParentalControl,
+        profile::profile_extractor::ViewerProfile, response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    Query(query): Query<SearchVideosQueryParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<SearchVideosOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input =
+        SearchVideosInputDTO::try_from((query, ParentalControl::from(&profile).max_rating, region))
+            .map_err(|msg| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ValidationErrorResponse {
+                        message: "Erro ao buscar vídeos".to_string(),
+                        errors: json!([msg]),
+                    }),
+                )
+            })?;
+
+    match search_videos_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                search_videos_service::SearchVideosError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                search_videos_service::SearchVideosError::Index(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar vídeos".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::modules::search::{
+    dto::{
+        io::{suggest_input_dto::SuggestInputDTO, suggestion_output_dto::SuggestionOutputDTO},
+        query_params::suggest_query_params_dto::SuggestQueryParamsDTO,
+    },
+    services::suggest_service,
+};
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion, maturity::ParentalControl,
+        profile::profile_extractor::ViewerProfile, response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    Query(query): Query<SuggestQueryParamsDTO>,
+) -> Result<
+    (
+        StatusCode,
+        Json<ResponseInterface<Vec<SuggestionOutputDTO>>>,
+    ),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input =
+        SuggestInputDTO::try_from((query, ParentalControl::from(&profile).max_rating, region))
+            .map_err(|msg| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ValidationErrorResponse {
+                        message: "Erro ao buscar sugestões".to_string(),
+                        errors: json!([msg]),
+                    }),
+                )
+            })?;
+
+    match suggest_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                suggest_service::SuggestError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar sugestões".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
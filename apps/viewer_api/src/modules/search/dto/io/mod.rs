@@ -0,0 +1,4 @@
+pub mod search_videos_input_dto;
+pub mod search_videos_output_dto;
+pub mod suggest_input_dto;
+pub mod suggestion_output_dto;
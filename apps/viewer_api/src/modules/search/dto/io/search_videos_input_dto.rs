@@ -0,0 +1,82 @@
+use shared::enums::maturity_rating_enum::MaturityRatingEnum;
+use shared::enums::video_type_enum::VideoTypeEnum;
+use validator::Validate;
+
+use crate::modules::search::dto::query_params::search_videos_query_params_dto::SearchVideosQueryParamsDTO;
+
+const DEFAULT_TAKE: i32 = 20;
+
+#[derive(Debug)]
+pub struct SearchVideosInputDTO {
+    pub text: Option<String>,
+    pub category_id: Option<i32>,
+    pub year_from: Option<i32>,
+    pub year_to: Option<i32>,
+    pub video_type: Option<VideoTypeEnum>,
+    pub premium: Option<bool>,
+    pub skip: u64,
+    pub take: u64,
+    /// Classificação máxima do perfil; `None` libera tudo.
+    pub max_rating: Option<MaturityRatingEnum>,
+    /// Região do espectador, para as janelas de disponibilidade.
+    pub region: Option<String>,
+}
+
+impl
+    TryFrom<(
+        SearchVideosQueryParamsDTO,
+        Option<MaturityRatingEnum>,
+        Option<String>,
+    )> for SearchVideosInputDTO
+{
+    type Error = String;
+
+    fn try_from(
+        (query, max_rating, region): (
+            SearchVideosQueryParamsDTO,
+            Option<MaturityRatingEnum>,
+            Option<String>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        if let Err(errors) = query.validate() {
+            let message = errors
+                .field_errors()
+                .values()
+                .flat_map(|errors| errors.iter())
+                .find_map(|error| error.message.as_ref().map(|msg| msg.to_string()))
+                .unwrap_or_else(|| "Parâmetros de busca inválidos".to_string());
+            return Err(message);
+        }
+
+        // o ano exato equivale ao intervalo de um ano só
+        let (year_from, year_to) = match query.release_year {
+            Some(year) => (Some(year), Some(year)),
+            None => (query.year_from, query.year_to),
+        };
+        if let (Some(from), Some(to)) = (year_from, year_to) {
+            if from > to {
+                return Err("O ano inicial não pode ser maior que o final".to_string());
+            }
+        }
+
+        Ok(Self {
+            text: query
+                .q
+                .map(|q| q.trim().to_string())
+                .filter(|q| !q.is_empty()),
+            category_id: query.category_id,
+            year_from,
+            year_to,
+            video_type: query
+                .video_type
+                .as_deref()
+                .map(VideoTypeEnum::try_from)
+                .transpose()?,
+            premium: query.premium,
+            skip: query.skip.unwrap_or(0) as u64,
+            take: query.take.unwrap_or(DEFAULT_TAKE) as u64,
+            max_rating,
+            region,
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use shared::enums::maturity_rating_enum::MaturityRatingEnum;
+use shared::modules::search::normalize_term;
+use validator::Validate;
+
+use crate::modules::search::dto::query_params::suggest_query_params_dto::SuggestQueryParamsDTO;
+
+const DEFAULT_LIMIT: i32 = 8;
+
+#[derive(Debug)]
+pub struct SuggestInputDTO {
+    pub term: String,
+    pub limit: u64,
+    /// Classificação máxima do perfil; `None` libera tudo.
+    pub max_rating: Option<MaturityRatingEnum>,
+    /// Região do espectador, para as janelas de disponibilidade.
+    pub region: Option<String>,
+}
+
+impl
+    TryFrom<(
+        SuggestQueryParamsDTO,
+        Option<MaturityRatingEnum>,
+        Option<String>,
+    )> for SuggestInputDTO
+{
+    type Error = String;
+
+    fn try_from(
+        (query, max_rating, region): (
+            SuggestQueryParamsDTO,
+            Option<MaturityRatingEnum>,
+            Option<String>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        if let Err(errors) = query.validate() {
+            let message = errors
+                .field_errors()
+                .values()
+                .flat_map(|errors| errors.iter())
+                .find_map(|error| error.message.as_ref().map(|msg| msg.to_string()))
+                .unwrap_or_else(|| "Parâmetros de busca inválidos".to_string());
+            return Err(message);
+        }
+
+        Ok(Self {
+            term: normalize_term(&query.q),
+            limit: query.limit.unwrap_or(DEFAULT_LIMIT) as u64,
+            max_rating,
+            region,
+        })
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+use shared::enums::suggestion_kind_enum::SuggestionKindEnum;
+use shared::modules::database::repositories::search_repository::Suggestion;
+
+#[derive(Debug, Serialize)]
+pub struct SuggestionOutputDTO {
+    #[serde(rename = "type")]
+    pub kind: SuggestionKindEnum,
+    pub id: i32,
+    pub label: String,
+    pub score: f32,
+}
+
+impl From<Suggestion> for SuggestionOutputDTO {
+    fn from(suggestion: Suggestion) -> Self {
+        Self {
+            kind: suggestion.kind,
+            id: suggestion.id,
+            label: suggestion.label,
+            score: suggestion.score,
+        }
+    }
+}
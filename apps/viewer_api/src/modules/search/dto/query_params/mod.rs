@@ -0,0 +1,2 @@
+pub mod search_videos_query_params_dto;
+pub mod suggest_query_params_dto;
@@ -0,0 +1,34 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct SearchVideosQueryParamsDTO {
+    /// Termos da busca; aceita frases entre aspas, `or` e `-termo`.
+    #[validate(length(max = 200, message = "A busca deve ter no máximo 200 caracteres"))]
+    pub q: Option<String>,
+
+    #[validate(range(min = 1, message = "O ID da categoria deve ser positivo"))]
+    pub category_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "O ano de lançamento deve ser positivo"))]
+    pub release_year: Option<i32>,
+
+    #[validate(range(min = 1, message = "O ano inicial deve ser positivo"))]
+    pub year_from: Option<i32>,
+
+    #[validate(range(min = 1, message = "O ano final deve ser positivo"))]
+    pub year_to: Option<i32>,
+
+    /// `true` para só o que tem qualidade exclusiva do plano premium.
+    pub premium: Option<bool>,
+
+    /// `movie` ou `episode`.
+    #[serde(rename = "type")]
+    pub video_type: Option<String>,
+
+    #[validate(range(min = 0, message = "Skip não pode ser negativo"))]
+    pub skip: Option<i32>,
+
+    #[validate(range(min = 1, max = 100, message = "Take deve estar entre 1 e 100"))]
+    pub take: Option<i32>,
+}
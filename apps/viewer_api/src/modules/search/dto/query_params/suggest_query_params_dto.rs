@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct SuggestQueryParamsDTO {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "A busca deve ter entre 1 e 100 caracteres"
+    ))]
+    pub q: String,
+
+    #[validate(range(min = 1, max = 20, message = "O limite deve estar entre 1 e 20"))]
+    pub limit: Option<i32>,
+}
@@ -0,0 +1,2 @@
+pub mod search_videos_service;
+pub mod suggest_service;
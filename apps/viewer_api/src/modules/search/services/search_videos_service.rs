@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::{
+    enums::image_owner_enum::ImageOwnerEnum,
+    modules::{
+        app_state::AppState,
+        database::repositories::{
+            images_repository::catalog_image_urls,
+            search_repository::{IndexQuery, LiveFilter},
+        },
+    },
+};
+
+use crate::modules::search::dto::io::{
+    search_videos_input_dto::SearchVideosInputDTO,
+    search_videos_output_dto::{SearchVideoItemOutputDTO, SearchVideosOutputDTO},
+};
+
+pub enum SearchVideosError {
+    Database(String),
+    Index(String),
+}
+
+/// Os resultados vêm do índice configurado em `SEARCH_BACKEND`; as facetas,
+/// sempre do Postgres.
+pub async fn execute(
+    input: SearchVideosInputDTO,
+    state: Arc<AppState>,
+) -> Result<SearchVideosOutputDTO, SearchVideosError> {
+    let query = IndexQuery {
+        text: input.text,
+        category_id: input.category_id,
+        year_from: input.year_from,
+        year_to: input.year_to,
+        video_type: input.video_type,
+        premium: input.premium,
+        live: Some(LiveFilter {
+            at: Utc::now().naive_utc(),
+            region: input.region,
+            max_rating: input.max_rating,
+        }),
+        skip: input.skip,
+        take: input.take,
+    };
+
+    let result = state.search_index.search(&query).await.map_err(|e| {
+        SearchVideosError::Index(format!(
+            "Erro no índice de busca ({}): {}",
+            state.search_index.name(),
+            e
+        ))
+    })?;
+
+    let facets = state
+        .search_repo
+        .facets(&query)
+        .await
+        .map_err(|e| SearchVideosError::Database(format!("Erro ao contar facetas: {}", e)))?;
+
+    let video_ids: Vec<i32> = result.hits.iter().map(|hit| hit.video_id).collect();
+    let mut videos: HashMap<i32, _> = state
+        .video_repo
+        .find_by_ids(&video_ids)
+        .await
+        .map_err(|e| SearchVideosError::Database(format!("Erro ao buscar vídeos: {}", e)))?
+        .into_iter()
+        .map(|video| (video.id, video))
+        .collect();
+
+    // mantém a ordem do índice; um vídeo apagado depois da indexação some
+    let mut items: Vec<SearchVideoItemOutputDTO> = result
+        .hits
+        .into_iter()
+        .filter_map(|hit| {
+            videos
+                .remove(&hit.video_id)
+                .map(|video| (video, hit).into())
+        })
+        .collect();
+
+    let mut images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::VIDEO, &video_ids)
+        .await
+        .map(catalog_image_urls)
+        .map_err(|e| SearchVideosError::Database(format!("Erro ao buscar imagens: {}", e)))?;
+
+    for item in &mut items {
+        if let Some(urls) = images.remove(&item.id) {
+            item.images = urls;
+        }
+    }
+
+    Ok(SearchVideosOutputDTO {
+        total: result.total,
+        items,
+        facets,
+    })
+}
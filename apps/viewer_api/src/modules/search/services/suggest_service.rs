@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::modules::{
+    app_state::AppState,
+    database::repositories::search_repository::{SuggestQuery, Suggestion},
+    search::{suggest_cache_key, SUGGEST_CACHE_TTL_SECS},
+};
+
+use crate::modules::search::dto::io::{
+    suggest_input_dto::SuggestInputDTO, suggestion_output_dto::SuggestionOutputDTO,
+};
+
+pub enum SuggestError {
+    Database(String),
+}
+
+pub async fn execute(
+    input: SuggestInputDTO,
+    state: Arc<AppState>,
+) -> Result<Vec<SuggestionOutputDTO>, SuggestError> {
+    if input.term.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let key = suggest_cache_key(
+        &input.term,
+        input.region.as_deref(),
+        input.max_rating,
+        input.limit,
+    );
+
+    // o cache é só um atalho: se o Redis falhar, a consulta vai ao banco
+    match state.cache.get::<_, Vec<Suggestion>>(&key).await {
+        Ok(Some(suggestions)) => return Ok(suggestions.into_iter().map(Into::into).collect()),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Falha ao ler sugestões do cache: {:?}", e),
+    }
+
+    let suggestions = state
+        .search_repo
+        .suggest(&SuggestQuery {
+            term: input.term,
+            at: Utc::now().naive_utc(),
+            region: input.region,
+            max_rating: input.max_rating,
+            limit: input.limit,
+        })
+        .await
+        .map_err(|e| SuggestError::Database(format!("Erro ao buscar sugestões: {}", e)))?;
+
+    if let Err(e) = state
+        .cache
+        .set_with_ttl(&key, &suggestions, SUGGEST_CACHE_TTL_SECS)
+        .await
+    {
+        tracing::warn!("Falha ao gravar sugestões no cache: {:?}", e);
+    }
+
+    Ok(suggestions.into_iter().map(Into::into).collect())
+}
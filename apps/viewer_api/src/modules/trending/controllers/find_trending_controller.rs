@@ -0,0 +1,77 @@
+use crate::modules::trending::{
+    dto::{
+        io::{trending_input_dto::TrendingInputDTO, trending_output_dto::TrendingOutputDTO},
+        query_params::trending_query_params_dto::TrendingQueryParamsDTO,
+    },
+    services::find_trending_service,
+};
+use axum::{extract::Query, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState, auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion, maturity::ParentalControl,
+        profile::profile_extractor::ViewerProfile, response_interface::ResponseInterface,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    Query(query): Query<TrendingQueryParamsDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<TrendingOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let input =
+        TrendingInputDTO::try_from((query, ParentalControl::from(&profile).max_rating, region))
+            .map_err(|msg| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ValidationErrorResponse {
+                        message: "Erro ao buscar os títulos em alta".to_string(),
+                        errors: json!([msg]),
+                    }),
+                )
+            })?;
+
+    match find_trending_service::execute(input, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: None,
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                find_trending_service::FindTrendingError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                find_trending_service::FindTrendingError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar os títulos em alta".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod find_trending_controller;
+pub mod find_trending_in_category_controller;
@@ -0,0 +1,2 @@
+pub mod trending_input_dto;
+pub mod trending_output_dto;
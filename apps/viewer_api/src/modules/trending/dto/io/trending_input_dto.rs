@@ -0,0 +1,94 @@
+use shared::enums::maturity_rating_enum::MaturityRatingEnum;
+use shared::enums::trending_window_enum::TrendingWindowEnum;
+use validator::{Validate, ValidationErrors};
+
+use crate::modules::trending::dto::{
+    query_params::trending_query_params_dto::TrendingQueryParamsDTO,
+    route_params::trending_category_route_params_dto::TrendingCategoryRouteParamsDTO,
+};
+
+const DEFAULT_LIMIT: i32 = 10;
+
+#[derive(Debug)]
+pub struct TrendingInputDTO {
+    pub window: TrendingWindowEnum,
+    /// `None` para a lista geral.
+    pub category_id: Option<i32>,
+    pub limit: usize,
+    /// Classificação máxima do perfil; `None` libera tudo.
+    pub max_rating: Option<MaturityRatingEnum>,
+    /// Região do espectador, para as janelas de disponibilidade.
+    pub region: Option<String>,
+}
+
+impl
+    TryFrom<(
+        TrendingQueryParamsDTO,
+        Option<MaturityRatingEnum>,
+        Option<String>,
+    )> for TrendingInputDTO
+{
+    type Error = String;
+
+    fn try_from(
+        (query, max_rating, region): (
+            TrendingQueryParamsDTO,
+            Option<MaturityRatingEnum>,
+            Option<String>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        query.validate().map_err(first_message)?;
+
+        Ok(Self {
+            window: parse_window(query.window.as_deref())?,
+            category_id: None,
+            limit: query.limit.unwrap_or(DEFAULT_LIMIT) as usize,
+            max_rating,
+            region,
+        })
+    }
+}
+
+impl
+    TryFrom<(
+        TrendingCategoryRouteParamsDTO,
+        TrendingQueryParamsDTO,
+        Option<MaturityRatingEnum>,
+        Option<String>,
+    )> for TrendingInputDTO
+{
+    type Error = String;
+
+    fn try_from(
+        (params, query, max_rating, region): (
+            TrendingCategoryRouteParamsDTO,
+            TrendingQueryParamsDTO,
+            Option<MaturityRatingEnum>,
+            Option<String>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        params.validate().map_err(first_message)?;
+
+        Ok(Self {
+            category_id: Some(params.id),
+            ..Self::try_from((query, max_rating, region))?
+        })
+    }
+}
+
+/// Sem janela informada, usa as últimas 24 horas.
+fn parse_window(value: Option<&str>) -> Result<TrendingWindowEnum, String> {
+    value
+        .map(TrendingWindowEnum::try_from)
+        .transpose()
+        .map(|window| window.unwrap_or(TrendingWindowEnum::DAY))
+}
+
+fn first_message(errors: ValidationErrors) -> String {
+    errors
+        .field_errors()
+        .values()
+        .flat_map(|errors| errors.iter())
+        .find_map(|error| error.message.as_ref().map(|msg| msg.to_string()))
+        .unwrap_or_else(|| "Parâmetros inválidos".to_string())
+}
@@ -0,0 +1,33 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use shared::enums::trending_kind_enum::TrendingKindEnum;
+use shared::enums::trending_window_enum::TrendingWindowEnum;
+use shared::modules::database::repositories::images_repository::CatalogImageUrls;
+
+#[derive(Debug, Serialize)]
+pub struct TrendingItemOutputDTO {
+    /// Posição na lista, a partir de 1.
+    pub position: usize,
+    #[serde(rename = "type")]
+    pub kind: TrendingKindEnum,
+    pub id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub release_year: Option<i32>,
+    pub thumbnail_url: Option<String>,
+    pub images: CatalogImageUrls,
+    pub maturity_rating: Option<String>,
+    pub content_descriptors: Vec<String>,
+    /// Soma das visualizações da janela, com o peso decaído.
+    pub score: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrendingOutputDTO {
+    pub window: TrendingWindowEnum,
+    pub category_id: Option<i32>,
+    /// Momento do cálculo servido pelo Redis; `None` quando a lista foi
+    /// recalculada do banco na hora.
+    pub computed_at: Option<NaiveDateTime>,
+    pub items: Vec<TrendingItemOutputDTO>,
+}
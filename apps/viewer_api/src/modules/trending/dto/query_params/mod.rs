@@ -0,0 +1 @@
+pub mod trending_query_params_dto;
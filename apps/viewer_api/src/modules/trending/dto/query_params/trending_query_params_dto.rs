@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct TrendingQueryParamsDTO {
+    /// `24h`, `7d` ou `30d`.
+    pub window: Option<String>,
+    #[validate(range(min = 1, max = 50, message = "O limite deve estar entre 1 e 50"))]
+    pub limit: Option<i32>,
+}
@@ -0,0 +1 @@
+pub mod trending_category_route_params_dto;
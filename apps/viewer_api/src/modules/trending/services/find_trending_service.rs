@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::{
+    enums::{image_owner_enum::ImageOwnerEnum, trending_kind_enum::TrendingKindEnum},
+    modules::{
+        app_state::AppState,
+        database::repositories::{
+            images_repository::catalog_image_urls, search_repository::LiveFilter,
+        },
+        maturity,
+        trending::{self, TrendingEntry, TrendingScope, RANKING_SIZE},
+    },
+};
+
+use crate::modules::trending::dto::io::{
+    trending_input_dto::TrendingInputDTO,
+    trending_output_dto::{TrendingItemOutputDTO, TrendingOutputDTO},
+};
+
+pub enum FindTrendingError {
+    Database(String),
+    NotFound(String),
+}
+
+/// Títulos em alta na janela, só com o que está no ar para o espectador.
+/// Sem categoria é o "Top 10": filmes e séries, com os episódios somados na
+/// série.
+pub async fn execute(
+    input: TrendingInputDTO,
+    state: Arc<AppState>,
+) -> Result<TrendingOutputDTO, FindTrendingError> {
+    let map_err =
+        |e| FindTrendingError::Database(format!("Erro ao buscar os títulos em alta: {}", e));
+
+    let entries = find_live_entries(&state, &input).await?;
+    let computed_at = trending::computed_at(&state).await;
+
+    let video_ids = ids_of(&entries, TrendingKindEnum::VIDEO);
+    let serie_ids = ids_of(&entries, TrendingKindEnum::SERIE);
+
+    let mut videos: HashMap<i32, _> = state
+        .video_repo
+        .find_by_ids(&video_ids)
+        .await
+        .map_err(map_err)?
+        .into_iter()
+        .map(|video| (video.id, video))
+        .collect();
+    let mut series: HashMap<i32, _> = state
+        .serie_repo
+        .find_by_ids(&serie_ids)
+        .await
+        .map_err(map_err)?
+        .into_iter()
+        .map(|serie| (serie.id, serie))
+        .collect();
+
+    let mut video_images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::VIDEO, &video_ids)
+        .await
+        .map(catalog_image_urls)
+        .map_err(map_err)?;
+    let mut serie_images = state
+        .image_repo
+        .find_by_owners(ImageOwnerEnum::SERIE, &serie_ids)
+        .await
+        .map(catalog_image_urls)
+        .map_err(map_err)?;
+
+    let mut items = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let (title, description, release_year, thumbnail_url, images, rating, descriptors) =
+            match entry.kind {
+                TrendingKindEnum::VIDEO => {
+                    let Some(video) = videos.remove(&entry.id) else {
+                        continue;
+                    };
+                    (
+                        video.title,
+                        video.description,
+                        video.release_year,
+                        video.thumbnail_url,
+                        video_images.remove(&entry.id),
+                        video.maturity_rating,
+                        maturity::descriptors(&video.content_descriptors),
+                    )
+                }
+                TrendingKindEnum::SERIE => {
+                    let Some(serie) = series.remove(&entry.id) else {
+                        continue;
+                    };
+                    (
+                        serie.title,
+                        serie.description,
+                        serie.release_year,
+                        serie.thumbnail_url,
+                        serie_images.remove(&entry.id),
+                        serie.maturity_rating,
+                        maturity::descriptors(&serie.content_descriptors),
+                    )
+                }
+            };
+
+        items.push(TrendingItemOutputDTO {
+            position: items.len() + 1,
+            kind: entry.kind,
+            id: entry.id,
+            title,
+            description,
+            release_year,
+            thumbnail_url,
+            images: images.unwrap_or_default(),
+            maturity_rating: rating,
+            content_descriptors: descriptors,
+            score: entry.score,
+        });
+    }
+
+    Ok(TrendingOutputDTO {
+        window: input.window,
+        category_id: input.category_id,
+        computed_at,
+        items,
+    })
+}
+
+fn ids_of(entries: &[TrendingEntry], kind: TrendingKindEnum) -> Vec<i32> {
+    entries
+        .iter()
+        .filter(|entry| entry.kind == kind)
+        .map(|entry| entry.id)
+        .collect()
+}
+
+/// Os `limit` primeiros da lista que estão no ar na região do espectador e
+/// dentro da classificação do perfil.
+pub async fn find_live_entries(
+    state: &AppState,
+    input: &TrendingInputDTO,
+) -> Result<Vec<TrendingEntry>, FindTrendingError> {
+    let map_err =
+        |e| FindTrendingError::Database(format!("Erro ao buscar os títulos em alta: {}", e));
+
+    let scope = match input.category_id {
+        Some(category_id) => {
+            if state
+                .category_repo
+                .find_by_id(category_id)
+                .await
+                .map_err(map_err)?
+                .is_none()
+            {
+                return Err(FindTrendingError::NotFound(format!(
+                    "Categoria com id {} não encontrada",
+                    category_id
+                )));
+            }
+            TrendingScope::Category(category_id)
+        }
+        None => TrendingScope::Titles,
+    };
+
+    // busca a lista inteira porque parte dela pode estar fora do ar na região
+    let entries = trending::ranking(state, input.window, scope, RANKING_SIZE)
+        .await
+        .map_err(map_err)?;
+
+    let live = LiveFilter {
+        at: Utc::now().naive_utc(),
+        region: input.region.clone(),
+        max_rating: input.max_rating,
+    };
+    let live_videos = state
+        .search_repo
+        .find_live_ids(&ids_of(&entries, TrendingKindEnum::VIDEO), &live)
+        .await
+        .map_err(map_err)?;
+    let live_series = state
+        .trending_repo
+        .find_live_series_ids(&ids_of(&entries, TrendingKindEnum::SERIE), &live)
+        .await
+        .map_err(map_err)?;
+
+    Ok(entries
+        .into_iter()
+        .filter(|entry| match entry.kind {
+            TrendingKindEnum::VIDEO => live_videos.contains(&entry.id),
+            TrendingKindEnum::SERIE => live_series.contains(&entry.id),
+        })
+        .take(input.limit)
+        .collect())
+}
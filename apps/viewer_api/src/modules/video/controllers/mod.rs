@@ -0,0 +1,2 @@
+pub mod stream_video_controller;
+pub mod unlock_video_controller;
@@ -0,0 +1,135 @@
+use crate::modules::video::{
+    dto::{
+        io::stream_video_input_dto::StreamVideoInputDTO,
+        query_params::stream_video_query_params_dto::StreamVideoQueryParamsDTO,
+        route_params::stream_video_route_params_dto::StreamVideoRouteParamsDTO,
+    },
+    services::stream_video_service,
+};
+use axum::{
+    body::Body,
+    extract::{Path, Query},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion,
+        maturity::ParentalControl,
+        playback_session::{device_extractor::ViewerDevice, StreamClaim},
+        profile::profile_extractor::ViewerProfile,
+        validation::validation_layer::ValidationErrorResponse,
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    ViewerDevice(device): ViewerDevice,
+    Path(params): Path<StreamVideoRouteParamsDTO>,
+    Query(query): Query<StreamVideoQueryParamsDTO>,
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ValidationErrorResponse>)> {
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let height_limit = AccessGroupEnum::playback_height_limit(&user.access_groups);
+    let claim = StreamClaim {
+        limit: AccessGroupEnum::stream_limit(&user.access_groups),
+        user_id: user.id,
+        profile_id: profile.id,
+        device,
+    };
+    let input: StreamVideoInputDTO = (
+        params,
+        query,
+        range,
+        height_limit,
+        ParentalControl::from(&profile),
+        region,
+        claim,
+    )
+        .into();
+
+    match stream_video_service::execute(input, state).await {
+        Ok(chunk) => Ok((
+            StatusCode::PARTIAL_CONTENT,
+            [
+                (header::CONTENT_TYPE, chunk.content_type),
+                (header::ACCEPT_RANGES, "bytes".to_string()),
+                (
+                    header::CONTENT_RANGE,
+                    format!("bytes {}-{}/{}", chunk.start, chunk.end, chunk.total_size),
+                ),
+                (header::CONTENT_LENGTH, chunk.data.len().to_string()),
+            ],
+            Body::from(chunk.data),
+        )
+            .into_response()),
+        Err(err) => {
+            let (status, msg) = match err {
+                stream_video_service::StreamVideoError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                stream_video_service::StreamVideoError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                stream_video_service::StreamVideoError::Restricted(msg) => {
+                    (StatusCode::FORBIDDEN, msg)
+                }
+                stream_video_service::StreamVideoError::Kicked(msg) => (StatusCode::FORBIDDEN, msg),
+                stream_video_service::StreamVideoError::StreamLimit(limit, sessions) => {
+                    return Err((
+                        StatusCode::CONFLICT,
+                        Json(ValidationErrorResponse {
+                            message: format!(
+                                "Limite de {} tela(s) simultânea(s) atingido. Encerre a reprodução em outro dispositivo",
+                                limit
+                            ),
+                            errors: json!(sessions),
+                        }),
+                    ));
+                }
+                stream_video_service::StreamVideoError::Unavailable(msg) => {
+                    (StatusCode::SERVICE_UNAVAILABLE, msg)
+                }
+                stream_video_service::StreamVideoError::Storage(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                stream_video_service::StreamVideoError::RangeNotSatisfiable(total_size) => {
+                    return Ok((
+                        StatusCode::RANGE_NOT_SATISFIABLE,
+                        [(header::CONTENT_RANGE, format!("bytes */{}", total_size))],
+                    )
+                        .into_response());
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao reproduzir vídeo".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::modules::video::{
+    dto::{
+        io::unlock_video_output_dto::UnlockVideoOutputDTO,
+        request::unlock_video_request_dto::UnlockVideoRequestDTO,
+        route_params::unlock_video_route_params_dto::UnlockVideoRouteParamsDTO,
+    },
+    services::unlock_video_service,
+};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use macros::require_access;
+use serde_json::json;
+use shared::{
+    enums::access_group_enum::AccessGroupEnum,
+    modules::{
+        app_state::AppState,
+        auth::jwt_extractor::AuthenticatedUser,
+        availability::region_extractor::ViewerRegion,
+        profile::profile_extractor::ViewerProfile,
+        response_interface::ResponseInterface,
+        validation::validation_layer::{validate_json, ValidatedJson, ValidationErrorResponse},
+    },
+};
+use std::sync::Arc;
+
+#[axum::debug_handler]
+#[require_access(
+    AccessGroupEnum::VIEWER,
+    AccessGroupEnum::PREMIUM,
+    AccessGroupEnum::ADMIN,
+    AccessGroupEnum::SUPER_ADMIN
+)]
+pub async fn handler(
+    Extension(state): Extension<Arc<AppState>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    ViewerProfile(profile): ViewerProfile,
+    ViewerRegion(region): ViewerRegion,
+    Path(params): Path<UnlockVideoRouteParamsDTO>,
+    payload: Json<UnlockVideoRequestDTO>,
+) -> Result<
+    (StatusCode, Json<ResponseInterface<UnlockVideoOutputDTO>>),
+    (StatusCode, Json<ValidationErrorResponse>),
+> {
+    let ValidatedJson(payload) = validate_json(payload).await?;
+
+    match unlock_video_service::execute(user, profile, params, payload, region, state).await {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(ResponseInterface {
+                result: Some(result),
+                message: Some("Vídeo liberado para o perfil".to_string()),
+            }),
+        )),
+        Err(err) => {
+            let (status, msg) = match err {
+                unlock_video_service::UnlockVideoError::Database(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+                unlock_video_service::UnlockVideoError::NotFound(msg) => {
+                    (StatusCode::NOT_FOUND, msg)
+                }
+                unlock_video_service::UnlockVideoError::Forbidden(msg) => {
+                    (StatusCode::FORBIDDEN, msg)
+                }
+                unlock_video_service::UnlockVideoError::TooManyAttempts(msg) => {
+                    (StatusCode::TOO_MANY_REQUESTS, msg)
+                }
+                unlock_video_service::UnlockVideoError::Cache(msg) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, msg)
+                }
+            };
+            Err((
+                status,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao liberar o vídeo".to_string(),
+                    errors: json!([msg]),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod stream_video_input_dto;
+pub mod stream_video_output_dto;
+pub mod unlock_video_output_dto;
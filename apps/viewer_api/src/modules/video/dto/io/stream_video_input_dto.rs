@@ -0,0 +1,56 @@
+use shared::modules::{maturity::ParentalControl, playback_session::StreamClaim};
+
+use crate::modules::video::dto::{
+    query_params::stream_video_query_params_dto::StreamVideoQueryParamsDTO,
+    route_params::stream_video_route_params_dto::StreamVideoRouteParamsDTO,
+};
+
+#[derive(Debug)]
+pub struct StreamVideoInputDTO {
+    pub video_id: i32,
+    pub max_height: Option<i32>,
+    /// Altura máxima liberada pelo plano do usuário.
+    pub height_limit: Option<i32>,
+    pub trailer: bool,
+    pub range: Option<String>,
+    pub parental: ParentalControl,
+    /// Região do espectador, para as janelas de disponibilidade.
+    pub region: Option<String>,
+    /// Tela ocupada pela reprodução, renovada a cada requisição.
+    pub claim: StreamClaim,
+}
+
+impl
+    From<(
+        StreamVideoRouteParamsDTO,
+        StreamVideoQueryParamsDTO,
+        Option<String>,
+        Option<i32>,
+        ParentalControl,
+        Option<String>,
+        StreamClaim,
+    )> for StreamVideoInputDTO
+{
+    fn from(
+        (params, query, range, height_limit, parental, region, claim): (
+            StreamVideoRouteParamsDTO,
+            StreamVideoQueryParamsDTO,
+            Option<String>,
+            Option<i32>,
+            ParentalControl,
+            Option<String>,
+            StreamClaim,
+        ),
+    ) -> Self {
+        Self {
+            video_id: params.id,
+            max_height: query.max_height,
+            height_limit,
+            trailer: query.trailer.unwrap_or(false),
+            range,
+            parental,
+            region,
+            claim,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Debug)]
+pub struct StreamVideoOutputDTO {
+    pub data: Vec<u8>,
+    pub content_type: String,
+    pub start: u64,
+    pub end: u64,
+    pub total_size: u64,
+}
@@ -0,0 +1,9 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct UnlockVideoOutputDTO {
+    pub video_id: i32,
+    pub profile_id: i32,
+    pub unlocked_until: NaiveDateTime,
+}
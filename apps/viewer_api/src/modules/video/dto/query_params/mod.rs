@@ -0,0 +1 @@
+pub mod stream_video_query_params_dto;
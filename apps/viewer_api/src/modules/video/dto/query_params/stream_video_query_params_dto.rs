@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Default)]
+pub struct StreamVideoQueryParamsDTO {
+    #[validate(range(min = 1, message = "A altura máxima deve ser positiva"))]
+    pub max_height: Option<i32>,
+
+    pub trailer: Option<bool>,
+}
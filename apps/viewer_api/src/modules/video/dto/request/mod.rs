@@ -0,0 +1 @@
+pub mod unlock_video_request_dto;
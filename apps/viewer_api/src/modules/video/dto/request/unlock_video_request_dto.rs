@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UnlockVideoRequestDTO {
+    /// PIN do perfil principal da conta.
+    #[validate(length(equal = 4, message = "O PIN deve ter 4 dígitos"))]
+    pub pin: String,
+}
@@ -0,0 +1,2 @@
+pub mod stream_video_route_params_dto;
+pub mod unlock_video_route_params_dto;
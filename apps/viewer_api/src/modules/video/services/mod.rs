@@ -0,0 +1,2 @@
+pub mod stream_video_service;
+pub mod unlock_video_service;
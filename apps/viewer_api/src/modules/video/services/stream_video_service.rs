@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use shared::{
+    enums::video_asset_kind_enum::VideoAssetKindEnum,
+    modules::{
+        app_state::AppState,
+        database::schema::{
+            media_assets::Model as MediaAssetModel, video_assets::Model as VideoAssetModel,
+        },
+        playback_session::PlaybackSession,
+    },
+};
+
+use crate::modules::{
+    playback::services::entitled_renditions_service::{self, PlaybackError},
+    video::dto::io::{
+        stream_video_input_dto::StreamVideoInputDTO, stream_video_output_dto::StreamVideoOutputDTO,
+    },
+};
+
+/// Tamanho máximo devolvido por requisição quando o cliente pede um intervalo aberto.
+const MAX_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+
+pub enum StreamVideoError {
+    Database(String),
+    NotFound(String),
+    /// Acima da classificação do perfil e sem liberação com PIN.
+    Restricted(String),
+    /// Dispositivo desconectado pelo dono da conta.
+    Kicked(String),
+    /// Todas as telas do plano em uso; traz o limite e os dispositivos.
+    StreamLimit(usize, Vec<PlaybackSession>),
+    /// Não foi possível contar as telas da conta.
+    Unavailable(String),
+    RangeNotSatisfiable(u64),
+    Storage(String),
+}
+
+/// Disponibilidade, classificação e telas seguem as mesmas regras dos manifestos.
+impl From<PlaybackError> for StreamVideoError {
+    fn from(error: PlaybackError) -> Self {
+        match error {
+            PlaybackError::Database(msg) => StreamVideoError::Database(msg),
+            PlaybackError::NotFound(msg) => StreamVideoError::NotFound(msg),
+            PlaybackError::Restricted(msg) => StreamVideoError::Restricted(msg),
+            PlaybackError::Kicked(msg) => StreamVideoError::Kicked(msg),
+            PlaybackError::StreamLimit(limit, sessions) => {
+                StreamVideoError::StreamLimit(limit, sessions)
+            }
+            PlaybackError::Unavailable(msg) => StreamVideoError::Unavailable(msg),
+            PlaybackError::Storage(msg) => StreamVideoError::Storage(msg),
+        }
+    }
+}
+
+pub async fn execute(
+    input: StreamVideoInputDTO,
+    state: Arc<AppState>,
+) -> Result<StreamVideoOutputDTO, StreamVideoError> {
+    let video = entitled_renditions_service::find_live_video(
+        input.video_id,
+        input.region.as_deref(),
+        &input.parental,
+        &state,
+    )
+    .await?;
+
+    entitled_renditions_service::claim_screen(&state, &input.claim, video.id).await?;
+
+    let kinds: &[VideoAssetKindEnum] = if input.trailer {
+        &[VideoAssetKindEnum::TRAILER]
+    } else {
+        &[VideoAssetKindEnum::RENDITION, VideoAssetKindEnum::SOURCE]
+    };
+
+    let assets: Vec<_> = state
+        .video_asset_repo
+        .find_playable(video.id, kinds)
+        .await
+        .map_err(|e| StreamVideoError::Database(format!("Erro ao buscar arquivos: {}", e)))?
+        .into_iter()
+        .filter(|(asset, _)| fits_height(asset, input.height_limit))
+        .collect();
+
+    let (_, media_asset) = select_asset(&assets, input.max_height).ok_or_else(|| {
+        StreamVideoError::NotFound(format!(
+            "Nenhum arquivo disponível para o vídeo {}",
+            video.id
+        ))
+    })?;
+
+    let total_size = media_asset.size_bytes as u64;
+    let (start, end) = parse_range(input.range.as_deref(), total_size)
+        .ok_or(StreamVideoError::RangeNotSatisfiable(total_size))?;
+
+    let data = state
+        .video_storage_service
+        .stream_video(&media_asset.storage_key, Some(start..end + 1))
+        .await
+        .map_err(|e| StreamVideoError::Storage(format!("Erro ao ler arquivo: {}", e)))?;
+
+    Ok(StreamVideoOutputDTO {
+        data,
+        content_type: media_asset.content_type.clone(),
+        start,
+        end,
+        total_size,
+    })
+}
+
+/// Prefere a maior rendition que caiba em `max_height`; sem nenhuma que caiba,
+/// cai para o original e, por último, para a menor rendition disponível.
+fn select_asset(
+    assets: &[(VideoAssetModel, MediaAssetModel)],
+    max_height: Option<i32>,
+) -> Option<&(VideoAssetModel, MediaAssetModel)> {
+    let is_source = |asset: &VideoAssetModel| asset.kind == VideoAssetKindEnum::SOURCE.as_str();
+
+    assets
+        .iter()
+        .filter(|(asset, _)| !is_source(asset) && fits_height(asset, max_height))
+        .max_by_key(|(asset, _)| asset.height)
+        .or_else(|| assets.iter().find(|(asset, _)| is_source(asset)))
+        .or_else(|| assets.iter().min_by_key(|(asset, _)| asset.height))
+}
+
+/// Assets sem altura conhecida são aceitos em qualquer limite.
+fn fits_height(asset: &VideoAssetModel, max_height: Option<i32>) -> bool {
+    match (max_height, asset.height) {
+        (Some(max), Some(height)) => height <= max,
+        _ => true,
+    }
+}
+
+/// Interpreta o header `Range` (`bytes=início-fim`, `bytes=início-` ou `bytes=-sufixo`)
+/// e devolve o intervalo inclusivo a servir, limitado a `MAX_CHUNK_SIZE`.
+fn parse_range(header: Option<&str>, total_size: u64) -> Option<(u64, u64)> {
+    if total_size == 0 {
+        return None;
+    }
+
+    let last = total_size - 1;
+
+    let (start, end) = match header {
+        None => (0, last),
+        Some(value) => {
+            let spec = value.trim().strip_prefix("bytes=")?;
+            // Múltiplos intervalos não são suportados; serve apenas o primeiro.
+            let spec = spec.split(',').next()?.trim();
+            let (start, end) = spec.split_once('-')?;
+
+            match (start.trim(), end.trim()) {
+                ("", suffix) => {
+                    let suffix: u64 = suffix.parse().ok()?;
+                    if suffix == 0 {
+                        return None;
+                    }
+                    (total_size.saturating_sub(suffix), last)
+                }
+                (start, "") => (start.parse().ok()?, last),
+                (start, end) => (start.parse().ok()?, end.parse::<u64>().ok()?.min(last)),
+            }
+        }
+    };
+
+    if start > end || start > last {
+        return None;
+    }
+
+    Some((start, end.min(start + MAX_CHUNK_SIZE - 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::playback::services::entitled_renditions_service::fixtures::{
+        media_asset, rendition,
+    };
+
+    const TOTAL: u64 = 10_000;
+
+    #[test]
+    fn missing_header_serves_from_the_start() {
+        assert_eq!(parse_range(None, TOTAL), Some((0, TOTAL - 1)));
+        assert_eq!(parse_range(None, 0), None);
+    }
+
+    #[test]
+    fn closed_range_is_clamped_to_the_file() {
+        assert_eq!(parse_range(Some("bytes=100-199"), TOTAL), Some((100, 199)));
+        assert_eq!(
+            parse_range(Some("bytes=9000-20000"), TOTAL),
+            Some((9000, TOTAL - 1))
+        );
+    }
+
+    #[test]
+    fn open_ended_range_goes_to_the_end_within_the_chunk_limit() {
+        assert_eq!(
+            parse_range(Some("bytes=500-"), TOTAL),
+            Some((500, TOTAL - 1))
+        );
+
+        let large = 3 * MAX_CHUNK_SIZE;
+        assert_eq!(
+            parse_range(Some("bytes=10-"), large),
+            Some((10, 10 + MAX_CHUNK_SIZE - 1))
+        );
+    }
+
+    #[test]
+    fn suffix_range_serves_the_last_bytes() {
+        assert_eq!(
+            parse_range(Some("bytes=-500"), TOTAL),
+            Some((TOTAL - 500, TOTAL - 1))
+        );
+        // sufixo maior que o arquivo devolve o arquivo inteiro
+        assert_eq!(
+            parse_range(Some("bytes=-20000"), TOTAL),
+            Some((0, TOTAL - 1))
+        );
+        assert_eq!(parse_range(Some("bytes=-0"), TOTAL), None);
+    }
+
+    #[test]
+    fn start_after_end_is_not_satisfiable() {
+        assert_eq!(parse_range(Some("bytes=200-100"), TOTAL), None);
+    }
+
+    #[test]
+    fn start_beyond_the_file_is_not_satisfiable() {
+        assert_eq!(parse_range(Some("bytes=10000-"), TOTAL), None);
+        assert_eq!(parse_range(Some("bytes=12000-13000"), TOTAL), None);
+    }
+
+    #[test]
+    fn multi_range_serves_only_the_first_range() {
+        assert_eq!(
+            parse_range(Some("bytes=0-99, 200-299"), TOTAL),
+            Some((0, 99))
+        );
+        assert_eq!(
+            parse_range(Some("bytes=-100,0-9"), TOTAL),
+            Some((9900, 9999))
+        );
+    }
+
+    #[test]
+    fn malformed_header_is_not_satisfiable() {
+        assert_eq!(parse_range(Some("items=0-10"), TOTAL), None);
+        assert_eq!(parse_range(Some("bytes=abc-"), TOTAL), None);
+        assert_eq!(parse_range(Some("bytes=10"), TOTAL), None);
+    }
+
+    fn asset(id: i32, kind: VideoAssetKindEnum, height: i32) -> (VideoAssetModel, MediaAssetModel) {
+        let mut video_asset = rendition(id, (height * 16 / 9, height), &[], None).video_asset;
+        video_asset.kind = kind.as_str().to_string();
+        (video_asset, media_asset(id))
+    }
+
+    #[test]
+    fn select_asset_prefers_the_largest_fitting_rendition_then_the_source() {
+        let assets = [
+            asset(1, VideoAssetKindEnum::SOURCE, 2160),
+            asset(2, VideoAssetKindEnum::RENDITION, 1080),
+            asset(3, VideoAssetKindEnum::RENDITION, 720),
+        ];
+        let selected = |max_height| select_asset(&assets, max_height).map(|(_, media)| media.id);
+
+        assert_eq!(selected(None), Some(2));
+        assert_eq!(selected(Some(720)), Some(3));
+        assert_eq!(selected(Some(480)), Some(1));
+        assert_eq!(
+            select_asset(&assets[1..], Some(480)).map(|(_, m)| m.id),
+            Some(3)
+        );
+    }
+}
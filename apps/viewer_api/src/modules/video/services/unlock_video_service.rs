@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use bcrypt::verify;
+use chrono::{Duration, Utc};
+use shared::modules::{
+    app_state::AppState,
+    auth::User,
+    availability,
+    database::schema::profiles::Model as ProfileModel,
+    maturity::{self, UNLOCK_TTL_SECS},
+    profile::{limit_pin_attempts, pin_locked_message, PinAttempt},
+};
+
+use crate::modules::video::dto::{
+    io::unlock_video_output_dto::UnlockVideoOutputDTO,
+    request::unlock_video_request_dto::UnlockVideoRequestDTO,
+    route_params::unlock_video_route_params_dto::UnlockVideoRouteParamsDTO,
+};
+
+pub enum UnlockVideoError {
+    Database(String),
+    NotFound(String),
+    Forbidden(String),
+    TooManyAttempts(String),
+    Cache(String),
+}
+
+/// Libera para o perfil em uso um vídeo acima da sua classificação, com o
+/// PIN do perfil principal da conta. A liberação vale por algumas horas.
+pub async fn execute(
+    user: User,
+    profile: ProfileModel,
+    params: UnlockVideoRouteParamsDTO,
+    payload: UnlockVideoRequestDTO,
+    region: Option<String>,
+    state: Arc<AppState>,
+) -> Result<UnlockVideoOutputDTO, UnlockVideoError> {
+    let map_err = |e| UnlockVideoError::Database(format!("Erro ao buscar vídeo: {}", e));
+    let not_found =
+        || UnlockVideoError::NotFound(format!("Vídeo com id {} não encontrado", params.id));
+
+    let video = state
+        .video_repo
+        .find_by_id(params.id)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(not_found)?;
+
+    let now = Utc::now().naive_utc();
+    if !availability::is_video_live(&state, &video, region.as_deref(), now)
+        .await
+        .map_err(map_err)?
+    {
+        return Err(not_found());
+    }
+
+    let owner = state
+        .profile_repo
+        .find_default(&user.id)
+        .await
+        .map_err(|e| UnlockVideoError::Database(format!("Erro ao buscar o perfil: {}", e)))?;
+    let Some((owner, pin_hash)) = owner.and_then(|owner| {
+        let pin_hash = owner.pin_hash.clone()?;
+        Some((owner, pin_hash))
+    }) else {
+        return Err(UnlockVideoError::Forbidden(
+            "Cadastre um PIN no perfil principal para liberar títulos acima da classificação"
+                .to_string(),
+        ));
+    };
+
+    // o PIN da conta divide o limite de tentativas com a troca de perfil
+    let check = || match verify(&payload.pin, &pin_hash) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(UnlockVideoError::Forbidden("PIN incorreto".to_string())),
+        Err(_) => Err(UnlockVideoError::Database(
+            "Erro ao verificar o PIN".to_string(),
+        )),
+    };
+    match limit_pin_attempts(&state, &owner, check).await {
+        Ok(PinAttempt::Checked(result)) => result?,
+        Ok(PinAttempt::Locked) => {
+            return Err(UnlockVideoError::TooManyAttempts(pin_locked_message()))
+        }
+        Err(e) => {
+            return Err(UnlockVideoError::Database(format!(
+                "Erro ao conferir o PIN: {}",
+                e
+            )))
+        }
+    }
+
+    maturity::unlock_video(&state, profile.id, video.id)
+        .await
+        .map_err(|e| UnlockVideoError::Cache(format!("Erro ao liberar o vídeo: {}", e)))?;
+
+    Ok(UnlockVideoOutputDTO {
+        video_id: video.id,
+        profile_id: profile.id,
+        unlocked_until: now + Duration::seconds(UNLOCK_TTL_SECS as i64),
+    })
+}
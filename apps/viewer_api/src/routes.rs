@@ -1 +1,72 @@
+use crate::modules::home::controllers::find_home_controller;
+use crate::modules::playback::controllers::{
+    dash_manifest_controller, find_subtitle_tracks_controller, hls_master_playlist_controller,
+    hls_media_playlist_controller, hls_subtitle_playlist_controller, playback_segment_controller,
+    subtitle_file_controller,
+};
+use crate::modules::recommendation::controllers::{
+    find_recommendations_controller, find_similar_videos_controller,
+};
+use crate::modules::search::controllers::{search_videos_controller, suggest_controller};
+use crate::modules::trending::controllers::{
+    find_trending_controller, find_trending_in_category_controller,
+};
+use crate::modules::video::controllers::{stream_video_controller, unlock_video_controller};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 
+pub fn create_router() -> Router {
+    Router::new()
+        .route("/home", get(find_home_controller::handler))
+        .route("/search", get(search_videos_controller::handler))
+        .route("/search/suggest", get(suggest_controller::handler))
+        .route(
+            "/recommendations",
+            get(find_recommendations_controller::handler),
+        )
+        .route("/trending", get(find_trending_controller::handler))
+        .route(
+            "/trending/category/{id}",
+            get(find_trending_in_category_controller::handler),
+        )
+        .nest(
+            "/video",
+            Router::new()
+                .route("/{id}/stream", get(stream_video_controller::handler))
+                .route("/{id}/unlock", post(unlock_video_controller::handler))
+                .route(
+                    "/{id}/similar",
+                    get(find_similar_videos_controller::handler),
+                )
+                .route(
+                    "/{id}/hls/master.m3u8",
+                    get(hls_master_playlist_controller::handler),
+                )
+                .route(
+                    "/{id}/hls/{asset_id}/media.m3u8",
+                    get(hls_media_playlist_controller::handler),
+                )
+                .route(
+                    "/{id}/hls/subtitle/{language}/playlist.m3u8",
+                    get(hls_subtitle_playlist_controller::handler),
+                )
+                .route(
+                    "/{id}/subtitle",
+                    get(find_subtitle_tracks_controller::handler),
+                )
+                .route(
+                    "/{id}/subtitle/{language}",
+                    get(subtitle_file_controller::handler),
+                )
+                .route(
+                    "/{id}/dash/manifest.mpd",
+                    get(dash_manifest_controller::handler),
+                )
+                .route(
+                    "/{id}/segment/{asset_id}/{segment}",
+                    get(playback_segment_controller::handler),
+                ),
+        )
+}
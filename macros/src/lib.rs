@@ -10,6 +10,7 @@ pub fn require_access(attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as ItemFn);
     let args: Punctuated<Path, Comma> = parse_macro_input!(attr with Punctuated::parse_terminated);
 
+    let attrs = &input.attrs;
     let sig = &input.sig;
     let vis = &input.vis;
     let block = &input.block;
@@ -17,8 +18,9 @@ pub fn require_access(attr: TokenStream, item: TokenStream) -> TokenStream {
     let groups: Vec<TokenStream2> = args.iter().map(|path| quote! { #path }).collect();
 
     let expanded = quote! {
+        #(#attrs)*
         #vis #sig {
-            let user_groups = user.access_groups;
+            let user_groups = &user.access_groups;
             let authorized = [#(#groups),*].iter().any(|g| user_groups.contains(g));
 
             if !authorized {
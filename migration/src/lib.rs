@@ -2,6 +2,26 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20250828_140352_create_streaming_schema;
 mod m20250828_313242_create_index_and_dadas;
+mod m20251019_100000_create_upload_sessions;
+mod m20251019_110000_create_media_assets;
+mod m20251019_120000_create_video_assets;
+mod m20251019_130000_add_probe_metadata_to_media_assets;
+mod m20251019_140000_create_transcode_jobs;
+mod m20251020_090000_add_segment_index_to_media_assets;
+mod m20251021_090000_create_images;
+mod m20251022_090000_add_deleted_at_to_videos_and_series;
+mod m20251023_090000_add_unique_episode_numbers;
+mod m20251024_090000_add_external_ids;
+mod m20251025_090000_create_availability_windows;
+mod m20251026_090000_add_video_search;
+mod m20251027_090000_add_search_suggest;
+mod m20251028_090000_create_video_similarities;
+mod m20251029_090000_create_home_rows;
+mod m20251030_090000_create_profiles;
+mod m20251031_090000_add_maturity_ratings;
+mod m20251101_090000_create_events;
+mod m20251102_090000_create_auth_sessions;
+mod m20251103_090000_add_pin_lockout_to_profiles;
 
 pub struct Migrator;
 
@@ -11,6 +31,26 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20250828_140352_create_streaming_schema::Migration),
             Box::new(m20250828_313242_create_index_and_dadas::Migration),
+            Box::new(m20251019_100000_create_upload_sessions::Migration),
+            Box::new(m20251019_110000_create_media_assets::Migration),
+            Box::new(m20251019_120000_create_video_assets::Migration),
+            Box::new(m20251019_130000_add_probe_metadata_to_media_assets::Migration),
+            Box::new(m20251019_140000_create_transcode_jobs::Migration),
+            Box::new(m20251020_090000_add_segment_index_to_media_assets::Migration),
+            Box::new(m20251021_090000_create_images::Migration),
+            Box::new(m20251022_090000_add_deleted_at_to_videos_and_series::Migration),
+            Box::new(m20251023_090000_add_unique_episode_numbers::Migration),
+            Box::new(m20251024_090000_add_external_ids::Migration),
+            Box::new(m20251025_090000_create_availability_windows::Migration),
+            Box::new(m20251026_090000_add_video_search::Migration),
+            Box::new(m20251027_090000_add_search_suggest::Migration),
+            Box::new(m20251028_090000_create_video_similarities::Migration),
+            Box::new(m20251029_090000_create_home_rows::Migration),
+            Box::new(m20251030_090000_create_profiles::Migration),
+            Box::new(m20251031_090000_add_maturity_ratings::Migration),
+            Box::new(m20251101_090000_create_events::Migration),
+            Box::new(m20251102_090000_create_auth_sessions::Migration),
+            Box::new(m20251103_090000_add_pin_lockout_to_profiles::Migration),
         ]
     }
 }
@@ -0,0 +1,140 @@
+use crate::m20250828_140352_create_streaming_schema::Videos;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // UPLOAD_SESSIONS
+        manager
+            .create_table(
+                Table::create()
+                    .table(UploadSessions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UploadSessions::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UploadSessions::VideoId).integer().not_null())
+                    .col(ColumnDef::new(UploadSessions::BlobName).string().not_null())
+                    .col(ColumnDef::new(UploadSessions::FileName).string().not_null())
+                    .col(
+                        ColumnDef::new(UploadSessions::ContentType)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UploadSessions::TotalSize)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UploadSessions::BlockSize)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UploadSessions::TotalBlocks)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UploadSessions::ExpectedSha256)
+                            .string()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(UploadSessions::Status).string().not_null())
+                    .col(ColumnDef::new(UploadSessions::CreatedBy).string().null())
+                    .col(
+                        ColumnDef::new(UploadSessions::ExpiresAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UploadSessions::CompletedAt)
+                            .date_time()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(UploadSessions::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UploadSessions::UpdatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-upload_sessions-video_id")
+                    .from_tbl(UploadSessions::Table)
+                    .from_col(UploadSessions::VideoId)
+                    .to_tbl(Videos::Table)
+                    .to_col(Videos::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_upload_sessions_video")
+                    .table(UploadSessions::Table)
+                    .col(UploadSessions::VideoId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_upload_sessions_video").to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-upload_sessions-video_id")
+                    .table(UploadSessions::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(UploadSessions::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum UploadSessions {
+    Table,
+    Id,
+    VideoId,
+    BlobName,
+    FileName,
+    ContentType,
+    TotalSize,
+    BlockSize,
+    TotalBlocks,
+    ExpectedSha256,
+    Status,
+    CreatedBy,
+    ExpiresAt,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,115 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // MEDIA_ASSETS
+        manager
+            .create_table(
+                Table::create()
+                    .table(MediaAssets::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MediaAssets::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(MediaAssets::Container).string().not_null())
+                    .col(ColumnDef::new(MediaAssets::StorageKey).string().not_null())
+                    .col(ColumnDef::new(MediaAssets::Url).string().not_null())
+                    .col(
+                        ColumnDef::new(MediaAssets::OriginalFilename)
+                            .string()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(MediaAssets::ContentType).string().not_null())
+                    .col(
+                        ColumnDef::new(MediaAssets::SizeBytes)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MediaAssets::Sha256).string().not_null())
+                    .col(ColumnDef::new(MediaAssets::Status).string().not_null())
+                    .col(
+                        ColumnDef::new(MediaAssets::LastVerifiedAt)
+                            .date_time()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaAssets::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MediaAssets::UpdatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_media_assets_storage_key")
+                    .table(MediaAssets::Table)
+                    .col(MediaAssets::Container)
+                    .col(MediaAssets::StorageKey)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_media_assets_status")
+                    .table(MediaAssets::Table)
+                    .col(MediaAssets::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_media_assets_status").to_owned())
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_media_assets_storage_key")
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(MediaAssets::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum MediaAssets {
+    Table,
+    Id,
+    Container,
+    StorageKey,
+    Url,
+    OriginalFilename,
+    ContentType,
+    SizeBytes,
+    Sha256,
+    Status,
+    LastVerifiedAt,
+    CreatedAt,
+    UpdatedAt,
+}
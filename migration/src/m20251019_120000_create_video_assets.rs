@@ -0,0 +1,151 @@
+use crate::m20250828_140352_create_streaming_schema::Videos;
+use crate::m20251019_110000_create_media_assets::MediaAssets;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // VIDEO_ASSETS
+        manager
+            .create_table(
+                Table::create()
+                    .table(VideoAssets::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VideoAssets::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(VideoAssets::VideoId).integer().not_null())
+                    .col(
+                        ColumnDef::new(VideoAssets::MediaAssetId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(VideoAssets::Kind).string().not_null())
+                    .col(ColumnDef::new(VideoAssets::Width).integer().null())
+                    .col(ColumnDef::new(VideoAssets::Height).integer().null())
+                    .col(ColumnDef::new(VideoAssets::BitrateKbps).integer().null())
+                    .col(ColumnDef::new(VideoAssets::Codec).string().null())
+                    .col(ColumnDef::new(VideoAssets::Language).string().null())
+                    .col(
+                        ColumnDef::new(VideoAssets::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VideoAssets::UpdatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-video_assets-video_id")
+                    .from_tbl(VideoAssets::Table)
+                    .from_col(VideoAssets::VideoId)
+                    .to_tbl(Videos::Table)
+                    .to_col(Videos::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-video_assets-media_asset_id")
+                    .from_tbl(VideoAssets::Table)
+                    .from_col(VideoAssets::MediaAssetId)
+                    .to_tbl(MediaAssets::Table)
+                    .to_col(MediaAssets::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_video_assets_video_kind")
+                    .table(VideoAssets::Table)
+                    .col(VideoAssets::VideoId)
+                    .col(VideoAssets::Kind)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_video_assets_video_media_asset")
+                    .table(VideoAssets::Table)
+                    .col(VideoAssets::VideoId)
+                    .col(VideoAssets::MediaAssetId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_video_assets_video_media_asset")
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(Index::drop().name("idx_video_assets_video_kind").to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-video_assets-media_asset_id")
+                    .table(VideoAssets::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-video_assets-video_id")
+                    .table(VideoAssets::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(VideoAssets::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum VideoAssets {
+    Table,
+    Id,
+    VideoId,
+    MediaAssetId,
+    Kind,
+    Width,
+    Height,
+    BitrateKbps,
+    Codec,
+    Language,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,66 @@
+use crate::m20251019_110000_create_media_assets::MediaAssets;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // METADADOS EXTRAÍDOS DO CONTAINER
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MediaAssets::Table)
+                    .add_column(
+                        ColumnDef::new(ProbeColumns::ContainerFormat)
+                            .string()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(ProbeColumns::DurationMs)
+                            .big_integer()
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(ProbeColumns::Width).integer().null())
+                    .add_column(ColumnDef::new(ProbeColumns::Height).integer().null())
+                    .add_column(ColumnDef::new(ProbeColumns::VideoCodec).string().null())
+                    .add_column(ColumnDef::new(ProbeColumns::AudioCodec).string().null())
+                    .add_column(ColumnDef::new(ProbeColumns::Faststart).boolean().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MediaAssets::Table)
+                    .drop_column(ProbeColumns::ContainerFormat)
+                    .drop_column(ProbeColumns::DurationMs)
+                    .drop_column(ProbeColumns::Width)
+                    .drop_column(ProbeColumns::Height)
+                    .drop_column(ProbeColumns::VideoCodec)
+                    .drop_column(ProbeColumns::AudioCodec)
+                    .drop_column(ProbeColumns::Faststart)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ProbeColumns {
+    ContainerFormat,
+    DurationMs,
+    Width,
+    Height,
+    VideoCodec,
+    AudioCodec,
+    Faststart,
+}
@@ -0,0 +1,176 @@
+use crate::m20250828_140352_create_streaming_schema::Videos;
+use crate::m20251019_110000_create_media_assets::MediaAssets;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // TRANSCODE_JOBS
+        manager
+            .create_table(
+                Table::create()
+                    .table(TranscodeJobs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TranscodeJobs::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TranscodeJobs::VideoId).integer().not_null())
+                    .col(
+                        ColumnDef::new(TranscodeJobs::SourceMediaAssetId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TranscodeJobs::Status).string().not_null())
+                    .col(
+                        ColumnDef::new(TranscodeJobs::Progress)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(TranscodeJobs::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(TranscodeJobs::MaxAttempts)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TranscodeJobs::LastError).text().null())
+                    .col(ColumnDef::new(TranscodeJobs::LockedBy).string().null())
+                    .col(ColumnDef::new(TranscodeJobs::LockedAt).date_time().null())
+                    .col(
+                        ColumnDef::new(TranscodeJobs::RunAfter)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TranscodeJobs::StartedAt).date_time().null())
+                    .col(ColumnDef::new(TranscodeJobs::FinishedAt).date_time().null())
+                    .col(
+                        ColumnDef::new(TranscodeJobs::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TranscodeJobs::UpdatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-transcode_jobs-video_id")
+                    .from_tbl(TranscodeJobs::Table)
+                    .from_col(TranscodeJobs::VideoId)
+                    .to_tbl(Videos::Table)
+                    .to_col(Videos::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-transcode_jobs-source_media_asset_id")
+                    .from_tbl(TranscodeJobs::Table)
+                    .from_col(TranscodeJobs::SourceMediaAssetId)
+                    .to_tbl(MediaAssets::Table)
+                    .to_col(MediaAssets::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        // fila: o worker busca por status e run_after
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_transcode_jobs_status_run_after")
+                    .table(TranscodeJobs::Table)
+                    .col(TranscodeJobs::Status)
+                    .col(TranscodeJobs::RunAfter)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_transcode_jobs_video")
+                    .table(TranscodeJobs::Table)
+                    .col(TranscodeJobs::VideoId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_transcode_jobs_video").to_owned())
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_transcode_jobs_status_run_after")
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-transcode_jobs-source_media_asset_id")
+                    .table(TranscodeJobs::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-transcode_jobs-video_id")
+                    .table(TranscodeJobs::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(TranscodeJobs::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum TranscodeJobs {
+    Table,
+    Id,
+    VideoId,
+    SourceMediaAssetId,
+    Status,
+    Progress,
+    Attempts,
+    MaxAttempts,
+    LastError,
+    LockedBy,
+    LockedAt,
+    RunAfter,
+    StartedAt,
+    FinishedAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,44 @@
+use crate::m20251019_110000_create_media_assets::MediaAssets;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ÍNDICE DE SEGMENTOS (MP4 FRAGMENTADO) PARA HLS/DASH
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MediaAssets::Table)
+                    .add_column(
+                        ColumnDef::new(SegmentIndexColumns::SegmentIndex)
+                            .json_binary()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MediaAssets::Table)
+                    .drop_column(SegmentIndexColumns::SegmentIndex)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum SegmentIndexColumns {
+    SegmentIndex,
+}
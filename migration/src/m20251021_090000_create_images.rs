@@ -0,0 +1,111 @@
+use crate::m20250828_140352_create_streaming_schema::Videos;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // IMAGES (THUMBNAILS, POSTERS E FOTOS)
+        manager
+            .create_table(
+                Table::create()
+                    .table(Images::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Images::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Images::OwnerType).string().not_null())
+                    .col(ColumnDef::new(Images::OwnerId).integer().not_null())
+                    .col(ColumnDef::new(Images::Kind).string().not_null())
+                    .col(ColumnDef::new(Images::Container).string().not_null())
+                    .col(ColumnDef::new(Images::Format).string().not_null())
+                    .col(ColumnDef::new(Images::Width).integer().not_null())
+                    .col(ColumnDef::new(Images::Height).integer().not_null())
+                    .col(ColumnDef::new(Images::SizeBytes).big_integer().not_null())
+                    .col(ColumnDef::new(Images::Sha256).string().not_null())
+                    .col(ColumnDef::new(Images::OriginalKey).string().not_null())
+                    .col(ColumnDef::new(Images::OriginalUrl).string().not_null())
+                    .col(ColumnDef::new(Images::Variants).json_binary().not_null())
+                    .col(ColumnDef::new(Images::CreatedAt).date_time().not_null())
+                    .col(ColumnDef::new(Images::UpdatedAt).date_time().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // uma imagem de cada tipo por dono
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_images_owner_kind")
+                    .table(Images::Table)
+                    .col(Images::OwnerType)
+                    .col(Images::OwnerId)
+                    .col(Images::Kind)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // VIDEOS.THUMBNAIL_URL
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Videos::Table)
+                    .add_column(ColumnDef::new(ImageColumns::ThumbnailUrl).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Videos::Table)
+                    .drop_column(ImageColumns::ThumbnailUrl)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(Index::drop().name("idx_images_owner_kind").to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Images::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Images {
+    Table,
+    Id,
+    OwnerType,
+    OwnerId,
+    Kind,
+    Container,
+    Format,
+    Width,
+    Height,
+    SizeBytes,
+    Sha256,
+    OriginalKey,
+    OriginalUrl,
+    Variants,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum ImageColumns {
+    ThumbnailUrl,
+}
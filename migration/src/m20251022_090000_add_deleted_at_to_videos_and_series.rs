@@ -0,0 +1,84 @@
+use crate::m20250828_140352_create_streaming_schema::{Series, Videos};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // LIXEIRA: VIDEOS E SERIES
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Videos::Table)
+                    .add_column(ColumnDef::new(TrashColumns::DeletedAt).date_time().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Series::Table)
+                    .add_column(ColumnDef::new(TrashColumns::DeletedAt).date_time().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // a limpeza busca itens apagados há mais tempo que a retenção
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_videos_deleted_at")
+                    .table(Videos::Table)
+                    .col(TrashColumns::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_series_deleted_at")
+                    .table(Series::Table)
+                    .col(TrashColumns::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_series_deleted_at").to_owned())
+            .await?;
+        manager
+            .drop_index(Index::drop().name("idx_videos_deleted_at").to_owned())
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Series::Table)
+                    .drop_column(TrashColumns::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Videos::Table)
+                    .drop_column(TrashColumns::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum TrashColumns {
+    DeletedAt,
+}
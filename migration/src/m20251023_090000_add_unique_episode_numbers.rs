@@ -0,0 +1,79 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // DUPLICADOS: o índice não seria criado com episódios repetidos, então
+        // o mais antigo (menor id) fica com o número e os demais vão para o fim
+        // da temporada, na ordem de id
+        db.execute_unprepared(
+            r#"
+            WITH ranked AS (
+                SELECT id, series_id, COALESCE(season_number, -1) AS season,
+                       ROW_NUMBER() OVER (
+                           PARTITION BY series_id, COALESCE(season_number, -1), episode_number
+                           ORDER BY id
+                       ) AS position
+                FROM videos
+                WHERE series_id IS NOT NULL
+                  AND episode_number IS NOT NULL
+                  AND deleted_at IS NULL
+            ), last_numbers AS (
+                SELECT series_id, COALESCE(season_number, -1) AS season,
+                       MAX(episode_number) AS last_number
+                FROM videos
+                WHERE series_id IS NOT NULL AND deleted_at IS NULL
+                GROUP BY series_id, COALESCE(season_number, -1)
+            ), renumbered AS (
+                SELECT ranked.id,
+                       last_numbers.last_number + ROW_NUMBER() OVER (
+                           PARTITION BY ranked.series_id, ranked.season
+                           ORDER BY ranked.id
+                       ) AS episode_number
+                FROM ranked
+                JOIN last_numbers
+                  ON last_numbers.series_id = ranked.series_id
+                 AND last_numbers.season = ranked.season
+                WHERE ranked.position > 1
+            )
+            UPDATE videos
+            SET episode_number = renumbered.episode_number, updated_at = now()
+            FROM renumbered
+            WHERE videos.id = renumbered.id
+            "#,
+        )
+        .await?;
+
+        // EPISÓDIOS: (series_id, season_number, episode_number) ÚNICO
+        // sem temporada conta como uma temporada à parte (-1), senão o NULL
+        // deixaria passar repetidos; itens na lixeira ficam de fora para que
+        // o número possa ser reutilizado
+        db.execute_unprepared(
+            r#"
+            CREATE UNIQUE INDEX idx_videos_series_episode_unique
+            ON videos (series_id, COALESCE(season_number, -1), episode_number)
+            WHERE series_id IS NOT NULL AND deleted_at IS NULL
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_videos_series_episode_unique")
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
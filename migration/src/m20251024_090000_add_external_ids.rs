@@ -0,0 +1,76 @@
+use crate::m20250828_140352_create_streaming_schema::{
+    Actors, Categories, Directors, Series, Videos,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tabelas do catálogo que recebem `external_id`, com o nome do índice único.
+fn catalog_tables() -> Vec<(DynIden, &'static str)> {
+    vec![
+        (Categories::Table.into_iden(), "idx_categories_external_id"),
+        (Actors::Table.into_iden(), "idx_actors_external_id"),
+        (Directors::Table.into_iden(), "idx_directors_external_id"),
+        (Series::Table.into_iden(), "idx_series_external_id"),
+        (Videos::Table.into_iden(), "idx_videos_external_id"),
+    ]
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // IMPORTAÇÃO: IDENTIFICADOR DO SISTEMA DE ORIGEM
+        for (table, index) in catalog_tables() {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table.clone())
+                        .add_column(
+                            ColumnDef::new(ExternalIdColumns::ExternalId)
+                                .string_len(100)
+                                .null(),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+
+            // a importação faz upsert por external_id
+            manager
+                .create_index(
+                    Index::create()
+                        .name(index)
+                        .table(table)
+                        .col(ExternalIdColumns::ExternalId)
+                        .unique()
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for (table, index) in catalog_tables() {
+            manager
+                .drop_index(Index::drop().name(index).to_owned())
+                .await?;
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(ExternalIdColumns::ExternalId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ExternalIdColumns {
+    ExternalId,
+}
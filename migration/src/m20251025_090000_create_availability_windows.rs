@@ -0,0 +1,155 @@
+use crate::m20250828_140352_create_streaming_schema::{Series, Videos};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // AGENDAMENTO: JANELA GLOBAL EM VIDEOS E SERIES
+        for table in [Videos::Table.into_iden(), Series::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(
+                            ColumnDef::new(AvailabilityColumns::AvailableFrom)
+                                .date_time()
+                                .null(),
+                        )
+                        .add_column(
+                            ColumnDef::new(AvailabilityColumns::AvailableUntil)
+                                .date_time()
+                                .null(),
+                        )
+                        // o catálogo existente já está no ar
+                        .add_column(
+                            ColumnDef::new(AvailabilityColumns::ReleaseState)
+                                .string()
+                                .not_null()
+                                .default("LIVE"),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // JANELAS POR REGIÃO (SUBSTITUEM A GLOBAL NAQUELA REGIÃO)
+        manager
+            .create_table(
+                Table::create()
+                    .table(AvailabilityWindows::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AvailabilityWindows::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AvailabilityWindows::OwnerType)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AvailabilityWindows::OwnerId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AvailabilityWindows::Region)
+                            .string_len(2)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AvailabilityWindows::AvailableFrom)
+                            .date_time()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(AvailabilityWindows::AvailableUntil)
+                            .date_time()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(AvailabilityWindows::ReleaseState)
+                            .string()
+                            .not_null()
+                            .default("LIVE"),
+                    )
+                    .col(
+                        ColumnDef::new(AvailabilityWindows::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // uma janela por região de cada item
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_availability_windows_owner_region")
+                    .table(AvailabilityWindows::Table)
+                    .col(AvailabilityWindows::OwnerType)
+                    .col(AvailabilityWindows::OwnerId)
+                    .col(AvailabilityWindows::Region)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_availability_windows_owner_region")
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(AvailabilityWindows::Table).to_owned())
+            .await?;
+
+        for table in [Series::Table.into_iden(), Videos::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(AvailabilityColumns::ReleaseState)
+                        .drop_column(AvailabilityColumns::AvailableUntil)
+                        .drop_column(AvailabilityColumns::AvailableFrom)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum AvailabilityWindows {
+    Table,
+    Id,
+    OwnerType,
+    OwnerId,
+    Region,
+    AvailableFrom,
+    AvailableUntil,
+    ReleaseState,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum AvailabilityColumns {
+    AvailableFrom,
+    AvailableUntil,
+    ReleaseState,
+}
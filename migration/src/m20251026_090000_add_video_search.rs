@@ -0,0 +1,176 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // BUSCA: PORTUGUÊS SEM ACENTOS
+        db.execute_unprepared(
+            r#"
+            CREATE EXTENSION IF NOT EXISTS unaccent;
+
+            CREATE TEXT SEARCH CONFIGURATION portuguese_unaccent (COPY = portuguese);
+            ALTER TEXT SEARCH CONFIGURATION portuguese_unaccent
+                ALTER MAPPING FOR hword, hword_part, word WITH unaccent, portuguese_stem;
+            "#,
+        )
+        .await?;
+
+        // VIDEOS.SEARCH_VECTOR
+        // título (A), série (B), elenco e direção (C) e descrição (D); a coluna
+        // fica fora do model do SeaORM e só é lida pelas consultas de busca
+        db.execute_unprepared(
+            r#"
+            ALTER TABLE videos ADD COLUMN search_vector tsvector NOT NULL DEFAULT ''::tsvector;
+
+            CREATE FUNCTION video_search_document(
+                p_video_id integer,
+                p_title text,
+                p_description text,
+                p_series_id integer
+            ) RETURNS tsvector LANGUAGE sql STABLE AS $$
+                SELECT
+                    setweight(to_tsvector('portuguese_unaccent', coalesce(p_title, '')), 'A')
+                    || setweight(to_tsvector('portuguese_unaccent', coalesce(
+                        (SELECT s.title FROM series s WHERE s.id = p_series_id), '')), 'B')
+                    || setweight(to_tsvector('portuguese_unaccent', coalesce(
+                        (SELECT string_agg(a.name, ' ') FROM video_actors va
+                         JOIN actors a ON a.id = va.actor_id WHERE va.video_id = p_video_id), '')
+                        || ' ' || coalesce(
+                        (SELECT string_agg(d.name, ' ') FROM video_directors vd
+                         JOIN directors d ON d.id = vd.director_id WHERE vd.video_id = p_video_id), '')), 'C')
+                    || setweight(to_tsvector('portuguese_unaccent', coalesce(p_description, '')), 'D')
+            $$;
+
+            CREATE FUNCTION refresh_video_search_vector(p_video_ids integer[]) RETURNS void
+            LANGUAGE sql AS $$
+                UPDATE videos
+                SET search_vector = video_search_document(id, title, description, series_id)
+                WHERE id = ANY(p_video_ids)
+            $$;
+            "#,
+        )
+        .await?;
+
+        // GATILHOS: o vetor acompanha o vídeo, a série e os créditos
+        db.execute_unprepared(
+            r#"
+            CREATE FUNCTION videos_search_vector_trigger() RETURNS trigger LANGUAGE plpgsql AS $$
+            BEGIN
+                NEW.search_vector := video_search_document(
+                    NEW.id, NEW.title, NEW.description, NEW.series_id);
+                RETURN NEW;
+            END
+            $$;
+
+            CREATE TRIGGER trg_videos_search_vector
+                BEFORE INSERT OR UPDATE OF title, description, series_id ON videos
+                FOR EACH ROW EXECUTE FUNCTION videos_search_vector_trigger();
+
+            CREATE FUNCTION series_search_vector_trigger() RETURNS trigger LANGUAGE plpgsql AS $$
+            BEGIN
+                PERFORM refresh_video_search_vector(
+                    ARRAY(SELECT id FROM videos WHERE series_id = NEW.id));
+                RETURN NULL;
+            END
+            $$;
+
+            CREATE TRIGGER trg_series_search_vector
+                AFTER UPDATE OF title ON series
+                FOR EACH ROW EXECUTE FUNCTION series_search_vector_trigger();
+
+            CREATE FUNCTION credits_search_vector_trigger() RETURNS trigger LANGUAGE plpgsql AS $$
+            BEGIN
+                IF TG_OP IN ('UPDATE', 'DELETE') THEN
+                    PERFORM refresh_video_search_vector(ARRAY[OLD.video_id]);
+                END IF;
+                IF TG_OP IN ('INSERT', 'UPDATE') THEN
+                    PERFORM refresh_video_search_vector(ARRAY[NEW.video_id]);
+                END IF;
+                RETURN NULL;
+            END
+            $$;
+
+            CREATE TRIGGER trg_video_actors_search_vector
+                AFTER INSERT OR UPDATE OR DELETE ON video_actors
+                FOR EACH ROW EXECUTE FUNCTION credits_search_vector_trigger();
+
+            CREATE TRIGGER trg_video_directors_search_vector
+                AFTER INSERT OR UPDATE OR DELETE ON video_directors
+                FOR EACH ROW EXECUTE FUNCTION credits_search_vector_trigger();
+
+            CREATE FUNCTION actors_search_vector_trigger() RETURNS trigger LANGUAGE plpgsql AS $$
+            BEGIN
+                PERFORM refresh_video_search_vector(
+                    ARRAY(SELECT video_id FROM video_actors WHERE actor_id = NEW.id));
+                RETURN NULL;
+            END
+            $$;
+
+            CREATE TRIGGER trg_actors_search_vector
+                AFTER UPDATE OF name ON actors
+                FOR EACH ROW EXECUTE FUNCTION actors_search_vector_trigger();
+
+            CREATE FUNCTION directors_search_vector_trigger() RETURNS trigger LANGUAGE plpgsql AS $$
+            BEGIN
+                PERFORM refresh_video_search_vector(
+                    ARRAY(SELECT video_id FROM video_directors WHERE director_id = NEW.id));
+                RETURN NULL;
+            END
+            $$;
+
+            CREATE TRIGGER trg_directors_search_vector
+                AFTER UPDATE OF name ON directors
+                FOR EACH ROW EXECUTE FUNCTION directors_search_vector_trigger();
+            "#,
+        )
+        .await?;
+
+        db.execute_unprepared(
+            r#"
+            UPDATE videos
+            SET search_vector = video_search_document(id, title, description, series_id);
+
+            CREATE INDEX idx_videos_search_vector ON videos USING GIN (search_vector);
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                DROP TRIGGER IF EXISTS trg_directors_search_vector ON directors;
+                DROP TRIGGER IF EXISTS trg_actors_search_vector ON actors;
+                DROP TRIGGER IF EXISTS trg_video_directors_search_vector ON video_directors;
+                DROP TRIGGER IF EXISTS trg_video_actors_search_vector ON video_actors;
+                DROP TRIGGER IF EXISTS trg_series_search_vector ON series;
+                DROP TRIGGER IF EXISTS trg_videos_search_vector ON videos;
+
+                DROP FUNCTION IF EXISTS directors_search_vector_trigger();
+                DROP FUNCTION IF EXISTS actors_search_vector_trigger();
+                DROP FUNCTION IF EXISTS credits_search_vector_trigger();
+                DROP FUNCTION IF EXISTS series_search_vector_trigger();
+                DROP FUNCTION IF EXISTS videos_search_vector_trigger();
+                DROP FUNCTION IF EXISTS refresh_video_search_vector(integer[]);
+                DROP FUNCTION IF EXISTS video_search_document(integer, text, text, integer);
+
+                DROP INDEX IF EXISTS idx_videos_search_vector;
+                ALTER TABLE videos DROP COLUMN IF EXISTS search_vector;
+
+                DROP TEXT SEARCH CONFIGURATION IF EXISTS portuguese_unaccent;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // SUGESTÕES: TRIGRAMAS SOBRE O TEXTO SEM ACENTOS
+        // o `unaccent` é STABLE e não entra em índice; a versão com o dicionário
+        // explícito é estável de fato e pode ser marcada IMMUTABLE
+        db.execute_unprepared(
+            r#"
+            CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+            CREATE FUNCTION search_normalize(p_value text) RETURNS text
+            LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT AS $$
+                SELECT lower(public.unaccent('public.unaccent'::regdictionary, p_value))
+            $$;
+            "#,
+        )
+        .await?;
+
+        db.execute_unprepared(
+            r#"
+            CREATE INDEX idx_videos_title_trgm
+                ON videos USING GIN (search_normalize(title) gin_trgm_ops);
+            CREATE INDEX idx_series_title_trgm
+                ON series USING GIN (search_normalize(title) gin_trgm_ops);
+            CREATE INDEX idx_actors_name_trgm
+                ON actors USING GIN (search_normalize(name) gin_trgm_ops);
+            CREATE INDEX idx_directors_name_trgm
+                ON directors USING GIN (search_normalize(name) gin_trgm_ops);
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                DROP INDEX IF EXISTS idx_directors_name_trgm;
+                DROP INDEX IF EXISTS idx_actors_name_trgm;
+                DROP INDEX IF EXISTS idx_series_title_trgm;
+                DROP INDEX IF EXISTS idx_videos_title_trgm;
+
+                DROP FUNCTION IF EXISTS search_normalize(text);
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::m20250828_140352_create_streaming_schema::Videos;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // VIDEO_SIMILARITIES (RECALCULADA PELO MEDIA_WORKER)
+        manager
+            .create_table(
+                Table::create()
+                    .table(VideoSimilarities::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VideoSimilarities::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VideoSimilarities::VideoId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VideoSimilarities::SimilarVideoId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(VideoSimilarities::Score).double().not_null())
+                    .col(
+                        ColumnDef::new(VideoSimilarities::CoWatchScore)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VideoSimilarities::RatingScore)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VideoSimilarities::MetadataScore)
+                            .double()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VideoSimilarities::ComputedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        for (name, column) in [
+            ("fk-video_similarities-video_id", VideoSimilarities::VideoId),
+            (
+                "fk-video_similarities-similar_video_id",
+                VideoSimilarities::SimilarVideoId,
+            ),
+        ] {
+            manager
+                .create_foreign_key(
+                    ForeignKey::create()
+                        .name(name)
+                        .from_tbl(VideoSimilarities::Table)
+                        .from_col(column)
+                        .to_tbl(Videos::Table)
+                        .to_col(Videos::Id)
+                        .on_delete(ForeignKeyAction::Cascade)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_video_similarities_pair")
+                    .table(VideoSimilarities::Table)
+                    .col(VideoSimilarities::VideoId)
+                    .col(VideoSimilarities::SimilarVideoId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // "parecidos com X" e as linhas personalizadas leem por vídeo de origem
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_video_similarities_video_score")
+                    .table(VideoSimilarities::Table)
+                    .col(VideoSimilarities::VideoId)
+                    .col((VideoSimilarities::Score, IndexOrder::Desc))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(VideoSimilarities::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum VideoSimilarities {
+    Table,
+    Id,
+    VideoId,
+    SimilarVideoId,
+    Score,
+    CoWatchScore,
+    RatingScore,
+    MetadataScore,
+    ComputedAt,
+}
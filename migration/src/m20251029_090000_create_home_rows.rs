@@ -0,0 +1,135 @@
+use crate::m20250828_140352_create_streaming_schema::Categories;
+use sea_orm_migration::prelude::*;
+use shared::enums::home_row_kind_enum::HomeRowKindEnum;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // HOME_ROWS
+        manager
+            .create_table(
+                Table::create()
+                    .table(HomeRows::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(HomeRows::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(HomeRows::Kind).string().not_null())
+                    .col(ColumnDef::new(HomeRows::Title).string().not_null())
+                    .col(ColumnDef::new(HomeRows::Position).integer().not_null())
+                    .col(ColumnDef::new(HomeRows::CategoryId).integer().null())
+                    .col(ColumnDef::new(HomeRows::TrendingWindow).string().null())
+                    .col(
+                        ColumnDef::new(HomeRows::ItemLimit)
+                            .integer()
+                            .not_null()
+                            .default(20),
+                    )
+                    .col(
+                        ColumnDef::new(HomeRows::CacheTtlSecs)
+                            .integer()
+                            .not_null()
+                            .default(300),
+                    )
+                    .col(
+                        ColumnDef::new(HomeRows::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(ColumnDef::new(HomeRows::CreatedAt).date_time().not_null())
+                    .col(ColumnDef::new(HomeRows::UpdatedAt).date_time().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-home_rows-category_id")
+                    .from_tbl(HomeRows::Table)
+                    .from_col(HomeRows::CategoryId)
+                    .to_tbl(Categories::Table)
+                    .to_col(Categories::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_home_rows_position")
+                    .table(HomeRows::Table)
+                    .col(HomeRows::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        // DADOS INICIAIS
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                r#"
+                INSERT INTO home_rows (kind, title, position, trending_window, item_limit, cache_ttl_secs, is_active, created_at, updated_at) VALUES
+                ('{}', 'Continue assistindo', 1, NULL, 20, 0, TRUE, NOW(), NOW()),
+                ('{}', 'Séries em destaque', 2, NULL, 20, 300, TRUE, NOW(), NOW()),
+                ('{}', 'Top 10 de hoje', 3, 'DAY', 10, 300, TRUE, NOW(), NOW()),
+                ('{}', 'Minha lista', 4, NULL, 20, 0, TRUE, NOW(), NOW()),
+                ('{}', 'Recomendados para você', 5, NULL, 20, 300, TRUE, NOW(), NOW()),
+                ('{}', 'Lançamentos', 6, NULL, 20, 300, TRUE, NOW(), NOW())
+                "#,
+                HomeRowKindEnum::CONTINUE_WATCHING.as_str(),
+                HomeRowKindEnum::FEATURED_SERIES.as_str(),
+                HomeRowKindEnum::TRENDING.as_str(),
+                HomeRowKindEnum::MY_LIST.as_str(),
+                HomeRowKindEnum::RECOMMENDATIONS.as_str(),
+                HomeRowKindEnum::NEW_RELEASES.as_str(),
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_home_rows_position").to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-home_rows-category_id")
+                    .table(HomeRows::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(HomeRows::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum HomeRows {
+    Table,
+    Id,
+    Kind,
+    Title,
+    Position,
+    CategoryId,
+    TrendingWindow,
+    ItemLimit,
+    CacheTtlSecs,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,243 @@
+use crate::m20250828_140352_create_streaming_schema::{Favorites, Ratings, Users, WatchHistory};
+use sea_orm_migration::prelude::*;
+use shared::enums::maturity_rating_enum::MaturityRatingEnum;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tabelas de atividade que passam a pertencer ao perfil, com o nome da FK e do índice.
+fn activity_tables() -> Vec<(DynIden, &'static str, &'static str, &'static str)> {
+    vec![
+        (
+            WatchHistory::Table.into_iden(),
+            "watch_history",
+            "fk-watch_history-profile_id",
+            "idx_watch_history_profile",
+        ),
+        (
+            Favorites::Table.into_iden(),
+            "favorites",
+            "fk-favorites-profile_id",
+            "idx_favorites_profile",
+        ),
+        (
+            Ratings::Table.into_iden(),
+            "ratings",
+            "fk-ratings-profile_id",
+            "idx_ratings_profile",
+        ),
+    ]
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // PROFILES
+        manager
+            .create_table(
+                Table::create()
+                    .table(Profiles::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Profiles::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Profiles::UserId).string().not_null())
+                    .col(ColumnDef::new(Profiles::Name).string_len(50).not_null())
+                    .col(ColumnDef::new(Profiles::AvatarUrl).string().null())
+                    .col(
+                        ColumnDef::new(Profiles::MaturityLevel)
+                            .string_len(2)
+                            .not_null()
+                            .default(MaturityRatingEnum::A18.as_str()),
+                    )
+                    .col(
+                        ColumnDef::new(Profiles::IsKids)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(Profiles::PinHash).string().null())
+                    .col(
+                        ColumnDef::new(Profiles::IsDefault)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(Profiles::CreatedAt).date_time().not_null())
+                    .col(ColumnDef::new(Profiles::UpdatedAt).date_time().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-profiles-user_id")
+                    .from_tbl(Profiles::Table)
+                    .from_col(Profiles::UserId)
+                    .to_tbl(Users::Table)
+                    .to_col(Users::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_profiles_user_name")
+                    .table(Profiles::Table)
+                    .col(Profiles::UserId)
+                    .col(Profiles::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // um único perfil principal por conta
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX idx_profiles_user_default ON profiles (user_id) WHERE is_default",
+            )
+            .await?;
+
+        // todo usuário existente ganha o perfil principal, que herda a atividade da conta
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                r#"
+                INSERT INTO profiles (user_id, name, avatar_url, maturity_level, is_kids, is_default, created_at, updated_at)
+                SELECT id, LEFT(name, 50), profile_picture_url, '{}', FALSE, TRUE, NOW(), NOW() FROM users
+                "#,
+                MaturityRatingEnum::A18.as_str(),
+            ))
+            .await?;
+
+        for (table, name, foreign_key, index) in activity_tables() {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table.clone())
+                        .add_column(ColumnDef::new(ProfileIdColumns::ProfileId).integer().null())
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .get_connection()
+                .execute_unprepared(&format!(
+                    "UPDATE {name} t SET profile_id = p.id FROM profiles p \
+                     WHERE p.user_id = t.user_id AND p.is_default"
+                ))
+                .await?;
+
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table.clone())
+                        .modify_column(
+                            ColumnDef::new(ProfileIdColumns::ProfileId)
+                                .integer()
+                                .not_null(),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_foreign_key(
+                    ForeignKey::create()
+                        .name(foreign_key)
+                        .from_tbl(table.clone())
+                        .from_col(ProfileIdColumns::ProfileId)
+                        .to_tbl(Profiles::Table)
+                        .to_col(Profiles::Id)
+                        .on_delete(ForeignKeyAction::Cascade)
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_index(
+                    Index::create()
+                        .name(index)
+                        .table(table)
+                        .col(ProfileIdColumns::ProfileId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // a atividade continua ligada à conta pelo user_id
+        for (table, _, foreign_key, index) in activity_tables() {
+            manager
+                .drop_index(Index::drop().name(index).to_owned())
+                .await?;
+            manager
+                .drop_foreign_key(
+                    ForeignKey::drop()
+                        .name(foreign_key)
+                        .table(table.clone())
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(ProfileIdColumns::ProfileId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .drop_index(Index::drop().name("idx_profiles_user_default").to_owned())
+            .await?;
+        manager
+            .drop_index(Index::drop().name("idx_profiles_user_name").to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-profiles-user_id")
+                    .table(Profiles::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Profiles::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Profiles {
+    Table,
+    Id,
+    UserId,
+    Name,
+    AvatarUrl,
+    MaturityLevel,
+    IsKids,
+    PinHash,
+    IsDefault,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum ProfileIdColumns {
+    ProfileId,
+}
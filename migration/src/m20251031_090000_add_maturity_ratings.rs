@@ -0,0 +1,82 @@
+use crate::m20250828_140352_create_streaming_schema::{Series, Videos};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // CLASSIFICACAO INDICATIVA: VIDEOS E SERIES
+        // sem classificação o título só aparece para perfis sem restrição;
+        // episódios sem classificação própria herdam a da série
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Videos::Table)
+                    .add_column(
+                        ColumnDef::new(RatingColumns::MaturityRating)
+                            .string_len(2)
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(RatingColumns::ContentDescriptors)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Series::Table)
+                    .add_column(
+                        ColumnDef::new(RatingColumns::MaturityRating)
+                            .string_len(2)
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(RatingColumns::ContentDescriptors)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Series::Table)
+                    .drop_column(RatingColumns::ContentDescriptors)
+                    .drop_column(RatingColumns::MaturityRating)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Videos::Table)
+                    .drop_column(RatingColumns::ContentDescriptors)
+                    .drop_column(RatingColumns::MaturityRating)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum RatingColumns {
+    MaturityRating,
+    ContentDescriptors,
+}
@@ -0,0 +1,172 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // EVENTS: outbox gravado na mesma transação da mudança
+        manager
+            .create_table(
+                Table::create()
+                    .table(Events::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Events::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Events::EventType).string_len(64).not_null())
+                    .col(
+                        ColumnDef::new(Events::AggregateType)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Events::AggregateId)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Events::Payload).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(Events::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(Events::LastError).text().null())
+                    .col(ColumnDef::new(Events::AvailableAt).date_time().not_null())
+                    .col(ColumnDef::new(Events::PublishedAt).date_time().null())
+                    .col(ColumnDef::new(Events::CreatedAt).date_time().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // relay: busca os pendentes por available_at
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_events_published_at_available_at")
+                    .table(Events::Table)
+                    .col(Events::PublishedAt)
+                    .col(Events::AvailableAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // DEAD_LETTER_EVENTS: eventos que esgotaram as tentativas
+        manager
+            .create_table(
+                Table::create()
+                    .table(DeadLetterEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::EventId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::EventType)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::AggregateType)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::AggregateId)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::Attempts)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(DeadLetterEvents::LastError).text().null())
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetterEvents::FailedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dead_letter_events_event_id")
+                    .table(DeadLetterEvents::Table)
+                    .col(DeadLetterEvents::EventId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DeadLetterEvents::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Events::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Events {
+    Table,
+    Id,
+    EventType,
+    AggregateType,
+    AggregateId,
+    Payload,
+    Attempts,
+    LastError,
+    AvailableAt,
+    PublishedAt,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+pub enum DeadLetterEvents {
+    Table,
+    Id,
+    EventId,
+    EventType,
+    AggregateType,
+    AggregateId,
+    Payload,
+    Attempts,
+    LastError,
+    CreatedAt,
+    FailedAt,
+}
@@ -0,0 +1,87 @@
+use crate::m20250828_140352_create_streaming_schema::Users;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // AUTH_SESSIONS: um login por dispositivo, referenciado pelos tokens
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthSessions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthSessions::Id)
+                            .string_len(36)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuthSessions::UserId).string().not_null())
+                    .col(
+                        ColumnDef::new(AuthSessions::ExpiresAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthSessions::RevokedAt).date_time().null())
+                    .col(
+                        ColumnDef::new(AuthSessions::LastUsedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AuthSessions::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-auth_sessions-user_id")
+                    .from_tbl(AuthSessions::Table)
+                    .from_col(AuthSessions::UserId)
+                    .to_tbl(Users::Table)
+                    .to_col(Users::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_auth_sessions_user")
+                    .table(AuthSessions::Table)
+                    .col(AuthSessions::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuthSessions::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum AuthSessions {
+    Table,
+    Id,
+    UserId,
+    ExpiresAt,
+    RevokedAt,
+    LastUsedAt,
+    CreatedAt,
+}
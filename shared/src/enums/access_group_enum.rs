@@ -28,3 +28,61 @@ impl From<AccessGroupEnum> for i32 {
         group as i32
     }
 }
+
+impl AccessGroupEnum {
+    /// Maior altura de rendition liberada para o grupo; `None` libera todas.
+    pub fn max_playback_height(&self) -> Option<i32> {
+        match self {
+            AccessGroupEnum::VIEWER => Some(720),
+            AccessGroupEnum::PREMIUM | AccessGroupEnum::ADMIN | AccessGroupEnum::SUPER_ADMIN => {
+                None
+            }
+        }
+    }
+
+    /// Limite de altura para quem pertence a `groups`, valendo o grupo mais permissivo.
+    pub fn playback_height_limit(groups: &[AccessGroupEnum]) -> Option<i32> {
+        if groups.is_empty() {
+            return AccessGroupEnum::VIEWER.max_playback_height();
+        }
+
+        groups
+            .iter()
+            .map(AccessGroupEnum::max_playback_height)
+            .try_fold(0, |max, limit| limit.map(|limit| max.max(limit)))
+    }
+
+    /// Quantos perfis a conta do grupo pode ter.
+    pub fn max_profiles(&self) -> u64 {
+        match self {
+            AccessGroupEnum::VIEWER => 2,
+            AccessGroupEnum::PREMIUM | AccessGroupEnum::ADMIN | AccessGroupEnum::SUPER_ADMIN => 5,
+        }
+    }
+
+    /// Limite de perfis para quem pertence a `groups`, valendo o grupo mais permissivo.
+    pub fn profile_limit(groups: &[AccessGroupEnum]) -> u64 {
+        groups
+            .iter()
+            .map(AccessGroupEnum::max_profiles)
+            .max()
+            .unwrap_or_else(|| AccessGroupEnum::VIEWER.max_profiles())
+    }
+
+    /// Quantas telas da conta do grupo podem reproduzir ao mesmo tempo.
+    pub fn max_streams(&self) -> usize {
+        match self {
+            AccessGroupEnum::VIEWER => 1,
+            AccessGroupEnum::PREMIUM | AccessGroupEnum::ADMIN | AccessGroupEnum::SUPER_ADMIN => 4,
+        }
+    }
+
+    /// Limite de telas para quem pertence a `groups`, valendo o grupo mais permissivo.
+    pub fn stream_limit(groups: &[AccessGroupEnum]) -> usize {
+        groups
+            .iter()
+            .map(AccessGroupEnum::max_streams)
+            .max()
+            .unwrap_or_else(|| AccessGroupEnum::VIEWER.max_streams())
+    }
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Itens do catálogo que têm janela de disponibilidade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum AvailabilityOwnerEnum {
+    VIDEO,
+    SERIE,
+}
+
+impl AvailabilityOwnerEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AvailabilityOwnerEnum::VIDEO => "VIDEO",
+            AvailabilityOwnerEnum::SERIE => "SERIE",
+        }
+    }
+}
+
+impl TryFrom<&str> for AvailabilityOwnerEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "VIDEO" => Ok(AvailabilityOwnerEnum::VIDEO),
+            "SERIE" => Ok(AvailabilityOwnerEnum::SERIE),
+            _ => Err(format!("Tipo '{}' inválido. Use video ou serie", value)),
+        }
+    }
+}
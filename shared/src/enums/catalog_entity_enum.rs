@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Entidades do catálogo, na ordem em que são importadas: cada uma só
+/// referencia as anteriores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum CatalogEntityEnum {
+    CATEGORY,
+    ACTOR,
+    DIRECTOR,
+    SERIE,
+    VIDEO,
+}
+
+impl CatalogEntityEnum {
+    pub const ALL: [CatalogEntityEnum; 5] = [
+        CatalogEntityEnum::CATEGORY,
+        CatalogEntityEnum::ACTOR,
+        CatalogEntityEnum::DIRECTOR,
+        CatalogEntityEnum::SERIE,
+        CatalogEntityEnum::VIDEO,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CatalogEntityEnum::CATEGORY => "CATEGORY",
+            CatalogEntityEnum::ACTOR => "ACTOR",
+            CatalogEntityEnum::DIRECTOR => "DIRECTOR",
+            CatalogEntityEnum::SERIE => "SERIE",
+            CatalogEntityEnum::VIDEO => "VIDEO",
+        }
+    }
+}
+
+impl TryFrom<&str> for CatalogEntityEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "CATEGORY" => Ok(CatalogEntityEnum::CATEGORY),
+            "ACTOR" => Ok(CatalogEntityEnum::ACTOR),
+            "DIRECTOR" => Ok(CatalogEntityEnum::DIRECTOR),
+            "SERIE" => Ok(CatalogEntityEnum::SERIE),
+            "VIDEO" => Ok(CatalogEntityEnum::VIDEO),
+            _ => Err(format!(
+                "Entidade '{}' inválida. Use category, actor, director, serie ou video",
+                value
+            )),
+        }
+    }
+}
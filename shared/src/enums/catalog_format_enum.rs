@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// Formato dos arquivos de importação e exportação do catálogo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum CatalogFormatEnum {
+    /// Documento único com todas as entidades.
+    #[default]
+    JSON,
+    /// Um arquivo por entidade.
+    CSV,
+}
+
+impl CatalogFormatEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CatalogFormatEnum::JSON => "JSON",
+            CatalogFormatEnum::CSV => "CSV",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            CatalogFormatEnum::JSON => "application/json",
+            CatalogFormatEnum::CSV => "text/csv; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CatalogFormatEnum::JSON => "json",
+            CatalogFormatEnum::CSV => "csv",
+        }
+    }
+
+    /// Deduz o formato pela extensão do arquivo.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = path.rsplit_once('.')?.1;
+        Self::try_from(extension).ok()
+    }
+}
+
+impl TryFrom<&str> for CatalogFormatEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "JSON" => Ok(CatalogFormatEnum::JSON),
+            "CSV" => Ok(CatalogFormatEnum::CSV),
+            _ => Err(format!("Formato '{}' inválido. Use json ou csv", value)),
+        }
+    }
+}
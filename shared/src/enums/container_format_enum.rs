@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum ContainerFormatEnum {
+    MP4,
+    MATROSKA,
+    WEBM,
+}
+
+impl ContainerFormatEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContainerFormatEnum::MP4 => "MP4",
+            ContainerFormatEnum::MATROSKA => "MATROSKA",
+            ContainerFormatEnum::WEBM => "WEBM",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ContainerFormatEnum::MP4 => "video/mp4",
+            ContainerFormatEnum::MATROSKA => "video/x-matroska",
+            ContainerFormatEnum::WEBM => "video/webm",
+        }
+    }
+}
+
+impl From<&str> for ContainerFormatEnum {
+    fn from(value: &str) -> Self {
+        match value {
+            "MATROSKA" => ContainerFormatEnum::MATROSKA,
+            "WEBM" => ContainerFormatEnum::WEBM,
+            _ => ContainerFormatEnum::MP4,
+        }
+    }
+}
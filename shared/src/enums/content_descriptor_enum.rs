@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+/// Descritores de conteúdo da classificação indicativa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum ContentDescriptorEnum {
+    VIOLENCE,
+    EXTREME_VIOLENCE,
+    SEXUAL_CONTENT,
+    NUDITY,
+    SEX,
+    EXPLICIT_SEX,
+    DRUGS,
+    LEGAL_DRUGS,
+    ILLEGAL_DRUGS,
+    INAPPROPRIATE_LANGUAGE,
+    CRIMINAL_ACTS,
+    FEAR,
+}
+
+impl ContentDescriptorEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentDescriptorEnum::VIOLENCE => "VIOLENCE",
+            ContentDescriptorEnum::EXTREME_VIOLENCE => "EXTREME_VIOLENCE",
+            ContentDescriptorEnum::SEXUAL_CONTENT => "SEXUAL_CONTENT",
+            ContentDescriptorEnum::NUDITY => "NUDITY",
+            ContentDescriptorEnum::SEX => "SEX",
+            ContentDescriptorEnum::EXPLICIT_SEX => "EXPLICIT_SEX",
+            ContentDescriptorEnum::DRUGS => "DRUGS",
+            ContentDescriptorEnum::LEGAL_DRUGS => "LEGAL_DRUGS",
+            ContentDescriptorEnum::ILLEGAL_DRUGS => "ILLEGAL_DRUGS",
+            ContentDescriptorEnum::INAPPROPRIATE_LANGUAGE => "INAPPROPRIATE_LANGUAGE",
+            ContentDescriptorEnum::CRIMINAL_ACTS => "CRIMINAL_ACTS",
+            ContentDescriptorEnum::FEAR => "FEAR",
+        }
+    }
+}
+
+impl TryFrom<&str> for ContentDescriptorEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_uppercase().as_str() {
+            "VIOLENCE" => Ok(ContentDescriptorEnum::VIOLENCE),
+            "EXTREME_VIOLENCE" => Ok(ContentDescriptorEnum::EXTREME_VIOLENCE),
+            "SEXUAL_CONTENT" => Ok(ContentDescriptorEnum::SEXUAL_CONTENT),
+            "NUDITY" => Ok(ContentDescriptorEnum::NUDITY),
+            "SEX" => Ok(ContentDescriptorEnum::SEX),
+            "EXPLICIT_SEX" => Ok(ContentDescriptorEnum::EXPLICIT_SEX),
+            "DRUGS" => Ok(ContentDescriptorEnum::DRUGS),
+            "LEGAL_DRUGS" => Ok(ContentDescriptorEnum::LEGAL_DRUGS),
+            "ILLEGAL_DRUGS" => Ok(ContentDescriptorEnum::ILLEGAL_DRUGS),
+            "INAPPROPRIATE_LANGUAGE" => Ok(ContentDescriptorEnum::INAPPROPRIATE_LANGUAGE),
+            "CRIMINAL_ACTS" => Ok(ContentDescriptorEnum::CRIMINAL_ACTS),
+            "FEAR" => Ok(ContentDescriptorEnum::FEAR),
+            _ => Err(format!("Descritor de conteúdo '{}' inválido", value)),
+        }
+    }
+}
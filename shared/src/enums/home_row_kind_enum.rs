@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// Origem dos itens de uma linha da home do espectador.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum HomeRowKindEnum {
+    /// Séries com `is_featured`.
+    FEATURED_SERIES,
+    CONTINUE_WATCHING,
+    /// Favoritos do espectador.
+    MY_LIST,
+    TRENDING,
+    NEW_RELEASES,
+    /// Filmes e séries de uma categoria.
+    CATEGORY,
+    RECOMMENDATIONS,
+}
+
+impl HomeRowKindEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HomeRowKindEnum::FEATURED_SERIES => "FEATURED_SERIES",
+            HomeRowKindEnum::CONTINUE_WATCHING => "CONTINUE_WATCHING",
+            HomeRowKindEnum::MY_LIST => "MY_LIST",
+            HomeRowKindEnum::TRENDING => "TRENDING",
+            HomeRowKindEnum::NEW_RELEASES => "NEW_RELEASES",
+            HomeRowKindEnum::CATEGORY => "CATEGORY",
+            HomeRowKindEnum::RECOMMENDATIONS => "RECOMMENDATIONS",
+        }
+    }
+
+    /// Linhas que dependem do espectador e por isso têm cache por usuário.
+    pub fn is_personal(&self) -> bool {
+        matches!(
+            self,
+            HomeRowKindEnum::CONTINUE_WATCHING
+                | HomeRowKindEnum::MY_LIST
+                | HomeRowKindEnum::RECOMMENDATIONS
+        )
+    }
+}
+
+impl TryFrom<&str> for HomeRowKindEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "FEATURED_SERIES" => Ok(HomeRowKindEnum::FEATURED_SERIES),
+            "CONTINUE_WATCHING" => Ok(HomeRowKindEnum::CONTINUE_WATCHING),
+            "MY_LIST" => Ok(HomeRowKindEnum::MY_LIST),
+            "TRENDING" => Ok(HomeRowKindEnum::TRENDING),
+            "NEW_RELEASES" => Ok(HomeRowKindEnum::NEW_RELEASES),
+            "CATEGORY" => Ok(HomeRowKindEnum::CATEGORY),
+            "RECOMMENDATIONS" => Ok(HomeRowKindEnum::RECOMMENDATIONS),
+            _ => Err(format!(
+                "Tipo de linha '{}' inválido. Use featured_series, continue_watching, my_list, \
+                 trending, new_releases, category ou recommendations",
+                value
+            )),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum ImageFormatEnum {
+    JPEG,
+    PNG,
+    WEBP,
+}
+
+impl ImageFormatEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageFormatEnum::JPEG => "JPEG",
+            ImageFormatEnum::PNG => "PNG",
+            ImageFormatEnum::WEBP => "WEBP",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormatEnum::JPEG => "image/jpeg",
+            ImageFormatEnum::PNG => "image/png",
+            ImageFormatEnum::WEBP => "image/webp",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormatEnum::JPEG => "jpg",
+            ImageFormatEnum::PNG => "png",
+            ImageFormatEnum::WEBP => "webp",
+        }
+    }
+}
+
+impl From<&str> for ImageFormatEnum {
+    fn from(value: &str) -> Self {
+        match value {
+            "PNG" => ImageFormatEnum::PNG,
+            "WEBP" => ImageFormatEnum::WEBP,
+            _ => ImageFormatEnum::JPEG,
+        }
+    }
+}
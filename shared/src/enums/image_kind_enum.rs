@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum ImageKindEnum {
+    THUMBNAIL,
+    POSTER,
+    PHOTO,
+}
+
+impl ImageKindEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageKindEnum::THUMBNAIL => "THUMBNAIL",
+            ImageKindEnum::POSTER => "POSTER",
+            ImageKindEnum::PHOTO => "PHOTO",
+        }
+    }
+}
+
+impl TryFrom<&str> for ImageKindEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "THUMBNAIL" => Ok(ImageKindEnum::THUMBNAIL),
+            "POSTER" => Ok(ImageKindEnum::POSTER),
+            "PHOTO" => Ok(ImageKindEnum::PHOTO),
+            _ => Err(format!("Tipo de imagem '{}' inválido", value)),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::enums::image_kind_enum::ImageKindEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum ImageOwnerEnum {
+    VIDEO,
+    SERIE,
+    ACTOR,
+    DIRECTOR,
+}
+
+impl ImageOwnerEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageOwnerEnum::VIDEO => "VIDEO",
+            ImageOwnerEnum::SERIE => "SERIE",
+            ImageOwnerEnum::ACTOR => "ACTOR",
+            ImageOwnerEnum::DIRECTOR => "DIRECTOR",
+        }
+    }
+
+    /// Vídeos e séries têm thumbnail e poster; atores e diretores, só foto.
+    pub fn accepts(&self, kind: ImageKindEnum) -> bool {
+        match self {
+            ImageOwnerEnum::VIDEO | ImageOwnerEnum::SERIE => {
+                matches!(kind, ImageKindEnum::THUMBNAIL | ImageKindEnum::POSTER)
+            }
+            ImageOwnerEnum::ACTOR | ImageOwnerEnum::DIRECTOR => kind == ImageKindEnum::PHOTO,
+        }
+    }
+}
+
+impl TryFrom<&str> for ImageOwnerEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "VIDEO" => Ok(ImageOwnerEnum::VIDEO),
+            "SERIE" => Ok(ImageOwnerEnum::SERIE),
+            "ACTOR" => Ok(ImageOwnerEnum::ACTOR),
+            "DIRECTOR" => Ok(ImageOwnerEnum::DIRECTOR),
+            _ => Err(format!("Dono de imagem '{}' inválido", value)),
+        }
+    }
+}
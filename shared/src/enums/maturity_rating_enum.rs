@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+/// Classificação indicativa (ClassInd), da livre para todos até 18 anos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum MaturityRatingEnum {
+    L,
+    A10,
+    A12,
+    A14,
+    A16,
+    A18,
+}
+
+impl MaturityRatingEnum {
+    /// Classificação mais alta que um perfil infantil pode ter.
+    pub const KIDS_MAX: MaturityRatingEnum = MaturityRatingEnum::A12;
+
+    pub const ALL: [MaturityRatingEnum; 6] = [
+        MaturityRatingEnum::L,
+        MaturityRatingEnum::A10,
+        MaturityRatingEnum::A12,
+        MaturityRatingEnum::A14,
+        MaturityRatingEnum::A16,
+        MaturityRatingEnum::A18,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MaturityRatingEnum::L => "L",
+            MaturityRatingEnum::A10 => "10",
+            MaturityRatingEnum::A12 => "12",
+            MaturityRatingEnum::A14 => "14",
+            MaturityRatingEnum::A16 => "16",
+            MaturityRatingEnum::A18 => "18",
+        }
+    }
+
+    /// Classificações liberadas para quem vai até `self`.
+    pub fn up_to(self) -> Vec<MaturityRatingEnum> {
+        MaturityRatingEnum::ALL
+            .into_iter()
+            .filter(|rating| *rating <= self)
+            .collect()
+    }
+}
+
+impl TryFrom<&str> for MaturityRatingEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_uppercase().trim_start_matches('A') {
+            "L" => Ok(MaturityRatingEnum::L),
+            "10" => Ok(MaturityRatingEnum::A10),
+            "12" => Ok(MaturityRatingEnum::A12),
+            "14" => Ok(MaturityRatingEnum::A14),
+            "16" => Ok(MaturityRatingEnum::A16),
+            "18" => Ok(MaturityRatingEnum::A18),
+            _ => Err(format!(
+                "Classificação '{}' inválida. Use L, 10, 12, 14, 16 ou 18",
+                value
+            )),
+        }
+    }
+}
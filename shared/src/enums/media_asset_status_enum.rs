@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum MediaAssetStatusEnum {
+    AVAILABLE,
+    CORRUPTED,
+    MISSING,
+}
+
+impl MediaAssetStatusEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediaAssetStatusEnum::AVAILABLE => "AVAILABLE",
+            MediaAssetStatusEnum::CORRUPTED => "CORRUPTED",
+            MediaAssetStatusEnum::MISSING => "MISSING",
+        }
+    }
+}
+
+impl From<&str> for MediaAssetStatusEnum {
+    fn from(value: &str) -> Self {
+        match value {
+            "CORRUPTED" => MediaAssetStatusEnum::CORRUPTED,
+            "MISSING" => MediaAssetStatusEnum::MISSING,
+            _ => MediaAssetStatusEnum::AVAILABLE,
+        }
+    }
+}
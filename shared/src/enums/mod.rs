@@ -1 +1,23 @@
 pub mod access_group_enum;
+pub mod availability_owner_enum;
+pub mod catalog_entity_enum;
+pub mod catalog_format_enum;
+pub mod container_format_enum;
+pub mod content_descriptor_enum;
+pub mod home_row_kind_enum;
+pub mod image_format_enum;
+pub mod image_kind_enum;
+pub mod image_owner_enum;
+pub mod maturity_rating_enum;
+pub mod media_asset_status_enum;
+pub mod recommendation_reason_enum;
+pub mod release_state_enum;
+pub mod serie_episodes_action_enum;
+pub mod suggestion_kind_enum;
+pub mod transcode_job_status_enum;
+pub mod trash_item_type_enum;
+pub mod trending_kind_enum;
+pub mod trending_window_enum;
+pub mod upload_session_status_enum;
+pub mod video_asset_kind_enum;
+pub mod video_type_enum;
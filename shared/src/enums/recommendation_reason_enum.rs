@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// Por que um vídeo entrou nas recomendações do espectador.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum RecommendationReasonEnum {
+    BECAUSE_YOU_WATCHED,
+    POPULAR,
+}
+
+impl RecommendationReasonEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecommendationReasonEnum::BECAUSE_YOU_WATCHED => "BECAUSE_YOU_WATCHED",
+            RecommendationReasonEnum::POPULAR => "POPULAR",
+        }
+    }
+}
+
+impl TryFrom<&str> for RecommendationReasonEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "BECAUSE_YOU_WATCHED" => Ok(RecommendationReasonEnum::BECAUSE_YOU_WATCHED),
+            "POPULAR" => Ok(RecommendationReasonEnum::POPULAR),
+            _ => Err(format!(
+                "Motivo '{}' inválido. Use because_you_watched ou popular",
+                value
+            )),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// Estado de lançamento gravado pelo agendador ao cruzar os limites da janela.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum ReleaseStateEnum {
+    /// `available_from` ainda não chegou.
+    SCHEDULED,
+    #[default]
+    LIVE,
+    /// `available_until` já passou.
+    EXPIRED,
+}
+
+impl ReleaseStateEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseStateEnum::SCHEDULED => "SCHEDULED",
+            ReleaseStateEnum::LIVE => "LIVE",
+            ReleaseStateEnum::EXPIRED => "EXPIRED",
+        }
+    }
+
+    /// Estado da janela `[from, until)` no instante `at`; limites ausentes são abertos.
+    pub fn at(
+        from: Option<NaiveDateTime>,
+        until: Option<NaiveDateTime>,
+        at: NaiveDateTime,
+    ) -> Self {
+        match (from, until) {
+            (Some(from), _) if at < from => ReleaseStateEnum::SCHEDULED,
+            (_, Some(until)) if at >= until => ReleaseStateEnum::EXPIRED,
+            _ => ReleaseStateEnum::LIVE,
+        }
+    }
+}
+
+impl TryFrom<&str> for ReleaseStateEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "SCHEDULED" => Ok(ReleaseStateEnum::SCHEDULED),
+            "LIVE" => Ok(ReleaseStateEnum::LIVE),
+            "EXPIRED" => Ok(ReleaseStateEnum::EXPIRED),
+            _ => Err(format!("Estado de lançamento '{}' inválido", value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, NaiveDate};
+
+    #[test]
+    fn window_includes_its_start_and_excludes_its_end() {
+        let from = NaiveDate::from_ymd_opt(2025, 6, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let until = from + Duration::days(30);
+        let second = Duration::seconds(1);
+        let state = |at| ReleaseStateEnum::at(Some(from), Some(until), at);
+
+        assert_eq!(state(from - second), ReleaseStateEnum::SCHEDULED);
+        assert_eq!(state(from), ReleaseStateEnum::LIVE);
+        assert_eq!(state(until - second), ReleaseStateEnum::LIVE);
+        assert_eq!(state(until), ReleaseStateEnum::EXPIRED);
+    }
+
+    #[test]
+    fn missing_limits_are_open() {
+        let at = NaiveDate::from_ymd_opt(2025, 6, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let day = Duration::days(1);
+
+        assert_eq!(ReleaseStateEnum::at(None, None, at), ReleaseStateEnum::LIVE);
+        assert_eq!(
+            ReleaseStateEnum::at(Some(at - day), None, at + day * 3650),
+            ReleaseStateEnum::LIVE
+        );
+        assert_eq!(
+            ReleaseStateEnum::at(None, Some(at + day), at - day * 3650),
+            ReleaseStateEnum::LIVE
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// O que acontece com os episódios quando a série vai para a lixeira.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum SerieEpisodesActionEnum {
+    /// Os episódios vão para a lixeira junto com a série.
+    #[default]
+    TRASH,
+    /// Os episódios viram vídeos avulsos e continuam no catálogo.
+    DETACH,
+}
+
+impl SerieEpisodesActionEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SerieEpisodesActionEnum::TRASH => "TRASH",
+            SerieEpisodesActionEnum::DETACH => "DETACH",
+        }
+    }
+}
+
+impl TryFrom<&str> for SerieEpisodesActionEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "TRASH" => Ok(SerieEpisodesActionEnum::TRASH),
+            "DETACH" => Ok(SerieEpisodesActionEnum::DETACH),
+            _ => Err(format!(
+                "Ação '{}' inválida para os episódios. Use trash ou detach",
+                value
+            )),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// Origem de uma sugestão da busca enquanto o usuário digita.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum SuggestionKindEnum {
+    VIDEO,
+    SERIE,
+    ACTOR,
+    DIRECTOR,
+}
+
+impl SuggestionKindEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SuggestionKindEnum::VIDEO => "VIDEO",
+            SuggestionKindEnum::SERIE => "SERIE",
+            SuggestionKindEnum::ACTOR => "ACTOR",
+            SuggestionKindEnum::DIRECTOR => "DIRECTOR",
+        }
+    }
+}
+
+impl TryFrom<&str> for SuggestionKindEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "VIDEO" => Ok(SuggestionKindEnum::VIDEO),
+            "SERIE" => Ok(SuggestionKindEnum::SERIE),
+            "ACTOR" => Ok(SuggestionKindEnum::ACTOR),
+            "DIRECTOR" => Ok(SuggestionKindEnum::DIRECTOR),
+            _ => Err(format!(
+                "Tipo '{}' inválido. Use video, serie, actor ou director",
+                value
+            )),
+        }
+    }
+}
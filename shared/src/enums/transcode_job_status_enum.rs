@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum TranscodeJobStatusEnum {
+    PENDING,
+    RUNNING,
+    SUCCEEDED,
+    FAILED,
+}
+
+impl TranscodeJobStatusEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TranscodeJobStatusEnum::PENDING => "PENDING",
+            TranscodeJobStatusEnum::RUNNING => "RUNNING",
+            TranscodeJobStatusEnum::SUCCEEDED => "SUCCEEDED",
+            TranscodeJobStatusEnum::FAILED => "FAILED",
+        }
+    }
+}
+
+impl From<&str> for TranscodeJobStatusEnum {
+    fn from(value: &str) -> Self {
+        match value {
+            "RUNNING" => TranscodeJobStatusEnum::RUNNING,
+            "SUCCEEDED" => TranscodeJobStatusEnum::SUCCEEDED,
+            "FAILED" => TranscodeJobStatusEnum::FAILED,
+            _ => TranscodeJobStatusEnum::PENDING,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum TrashItemTypeEnum {
+    VIDEO,
+    SERIE,
+}
+
+impl TrashItemTypeEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrashItemTypeEnum::VIDEO => "VIDEO",
+            TrashItemTypeEnum::SERIE => "SERIE",
+        }
+    }
+}
+
+impl TryFrom<&str> for TrashItemTypeEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "VIDEO" => Ok(TrashItemTypeEnum::VIDEO),
+            "SERIE" => Ok(TrashItemTypeEnum::SERIE),
+            _ => Err(format!("Tipo '{}' inválido. Use video ou serie", value)),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Item de uma lista de em alta: um vídeo (filme ou episódio) ou uma série.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum TrendingKindEnum {
+    VIDEO,
+    SERIE,
+}
+
+impl TrendingKindEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrendingKindEnum::VIDEO => "VIDEO",
+            TrendingKindEnum::SERIE => "SERIE",
+        }
+    }
+}
+
+impl TryFrom<&str> for TrendingKindEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "VIDEO" => Ok(TrendingKindEnum::VIDEO),
+            "SERIE" => Ok(TrendingKindEnum::SERIE),
+            _ => Err(format!("Tipo '{}' inválido. Use video ou serie", value)),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// Janela móvel das listas de em alta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum TrendingWindowEnum {
+    DAY,
+    WEEK,
+    MONTH,
+}
+
+impl TrendingWindowEnum {
+    pub const ALL: [TrendingWindowEnum; 3] = [
+        TrendingWindowEnum::DAY,
+        TrendingWindowEnum::WEEK,
+        TrendingWindowEnum::MONTH,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrendingWindowEnum::DAY => "DAY",
+            TrendingWindowEnum::WEEK => "WEEK",
+            TrendingWindowEnum::MONTH => "MONTH",
+        }
+    }
+
+    /// Tamanho da janela: 24 horas, 7 dias ou 30 dias.
+    pub fn duration_secs(&self) -> i64 {
+        match self {
+            TrendingWindowEnum::DAY => 24 * 60 * 60,
+            TrendingWindowEnum::WEEK => 7 * 24 * 60 * 60,
+            TrendingWindowEnum::MONTH => 30 * 24 * 60 * 60,
+        }
+    }
+
+    /// Depois desse tempo uma visualização vale metade.
+    pub fn half_life_secs(&self) -> i64 {
+        match self {
+            TrendingWindowEnum::DAY => 6 * 60 * 60,
+            TrendingWindowEnum::WEEK => 2 * 24 * 60 * 60,
+            TrendingWindowEnum::MONTH => 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl TryFrom<&str> for TrendingWindowEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "DAY" | "24H" => Ok(TrendingWindowEnum::DAY),
+            "WEEK" | "7D" => Ok(TrendingWindowEnum::WEEK),
+            "MONTH" | "30D" => Ok(TrendingWindowEnum::MONTH),
+            _ => Err(format!("Janela '{}' inválida. Use 24h, 7d ou 30d", value)),
+        }
+    }
+}
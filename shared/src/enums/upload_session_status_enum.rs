@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum UploadSessionStatusEnum {
+    PENDING,
+    COMPLETED,
+    FAILED,
+}
+
+impl UploadSessionStatusEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UploadSessionStatusEnum::PENDING => "PENDING",
+            UploadSessionStatusEnum::COMPLETED => "COMPLETED",
+            UploadSessionStatusEnum::FAILED => "FAILED",
+        }
+    }
+}
+
+impl From<&str> for UploadSessionStatusEnum {
+    fn from(value: &str) -> Self {
+        match value {
+            "COMPLETED" => UploadSessionStatusEnum::COMPLETED,
+            "FAILED" => UploadSessionStatusEnum::FAILED,
+            _ => UploadSessionStatusEnum::PENDING,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum VideoAssetKindEnum {
+    SOURCE,
+    RENDITION,
+    TRAILER,
+    THUMBNAIL,
+    SUBTITLE,
+}
+
+impl VideoAssetKindEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VideoAssetKindEnum::SOURCE => "SOURCE",
+            VideoAssetKindEnum::RENDITION => "RENDITION",
+            VideoAssetKindEnum::TRAILER => "TRAILER",
+            VideoAssetKindEnum::THUMBNAIL => "THUMBNAIL",
+            VideoAssetKindEnum::SUBTITLE => "SUBTITLE",
+        }
+    }
+}
+
+impl TryFrom<&str> for VideoAssetKindEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "SOURCE" => Ok(VideoAssetKindEnum::SOURCE),
+            "RENDITION" => Ok(VideoAssetKindEnum::RENDITION),
+            "TRAILER" => Ok(VideoAssetKindEnum::TRAILER),
+            "THUMBNAIL" => Ok(VideoAssetKindEnum::THUMBNAIL),
+            "SUBTITLE" => Ok(VideoAssetKindEnum::SUBTITLE),
+            _ => Err(format!("Tipo de asset '{}' inválido", value)),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Filme avulso ou episódio de série, conforme `series_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub enum VideoTypeEnum {
+    MOVIE,
+    EPISODE,
+}
+
+impl VideoTypeEnum {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VideoTypeEnum::MOVIE => "MOVIE",
+            VideoTypeEnum::EPISODE => "EPISODE",
+        }
+    }
+}
+
+impl TryFrom<&str> for VideoTypeEnum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_uppercase().as_str() {
+            "MOVIE" => Ok(VideoTypeEnum::MOVIE),
+            "EPISODE" => Ok(VideoTypeEnum::EPISODE),
+            _ => Err(format!("Tipo '{}' inválido. Use movie ou episode", value)),
+        }
+    }
+}
@@ -4,36 +4,112 @@ use anyhow::Result;
 use sea_orm::Database;
 
 use crate::modules::auth::AuthService;
+use crate::modules::azure_storage::services::image_storage_service::ImageStorageService;
 use crate::modules::azure_storage::services::video_storage_service::VideoStorageService;
+use crate::modules::cache::redis_cache::RedisCache;
 use crate::modules::config::Config;
 use crate::modules::database::repositories::{
-    serie_repository::SerieRepository, users_repository::UsersRepository,
+    actors_repository::ActorsRepository, auth_sessions_repository::AuthSessionsRepository,
+    availability_repository::AvailabilityRepository, catalog_repository::CatalogRepository,
+    categories_repository::CategoriesRepository, directors_repository::DirectorsRepository,
+    events_repository::EventsRepository, home_rows_repository::HomeRowsRepository,
+    images_repository::ImagesRepository, maturity_ratings_repository::MaturityRatingsRepository,
+    media_assets_repository::MediaAssetsRepository, profiles_repository::ProfilesRepository,
+    recommendations_repository::RecommendationsRepository, search_repository::SearchRepository,
+    serie_repository::SerieRepository, transcode_jobs_repository::TranscodeJobsRepository,
+    trending_repository::TrendingRepository, upload_sessions_repository::UploadSessionsRepository,
+    users_repository::UsersRepository, video_assets_repository::VideoAssetsRepository,
     videos_repository::VideosRepository,
 };
+use crate::modules::search::index::{build_search_index, SearchIndex};
 
 #[derive(Clone)]
 pub struct AppState {
     pub auth_service: Arc<AuthService>,
     pub user_repo: Arc<UsersRepository>,
+    pub auth_session_repo: Arc<AuthSessionsRepository>,
     pub video_repo: Arc<VideosRepository>,
     pub serie_repo: Arc<SerieRepository>,
+    pub upload_session_repo: Arc<UploadSessionsRepository>,
+    pub media_asset_repo: Arc<MediaAssetsRepository>,
+    pub video_asset_repo: Arc<VideoAssetsRepository>,
+    pub transcode_job_repo: Arc<TranscodeJobsRepository>,
+    pub image_repo: Arc<ImagesRepository>,
+    pub category_repo: Arc<CategoriesRepository>,
+    pub actor_repo: Arc<ActorsRepository>,
+    pub director_repo: Arc<DirectorsRepository>,
+    pub catalog_repo: Arc<CatalogRepository>,
+    pub availability_repo: Arc<AvailabilityRepository>,
+    pub search_repo: Arc<SearchRepository>,
+    pub search_index: Arc<dyn SearchIndex>,
+    pub recommendation_repo: Arc<RecommendationsRepository>,
+    pub trending_repo: Arc<TrendingRepository>,
+    pub home_row_repo: Arc<HomeRowsRepository>,
+    pub profile_repo: Arc<ProfilesRepository>,
+    pub maturity_rating_repo: Arc<MaturityRatingsRepository>,
+    pub event_repo: Arc<EventsRepository>,
     pub video_storage_service: Arc<VideoStorageService>,
+    pub image_storage_service: Arc<ImageStorageService>,
+    pub cache: Arc<RedisCache>,
 }
 
 impl AppState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         auth_service: Arc<AuthService>,
         user_repo: Arc<UsersRepository>,
+        auth_session_repo: Arc<AuthSessionsRepository>,
         video_repo: Arc<VideosRepository>,
         serie_repo: Arc<SerieRepository>,
+        upload_session_repo: Arc<UploadSessionsRepository>,
+        media_asset_repo: Arc<MediaAssetsRepository>,
+        video_asset_repo: Arc<VideoAssetsRepository>,
+        transcode_job_repo: Arc<TranscodeJobsRepository>,
+        image_repo: Arc<ImagesRepository>,
+        category_repo: Arc<CategoriesRepository>,
+        actor_repo: Arc<ActorsRepository>,
+        director_repo: Arc<DirectorsRepository>,
+        catalog_repo: Arc<CatalogRepository>,
+        availability_repo: Arc<AvailabilityRepository>,
+        search_repo: Arc<SearchRepository>,
+        search_index: Arc<dyn SearchIndex>,
+        recommendation_repo: Arc<RecommendationsRepository>,
+        trending_repo: Arc<TrendingRepository>,
+        home_row_repo: Arc<HomeRowsRepository>,
+        profile_repo: Arc<ProfilesRepository>,
+        maturity_rating_repo: Arc<MaturityRatingsRepository>,
+        event_repo: Arc<EventsRepository>,
         video_storage_service: Arc<VideoStorageService>,
+        image_storage_service: Arc<ImageStorageService>,
+        cache: Arc<RedisCache>,
     ) -> Self {
         Self {
             auth_service,
             user_repo,
+            auth_session_repo,
             video_repo,
             serie_repo,
+            upload_session_repo,
+            media_asset_repo,
+            video_asset_repo,
+            transcode_job_repo,
+            image_repo,
+            category_repo,
+            actor_repo,
+            director_repo,
+            catalog_repo,
+            availability_repo,
+            search_repo,
+            search_index,
+            recommendation_repo,
+            trending_repo,
+            home_row_repo,
+            profile_repo,
+            maturity_rating_repo,
+            event_repo,
             video_storage_service,
+            image_storage_service,
+            cache,
         }
     }
 
@@ -41,8 +117,27 @@ impl AppState {
         let db_conn = Database::connect(&config.database_url).await?;
 
         let users_repo = Arc::new(UsersRepository::new(db_conn.clone()));
+        let auth_session_repo = Arc::new(AuthSessionsRepository::new(db_conn.clone()));
         let video_repo = Arc::new(VideosRepository::new(db_conn.clone()));
-        let serie_repo = Arc::new(SerieRepository::new(db_conn));
+        let serie_repo = Arc::new(SerieRepository::new(db_conn.clone()));
+        let upload_session_repo = Arc::new(UploadSessionsRepository::new(db_conn.clone()));
+        let media_asset_repo = Arc::new(MediaAssetsRepository::new(db_conn.clone()));
+        let video_asset_repo = Arc::new(VideoAssetsRepository::new(db_conn.clone()));
+        let transcode_job_repo = Arc::new(TranscodeJobsRepository::new(db_conn.clone()));
+        let image_repo = Arc::new(ImagesRepository::new(db_conn.clone()));
+        let category_repo = Arc::new(CategoriesRepository::new(db_conn.clone()));
+        let actor_repo = Arc::new(ActorsRepository::new(db_conn.clone()));
+        let director_repo = Arc::new(DirectorsRepository::new(db_conn.clone()));
+        let catalog_repo = Arc::new(CatalogRepository::new(db_conn.clone()));
+        let availability_repo = Arc::new(AvailabilityRepository::new(db_conn.clone()));
+        let search_repo = Arc::new(SearchRepository::new(db_conn.clone()));
+        let search_index = build_search_index(config, db_conn.clone())?;
+        let recommendation_repo = Arc::new(RecommendationsRepository::new(db_conn.clone()));
+        let trending_repo = Arc::new(TrendingRepository::new(db_conn.clone()));
+        let home_row_repo = Arc::new(HomeRowsRepository::new(db_conn.clone()));
+        let profile_repo = Arc::new(ProfilesRepository::new(db_conn.clone()));
+        let maturity_rating_repo = Arc::new(MaturityRatingsRepository::new(db_conn.clone()));
+        let event_repo = Arc::new(EventsRepository::new(db_conn));
 
         let auth_service = Arc::new(AuthService::new(
             config.jwt_access_secret.clone(),
@@ -52,13 +147,38 @@ impl AppState {
         ));
 
         let video_storage_service = Arc::new(VideoStorageService::new(config).await?);
+        let image_storage_service = Arc::new(ImageStorageService::new(config).await?);
+
+        // a conexão só é aberta no primeiro comando
+        let cache = Arc::new(RedisCache::new(&config.redis_url)?);
 
         Ok(Arc::new(Self::new(
             auth_service,
             users_repo,
+            auth_session_repo,
             video_repo,
             serie_repo,
+            upload_session_repo,
+            media_asset_repo,
+            video_asset_repo,
+            transcode_job_repo,
+            image_repo,
+            category_repo,
+            actor_repo,
+            director_repo,
+            catalog_repo,
+            availability_repo,
+            search_repo,
+            search_index,
+            recommendation_repo,
+            trending_repo,
+            home_row_repo,
+            profile_repo,
+            maturity_rating_repo,
+            event_repo,
             video_storage_service,
+            image_storage_service,
+            cache,
         )))
     }
 }
@@ -5,7 +5,7 @@ use crate::{
     modules::database::repositories::users_repository::{LoginRequest, UsersRepository},
 };
 use bcrypt::{hash, DEFAULT_COST};
-use chrono::{Duration, Utc};
+use chrono::{Duration, NaiveDateTime, Utc};
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +15,8 @@ pub struct TokenInfo {
     pub email: String,
     pub name: String,
     pub access_groups: Vec<i32>,
+    pub profile_id: Option<i32>,
+    pub session_id: Option<String>,
 }
 
 /// Estrutura do token JWT
@@ -26,6 +28,12 @@ pub struct Claims {
     pub email: String,
     pub name: String,
     pub access_groups: Vec<i32>,
+    /// Perfil escolhido; tokens antigos não trazem e valem pelo perfil principal.
+    #[serde(default)]
+    pub profile_id: Option<i32>,
+    /// Sessão de login emitida pelo servidor; identifica o dispositivo.
+    #[serde(default)]
+    pub sid: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,6 +41,10 @@ struct RefreshClaims {
     sub: String,
     iat: i64,
     exp: i64,
+    #[serde(default)]
+    profile_id: Option<i32>,
+    #[serde(default)]
+    sid: Option<String>,
 }
 
 /// Representa o usuário interno
@@ -43,6 +55,9 @@ pub struct User {
     pub name: String,
     pub password_hash: String,
     pub access_groups: Vec<AccessGroupEnum>,
+    pub profile_id: Option<i32>,
+    /// Sessão de login do token; `None` em tokens emitidos antes das sessões.
+    pub session_id: Option<String>,
 }
 
 /// Resposta do login
@@ -106,21 +121,20 @@ impl AuthService {
             .await
             .map_err(|e| format!("Erro ao criar usuário: {}", e))?;
 
+        let profile_id = self.default_profile_id(state, &user_model.id).await?;
+        let session_id = self.open_session(state, &user_model.id).await?;
+
         let user = User {
             id: user_model.id.clone(),
             email: user_model.email.clone(),
             name: user_model.name.clone(),
             password_hash: user_model.password_hash.clone(),
             access_groups,
+            profile_id,
+            session_id: Some(session_id),
         };
 
-        let access_token = self.generate_access_token(&user)?;
-        let refresh_token = self.generate_refresh_token(&user)?;
-
-        Ok(AuthResponse {
-            access_token,
-            refresh_token,
-        })
+        self.issue_tokens(&user)
     }
 
     /// Login e geração de tokens
@@ -147,22 +161,22 @@ impl AuthService {
             .map(|id| (id as i32).into())
             .collect();
 
+        // entra no perfil principal até o usuário escolher outro
+        let profile_id = self.default_profile_id(state, &user_model.id).await?;
+        let session_id = self.open_session(state, &user_model.id).await?;
+
         let user = User {
             id: user_model.id.clone(),
             email: user_model.email.clone(),
             name: user_model.name.clone(),
             password_hash: user_model.password_hash.clone(),
             access_groups,
+            profile_id,
+            session_id: Some(session_id),
         };
 
         // Gera tokens
-        let access_token = self.generate_access_token(&user)?;
-        let refresh_token = self.generate_refresh_token(&user)?;
-
-        Ok(AuthResponse {
-            access_token,
-            refresh_token,
-        })
+        self.issue_tokens(&user)
     }
 
     /// Validar access token
@@ -176,6 +190,8 @@ impl AuthService {
             email: token_data.claims.email,
             name: token_data.claims.name,
             access_groups: token_data.claims.access_groups,
+            profile_id: token_data.claims.profile_id,
+            session_id: token_data.claims.sid,
         })
     }
 
@@ -200,21 +216,58 @@ impl AuthService {
             })?;
 
         let user_id = token_data.claims.sub;
+
+        // o refresh só vale enquanto a sessão de login não for revogada
+        let session_id = token_data.claims.sid.ok_or("Sessão encerrada")?;
+        state
+            .auth_session_repo
+            .find_active(&user_id, &session_id)
+            .await
+            .map_err(|e| format!("Erro ao acessar o banco de dados: {}", e))?
+            .ok_or("Sessão encerrada")?;
+        state
+            .auth_session_repo
+            .renew(&session_id, self.refresh_expires_at())
+            .await
+            .map_err(|e| format!("Erro ao acessar o banco de dados: {}", e))?;
+
         let user_model = UsersRepository::find_by_id(&state.user_repo, &user_id)
             .await
             .map_err(|e| format!("Erro ao acessar o banco de dados: {}", e))?
             .ok_or("Usuário não encontrado")?;
 
+        // mantém o perfil escolhido enquanto ele existir
+        let selected = match token_data.claims.profile_id {
+            Some(profile_id) => state
+                .profile_repo
+                .find_owned(&user_id, profile_id)
+                .await
+                .map_err(|e| format!("Erro ao acessar o banco de dados: {}", e))?
+                .map(|profile| profile.id),
+            None => None,
+        };
+        let profile_id = match selected {
+            Some(profile_id) => Some(profile_id),
+            None => self.default_profile_id(state, &user_id).await?,
+        };
+
         let user = User {
             id: user_model.id,
             email: user_model.email,
             name: user_model.name,
             password_hash: user_model.password_hash,
             access_groups: user_model.access_groups,
+            profile_id,
+            session_id: Some(session_id),
         };
 
-        let access_token = self.generate_access_token(&user)?;
-        let refresh_token = self.generate_refresh_token(&user)?;
+        self.issue_tokens(&user)
+    }
+
+    /// Gera o par de tokens para o usuário, no perfil de `user.profile_id`
+    pub fn issue_tokens(&self, user: &User) -> Result<AuthResponse, String> {
+        let access_token = self.generate_access_token(user)?;
+        let refresh_token = self.generate_refresh_token(user)?;
 
         Ok(AuthResponse {
             access_token,
@@ -222,6 +275,38 @@ impl AuthService {
         })
     }
 
+    /// Por quanto tempo um access token emitido agora continua válido.
+    pub fn access_token_ttl_secs(&self) -> u64 {
+        self.access_expiry_hours * 3600
+    }
+
+    fn refresh_expires_at(&self) -> NaiveDateTime {
+        (Utc::now() + Duration::days(self.refresh_expiry_days as i64)).naive_utc()
+    }
+
+    /// Abre a sessão de login que os tokens vão carregar.
+    async fn open_session(&self, state: &AppState, user_id: &str) -> Result<String, String> {
+        state
+            .auth_session_repo
+            .create(user_id, self.refresh_expires_at())
+            .await
+            .map(|session| session.id)
+            .map_err(|e| format!("Erro ao criar a sessão: {}", e))
+    }
+
+    async fn default_profile_id(
+        &self,
+        state: &AppState,
+        user_id: &str,
+    ) -> Result<Option<i32>, String> {
+        state
+            .profile_repo
+            .find_default(user_id)
+            .await
+            .map(|profile| profile.map(|profile| profile.id))
+            .map_err(|e| format!("Erro ao acessar o banco de dados: {}", e))
+    }
+
     pub fn generate_access_token(&self, user: &User) -> Result<String, String> {
         let now = Utc::now();
         let exp = now + Duration::hours(self.access_expiry_hours as i64);
@@ -231,6 +316,8 @@ impl AuthService {
             email: user.email.clone(),
             name: user.name.clone(),
             access_groups: user.access_groups.iter().map(|g| *g as i32).collect(),
+            profile_id: user.profile_id,
+            sid: user.session_id.clone(),
             iat: now.timestamp(),
             exp: exp.timestamp(),
         };
@@ -249,6 +336,8 @@ impl AuthService {
             sub: user.id.clone(),
             iat: now.timestamp(),
             exp: exp.timestamp(),
+            profile_id: user.profile_id,
+            sid: user.session_id.clone(),
         };
 
         let key = EncodingKey::from_secret(self.refresh_secret.as_ref());
@@ -278,6 +367,8 @@ pub fn user_from_jwt(token: &str, secret: &str) -> Result<User, jsonwebtoken::er
                 name: claims.name,
                 password_hash: "".to_string(),
                 access_groups: claims.access_groups.into_iter().map(|i| i.into()).collect(),
+                profile_id: claims.profile_id,
+                session_id: claims.sid,
             })
         }
         Err(err) => {
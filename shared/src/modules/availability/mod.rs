@@ -0,0 +1,437 @@
+//! Janelas de disponibilidade de vídeos e séries: o que está no ar em cada
+//! instante e região, e os lançamentos gravados pelo agendador do media_worker.
+//!
+//! A janela regional, quando existe, substitui a global naquela região. Um
+//! episódio só está no ar quando a janela da série também está.
+
+pub mod region_extractor;
+
+use chrono::NaiveDateTime;
+use sea_orm::DbErr;
+use serde::Serialize;
+
+use crate::enums::availability_owner_enum::AvailabilityOwnerEnum;
+use crate::enums::release_state_enum::ReleaseStateEnum;
+use crate::modules::app_state::AppState;
+use crate::modules::database::repositories::availability_repository::{
+    AvailabilityRepository, ReleaseTransition,
+};
+use crate::modules::database::repositories::serie_repository::SerieRepository;
+use crate::modules::database::schema::videos::Model as VideoModel;
+
+/// Cabeçalho com a região do espectador (ISO 3166-1 alfa-2).
+pub const REGION_HEADER: &str = "x-region";
+
+/// Canal Redis dos avisos de lançamento.
+pub const NEW_RELEASE_CHANNEL: &str = "catalog:new-release";
+
+/// Chaves de cache com dados do catálogo visto pelo espectador.
+pub const CATALOG_CACHE_PATTERN: &str = "catalog:*";
+
+/// Aviso publicado quando um item entra no ar.
+#[derive(Debug, Clone, Serialize)]
+pub struct NewReleaseEvent {
+    #[serde(rename = "type")]
+    pub item_type: AvailabilityOwnerEnum,
+    pub id: i32,
+    pub title: String,
+    pub series_id: Option<i32>,
+    /// Região da janela que abriu; `None` para a janela global.
+    pub region: Option<String>,
+    pub released_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReleaseReport {
+    pub transitions: usize,
+    pub releases: usize,
+}
+
+/// Valida e normaliza o código da região (`br` → `BR`).
+pub fn normalize_region(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if value.len() != 2 || !value.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!(
+            "Região '{}' inválida. Use o código ISO de duas letras, como BR",
+            value
+        ));
+    }
+
+    Ok(value.to_ascii_uppercase())
+}
+
+/// Estado do item em `at`, usando a janela regional quando houver.
+pub async fn release_state_at(
+    state: &AppState,
+    owner: AvailabilityOwnerEnum,
+    owner_id: i32,
+    window: (Option<NaiveDateTime>, Option<NaiveDateTime>),
+    region: Option<&str>,
+    at: NaiveDateTime,
+) -> Result<ReleaseStateEnum, DbErr> {
+    window_state_at(
+        &state.availability_repo,
+        owner,
+        owner_id,
+        window,
+        region,
+        at,
+    )
+    .await
+}
+
+/// Indica se o vídeo pode ser assistido em `at` na região, considerando o
+/// `is_available` manual, a janela do vídeo e a da série.
+pub async fn is_video_live(
+    state: &AppState,
+    video: &VideoModel,
+    region: Option<&str>,
+    at: NaiveDateTime,
+) -> Result<bool, DbErr> {
+    video_live(
+        &state.availability_repo,
+        &state.serie_repo,
+        video,
+        region,
+        at,
+    )
+    .await
+}
+
+async fn window_state_at(
+    availability_repo: &AvailabilityRepository,
+    owner: AvailabilityOwnerEnum,
+    owner_id: i32,
+    (from, until): (Option<NaiveDateTime>, Option<NaiveDateTime>),
+    region: Option<&str>,
+    at: NaiveDateTime,
+) -> Result<ReleaseStateEnum, DbErr> {
+    let regional = match region {
+        Some(region) => {
+            availability_repo
+                .find_for_region(owner, owner_id, region)
+                .await?
+        }
+        None => None,
+    };
+
+    Ok(match regional {
+        Some(window) => ReleaseStateEnum::at(window.available_from, window.available_until, at),
+        None => ReleaseStateEnum::at(from, until, at),
+    })
+}
+
+async fn video_live(
+    availability_repo: &AvailabilityRepository,
+    serie_repo: &SerieRepository,
+    video: &VideoModel,
+    region: Option<&str>,
+    at: NaiveDateTime,
+) -> Result<bool, DbErr> {
+    if !video.is_available {
+        return Ok(false);
+    }
+
+    let video_state = window_state_at(
+        availability_repo,
+        AvailabilityOwnerEnum::VIDEO,
+        video.id,
+        (video.available_from, video.available_until),
+        region,
+        at,
+    )
+    .await?;
+    if video_state != ReleaseStateEnum::LIVE {
+        return Ok(false);
+    }
+
+    let Some(series_id) = video.series_id else {
+        return Ok(true);
+    };
+    let Some(serie) = serie_repo.find_by_id(series_id).await? else {
+        return Ok(false);
+    };
+
+    let serie_state = window_state_at(
+        availability_repo,
+        AvailabilityOwnerEnum::SERIE,
+        serie.id,
+        (serie.available_from, serie.available_until),
+        region,
+        at,
+    )
+    .await?;
+
+    Ok(serie_state == ReleaseStateEnum::LIVE)
+}
+
+/// Grava os estados que mudaram até `now`, limpa o cache do catálogo e
+/// publica um [`NewReleaseEvent`] para cada janela que abriu.
+///
+/// Falhas no Redis não desfazem a gravação: ficam no log e o cache expira
+/// sozinho.
+pub async fn apply_release_states(
+    state: &AppState,
+    now: NaiveDateTime,
+) -> Result<ReleaseReport, DbErr> {
+    let transitions = state.availability_repo.apply_release_states(now).await?;
+    if transitions.is_empty() {
+        return Ok(ReleaseReport::default());
+    }
+
+    invalidate_catalog_cache(state).await;
+
+    let mut report = ReleaseReport {
+        transitions: transitions.len(),
+        releases: 0,
+    };
+
+    for transition in transitions
+        .into_iter()
+        .filter(|t| t.release_state == ReleaseStateEnum::LIVE)
+    {
+        let Some(event) = release_event(state, transition, now).await? else {
+            continue;
+        };
+
+        if let Err(e) = state.cache.publish(NEW_RELEASE_CHANNEL, &event).await {
+            tracing::warn!(
+                "Falha ao publicar o lançamento de {} {}: {:?}",
+                event.item_type.as_str(),
+                event.id,
+                e
+            );
+        }
+        report.releases += 1;
+    }
+
+    Ok(report)
+}
+
+/// Remove do cache as respostas do catálogo, que podem ter mudado.
+pub async fn invalidate_catalog_cache(state: &AppState) {
+    if let Err(e) = state.cache.clear_pattern(CATALOG_CACHE_PATTERN).await {
+        tracing::warn!("Falha ao limpar o cache do catálogo: {:?}", e);
+    }
+}
+
+/// Monta o aviso de lançamento. Vídeos indisponíveis ou apagados não geram aviso.
+async fn release_event(
+    state: &AppState,
+    transition: ReleaseTransition,
+    now: NaiveDateTime,
+) -> Result<Option<NewReleaseEvent>, DbErr> {
+    let (title, series_id) = match transition.owner {
+        AvailabilityOwnerEnum::VIDEO => {
+            match state.video_repo.find_by_id(transition.owner_id).await? {
+                Some(video) if video.is_available => (video.title, video.series_id),
+                _ => return Ok(None),
+            }
+        }
+        AvailabilityOwnerEnum::SERIE => {
+            match state.serie_repo.find_by_id(transition.owner_id).await? {
+                Some(serie) => (serie.title, None),
+                None => return Ok(None),
+            }
+        }
+    };
+
+    Ok(Some(NewReleaseEvent {
+        item_type: transition.owner,
+        id: transition.owner_id,
+        title,
+        series_id,
+        region: transition.region,
+        released_at: now,
+    }))
+}
+
+/// Os testes com banco ficam com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::database::repositories::videos_repository::VideosRepository;
+    use crate::modules::database::test_support::test_db;
+    use chrono::{Duration, NaiveDate};
+    use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement, Value};
+
+    /// Janelas em 1990, longe de qualquer dado real do banco de teste.
+    fn start() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(1990, 3, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    async fn insert(db: &DatabaseConnection, sql: &str, values: Vec<Value>) -> i32 {
+        db.query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await
+        .expect(sql)
+        .expect(sql)
+        .try_get("", "id")
+        .expect(sql)
+    }
+
+    async fn video(
+        db: &DatabaseConnection,
+        series_id: Option<i32>,
+        window: (Option<NaiveDateTime>, Option<NaiveDateTime>),
+    ) -> VideoModel {
+        let id = insert(
+            db,
+            "INSERT INTO videos (title, duration_seconds, is_available, rating, series_id,
+                available_from, available_until, created_at, updated_at)
+             VALUES ('Janela', 60, true, 0, $1, $2, $3, now(), now()) RETURNING id",
+            vec![series_id.into(), window.0.into(), window.1.into()],
+        )
+        .await;
+        VideosRepository::new(db.clone())
+            .find_by_id(id)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    async fn region_window(
+        db: &DatabaseConnection,
+        owner: AvailabilityOwnerEnum,
+        owner_id: i32,
+        region: &str,
+        (from, until): (Option<NaiveDateTime>, Option<NaiveDateTime>),
+    ) {
+        insert(
+            db,
+            "INSERT INTO availability_windows (owner_type, owner_id, region, available_from,
+                available_until, created_at)
+             VALUES ($1, $2, $3, $4, $5, now()) RETURNING id",
+            vec![
+                owner.as_str().into(),
+                owner_id.into(),
+                region.into(),
+                from.into(),
+                until.into(),
+            ],
+        )
+        .await;
+    }
+
+    async fn cleanup(db: &DatabaseConnection, video_ids: &[i32], serie_id: i32) {
+        for video_id in video_ids {
+            db.execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "DELETE FROM availability_windows WHERE owner_type = 'VIDEO' AND owner_id = $1",
+                [(*video_id).into()],
+            ))
+            .await
+            .unwrap();
+            db.execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "DELETE FROM videos WHERE id = $1",
+                [(*video_id).into()],
+            ))
+            .await
+            .unwrap();
+        }
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM availability_windows WHERE owner_type = 'SERIE' AND owner_id = $1",
+            [serie_id.into()],
+        ))
+        .await
+        .unwrap();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM series WHERE id = $1",
+            [serie_id.into()],
+        ))
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn windows_limit_playback_by_instant_region_and_serie() {
+        let db = test_db().await;
+        let availability = AvailabilityRepository::new(db.clone());
+        let series = SerieRepository::new(db.clone());
+        let live = |video: VideoModel, region: Option<&'static str>, at: NaiveDateTime| {
+            let (availability, series) = (&availability, &series);
+            async move {
+                video_live(availability, series, &video, region, at)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        let t = start();
+        let end = t + Duration::hours(2);
+        let second = Duration::seconds(1);
+
+        let serie_id = insert(
+            &db,
+            "INSERT INTO series (title, is_featured, available_from, created_at, updated_at)
+             VALUES ('Janela', false, $1, now(), now()) RETURNING id",
+            vec![Some(t + Duration::days(1)).into()],
+        )
+        .await;
+        let windowed = video(&db, None, (Some(t), Some(end))).await;
+        let open_ended = video(&db, None, (Some(t), None)).await;
+        let episode = video(&db, Some(serie_id), (None, None)).await;
+
+        // início incluso, fim excluído
+        assert!(!live(windowed.clone(), None, t - second).await);
+        assert!(live(windowed.clone(), None, t).await);
+        assert!(live(windowed.clone(), None, end - second).await);
+        assert!(!live(windowed.clone(), None, end).await);
+        assert_eq!(
+            window_state_at(
+                &availability,
+                AvailabilityOwnerEnum::VIDEO,
+                windowed.id,
+                (windowed.available_from, windowed.available_until),
+                None,
+                end,
+            )
+            .await
+            .unwrap(),
+            ReleaseStateEnum::EXPIRED
+        );
+
+        // sem fim, segue no ar
+        assert!(!live(open_ended.clone(), None, t - second).await);
+        assert!(live(open_ended.clone(), None, t + Duration::days(3650)).await);
+
+        // a janela regional substitui a global só na sua região
+        region_window(
+            &db,
+            AvailabilityOwnerEnum::VIDEO,
+            windowed.id,
+            "BR",
+            (Some(end), None),
+        )
+        .await;
+        assert!(!live(windowed.clone(), Some("BR"), t).await);
+        assert!(live(windowed.clone(), Some("BR"), end).await);
+        assert!(live(windowed.clone(), Some("US"), t).await);
+        assert!(!live(windowed.clone(), Some("US"), end).await);
+
+        // o episódio depende da janela da série, que também pode ser regional
+        assert!(!live(episode.clone(), None, t).await);
+        assert!(live(episode.clone(), None, t + Duration::days(1)).await);
+        region_window(
+            &db,
+            AvailabilityOwnerEnum::SERIE,
+            serie_id,
+            "PT",
+            (None, None),
+        )
+        .await;
+        assert!(live(episode.clone(), Some("PT"), t).await);
+
+        cleanup(&db, &[windowed.id, open_ended.id, episode.id], serie_id).await;
+    }
+}
@@ -0,0 +1,41 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{request::Parts, StatusCode},
+    Json,
+};
+
+use crate::modules::availability::{normalize_region, REGION_HEADER};
+use crate::modules::validation::validation_layer::ValidationErrorResponse;
+
+/// Região do espectador, lida do cabeçalho `X-Region`. Sem o cabeçalho vale a
+/// janela global de disponibilidade.
+#[derive(Debug, Clone, Default)]
+pub struct ViewerRegion(pub Option<String>);
+
+impl<S> FromRequestParts<S> for ViewerRegion
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, Json<ValidationErrorResponse>);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(REGION_HEADER) else {
+            return Ok(Self(None));
+        };
+
+        value
+            .to_str()
+            .map_err(|_| "Cabeçalho X-Region inválido".to_string())
+            .and_then(normalize_region)
+            .map(|region| Self(Some(region)))
+            .map_err(|msg| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ValidationErrorResponse {
+                        message: "Região inválida".to_string(),
+                        errors: serde_json::json!([msg]),
+                    }),
+                )
+            })
+    }
+}
@@ -9,17 +9,40 @@ use azure_storage::shared_access_signature::service_sas::{
 use azure_storage_blobs::blob::operations::PutBlockBlobResponse;
 use azure_storage_blobs::blob::BlobBlockType;
 use azure_storage_blobs::blob::BlockList;
+use azure_storage_blobs::blob::BlockListType;
 use azure_storage_blobs::prelude::*;
 use futures::StreamExt;
+use md5::Md5;
+use sha2::{Digest, Sha256};
 use std::ops::Range;
 use std::sync::Arc;
 use time::OffsetDateTime;
-use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+pub struct UploadedBlob {
+    pub url: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+pub struct StagedBlock {
+    pub block_id: String,
+    pub size: u64,
+    pub committed: bool,
+}
+
+pub struct ListedBlob {
+    pub name: String,
+    pub size: u64,
+    pub last_modified: OffsetDateTime,
+}
 
 pub struct StorageServiceConfig {
     pub account_name: String,
     pub account_key: String,
     pub container_name: String,
+    /// Libera leitura anônima dos blobs (não da listagem do container).
+    pub public_read: bool,
 }
 
 #[allow(dead_code)]
@@ -37,9 +60,15 @@ impl StorageService {
         let container_client =
             Arc::new(blob_service_client.container_client(config.container_name.clone()));
 
+        let public_access = if config.public_read {
+            PublicAccess::Blob
+        } else {
+            PublicAccess::None
+        };
+
         match container_client
             .create()
-            .public_access(PublicAccess::None)
+            .public_access(public_access)
             .into_future()
             .await
         {
@@ -151,8 +180,9 @@ impl StorageService {
         buffer_size: usize,
         blob_content_type: Option<&str>,
         max_file_size: Option<u64>,
-    ) -> Result<(String, u64)> {
+    ) -> Result<UploadedBlob> {
         let mut total_bytes: u64 = 0;
+        let mut hasher = Sha256::new();
         let mut block_ids: Vec<String> = Vec::new();
 
         let content_type = blob_content_type
@@ -182,12 +212,14 @@ impl StorageService {
             }
 
             let chunk = buffer[..n].to_vec();
+            hasher.update(&chunk);
 
             let block_id = format!("{:08}", block_number);
             let block_id_base64 = base64::encode(&block_id);
 
             blob_client
-                .put_block(block_id_base64.clone(), chunk)
+                .put_block(block_id_base64.clone(), chunk.clone())
+                .hash(Self::content_md5(&chunk))
                 .into_future()
                 .await?;
 
@@ -206,7 +238,34 @@ impl StorageService {
             .into_future()
             .await?;
 
-        Ok((blob_client.url()?.to_string(), total_bytes))
+        Ok(UploadedBlob {
+            url: blob_client.url()?.to_string(),
+            size: total_bytes,
+            sha256: format!("{:x}", hasher.finalize()),
+        })
+    }
+
+    /// Enviado como `Content-MD5` em cada bloco para o storage rejeitar
+    /// blocos corrompidos durante a transferência.
+    fn content_md5(data: &[u8]) -> Hash {
+        Hash::MD5(Md5::digest(data).into())
+    }
+
+    /// Lista todos os blobs confirmados do container, percorrendo as páginas.
+    pub async fn list_blobs(&self) -> Result<Vec<ListedBlob>> {
+        let mut stream = self.container_client.list_blobs().into_stream();
+        let mut blobs = Vec::new();
+
+        while let Some(page) = stream.next().await {
+            let page = page?;
+            blobs.extend(page.blobs.blobs().map(|blob| ListedBlob {
+                name: blob.name.clone(),
+                size: blob.properties.content_length,
+                last_modified: blob.properties.last_modified,
+            }));
+        }
+
+        Ok(blobs)
     }
 
     pub async fn delete_blob(&self, blob_name: &str) -> Result<()> {
@@ -242,4 +301,143 @@ impl StorageService {
 
         Ok(bytes)
     }
+
+    pub async fn put_block(&self, blob_name: &str, block_id: &str, data: Vec<u8>) -> Result<()> {
+        let blob_client = self.get_blob_client(blob_name);
+
+        let hash = Self::content_md5(&data);
+
+        blob_client
+            .put_block(BlockId::new(block_id.to_string()), data)
+            .hash(hash)
+            .into_future()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_block_list(&self, blob_name: &str) -> Result<Vec<StagedBlock>> {
+        let blob_client = self.get_blob_client(blob_name);
+
+        let response = match blob_client
+            .get_block_list()
+            .block_list_type(BlockListType::All)
+            .into_future()
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                if format!("{:?}", e).contains("BlobNotFound") {
+                    return Ok(Vec::new());
+                }
+                return Err(e.into());
+            }
+        };
+
+        let blocks = response
+            .block_with_size_list
+            .blocks
+            .into_iter()
+            .map(|block| {
+                let (id, committed) = match block.block_list_type {
+                    BlobBlockType::Committed(id) => (id, true),
+                    BlobBlockType::Uncommitted(id) | BlobBlockType::Latest(id) => (id, false),
+                };
+
+                StagedBlock {
+                    block_id: String::from_utf8_lossy(&id.bytes()).to_string(),
+                    size: block.size_in_bytes,
+                    committed,
+                }
+            })
+            .collect();
+
+        Ok(blocks)
+    }
+
+    pub async fn commit_block_list(
+        &self,
+        blob_name: &str,
+        block_ids: &[String],
+        blob_content_type: &str,
+    ) -> Result<String> {
+        let blob_client = self.get_blob_client(blob_name);
+
+        let block_list = BlockList {
+            blocks: block_ids
+                .iter()
+                .map(|id| BlobBlockType::Latest(BlockId::new(id.clone())))
+                .collect(),
+        };
+
+        blob_client
+            .put_block_list(block_list)
+            .content_type(blob_content_type.to_owned())
+            .into_future()
+            .await?;
+
+        Ok(blob_client.url()?.to_string())
+    }
+
+    pub async fn blob_exists(&self, blob_name: &str) -> Result<bool> {
+        match self
+            .get_blob_client(blob_name)
+            .get_properties()
+            .into_future()
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if format!("{:?}", e).contains("BlobNotFound") {
+                    Ok(false)
+                } else {
+                    Err(e.into())
+                }
+            }
+        }
+    }
+
+    pub async fn compute_sha256(&self, blob_name: &str) -> Result<(String, u64)> {
+        let blob_client = self.get_blob_client(blob_name);
+
+        let mut stream = blob_client.get().into_stream();
+        let mut hasher = Sha256::new();
+        let mut total_bytes: u64 = 0;
+
+        while let Some(chunk) = stream.next().await {
+            let mut body = chunk?.data;
+            while let Some(bytes) = body.next().await {
+                let bytes = bytes?;
+                total_bytes += bytes.len() as u64;
+                hasher.update(&bytes);
+            }
+        }
+
+        Ok((format!("{:x}", hasher.finalize()), total_bytes))
+    }
+
+    /// Grava o blob em `writer` em streaming, sem carregá-lo inteiro em memória.
+    pub async fn download_to_writer<W: AsyncWrite + Unpin + Send>(
+        &self,
+        blob_name: &str,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let blob_client = self.get_blob_client(blob_name);
+
+        let mut stream = blob_client.get().into_stream();
+        let mut total_bytes: u64 = 0;
+
+        while let Some(chunk) = stream.next().await {
+            let mut body = chunk?.data;
+            while let Some(bytes) = body.next().await {
+                let bytes = bytes?;
+                total_bytes += bytes.len() as u64;
+                writer.write_all(&bytes).await?;
+            }
+        }
+
+        writer.flush().await?;
+
+        Ok(total_bytes)
+    }
 }
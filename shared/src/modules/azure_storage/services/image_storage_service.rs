@@ -0,0 +1,95 @@
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::enums::image_format_enum::ImageFormatEnum;
+use crate::modules::azure_storage::model_storage_service::{
+    ListedBlob, StorageService, StorageServiceConfig,
+};
+use crate::modules::config::Config;
+
+pub struct SavedImageFile {
+    pub blob_name: String,
+    pub url: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// Container público das imagens de catálogo: as URLs das variantes vão
+/// direto para os clientes, sem passar pelas APIs.
+pub struct ImageStorageService {
+    storage_service: Arc<StorageService>,
+    storage_url: String,
+    container_name: String,
+}
+
+impl ImageStorageService {
+    pub async fn new(config: &Config) -> Result<Self> {
+        let storage_service = StorageService::new(StorageServiceConfig {
+            account_name: config.azure_cast_rustaccount_name.clone(),
+            account_key: config.azure_cast_rustaccount_key.clone(),
+            container_name: config.azure_cast_rust_image_container.clone(),
+            public_read: true,
+        })
+        .await?;
+
+        Ok(Self {
+            storage_service: Arc::new(storage_service),
+            storage_url: config.azure_cast_rust_storage_url.clone(),
+            container_name: config.azure_cast_rust_image_container.clone(),
+        })
+    }
+
+    pub fn container_name(&self) -> &str {
+        &self.container_name
+    }
+
+    pub fn get_image_url(&self, blob_name: &str) -> String {
+        format!("{}/{}/{}", self.storage_url, self.container_name, blob_name)
+    }
+
+    /// Todas as variantes de um envio ficam sob o mesmo prefixo
+    /// (`<dono>/<id>/<tipo>/<uuid>/`), o que facilita a limpeza.
+    pub fn generate_image_prefix(&self, owner: &str, owner_id: i32, kind: &str) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            owner.to_lowercase(),
+            owner_id,
+            kind.to_lowercase(),
+            Uuid::new_v4()
+        )
+    }
+
+    pub async fn save_image(
+        &self,
+        data: Vec<u8>,
+        prefix: &str,
+        name: &str,
+        format: ImageFormatEnum,
+    ) -> Result<SavedImageFile> {
+        let blob_name = format!("{}/{}.{}", prefix, name, format.extension());
+        let size = data.len() as u64;
+        let sha256 = format!("{:x}", Sha256::digest(&data));
+
+        self.storage_service
+            .as_ref()
+            .upload_buffer(data, &blob_name, format.mime_type())
+            .await?;
+
+        Ok(SavedImageFile {
+            url: self.get_image_url(&blob_name),
+            blob_name,
+            size,
+            sha256,
+        })
+    }
+
+    pub async fn list_image_blobs(&self) -> Result<Vec<ListedBlob>> {
+        self.storage_service.as_ref().list_blobs().await
+    }
+
+    pub async fn delete_image(&self, blob_name: &str) -> Result<()> {
+        self.storage_service.as_ref().delete_blob(blob_name).await
+    }
+}
@@ -1 +1,2 @@
+pub mod image_storage_service;
 pub mod video_storage_service;
@@ -1,17 +1,59 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
 use slug::slugify;
 use std::ops::Range;
 use std::sync::Arc;
-use tokio::io::AsyncRead;
+use tokio::io::{AsyncRead, AsyncWrite};
 use url::Url;
 use uuid::Uuid;
 
-use crate::modules::azure_storage::model_storage_service::{StorageService, StorageServiceConfig};
+use crate::modules::azure_storage::model_storage_service::{
+    ListedBlob, StagedBlock, StorageService, StorageServiceConfig,
+};
 use crate::modules::config::Config;
+use crate::modules::database::schema::media_assets::Model as MediaAssetModel;
+use crate::modules::media_probe::{self, ByteSource, ProbeError, ProbeResult};
+
+pub struct SavedVideoFile {
+    pub blob_name: String,
+    pub url: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// Leitura por intervalos de um blob de vídeo, usada pelo prober de containers.
+struct VideoBlobSource<'a> {
+    storage_service: &'a StorageService,
+    blob_name: &'a str,
+    size: u64,
+}
+
+#[async_trait]
+impl ByteSource for VideoBlobSource<'_> {
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    async fn read_at(&self, offset: u64, len: u64) -> Result<Vec<u8>> {
+        let end = offset.saturating_add(len).min(self.size);
+        if offset >= end {
+            return Ok(Vec::new());
+        }
+
+        self.storage_service
+            .get_blob_range(self.blob_name, Some(offset..end))
+            .await
+    }
+}
 
 pub struct VideoStorageService {
     storage_service: Arc<StorageService>,
     storage_url: String,
+    container_name: String,
+    upload_block_size: u64,
+    upload_max_file_size: u64,
+    upload_session_expiry_hours: u64,
 }
 
 impl VideoStorageService {
@@ -20,15 +62,40 @@ impl VideoStorageService {
             account_name: config.azure_cast_rustaccount_name.clone(),
             account_key: config.azure_cast_rustaccount_key.clone(),
             container_name: config.azure_cast_rust_video_container.clone(),
+            public_read: false,
         })
         .await?;
 
         Ok(Self {
             storage_service: Arc::new(storage_service),
             storage_url: config.azure_cast_rust_storage_url.clone(),
+            container_name: config.azure_cast_rust_video_container.clone(),
+            upload_block_size: config.upload_block_size_bytes,
+            upload_max_file_size: config.upload_max_file_size_bytes,
+            upload_session_expiry_hours: config.upload_session_expiry_hours,
         })
     }
 
+    pub fn container_name(&self) -> &str {
+        &self.container_name
+    }
+
+    pub fn upload_block_size(&self) -> u64 {
+        self.upload_block_size
+    }
+
+    pub fn upload_max_file_size(&self) -> u64 {
+        self.upload_max_file_size
+    }
+
+    pub fn upload_session_expiry_hours(&self) -> u64 {
+        self.upload_session_expiry_hours
+    }
+
+    pub fn get_video_url(&self, blob_name: &str) -> String {
+        format!("{}/{}", self.storage_url, blob_name)
+    }
+
     pub fn generate_video_blob_path(&self, file_name: &str) -> String {
         format!("{}-{}", Uuid::new_v4(), slugify(file_name))
     }
@@ -47,10 +114,10 @@ impl VideoStorageService {
         file_name: &str,
         blob_content_type: &str,
         max_file_size: u64,
-    ) -> Result<(String, u64)> {
+    ) -> Result<SavedVideoFile> {
         let blob_name = self.generate_video_blob_path(file_name);
 
-        let (_blob_url, total_bytes) = self
+        let uploaded = self
             .storage_service
             .as_ref()
             .upload_stream_with_blocks(
@@ -62,9 +129,37 @@ impl VideoStorageService {
             )
             .await?;
 
-        let full_url = format!("{}/{}", self.storage_url, blob_name);
+        Ok(SavedVideoFile {
+            url: self.get_video_url(&blob_name),
+            blob_name,
+            size: uploaded.size,
+            sha256: uploaded.sha256,
+        })
+    }
 
-        Ok((full_url, total_bytes))
+    /// Grava um arquivo pequeno já em memória (legendas) de uma só vez.
+    pub async fn save_video_text_file(
+        &self,
+        content: String,
+        file_name: &str,
+        blob_content_type: &str,
+    ) -> Result<SavedVideoFile> {
+        let blob_name = self.generate_video_blob_path(file_name);
+        let data = content.into_bytes();
+        let size = data.len() as u64;
+        let sha256 = format!("{:x}", Sha256::digest(&data));
+
+        self.storage_service
+            .as_ref()
+            .upload_buffer(data, &blob_name, blob_content_type)
+            .await?;
+
+        Ok(SavedVideoFile {
+            url: self.get_video_url(&blob_name),
+            blob_name,
+            size,
+            sha256,
+        })
     }
 
     pub fn get_blob_name_from_url(&self, url: &str) -> Result<String> {
@@ -99,4 +194,102 @@ impl VideoStorageService {
     pub async fn delete_video(&self, blob_name: &str) -> Result<()> {
         self.storage_service.as_ref().delete_blob(blob_name).await
     }
+
+    /// Os ids de bloco precisam ter o mesmo tamanho dentro de um blob,
+    /// por isso o número do bloco é sempre formatado com 8 dígitos.
+    pub fn block_id_for(&self, block_number: i32) -> String {
+        format!("{:08}", block_number)
+    }
+
+    pub fn block_number_from_id(&self, block_id: &str) -> Option<i32> {
+        block_id.parse().ok()
+    }
+
+    pub async fn stage_video_block(
+        &self,
+        blob_name: &str,
+        block_number: i32,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        self.storage_service
+            .as_ref()
+            .put_block(blob_name, &self.block_id_for(block_number), data)
+            .await
+    }
+
+    pub async fn list_video_blocks(&self, blob_name: &str) -> Result<Vec<StagedBlock>> {
+        self.storage_service
+            .as_ref()
+            .get_block_list(blob_name)
+            .await
+    }
+
+    pub async fn commit_video_blocks(
+        &self,
+        blob_name: &str,
+        total_blocks: i32,
+        blob_content_type: &str,
+    ) -> Result<String> {
+        let block_ids: Vec<String> = (0..total_blocks).map(|n| self.block_id_for(n)).collect();
+
+        self.storage_service
+            .as_ref()
+            .commit_block_list(blob_name, &block_ids, blob_content_type)
+            .await?;
+
+        Ok(self.get_video_url(blob_name))
+    }
+
+    /// Apaga o blob de um media asset, recusando assets de outro container.
+    pub async fn delete_asset_blob(&self, asset: &MediaAssetModel) -> Result<()> {
+        if asset.container != self.container_name {
+            bail!(
+                "Media asset {} pertence ao container '{}'",
+                asset.id,
+                asset.container
+            );
+        }
+
+        self.delete_video(&asset.storage_key).await
+    }
+
+    pub async fn probe_video(
+        &self,
+        blob_name: &str,
+        size: u64,
+    ) -> std::result::Result<ProbeResult, ProbeError> {
+        let source = VideoBlobSource {
+            storage_service: self.storage_service.as_ref(),
+            blob_name,
+            size,
+        };
+
+        media_probe::probe(&source).await
+    }
+
+    pub async fn download_video<W: AsyncWrite + Unpin + Send>(
+        &self,
+        blob_name: &str,
+        writer: &mut W,
+    ) -> Result<u64> {
+        self.storage_service
+            .as_ref()
+            .download_to_writer(blob_name, writer)
+            .await
+    }
+
+    pub async fn list_video_blobs(&self) -> Result<Vec<ListedBlob>> {
+        self.storage_service.as_ref().list_blobs().await
+    }
+
+    pub async fn video_exists(&self, blob_name: &str) -> Result<bool> {
+        self.storage_service.as_ref().blob_exists(blob_name).await
+    }
+
+    pub async fn compute_video_checksum(&self, blob_name: &str) -> Result<(String, u64)> {
+        self.storage_service
+            .as_ref()
+            .compute_sha256(blob_name)
+            .await
+    }
 }
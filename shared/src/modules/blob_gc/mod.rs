@@ -0,0 +1,188 @@
+//! Reconciliação entre os containers do storage e o banco: encontra blobs que
+//! nenhum registro referencia (uploads interrompidos, deleções que falharam)
+//! e os apaga depois de um período de carência.
+
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+
+use crate::modules::app_state::AppState;
+use crate::modules::azure_storage::model_storage_service::ListedBlob;
+
+#[derive(Debug, Clone)]
+pub struct BlobGcOptions {
+    /// Apenas relata os órfãos, sem apagar nada.
+    pub dry_run: bool,
+    /// Blobs modificados há menos tempo que isso são ignorados: podem ser de
+    /// um upload ou transcodificação cujo registro ainda não foi gravado.
+    pub grace_period: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrphanBlob {
+    pub name: String,
+    pub size: u64,
+    pub last_modified: NaiveDateTime,
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ContainerGcReport {
+    pub container: String,
+    pub scanned: usize,
+    pub referenced: usize,
+    pub within_grace_period: usize,
+    pub orphans: Vec<OrphanBlob>,
+    pub failed: usize,
+}
+
+impl ContainerGcReport {
+    pub fn orphan_bytes(&self) -> u64 {
+        self.orphans.iter().map(|orphan| orphan.size).sum()
+    }
+
+    pub fn deleted(&self) -> usize {
+        self.orphans.iter().filter(|orphan| orphan.deleted).count()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlobGcReport {
+    pub dry_run: bool,
+    pub containers: Vec<ContainerGcReport>,
+}
+
+/// Varre os containers de vídeo e de imagem.
+///
+/// As referências são carregadas antes da listagem; um blob criado no meio
+/// da varredura aparece sem referência, mas cai no período de carência. Se
+/// qualquer consulta falhar, nada é apagado.
+pub async fn collect(state: &AppState, options: &BlobGcOptions) -> Result<BlobGcReport> {
+    let cutoff = Utc::now().naive_utc() - options.grace_period;
+
+    let video_storage = state.video_storage_service.as_ref();
+    let image_storage = state.image_storage_service.as_ref();
+
+    let mut video_refs: HashSet<String> = state
+        .media_asset_repo
+        .find_storage_keys(video_storage.container_name())
+        .await
+        .context("Erro ao buscar media assets")?
+        .into_iter()
+        .collect();
+    video_refs.extend(
+        state
+            .upload_session_repo
+            .find_pending_blob_names()
+            .await
+            .context("Erro ao buscar sessões de upload")?,
+    );
+    video_refs.extend(
+        state
+            .video_repo
+            .find_media_urls()
+            .await
+            .context("Erro ao buscar URLs dos vídeos")?
+            .iter()
+            .filter_map(|url| video_storage.get_blob_name_from_url(url).ok()),
+    );
+
+    let image_refs: HashSet<String> = state
+        .image_repo
+        .find_storage_keys()
+        .await
+        .context("Erro ao buscar imagens")?
+        .into_iter()
+        .collect();
+
+    let video_blobs = video_storage
+        .list_video_blobs()
+        .await
+        .context("Erro ao listar o container de vídeos")?;
+    let image_blobs = image_storage
+        .list_image_blobs()
+        .await
+        .context("Erro ao listar o container de imagens")?;
+
+    let mut videos = classify(
+        video_storage.container_name(),
+        video_blobs,
+        &video_refs,
+        cutoff,
+    );
+    let mut images = classify(
+        image_storage.container_name(),
+        image_blobs,
+        &image_refs,
+        cutoff,
+    );
+
+    if !options.dry_run {
+        for orphan in &mut videos.orphans {
+            match video_storage.delete_video(&orphan.name).await {
+                Ok(()) => orphan.deleted = true,
+                Err(e) => {
+                    videos.failed += 1;
+                    tracing::warn!("Falha ao apagar blob órfão '{}': {:?}", orphan.name, e);
+                }
+            }
+        }
+
+        for orphan in &mut images.orphans {
+            match image_storage.delete_image(&orphan.name).await {
+                Ok(()) => orphan.deleted = true,
+                Err(e) => {
+                    images.failed += 1;
+                    tracing::warn!("Falha ao apagar blob órfão '{}': {:?}", orphan.name, e);
+                }
+            }
+        }
+    }
+
+    Ok(BlobGcReport {
+        dry_run: options.dry_run,
+        containers: vec![videos, images],
+    })
+}
+
+fn classify(
+    container: &str,
+    blobs: Vec<ListedBlob>,
+    referenced: &HashSet<String>,
+    cutoff: NaiveDateTime,
+) -> ContainerGcReport {
+    let mut report = ContainerGcReport {
+        container: container.to_string(),
+        scanned: blobs.len(),
+        ..Default::default()
+    };
+
+    for blob in blobs {
+        if referenced.contains(&blob.name) {
+            report.referenced += 1;
+            continue;
+        }
+
+        let last_modified = DateTime::from_timestamp(
+            blob.last_modified.unix_timestamp(),
+            blob.last_modified.nanosecond(),
+        )
+        .map(|date| date.naive_utc())
+        .unwrap_or(cutoff);
+
+        if last_modified >= cutoff {
+            report.within_grace_period += 1;
+            continue;
+        }
+
+        report.orphans.push(OrphanBlob {
+            name: blob.name,
+            size: blob.size,
+            last_modified,
+            deleted: false,
+        });
+    }
+
+    report
+}
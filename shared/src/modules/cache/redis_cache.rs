@@ -91,6 +91,11 @@ impl RedisCache {
         Ok(result > 0)
     }
 
+    pub async fn keys(&self, pattern: &str) -> Result<Vec<String>, RedisError> {
+        let mut conn = self.get_connection()?;
+        conn.keys(pattern)
+    }
+
     pub async fn clear_pattern(&self, pattern: &str) -> Result<u64, RedisError> {
         let mut conn = self.get_connection()?;
         let keys: Vec<String> = conn.keys(pattern)?;
@@ -204,6 +209,127 @@ impl RedisCache {
         Ok(result > 0)
     }
 
+    /// Publica a mensagem serializada em JSON no canal. Retorna quantos
+    /// assinantes a receberam.
+    pub async fn publish<C, V>(&self, channel: C, message: &V) -> Result<u64, RedisError>
+    where
+        C: AsRef<str>,
+        V: Serialize,
+    {
+        let mut conn = self.get_connection()?;
+        let serialized = serde_json::to_string(message).map_err(|e| {
+            RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Serialization failed",
+                e.to_string(),
+            ))
+        })?;
+        conn.publish(channel.as_ref(), serialized)
+    }
+
+    /// Troca todo o conteúdo do sorted set de uma vez: grava numa chave
+    /// temporária e renomeia, para que ninguém leia o conjunto pela metade.
+    pub async fn replace_sorted_set<K>(
+        &self,
+        key: K,
+        members: &[(String, f64)],
+        ttl_seconds: u64,
+    ) -> Result<(), RedisError>
+    where
+        K: AsRef<str>,
+    {
+        let mut conn = self.get_connection()?;
+        let key = key.as_ref();
+
+        if members.is_empty() {
+            let _: i32 = conn.del(key)?;
+            return Ok(());
+        }
+
+        let staging = format!("{}:staging", key);
+        let items: Vec<(f64, &str)> = members
+            .iter()
+            .map(|(member, score)| (*score, member.as_str()))
+            .collect();
+
+        redis::pipe()
+            .atomic()
+            .del(&staging)
+            .ignore()
+            .zadd_multiple(&staging, &items)
+            .ignore()
+            .rename(&staging, key)
+            .ignore()
+            .expire(key, ttl_seconds as i64)
+            .ignore()
+            .query::<()>(&mut conn)?;
+
+        Ok(())
+    }
+
+    /// Até `count` membros do sorted set, do maior para o menor score.
+    pub async fn get_sorted_set_desc<K>(
+        &self,
+        key: K,
+        count: usize,
+    ) -> Result<Vec<(String, f64)>, RedisError>
+    where
+        K: AsRef<str>,
+    {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.get_connection()?;
+        conn.zrevrange_withscores(key.as_ref(), 0, count as isize - 1)
+    }
+
+    /// Acrescenta uma entrada ao stream, mantendo cerca de `max_len` entradas.
+    /// Retorna o id gerado pelo Redis.
+    pub async fn stream_add<K>(
+        &self,
+        key: K,
+        max_len: usize,
+        fields: &[(&str, String)],
+    ) -> Result<String, RedisError>
+    where
+        K: AsRef<str>,
+    {
+        let mut conn = self.get_connection()?;
+        let mut cmd = redis::cmd("XADD");
+        cmd.arg(key.as_ref())
+            .arg("MAXLEN")
+            .arg("~")
+            .arg(max_len)
+            .arg("*");
+        for (field, value) in fields {
+            cmd.arg(*field).arg(value);
+        }
+        cmd.query(&mut conn)
+    }
+
+    /// Executa um script Lua, para operações que precisam ler e gravar sem
+    /// outra requisição no meio.
+    pub async fn run_script<T>(
+        &self,
+        script: &redis::Script,
+        keys: &[&str],
+        args: &[String],
+    ) -> Result<T, RedisError>
+    where
+        T: redis::FromRedisValue,
+    {
+        let mut conn = self.get_connection()?;
+        let mut invocation = script.prepare_invoke();
+        for key in keys {
+            invocation.key(*key);
+        }
+        for arg in args {
+            invocation.arg(arg);
+        }
+        invocation.invoke(&mut conn)
+    }
+
     pub async fn ping(&self) -> Result<String, RedisError> {
         let mut conn = self.get_connection()?;
         let pong: String = redis::cmd("PING").query(&mut conn)?;
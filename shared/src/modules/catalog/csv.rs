@@ -0,0 +1,183 @@
+//! CSV do catálogo: um arquivo por entidade, com cabeçalho. Nos vídeos, as
+//! listas de créditos ficam numa célula, separadas por `|`; cada item do
+//! elenco é `ator:papel`, com `:lead` no fim para o protagonista. Célula
+//! vazia mantém os créditos atuais.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::catalog_entity_enum::CatalogEntityEnum;
+use crate::modules::catalog::rows::{CastRow, CatalogDocument, VideoRow};
+
+const LIST_SEPARATOR: char = '|';
+const LEAD_SUFFIX: &str = ":lead";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct VideoCsvRow {
+    external_id: String,
+    serie_external_id: Option<String>,
+    season_number: Option<i32>,
+    episode_number: Option<i32>,
+    title: String,
+    description: Option<String>,
+    duration_seconds: Option<i32>,
+    release_year: Option<i32>,
+    thumbnail_url: Option<String>,
+    trailer_url: Option<String>,
+    #[serde(default)]
+    is_available: bool,
+    categories: Option<String>,
+    cast: Option<String>,
+    directors: Option<String>,
+}
+
+impl From<VideoCsvRow> for VideoRow {
+    fn from(row: VideoCsvRow) -> Self {
+        Self {
+            external_id: row.external_id,
+            serie_external_id: row.serie_external_id,
+            season_number: row.season_number,
+            episode_number: row.episode_number,
+            title: row.title,
+            description: row.description,
+            duration_seconds: row.duration_seconds,
+            release_year: row.release_year,
+            thumbnail_url: row.thumbnail_url,
+            trailer_url: row.trailer_url,
+            is_available: row.is_available,
+            categories: row.categories.map(|cell| split_list(&cell)),
+            cast: row.cast.map(|cell| {
+                split_list(&cell)
+                    .iter()
+                    .map(|item| parse_cast(item))
+                    .collect()
+            }),
+            directors: row.directors.map(|cell| split_list(&cell)),
+        }
+    }
+}
+
+impl From<&VideoRow> for VideoCsvRow {
+    fn from(row: &VideoRow) -> Self {
+        Self {
+            external_id: row.external_id.clone(),
+            serie_external_id: row.serie_external_id.clone(),
+            season_number: row.season_number,
+            episode_number: row.episode_number,
+            title: row.title.clone(),
+            description: row.description.clone(),
+            duration_seconds: row.duration_seconds,
+            release_year: row.release_year,
+            thumbnail_url: row.thumbnail_url.clone(),
+            trailer_url: row.trailer_url.clone(),
+            is_available: row.is_available,
+            categories: row.categories.as_deref().map(join_list),
+            cast: row.cast.as_ref().map(|cast| {
+                cast.iter()
+                    .map(format_cast)
+                    .collect::<Vec<_>>()
+                    .join(&LIST_SEPARATOR.to_string())
+            }),
+            directors: row.directors.as_deref().map(join_list),
+        }
+    }
+}
+
+fn split_list(cell: &str) -> Vec<String> {
+    cell.split(LIST_SEPARATOR)
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn join_list(items: &[String]) -> String {
+    items.join(&LIST_SEPARATOR.to_string())
+}
+
+fn parse_cast(item: &str) -> CastRow {
+    let (actor, rest) = item.split_once(':').unwrap_or((item, ""));
+    let (role, is_lead) = match rest.strip_suffix(LEAD_SUFFIX) {
+        Some(role) => (role, true),
+        None if rest == &LEAD_SUFFIX[1..] => ("", true),
+        None => (rest, false),
+    };
+
+    CastRow {
+        actor_external_id: actor.trim().to_string(),
+        role_name: Some(role.trim())
+            .filter(|role| !role.is_empty())
+            .map(str::to_string),
+        is_lead,
+    }
+}
+
+fn format_cast(member: &CastRow) -> String {
+    let mut item = member.actor_external_id.clone();
+    if member.role_name.is_some() || member.is_lead {
+        item.push(':');
+        item.push_str(member.role_name.as_deref().unwrap_or_default());
+    }
+    if member.is_lead {
+        item.push_str(LEAD_SUFFIX);
+    }
+    item
+}
+
+fn read_rows<T: DeserializeOwned>(data: &str) -> Result<Vec<T>, String> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .trim(::csv::Trim::All)
+        .from_reader(data.as_bytes());
+
+    reader
+        .deserialize()
+        .map(|record| record.map_err(|e| format!("CSV inválido: {}", e)))
+        .collect()
+}
+
+fn write_rows<T: Serialize>(rows: impl IntoIterator<Item = T>) -> Result<String, String> {
+    let mut writer = ::csv::Writer::from_writer(Vec::new());
+
+    for row in rows {
+        writer
+            .serialize(row)
+            .map_err(|e| format!("Erro ao gerar CSV: {}", e))?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("Erro ao gerar CSV: {}", e))?;
+
+    String::from_utf8(bytes).map_err(|e| format!("Erro ao gerar CSV: {}", e))
+}
+
+/// Lê o CSV de uma entidade como um documento que só tem aquela entidade.
+pub fn read(entity: CatalogEntityEnum, data: &str) -> Result<CatalogDocument, String> {
+    let mut document = CatalogDocument::default();
+
+    match entity {
+        CatalogEntityEnum::CATEGORY => document.categories = read_rows(data)?,
+        CatalogEntityEnum::ACTOR => document.actors = read_rows(data)?,
+        CatalogEntityEnum::DIRECTOR => document.directors = read_rows(data)?,
+        CatalogEntityEnum::SERIE => document.series = read_rows(data)?,
+        CatalogEntityEnum::VIDEO => {
+            document.videos = read_rows::<VideoCsvRow>(data)?
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        }
+    }
+
+    Ok(document)
+}
+
+/// Gera o CSV de uma entidade do documento.
+pub fn write(entity: CatalogEntityEnum, document: &CatalogDocument) -> Result<String, String> {
+    match entity {
+        CatalogEntityEnum::CATEGORY => write_rows(&document.categories),
+        CatalogEntityEnum::ACTOR => write_rows(&document.actors),
+        CatalogEntityEnum::DIRECTOR => write_rows(&document.directors),
+        CatalogEntityEnum::SERIE => write_rows(&document.series),
+        CatalogEntityEnum::VIDEO => write_rows(document.videos.iter().map(VideoCsvRow::from)),
+    }
+}
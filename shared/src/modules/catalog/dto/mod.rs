@@ -0,0 +1,2 @@
+pub mod create_serie_input_dto;
+pub mod create_video_request_dto;
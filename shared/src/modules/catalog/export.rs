@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use sea_orm::DbErr;
+
+use crate::modules::app_state::AppState;
+use crate::modules::catalog::rows::{
+    CastRow, CatalogDocument, CategoryRow, PersonRow, SerieRow, VideoRow,
+};
+
+/// Catálogo completo fora da lixeira, no mesmo formato aceito pela
+/// importação. Itens sem `external_id` recebem um antes da exportação.
+pub async fn run(state: &AppState) -> Result<CatalogDocument, DbErr> {
+    state.catalog_repo.assign_missing_external_ids().await?;
+    let snapshot = state.catalog_repo.snapshot().await?;
+
+    let category_ids: HashMap<i32, String> = snapshot
+        .categories
+        .iter()
+        .map(|c| (c.id, c.external_id.clone().unwrap_or_default()))
+        .collect();
+    let actor_ids: HashMap<i32, String> = snapshot
+        .actors
+        .iter()
+        .map(|a| (a.id, a.external_id.clone().unwrap_or_default()))
+        .collect();
+    let director_ids: HashMap<i32, String> = snapshot
+        .directors
+        .iter()
+        .map(|d| (d.id, d.external_id.clone().unwrap_or_default()))
+        .collect();
+    let serie_ids: HashMap<i32, String> = snapshot
+        .series
+        .iter()
+        .map(|s| (s.id, s.external_id.clone().unwrap_or_default()))
+        .collect();
+
+    let mut video_categories: HashMap<i32, Vec<String>> = HashMap::new();
+    for (video_id, category_id) in &snapshot.video_categories {
+        if let Some(external_id) = category_ids.get(category_id) {
+            video_categories
+                .entry(*video_id)
+                .or_default()
+                .push(external_id.clone());
+        }
+    }
+    let mut video_cast: HashMap<i32, Vec<CastRow>> = HashMap::new();
+    for member in &snapshot.video_actors {
+        if let Some(external_id) = actor_ids.get(&member.actor_id) {
+            video_cast
+                .entry(member.video_id)
+                .or_default()
+                .push(CastRow {
+                    actor_external_id: external_id.clone(),
+                    role_name: member.role_name.clone(),
+                    is_lead: member.is_lead,
+                });
+        }
+    }
+    let mut video_directors: HashMap<i32, Vec<String>> = HashMap::new();
+    for (video_id, director_id) in &snapshot.video_directors {
+        if let Some(external_id) = director_ids.get(director_id) {
+            video_directors
+                .entry(*video_id)
+                .or_default()
+                .push(external_id.clone());
+        }
+    }
+
+    Ok(CatalogDocument {
+        categories: snapshot
+            .categories
+            .into_iter()
+            .map(|c| CategoryRow {
+                external_id: c.external_id.unwrap_or_default(),
+                name: c.name,
+                description: c.description,
+            })
+            .collect(),
+        actors: snapshot
+            .actors
+            .into_iter()
+            .map(|a| PersonRow {
+                external_id: a.external_id.unwrap_or_default(),
+                name: a.name,
+                biography: a.biography,
+                birth_date: a.birth_date.map(|date| date.date()),
+                profile_picture_url: a.profile_picture_url,
+            })
+            .collect(),
+        directors: snapshot
+            .directors
+            .into_iter()
+            .map(|d| PersonRow {
+                external_id: d.external_id.unwrap_or_default(),
+                name: d.name,
+                biography: d.biography,
+                birth_date: d.birth_date.map(|date| date.date()),
+                profile_picture_url: d.profile_picture_url,
+            })
+            .collect(),
+        series: snapshot
+            .series
+            .into_iter()
+            .map(|s| SerieRow {
+                external_id: s.external_id.unwrap_or_default(),
+                title: s.title,
+                description: s.description,
+                release_year: s.release_year,
+                thumbnail_url: s.thumbnail_url,
+                is_featured: s.is_featured,
+            })
+            .collect(),
+        videos: snapshot
+            .videos
+            .into_iter()
+            .map(|v| VideoRow {
+                serie_external_id: v.series_id.and_then(|id| serie_ids.get(&id).cloned()),
+                season_number: v.season_number,
+                episode_number: v.episode_number,
+                title: v.title,
+                description: v.description,
+                duration_seconds: Some(v.duration_seconds).filter(|&d| d > 0),
+                release_year: v.release_year,
+                thumbnail_url: v.thumbnail_url,
+                trailer_url: v.trailer_url,
+                is_available: v.is_available,
+                categories: Some(video_categories.remove(&v.id).unwrap_or_default()),
+                cast: Some(video_cast.remove(&v.id).unwrap_or_default()),
+                directors: Some(video_directors.remove(&v.id).unwrap_or_default()),
+                external_id: v.external_id.unwrap_or_default(),
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,475 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::DbErr;
+use serde::Serialize;
+
+use crate::enums::catalog_entity_enum::CatalogEntityEnum;
+use crate::modules::app_state::AppState;
+use crate::modules::catalog::rows::{CatalogDocument, CatalogRow, VideoRow};
+use crate::modules::database::repositories::catalog_repository::{CatalogImportPlan, ExternalItem};
+use crate::modules::database::repositories::videos_repository::episode_label;
+
+/// Quantidade de itens por entidade.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct ImportCounts {
+    pub categories: usize,
+    pub actors: usize,
+    pub directors: usize,
+    pub series: usize,
+    pub videos: usize,
+}
+
+impl ImportCounts {
+    fn add(&mut self, entity: CatalogEntityEnum) {
+        match entity {
+            CatalogEntityEnum::CATEGORY => self.categories += 1,
+            CatalogEntityEnum::ACTOR => self.actors += 1,
+            CatalogEntityEnum::DIRECTOR => self.directors += 1,
+            CatalogEntityEnum::SERIE => self.series += 1,
+            CatalogEntityEnum::VIDEO => self.videos += 1,
+        }
+    }
+}
+
+/// Erros de uma linha. `row` começa em 1 e conta só as linhas da entidade
+/// (no CSV, a linha de dados, sem o cabeçalho).
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportRowError {
+    pub entity: CatalogEntityEnum,
+    pub row: usize,
+    pub external_id: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub created: ImportCounts,
+    pub updated: ImportCounts,
+    pub errors: Vec<ImportRowError>,
+}
+
+impl ImportReport {
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
+
+/// Linhas de uma entidade com os erros encontrados até aqui.
+struct Checked<'a, T> {
+    entity: CatalogEntityEnum,
+    rows: &'a [T],
+    errors: Vec<Vec<String>>,
+}
+
+impl<'a, T: CatalogRow> Checked<'a, T> {
+    /// Valida cada linha e aponta `external_id` repetidos no arquivo.
+    fn new(entity: CatalogEntityEnum, rows: &'a [T]) -> Self {
+        let mut first_row: HashMap<&str, usize> = HashMap::new();
+        let errors = rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let mut errors = row.errors();
+                if let Some(first) = first_row.get(row.external_id()) {
+                    errors.push(format!(
+                        "external_id: '{}' repete a linha {}",
+                        row.external_id(),
+                        first + 1
+                    ));
+                } else {
+                    first_row.insert(row.external_id(), index);
+                }
+                errors
+            })
+            .collect();
+
+        Self {
+            entity,
+            rows,
+            errors,
+        }
+    }
+
+    fn external_ids(&self) -> Vec<String> {
+        self.rows
+            .iter()
+            .map(|row| row.external_id().to_string())
+            .collect()
+    }
+
+    /// `external_id` das linhas sem erro.
+    fn valid_ids(&self) -> HashSet<&'a str> {
+        self.rows
+            .iter()
+            .zip(&self.errors)
+            .filter(|(_, errors)| errors.is_empty())
+            .map(|(row, _)| row.external_id())
+            .collect()
+    }
+
+    /// `external_id` de todas as linhas, com ou sem erro.
+    fn all_ids(&self) -> HashSet<&'a str> {
+        self.rows.iter().map(|row| row.external_id()).collect()
+    }
+
+    /// Rejeita linhas que casam com séries ou vídeos na lixeira.
+    fn reject_trashed(&mut self, existing: &HashMap<String, ExternalItem>) {
+        for (row, errors) in self.rows.iter().zip(self.errors.iter_mut()) {
+            if existing
+                .get(row.external_id())
+                .is_some_and(|item| item.trashed)
+            {
+                errors.push(format!(
+                    "external_id: '{}' está na lixeira; restaure o item antes de importar",
+                    row.external_id()
+                ));
+            }
+        }
+    }
+
+    /// Move os erros para o relatório e devolve as linhas válidas com o id
+    /// a atualizar, contando criações e atualizações.
+    fn finish(
+        self,
+        existing: &HashMap<String, ExternalItem>,
+        report: &mut ImportReport,
+    ) -> Vec<(Option<i32>, T)>
+    where
+        T: Clone,
+    {
+        let mut valid = Vec::new();
+
+        for (index, (row, errors)) in self.rows.iter().zip(self.errors).enumerate() {
+            if !errors.is_empty() {
+                report.errors.push(ImportRowError {
+                    entity: self.entity,
+                    row: index + 1,
+                    external_id: row.external_id().to_string(),
+                    errors,
+                });
+                continue;
+            }
+
+            let id = existing.get(row.external_id()).map(|item| item.id);
+            match id {
+                Some(_) => report.updated.add(self.entity),
+                None => report.created.add(self.entity),
+            }
+            valid.push((id, row.clone()));
+        }
+
+        valid
+    }
+}
+
+/// Referências de um vídeo a outra entidade: precisam estar entre as linhas
+/// válidas do arquivo ou já existir no banco.
+struct References<'a> {
+    label: &'static str,
+    valid: HashSet<&'a str>,
+    in_file: HashSet<&'a str>,
+    existing: HashMap<String, ExternalItem>,
+}
+
+impl References<'_> {
+    fn check(&self, external_id: &str) -> Option<String> {
+        if self.valid.contains(external_id) {
+            return None;
+        }
+        if self.in_file.contains(external_id) {
+            return Some(format!(
+                "{} '{}' tem erros no arquivo",
+                self.label, external_id
+            ));
+        }
+        match self.existing.get(external_id) {
+            Some(item) if item.trashed => {
+                Some(format!("{} '{}' está na lixeira", self.label, external_id))
+            }
+            Some(_) => None,
+            None => Some(format!("{} '{}' não encontrado", self.label, external_id)),
+        }
+    }
+
+    fn check_list(&self, field: &str, external_ids: &[String], errors: &mut Vec<String>) {
+        let mut seen = HashSet::new();
+        for external_id in external_ids {
+            if !seen.insert(external_id.as_str()) {
+                errors.push(format!("{}: '{}' repetido", field, external_id));
+            } else if let Some(err) = self.check(external_id) {
+                errors.push(format!("{}: {}", field, err));
+            }
+        }
+    }
+
+    /// Ids já gravados das referências que não estão sendo importadas.
+    fn existing_ids(&self) -> HashMap<String, i32> {
+        self.existing
+            .iter()
+            .filter(|(external_id, item)| {
+                !item.trashed && !self.valid.contains(external_id.as_str())
+            })
+            .map(|(external_id, item)| (external_id.clone(), item.id))
+            .collect()
+    }
+}
+
+fn referenced_ids<'a>(
+    videos: &'a [VideoRow],
+    pick: impl Fn(&'a VideoRow) -> Vec<&'a String>,
+) -> Vec<String> {
+    let unique: HashSet<&String> = videos.iter().flat_map(pick).collect();
+    unique.into_iter().cloned().collect()
+}
+
+async fn existing_with_references(
+    state: &AppState,
+    entity: CatalogEntityEnum,
+    mut external_ids: Vec<String>,
+    referenced: Vec<String>,
+) -> Result<HashMap<String, ExternalItem>, DbErr> {
+    external_ids.extend(referenced);
+    external_ids.sort();
+    external_ids.dedup();
+
+    state
+        .catalog_repo
+        .find_by_external_ids(entity, &external_ids)
+        .await
+}
+
+/// Valida o documento linha a linha e, fora do modo de simulação, grava as
+/// linhas válidas numa única transação. Linhas com erro (ou que dependem de
+/// uma linha com erro) ficam de fora e vão para o relatório.
+pub async fn run(
+    state: &AppState,
+    document: &CatalogDocument,
+    dry_run: bool,
+) -> Result<ImportReport, DbErr> {
+    let mut report = ImportReport {
+        dry_run,
+        ..Default::default()
+    };
+
+    let mut categories = Checked::new(CatalogEntityEnum::CATEGORY, &document.categories);
+    let actors = Checked::new(CatalogEntityEnum::ACTOR, &document.actors);
+    let directors = Checked::new(CatalogEntityEnum::DIRECTOR, &document.directors);
+    let mut series = Checked::new(CatalogEntityEnum::SERIE, &document.series);
+    let mut videos = Checked::new(CatalogEntityEnum::VIDEO, &document.videos);
+
+    let mut existing_categories = existing_with_references(
+        state,
+        CatalogEntityEnum::CATEGORY,
+        categories.external_ids(),
+        referenced_ids(&document.videos, |v| {
+            v.categories.iter().flatten().collect()
+        }),
+    )
+    .await?;
+    let existing_actors = existing_with_references(
+        state,
+        CatalogEntityEnum::ACTOR,
+        actors.external_ids(),
+        referenced_ids(&document.videos, |v| {
+            v.cast
+                .iter()
+                .flatten()
+                .map(|member| &member.actor_external_id)
+                .collect()
+        }),
+    )
+    .await?;
+    let existing_directors = existing_with_references(
+        state,
+        CatalogEntityEnum::DIRECTOR,
+        directors.external_ids(),
+        referenced_ids(&document.videos, |v| v.directors.iter().flatten().collect()),
+    )
+    .await?;
+    let existing_series = existing_with_references(
+        state,
+        CatalogEntityEnum::SERIE,
+        series.external_ids(),
+        referenced_ids(&document.videos, |v| v.serie_external_id.iter().collect()),
+    )
+    .await?;
+    let existing_videos = state
+        .catalog_repo
+        .find_by_external_ids(CatalogEntityEnum::VIDEO, &videos.external_ids())
+        .await?;
+
+    series.reject_trashed(&existing_series);
+    videos.reject_trashed(&existing_videos);
+
+    // o nome da categoria é único: uma categoria sem external_id com o mesmo
+    // nome é adotada pela linha; com outro external_id, é conflito
+    let names: Vec<String> = document.categories.iter().map(|c| c.name.clone()).collect();
+    let by_name: HashMap<String, _> = state
+        .catalog_repo
+        .find_categories_by_names(&names)
+        .await?
+        .into_iter()
+        .map(|category| (category.name.to_lowercase(), category))
+        .collect();
+    let mut seen_names: HashMap<String, usize> = HashMap::new();
+    for (index, (row, errors)) in document
+        .categories
+        .iter()
+        .zip(categories.errors.iter_mut())
+        .enumerate()
+    {
+        let name = row.name.to_lowercase();
+        if let Some(first) = seen_names.get(&name) {
+            errors.push(format!("name: '{}' repete a linha {}", row.name, first + 1));
+            continue;
+        }
+        seen_names.insert(name.clone(), index);
+
+        let Some(category) = by_name.get(&name) else {
+            continue;
+        };
+        match (
+            &category.external_id,
+            existing_categories.get(&row.external_id),
+        ) {
+            (_, Some(item)) if item.id == category.id => {}
+            (None, None) => {
+                existing_categories.insert(
+                    row.external_id.clone(),
+                    ExternalItem {
+                        id: category.id,
+                        trashed: false,
+                    },
+                );
+            }
+            _ => errors.push(format!(
+                "name: já existe a categoria '{}' (id {})",
+                category.name, category.id
+            )),
+        }
+    }
+
+    let category_refs = References {
+        label: "Categoria",
+        valid: categories.valid_ids(),
+        in_file: categories.all_ids(),
+        existing: existing_categories.clone(),
+    };
+    let actor_refs = References {
+        label: "Ator",
+        valid: actors.valid_ids(),
+        in_file: actors.all_ids(),
+        existing: existing_actors.clone(),
+    };
+    let director_refs = References {
+        label: "Diretor",
+        valid: directors.valid_ids(),
+        in_file: directors.all_ids(),
+        existing: existing_directors.clone(),
+    };
+    let serie_refs = References {
+        label: "Série",
+        valid: series.valid_ids(),
+        in_file: series.all_ids(),
+        existing: existing_series.clone(),
+    };
+
+    for (row, errors) in document.videos.iter().zip(videos.errors.iter_mut()) {
+        if let Some(serie) = &row.serie_external_id {
+            if let Some(err) = serie_refs.check(serie) {
+                errors.push(format!("serie_external_id: {}", err));
+            }
+        }
+        if let Some(ids) = &row.categories {
+            category_refs.check_list("categories", ids, errors);
+        }
+        if let Some(cast) = &row.cast {
+            let ids: Vec<String> = cast.iter().map(|m| m.actor_external_id.clone()).collect();
+            actor_refs.check_list("cast", &ids, errors);
+        }
+        if let Some(ids) = &row.directors {
+            director_refs.check_list("directors", ids, errors);
+        }
+    }
+
+    // posição do episódio: única no arquivo e entre os episódios que já
+    // estão na série e não fazem parte da importação
+    let imported_videos = videos.valid_ids();
+    let serie_ids: Vec<i32> = existing_series
+        .values()
+        .filter(|item| !item.trashed)
+        .map(|item| item.id)
+        .collect();
+    let mut taken: HashMap<(i32, Option<i32>, i32), i32> = HashMap::new();
+    for episode in state.catalog_repo.find_episodes(&serie_ids).await? {
+        let imported = episode
+            .external_id
+            .as_deref()
+            .is_some_and(|external_id| imported_videos.contains(external_id));
+        if let (Some(series_id), Some(episode_number), false) =
+            (episode.series_id, episode.episode_number, imported)
+        {
+            taken.insert(
+                (series_id, episode.season_number, episode_number),
+                episode.id,
+            );
+        }
+    }
+
+    let mut positions: HashMap<(&str, Option<i32>, i32), usize> = HashMap::new();
+    for (index, (row, errors)) in document
+        .videos
+        .iter()
+        .zip(videos.errors.iter_mut())
+        .enumerate()
+    {
+        let (Some(serie), Some(episode_number)) = (&row.serie_external_id, row.episode_number)
+        else {
+            continue;
+        };
+        let label = episode_label(row.season_number, episode_number);
+
+        if let Some(first) = positions.get(&(serie.as_str(), row.season_number, episode_number)) {
+            errors.push(format!(
+                "episode_number: {} da série '{}' repete a linha {}",
+                label,
+                serie,
+                first + 1
+            ));
+            continue;
+        }
+        positions.insert((serie.as_str(), row.season_number, episode_number), index);
+
+        let series_id = existing_series.get(serie).map(|item| item.id);
+        if let Some(video_id) = series_id
+            .and_then(|series_id| taken.get(&(series_id, row.season_number, episode_number)))
+        {
+            errors.push(format!(
+                "episode_number: a série '{}' já tem o episódio {} (vídeo {})",
+                serie, label, video_id
+            ));
+        }
+    }
+
+    let mut references = HashMap::new();
+    references.insert(CatalogEntityEnum::CATEGORY, category_refs.existing_ids());
+    references.insert(CatalogEntityEnum::ACTOR, actor_refs.existing_ids());
+    references.insert(CatalogEntityEnum::DIRECTOR, director_refs.existing_ids());
+    references.insert(CatalogEntityEnum::SERIE, serie_refs.existing_ids());
+
+    let plan = CatalogImportPlan {
+        categories: categories.finish(&existing_categories, &mut report),
+        actors: actors.finish(&existing_actors, &mut report),
+        directors: directors.finish(&existing_directors, &mut report),
+        series: series.finish(&existing_series, &mut report),
+        videos: videos.finish(&existing_videos, &mut report),
+        references,
+    };
+
+    if !dry_run {
+        state.catalog_repo.apply(plan).await?;
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,13 @@
+//! Importação e exportação do catálogo (categorias, atores, diretores,
+//! séries e vídeos com seus créditos) em JSON ou CSV. Cada item é
+//! identificado pelo `external_id`, o que permite repetir a importação e
+//! levar dados de um ambiente para outro.
+
+pub mod csv;
+pub mod dto;
+pub mod export;
+pub mod import;
+pub mod rows;
+
+/// Tamanho máximo do arquivo aceito na importação pela API.
+pub const MAX_IMPORT_SIZE: usize = 20 * 1024 * 1024;
@@ -0,0 +1,390 @@
+use chrono::{NaiveDate, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+use crate::modules::{
+    catalog::dto::{
+        create_serie_input_dto::CreateSerieInputDTO,
+        create_video_request_dto::CreateVideoRequestDTO,
+    },
+    validation::validate_release_year::validate_release_year,
+};
+
+/// Catálogo completo no formato JSON. Entidades ausentes são tratadas como
+/// listas vazias.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CatalogDocument {
+    #[serde(default)]
+    pub categories: Vec<CategoryRow>,
+    #[serde(default)]
+    pub actors: Vec<PersonRow>,
+    #[serde(default)]
+    pub directors: Vec<PersonRow>,
+    #[serde(default)]
+    pub series: Vec<SerieRow>,
+    #[serde(default)]
+    pub videos: Vec<VideoRow>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct CategoryRow {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "O external_id deve ter entre 1 e 100 caracteres"
+    ))]
+    pub external_id: String,
+
+    #[validate(length(
+        min = 2,
+        max = 100,
+        message = "O nome deve ter entre 2 e 100 caracteres"
+    ))]
+    pub name: String,
+
+    #[validate(length(max = 500, message = "A descrição não pode ter mais de 500 caracteres"))]
+    pub description: Option<String>,
+}
+
+/// Ator ou diretor.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct PersonRow {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "O external_id deve ter entre 1 e 100 caracteres"
+    ))]
+    pub external_id: String,
+
+    #[validate(length(
+        min = 2,
+        max = 150,
+        message = "O nome deve ter entre 2 e 150 caracteres"
+    ))]
+    pub name: String,
+
+    #[validate(length(
+        max = 2000,
+        message = "A biografia não pode ter mais de 2000 caracteres"
+    ))]
+    pub biography: Option<String>,
+
+    pub birth_date: Option<NaiveDate>,
+
+    #[validate(url(message = "A foto deve ser uma URL válida"))]
+    pub profile_picture_url: Option<String>,
+}
+
+/// Os campos da série são validados pelas mesmas regras de
+/// `CreateSerieInputDTO`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct SerieRow {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "O external_id deve ter entre 1 e 100 caracteres"
+    ))]
+    pub external_id: String,
+
+    pub title: String,
+
+    pub description: Option<String>,
+
+    pub release_year: Option<i32>,
+
+    pub thumbnail_url: Option<String>,
+
+    #[serde(default)]
+    pub is_featured: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct CastRow {
+    #[validate(length(min = 1, message = "Informe o external_id do ator"))]
+    pub actor_external_id: String,
+
+    #[validate(length(max = 200, message = "O papel não pode ter mais de 200 caracteres"))]
+    pub role_name: Option<String>,
+
+    #[serde(default)]
+    pub is_lead: bool,
+}
+
+/// Vídeo avulso ou, com `serie_external_id`, episódio de uma série. Listas
+/// de créditos ausentes mantêm os vínculos atuais; listas vazias os removem.
+/// Os campos do vídeo são validados pelas mesmas regras de
+/// `CreateVideoRequestDTO`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct VideoRow {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "O external_id deve ter entre 1 e 100 caracteres"
+    ))]
+    pub external_id: String,
+
+    pub serie_external_id: Option<String>,
+
+    pub season_number: Option<i32>,
+
+    pub episode_number: Option<i32>,
+
+    pub title: String,
+
+    pub description: Option<String>,
+
+    pub duration_seconds: Option<i32>,
+
+    pub release_year: Option<i32>,
+
+    pub thumbnail_url: Option<String>,
+
+    pub trailer_url: Option<String>,
+
+    #[serde(default)]
+    pub is_available: bool,
+
+    pub categories: Option<Vec<String>>,
+
+    #[validate(nested)]
+    pub cast: Option<Vec<CastRow>>,
+
+    pub directors: Option<Vec<String>>,
+}
+
+/// Linha de qualquer entidade do catálogo.
+pub trait CatalogRow: Validate {
+    fn external_id(&self) -> &str;
+
+    /// Erros do DTO de criação equivalente da API, quando houver.
+    fn dto_errors(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Regras além das anotações do `validator`.
+    fn extra_errors(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Todas as mensagens de erro da linha, no formato "campo: mensagem".
+    fn errors(&self) -> Vec<String> {
+        let mut messages = self.dto_errors();
+        if let Err(errors) = self.validate() {
+            collect_messages(&errors, "", &mut messages);
+        }
+        messages.sort();
+        messages.extend(self.extra_errors());
+        messages
+    }
+}
+
+impl CatalogRow for CategoryRow {
+    fn external_id(&self) -> &str {
+        &self.external_id
+    }
+}
+
+impl CatalogRow for PersonRow {
+    fn external_id(&self) -> &str {
+        &self.external_id
+    }
+
+    fn extra_errors(&self) -> Vec<String> {
+        match self.birth_date {
+            Some(date) if date > Utc::now().date_naive() => {
+                vec!["birth_date: A data de nascimento não pode estar no futuro".to_string()]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl CatalogRow for SerieRow {
+    fn external_id(&self) -> &str {
+        &self.external_id
+    }
+
+    fn dto_errors(&self) -> Vec<String> {
+        dto_errors::<CreateSerieInputDTO>(self)
+    }
+
+    fn extra_errors(&self) -> Vec<String> {
+        release_year_errors(&self.release_year)
+    }
+}
+
+impl CatalogRow for VideoRow {
+    fn external_id(&self) -> &str {
+        &self.external_id
+    }
+
+    fn dto_errors(&self) -> Vec<String> {
+        dto_errors::<CreateVideoRequestDTO>(self)
+    }
+
+    fn extra_errors(&self) -> Vec<String> {
+        let mut messages = release_year_errors(&self.release_year);
+
+        if self.serie_external_id.is_none()
+            && (self.season_number.is_some() || self.episode_number.is_some())
+        {
+            messages.push(
+                "serie_external_id: Temporada e episódio só valem para vídeos de uma série"
+                    .to_string(),
+            );
+        }
+
+        messages
+    }
+}
+
+/// Converte a linha no DTO da API (campos extras são ignorados) e devolve
+/// as mensagens da sua validação.
+fn dto_errors<D: DeserializeOwned + Validate>(row: &impl Serialize) -> Vec<String> {
+    let dto: D = match serde_json::to_value(row).and_then(serde_json::from_value) {
+        Ok(dto) => dto,
+        Err(err) => return vec![format!("Linha inválida: {}", err)],
+    };
+
+    let mut messages = Vec::new();
+    if let Err(errors) = dto.validate() {
+        collect_messages(&errors, "", &mut messages);
+    }
+    messages
+}
+
+fn release_year_errors(release_year: &Option<i32>) -> Vec<String> {
+    match validate_release_year(release_year) {
+        Ok(()) => Vec::new(),
+        Err(err) => vec![format!("release_year: {}", err.message.unwrap_or_default())],
+    }
+}
+
+fn collect_messages(errors: &ValidationErrors, prefix: &str, out: &mut Vec<String>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                for err in field_errors {
+                    let message = err
+                        .message
+                        .as_ref()
+                        .map(|m| m.to_string())
+                        .unwrap_or_else(|| err.code.to_string());
+                    out.push(format!("{}: {}", path, message));
+                }
+            }
+            ValidationErrorsKind::Struct(inner) => collect_messages(inner, &path, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, inner) in items {
+                    collect_messages(inner, &format!("{}[{}]", path, index), out);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video() -> VideoRow {
+        VideoRow {
+            external_id: "v-1".to_string(),
+            title: "Filme".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn serie() -> SerieRow {
+        SerieRow {
+            external_id: "s-1".to_string(),
+            title: "Série".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn valid_rows_have_no_errors() {
+        assert!(video().errors().is_empty());
+        assert!(serie().errors().is_empty());
+    }
+
+    #[test]
+    fn video_fields_follow_the_create_video_dto() {
+        let row = VideoRow {
+            title: "ab".to_string(),
+            duration_seconds: Some(30),
+            trailer_url: Some("não é url".to_string()),
+            serie_external_id: Some("s-1".to_string()),
+            episode_number: Some(0),
+            ..video()
+        };
+
+        assert_eq!(
+            row.errors(),
+            vec![
+                "duration_seconds: Deve ter no mínimo 1 minuto (60 segundos)",
+                "episode_number: O número do episódio deve ser positivo",
+                "title: O título deve ter pelo menos 3 caracteres",
+                "trailer_url: O trailer deve ser uma URL válida",
+            ]
+        );
+    }
+
+    #[test]
+    fn serie_fields_follow_the_create_serie_dto() {
+        let row = SerieRow {
+            title: "ab".to_string(),
+            thumbnail_url: Some("thumb".to_string()),
+            ..serie()
+        };
+
+        assert_eq!(
+            row.errors(),
+            vec![
+                "thumbnail_url: A thumbnail deve ser uma URL válida",
+                "title: O título da série deve ter pelo menos 3 caracteres",
+            ]
+        );
+    }
+
+    #[test]
+    fn row_rules_are_kept_alongside_the_dto() {
+        let row = VideoRow {
+            external_id: String::new(),
+            season_number: Some(1),
+            cast: Some(vec![CastRow::default()]),
+            release_year: Some(1700),
+            ..video()
+        };
+
+        let errors = row.errors();
+        assert!(errors
+            .contains(&"cast[0].actor_external_id: Informe o external_id do ator".to_string()));
+        assert!(errors
+            .contains(&"external_id: O external_id deve ter entre 1 e 100 caracteres".to_string()));
+        assert!(errors.iter().any(|e| e.starts_with("release_year: ")));
+        assert!(errors.iter().any(|e| e.starts_with("serie_external_id: ")));
+    }
+
+    #[test]
+    fn person_birth_date_cannot_be_in_the_future() {
+        let row = PersonRow {
+            external_id: "p-1".to_string(),
+            name: "Pessoa".to_string(),
+            birth_date: Some(Utc::now().date_naive() + chrono::Duration::days(1)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            row.errors(),
+            vec!["birth_date: A data de nascimento não pode estar no futuro"]
+        );
+    }
+}
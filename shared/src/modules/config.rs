@@ -1,4 +1,4 @@
-use axum::http::Result;
+use anyhow::{ensure, Result};
 use serde::Deserialize;
 use std::net::SocketAddr;
 
@@ -21,13 +21,52 @@ pub struct Config {
     pub azure_cast_rustqueue_port: u16,
     pub azure_cast_rusttable_port: u16,
     pub azure_cast_rust_video_container: String,
+    pub azure_cast_rust_image_container: String,
     pub azure_cast_rust_storage_url: String,
+
+    pub upload_block_size_bytes: u64,
+    pub upload_max_file_size_bytes: u64,
+    pub upload_session_expiry_hours: u64,
+
+    pub transcoder: String,
+    pub ffmpeg_path: String,
+    pub media_worker_tmp_dir: String,
+    pub media_worker_poll_interval_secs: u64,
+    pub transcode_job_timeout_minutes: u64,
+    pub transcode_retry_base_delay_secs: u64,
+
+    pub blob_gc_interval_hours: u64,
+    pub blob_gc_grace_period_hours: u64,
+    pub blob_gc_dry_run: bool,
+
+    pub trash_retention_days: u64,
+    pub trash_purge_interval_hours: u64,
+
+    pub release_scheduler_interval_secs: u64,
+
+    pub similarity_interval_hours: u64,
+
+    pub trending_interval_secs: u64,
+
+    pub event_relay_interval_secs: u64,
+
+    pub search_backend: String,
+    pub search_index_path: String,
 }
 
 impl Config {
     pub fn from_env() -> Result<Self> {
         dotenvy::dotenv().ok();
 
+        let upload_block_size_bytes = std::env::var("UPLOAD_BLOCK_SIZE_BYTES")
+            .unwrap_or_else(|_| "4194304".to_string())
+            .parse()
+            .unwrap_or(4 * 1024 * 1024);
+        ensure!(
+            upload_block_size_bytes > 0,
+            "UPLOAD_BLOCK_SIZE_BYTES deve ser maior que 0"
+        );
+
         Ok(Config {
             database_url: std::env::var("DATABASE_URL").unwrap_or_else(|_| "".to_string()),
             redis_url: std::env::var("REDIS_URL")
@@ -76,8 +115,84 @@ impl Config {
                 .unwrap_or(10002),
             azure_cast_rust_video_container: std::env::var("AZURE_CAST_RUST_VIDEO_CONTAINER")
                 .unwrap_or_else(|_| "video".to_string()),
+            azure_cast_rust_image_container: std::env::var("AZURE_CAST_RUST_IMAGE_CONTAINER")
+                .unwrap_or_else(|_| "image".to_string()),
             azure_cast_rust_storage_url: std::env::var("AZURE_CAST_RUST_STORAGE_URL")
                 .unwrap_or_else(|_| "http://0.0.0.0:10000/devstoreaccount1".to_string()),
+
+            upload_block_size_bytes,
+            upload_max_file_size_bytes: std::env::var("UPLOAD_MAX_FILE_SIZE_BYTES")
+                .unwrap_or_else(|_| "53687091200".to_string())
+                .parse()
+                .unwrap_or(50 * 1024 * 1024 * 1024),
+            upload_session_expiry_hours: std::env::var("UPLOAD_SESSION_EXPIRY_HOURS")
+                .unwrap_or_else(|_| "24".to_string())
+                .parse()
+                .unwrap_or(24),
+
+            transcoder: std::env::var("TRANSCODER").unwrap_or_else(|_| "ffmpeg".to_string()),
+            ffmpeg_path: std::env::var("FFMPEG_PATH").unwrap_or_else(|_| "ffmpeg".to_string()),
+            media_worker_tmp_dir: std::env::var("MEDIA_WORKER_TMP_DIR")
+                .unwrap_or_else(|_| std::env::temp_dir().to_string_lossy().to_string()),
+            media_worker_poll_interval_secs: std::env::var("MEDIA_WORKER_POLL_INTERVAL_SECS")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
+            transcode_job_timeout_minutes: std::env::var("TRANSCODE_JOB_TIMEOUT_MINUTES")
+                .unwrap_or_else(|_| "120".to_string())
+                .parse()
+                .unwrap_or(120),
+            transcode_retry_base_delay_secs: std::env::var("TRANSCODE_RETRY_BASE_DELAY_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+
+            blob_gc_interval_hours: std::env::var("BLOB_GC_INTERVAL_HOURS")
+                .unwrap_or_else(|_| "24".to_string())
+                .parse()
+                .unwrap_or(24),
+            blob_gc_grace_period_hours: std::env::var("BLOB_GC_GRACE_PERIOD_HOURS")
+                .unwrap_or_else(|_| "24".to_string())
+                .parse()
+                .unwrap_or(24),
+            blob_gc_dry_run: std::env::var("BLOB_GC_DRY_RUN")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .unwrap_or(false),
+
+            trash_retention_days: std::env::var("TRASH_RETENTION_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .unwrap_or(30),
+            trash_purge_interval_hours: std::env::var("TRASH_PURGE_INTERVAL_HOURS")
+                .unwrap_or_else(|_| "24".to_string())
+                .parse()
+                .unwrap_or(24),
+
+            release_scheduler_interval_secs: std::env::var("RELEASE_SCHEDULER_INTERVAL_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+
+            similarity_interval_hours: std::env::var("SIMILARITY_INTERVAL_HOURS")
+                .unwrap_or_else(|_| "24".to_string())
+                .parse()
+                .unwrap_or(24),
+
+            trending_interval_secs: std::env::var("TRENDING_INTERVAL_SECS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .unwrap_or(300),
+
+            event_relay_interval_secs: std::env::var("EVENT_RELAY_INTERVAL_SECS")
+                .unwrap_or_else(|_| "2".to_string())
+                .parse()
+                .unwrap_or(2),
+
+            search_backend: std::env::var("SEARCH_BACKEND")
+                .unwrap_or_else(|_| "postgres".to_string()),
+            search_index_path: std::env::var("SEARCH_INDEX_PATH")
+                .unwrap_or_else(|_| "./data/search-index".to_string()),
         })
     }
 
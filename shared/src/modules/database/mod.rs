@@ -1,3 +1,5 @@
 pub mod connection;
 pub mod repositories;
 pub mod schema;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
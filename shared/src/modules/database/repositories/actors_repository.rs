@@ -0,0 +1,142 @@
+use crate::modules::database::schema::actors::Model as ActorModel;
+use crate::modules::database::schema::video_actors::Model as VideoActorModel;
+use crate::modules::database::schema::{actors, video_actors};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{DatabaseConnection, DbErr, QueryOrder, QuerySelect, Set};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateActorRequest {
+    pub name: String,
+    pub biography: Option<String>,
+    pub birth_date: Option<NaiveDateTime>,
+    pub profile_picture_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct UpdateActorRequest {
+    pub name: Option<String>,
+    pub biography: Option<String>,
+    pub birth_date: Option<NaiveDateTime>,
+    pub profile_picture_url: Option<String>,
+}
+
+pub struct ActorsRepository {
+    db: DatabaseConnection,
+}
+
+impl ActorsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(&self, request: CreateActorRequest) -> Result<ActorModel, DbErr> {
+        let actor = actors::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            name: Set(request.name),
+            biography: Set(request.biography),
+            birth_date: Set(request.birth_date),
+            profile_picture_url: Set(request.profile_picture_url),
+            created_at: Set(Utc::now().naive_utc()),
+            external_id: sea_orm::ActiveValue::NotSet,
+        };
+
+        actor.insert(&self.db).await
+    }
+
+    pub async fn find_by_id(&self, actor_id: i32) -> Result<Option<ActorModel>, DbErr> {
+        actors::Entity::find_by_id(actor_id).one(&self.db).await
+    }
+
+    pub async fn find_by_ids(&self, actor_ids: &[i32]) -> Result<Vec<ActorModel>, DbErr> {
+        if actor_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        actors::Entity::find()
+            .filter(actors::Column::Id.is_in(actor_ids.to_vec()))
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn find_many(
+        &self,
+        name: Option<&str>,
+        skip: Option<u64>,
+        take: Option<u64>,
+    ) -> Result<Vec<ActorModel>, DbErr> {
+        let mut query = actors::Entity::find().order_by_asc(actors::Column::Name);
+
+        if let Some(name) = name {
+            query = query.filter(actors::Column::Name.contains(name));
+        }
+        if let Some(skip) = skip {
+            query = query.offset(skip);
+        }
+        if let Some(take) = take {
+            query = query.limit(take);
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Elenco de cada vídeo informado com o papel de cada ator, protagonistas
+    /// primeiro.
+    pub async fn find_by_videos(
+        &self,
+        video_ids: &[i32],
+    ) -> Result<Vec<(VideoActorModel, ActorModel)>, DbErr> {
+        if video_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = video_actors::Entity::find()
+            .find_also_related(actors::Entity)
+            .filter(video_actors::Column::VideoId.is_in(video_ids.to_vec()))
+            .order_by_desc(video_actors::Column::IsLead)
+            .order_by_asc(video_actors::Column::Id)
+            .all(&self.db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(role, actor)| actor.map(|actor| (role, actor)))
+            .collect())
+    }
+
+    pub async fn update(
+        &self,
+        actor_id: i32,
+        request: UpdateActorRequest,
+    ) -> Result<ActorModel, DbErr> {
+        let mut active_model = actors::ActiveModel {
+            id: Set(actor_id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            active_model.name = Set(name);
+        }
+        if request.biography.is_some() {
+            active_model.biography = Set(request.biography);
+        }
+        if request.birth_date.is_some() {
+            active_model.birth_date = Set(request.birth_date);
+        }
+        if request.profile_picture_url.is_some() {
+            active_model.profile_picture_url = Set(request.profile_picture_url);
+        }
+
+        active_model.update(&self.db).await
+    }
+
+    /// Remove o ator; as participações em vídeos vão junto por cascata.
+    pub async fn delete(&self, actor_id: i32) -> Result<bool, DbErr> {
+        let result = actors::Entity::delete_by_id(actor_id)
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
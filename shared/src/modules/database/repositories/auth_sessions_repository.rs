@@ -0,0 +1,159 @@
+use crate::modules::database::schema::auth_sessions;
+use crate::modules::database::schema::auth_sessions::Model as AuthSessionModel;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{DatabaseConnection, DbErr, Set};
+use uuid::Uuid;
+
+/// Sessões de login. O id vai nos tokens e identifica o dispositivo nas
+/// telas simultâneas; revogar a sessão invalida o refresh token dela.
+pub struct AuthSessionsRepository {
+    db: DatabaseConnection,
+}
+
+impl AuthSessionsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: &str,
+        expires_at: NaiveDateTime,
+    ) -> Result<AuthSessionModel, DbErr> {
+        let now = Utc::now().naive_utc();
+        let session = auth_sessions::ActiveModel {
+            id: Set(Uuid::new_v4().to_string()),
+            user_id: Set(user_id.to_string()),
+            expires_at: Set(expires_at),
+            revoked_at: Set(None),
+            last_used_at: Set(now),
+            created_at: Set(now),
+        };
+
+        session.insert(&self.db).await
+    }
+
+    /// Sessão do usuário que ainda não expirou nem foi revogada.
+    pub async fn find_active(
+        &self,
+        user_id: &str,
+        session_id: &str,
+    ) -> Result<Option<AuthSessionModel>, DbErr> {
+        auth_sessions::Entity::find_by_id(session_id.to_string())
+            .filter(auth_sessions::Column::UserId.eq(user_id))
+            .filter(auth_sessions::Column::RevokedAt.is_null())
+            .filter(auth_sessions::Column::ExpiresAt.gt(Utc::now().naive_utc()))
+            .one(&self.db)
+            .await
+    }
+
+    /// Registra o uso da sessão no refresh e estende a validade junto com o novo token.
+    pub async fn renew(&self, session_id: &str, expires_at: NaiveDateTime) -> Result<(), DbErr> {
+        auth_sessions::Entity::update_many()
+            .col_expr(auth_sessions::Column::ExpiresAt, Expr::value(expires_at))
+            .col_expr(
+                auth_sessions::Column::LastUsedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(auth_sessions::Column::Id.eq(session_id))
+            .filter(auth_sessions::Column::RevokedAt.is_null())
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Revoga a sessão do usuário. Retorna `false` se ela não existe, é de
+    /// outra conta ou já tinha sido revogada.
+    pub async fn revoke(&self, user_id: &str, session_id: &str) -> Result<bool, DbErr> {
+        let result = auth_sessions::Entity::update_many()
+            .col_expr(
+                auth_sessions::Column::RevokedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(auth_sessions::Column::Id.eq(session_id))
+            .filter(auth_sessions::Column::UserId.eq(user_id))
+            .filter(auth_sessions::Column::RevokedAt.is_null())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+/// Os testes com banco ficam com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::database::test_support::test_db;
+    use chrono::Duration;
+    use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn revoked_or_foreign_sessions_are_not_active() {
+        let db = test_db().await;
+        let repo = AuthSessionsRepository::new(db.clone());
+
+        let user_id = Uuid::new_v4().to_string();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "INSERT INTO users (id, email, password_hash, name, role, created_at, updated_at)
+             VALUES ($1, $1 || '@sessions.test', 'x', 'Sessões', 'test', now(), now())",
+            vec![user_id.clone().into()],
+        ))
+        .await
+        .unwrap();
+
+        let expires_at = Utc::now().naive_utc() + Duration::days(1);
+        let session = repo.create(&user_id, expires_at).await.unwrap();
+        let expired = repo
+            .create(&user_id, Utc::now().naive_utc() - Duration::minutes(1))
+            .await
+            .unwrap();
+
+        assert!(repo
+            .find_active(&user_id, &session.id)
+            .await
+            .unwrap()
+            .is_some());
+        assert!(repo
+            .find_active(&user_id, &expired.id)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(repo
+            .find_active("outro", &session.id)
+            .await
+            .unwrap()
+            .is_none());
+
+        // só o dono revoga, e uma vez
+        assert!(!repo.revoke("outro", &session.id).await.unwrap());
+        assert!(repo.revoke(&user_id, &session.id).await.unwrap());
+        assert!(!repo.revoke(&user_id, &session.id).await.unwrap());
+        assert!(repo
+            .find_active(&user_id, &session.id)
+            .await
+            .unwrap()
+            .is_none());
+
+        // renovar não reabre uma sessão revogada
+        repo.renew(&session.id, expires_at).await.unwrap();
+        assert!(repo
+            .find_active(&user_id, &session.id)
+            .await
+            .unwrap()
+            .is_none());
+
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM users WHERE id = $1",
+            vec![user_id.into()],
+        ))
+        .await
+        .unwrap();
+    }
+}
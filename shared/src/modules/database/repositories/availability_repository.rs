@@ -0,0 +1,376 @@
+use crate::enums::availability_owner_enum::AvailabilityOwnerEnum;
+use crate::enums::maturity_rating_enum::MaturityRatingEnum;
+use crate::enums::release_state_enum::ReleaseStateEnum;
+use crate::modules::database::repositories::events_repository::EventsRepository;
+use crate::modules::database::schema::availability_windows::Model as AvailabilityWindowModel;
+use crate::modules::database::schema::series::Model as SerieModel;
+use crate::modules::database::schema::videos::Model as VideoModel;
+use crate::modules::database::schema::{availability_windows, series, videos};
+use crate::modules::events::DomainEvent;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveValue::NotSet, DatabaseConnection, DbBackend, DbErr, QueryOrder, Set, Statement,
+    TransactionTrait,
+};
+
+/// Estado esperado no instante `$1`, com a mesma regra de [`ReleaseStateEnum::at`].
+const RELEASE_STATE_SQL: &str = "CASE WHEN available_from > $1 THEN 'SCHEDULED' \
+     WHEN available_until <= $1 THEN 'EXPIRED' ELSE 'LIVE' END";
+
+/// Janela efetiva de `{item}` (a da região `$2`, se houver, senão a global)
+/// contém o instante `$1`. Limites nulos são abertos.
+fn live_window_sql(item: &str, window: &str) -> String {
+    format!(
+        "COALESCE(CASE WHEN {window}.id IS NULL THEN {item}.available_from \
+         ELSE {window}.available_from END <= $1, TRUE) \
+         AND COALESCE(CASE WHEN {window}.id IS NULL THEN {item}.available_until \
+         ELSE {window}.available_until END > $1, TRUE)"
+    )
+}
+
+#[derive(Debug, Clone)]
+pub struct RegionWindow {
+    /// Código ISO 3166-1 alfa-2, em maiúsculas.
+    pub region: String,
+    pub available_from: Option<NaiveDateTime>,
+    pub available_until: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SetAvailabilityRequest {
+    pub available_from: Option<NaiveDateTime>,
+    pub available_until: Option<NaiveDateTime>,
+    pub regions: Vec<RegionWindow>,
+}
+
+/// Mudança de estado gravada pelo agendador. `region` vem preenchida quando a
+/// mudança é de uma janela regional.
+#[derive(Debug, Clone)]
+pub struct ReleaseTransition {
+    pub owner: AvailabilityOwnerEnum,
+    pub owner_id: i32,
+    pub region: Option<String>,
+    pub release_state: ReleaseStateEnum,
+}
+
+pub struct AvailabilityRepository {
+    db: DatabaseConnection,
+}
+
+impl AvailabilityRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Janelas regionais do item, por região.
+    pub async fn find_by_owner(
+        &self,
+        owner: AvailabilityOwnerEnum,
+        owner_id: i32,
+    ) -> Result<Vec<AvailabilityWindowModel>, DbErr> {
+        availability_windows::Entity::find()
+            .filter(availability_windows::Column::OwnerType.eq(owner.as_str()))
+            .filter(availability_windows::Column::OwnerId.eq(owner_id))
+            .order_by_asc(availability_windows::Column::Region)
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn find_for_region(
+        &self,
+        owner: AvailabilityOwnerEnum,
+        owner_id: i32,
+        region: &str,
+    ) -> Result<Option<AvailabilityWindowModel>, DbErr> {
+        availability_windows::Entity::find()
+            .filter(availability_windows::Column::OwnerType.eq(owner.as_str()))
+            .filter(availability_windows::Column::OwnerId.eq(owner_id))
+            .filter(availability_windows::Column::Region.eq(region))
+            .one(&self.db)
+            .await
+    }
+
+    /// Troca a janela global e todas as regionais do item. Retorna `false` se
+    /// o item não existir ou estiver na lixeira.
+    ///
+    /// O `release_state` não muda aqui: o agendador o acerta na próxima rodada
+    /// e é ele quem avisa sobre o lançamento.
+    pub async fn replace(
+        &self,
+        owner: AvailabilityOwnerEnum,
+        owner_id: i32,
+        request: SetAvailabilityRequest,
+    ) -> Result<bool, DbErr> {
+        let txn = self.db.begin().await?;
+        let now = Utc::now().naive_utc();
+
+        let updated = match owner {
+            AvailabilityOwnerEnum::VIDEO => {
+                videos::Entity::update_many()
+                    .col_expr(
+                        videos::Column::AvailableFrom,
+                        Expr::value(request.available_from),
+                    )
+                    .col_expr(
+                        videos::Column::AvailableUntil,
+                        Expr::value(request.available_until),
+                    )
+                    .col_expr(videos::Column::UpdatedAt, Expr::value(now))
+                    .filter(videos::Column::Id.eq(owner_id))
+                    .filter(videos::Column::DeletedAt.is_null())
+                    .exec(&txn)
+                    .await?
+            }
+            AvailabilityOwnerEnum::SERIE => {
+                series::Entity::update_many()
+                    .col_expr(
+                        series::Column::AvailableFrom,
+                        Expr::value(request.available_from),
+                    )
+                    .col_expr(
+                        series::Column::AvailableUntil,
+                        Expr::value(request.available_until),
+                    )
+                    .col_expr(series::Column::UpdatedAt, Expr::value(now))
+                    .filter(series::Column::Id.eq(owner_id))
+                    .filter(series::Column::DeletedAt.is_null())
+                    .exec(&txn)
+                    .await?
+            }
+        };
+
+        if updated.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(false);
+        }
+
+        delete_windows(&txn, owner, owner_id).await?;
+
+        if !request.regions.is_empty() {
+            let windows =
+                request
+                    .regions
+                    .into_iter()
+                    .map(|window| availability_windows::ActiveModel {
+                        id: NotSet,
+                        owner_type: Set(owner.as_str().to_string()),
+                        owner_id: Set(owner_id),
+                        region: Set(window.region),
+                        available_from: Set(window.available_from),
+                        available_until: Set(window.available_until),
+                        release_state: NotSet,
+                        created_at: Set(now),
+                    });
+            availability_windows::Entity::insert_many(windows)
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Remove as janelas regionais de um item, usado quando ele é apagado.
+    pub async fn delete_by_owner(
+        &self,
+        owner: AvailabilityOwnerEnum,
+        owner_id: i32,
+    ) -> Result<(), DbErr> {
+        delete_windows(&self.db, owner, owner_id).await
+    }
+
+    /// Grava o estado de lançamento de cada janela que cruzou um limite até
+    /// `now` e devolve as mudanças. Itens na lixeira ficam de fora. Cada
+    /// janela que abriu gera um `VideoPublished` ou `SeriePublished` na mesma
+    /// transação.
+    pub async fn apply_release_states(
+        &self,
+        now: NaiveDateTime,
+    ) -> Result<Vec<ReleaseTransition>, DbErr> {
+        let txn = self.db.begin().await?;
+        let mut transitions = Vec::new();
+
+        for owner in [AvailabilityOwnerEnum::SERIE, AvailabilityOwnerEnum::VIDEO] {
+            let table = match owner {
+                AvailabilityOwnerEnum::VIDEO => "videos",
+                AvailabilityOwnerEnum::SERIE => "series",
+            };
+            let rows = txn
+                .query_all(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        "UPDATE {table} SET release_state = {RELEASE_STATE_SQL} \
+                         WHERE deleted_at IS NULL AND release_state <> {RELEASE_STATE_SQL} \
+                         RETURNING id, release_state"
+                    ),
+                    [now.into()],
+                ))
+                .await?;
+
+            for row in rows {
+                transitions.push(ReleaseTransition {
+                    owner,
+                    owner_id: row.try_get("", "id")?,
+                    region: None,
+                    release_state: parse_state(row.try_get("", "release_state")?)?,
+                });
+            }
+        }
+
+        let rows = txn
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                format!(
+                    "UPDATE availability_windows SET release_state = {RELEASE_STATE_SQL} \
+                     WHERE release_state <> {RELEASE_STATE_SQL} \
+                     RETURNING owner_type, owner_id, region, release_state"
+                ),
+                [now.into()],
+            ))
+            .await?;
+
+        for row in rows {
+            let owner_type: String = row.try_get("", "owner_type")?;
+            transitions.push(ReleaseTransition {
+                owner: AvailabilityOwnerEnum::try_from(owner_type.as_str())
+                    .map_err(DbErr::Custom)?,
+                owner_id: row.try_get("", "owner_id")?,
+                region: Some(row.try_get("", "region")?),
+                release_state: parse_state(row.try_get("", "release_state")?)?,
+            });
+        }
+
+        let events: Vec<DomainEvent> = transitions
+            .iter()
+            .filter(|t| t.release_state == ReleaseStateEnum::LIVE)
+            .map(|t| match t.owner {
+                AvailabilityOwnerEnum::VIDEO => DomainEvent::VideoPublished {
+                    video_id: t.owner_id,
+                    region: t.region.clone(),
+                },
+                AvailabilityOwnerEnum::SERIE => DomainEvent::SeriePublished {
+                    serie_id: t.owner_id,
+                    region: t.region.clone(),
+                },
+            })
+            .collect();
+        EventsRepository::record_many(&txn, &events).await?;
+
+        txn.commit().await?;
+
+        Ok(transitions)
+    }
+
+    /// Séries no ar em `at` para a região (ou pela janela global, sem região).
+    pub async fn find_live_series(
+        &self,
+        at: NaiveDateTime,
+        region: Option<&str>,
+    ) -> Result<Vec<SerieModel>, DbErr> {
+        let (from, live) = live_series_sql(None);
+
+        series::Entity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                format!("SELECT s.* FROM {from} WHERE {live} ORDER BY s.title, s.id"),
+                [at.into(), region.map(str::to_string).into()],
+            ))
+            .all(&self.db)
+            .await
+    }
+
+    /// Vídeos no ar em `at` para a região. Episódios dependem também da janela
+    /// da série.
+    pub async fn find_live_videos(
+        &self,
+        at: NaiveDateTime,
+        region: Option<&str>,
+    ) -> Result<Vec<VideoModel>, DbErr> {
+        let (from, live) = live_videos_sql(None);
+
+        videos::Entity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                format!("SELECT v.* FROM {from} WHERE {live} ORDER BY v.title, v.id"),
+                [at.into(), region.map(str::to_string).into()],
+            ))
+            .all(&self.db)
+            .await
+    }
+}
+
+/// Restringe `{rating}` às classificações até `max`. Títulos sem classificação
+/// só aparecem sem restrição. Os literais vêm do enum, não do cliente.
+fn rating_sql(rating: &str, max: Option<MaturityRatingEnum>) -> String {
+    match max {
+        None => String::new(),
+        Some(max) => {
+            let allowed = max
+                .up_to()
+                .iter()
+                .map(|r| format!("'{}'", r.as_str()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(" AND {rating} IN ({allowed})")
+        }
+    }
+}
+
+/// `FROM` (com o alias `s`) e condição das séries no ar no instante `$1` para a
+/// região `$2`, até a classificação `max_rating` (sem limite com `None`).
+pub(crate) fn live_series_sql(max_rating: Option<MaturityRatingEnum>) -> (String, String) {
+    let from = "series s \
+         LEFT JOIN availability_windows sw ON sw.owner_type = 'SERIE' \
+         AND sw.owner_id = s.id AND sw.region = $2"
+        .to_string();
+    let live = format!(
+        "s.deleted_at IS NULL AND {}{}",
+        live_window_sql("s", "sw"),
+        rating_sql("s.maturity_rating", max_rating)
+    );
+
+    (from, live)
+}
+
+/// `FROM` (com o alias `v`) e condição dos vídeos no ar no instante `$1` para a
+/// região `$2`, para as consultas que listam o catálogo do espectador. Episódios
+/// sem classificação própria herdam a da série.
+pub(crate) fn live_videos_sql(max_rating: Option<MaturityRatingEnum>) -> (String, String) {
+    let from = "videos v \
+         LEFT JOIN availability_windows vw ON vw.owner_type = 'VIDEO' \
+         AND vw.owner_id = v.id AND vw.region = $2 \
+         LEFT JOIN series s ON s.id = v.series_id \
+         LEFT JOIN availability_windows sw ON sw.owner_type = 'SERIE' \
+         AND sw.owner_id = s.id AND sw.region = $2"
+        .to_string();
+    let live = format!(
+        "v.deleted_at IS NULL AND v.is_available AND {} \
+         AND (v.series_id IS NULL OR (s.deleted_at IS NULL AND {})){}",
+        live_window_sql("v", "vw"),
+        live_window_sql("s", "sw"),
+        rating_sql("COALESCE(v.maturity_rating, s.maturity_rating)", max_rating)
+    );
+
+    (from, live)
+}
+
+pub(crate) async fn delete_windows<C: ConnectionTrait>(
+    conn: &C,
+    owner: AvailabilityOwnerEnum,
+    owner_id: i32,
+) -> Result<(), DbErr> {
+    availability_windows::Entity::delete_many()
+        .filter(availability_windows::Column::OwnerType.eq(owner.as_str()))
+        .filter(availability_windows::Column::OwnerId.eq(owner_id))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+fn parse_state(value: String) -> Result<ReleaseStateEnum, DbErr> {
+    ReleaseStateEnum::try_from(value.as_str()).map_err(DbErr::Custom)
+}
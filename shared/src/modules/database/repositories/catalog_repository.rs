@@ -0,0 +1,422 @@
+use std::collections::HashMap;
+
+use crate::enums::catalog_entity_enum::CatalogEntityEnum;
+use crate::modules::catalog::rows::{CategoryRow, PersonRow, SerieRow, VideoRow};
+use crate::modules::database::repositories::videos_repository::{
+    replace_credits, SetVideoCreditsRequest, VideoCastMember,
+};
+use crate::modules::database::schema::actors::Model as ActorModel;
+use crate::modules::database::schema::categories::Model as CategoryModel;
+use crate::modules::database::schema::directors::Model as DirectorModel;
+use crate::modules::database::schema::series::Model as SerieModel;
+use crate::modules::database::schema::video_actors::Model as VideoActorModel;
+use crate::modules::database::schema::videos::Model as VideoModel;
+use crate::modules::database::schema::{
+    actors, categories, directors, series, video_actors, video_categories, video_directors, videos,
+};
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{ActiveValue::NotSet, DatabaseConnection, DbErr, QueryOrder, Set, TransactionTrait};
+
+/// Item do catálogo já gravado, encontrado pelo `external_id`.
+#[derive(Debug, Clone, Copy)]
+pub struct ExternalItem {
+    pub id: i32,
+    /// Séries e vídeos na lixeira; as demais entidades nunca estão.
+    pub trashed: bool,
+}
+
+/// Linhas já validadas, com o id do registro a atualizar (`None` cria um
+/// novo). `references` traz os ids existentes de tudo que os vídeos e
+/// episódios citam e não está sendo importado.
+#[derive(Debug, Default)]
+pub struct CatalogImportPlan {
+    pub categories: Vec<(Option<i32>, CategoryRow)>,
+    pub actors: Vec<(Option<i32>, PersonRow)>,
+    pub directors: Vec<(Option<i32>, PersonRow)>,
+    pub series: Vec<(Option<i32>, SerieRow)>,
+    pub videos: Vec<(Option<i32>, VideoRow)>,
+    pub references: HashMap<CatalogEntityEnum, HashMap<String, i32>>,
+}
+
+/// Catálogo fora da lixeira, com os vínculos dos vídeos.
+#[derive(Debug, Default)]
+pub struct CatalogSnapshot {
+    pub categories: Vec<CategoryModel>,
+    pub actors: Vec<ActorModel>,
+    pub directors: Vec<DirectorModel>,
+    pub series: Vec<SerieModel>,
+    pub videos: Vec<VideoModel>,
+    pub video_categories: Vec<(i32, i32)>,
+    pub video_actors: Vec<VideoActorModel>,
+    pub video_directors: Vec<(i32, i32)>,
+}
+
+pub struct CatalogRepository {
+    db: DatabaseConnection,
+}
+
+impl CatalogRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Itens da entidade com os `external_id` informados, incluindo os que
+    /// estão na lixeira.
+    pub async fn find_by_external_ids(
+        &self,
+        entity: CatalogEntityEnum,
+        external_ids: &[String],
+    ) -> Result<HashMap<String, ExternalItem>, DbErr> {
+        if external_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let ids = external_ids.iter().cloned();
+        let found: Vec<(Option<String>, i32, bool)> = match entity {
+            CatalogEntityEnum::CATEGORY => categories::Entity::find()
+                .filter(categories::Column::ExternalId.is_in(ids))
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|c| (c.external_id, c.id, false))
+                .collect(),
+            CatalogEntityEnum::ACTOR => actors::Entity::find()
+                .filter(actors::Column::ExternalId.is_in(ids))
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|a| (a.external_id, a.id, false))
+                .collect(),
+            CatalogEntityEnum::DIRECTOR => directors::Entity::find()
+                .filter(directors::Column::ExternalId.is_in(ids))
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|d| (d.external_id, d.id, false))
+                .collect(),
+            CatalogEntityEnum::SERIE => series::Entity::find()
+                .filter(series::Column::ExternalId.is_in(ids))
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|s| (s.external_id, s.id, s.deleted_at.is_some()))
+                .collect(),
+            CatalogEntityEnum::VIDEO => videos::Entity::find()
+                .filter(videos::Column::ExternalId.is_in(ids))
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|v| (v.external_id, v.id, v.deleted_at.is_some()))
+                .collect(),
+        };
+
+        Ok(found
+            .into_iter()
+            .filter_map(|(external_id, id, trashed)| {
+                external_id.map(|external_id| (external_id, ExternalItem { id, trashed }))
+            })
+            .collect())
+    }
+
+    /// Categorias com os nomes informados, sem diferenciar maiúsculas.
+    pub async fn find_categories_by_names(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<CategoryModel>, DbErr> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        categories::Entity::find()
+            .filter(
+                Expr::expr(Func::lower(Expr::col(categories::Column::Name)))
+                    .is_in(names.iter().map(|name| name.to_lowercase())),
+            )
+            .all(&self.db)
+            .await
+    }
+
+    /// Episódios fora da lixeira das séries informadas.
+    pub async fn find_episodes(&self, serie_ids: &[i32]) -> Result<Vec<VideoModel>, DbErr> {
+        if serie_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        videos::Entity::find()
+            .filter(videos::Column::SeriesId.is_in(serie_ids.iter().copied()))
+            .filter(videos::Column::DeletedAt.is_null())
+            .all(&self.db)
+            .await
+    }
+
+    /// Grava o plano numa única transação: entidades na ordem de
+    /// dependência e, por fim, os créditos dos vídeos.
+    pub async fn apply(&self, plan: CatalogImportPlan) -> Result<(), DbErr> {
+        let now = Utc::now().naive_utc();
+        let txn = self.db.begin().await?;
+        let mut references = plan.references;
+
+        for (id, row) in plan.categories {
+            let model = categories::ActiveModel {
+                id: id.map_or(NotSet, Set),
+                name: Set(row.name),
+                description: Set(row.description),
+                created_at: if id.is_some() { NotSet } else { Set(now) },
+                external_id: Set(Some(row.external_id.clone())),
+            };
+            let saved = match id {
+                Some(_) => model.update(&txn).await?,
+                None => model.insert(&txn).await?,
+            };
+            references
+                .entry(CatalogEntityEnum::CATEGORY)
+                .or_default()
+                .insert(row.external_id, saved.id);
+        }
+
+        for (id, row) in plan.actors {
+            let model = actors::ActiveModel {
+                id: id.map_or(NotSet, Set),
+                name: Set(row.name),
+                biography: Set(row.biography),
+                birth_date: Set(row.birth_date.map(|date| date.and_time(Default::default()))),
+                profile_picture_url: Set(row.profile_picture_url),
+                created_at: if id.is_some() { NotSet } else { Set(now) },
+                external_id: Set(Some(row.external_id.clone())),
+            };
+            let saved = match id {
+                Some(_) => model.update(&txn).await?,
+                None => model.insert(&txn).await?,
+            };
+            references
+                .entry(CatalogEntityEnum::ACTOR)
+                .or_default()
+                .insert(row.external_id, saved.id);
+        }
+
+        for (id, row) in plan.directors {
+            let model = directors::ActiveModel {
+                id: id.map_or(NotSet, Set),
+                name: Set(row.name),
+                biography: Set(row.biography),
+                birth_date: Set(row.birth_date.map(|date| date.and_time(Default::default()))),
+                profile_picture_url: Set(row.profile_picture_url),
+                created_at: if id.is_some() { NotSet } else { Set(now) },
+                external_id: Set(Some(row.external_id.clone())),
+            };
+            let saved = match id {
+                Some(_) => model.update(&txn).await?,
+                None => model.insert(&txn).await?,
+            };
+            references
+                .entry(CatalogEntityEnum::DIRECTOR)
+                .or_default()
+                .insert(row.external_id, saved.id);
+        }
+
+        for (id, row) in plan.series {
+            let model = series::ActiveModel {
+                id: id.map_or(NotSet, Set),
+                title: Set(row.title),
+                description: Set(row.description),
+                release_year: Set(row.release_year),
+                thumbnail_url: Set(row.thumbnail_url),
+                is_featured: Set(row.is_featured),
+                created_at: if id.is_some() { NotSet } else { Set(now) },
+                updated_at: Set(now),
+                deleted_at: NotSet,
+                external_id: Set(Some(row.external_id.clone())),
+                available_from: NotSet,
+                available_until: NotSet,
+                release_state: NotSet,
+                maturity_rating: NotSet,
+                content_descriptors: NotSet,
+            };
+            let saved = match id {
+                Some(_) => model.update(&txn).await?,
+                None => model.insert(&txn).await?,
+            };
+            references
+                .entry(CatalogEntityEnum::SERIE)
+                .or_default()
+                .insert(row.external_id, saved.id);
+        }
+
+        // libera as posições antes de renumerar, como na reordenação da série
+        let updated_video_ids: Vec<i32> = plan.videos.iter().filter_map(|(id, _)| *id).collect();
+        if !updated_video_ids.is_empty() {
+            videos::Entity::update_many()
+                .col_expr(
+                    videos::Column::EpisodeNumber,
+                    Expr::value(Option::<i32>::None),
+                )
+                .filter(videos::Column::Id.is_in(updated_video_ids))
+                .exec(&txn)
+                .await?;
+        }
+
+        let resolve = |entity: CatalogEntityEnum, external_ids: Option<Vec<String>>| {
+            external_ids.map(|external_ids| {
+                external_ids
+                    .iter()
+                    .filter_map(|external_id| {
+                        references
+                            .get(&entity)
+                            .and_then(|ids| ids.get(external_id))
+                            .copied()
+                    })
+                    .collect::<Vec<i32>>()
+            })
+        };
+
+        let mut credits = Vec::with_capacity(plan.videos.len());
+        for (id, row) in plan.videos {
+            let series_id = row.serie_external_id.as_ref().and_then(|external_id| {
+                references
+                    .get(&CatalogEntityEnum::SERIE)
+                    .and_then(|ids| ids.get(external_id))
+                    .copied()
+            });
+
+            let model = videos::ActiveModel {
+                id: id.map_or(NotSet, Set),
+                title: Set(row.title),
+                description: Set(row.description),
+                duration_seconds: match (id, row.duration_seconds) {
+                    (_, Some(duration)) => Set(duration),
+                    (Some(_), None) => NotSet,
+                    (None, None) => Set(0),
+                },
+                video_url: if id.is_some() { NotSet } else { Set(None) },
+                trailer_url: Set(row.trailer_url),
+                thumbnail_url: Set(row.thumbnail_url),
+                is_available: Set(row.is_available),
+                rating: if id.is_some() { NotSet } else { Set(0.0) },
+                series_id: Set(series_id),
+                episode_number: Set(row.episode_number),
+                season_number: Set(row.season_number),
+                release_year: Set(row.release_year),
+                created_at: if id.is_some() { NotSet } else { Set(now) },
+                updated_at: Set(now),
+                deleted_at: NotSet,
+                external_id: Set(Some(row.external_id)),
+                available_from: NotSet,
+                available_until: NotSet,
+                release_state: NotSet,
+                maturity_rating: NotSet,
+                content_descriptors: NotSet,
+            };
+            let saved = match id {
+                Some(_) => model.update(&txn).await?,
+                None => model.insert(&txn).await?,
+            };
+
+            let cast = row.cast.map(|cast| {
+                cast.into_iter()
+                    .filter_map(|member| {
+                        references
+                            .get(&CatalogEntityEnum::ACTOR)
+                            .and_then(|ids| ids.get(&member.actor_external_id))
+                            .map(|&actor_id| VideoCastMember {
+                                actor_id,
+                                role_name: member.role_name,
+                                is_lead: member.is_lead,
+                            })
+                    })
+                    .collect()
+            });
+
+            credits.push((
+                saved.id,
+                SetVideoCreditsRequest {
+                    category_ids: resolve(CatalogEntityEnum::CATEGORY, row.categories),
+                    cast,
+                    director_ids: resolve(CatalogEntityEnum::DIRECTOR, row.directors),
+                },
+            ));
+        }
+
+        for (video_id, request) in credits {
+            replace_credits(&txn, video_id, request).await?;
+        }
+
+        txn.commit().await
+    }
+
+    /// Dá um `external_id` ("video-12", "serie-3"...) a quem ainda não tem,
+    /// para que a exportação possa ser importada de volta sem duplicar.
+    pub async fn assign_missing_external_ids(&self) -> Result<(), DbErr> {
+        for (table, prefix) in [
+            ("categories", "category"),
+            ("actors", "actor"),
+            ("directors", "director"),
+            ("series", "serie"),
+            ("videos", "video"),
+        ] {
+            self.db
+                .execute_unprepared(&format!(
+                    "UPDATE {table} SET external_id = '{prefix}-' || id WHERE external_id IS NULL"
+                ))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn snapshot(&self) -> Result<CatalogSnapshot, DbErr> {
+        let videos = videos::Entity::find()
+            .filter(videos::Column::DeletedAt.is_null())
+            .order_by_asc(videos::Column::SeriesId)
+            .order_by_asc(videos::Column::SeasonNumber)
+            .order_by_asc(videos::Column::EpisodeNumber)
+            .order_by_asc(videos::Column::Id)
+            .all(&self.db)
+            .await?;
+        let video_ids: Vec<i32> = videos.iter().map(|video| video.id).collect();
+
+        Ok(CatalogSnapshot {
+            categories: categories::Entity::find()
+                .order_by_asc(categories::Column::Id)
+                .all(&self.db)
+                .await?,
+            actors: actors::Entity::find()
+                .order_by_asc(actors::Column::Id)
+                .all(&self.db)
+                .await?,
+            directors: directors::Entity::find()
+                .order_by_asc(directors::Column::Id)
+                .all(&self.db)
+                .await?,
+            series: series::Entity::find()
+                .filter(series::Column::DeletedAt.is_null())
+                .order_by_asc(series::Column::Id)
+                .all(&self.db)
+                .await?,
+            video_categories: video_categories::Entity::find()
+                .filter(video_categories::Column::VideoId.is_in(video_ids.clone()))
+                .order_by_asc(video_categories::Column::Id)
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|link| (link.video_id, link.category_id))
+                .collect(),
+            video_actors: video_actors::Entity::find()
+                .filter(video_actors::Column::VideoId.is_in(video_ids.clone()))
+                .order_by_desc(video_actors::Column::IsLead)
+                .order_by_asc(video_actors::Column::Id)
+                .all(&self.db)
+                .await?,
+            video_directors: video_directors::Entity::find()
+                .filter(video_directors::Column::VideoId.is_in(video_ids))
+                .order_by_asc(video_directors::Column::Id)
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|link| (link.video_id, link.director_id))
+                .collect(),
+            videos,
+        })
+    }
+}
@@ -0,0 +1,141 @@
+use crate::modules::database::schema::categories::Model as CategoryModel;
+use crate::modules::database::schema::{categories, video_categories};
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{DatabaseConnection, DbErr, QueryOrder, QuerySelect, Set};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCategoryRequest {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct UpdateCategoryRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+pub struct CategoriesRepository {
+    db: DatabaseConnection,
+}
+
+impl CategoriesRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(&self, request: CreateCategoryRequest) -> Result<CategoryModel, DbErr> {
+        let category = categories::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            name: Set(request.name),
+            description: Set(request.description),
+            created_at: Set(Utc::now().naive_utc()),
+            external_id: sea_orm::ActiveValue::NotSet,
+        };
+
+        category.insert(&self.db).await
+    }
+
+    pub async fn find_by_id(&self, category_id: i32) -> Result<Option<CategoryModel>, DbErr> {
+        categories::Entity::find_by_id(category_id)
+            .one(&self.db)
+            .await
+    }
+
+    /// Busca pelo nome exato, sem diferenciar maiúsculas (o nome é único).
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<CategoryModel>, DbErr> {
+        categories::Entity::find()
+            .filter(
+                Expr::expr(Func::lower(Expr::col(categories::Column::Name)))
+                    .eq(name.to_lowercase()),
+            )
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn find_by_ids(&self, category_ids: &[i32]) -> Result<Vec<CategoryModel>, DbErr> {
+        if category_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        categories::Entity::find()
+            .filter(categories::Column::Id.is_in(category_ids.to_vec()))
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn find_many(
+        &self,
+        name: Option<&str>,
+        skip: Option<u64>,
+        take: Option<u64>,
+    ) -> Result<Vec<CategoryModel>, DbErr> {
+        let mut query = categories::Entity::find().order_by_asc(categories::Column::Name);
+
+        if let Some(name) = name {
+            query = query.filter(categories::Column::Name.contains(name));
+        }
+        if let Some(skip) = skip {
+            query = query.offset(skip);
+        }
+        if let Some(take) = take {
+            query = query.limit(take);
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Categorias de cada vídeo informado, como pares (video_id, categoria).
+    pub async fn find_by_videos(
+        &self,
+        video_ids: &[i32],
+    ) -> Result<Vec<(i32, CategoryModel)>, DbErr> {
+        if video_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = video_categories::Entity::find()
+            .find_also_related(categories::Entity)
+            .filter(video_categories::Column::VideoId.is_in(video_ids.to_vec()))
+            .order_by_asc(categories::Column::Name)
+            .all(&self.db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(link, category)| category.map(|category| (link.video_id, category)))
+            .collect())
+    }
+
+    pub async fn update(
+        &self,
+        category_id: i32,
+        request: UpdateCategoryRequest,
+    ) -> Result<CategoryModel, DbErr> {
+        let mut active_model = categories::ActiveModel {
+            id: Set(category_id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            active_model.name = Set(name);
+        }
+        if request.description.is_some() {
+            active_model.description = Set(request.description);
+        }
+
+        active_model.update(&self.db).await
+    }
+
+    /// Remove a categoria; as associações com vídeos vão junto por cascata.
+    pub async fn delete(&self, category_id: i32) -> Result<bool, DbErr> {
+        let result = categories::Entity::delete_by_id(category_id)
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::modules::database::schema::directors::Model as DirectorModel;
+use crate::modules::database::schema::{directors, video_directors};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{DatabaseConnection, DbErr, QueryOrder, QuerySelect, Set};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateDirectorRequest {
+    pub name: String,
+    pub biography: Option<String>,
+    pub birth_date: Option<NaiveDateTime>,
+    pub profile_picture_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct UpdateDirectorRequest {
+    pub name: Option<String>,
+    pub biography: Option<String>,
+    pub birth_date: Option<NaiveDateTime>,
+    pub profile_picture_url: Option<String>,
+}
+
+pub struct DirectorsRepository {
+    db: DatabaseConnection,
+}
+
+impl DirectorsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(&self, request: CreateDirectorRequest) -> Result<DirectorModel, DbErr> {
+        let director = directors::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            name: Set(request.name),
+            biography: Set(request.biography),
+            birth_date: Set(request.birth_date),
+            profile_picture_url: Set(request.profile_picture_url),
+            created_at: Set(Utc::now().naive_utc()),
+            external_id: sea_orm::ActiveValue::NotSet,
+        };
+
+        director.insert(&self.db).await
+    }
+
+    pub async fn find_by_id(&self, director_id: i32) -> Result<Option<DirectorModel>, DbErr> {
+        directors::Entity::find_by_id(director_id)
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn find_by_ids(&self, director_ids: &[i32]) -> Result<Vec<DirectorModel>, DbErr> {
+        if director_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        directors::Entity::find()
+            .filter(directors::Column::Id.is_in(director_ids.to_vec()))
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn find_many(
+        &self,
+        name: Option<&str>,
+        skip: Option<u64>,
+        take: Option<u64>,
+    ) -> Result<Vec<DirectorModel>, DbErr> {
+        let mut query = directors::Entity::find().order_by_asc(directors::Column::Name);
+
+        if let Some(name) = name {
+            query = query.filter(directors::Column::Name.contains(name));
+        }
+        if let Some(skip) = skip {
+            query = query.offset(skip);
+        }
+        if let Some(take) = take {
+            query = query.limit(take);
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Direção de cada vídeo informado, como pares (video_id, diretor).
+    pub async fn find_by_videos(
+        &self,
+        video_ids: &[i32],
+    ) -> Result<Vec<(i32, DirectorModel)>, DbErr> {
+        if video_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = video_directors::Entity::find()
+            .find_also_related(directors::Entity)
+            .filter(video_directors::Column::VideoId.is_in(video_ids.to_vec()))
+            .order_by_asc(directors::Column::Name)
+            .all(&self.db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(link, director)| director.map(|director| (link.video_id, director)))
+            .collect())
+    }
+
+    pub async fn update(
+        &self,
+        director_id: i32,
+        request: UpdateDirectorRequest,
+    ) -> Result<DirectorModel, DbErr> {
+        let mut active_model = directors::ActiveModel {
+            id: Set(director_id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            active_model.name = Set(name);
+        }
+        if request.biography.is_some() {
+            active_model.biography = Set(request.biography);
+        }
+        if request.birth_date.is_some() {
+            active_model.birth_date = Set(request.birth_date);
+        }
+        if request.profile_picture_url.is_some() {
+            active_model.profile_picture_url = Set(request.profile_picture_url);
+        }
+
+        active_model.update(&self.db).await
+    }
+
+    /// Remove o diretor; as associações com vídeos vão junto por cascata.
+    pub async fn delete(&self, director_id: i32) -> Result<bool, DbErr> {
+        let result = directors::Entity::delete_by_id(director_id)
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
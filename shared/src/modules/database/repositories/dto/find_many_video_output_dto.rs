@@ -13,4 +13,5 @@ pub struct FindManyVideoOutputDTO {
     pub episode_number: Option<i32>,
     pub season_number: Option<i32>,
     pub release_year: Option<i32>,
+    pub thumbnail_url: Option<String>,
 }
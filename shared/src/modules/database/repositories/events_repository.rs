@@ -0,0 +1,160 @@
+use crate::modules::database::schema::events::Model as EventModel;
+use crate::modules::database::schema::{dead_letter_events, events};
+use crate::modules::events::DomainEvent;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, DbErr, Set, Statement, TransactionTrait,
+};
+
+pub struct EventsRepository {
+    db: DatabaseConnection,
+}
+
+impl EventsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Grava o evento no outbox. Deve rodar na transação da mudança que ele
+    /// descreve, para que um não exista sem o outro.
+    pub async fn record<C: ConnectionTrait>(conn: &C, event: &DomainEvent) -> Result<(), DbErr> {
+        Self::record_many(conn, std::slice::from_ref(event)).await
+    }
+
+    pub async fn record_many<C: ConnectionTrait>(
+        conn: &C,
+        events: &[DomainEvent],
+    ) -> Result<(), DbErr> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now().naive_utc();
+        let models = events
+            .iter()
+            .map(|event| {
+                let (aggregate_type, aggregate_id) = event.aggregate();
+                Ok(events::ActiveModel {
+                    id: sea_orm::ActiveValue::NotSet,
+                    event_type: Set(event.event_type().to_string()),
+                    aggregate_type: Set(aggregate_type.to_string()),
+                    aggregate_id: Set(aggregate_id),
+                    payload: Set(event.payload().map_err(|e| DbErr::Custom(e.to_string()))?),
+                    attempts: Set(0),
+                    last_error: Set(None),
+                    available_at: Set(now),
+                    published_at: Set(None),
+                    created_at: Set(now),
+                })
+            })
+            .collect::<Result<Vec<_>, DbErr>>()?;
+
+        events::Entity::insert_many(models).exec(conn).await?;
+
+        Ok(())
+    }
+
+    /// Reserva até `limit` eventos pendentes por `lease_secs`, contando uma
+    /// tentativa. Se o relay parar no meio, o evento volta a ser entregue
+    /// quando a reserva vencer.
+    ///
+    /// `FOR UPDATE SKIP LOCKED` permite mais de um relay sem que dois peguem
+    /// o mesmo evento.
+    pub async fn claim_due(
+        &self,
+        now: NaiveDateTime,
+        limit: u64,
+        lease_secs: i64,
+    ) -> Result<Vec<EventModel>, DbErr> {
+        events::Entity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE events
+                   SET attempts = attempts + 1,
+                       available_at = $1 + make_interval(secs => $2::float8)
+                   WHERE id IN (
+                       SELECT id FROM events
+                       WHERE published_at IS NULL AND available_at <= $1
+                       ORDER BY id
+                       FOR UPDATE SKIP LOCKED
+                       LIMIT $3
+                   )
+                   RETURNING *"#,
+                [now.into(), lease_secs.into(), (limit as i64).into()],
+            ))
+            .all(&self.db)
+            .await
+            .map(|mut events| {
+                // o RETURNING não garante a ordem do SELECT
+                events.sort_by_key(|event| event.id);
+                events
+            })
+    }
+
+    pub async fn mark_published(&self, event_id: i64, now: NaiveDateTime) -> Result<(), DbErr> {
+        events::Entity::update_many()
+            .col_expr(events::Column::PublishedAt, Expr::value(now))
+            .col_expr(
+                events::Column::LastError,
+                Expr::value(Option::<String>::None),
+            )
+            .filter(events::Column::Id.eq(event_id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Registra a falha e devolve o evento à fila a partir de `retry_at`.
+    pub async fn schedule_retry(
+        &self,
+        event_id: i64,
+        error: &str,
+        retry_at: NaiveDateTime,
+    ) -> Result<(), DbErr> {
+        events::Entity::update_many()
+            .col_expr(events::Column::LastError, Expr::value(error))
+            .col_expr(events::Column::AvailableAt, Expr::value(retry_at))
+            .filter(events::Column::Id.eq(event_id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Move o evento para `dead_letter_events`, tirando-o do outbox.
+    pub async fn dead_letter(&self, event: &EventModel, error: &str) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        dead_letter_events::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            event_id: Set(event.id),
+            event_type: Set(event.event_type.clone()),
+            aggregate_type: Set(event.aggregate_type.clone()),
+            aggregate_id: Set(event.aggregate_id.clone()),
+            payload: Set(event.payload.clone()),
+            attempts: Set(event.attempts),
+            last_error: Set(Some(error.to_string())),
+            created_at: Set(event.created_at),
+            failed_at: Set(Utc::now().naive_utc()),
+        }
+        .insert(&txn)
+        .await?;
+
+        events::Entity::delete_by_id(event.id).exec(&txn).await?;
+
+        txn.commit().await
+    }
+
+    /// Apaga os eventos já entregues antes de `before`.
+    pub async fn purge_published(&self, before: NaiveDateTime) -> Result<u64, DbErr> {
+        let result = events::Entity::delete_many()
+            .filter(events::Column::PublishedAt.lt(before))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
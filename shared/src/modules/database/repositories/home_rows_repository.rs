@@ -0,0 +1,266 @@
+use crate::enums::home_row_kind_enum::HomeRowKindEnum;
+use crate::enums::trending_window_enum::TrendingWindowEnum;
+use crate::modules::database::repositories::availability_repository::{
+    live_series_sql, live_videos_sql,
+};
+use crate::modules::database::repositories::search_repository::LiveFilter;
+use crate::modules::database::schema::home_rows;
+use crate::modules::database::schema::home_rows::Model as HomeRowModel;
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::{
+    ActiveValue::NotSet, DatabaseConnection, DbBackend, DbErr, FromQueryResult, QueryOrder,
+    QuerySelect, Set, Statement,
+};
+
+/// Definição de uma linha da home, já validada pelo admin.
+#[derive(Debug, Clone)]
+pub struct HomeRowRequest {
+    pub kind: HomeRowKindEnum,
+    pub title: String,
+    pub position: i32,
+    /// Obrigatória em `CATEGORY`; em `TRENDING` limita a lista à categoria.
+    pub category_id: Option<i32>,
+    /// Só em `TRENDING`.
+    pub trending_window: Option<TrendingWindowEnum>,
+    pub item_limit: i32,
+    /// 0 desliga o cache da linha.
+    pub cache_ttl_secs: i32,
+    pub is_active: bool,
+}
+
+/// Item de uma linha: `item_type` é `VIDEO` ou `SERIE`. `watched_seconds`
+/// só vem em "continue assistindo".
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct HomeRowItem {
+    pub item_type: String,
+    pub id: i32,
+    pub watched_seconds: Option<i32>,
+}
+
+/// Data de estreia na região: início da janela efetiva, ou a criação para
+/// itens sem janela. Usa os aliases de `live_videos_sql`/`live_series_sql`.
+fn released_at_sql(item: &str, window: &str) -> String {
+    format!(
+        "COALESCE(CASE WHEN {window}.id IS NULL THEN {item}.available_from \
+         ELSE {window}.available_from END, {item}.created_at)"
+    )
+}
+
+pub struct HomeRowsRepository {
+    db: DatabaseConnection,
+}
+
+impl HomeRowsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(&self, request: HomeRowRequest) -> Result<HomeRowModel, DbErr> {
+        let now = Utc::now().naive_utc();
+        let row = home_rows::ActiveModel {
+            id: NotSet,
+            kind: Set(request.kind.as_str().to_string()),
+            title: Set(request.title),
+            position: Set(request.position),
+            category_id: Set(request.category_id),
+            trending_window: Set(request.trending_window.map(|w| w.as_str().to_string())),
+            item_limit: Set(request.item_limit),
+            cache_ttl_secs: Set(request.cache_ttl_secs),
+            is_active: Set(request.is_active),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        row.insert(&self.db).await
+    }
+
+    pub async fn find_by_id(&self, row_id: i32) -> Result<Option<HomeRowModel>, DbErr> {
+        home_rows::Entity::find_by_id(row_id).one(&self.db).await
+    }
+
+    /// Linhas na ordem de exibição.
+    pub async fn find_all(&self, only_active: bool) -> Result<Vec<HomeRowModel>, DbErr> {
+        let mut query = home_rows::Entity::find()
+            .order_by_asc(home_rows::Column::Position)
+            .order_by_asc(home_rows::Column::Id);
+
+        if only_active {
+            query = query.filter(home_rows::Column::IsActive.eq(true));
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Posição logo depois da última linha.
+    pub async fn next_position(&self) -> Result<i32, DbErr> {
+        let max: Option<Option<i32>> = home_rows::Entity::find()
+            .select_only()
+            .column_as(home_rows::Column::Position.max(), "max")
+            .into_tuple()
+            .one(&self.db)
+            .await?;
+
+        Ok(max.flatten().unwrap_or(0) + 1)
+    }
+
+    pub async fn update(
+        &self,
+        row_id: i32,
+        request: HomeRowRequest,
+    ) -> Result<HomeRowModel, DbErr> {
+        let row = home_rows::ActiveModel {
+            id: Set(row_id),
+            kind: Set(request.kind.as_str().to_string()),
+            title: Set(request.title),
+            position: Set(request.position),
+            category_id: Set(request.category_id),
+            trending_window: Set(request.trending_window.map(|w| w.as_str().to_string())),
+            item_limit: Set(request.item_limit),
+            cache_ttl_secs: Set(request.cache_ttl_secs),
+            is_active: Set(request.is_active),
+            created_at: NotSet,
+            updated_at: Set(Utc::now().naive_utc()),
+        };
+
+        row.update(&self.db).await
+    }
+
+    pub async fn delete(&self, row_id: i32) -> Result<bool, DbErr> {
+        let result = home_rows::Entity::delete_by_id(row_id)
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Séries com `is_featured` no ar, as mais recentes primeiro.
+    pub async fn find_featured_series(
+        &self,
+        live: &LiveFilter,
+        limit: u64,
+    ) -> Result<Vec<HomeRowItem>, DbErr> {
+        let (from, condition) = live_series_sql(live.max_rating);
+
+        HomeRowItem::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT 'SERIE' AS item_type, s.id, NULL::int AS watched_seconds \
+                 FROM {from} WHERE {condition} AND s.is_featured \
+                 ORDER BY {released_at} DESC, s.id LIMIT $3",
+                released_at = released_at_sql("s", "sw")
+            ),
+            [
+                live.at.into(),
+                live.region.clone().into(),
+                (limit as i64).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await
+    }
+
+    /// Vídeos começados e não concluídos, do mais recente para o mais antigo.
+    /// De cada série entra só o último episódio assistido.
+    pub async fn find_continue_watching(
+        &self,
+        profile_id: i32,
+        live: &LiveFilter,
+        limit: u64,
+    ) -> Result<Vec<HomeRowItem>, DbErr> {
+        let (from, condition) = live_videos_sql(live.max_rating);
+
+        HomeRowItem::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT 'VIDEO' AS item_type, id, watched_seconds FROM ( \
+                   SELECT DISTINCT ON (COALESCE(v.series_id, -v.id)) \
+                     v.id, wh.watched_seconds, wh.last_watched_at \
+                   FROM {from} \
+                   JOIN watch_history wh ON wh.video_id = v.id AND wh.profile_id = $3 \
+                   WHERE {condition} AND NOT wh.is_completed AND wh.watched_seconds > 0 \
+                   ORDER BY COALESCE(v.series_id, -v.id), wh.last_watched_at DESC \
+                 ) started \
+                 ORDER BY last_watched_at DESC, id LIMIT $4"
+            ),
+            [
+                live.at.into(),
+                live.region.clone().into(),
+                profile_id.into(),
+                (limit as i64).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await
+    }
+
+    /// Favoritos do perfil que estão no ar, os adicionados por último primeiro.
+    pub async fn find_favorites(
+        &self,
+        profile_id: i32,
+        live: &LiveFilter,
+        limit: u64,
+    ) -> Result<Vec<HomeRowItem>, DbErr> {
+        let (from, condition) = live_videos_sql(live.max_rating);
+
+        HomeRowItem::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT 'VIDEO' AS item_type, v.id, NULL::int AS watched_seconds \
+                 FROM {from} JOIN favorites f ON f.video_id = v.id AND f.profile_id = $3 \
+                 WHERE {condition} ORDER BY f.added_at DESC, v.id LIMIT $4"
+            ),
+            [
+                live.at.into(),
+                live.region.clone().into(),
+                profile_id.into(),
+                (limit as i64).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await
+    }
+
+    /// Filmes e séries no ar, da estreia mais recente para a mais antiga.
+    /// Com `category_id`, só os filmes da categoria e as séries com
+    /// episódios nela.
+    pub async fn find_titles(
+        &self,
+        category_id: Option<i32>,
+        live: &LiveFilter,
+        limit: u64,
+    ) -> Result<Vec<HomeRowItem>, DbErr> {
+        let (video_from, video_live) = live_videos_sql(live.max_rating);
+        let (serie_from, serie_live) = live_series_sql(live.max_rating);
+
+        HomeRowItem::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT item_type, id, NULL::int AS watched_seconds FROM ( \
+                   SELECT 'VIDEO' AS item_type, v.id, {video_released} AS released_at \
+                   FROM {video_from} WHERE {video_live} AND v.series_id IS NULL \
+                   AND ($3::int IS NULL OR EXISTS (SELECT 1 FROM video_categories vc \
+                     WHERE vc.video_id = v.id AND vc.category_id = $3)) \
+                   UNION ALL \
+                   SELECT 'SERIE' AS item_type, s.id, {serie_released} AS released_at \
+                   FROM {serie_from} WHERE {serie_live} \
+                   AND ($3::int IS NULL OR EXISTS (SELECT 1 FROM videos e \
+                     JOIN video_categories vc ON vc.video_id = e.id \
+                     WHERE e.series_id = s.id AND e.deleted_at IS NULL \
+                     AND vc.category_id = $3)) \
+                 ) titles \
+                 ORDER BY released_at DESC, item_type DESC, id LIMIT $4",
+                video_released = released_at_sql("v", "vw"),
+                serie_released = released_at_sql("s", "sw"),
+            ),
+            [
+                live.at.into(),
+                live.region.clone().into(),
+                category_id.into(),
+                (limit as i64).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::enums::image_format_enum::ImageFormatEnum;
+use crate::enums::image_kind_enum::ImageKindEnum;
+use crate::enums::image_owner_enum::ImageOwnerEnum;
+use crate::modules::database::schema::images::Model as ImageModel;
+use crate::modules::database::schema::{actors, directors, images, series, videos};
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, QueryOrder, Set, TransactionTrait};
+use serde::{Deserialize, Serialize};
+
+/// Variante redimensionada, guardada na coluna `variants`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageVariant {
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    pub format: ImageFormatEnum,
+    pub storage_key: String,
+    pub url: String,
+}
+
+/// Variante usada para preencher as colunas de URL antigas
+/// (`videos.thumbnail_url`, `series.thumbnail_url`, `profile_picture_url`).
+pub const LEGACY_URL_VARIANT: &str = "medium";
+
+#[derive(Debug)]
+pub struct CreateImageRequest {
+    pub owner: ImageOwnerEnum,
+    pub owner_id: i32,
+    pub kind: ImageKindEnum,
+    pub container: String,
+    pub format: ImageFormatEnum,
+    pub width: i32,
+    pub height: i32,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub original_key: String,
+    pub original_url: String,
+    pub variants: Vec<ImageVariant>,
+}
+
+/// Resultado da troca de uma imagem.
+///
+/// `previous` é a imagem substituída; seus blobs devem ser apagados pelo
+/// chamador após o commit.
+#[derive(Debug)]
+pub struct ReplacedImage {
+    pub image: ImageModel,
+    pub previous: Option<ImageModel>,
+}
+
+pub fn image_variants(image: &ImageModel) -> Vec<ImageVariant> {
+    serde_json::from_value(image.variants.clone()).unwrap_or_default()
+}
+
+/// URLs das variantes por nome (`small`, `medium`, `large`).
+pub fn image_variant_urls(image: &ImageModel) -> BTreeMap<String, String> {
+    image_variants(image)
+        .into_iter()
+        .map(|variant| (variant.name, variant.url))
+        .collect()
+}
+
+/// URLs das variantes por tipo (`thumbnail`, `poster`, `photo`).
+pub type CatalogImageUrls = BTreeMap<String, BTreeMap<String, String>>;
+
+/// Agrupa as imagens por dono, no formato devolvido pelas respostas do catálogo.
+pub fn catalog_image_urls(images: Vec<ImageModel>) -> HashMap<i32, CatalogImageUrls> {
+    let mut grouped: HashMap<i32, CatalogImageUrls> = HashMap::new();
+
+    for image in images {
+        let urls = image_variant_urls(&image);
+        grouped
+            .entry(image.owner_id)
+            .or_default()
+            .insert(image.kind.to_lowercase(), urls);
+    }
+
+    grouped
+}
+
+/// Blobs de uma imagem: o original e todas as variantes.
+pub fn image_storage_keys(image: &ImageModel) -> Vec<String> {
+    std::iter::once(image.original_key.clone())
+        .chain(image_variants(image).into_iter().map(|v| v.storage_key))
+        .collect()
+}
+
+pub struct ImagesRepository {
+    db: DatabaseConnection,
+}
+
+impl ImagesRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn owner_exists(&self, owner: ImageOwnerEnum, owner_id: i32) -> Result<bool, DbErr> {
+        let found = match owner {
+            ImageOwnerEnum::VIDEO => videos::Entity::find_by_id(owner_id)
+                .filter(videos::Column::DeletedAt.is_null())
+                .one(&self.db)
+                .await?
+                .is_some(),
+            ImageOwnerEnum::SERIE => series::Entity::find_by_id(owner_id)
+                .filter(series::Column::DeletedAt.is_null())
+                .one(&self.db)
+                .await?
+                .is_some(),
+            ImageOwnerEnum::ACTOR => actors::Entity::find_by_id(owner_id)
+                .one(&self.db)
+                .await?
+                .is_some(),
+            ImageOwnerEnum::DIRECTOR => directors::Entity::find_by_id(owner_id)
+                .one(&self.db)
+                .await?
+                .is_some(),
+        };
+
+        Ok(found)
+    }
+
+    pub async fn find_by_owner(
+        &self,
+        owner: ImageOwnerEnum,
+        owner_id: i32,
+    ) -> Result<Vec<ImageModel>, DbErr> {
+        self.find_by_owners(owner, &[owner_id]).await
+    }
+
+    pub async fn find_by_owners(
+        &self,
+        owner: ImageOwnerEnum,
+        owner_ids: &[i32],
+    ) -> Result<Vec<ImageModel>, DbErr> {
+        if owner_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        images::Entity::find()
+            .filter(images::Column::OwnerType.eq(owner.as_str()))
+            .filter(images::Column::OwnerId.is_in(owner_ids.iter().copied()))
+            .order_by_asc(images::Column::OwnerId)
+            .order_by_asc(images::Column::Kind)
+            .all(&self.db)
+            .await
+    }
+
+    /// Registra a imagem, substituindo a anterior do mesmo dono e tipo.
+    pub async fn replace(&self, request: CreateImageRequest) -> Result<ReplacedImage, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let previous = delete_image(&txn, request.owner, request.owner_id, request.kind).await?;
+
+        let legacy_url = request
+            .variants
+            .iter()
+            .find(|variant| variant.name == LEGACY_URL_VARIANT)
+            .map(|variant| variant.url.clone());
+
+        let now = Utc::now().naive_utc();
+        let image = images::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            owner_type: Set(request.owner.as_str().to_string()),
+            owner_id: Set(request.owner_id),
+            kind: Set(request.kind.as_str().to_string()),
+            container: Set(request.container),
+            format: Set(request.format.as_str().to_string()),
+            width: Set(request.width),
+            height: Set(request.height),
+            size_bytes: Set(request.size_bytes),
+            sha256: Set(request.sha256),
+            original_key: Set(request.original_key),
+            original_url: Set(request.original_url),
+            variants: Set(serde_json::to_value(&request.variants)
+                .map_err(|e| DbErr::Custom(e.to_string()))?),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(&txn)
+        .await?;
+
+        set_legacy_url(
+            &txn,
+            request.owner,
+            request.owner_id,
+            request.kind,
+            legacy_url,
+        )
+        .await?;
+
+        txn.commit().await?;
+
+        Ok(ReplacedImage { image, previous })
+    }
+
+    /// Remove a imagem e devolve o registro para que os blobs sejam apagados.
+    pub async fn delete(
+        &self,
+        owner: ImageOwnerEnum,
+        owner_id: i32,
+        kind: ImageKindEnum,
+    ) -> Result<Option<ImageModel>, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let removed = delete_image(&txn, owner, owner_id, kind).await?;
+        if removed.is_some() {
+            set_legacy_url(&txn, owner, owner_id, kind, None).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(removed)
+    }
+
+    /// Chaves de todos os blobs referenciados: originais e variantes.
+    pub async fn find_storage_keys(&self) -> Result<Vec<String>, DbErr> {
+        let images = images::Entity::find().all(&self.db).await?;
+
+        Ok(images.iter().flat_map(image_storage_keys).collect())
+    }
+
+    /// Remove todas as imagens de um dono, usado quando o dono é apagado.
+    pub async fn delete_by_owner(
+        &self,
+        owner: ImageOwnerEnum,
+        owner_id: i32,
+    ) -> Result<Vec<ImageModel>, DbErr> {
+        delete_owner_images(&self.db, owner, owner_id).await
+    }
+}
+
+/// Apaga os registros das imagens do dono e os devolve, para que os blobs
+/// sejam removidos depois.
+pub(crate) async fn delete_owner_images<C: ConnectionTrait>(
+    conn: &C,
+    owner: ImageOwnerEnum,
+    owner_id: i32,
+) -> Result<Vec<ImageModel>, DbErr> {
+    let removed = images::Entity::find()
+        .filter(images::Column::OwnerType.eq(owner.as_str()))
+        .filter(images::Column::OwnerId.eq(owner_id))
+        .order_by_asc(images::Column::Kind)
+        .all(conn)
+        .await?;
+
+    images::Entity::delete_many()
+        .filter(images::Column::OwnerType.eq(owner.as_str()))
+        .filter(images::Column::OwnerId.eq(owner_id))
+        .exec(conn)
+        .await?;
+
+    Ok(removed)
+}
+
+async fn delete_image<C: ConnectionTrait>(
+    conn: &C,
+    owner: ImageOwnerEnum,
+    owner_id: i32,
+    kind: ImageKindEnum,
+) -> Result<Option<ImageModel>, DbErr> {
+    let existing = images::Entity::find()
+        .filter(images::Column::OwnerType.eq(owner.as_str()))
+        .filter(images::Column::OwnerId.eq(owner_id))
+        .filter(images::Column::Kind.eq(kind.as_str()))
+        .one(conn)
+        .await?;
+
+    if let Some(image) = &existing {
+        images::Entity::delete_by_id(image.id).exec(conn).await?;
+    }
+
+    Ok(existing)
+}
+
+/// Mantém as colunas de URL anteriores ao pipeline de imagens apontando para
+/// a variante média. Posters não têm coluna própria.
+async fn set_legacy_url<C: ConnectionTrait>(
+    conn: &C,
+    owner: ImageOwnerEnum,
+    owner_id: i32,
+    kind: ImageKindEnum,
+    url: Option<String>,
+) -> Result<(), DbErr> {
+    match (owner, kind) {
+        (ImageOwnerEnum::VIDEO, ImageKindEnum::THUMBNAIL) => {
+            videos::Entity::update_many()
+                .col_expr(videos::Column::ThumbnailUrl, Expr::value(url))
+                .col_expr(
+                    videos::Column::UpdatedAt,
+                    Expr::value(Utc::now().naive_utc()),
+                )
+                .filter(videos::Column::Id.eq(owner_id))
+                .exec(conn)
+                .await?;
+        }
+        (ImageOwnerEnum::SERIE, ImageKindEnum::THUMBNAIL) => {
+            series::Entity::update_many()
+                .col_expr(series::Column::ThumbnailUrl, Expr::value(url))
+                .col_expr(
+                    series::Column::UpdatedAt,
+                    Expr::value(Utc::now().naive_utc()),
+                )
+                .filter(series::Column::Id.eq(owner_id))
+                .exec(conn)
+                .await?;
+        }
+        (ImageOwnerEnum::ACTOR, ImageKindEnum::PHOTO) => {
+            actors::Entity::update_many()
+                .col_expr(actors::Column::ProfilePictureUrl, Expr::value(url))
+                .filter(actors::Column::Id.eq(owner_id))
+                .exec(conn)
+                .await?;
+        }
+        (ImageOwnerEnum::DIRECTOR, ImageKindEnum::PHOTO) => {
+            directors::Entity::update_many()
+                .col_expr(directors::Column::ProfilePictureUrl, Expr::value(url))
+                .filter(directors::Column::Id.eq(owner_id))
+                .exec(conn)
+                .await?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use crate::enums::content_descriptor_enum::ContentDescriptorEnum;
+use crate::enums::maturity_rating_enum::MaturityRatingEnum;
+use crate::modules::database::repositories::events_repository::EventsRepository;
+use crate::modules::database::schema::{series, videos};
+use crate::modules::events::DomainEvent;
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{DatabaseConnection, DbErr, QuerySelect, TransactionTrait};
+use serde_json::json;
+
+/// Classificação aplicada de uma vez a vídeos e séries.
+#[derive(Debug, Clone)]
+pub struct AssignMaturityRating {
+    pub video_ids: Vec<i32>,
+    pub serie_ids: Vec<i32>,
+    pub maturity_rating: MaturityRatingEnum,
+    /// `None` mantém os descritores de cada item.
+    pub content_descriptors: Option<Vec<ContentDescriptorEnum>>,
+}
+
+/// Ids que existiam (fora da lixeira) e foram atualizados.
+#[derive(Debug, Clone, Default)]
+pub struct AssignedMaturityRating {
+    pub video_ids: Vec<i32>,
+    pub serie_ids: Vec<i32>,
+}
+
+pub struct MaturityRatingsRepository {
+    db: DatabaseConnection,
+}
+
+impl MaturityRatingsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Grava a classificação em todos os itens numa transação só. Ids que não
+    /// existem ou estão na lixeira são ignorados e ficam fora do retorno.
+    pub async fn assign(
+        &self,
+        request: AssignMaturityRating,
+    ) -> Result<AssignedMaturityRating, DbErr> {
+        let txn = self.db.begin().await?;
+        let now = Utc::now().naive_utc();
+        let rating = request.maturity_rating.as_str();
+        let descriptor_names: Option<Vec<String>> =
+            request.content_descriptors.map(|descriptors| {
+                descriptors
+                    .iter()
+                    .map(|descriptor| descriptor.as_str().to_string())
+                    .collect()
+            });
+        let descriptors = descriptor_names.as_ref().map(|names| json!(names));
+
+        let video_ids: Vec<i32> = if request.video_ids.is_empty() {
+            Vec::new()
+        } else {
+            videos::Entity::find()
+                .select_only()
+                .column(videos::Column::Id)
+                .filter(videos::Column::Id.is_in(request.video_ids))
+                .filter(videos::Column::DeletedAt.is_null())
+                .lock_exclusive()
+                .into_tuple()
+                .all(&txn)
+                .await?
+        };
+
+        if !video_ids.is_empty() {
+            let mut update = videos::Entity::update_many()
+                .col_expr(videos::Column::MaturityRating, Expr::value(rating))
+                .col_expr(videos::Column::UpdatedAt, Expr::value(now));
+            if let Some(descriptors) = &descriptors {
+                update = update.col_expr(
+                    videos::Column::ContentDescriptors,
+                    Expr::value(descriptors.clone()),
+                );
+            }
+            update
+                .filter(videos::Column::Id.is_in(video_ids.clone()))
+                .exec(&txn)
+                .await?;
+        }
+
+        let serie_ids: Vec<i32> = if request.serie_ids.is_empty() {
+            Vec::new()
+        } else {
+            series::Entity::find()
+                .select_only()
+                .column(series::Column::Id)
+                .filter(series::Column::Id.is_in(request.serie_ids))
+                .filter(series::Column::DeletedAt.is_null())
+                .lock_exclusive()
+                .into_tuple()
+                .all(&txn)
+                .await?
+        };
+
+        if !serie_ids.is_empty() {
+            let mut update = series::Entity::update_many()
+                .col_expr(series::Column::MaturityRating, Expr::value(rating))
+                .col_expr(series::Column::UpdatedAt, Expr::value(now));
+            if let Some(descriptors) = descriptors {
+                update =
+                    update.col_expr(series::Column::ContentDescriptors, Expr::value(descriptors));
+            }
+            update
+                .filter(series::Column::Id.is_in(serie_ids.clone()))
+                .exec(&txn)
+                .await?;
+        }
+
+        if !video_ids.is_empty() || !serie_ids.is_empty() {
+            EventsRepository::record(
+                &txn,
+                &DomainEvent::RatingChanged {
+                    video_ids: video_ids.clone(),
+                    serie_ids: serie_ids.clone(),
+                    maturity_rating: rating.to_string(),
+                    content_descriptors: descriptor_names,
+                },
+            )
+            .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(AssignedMaturityRating {
+            video_ids,
+            serie_ids,
+        })
+    }
+}
@@ -0,0 +1,152 @@
+use crate::enums::media_asset_status_enum::MediaAssetStatusEnum;
+use crate::modules::database::schema::media_assets;
+use crate::modules::database::schema::media_assets::Model as MediaAssetModel;
+use crate::modules::media_probe::ProbeResult;
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Expr, Order};
+use sea_orm::{DatabaseConnection, DbErr, QueryOrder, QuerySelect, Set};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateMediaAssetRequest {
+    pub container: String,
+    pub storage_key: String,
+    pub url: String,
+    pub original_filename: Option<String>,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub probe: Option<ProbeResult>,
+}
+
+impl CreateMediaAssetRequest {
+    pub fn into_active_model(self) -> media_assets::ActiveModel {
+        let now = Utc::now().naive_utc();
+        let probe = self.probe;
+        media_assets::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            container: Set(self.container),
+            storage_key: Set(self.storage_key),
+            url: Set(self.url),
+            original_filename: Set(self.original_filename),
+            content_type: Set(self.content_type),
+            size_bytes: Set(self.size_bytes),
+            sha256: Set(self.sha256),
+            status: Set(MediaAssetStatusEnum::AVAILABLE.as_str().to_string()),
+            last_verified_at: Set(Some(now)),
+            container_format: Set(probe.as_ref().map(|p| p.format.as_str().to_string())),
+            duration_ms: Set(probe.as_ref().and_then(|p| p.duration_ms)),
+            width: Set(probe.as_ref().and_then(|p| p.width)),
+            height: Set(probe.as_ref().and_then(|p| p.height)),
+            video_codec: Set(probe.as_ref().and_then(|p| p.video_codec.clone())),
+            audio_codec: Set(probe.as_ref().and_then(|p| p.audio_codec.clone())),
+            faststart: Set(probe.as_ref().and_then(|p| p.faststart)),
+            segment_index: Set(probe
+                .as_ref()
+                .and_then(|p| p.segment_index.as_ref())
+                .and_then(|index| serde_json::to_value(index).ok())),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+    }
+}
+
+pub struct MediaAssetsRepository {
+    db: DatabaseConnection,
+}
+
+impl MediaAssetsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(&self, request: CreateMediaAssetRequest) -> Result<MediaAssetModel, DbErr> {
+        request.into_active_model().insert(&self.db).await
+    }
+
+    pub async fn find_by_id(&self, asset_id: i32) -> Result<Option<MediaAssetModel>, DbErr> {
+        media_assets::Entity::find_by_id(asset_id)
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn find_by_ids(&self, asset_ids: &[i32]) -> Result<Vec<MediaAssetModel>, DbErr> {
+        media_assets::Entity::find()
+            .filter(media_assets::Column::Id.is_in(asset_ids.iter().copied()))
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn find_many(
+        &self,
+        status: Option<MediaAssetStatusEnum>,
+        skip: Option<u64>,
+        take: Option<u64>,
+    ) -> Result<Vec<MediaAssetModel>, DbErr> {
+        let mut query = media_assets::Entity::find().order_by_desc(media_assets::Column::CreatedAt);
+
+        if let Some(status) = status {
+            query = query.filter(media_assets::Column::Status.eq(status.as_str()));
+        }
+        if let Some(skip) = skip {
+            query = query.offset(skip);
+        }
+        if let Some(take) = take {
+            query = query.limit(take);
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Assets nunca verificados vêm primeiro, depois os verificados há mais tempo.
+    pub async fn find_for_verification(&self, limit: u64) -> Result<Vec<MediaAssetModel>, DbErr> {
+        media_assets::Entity::find()
+            .order_by(
+                Expr::col(media_assets::Column::LastVerifiedAt).is_null(),
+                Order::Desc,
+            )
+            .order_by_asc(media_assets::Column::LastVerifiedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+    }
+
+    /// Chaves de todos os blobs do container referenciados por media assets.
+    pub async fn find_storage_keys(&self, container: &str) -> Result<Vec<String>, DbErr> {
+        media_assets::Entity::find()
+            .select_only()
+            .column(media_assets::Column::StorageKey)
+            .filter(media_assets::Column::Container.eq(container))
+            .into_tuple::<String>()
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn delete_many(&self, asset_ids: &[i32]) -> Result<(), DbErr> {
+        media_assets::Entity::delete_many()
+            .filter(media_assets::Column::Id.is_in(asset_ids.iter().copied()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_verification(
+        &self,
+        asset_id: i32,
+        status: MediaAssetStatusEnum,
+    ) -> Result<(), DbErr> {
+        let now = Utc::now().naive_utc();
+
+        media_assets::Entity::update_many()
+            .col_expr(media_assets::Column::Status, Expr::value(status.as_str()))
+            .col_expr(media_assets::Column::LastVerifiedAt, Expr::value(now))
+            .col_expr(media_assets::Column::UpdatedAt, Expr::value(now))
+            .filter(media_assets::Column::Id.eq(asset_id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
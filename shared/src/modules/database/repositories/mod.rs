@@ -1,4 +1,22 @@
+pub mod actors_repository;
+pub mod auth_sessions_repository;
+pub mod availability_repository;
+pub mod catalog_repository;
+pub mod categories_repository;
+pub mod directors_repository;
 pub mod dto;
+pub mod events_repository;
+pub mod home_rows_repository;
+pub mod images_repository;
+pub mod maturity_ratings_repository;
+pub mod media_assets_repository;
+pub mod profiles_repository;
+pub mod recommendations_repository;
+pub mod search_repository;
 pub mod serie_repository;
+pub mod transcode_jobs_repository;
+pub mod trending_repository;
+pub mod upload_sessions_repository;
 pub mod users_repository;
+pub mod video_assets_repository;
 pub mod videos_repository;
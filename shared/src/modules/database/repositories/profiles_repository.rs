@@ -0,0 +1,339 @@
+use crate::enums::maturity_rating_enum::MaturityRatingEnum;
+use crate::modules::database::schema::profiles;
+use crate::modules::database::schema::profiles::Model as ProfileModel;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{
+    ActiveValue::NotSet, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, PaginatorTrait,
+    QueryOrder, Set, Statement, TransactionTrait,
+};
+
+/// Dados de um perfil, já validados. `pin_hash` é o bcrypt do PIN.
+#[derive(Debug, Clone)]
+pub struct ProfileRequest {
+    pub name: String,
+    pub avatar_url: Option<String>,
+    pub maturity_level: MaturityRatingEnum,
+    pub is_kids: bool,
+    pub pin_hash: Option<String>,
+}
+
+pub struct ProfilesRepository {
+    db: DatabaseConnection,
+}
+
+impl ProfilesRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Cria o perfil se a conta ainda tiver menos de `limit` perfis; `None`
+    /// quando o limite já foi atingido. A linha do usuário fica travada até o
+    /// fim da transação, então criações simultâneas não passam do limite.
+    pub async fn create_within_limit(
+        &self,
+        user_id: &str,
+        request: ProfileRequest,
+        limit: u64,
+    ) -> Result<Option<ProfileModel>, DbErr> {
+        let txn = self.db.begin().await?;
+
+        txn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT 1 FROM users WHERE id = $1 FOR UPDATE",
+            [user_id.into()],
+        ))
+        .await?;
+
+        let count = profiles::Entity::find()
+            .filter(profiles::Column::UserId.eq(user_id))
+            .count(&txn)
+            .await?;
+        if count >= limit {
+            return Ok(None);
+        }
+
+        let profile = Self::insert(&txn, user_id, request, false).await?;
+        txn.commit().await?;
+
+        Ok(Some(profile))
+    }
+
+    /// Perfil principal criado junto com a conta, na transação do cadastro.
+    pub async fn create_default<C: ConnectionTrait>(
+        conn: &C,
+        user_id: &str,
+        name: &str,
+    ) -> Result<ProfileModel, DbErr> {
+        let request = ProfileRequest {
+            name: name.chars().take(50).collect(),
+            avatar_url: None,
+            maturity_level: MaturityRatingEnum::A18,
+            is_kids: false,
+            pin_hash: None,
+        };
+
+        Self::insert(conn, user_id, request, true).await
+    }
+
+    async fn insert<C: ConnectionTrait>(
+        conn: &C,
+        user_id: &str,
+        request: ProfileRequest,
+        is_default: bool,
+    ) -> Result<ProfileModel, DbErr> {
+        let now = Utc::now().naive_utc();
+        let profile = profiles::ActiveModel {
+            id: NotSet,
+            user_id: Set(user_id.to_string()),
+            name: Set(request.name),
+            avatar_url: Set(request.avatar_url),
+            maturity_level: Set(request.maturity_level.as_str().to_string()),
+            is_kids: Set(request.is_kids),
+            pin_hash: Set(request.pin_hash),
+            is_default: Set(is_default),
+            pin_failed_attempts: Set(0),
+            pin_locked_until: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        profile.insert(conn).await
+    }
+
+    /// Perfis da conta, o principal primeiro.
+    pub async fn find_by_user(&self, user_id: &str) -> Result<Vec<ProfileModel>, DbErr> {
+        profiles::Entity::find()
+            .filter(profiles::Column::UserId.eq(user_id))
+            .order_by_desc(profiles::Column::IsDefault)
+            .order_by_asc(profiles::Column::CreatedAt)
+            .order_by_asc(profiles::Column::Id)
+            .all(&self.db)
+            .await
+    }
+
+    /// Perfil só se pertencer à conta.
+    pub async fn find_owned(
+        &self,
+        user_id: &str,
+        profile_id: i32,
+    ) -> Result<Option<ProfileModel>, DbErr> {
+        profiles::Entity::find_by_id(profile_id)
+            .filter(profiles::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn find_default(&self, user_id: &str) -> Result<Option<ProfileModel>, DbErr> {
+        profiles::Entity::find()
+            .filter(profiles::Column::UserId.eq(user_id))
+            .filter(profiles::Column::IsDefault.eq(true))
+            .one(&self.db)
+            .await
+    }
+
+    /// Conta uma tentativa de PIN antes de conferi-lo. Na `max_attempts`-ésima
+    /// tentativa seguida sem acerto o PIN fica bloqueado até `locked_until`.
+    /// Retorna `false` se o PIN já está bloqueado em `now`.
+    pub async fn reserve_pin_attempt(
+        &self,
+        profile_id: i32,
+        max_attempts: i32,
+        now: NaiveDateTime,
+        locked_until: NaiveDateTime,
+    ) -> Result<bool, DbErr> {
+        let reserved = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "UPDATE profiles
+                 SET pin_failed_attempts = CASE WHEN pin_failed_attempts + 1 >= $2
+                         THEN 0 ELSE pin_failed_attempts + 1 END,
+                     pin_locked_until = CASE WHEN pin_failed_attempts + 1 >= $2
+                         THEN $4 ELSE NULL END
+                 WHERE id = $1 AND (pin_locked_until IS NULL OR pin_locked_until <= $3)
+                 RETURNING id",
+                [
+                    profile_id.into(),
+                    max_attempts.into(),
+                    now.into(),
+                    locked_until.into(),
+                ],
+            ))
+            .await?;
+
+        Ok(reserved.is_some())
+    }
+
+    /// PIN certo: zera as tentativas e tira o bloqueio.
+    pub async fn reset_pin_attempts(&self, profile_id: i32) -> Result<(), DbErr> {
+        profiles::Entity::update_many()
+            .col_expr(profiles::Column::PinFailedAttempts, Expr::value(0))
+            .col_expr(
+                profiles::Column::PinLockedUntil,
+                Expr::value(None::<NaiveDateTime>),
+            )
+            .filter(profiles::Column::Id.eq(profile_id))
+            .filter(
+                profiles::Column::PinFailedAttempts
+                    .gt(0)
+                    .or(profiles::Column::PinLockedUntil.is_not_null()),
+            )
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Outro perfil da conta com o mesmo nome, ignorando maiúsculas.
+    pub async fn name_taken(
+        &self,
+        user_id: &str,
+        name: &str,
+        except_id: Option<i32>,
+    ) -> Result<bool, DbErr> {
+        let mut query = profiles::Entity::find()
+            .filter(profiles::Column::UserId.eq(user_id))
+            .filter(
+                Expr::expr(Func::lower(Expr::col(profiles::Column::Name))).eq(name.to_lowercase()),
+            );
+
+        if let Some(except_id) = except_id {
+            query = query.filter(profiles::Column::Id.ne(except_id));
+        }
+
+        Ok(query.count(&self.db).await? > 0)
+    }
+
+    pub async fn update(
+        &self,
+        profile_id: i32,
+        request: ProfileRequest,
+    ) -> Result<ProfileModel, DbErr> {
+        let profile = profiles::ActiveModel {
+            id: Set(profile_id),
+            user_id: NotSet,
+            name: Set(request.name),
+            avatar_url: Set(request.avatar_url),
+            maturity_level: Set(request.maturity_level.as_str().to_string()),
+            is_kids: Set(request.is_kids),
+            pin_hash: Set(request.pin_hash),
+            is_default: NotSet,
+            pin_failed_attempts: NotSet,
+            pin_locked_until: NotSet,
+            created_at: NotSet,
+            updated_at: Set(Utc::now().naive_utc()),
+        };
+
+        profile.update(&self.db).await
+    }
+
+    /// Apaga o perfil; histórico, lista e avaliações vão junto pela FK.
+    pub async fn delete(&self, profile_id: i32) -> Result<bool, DbErr> {
+        let result = profiles::Entity::delete_by_id(profile_id)
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+/// Os testes com banco ficam com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::database::test_support::test_db;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    fn request(name: &str) -> ProfileRequest {
+        ProfileRequest {
+            name: name.to_string(),
+            avatar_url: None,
+            maturity_level: MaturityRatingEnum::A18,
+            is_kids: false,
+            pin_hash: None,
+        }
+    }
+
+    async fn user(db: &DatabaseConnection) -> String {
+        let user_id = Uuid::new_v4().to_string();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "INSERT INTO users (id, email, password_hash, name, role, created_at, updated_at)
+             VALUES ($1, $1 || '@profiles.test', 'x', 'Perfis', 'test', now(), now())",
+            [user_id.clone().into()],
+        ))
+        .await
+        .unwrap();
+        user_id
+    }
+
+    async fn delete_user(db: &DatabaseConnection, user_id: String) {
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM users WHERE id = $1",
+            [user_id.into()],
+        ))
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn concurrent_creates_stop_at_the_limit() {
+        let db = test_db().await;
+        let repo = ProfilesRepository::new(db.clone());
+        let user_id = user(&db).await;
+
+        let created = futures::future::join_all(
+            (0..6)
+                .map(|n| repo.create_within_limit(&user_id, request(&format!("Perfil {}", n)), 3)),
+        )
+        .await;
+
+        let created = created
+            .into_iter()
+            .map(|result| result.unwrap())
+            .filter(Option::is_some)
+            .count();
+        assert_eq!(created, 3);
+        assert_eq!(repo.find_by_user(&user_id).await.unwrap().len(), 3);
+
+        delete_user(&db, user_id).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn pin_locks_after_max_attempts_until_it_expires() {
+        let db = test_db().await;
+        let repo = ProfilesRepository::new(db.clone());
+        let user_id = user(&db).await;
+        let profile = ProfilesRepository::create_default(&db, &user_id, "Dono")
+            .await
+            .unwrap();
+
+        let now = Utc::now().naive_utc();
+        let locked_until = now + Duration::minutes(15);
+        let reserve = |at| repo.reserve_pin_attempt(profile.id, 3, at, locked_until);
+
+        // um acerto no meio zera a contagem
+        assert!(reserve(now).await.unwrap());
+        assert!(reserve(now).await.unwrap());
+        repo.reset_pin_attempts(profile.id).await.unwrap();
+
+        for _ in 0..3 {
+            assert!(reserve(now).await.unwrap());
+        }
+        assert!(!reserve(now).await.unwrap());
+        assert!(!reserve(locked_until - Duration::seconds(1)).await.unwrap());
+        assert!(reserve(locked_until).await.unwrap());
+
+        let stored = repo.find_default(&user_id).await.unwrap().unwrap();
+        assert_eq!(stored.pin_failed_attempts, 1);
+        assert_eq!(stored.pin_locked_until, None);
+
+        delete_user(&db, user_id).await;
+    }
+}
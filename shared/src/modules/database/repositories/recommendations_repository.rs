@@ -0,0 +1,288 @@
+use crate::modules::database::repositories::availability_repository::live_videos_sql;
+use crate::modules::database::repositories::search_repository::LiveFilter;
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, DbErr, FromQueryResult, Statement,
+    TransactionTrait,
+};
+
+/// Peso de cada sinal na nota final de similaridade entre dois vídeos.
+pub const CO_WATCH_WEIGHT: f64 = 0.5;
+pub const RATING_WEIGHT: f64 = 0.2;
+pub const METADATA_WEIGHT: f64 = 0.3;
+
+/// Quantos vídeos parecidos são guardados para cada vídeo.
+pub const SIMILAR_PER_VIDEO: i64 = 50;
+
+/// Menos que isso assistido (e não concluído) não conta como interesse.
+pub const MIN_WATCHED_SECONDS: i32 = 60;
+
+/// Itens mais recentes do histórico usados como base da linha personalizada.
+const SEED_LIMIT: i64 = 20;
+
+#[derive(Debug, Clone)]
+pub struct SimilarityReport {
+    pub pairs: u64,
+    pub computed_at: NaiveDateTime,
+}
+
+/// Vídeo recomendado; `because_video_id` é o item do histórico que mais
+/// contribuiu, quando a recomendação é personalizada.
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct Recommendation {
+    pub video_id: i32,
+    pub score: f64,
+    pub because_video_id: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PersonalQuery {
+    pub profile_id: i32,
+    pub live: LiveFilter,
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct PopularQuery {
+    /// Com perfil, tira o que ele já concluiu.
+    pub profile_id: Option<i32>,
+    pub live: LiveFilter,
+    /// Audiência contada a partir deste instante.
+    pub since: NaiveDateTime,
+    pub exclude: Vec<i32>,
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecommendationsRepository {
+    db: DatabaseConnection,
+}
+
+fn ids_param(ids: &[i32]) -> String {
+    ids.iter().map(i32::to_string).collect::<Vec<_>>().join(",")
+}
+
+impl RecommendationsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Recalcula toda a tabela `video_similarities` numa transação. A nota
+    /// combina três cossenos entre os vídeos: quem assistiu aos dois
+    /// (concluído pesa 1, parcial 0,5), as notas dadas pelos mesmos perfis
+    /// (centradas na média de cada um) e categorias, elenco e direção em
+    /// comum. Episódios da mesma série não contam como parecidos entre si.
+    pub async fn rebuild_similarities(
+        &self,
+        computed_at: NaiveDateTime,
+    ) -> Result<SimilarityReport, DbErr> {
+        let statement = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "WITH catalog AS ( \
+                SELECT id, series_id FROM videos WHERE deleted_at IS NULL), \
+             engagement AS ( \
+                SELECT wh.profile_id, wh.video_id, \
+                       MAX(CASE WHEN wh.is_completed THEN 1.0 ELSE 0.5 END)::float8 AS weight \
+                FROM watch_history wh JOIN catalog c ON c.id = wh.video_id \
+                WHERE wh.is_completed OR wh.watched_seconds >= $1 \
+                GROUP BY wh.profile_id, wh.video_id), \
+             engagement_norm AS ( \
+                SELECT video_id, sqrt(SUM(weight * weight)) AS norm \
+                FROM engagement GROUP BY video_id), \
+             co_watch AS ( \
+                SELECT a.video_id, b.video_id AS similar_video_id, \
+                       SUM(a.weight * b.weight) / (na.norm * nb.norm) AS score \
+                FROM engagement a \
+                JOIN engagement b ON b.profile_id = a.profile_id AND b.video_id <> a.video_id \
+                JOIN engagement_norm na ON na.video_id = a.video_id \
+                JOIN engagement_norm nb ON nb.video_id = b.video_id \
+                GROUP BY a.video_id, b.video_id, na.norm, nb.norm), \
+             rated AS ( \
+                SELECT profile_id, video_id, centered FROM ( \
+                    SELECT r.profile_id, r.video_id, \
+                           (r.rating - AVG(r.rating) OVER (PARTITION BY r.profile_id))::float8 \
+                               AS centered \
+                    FROM ratings r JOIN catalog c ON c.id = r.video_id) centered_ratings \
+                WHERE centered <> 0), \
+             rated_norm AS ( \
+                SELECT video_id, sqrt(SUM(centered * centered)) AS norm \
+                FROM rated GROUP BY video_id), \
+             rating_similarity AS ( \
+                SELECT a.video_id, b.video_id AS similar_video_id, \
+                       SUM(a.centered * b.centered) / (na.norm * nb.norm) AS score \
+                FROM rated a \
+                JOIN rated b ON b.profile_id = a.profile_id AND b.video_id <> a.video_id \
+                JOIN rated_norm na ON na.video_id = a.video_id \
+                JOIN rated_norm nb ON nb.video_id = b.video_id \
+                GROUP BY a.video_id, b.video_id, na.norm, nb.norm), \
+             features AS ( \
+                SELECT video_id, 'c' || category_id AS feature, 0.4::float8 AS weight \
+                FROM video_categories \
+                UNION ALL \
+                SELECT video_id, 'a' || actor_id, 0.35 FROM video_actors \
+                UNION ALL \
+                SELECT video_id, 'd' || director_id, 0.25 FROM video_directors), \
+             features_norm AS ( \
+                SELECT video_id, sqrt(SUM(weight * weight)) AS norm \
+                FROM features GROUP BY video_id), \
+             metadata AS ( \
+                SELECT a.video_id, b.video_id AS similar_video_id, \
+                       SUM(a.weight * b.weight) / (na.norm * nb.norm) AS score \
+                FROM features a \
+                JOIN features b ON b.feature = a.feature AND b.video_id <> a.video_id \
+                JOIN features_norm na ON na.video_id = a.video_id \
+                JOIN features_norm nb ON nb.video_id = b.video_id \
+                GROUP BY a.video_id, b.video_id, na.norm, nb.norm), \
+             pairs AS ( \
+                SELECT video_id, similar_video_id, \
+                       SUM(co_watch) AS co_watch, SUM(rating) AS rating, \
+                       SUM(metadata) AS metadata \
+                FROM ( \
+                    SELECT video_id, similar_video_id, score AS co_watch, \
+                           0::float8 AS rating, 0::float8 AS metadata FROM co_watch \
+                    UNION ALL \
+                    SELECT video_id, similar_video_id, 0, score, 0 FROM rating_similarity \
+                    UNION ALL \
+                    SELECT video_id, similar_video_id, 0, 0, score FROM metadata) signals \
+                GROUP BY video_id, similar_video_id), \
+             scored AS ( \
+                SELECT p.*, $2 * p.co_watch + $3 * p.rating + $4 * p.metadata AS score \
+                FROM pairs p \
+                JOIN catalog a ON a.id = p.video_id \
+                JOIN catalog b ON b.id = p.similar_video_id \
+                WHERE a.series_id IS NULL OR a.series_id IS DISTINCT FROM b.series_id), \
+             ranked AS ( \
+                SELECT scored.*, ROW_NUMBER() OVER ( \
+                    PARTITION BY video_id ORDER BY score DESC, similar_video_id) AS position \
+                FROM scored WHERE score > 0) \
+             INSERT INTO video_similarities \
+                (video_id, similar_video_id, score, co_watch_score, rating_score, \
+                 metadata_score, computed_at) \
+             SELECT video_id, similar_video_id, score, co_watch, rating, metadata, $6 \
+             FROM ranked WHERE position <= $5",
+            [
+                MIN_WATCHED_SECONDS.into(),
+                CO_WATCH_WEIGHT.into(),
+                RATING_WEIGHT.into(),
+                METADATA_WEIGHT.into(),
+                SIMILAR_PER_VIDEO.into(),
+                computed_at.into(),
+            ],
+        );
+
+        let txn = self.db.begin().await?;
+        txn.execute_unprepared("DELETE FROM video_similarities")
+            .await?;
+        let inserted = txn.execute(statement).await?;
+        txn.commit().await?;
+
+        Ok(SimilarityReport {
+            pairs: inserted.rows_affected(),
+            computed_at,
+        })
+    }
+
+    /// Vídeos no ar mais parecidos com `video_id`, do mais ao menos parecido.
+    pub async fn find_similar(
+        &self,
+        video_id: i32,
+        live: &LiveFilter,
+        limit: u64,
+    ) -> Result<Vec<Recommendation>, DbErr> {
+        let (from, condition) = live_videos_sql(live.max_rating);
+
+        Recommendation::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT v.id AS video_id, vs.score, NULL::int AS because_video_id \
+                 FROM {from} JOIN video_similarities vs ON vs.similar_video_id = v.id \
+                 WHERE {condition} AND vs.video_id = $3 \
+                 ORDER BY vs.score DESC, v.id LIMIT $4"
+            ),
+            [
+                live.at.into(),
+                live.region.clone().into(),
+                video_id.into(),
+                (limit as i64).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await
+    }
+
+    /// "Porque você assistiu": soma a similaridade com os itens mais recentes
+    /// do histórico (com o mesmo mínimo assistido do cálculo), pesando mais os
+    /// últimos, e tira o que o perfil já concluiu. Vazio para quem ainda não tem histórico.
+    pub async fn find_personal(&self, query: &PersonalQuery) -> Result<Vec<Recommendation>, DbErr> {
+        let (from, condition) = live_videos_sql(query.live.max_rating);
+
+        Recommendation::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "WITH seeds AS ( \
+                    SELECT video_id, ROW_NUMBER() OVER (ORDER BY last_watched_at DESC) AS recency \
+                    FROM (SELECT video_id, MAX(last_watched_at) AS last_watched_at \
+                          FROM watch_history WHERE profile_id = $3 \
+                          AND (is_completed OR watched_seconds >= $6) GROUP BY video_id \
+                          ORDER BY last_watched_at DESC LIMIT $5) recent), \
+                 candidates AS ( \
+                    SELECT vs.similar_video_id AS video_id, \
+                           SUM(vs.score / sqrt(s.recency)) AS score, \
+                           (ARRAY_AGG(vs.video_id ORDER BY vs.score / sqrt(s.recency) DESC))[1] \
+                               AS because_video_id \
+                    FROM seeds s JOIN video_similarities vs ON vs.video_id = s.video_id \
+                    GROUP BY vs.similar_video_id) \
+                 SELECT c.video_id, c.score, c.because_video_id \
+                 FROM {from} JOIN candidates c ON c.video_id = v.id \
+                 WHERE {condition} AND NOT EXISTS ( \
+                    SELECT 1 FROM watch_history wh \
+                    WHERE wh.profile_id = $3 AND wh.video_id = v.id AND wh.is_completed) \
+                 ORDER BY c.score DESC, v.id LIMIT $4"
+            ),
+            [
+                query.live.at.into(),
+                query.live.region.clone().into(),
+                query.profile_id.into(),
+                (query.limit as i64).into(),
+                SEED_LIMIT.into(),
+                MIN_WATCHED_SECONDS.into(),
+            ],
+        ))
+        .all(&self.db)
+        .await
+    }
+
+    /// Mais assistidos por perfis diferentes desde `since`; empates (e o
+    /// catálogo sem audiência) seguem a nota média e a data de cadastro.
+    pub async fn find_popular(&self, query: &PopularQuery) -> Result<Vec<Recommendation>, DbErr> {
+        let (from, condition) = live_videos_sql(query.live.max_rating);
+
+        Recommendation::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT v.id AS video_id, COALESCE(p.viewers, 0)::float8 AS score, \
+                        NULL::int AS because_video_id \
+                 FROM {from} \
+                 LEFT JOIN ( \
+                    SELECT video_id, COUNT(DISTINCT profile_id) AS viewers FROM watch_history \
+                    WHERE last_watched_at >= $3 GROUP BY video_id) p ON p.video_id = v.id \
+                 WHERE {condition} \
+                 AND NOT (v.id = ANY(string_to_array($6, ',')::int[])) \
+                 AND ($4::int IS NULL OR NOT EXISTS ( \
+                    SELECT 1 FROM watch_history wh \
+                    WHERE wh.profile_id = $4 AND wh.video_id = v.id AND wh.is_completed)) \
+                 ORDER BY score DESC, v.rating DESC, v.created_at DESC, v.id DESC LIMIT $5"
+            ),
+            [
+                query.live.at.into(),
+                query.live.region.clone().into(),
+                query.since.into(),
+                query.profile_id.into(),
+                (query.limit as i64).into(),
+                ids_param(&query.exclude).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await
+    }
+}
@@ -0,0 +1,683 @@
+use crate::enums::access_group_enum::AccessGroupEnum;
+use crate::enums::maturity_rating_enum::MaturityRatingEnum;
+use crate::enums::media_asset_status_enum::MediaAssetStatusEnum;
+use crate::enums::suggestion_kind_enum::SuggestionKindEnum;
+use crate::enums::video_asset_kind_enum::VideoAssetKindEnum;
+use crate::enums::video_type_enum::VideoTypeEnum;
+use crate::modules::database::repositories::availability_repository::{
+    live_series_sql, live_videos_sql,
+};
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, DbErr, FromQueryResult, Statement, Value,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// Configuração de busca criada na migração: dicionário português sem acentos.
+pub const SEARCH_CONFIG: &str = "portuguese_unaccent";
+
+/// Trechos com os termos da busca entre `<b>` e `</b>`, por campo.
+pub type Highlights = BTreeMap<String, String>;
+
+/// Instante e região usados para as janelas de disponibilidade, e a
+/// classificação máxima do perfil (`None` libera tudo).
+#[derive(Debug, Clone)]
+pub struct LiveFilter {
+    pub at: NaiveDateTime,
+    pub region: Option<String>,
+    pub max_rating: Option<MaturityRatingEnum>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct IndexQuery {
+    /// Termos no formato do `websearch_to_tsquery` (aspas, `or`, `-termo`).
+    /// Sem texto, lista do mais recente para o mais antigo.
+    pub text: Option<String>,
+    pub category_id: Option<i32>,
+    pub year_from: Option<i32>,
+    pub year_to: Option<i32>,
+    pub video_type: Option<VideoTypeEnum>,
+    /// `Some(true)` traz só vídeos com qualidade acima da liberada ao plano
+    /// VIEWER; `Some(false)`, só os demais.
+    pub premium: Option<bool>,
+    /// Só o que está no ar; `None` inclui também o que está fora do ar.
+    pub live: Option<LiveFilter>,
+    pub skip: u64,
+    pub take: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexHit {
+    pub video_id: i32,
+    pub score: f32,
+    pub highlights: Highlights,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct IndexSearchResult {
+    pub hits: Vec<IndexHit>,
+    pub total: u64,
+}
+
+/// O que os índices de busca guardam de cada vídeo.
+#[derive(Debug, Clone)]
+pub struct SearchDocument {
+    pub video_id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub series_title: Option<String>,
+    /// Nomes do elenco e da direção.
+    pub people: Vec<String>,
+    pub category_ids: Vec<i32>,
+    pub release_year: Option<i32>,
+    pub is_episode: bool,
+    pub premium: bool,
+    pub is_available: bool,
+    pub created_at: NaiveDateTime,
+}
+
+/// Item cuja mudança altera os documentos dos vídeos ligados a ele.
+#[derive(Debug, Clone, Copy)]
+pub enum IndexScope {
+    Serie(i32),
+    Actor(i32),
+    Director(i32),
+    Category(i32),
+}
+
+#[derive(Debug, Clone, Serialize, FromQueryResult)]
+pub struct CategoryFacet {
+    pub id: i32,
+    pub name: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, FromQueryResult)]
+pub struct ReleaseYearFacet {
+    pub value: i32,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VideoTypeFacet {
+    pub value: VideoTypeEnum,
+    pub count: i64,
+}
+
+/// Contagens por filtro. Cada faceta ignora o próprio filtro, para que o
+/// cliente mostre as alternativas à seleção atual.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SearchFacets {
+    pub categories: Vec<CategoryFacet>,
+    pub release_years: Vec<ReleaseYearFacet>,
+    pub types: Vec<VideoTypeFacet>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SuggestQuery {
+    /// O que o usuário digitou até agora.
+    pub term: String,
+    pub at: NaiveDateTime,
+    pub region: Option<String>,
+    /// Classificação máxima do perfil; `None` libera tudo.
+    pub max_rating: Option<MaturityRatingEnum>,
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub kind: SuggestionKindEnum,
+    pub id: i32,
+    pub label: String,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Facet {
+    Category,
+    ReleaseYear,
+    VideoType,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CountRow {
+    count: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct IdRow {
+    id: i32,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct HitRow {
+    id: i32,
+    score: f32,
+    title_highlight: Option<String>,
+    series_title_highlight: Option<String>,
+    description_highlight: Option<String>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct DocumentRow {
+    id: i32,
+    title: String,
+    description: Option<String>,
+    series_title: Option<String>,
+    series_id: Option<i32>,
+    release_year: Option<i32>,
+    is_available: bool,
+    created_at: NaiveDateTime,
+    people: Option<String>,
+    category_ids: Option<String>,
+    premium: bool,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct SuggestionRow {
+    kind: String,
+    id: i32,
+    label: String,
+    score: f32,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct VideoTypeRow {
+    is_movie: bool,
+    count: i64,
+}
+
+/// Vídeos com rendition (ou original) disponível acima da altura `{height}`.
+fn premium_sql(height: &str) -> String {
+    format!(
+        "EXISTS (SELECT 1 FROM video_assets pva \
+         JOIN media_assets pma ON pma.id = pva.media_asset_id \
+         WHERE pva.video_id = v.id AND pva.kind IN ('{}', '{}') \
+         AND pma.status = '{}' AND pva.height > {height})",
+        VideoAssetKindEnum::RENDITION.as_str(),
+        VideoAssetKindEnum::SOURCE.as_str(),
+        MediaAssetStatusEnum::AVAILABLE.as_str()
+    )
+}
+
+/// Altura máxima do plano VIEWER; acima dela o vídeo conta como premium.
+fn premium_height() -> i32 {
+    AccessGroupEnum::VIEWER
+        .max_playback_height()
+        .unwrap_or(i32::MAX)
+}
+
+/// Lista de ids como parâmetro (`ANY(string_to_array($n, ',')::int[])`).
+fn ids_param(ids: &[i32]) -> String {
+    ids.iter().map(i32::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// Consulta montada com os parâmetros numerados na ordem em que são usados.
+struct SearchSql {
+    from: String,
+    conditions: Vec<String>,
+    values: Vec<Value>,
+    tsquery: Option<String>,
+}
+
+impl SearchSql {
+    fn new(query: &IndexQuery, without: Option<Facet>) -> Self {
+        let mut sql = match &query.live {
+            Some(live) => {
+                let (from, condition) = live_videos_sql(live.max_rating);
+                Self {
+                    from,
+                    conditions: vec![condition],
+                    values: vec![live.at.into(), live.region.clone().into()],
+                    tsquery: None,
+                }
+            }
+            None => Self {
+                from: "videos v LEFT JOIN series s ON s.id = v.series_id".to_string(),
+                conditions: vec![
+                    "v.deleted_at IS NULL AND (v.series_id IS NULL OR s.deleted_at IS NULL)"
+                        .to_string(),
+                ],
+                values: Vec::new(),
+                tsquery: None,
+            },
+        };
+
+        if let Some(text) = &query.text {
+            let param = sql.bind(text.clone());
+            let tsquery = format!("websearch_to_tsquery('{SEARCH_CONFIG}', {param})");
+            sql.conditions.push(format!("v.search_vector @@ {tsquery}"));
+            sql.tsquery = Some(tsquery);
+        }
+
+        if let (Some(category_id), false) = (query.category_id, without == Some(Facet::Category)) {
+            let param = sql.bind(category_id);
+            sql.conditions.push(format!(
+                "EXISTS (SELECT 1 FROM video_categories vc \
+                 WHERE vc.video_id = v.id AND vc.category_id = {param})"
+            ));
+        }
+
+        if without != Some(Facet::ReleaseYear) {
+            if let Some(year) = query.year_from {
+                let param = sql.bind(year);
+                sql.conditions.push(format!("v.release_year >= {param}"));
+            }
+            if let Some(year) = query.year_to {
+                let param = sql.bind(year);
+                sql.conditions.push(format!("v.release_year <= {param}"));
+            }
+        }
+
+        if let (Some(video_type), false) = (query.video_type, without == Some(Facet::VideoType)) {
+            sql.conditions.push(match video_type {
+                VideoTypeEnum::MOVIE => "v.series_id IS NULL".to_string(),
+                VideoTypeEnum::EPISODE => "v.series_id IS NOT NULL".to_string(),
+            });
+        }
+
+        if let Some(premium) = query.premium {
+            let param = sql.bind(premium_height());
+            let exists = premium_sql(&param);
+            sql.conditions.push(if premium {
+                exists
+            } else {
+                format!("NOT {exists}")
+            });
+        }
+
+        sql
+    }
+
+    fn bind(&mut self, value: impl Into<Value>) -> String {
+        self.values.push(value.into());
+        format!("${}", self.values.len())
+    }
+
+    fn rank(&self) -> String {
+        match &self.tsquery {
+            Some(tsquery) => format!("ts_rank(v.search_vector, {tsquery})"),
+            None => "0::real".to_string(),
+        }
+    }
+
+    /// Trecho de `column` com os termos destacados; nulo sem texto na busca.
+    fn headline(&self, column: &str, options: &str) -> String {
+        match &self.tsquery {
+            Some(tsquery) => format!(
+                "ts_headline('{SEARCH_CONFIG}', {column}, {tsquery}, \
+                 'StartSel=<b>, StopSel=</b>, {options}')"
+            ),
+            None => "NULL::text".to_string(),
+        }
+    }
+
+    fn statement(&self, select: &str, joins: &str, tail: &str) -> Statement {
+        Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT {select} FROM {} {joins} WHERE {} {tail}",
+                self.from,
+                self.conditions.join(" AND ")
+            ),
+            self.values.clone(),
+        )
+    }
+}
+
+pub struct SearchRepository {
+    db: DatabaseConnection,
+}
+
+impl SearchRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Busca textual no `search_vector`, ordenada por relevância (`ts_rank`),
+    /// com os trechos destacados de título, série e descrição.
+    pub async fn search(&self, query: &IndexQuery) -> Result<IndexSearchResult, DbErr> {
+        let sql = SearchSql::new(query, None);
+
+        let rows = HitRow::find_by_statement(sql.statement(
+            &format!(
+                "v.id, {} AS score, {} AS title_highlight, \
+                 {} AS series_title_highlight, {} AS description_highlight",
+                sql.rank(),
+                sql.headline("v.title", "HighlightAll=true"),
+                sql.headline("s.title", "HighlightAll=true"),
+                sql.headline("v.description", "MaxFragments=1, MaxWords=30, MinWords=10")
+            ),
+            "",
+            &format!(
+                "ORDER BY score DESC, v.created_at DESC, v.id DESC LIMIT {} OFFSET {}",
+                query.take, query.skip
+            ),
+        ))
+        .all(&self.db)
+        .await?;
+
+        let hits = rows
+            .into_iter()
+            .map(|row| {
+                let highlights = [
+                    ("title", row.title_highlight),
+                    ("series_title", row.series_title_highlight),
+                    ("description", row.description_highlight),
+                ]
+                .into_iter()
+                .filter_map(|(field, fragment)| {
+                    fragment
+                        .filter(|fragment| fragment.contains("<b>"))
+                        .map(|fragment| (field.to_string(), fragment))
+                })
+                .collect();
+
+                IndexHit {
+                    video_id: row.id,
+                    score: row.score,
+                    highlights,
+                }
+            })
+            .collect();
+
+        let total = CountRow::find_by_statement(sql.statement("COUNT(*) AS count", "", ""))
+            .one(&self.db)
+            .await?
+            .map_or(0, |row| row.count);
+
+        Ok(IndexSearchResult {
+            hits,
+            total: total as u64,
+        })
+    }
+
+    /// Contagens por categoria, ano de lançamento e tipo dos vídeos que
+    /// atendem a `query`.
+    pub async fn facets(&self, query: &IndexQuery) -> Result<SearchFacets, DbErr> {
+        let categories = CategoryFacet::find_by_statement(
+            SearchSql::new(query, Some(Facet::Category)).statement(
+                "c.id, c.name, COUNT(*) AS count",
+                "JOIN video_categories fvc ON fvc.video_id = v.id \
+                 JOIN categories c ON c.id = fvc.category_id",
+                "GROUP BY c.id, c.name ORDER BY count DESC, c.name",
+            ),
+        )
+        .all(&self.db)
+        .await?;
+
+        let release_years = ReleaseYearFacet::find_by_statement(
+            SearchSql::new(query, Some(Facet::ReleaseYear)).statement(
+                "v.release_year AS value, COUNT(*) AS count",
+                "",
+                "AND v.release_year IS NOT NULL GROUP BY v.release_year ORDER BY v.release_year DESC",
+            ),
+        )
+        .all(&self.db)
+        .await?;
+
+        let types = VideoTypeRow::find_by_statement(
+            SearchSql::new(query, Some(Facet::VideoType)).statement(
+                "v.series_id IS NULL AS is_movie, COUNT(*) AS count",
+                "",
+                "GROUP BY 1 ORDER BY 1 DESC",
+            ),
+        )
+        .all(&self.db)
+        .await?
+        .into_iter()
+        .map(|row| VideoTypeFacet {
+            value: if row.is_movie {
+                VideoTypeEnum::MOVIE
+            } else {
+                VideoTypeEnum::EPISODE
+            },
+            count: row.count,
+        })
+        .collect();
+
+        Ok(SearchFacets {
+            categories,
+            release_years,
+            types,
+        })
+    }
+
+    /// Documentos dos vídeos fora da lixeira; `None` traz o catálogo inteiro.
+    pub async fn find_documents(
+        &self,
+        video_ids: Option<&[i32]>,
+    ) -> Result<Vec<SearchDocument>, DbErr> {
+        let mut values: Vec<Value> = vec![premium_height().into()];
+        let mut filter = String::new();
+        if let Some(video_ids) = video_ids {
+            if video_ids.is_empty() {
+                return Ok(Vec::new());
+            }
+            values.push(ids_param(video_ids).into());
+            filter = "AND v.id = ANY(string_to_array($2, ',')::int[])".to_string();
+        }
+
+        let rows = DocumentRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT v.id, v.title, v.description, s.title AS series_title, v.series_id, \
+                 v.release_year, v.is_available, v.created_at, \
+                 (SELECT string_agg(p.name, E'\\n') FROM ( \
+                    SELECT a.name FROM video_actors va JOIN actors a ON a.id = va.actor_id \
+                    WHERE va.video_id = v.id \
+                    UNION ALL \
+                    SELECT d.name FROM video_directors vd JOIN directors d ON d.id = vd.director_id \
+                    WHERE vd.video_id = v.id) p) AS people, \
+                 (SELECT string_agg(vc.category_id::text, ',') FROM video_categories vc \
+                  WHERE vc.video_id = v.id) AS category_ids, \
+                 {} AS premium \
+                 FROM videos v LEFT JOIN series s ON s.id = v.series_id \
+                 WHERE v.deleted_at IS NULL AND (v.series_id IS NULL OR s.deleted_at IS NULL) \
+                 {filter} ORDER BY v.id",
+                premium_sql("$1")
+            ),
+            values,
+        ))
+        .all(&self.db)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let category_ids = row
+                    .category_ids
+                    .as_deref()
+                    .unwrap_or_default()
+                    .split(',')
+                    .filter(|id| !id.is_empty())
+                    .map(|id| {
+                        id.parse()
+                            .map_err(|_| DbErr::Custom(format!("Categoria '{}' inválida", id)))
+                    })
+                    .collect::<Result<Vec<i32>, DbErr>>()?;
+
+                Ok(SearchDocument {
+                    video_id: row.id,
+                    title: row.title,
+                    description: row.description,
+                    series_title: row.series_title,
+                    people: row
+                        .people
+                        .map(|people| people.lines().map(str::to_string).collect())
+                        .unwrap_or_default(),
+                    category_ids,
+                    release_year: row.release_year,
+                    is_episode: row.series_id.is_some(),
+                    premium: row.premium,
+                    is_available: row.is_available,
+                    created_at: row.created_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Vídeos (inclusive na lixeira) ligados ao item.
+    pub async fn find_video_ids(&self, scope: IndexScope) -> Result<Vec<i32>, DbErr> {
+        let (sql, id) = match scope {
+            IndexScope::Serie(id) => ("SELECT id FROM videos WHERE series_id = $1", id),
+            IndexScope::Actor(id) => (
+                "SELECT DISTINCT video_id AS id FROM video_actors WHERE actor_id = $1",
+                id,
+            ),
+            IndexScope::Director(id) => (
+                "SELECT DISTINCT video_id AS id FROM video_directors WHERE director_id = $1",
+                id,
+            ),
+            IndexScope::Category(id) => (
+                "SELECT DISTINCT video_id AS id FROM video_categories WHERE category_id = $1",
+                id,
+            ),
+        };
+
+        Ok(IdRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [id.into()],
+        ))
+        .all(&self.db)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect())
+    }
+
+    /// Quais dos vídeos estão no ar no instante e na região, dentro da
+    /// classificação do filtro.
+    pub async fn find_live_ids(
+        &self,
+        video_ids: &[i32],
+        live: &LiveFilter,
+    ) -> Result<HashSet<i32>, DbErr> {
+        if video_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let (from, condition) = live_videos_sql(live.max_rating);
+
+        Ok(IdRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT v.id FROM {from} WHERE {condition} \
+                 AND v.id = ANY(string_to_array($3, ',')::int[])"
+            ),
+            [
+                live.at.into(),
+                live.region.clone().into(),
+                ids_param(video_ids).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect())
+    }
+
+    /// Recalcula o `search_vector` dos vídeos (ou de todos, com `None`). Os
+    /// gatilhos já o mantêm; isto serve para a reindexação completa.
+    pub async fn refresh_search_vectors(&self, video_ids: Option<&[i32]>) -> Result<(), DbErr> {
+        let statement = match video_ids {
+            Some(video_ids) => Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT refresh_video_search_vector(string_to_array($1, ',')::int[])",
+                [ids_param(video_ids).into()],
+            ),
+            None => Statement::from_string(
+                DbBackend::Postgres,
+                "UPDATE videos SET search_vector = \
+                 video_search_document(id, title, description, series_id)",
+            ),
+        };
+
+        self.db.execute(statement).await?;
+
+        Ok(())
+    }
+
+    /// Títulos, séries, atores e diretores parecidos com `term`, do mais
+    /// parecido para o menos. Tolera erros de digitação pela similaridade de
+    /// trigramas e dá preferência a quem começa (ou tem uma palavra que começa)
+    /// com o termo. Vídeos e séries fora do ar ficam de fora.
+    pub async fn suggest(&self, query: &SuggestQuery) -> Result<Vec<Suggestion>, DbErr> {
+        let (videos_from, videos_live) = live_videos_sql(query.max_rating);
+        let (series_from, series_live) = live_series_sql(query.max_rating);
+
+        let branch = |kind: SuggestionKindEnum, from: &str, live: &str, id: &str, label: &str| {
+            let text = format!("search_normalize({label})");
+            format!(
+                "(SELECT '{kind}' AS kind, {id} AS id, {label} AS label, \
+                 (GREATEST(similarity({text}, t.term), word_similarity(t.term, {text})) \
+                 + CASE WHEN starts_with({text}, t.term) THEN 1.0 \
+                 WHEN strpos({text}, ' ' || t.term) > 0 THEN 0.5 ELSE 0.0 END)::real AS score \
+                 FROM {from} CROSS JOIN t \
+                 WHERE {live} AND ({text} % t.term OR t.term <% {text} \
+                 OR starts_with({text}, t.term)) \
+                 ORDER BY score DESC, label LIMIT $4)",
+                kind = kind.as_str()
+            )
+        };
+
+        let sql = format!(
+            "WITH t AS (SELECT search_normalize($3) AS term) \
+             SELECT * FROM ({} UNION ALL {} UNION ALL {} UNION ALL {}) suggestions \
+             ORDER BY score DESC, label, kind LIMIT $4",
+            branch(
+                SuggestionKindEnum::VIDEO,
+                &videos_from,
+                &videos_live,
+                "v.id",
+                "v.title"
+            ),
+            branch(
+                SuggestionKindEnum::SERIE,
+                &series_from,
+                &series_live,
+                "s.id",
+                "s.title"
+            ),
+            branch(
+                SuggestionKindEnum::ACTOR,
+                "actors a",
+                "TRUE",
+                "a.id",
+                "a.name"
+            ),
+            branch(
+                SuggestionKindEnum::DIRECTOR,
+                "directors d",
+                "TRUE",
+                "d.id",
+                "d.name"
+            ),
+        );
+
+        SuggestionRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [
+                query.at.into(),
+                query.region.clone().into(),
+                query.term.clone().into(),
+                (query.limit as i64).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await?
+        .into_iter()
+        .map(|row| {
+            Ok(Suggestion {
+                kind: SuggestionKindEnum::try_from(row.kind.as_str()).map_err(DbErr::Custom)?,
+                id: row.id,
+                label: row.label,
+                score: row.score,
+            })
+        })
+        .collect()
+    }
+}
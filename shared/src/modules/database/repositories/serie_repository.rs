@@ -1,10 +1,27 @@
-use crate::modules::database::schema::series;
+use crate::enums::availability_owner_enum::AvailabilityOwnerEnum;
+use crate::enums::image_owner_enum::ImageOwnerEnum;
+use crate::enums::serie_episodes_action_enum::SerieEpisodesActionEnum;
+use crate::modules::database::repositories::availability_repository::delete_windows;
+use crate::modules::database::repositories::images_repository::delete_owner_images;
+use crate::modules::database::repositories::videos_repository::{purge_video_rows, PurgedVideo};
+use crate::modules::database::schema::images::Model as ImageModel;
 use crate::modules::database::schema::series::Model as SerieModel;
-use chrono::Utc;
+use crate::modules::database::schema::videos::Model as VideoModel;
+use crate::modules::database::schema::{series, videos};
+use chrono::{NaiveDateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{DatabaseConnection, DbErr, Set};
+use sea_orm::sea_query::Expr;
+use sea_orm::{DatabaseConnection, DbErr, QueryOrder, QuerySelect, Set, TransactionTrait};
 use serde::Deserialize;
 
+/// Registros removidos junto com a série, cujos arquivos ainda precisam sair
+/// do storage.
+#[derive(Debug, Default)]
+pub struct PurgedSerie {
+    pub episodes: Vec<(VideoModel, PurgedVideo)>,
+    pub images: Vec<ImageModel>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateSerieRequest {
     pub title: String,
@@ -14,6 +31,23 @@ pub struct CreateSerieRequest {
     pub is_featured: bool,
 }
 
+#[derive(Debug, Deserialize, Default)]
+pub struct UpdateSerieRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub release_year: Option<i32>,
+    pub thumbnail_url: Option<String>,
+    pub is_featured: Option<bool>,
+}
+
+/// Nova posição de um episódio dentro da série.
+#[derive(Debug, Clone, Copy)]
+pub struct EpisodeNumbering {
+    pub video_id: i32,
+    pub season_number: Option<i32>,
+    pub episode_number: i32,
+}
+
 pub struct SerieRepository {
     db: DatabaseConnection,
 }
@@ -34,12 +68,470 @@ impl SerieRepository {
             is_featured: Set(request.is_featured),
             created_at: Set(now),
             updated_at: Set(now),
+            deleted_at: Set(None),
+            external_id: sea_orm::ActiveValue::NotSet,
+            available_from: sea_orm::ActiveValue::NotSet,
+            available_until: sea_orm::ActiveValue::NotSet,
+            release_state: sea_orm::ActiveValue::NotSet,
+            maturity_rating: sea_orm::ActiveValue::NotSet,
+            content_descriptors: sea_orm::ActiveValue::NotSet,
         };
 
         video.insert(&self.db).await
     }
 
+    /// Séries na lixeira são tratadas como inexistentes; use
+    /// [`Self::find_trashed_by_id`] para encontrá-las.
     pub async fn find_by_id(&self, serie_id: i32) -> Result<Option<SerieModel>, DbErr> {
-        series::Entity::find_by_id(serie_id).one(&self.db).await
+        series::Entity::find_by_id(serie_id)
+            .filter(series::Column::DeletedAt.is_null())
+            .one(&self.db)
+            .await
+    }
+
+    /// Séries fora da lixeira com os ids informados, sem ordem definida.
+    pub async fn find_by_ids(&self, serie_ids: &[i32]) -> Result<Vec<SerieModel>, DbErr> {
+        if serie_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        series::Entity::find()
+            .filter(series::Column::Id.is_in(serie_ids.to_vec()))
+            .filter(series::Column::DeletedAt.is_null())
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn find_many(
+        &self,
+        title: Option<&str>,
+        is_featured: Option<bool>,
+        skip: Option<u64>,
+        take: Option<u64>,
+    ) -> Result<Vec<SerieModel>, DbErr> {
+        let mut query = series::Entity::find()
+            .filter(series::Column::DeletedAt.is_null())
+            .order_by_asc(series::Column::Title);
+
+        if let Some(title) = title {
+            query = query.filter(series::Column::Title.contains(title));
+        }
+        if let Some(is_featured) = is_featured {
+            query = query.filter(series::Column::IsFeatured.eq(is_featured));
+        }
+        if let Some(skip) = skip {
+            query = query.offset(skip);
+        }
+        if let Some(take) = take {
+            query = query.limit(take);
+        }
+
+        query.all(&self.db).await
+    }
+
+    pub async fn update(
+        &self,
+        serie_id: i32,
+        request: UpdateSerieRequest,
+    ) -> Result<SerieModel, DbErr> {
+        let mut active_model = series::ActiveModel {
+            id: Set(serie_id),
+            updated_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        if let Some(title) = request.title {
+            active_model.title = Set(title);
+        }
+        if request.description.is_some() {
+            active_model.description = Set(request.description);
+        }
+        if request.release_year.is_some() {
+            active_model.release_year = Set(request.release_year);
+        }
+        if request.thumbnail_url.is_some() {
+            active_model.thumbnail_url = Set(request.thumbnail_url);
+        }
+        if let Some(is_featured) = request.is_featured {
+            active_model.is_featured = Set(is_featured);
+        }
+
+        // série na lixeira não é alterada; volta `RecordNotUpdated`
+        series::Entity::update(active_model)
+            .filter(series::Column::DeletedAt.is_null())
+            .exec(&self.db)
+            .await
+    }
+
+    /// Renumera os episódios de uma vez. Os números antigos são liberados antes
+    /// da gravação, então trocas (1 ↔ 2) não esbarram no índice único.
+    pub async fn reorder_episodes(
+        &self,
+        serie_id: i32,
+        numbering: &[EpisodeNumbering],
+    ) -> Result<(), DbErr> {
+        let now = Utc::now().naive_utc();
+        let video_ids: Vec<i32> = numbering.iter().map(|n| n.video_id).collect();
+        let txn = self.db.begin().await?;
+
+        videos::Entity::update_many()
+            .col_expr(videos::Column::EpisodeNumber, Expr::value(None::<i32>))
+            .filter(videos::Column::Id.is_in(video_ids))
+            .filter(videos::Column::SeriesId.eq(serie_id))
+            .filter(videos::Column::DeletedAt.is_null())
+            .exec(&txn)
+            .await?;
+
+        for episode in numbering {
+            videos::Entity::update_many()
+                .col_expr(
+                    videos::Column::SeasonNumber,
+                    Expr::value(episode.season_number),
+                )
+                .col_expr(
+                    videos::Column::EpisodeNumber,
+                    Expr::value(episode.episode_number),
+                )
+                .col_expr(videos::Column::UpdatedAt, Expr::value(now))
+                .filter(videos::Column::Id.eq(episode.video_id))
+                .filter(videos::Column::SeriesId.eq(serie_id))
+                .filter(videos::Column::DeletedAt.is_null())
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await
+    }
+
+    pub async fn find_trashed_by_id(&self, serie_id: i32) -> Result<Option<SerieModel>, DbErr> {
+        series::Entity::find_by_id(serie_id)
+            .filter(series::Column::DeletedAt.is_not_null())
+            .one(&self.db)
+            .await
+    }
+
+    /// Itens da lixeira, os apagados mais recentemente primeiro.
+    pub async fn find_trashed(
+        &self,
+        skip: Option<u64>,
+        take: Option<u64>,
+    ) -> Result<Vec<SerieModel>, DbErr> {
+        let mut query = series::Entity::find()
+            .filter(series::Column::DeletedAt.is_not_null())
+            .order_by_desc(series::Column::DeletedAt);
+
+        if let Some(skip) = skip {
+            query = query.offset(skip);
+        }
+        if let Some(take) = take {
+            query = query.limit(take);
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Séries na lixeira desde antes de `deleted_before`, prontas para a remoção definitiva.
+    pub async fn find_purgeable(
+        &self,
+        deleted_before: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<SerieModel>, DbErr> {
+        series::Entity::find()
+            .filter(series::Column::DeletedAt.lt(deleted_before))
+            .order_by_asc(series::Column::DeletedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+    }
+
+    /// Move a série para a lixeira. Com [`SerieEpisodesActionEnum::TRASH`] os
+    /// episódios visíveis vão junto, com o mesmo `deleted_at`, o que permite
+    /// restaurar exatamente esses episódios depois; com
+    /// [`SerieEpisodesActionEnum::DETACH`] eles viram vídeos avulsos.
+    pub async fn soft_delete(
+        &self,
+        serie_id: i32,
+        episodes: SerieEpisodesActionEnum,
+    ) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+        let txn = self.db.begin().await?;
+
+        let result = series::Entity::update_many()
+            .col_expr(series::Column::DeletedAt, Expr::value(now))
+            .col_expr(series::Column::UpdatedAt, Expr::value(now))
+            .filter(series::Column::Id.eq(serie_id))
+            .filter(series::Column::DeletedAt.is_null())
+            .exec(&txn)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Ok(false);
+        }
+
+        let episodes_update = match episodes {
+            SerieEpisodesActionEnum::TRASH => videos::Entity::update_many()
+                .col_expr(videos::Column::DeletedAt, Expr::value(now))
+                .filter(videos::Column::DeletedAt.is_null()),
+            // inclui os episódios já na lixeira: restaurados depois, voltam avulsos
+            SerieEpisodesActionEnum::DETACH => videos::Entity::update_many()
+                .col_expr(videos::Column::SeriesId, Expr::value(None::<i32>))
+                .col_expr(videos::Column::SeasonNumber, Expr::value(None::<i32>))
+                .col_expr(videos::Column::EpisodeNumber, Expr::value(None::<i32>)),
+        };
+
+        episodes_update
+            .col_expr(videos::Column::UpdatedAt, Expr::value(now))
+            .filter(videos::Column::SeriesId.eq(serie_id))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Restaura a série e os episódios que foram para a lixeira junto com ela.
+    /// Episódios apagados individualmente antes continuam na lixeira.
+    pub async fn restore(&self, serie_id: i32) -> Result<bool, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let Some(serie) = series::Entity::find_by_id(serie_id)
+            .filter(series::Column::DeletedAt.is_not_null())
+            .one(&txn)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        let now = Utc::now().naive_utc();
+
+        series::Entity::update_many()
+            .col_expr(
+                series::Column::DeletedAt,
+                Expr::value(None::<NaiveDateTime>),
+            )
+            .col_expr(series::Column::UpdatedAt, Expr::value(now))
+            .filter(series::Column::Id.eq(serie_id))
+            .exec(&txn)
+            .await?;
+
+        videos::Entity::update_many()
+            .col_expr(
+                videos::Column::DeletedAt,
+                Expr::value(None::<NaiveDateTime>),
+            )
+            .col_expr(videos::Column::UpdatedAt, Expr::value(now))
+            .filter(videos::Column::SeriesId.eq(serie_id))
+            .filter(videos::Column::DeletedAt.eq(serie.deleted_at))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Remove a série definitivamente, numa única transação com todos os
+    /// episódios, inclusive os que não estão na lixeira.
+    pub async fn purge(&self, serie_id: i32) -> Result<PurgedSerie, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let mut episodes = Vec::new();
+        for episode in videos::Entity::find()
+            .filter(videos::Column::SeriesId.eq(serie_id))
+            .all(&txn)
+            .await?
+        {
+            let purged = purge_video_rows(&txn, episode.id).await?;
+            episodes.push((episode, purged));
+        }
+
+        series::Entity::delete_by_id(serie_id).exec(&txn).await?;
+        delete_windows(&txn, AvailabilityOwnerEnum::SERIE, serie_id).await?;
+        let images = delete_owner_images(&txn, ImageOwnerEnum::SERIE, serie_id).await?;
+
+        txn.commit().await?;
+
+        Ok(PurgedSerie { episodes, images })
+    }
+}
+
+/// Os testes com banco ficam com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::database::test_support::test_db;
+    use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+    async fn insert(db: &DatabaseConnection, sql: &str, values: Vec<sea_orm::Value>) -> i32 {
+        db.query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await
+        .expect(sql)
+        .expect(sql)
+        .try_get("", "id")
+        .expect(sql)
+    }
+
+    async fn count(db: &DatabaseConnection, sql: &str, values: Vec<sea_orm::Value>) -> i64 {
+        db.query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await
+        .expect(sql)
+        .expect(sql)
+        .try_get_by_index(0)
+        .expect(sql)
+    }
+
+    /// Série com dois episódios; o primeiro tem um arquivo e ambos, série
+    /// inclusive, têm janela regional. A série tem uma imagem.
+    async fn fixture(db: &DatabaseConnection) -> (i32, Vec<i32>) {
+        let serie_id = insert(
+            db,
+            "INSERT INTO series (title, is_featured, created_at, updated_at)
+             VALUES ('Série da lixeira', false, now(), now()) RETURNING id",
+            vec![],
+        )
+        .await;
+
+        let mut episode_ids = Vec::new();
+        for number in 1..=2 {
+            episode_ids.push(
+                insert(
+                    db,
+                    "INSERT INTO videos (title, duration_seconds, is_available, rating,
+                        series_id, episode_number, created_at, updated_at)
+                     VALUES ('Episódio', 60, true, 0, $1, $2, now(), now()) RETURNING id",
+                    vec![serie_id.into(), number.into()],
+                )
+                .await,
+            );
+        }
+
+        let media_asset_id = insert(
+            db,
+            "INSERT INTO media_assets (container, storage_key, url, content_type, size_bytes,
+                sha256, status, created_at, updated_at)
+             VALUES ('video', 'trash/ep.mp4', 'http://blob/trash/ep.mp4', 'video/mp4', 1,
+                'x', 'READY', now(), now()) RETURNING id",
+            vec![],
+        )
+        .await;
+        insert(
+            db,
+            "INSERT INTO video_assets (video_id, media_asset_id, kind, created_at, updated_at)
+             VALUES ($1, $2, 'SOURCE', now(), now()) RETURNING id",
+            vec![episode_ids[0].into(), media_asset_id.into()],
+        )
+        .await;
+
+        for (owner, owner_id) in [("SERIE", serie_id), ("VIDEO", episode_ids[1])] {
+            insert(
+                db,
+                "INSERT INTO availability_windows (owner_type, owner_id, region, created_at)
+                 VALUES ($1, $2, 'BR', now()) RETURNING id",
+                vec![owner.into(), owner_id.into()],
+            )
+            .await;
+        }
+
+        insert(
+            db,
+            "INSERT INTO images (owner_type, owner_id, kind, container, format, width, height,
+                size_bytes, sha256, original_key, original_url, variants, created_at, updated_at)
+             VALUES ('SERIE', $1, 'POSTER', 'image', 'JPEG', 100, 100, 1, 'x',
+                'trash/poster.jpg', 'http://blob/trash/poster.jpg', '{}', now(), now())
+             RETURNING id",
+            vec![serie_id.into()],
+        )
+        .await;
+
+        (serie_id, episode_ids)
+    }
+
+    async fn remaining_rows(db: &DatabaseConnection, serie_id: i32, episode_ids: &[i32]) -> i64 {
+        let ids: Vec<sea_orm::Value> = vec![
+            serie_id.into(),
+            episode_ids[0].into(),
+            episode_ids[1].into(),
+        ];
+        count(
+            db,
+            "SELECT (SELECT count(*) FROM series WHERE id = $1)
+                  + (SELECT count(*) FROM videos WHERE id IN ($2, $3))
+                  + (SELECT count(*) FROM video_assets WHERE video_id IN ($2, $3))
+                  + (SELECT count(*) FROM availability_windows
+                     WHERE (owner_type = 'SERIE' AND owner_id = $1)
+                        OR (owner_type = 'VIDEO' AND owner_id IN ($2, $3)))
+                  + (SELECT count(*) FROM images WHERE owner_type = 'SERIE' AND owner_id = $1)",
+            ids,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn purge_removes_serie_episodes_and_dependents() {
+        let db = test_db().await;
+        let (serie_id, episode_ids) = fixture(&db).await;
+        let repo = SerieRepository::new(db.clone());
+
+        let purged = repo.purge(serie_id).await.unwrap();
+
+        let mut purged_ids: Vec<i32> = purged.episodes.iter().map(|(e, _)| e.id).collect();
+        purged_ids.sort();
+        assert_eq!(purged_ids, episode_ids);
+        let assets: Vec<&str> = purged
+            .episodes
+            .iter()
+            .flat_map(|(_, p)| p.media_assets.iter().map(|m| m.storage_key.as_str()))
+            .collect();
+        assert_eq!(assets, vec!["trash/ep.mp4"]);
+        assert_eq!(purged.images.len(), 1);
+        assert_eq!(remaining_rows(&db, serie_id, &episode_ids).await, 0);
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn failed_purge_keeps_everything() {
+        let db = test_db().await;
+        let (serie_id, episode_ids) = fixture(&db).await;
+        let repo = SerieRepository::new(db.clone());
+        let before = remaining_rows(&db, serie_id, &episode_ids).await;
+
+        // Falha no último passo, depois de episódios e série já apagados.
+        let function = format!("fail_purge_{}", serie_id);
+        db.execute_unprepared(&format!(
+            "CREATE FUNCTION {function}() RETURNS trigger AS $$
+             BEGIN
+                 IF OLD.owner_type = 'SERIE' AND OLD.owner_id = {serie_id} THEN
+                     RAISE EXCEPTION 'falha simulada';
+                 END IF;
+                 RETURN OLD;
+             END $$ LANGUAGE plpgsql;
+             CREATE TRIGGER {function} BEFORE DELETE ON images
+                 FOR EACH ROW EXECUTE FUNCTION {function}();"
+        ))
+        .await
+        .unwrap();
+
+        let result = repo.purge(serie_id).await;
+
+        db.execute_unprepared(&format!(
+            "DROP TRIGGER {function} ON images; DROP FUNCTION {function}();"
+        ))
+        .await
+        .unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(remaining_rows(&db, serie_id, &episode_ids).await, before);
+
+        repo.purge(serie_id).await.unwrap();
     }
 }
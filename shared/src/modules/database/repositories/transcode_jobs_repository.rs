@@ -0,0 +1,431 @@
+use crate::enums::transcode_job_status_enum::TranscodeJobStatusEnum;
+use crate::enums::video_asset_kind_enum::VideoAssetKindEnum;
+use crate::modules::database::schema::transcode_jobs;
+use crate::modules::database::schema::transcode_jobs::Model as TranscodeJobModel;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, DbErr, QueryOrder, QuerySelect, Set, Statement,
+};
+
+/// Tentativas antes de um job ser marcado como `FAILED` em definitivo.
+pub const DEFAULT_TRANSCODE_MAX_ATTEMPTS: i32 = 3;
+
+/// Erro gravado nos jobs cujo worker parou de responder.
+pub const STALE_JOB_ERROR: &str = "O worker parou de responder durante o job";
+
+/// Resultado de [`TranscodeJobsRepository::requeue_stale`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StaleJobs {
+    pub requeued: u64,
+    /// Jobs sem tentativas restantes, marcados como `FAILED`.
+    pub failed: u64,
+}
+
+pub struct TranscodeJobsRepository {
+    db: DatabaseConnection,
+}
+
+impl TranscodeJobsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn enqueue(
+        &self,
+        video_id: i32,
+        source_media_asset_id: i32,
+    ) -> Result<TranscodeJobModel, DbErr> {
+        enqueue_transcode_job(&self.db, video_id, source_media_asset_id).await
+    }
+
+    /// Enfileira o job do original que acabou de ser enviado, se ele ainda é o
+    /// original do vídeo e nenhum job foi criado para ele. Entregas repetidas
+    /// do `VideoSourceReplaced` não duplicam o job; retorna `None` nesses casos.
+    pub async fn enqueue_for_source(
+        &self,
+        video_id: i32,
+        source_media_asset_id: i32,
+    ) -> Result<Option<TranscodeJobModel>, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        transcode_jobs::Entity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO transcode_jobs
+                       (video_id, source_media_asset_id, status, progress, attempts,
+                        max_attempts, run_after, created_at, updated_at)
+                   SELECT $1, $2, $3, 0, 0, $4, $5, $5, $5
+                   WHERE EXISTS (
+                       SELECT 1 FROM video_assets
+                       WHERE video_id = $1 AND media_asset_id = $2 AND kind = $6
+                   )
+                   AND NOT EXISTS (
+                       SELECT 1 FROM transcode_jobs WHERE source_media_asset_id = $2
+                   )
+                   RETURNING *"#,
+                [
+                    video_id.into(),
+                    source_media_asset_id.into(),
+                    TranscodeJobStatusEnum::PENDING.as_str().into(),
+                    DEFAULT_TRANSCODE_MAX_ATTEMPTS.into(),
+                    now.into(),
+                    VideoAssetKindEnum::SOURCE.as_str().into(),
+                ],
+            ))
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn find_by_id(&self, job_id: i32) -> Result<Option<TranscodeJobModel>, DbErr> {
+        transcode_jobs::Entity::find_by_id(job_id)
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn find_many(
+        &self,
+        status: Option<TranscodeJobStatusEnum>,
+        video_id: Option<i32>,
+        skip: Option<u64>,
+        take: Option<u64>,
+    ) -> Result<Vec<TranscodeJobModel>, DbErr> {
+        let mut query =
+            transcode_jobs::Entity::find().order_by_desc(transcode_jobs::Column::CreatedAt);
+
+        if let Some(status) = status {
+            query = query.filter(transcode_jobs::Column::Status.eq(status.as_str()));
+        }
+        if let Some(video_id) = video_id {
+            query = query.filter(transcode_jobs::Column::VideoId.eq(video_id));
+        }
+        if let Some(skip) = skip {
+            query = query.offset(skip);
+        }
+        if let Some(take) = take {
+            query = query.limit(take);
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Reserva o próximo job pendente para `worker_id`.
+    ///
+    /// `FOR UPDATE SKIP LOCKED` permite vários workers consumindo a mesma fila
+    /// sem disputar a mesma linha.
+    pub async fn claim_next(&self, worker_id: &str) -> Result<Option<TranscodeJobModel>, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        transcode_jobs::Entity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE transcode_jobs
+                   SET status = $1, locked_by = $2, locked_at = $3, started_at = $3,
+                       attempts = attempts + 1, updated_at = $3
+                   WHERE id = (
+                       SELECT id FROM transcode_jobs
+                       WHERE status = $4 AND run_after <= $3
+                       ORDER BY run_after, id
+                       FOR UPDATE SKIP LOCKED
+                       LIMIT 1
+                   )
+                   RETURNING *"#,
+                [
+                    TranscodeJobStatusEnum::RUNNING.as_str().into(),
+                    worker_id.into(),
+                    now.into(),
+                    TranscodeJobStatusEnum::PENDING.as_str().into(),
+                ],
+            ))
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn update_progress(&self, job_id: i32, progress: i32) -> Result<(), DbErr> {
+        transcode_jobs::Entity::update_many()
+            .col_expr(transcode_jobs::Column::Progress, Expr::value(progress))
+            .col_expr(
+                transcode_jobs::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(transcode_jobs::Column::Id.eq(job_id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Conclui o job, se ele ainda pertence a `worker_id`. Retorna `false`
+    /// quando o lock já foi retomado por outro worker e nada foi gravado.
+    pub async fn mark_succeeded(&self, job_id: i32, worker_id: &str) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let result = transcode_jobs::Entity::update_many()
+            .col_expr(
+                transcode_jobs::Column::Status,
+                Expr::value(TranscodeJobStatusEnum::SUCCEEDED.as_str()),
+            )
+            .col_expr(transcode_jobs::Column::Progress, Expr::value(100))
+            .col_expr(
+                transcode_jobs::Column::LastError,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                transcode_jobs::Column::LockedBy,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(transcode_jobs::Column::FinishedAt, Expr::value(now))
+            .col_expr(transcode_jobs::Column::UpdatedAt, Expr::value(now))
+            .filter(transcode_jobs::Column::Id.eq(job_id))
+            .filter(owned_by(worker_id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// Registra a falha; com `retry_at` o job volta para a fila, senão termina
+    /// em `FAILED`. Como em [`Self::mark_succeeded`], só vale para o dono do lock.
+    pub async fn mark_failed(
+        &self,
+        job_id: i32,
+        worker_id: &str,
+        error: &str,
+        retry_at: Option<NaiveDateTime>,
+    ) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let mut update = transcode_jobs::Entity::update_many()
+            .col_expr(transcode_jobs::Column::LastError, Expr::value(error))
+            .col_expr(
+                transcode_jobs::Column::LockedBy,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(transcode_jobs::Column::UpdatedAt, Expr::value(now))
+            .filter(transcode_jobs::Column::Id.eq(job_id))
+            .filter(owned_by(worker_id));
+
+        update = match retry_at {
+            Some(retry_at) => update
+                .col_expr(
+                    transcode_jobs::Column::Status,
+                    Expr::value(TranscodeJobStatusEnum::PENDING.as_str()),
+                )
+                .col_expr(transcode_jobs::Column::Progress, Expr::value(0))
+                .col_expr(transcode_jobs::Column::RunAfter, Expr::value(retry_at)),
+            None => update
+                .col_expr(
+                    transcode_jobs::Column::Status,
+                    Expr::value(TranscodeJobStatusEnum::FAILED.as_str()),
+                )
+                .col_expr(transcode_jobs::Column::FinishedAt, Expr::value(now)),
+        };
+
+        let result = update.exec(&self.db).await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// Devolve à fila jobs `RUNNING` cujo worker parou de responder. Os que
+    /// já gastaram todas as tentativas terminam em `FAILED`, para que um
+    /// arquivo que derruba o worker não fique voltando para sempre.
+    pub async fn requeue_stale(&self, locked_before: NaiveDateTime) -> Result<StaleJobs, DbErr> {
+        let now = Utc::now().naive_utc();
+        let exhausted = Expr::col(transcode_jobs::Column::Attempts)
+            .gte(Expr::col(transcode_jobs::Column::MaxAttempts));
+
+        let failed = transcode_jobs::Entity::update_many()
+            .col_expr(
+                transcode_jobs::Column::Status,
+                Expr::value(TranscodeJobStatusEnum::FAILED.as_str()),
+            )
+            .col_expr(
+                transcode_jobs::Column::LockedBy,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                transcode_jobs::Column::LastError,
+                Expr::value(STALE_JOB_ERROR),
+            )
+            .col_expr(transcode_jobs::Column::FinishedAt, Expr::value(now))
+            .col_expr(transcode_jobs::Column::UpdatedAt, Expr::value(now))
+            .filter(transcode_jobs::Column::Status.eq(TranscodeJobStatusEnum::RUNNING.as_str()))
+            .filter(transcode_jobs::Column::LockedAt.lt(locked_before))
+            .filter(exhausted.clone())
+            .exec(&self.db)
+            .await?;
+
+        let requeued = transcode_jobs::Entity::update_many()
+            .col_expr(
+                transcode_jobs::Column::Status,
+                Expr::value(TranscodeJobStatusEnum::PENDING.as_str()),
+            )
+            .col_expr(
+                transcode_jobs::Column::LockedBy,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                transcode_jobs::Column::LastError,
+                Expr::value(STALE_JOB_ERROR),
+            )
+            .col_expr(transcode_jobs::Column::Progress, Expr::value(0))
+            .col_expr(transcode_jobs::Column::UpdatedAt, Expr::value(now))
+            .filter(transcode_jobs::Column::Status.eq(TranscodeJobStatusEnum::RUNNING.as_str()))
+            .filter(transcode_jobs::Column::LockedAt.lt(locked_before))
+            .filter(exhausted.not())
+            .exec(&self.db)
+            .await?;
+
+        Ok(StaleJobs {
+            requeued: requeued.rows_affected,
+            failed: failed.rows_affected,
+        })
+    }
+
+    /// Recoloca um job `FAILED` na fila com as tentativas zeradas.
+    pub async fn retry(&self, job_id: i32) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let result = transcode_jobs::Entity::update_many()
+            .col_expr(
+                transcode_jobs::Column::Status,
+                Expr::value(TranscodeJobStatusEnum::PENDING.as_str()),
+            )
+            .col_expr(transcode_jobs::Column::Attempts, Expr::value(0))
+            .col_expr(transcode_jobs::Column::Progress, Expr::value(0))
+            .col_expr(
+                transcode_jobs::Column::FinishedAt,
+                Expr::value(Option::<NaiveDateTime>::None),
+            )
+            .col_expr(transcode_jobs::Column::RunAfter, Expr::value(now))
+            .col_expr(transcode_jobs::Column::UpdatedAt, Expr::value(now))
+            .filter(transcode_jobs::Column::Id.eq(job_id))
+            .filter(transcode_jobs::Column::Status.eq(TranscodeJobStatusEnum::FAILED.as_str()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
+
+/// O job ainda está em execução com o lock de `worker_id`.
+fn owned_by(worker_id: &str) -> sea_orm::sea_query::SimpleExpr {
+    transcode_jobs::Column::LockedBy
+        .eq(worker_id)
+        .and(transcode_jobs::Column::Status.eq(TranscodeJobStatusEnum::RUNNING.as_str()))
+}
+
+pub(crate) async fn enqueue_transcode_job<C: ConnectionTrait>(
+    conn: &C,
+    video_id: i32,
+    source_media_asset_id: i32,
+) -> Result<TranscodeJobModel, DbErr> {
+    let now = Utc::now().naive_utc();
+
+    transcode_jobs::ActiveModel {
+        id: sea_orm::ActiveValue::NotSet,
+        video_id: Set(video_id),
+        source_media_asset_id: Set(source_media_asset_id),
+        status: Set(TranscodeJobStatusEnum::PENDING.as_str().to_string()),
+        progress: Set(0),
+        attempts: Set(0),
+        max_attempts: Set(DEFAULT_TRANSCODE_MAX_ATTEMPTS),
+        last_error: Set(None),
+        locked_by: Set(None),
+        locked_at: Set(None),
+        run_after: Set(now),
+        started_at: Set(None),
+        finished_at: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+    }
+    .insert(conn)
+    .await
+}
+
+/// Os testes com banco ficam com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::database::test_support::test_db;
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn enqueue_for_source_creates_one_job_per_current_source() {
+        let db = test_db().await;
+        let repo = TranscodeJobsRepository::new(db.clone());
+
+        let ids = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                r#"WITH video AS (
+                       INSERT INTO videos (title, duration_seconds, is_available, rating,
+                                           created_at, updated_at)
+                       VALUES ('enqueue test', 60, false, 0, now(), now())
+                       RETURNING id
+                   ), assets AS (
+                       INSERT INTO media_assets (container, storage_key, url, content_type,
+                                                 size_bytes, sha256, status, created_at,
+                                                 updated_at)
+                       VALUES ('videos', 'enqueue-test', 'http://localhost/enqueue-test',
+                               'video/mp4', 1, 'sha', 'READY', now(), now()),
+                              ('videos', 'enqueue-test-old', 'http://localhost/enqueue-test-old',
+                               'video/mp4', 1, 'sha', 'READY', now(), now())
+                       RETURNING id
+                   ), source AS (
+                       INSERT INTO video_assets (video_id, media_asset_id, kind, created_at,
+                                                 updated_at)
+                       SELECT video.id, MIN(assets.id), 'SOURCE', now(), now()
+                       FROM video, assets GROUP BY video.id
+                       RETURNING video_id, media_asset_id
+                   )
+                   SELECT source.video_id, source.media_asset_id AS source_id,
+                          (SELECT MAX(id) FROM assets) AS replaced_id
+                   FROM source"#
+                    .to_string(),
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        let video_id: i32 = ids.try_get("", "video_id").unwrap();
+        let source_id: i32 = ids.try_get("", "source_id").unwrap();
+        let replaced_id: i32 = ids.try_get("", "replaced_id").unwrap();
+
+        let job = repo
+            .enqueue_for_source(video_id, source_id)
+            .await
+            .unwrap()
+            .expect("job do original atual");
+        assert_eq!(job.source_media_asset_id, source_id);
+        assert_eq!(job.status, TranscodeJobStatusEnum::PENDING.as_str());
+
+        // entrega repetida do evento
+        assert!(repo
+            .enqueue_for_source(video_id, source_id)
+            .await
+            .unwrap()
+            .is_none());
+        // arquivo que não é mais o original do vídeo
+        assert!(repo
+            .enqueue_for_source(video_id, replaced_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM media_assets WHERE id IN ($1, $2)",
+            [source_id.into(), replaced_id.into()],
+        ))
+        .await
+        .unwrap();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM videos WHERE id = $1",
+            [video_id.into()],
+        ))
+        .await
+        .unwrap();
+    }
+}
@@ -0,0 +1,316 @@
+use crate::enums::trending_kind_enum::TrendingKindEnum;
+use crate::enums::trending_window_enum::TrendingWindowEnum;
+use crate::modules::database::repositories::availability_repository::live_series_sql;
+use crate::modules::database::repositories::recommendations_repository::MIN_WATCHED_SECONDS;
+use crate::modules::database::repositories::search_repository::LiveFilter;
+use chrono::NaiveDateTime;
+use sea_orm::{DatabaseConnection, DbBackend, DbErr, FromQueryResult, Statement, Value};
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Pontuação de um vídeo na janela, com o que é preciso para agregá-lo por
+/// série e por categoria.
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct VideoTrendScore {
+    pub video_id: i32,
+    pub series_id: Option<i32>,
+    pub score: f64,
+    /// Ids separados por vírgula.
+    pub category_ids: Option<String>,
+}
+
+impl VideoTrendScore {
+    pub fn category_ids(&self) -> Vec<i32> {
+        self.category_ids
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|id| id.parse().ok())
+            .collect()
+    }
+}
+
+/// Audiência de um vídeo ou série na janela, para o painel do admin.
+#[derive(Debug, Clone, Serialize, FromQueryResult)]
+pub struct TrendStats {
+    pub id: i32,
+    pub title: String,
+    pub score: f64,
+    pub views: i64,
+    pub viewers: i64,
+    pub completions: i64,
+    pub watched_seconds: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TrendingRepository {
+    db: DatabaseConnection,
+}
+
+#[derive(FromQueryResult)]
+struct IdRow {
+    id: i32,
+}
+
+fn ids_param(ids: &[i32]) -> String {
+    ids.iter().map(i32::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// Visualizações da janela que terminou em `$1` (duração `$2` e meia-vida `$3`
+/// em segundos), já com o peso decaído: concluída vale 1, parcial 0,5, e o
+/// peso cai pela metade a cada meia-vida. Vídeos e séries na lixeira ficam de
+/// fora. Usa os aliases `wh`, `v` e `s`.
+fn events_sql() -> (&'static str, &'static str, &'static str) {
+    let weight = "CASE WHEN wh.is_completed THEN 1.0 ELSE 0.5 END::float8 \
+         * power(0.5::float8, EXTRACT(EPOCH FROM ($1 - wh.last_watched_at))::float8 / $3::float8)";
+    let from = "watch_history wh \
+         JOIN videos v ON v.id = wh.video_id AND v.deleted_at IS NULL \
+         LEFT JOIN series s ON s.id = v.series_id";
+    let condition = "wh.last_watched_at > $1 - make_interval(secs => $2::float8) \
+         AND wh.last_watched_at <= $1 \
+         AND (wh.is_completed OR wh.watched_seconds >= $4) \
+         AND (v.series_id IS NULL OR s.deleted_at IS NULL)";
+
+    (weight, from, condition)
+}
+
+fn window_values(window: TrendingWindowEnum, at: NaiveDateTime) -> Vec<Value> {
+    vec![
+        at.into(),
+        window.duration_secs().into(),
+        window.half_life_secs().into(),
+        MIN_WATCHED_SECONDS.into(),
+    ]
+}
+
+impl TrendingRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Pontuação de cada vídeo assistido na janela que termina em `at`. O
+    /// resultado depende só do banco e de `at`, então pode ser recalculado a
+    /// qualquer momento.
+    pub async fn find_video_scores(
+        &self,
+        window: TrendingWindowEnum,
+        at: NaiveDateTime,
+    ) -> Result<Vec<VideoTrendScore>, DbErr> {
+        let (weight, from, condition) = events_sql();
+
+        VideoTrendScore::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT v.id AS video_id, v.series_id, SUM({weight}) AS score, \
+                 (SELECT string_agg(vc.category_id::text, ',' ORDER BY vc.category_id) \
+                    FROM video_categories vc WHERE vc.video_id = v.id) AS category_ids \
+                 FROM {from} WHERE {condition} \
+                 GROUP BY v.id, v.series_id ORDER BY v.id"
+            ),
+            window_values(window, at),
+        ))
+        .all(&self.db)
+        .await
+    }
+
+    /// Ranking com a audiência detalhada, calculado direto no banco.
+    pub async fn find_stats(
+        &self,
+        kind: TrendingKindEnum,
+        window: TrendingWindowEnum,
+        at: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<TrendStats>, DbErr> {
+        let (weight, from, condition) = events_sql();
+        let (id, title, filter) = match kind {
+            TrendingKindEnum::VIDEO => ("v.id", "v.title", ""),
+            TrendingKindEnum::SERIE => ("s.id", "s.title", "AND s.id IS NOT NULL"),
+        };
+
+        let mut values = window_values(window, at);
+        values.push((limit as i64).into());
+
+        TrendStats::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT {id} AS id, {title} AS title, SUM({weight}) AS score, \
+                 COUNT(*) AS views, COUNT(DISTINCT wh.profile_id) AS viewers, \
+                 COUNT(*) FILTER (WHERE wh.is_completed) AS completions, \
+                 COALESCE(SUM(wh.watched_seconds), 0)::bigint AS watched_seconds \
+                 FROM {from} WHERE {condition} {filter} \
+                 GROUP BY {id}, {title} ORDER BY score DESC, {id} LIMIT $5"
+            ),
+            values,
+        ))
+        .all(&self.db)
+        .await
+    }
+
+    /// Quais das séries estão no ar no instante e na região.
+    pub async fn find_live_series_ids(
+        &self,
+        serie_ids: &[i32],
+        live: &LiveFilter,
+    ) -> Result<HashSet<i32>, DbErr> {
+        if serie_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let (from, condition) = live_series_sql(live.max_rating);
+
+        Ok(IdRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                "SELECT s.id FROM {from} WHERE {condition} \
+                 AND s.id = ANY(string_to_array($3, ',')::int[])"
+            ),
+            [
+                live.at.into(),
+                live.region.clone().into(),
+                ids_param(serie_ids).into(),
+            ],
+        ))
+        .all(&self.db)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect())
+    }
+}
+
+/// Os testes com banco ficam com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::database::test_support::test_db;
+    use chrono::{Duration, NaiveDate};
+    use sea_orm::ConnectionTrait;
+
+    async fn exec(db: &DatabaseConnection, sql: &str, values: Vec<Value>) {
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await
+        .expect(sql);
+    }
+
+    async fn insert(db: &DatabaseConnection, sql: &str, values: Vec<Value>) -> i32 {
+        db.query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            values,
+        ))
+        .await
+        .expect(sql)
+        .expect(sql)
+        .try_get("", "id")
+        .expect(sql)
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn views_decay_by_half_life_within_the_window() {
+        let db = test_db().await;
+
+        // Bem no futuro, para que nenhuma outra visualização caia na janela.
+        let at = NaiveDate::from_ymd_opt(2100, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        let user_id = uuid::Uuid::new_v4().to_string();
+        exec(
+            &db,
+            "INSERT INTO users (id, email, password_hash, name, role, created_at, updated_at)
+             VALUES ($1, $1 || '@trending.test', 'x', 'Em alta', 'test', now(), now())",
+            vec![user_id.clone().into()],
+        )
+        .await;
+        let profile_id = insert(
+            &db,
+            "INSERT INTO profiles (user_id, name, created_at, updated_at)
+             VALUES ($1, 'Em alta', now(), now()) RETURNING id",
+            vec![user_id.clone().into()],
+        )
+        .await;
+
+        let mut video_ids = Vec::new();
+        for trashed in [false, true] {
+            video_ids.push(
+                insert(
+                    &db,
+                    "INSERT INTO videos (title, duration_seconds, is_available, rating,
+                        created_at, updated_at, deleted_at)
+                     VALUES ('Em alta', 600, true, 0, now(), now(), CASE WHEN $1 THEN now() END)
+                     RETURNING id",
+                    vec![trashed.into()],
+                )
+                .await,
+            );
+        }
+        let (video_id, trashed_id) = (video_ids[0], video_ids[1]);
+
+        for (id, watched_seconds, completed, hours_ago) in [
+            (video_id, 600, true, 6),
+            (video_id, 120, false, 0),
+            (video_id, 10, false, 1),
+            (video_id, 600, true, 25),
+            (trashed_id, 600, true, 1),
+        ] {
+            exec(
+                &db,
+                "INSERT INTO watch_history (user_id, profile_id, video_id, watched_seconds,
+                    is_completed, last_watched_at, created_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, now())",
+                vec![
+                    user_id.clone().into(),
+                    profile_id.into(),
+                    id.into(),
+                    watched_seconds.into(),
+                    completed.into(),
+                    (at - Duration::hours(hours_ago)).into(),
+                ],
+            )
+            .await;
+        }
+
+        let repo = TrendingRepository::new(db.clone());
+        let day = repo.find_video_scores(TrendingWindowEnum::DAY, at).await;
+        let week = repo.find_video_scores(TrendingWindowEnum::WEEK, at).await;
+
+        exec(
+            &db,
+            "DELETE FROM watch_history WHERE user_id = $1",
+            vec![user_id.clone().into()],
+        )
+        .await;
+        exec(
+            &db,
+            "DELETE FROM videos WHERE id IN ($1, $2)",
+            vec![video_id.into(), trashed_id.into()],
+        )
+        .await;
+        exec(
+            &db,
+            "DELETE FROM profiles WHERE id = $1",
+            vec![profile_id.into()],
+        )
+        .await;
+        exec(&db, "DELETE FROM users WHERE id = $1", vec![user_id.into()]).await;
+
+        // Concluída há uma meia-vida (6h) vale 0,5 e a parcial de agora, 0,5;
+        // a de 10 segundos não conta e a de 25 horas fica fora do dia.
+        let day = day.unwrap();
+        assert_eq!(day.len(), 1);
+        assert_eq!(day[0].video_id, video_id);
+        assert!((day[0].score - 1.0).abs() < 1e-9);
+
+        let half_life = TrendingWindowEnum::WEEK.half_life_secs() as f64 / 3600.0;
+        let expected = 0.5f64.powf(6.0 / half_life) + 0.5 + 0.5f64.powf(25.0 / half_life);
+        let week = week.unwrap();
+        assert_eq!(week.len(), 1);
+        assert!((week[0].score - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::enums::upload_session_status_enum::UploadSessionStatusEnum;
+use crate::modules::database::repositories::media_assets_repository::CreateMediaAssetRequest;
+use crate::modules::database::repositories::video_assets_repository::{
+    replace_source_asset, ReplacedSourceAsset,
+};
+use crate::modules::database::schema::upload_sessions::Model as UploadSessionModel;
+use crate::modules::database::schema::{upload_sessions, videos};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{DatabaseConnection, DbErr, QuerySelect, Set, TransactionTrait};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateUploadSessionRequest {
+    pub video_id: i32,
+    pub blob_name: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub total_size: i64,
+    pub block_size: i64,
+    pub total_blocks: i32,
+    pub expected_sha256: Option<String>,
+    pub created_by: Option<String>,
+    pub expires_at: NaiveDateTime,
+}
+
+pub struct UploadSessionsRepository {
+    db: DatabaseConnection,
+}
+
+impl UploadSessionsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(
+        &self,
+        request: CreateUploadSessionRequest,
+    ) -> Result<UploadSessionModel, DbErr> {
+        let now = Utc::now().naive_utc();
+        let session = upload_sessions::ActiveModel {
+            id: Set(Uuid::new_v4().to_string()),
+            video_id: Set(request.video_id),
+            blob_name: Set(request.blob_name),
+            file_name: Set(request.file_name),
+            content_type: Set(request.content_type),
+            total_size: Set(request.total_size),
+            block_size: Set(request.block_size),
+            total_blocks: Set(request.total_blocks),
+            expected_sha256: Set(request.expected_sha256),
+            status: Set(UploadSessionStatusEnum::PENDING.as_str().to_string()),
+            created_by: Set(request.created_by),
+            expires_at: Set(request.expires_at),
+            completed_at: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        session.insert(&self.db).await
+    }
+
+    pub async fn find_by_id(&self, session_id: &str) -> Result<Option<UploadSessionModel>, DbErr> {
+        upload_sessions::Entity::find_by_id(session_id.to_string())
+            .one(&self.db)
+            .await
+    }
+
+    /// Blobs de sessões ainda em andamento, cujos blocos podem ser confirmados a qualquer momento.
+    pub async fn find_pending_blob_names(&self) -> Result<Vec<String>, DbErr> {
+        upload_sessions::Entity::find()
+            .select_only()
+            .column(upload_sessions::Column::BlobName)
+            .filter(upload_sessions::Column::Status.eq(UploadSessionStatusEnum::PENDING.as_str()))
+            .into_tuple::<String>()
+            .all(&self.db)
+            .await
+    }
+
+    pub async fn mark_failed(&self, session_id: &str) -> Result<(), DbErr> {
+        upload_sessions::Entity::update_many()
+            .col_expr(
+                upload_sessions::Column::Status,
+                Expr::value(UploadSessionStatusEnum::FAILED.as_str()),
+            )
+            .col_expr(
+                upload_sessions::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(upload_sessions::Column::Id.eq(session_id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Troca o `video_url` do vídeo, registra o media asset como novo original
+    /// e conclui a sessão na mesma transação.
+    ///
+    /// A troca só acontece se o vídeo ainda apontar para `expected_video_url`;
+    /// caso outro upload tenha concluído antes, retorna `Ok(None)` sem alterar nada.
+    pub async fn complete_and_swap_video_url(
+        &self,
+        session_id: &str,
+        video_id: i32,
+        expected_video_url: Option<String>,
+        new_video_url: String,
+        asset: CreateMediaAssetRequest,
+    ) -> Result<Option<ReplacedSourceAsset>, DbErr> {
+        let txn = self.db.begin().await?;
+        let now = Utc::now().naive_utc();
+
+        let mut swap = videos::Entity::update_many()
+            .col_expr(videos::Column::VideoUrl, Expr::value(new_video_url))
+            .col_expr(videos::Column::UpdatedAt, Expr::value(now))
+            .filter(videos::Column::Id.eq(video_id));
+
+        swap = match expected_video_url {
+            Some(url) => swap.filter(videos::Column::VideoUrl.eq(url)),
+            None => swap.filter(videos::Column::VideoUrl.is_null()),
+        };
+
+        if swap.exec(&txn).await?.rows_affected != 1 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let completed = upload_sessions::Entity::update_many()
+            .col_expr(
+                upload_sessions::Column::Status,
+                Expr::value(UploadSessionStatusEnum::COMPLETED.as_str()),
+            )
+            .col_expr(upload_sessions::Column::CompletedAt, Expr::value(now))
+            .col_expr(upload_sessions::Column::UpdatedAt, Expr::value(now))
+            .filter(upload_sessions::Column::Id.eq(session_id))
+            .filter(upload_sessions::Column::Status.eq(UploadSessionStatusEnum::PENDING.as_str()))
+            .exec(&txn)
+            .await?;
+
+        if completed.rows_affected != 1 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let replaced = replace_source_asset(&txn, video_id, asset).await?;
+
+        txn.commit().await?;
+
+        Ok(Some(replaced))
+    }
+}
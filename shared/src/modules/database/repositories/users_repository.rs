@@ -8,7 +8,10 @@ use sea_orm::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::modules::database::repositories::events_repository::EventsRepository;
+use crate::modules::database::repositories::profiles_repository::ProfilesRepository;
 use crate::modules::database::schema::*;
+use crate::modules::events::DomainEvent;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoginRequest {
@@ -111,6 +114,17 @@ impl UsersRepository {
             .await?;
         }
 
+        ProfilesRepository::create_default(&txn, &user.id, &user.name).await?;
+
+        EventsRepository::record(
+            &txn,
+            &DomainEvent::UserRegistered {
+                user_id: user.id.clone(),
+                email: user.email.clone(),
+            },
+        )
+        .await?;
+
         txn.commit().await?;
 
         Ok(AuthUser {
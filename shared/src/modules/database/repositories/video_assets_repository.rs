@@ -0,0 +1,375 @@
+use crate::enums::media_asset_status_enum::MediaAssetStatusEnum;
+use crate::enums::video_asset_kind_enum::VideoAssetKindEnum;
+use crate::modules::database::repositories::events_repository::EventsRepository;
+use crate::modules::database::repositories::media_assets_repository::CreateMediaAssetRequest;
+use crate::modules::database::schema::media_assets::Model as MediaAssetModel;
+use crate::modules::database::schema::video_assets::Model as VideoAssetModel;
+use crate::modules::database::schema::{media_assets, video_assets, videos};
+use crate::modules::events::DomainEvent;
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, QueryOrder, Set, TransactionTrait};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateVideoAssetRequest {
+    pub video_id: i32,
+    pub media_asset_id: i32,
+    pub kind: VideoAssetKindEnum,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub bitrate_kbps: Option<i32>,
+    pub codec: Option<String>,
+    pub language: Option<String>,
+}
+
+impl CreateVideoAssetRequest {
+    pub fn into_active_model(self) -> video_assets::ActiveModel {
+        let now = Utc::now().naive_utc();
+        video_assets::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            video_id: Set(self.video_id),
+            media_asset_id: Set(self.media_asset_id),
+            kind: Set(self.kind.as_str().to_string()),
+            width: Set(self.width),
+            height: Set(self.height),
+            bitrate_kbps: Set(self.bitrate_kbps),
+            codec: Set(self.codec),
+            language: Set(self.language),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+    }
+}
+
+/// Rendition gerada pelo worker de transcodificação, ainda não registrada.
+#[derive(Debug, Deserialize)]
+pub struct CreateRenditionRequest {
+    pub asset: CreateMediaAssetRequest,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub bitrate_kbps: Option<i32>,
+    pub codec: Option<String>,
+}
+
+/// Resultado da troca do arquivo original de um vídeo.
+///
+/// `previous` traz os media assets que deixaram de ser referenciados; os blobs
+/// correspondentes devem ser apagados pelo chamador após o commit.
+/// `duration_seconds` é a duração gravada no vídeo a partir do arquivo, quando
+/// o container a informou.
+#[derive(Debug)]
+pub struct ReplacedSourceAsset {
+    pub asset: MediaAssetModel,
+    pub previous: Vec<MediaAssetModel>,
+    pub duration_seconds: Option<i32>,
+}
+
+/// Legenda registrada para um idioma, com os arquivos que ela substituiu.
+#[derive(Debug)]
+pub struct ReplacedSubtitle {
+    pub video_asset: VideoAssetModel,
+    pub asset: MediaAssetModel,
+    pub previous: Vec<MediaAssetModel>,
+}
+
+pub struct VideoAssetsRepository {
+    db: DatabaseConnection,
+}
+
+impl VideoAssetsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(&self, request: CreateVideoAssetRequest) -> Result<VideoAssetModel, DbErr> {
+        request.into_active_model().insert(&self.db).await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        video_asset_id: i32,
+    ) -> Result<Option<(VideoAssetModel, Option<MediaAssetModel>)>, DbErr> {
+        video_assets::Entity::find_by_id(video_asset_id)
+            .find_also_related(media_assets::Entity)
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn find_by_video(
+        &self,
+        video_id: i32,
+        kind: Option<VideoAssetKindEnum>,
+    ) -> Result<Vec<(VideoAssetModel, Option<MediaAssetModel>)>, DbErr> {
+        let mut query = video_assets::Entity::find()
+            .find_also_related(media_assets::Entity)
+            .filter(video_assets::Column::VideoId.eq(video_id))
+            .order_by_asc(video_assets::Column::Kind)
+            .order_by_desc(video_assets::Column::Height);
+
+        if let Some(kind) = kind {
+            query = query.filter(video_assets::Column::Kind.eq(kind.as_str()));
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Assets do vídeo com arquivo íntegro no storage, prontos para reprodução.
+    pub async fn find_playable(
+        &self,
+        video_id: i32,
+        kinds: &[VideoAssetKindEnum],
+    ) -> Result<Vec<(VideoAssetModel, MediaAssetModel)>, DbErr> {
+        let assets = video_assets::Entity::find()
+            .find_also_related(media_assets::Entity)
+            .filter(video_assets::Column::VideoId.eq(video_id))
+            .filter(video_assets::Column::Kind.is_in(kinds.iter().map(|k| k.as_str())))
+            .filter(media_assets::Column::Status.eq(MediaAssetStatusEnum::AVAILABLE.as_str()))
+            .order_by_desc(video_assets::Column::Height)
+            .all(&self.db)
+            .await?;
+
+        Ok(assets
+            .into_iter()
+            .filter_map(|(video_asset, media_asset)| media_asset.map(|m| (video_asset, m)))
+            .collect())
+    }
+
+    pub async fn delete(&self, video_asset_id: i32) -> Result<(), DbErr> {
+        video_assets::Entity::delete_by_id(video_asset_id)
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Substitui todas as renditions do vídeo pelas recém-geradas e devolve os
+    /// media assets das anteriores, cujos blobs devem ser apagados pelo chamador.
+    pub async fn replace_renditions(
+        &self,
+        video_id: i32,
+        renditions: Vec<CreateRenditionRequest>,
+    ) -> Result<Vec<MediaAssetModel>, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let previous =
+            delete_assets_of_kind(&txn, video_id, VideoAssetKindEnum::RENDITION, None).await?;
+
+        for rendition in renditions {
+            let asset = rendition.asset.into_active_model().insert(&txn).await?;
+
+            CreateVideoAssetRequest {
+                video_id,
+                media_asset_id: asset.id,
+                kind: VideoAssetKindEnum::RENDITION,
+                width: rendition.width,
+                height: rendition.height,
+                bitrate_kbps: rendition.bitrate_kbps,
+                codec: rendition.codec,
+                language: None,
+            }
+            .into_active_model()
+            .insert(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(previous)
+    }
+
+    /// Registra a legenda de `language`, substituindo a anterior do mesmo idioma.
+    pub async fn replace_subtitle(
+        &self,
+        video_id: i32,
+        language: &str,
+        asset: CreateMediaAssetRequest,
+        duration_ms: i64,
+    ) -> Result<ReplacedSubtitle, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let previous =
+            delete_assets_of_kind(&txn, video_id, VideoAssetKindEnum::SUBTITLE, Some(language))
+                .await?;
+
+        let mut asset = asset.into_active_model();
+        asset.duration_ms = Set(Some(duration_ms));
+        let asset = asset.insert(&txn).await?;
+
+        let video_asset = CreateVideoAssetRequest {
+            video_id,
+            media_asset_id: asset.id,
+            kind: VideoAssetKindEnum::SUBTITLE,
+            width: None,
+            height: None,
+            bitrate_kbps: None,
+            codec: Some("webvtt".to_string()),
+            language: Some(language.to_string()),
+        }
+        .into_active_model()
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+
+        Ok(ReplacedSubtitle {
+            video_asset,
+            asset,
+            previous,
+        })
+    }
+
+    /// Remove a legenda do idioma e devolve os media assets cujos blobs devem ser apagados.
+    pub async fn delete_subtitle(
+        &self,
+        video_id: i32,
+        language: &str,
+    ) -> Result<Vec<MediaAssetModel>, DbErr> {
+        delete_assets_of_kind(
+            &self.db,
+            video_id,
+            VideoAssetKindEnum::SUBTITLE,
+            Some(language),
+        )
+        .await
+    }
+
+    pub async fn replace_source(
+        &self,
+        video_id: i32,
+        asset: CreateMediaAssetRequest,
+    ) -> Result<ReplacedSourceAsset, DbErr> {
+        let txn = self.db.begin().await?;
+        let replaced = replace_source_asset(&txn, video_id, asset).await?;
+        txn.commit().await?;
+
+        Ok(replaced)
+    }
+}
+
+/// Registra `asset` como o novo arquivo original do vídeo, removendo os
+/// registros do original anterior. Quando o container foi inspecionado, a
+/// duração real substitui a informada no cadastro. Deve rodar dentro de uma
+/// transação.
+///
+/// O job de transcodificação e a busca ficam com os assinantes do
+/// `VideoSourceReplaced` gravado aqui.
+pub(crate) async fn replace_source_asset<C: ConnectionTrait>(
+    conn: &C,
+    video_id: i32,
+    asset: CreateMediaAssetRequest,
+) -> Result<ReplacedSourceAsset, DbErr> {
+    let previous = delete_assets_of_kind(conn, video_id, VideoAssetKindEnum::SOURCE, None).await?;
+
+    let duration_seconds = asset.probe.as_ref().and_then(|p| p.duration_seconds());
+    if let Some(duration) = duration_seconds {
+        videos::Entity::update_many()
+            .col_expr(videos::Column::DurationSeconds, Expr::value(duration))
+            .col_expr(
+                videos::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(videos::Column::Id.eq(video_id))
+            .exec(conn)
+            .await?;
+    }
+
+    let asset = asset.into_active_model().insert(conn).await?;
+
+    CreateVideoAssetRequest {
+        video_id,
+        media_asset_id: asset.id,
+        kind: VideoAssetKindEnum::SOURCE,
+        width: asset.width,
+        height: asset.height,
+        bitrate_kbps: bitrate_kbps(&asset),
+        codec: asset.video_codec.clone(),
+        language: None,
+    }
+    .into_active_model()
+    .insert(conn)
+    .await?;
+
+    EventsRepository::record(
+        conn,
+        &DomainEvent::VideoSourceReplaced {
+            video_id,
+            media_asset_id: asset.id,
+        },
+    )
+    .await?;
+
+    Ok(ReplacedSourceAsset {
+        asset,
+        previous,
+        duration_seconds,
+    })
+}
+
+/// Remove os media assets vinculados ao vídeo com o tipo (e idioma, quando
+/// informado) pedido — os vínculos caem em cascata — e devolve os registros removidos.
+async fn delete_assets_of_kind<C: ConnectionTrait>(
+    conn: &C,
+    video_id: i32,
+    kind: VideoAssetKindEnum,
+    language: Option<&str>,
+) -> Result<Vec<MediaAssetModel>, DbErr> {
+    let mut query = video_assets::Entity::find()
+        .find_also_related(media_assets::Entity)
+        .filter(video_assets::Column::VideoId.eq(video_id))
+        .filter(video_assets::Column::Kind.eq(kind.as_str()));
+
+    if let Some(language) = language {
+        query = query.filter(video_assets::Column::Language.eq(language));
+    }
+
+    let previous: Vec<MediaAssetModel> = query
+        .all(conn)
+        .await?
+        .into_iter()
+        .filter_map(|(_, media_asset)| media_asset)
+        .collect();
+
+    if !previous.is_empty() {
+        media_assets::Entity::delete_many()
+            .filter(media_assets::Column::Id.is_in(previous.iter().map(|m| m.id)))
+            .exec(conn)
+            .await?;
+    }
+
+    Ok(previous)
+}
+
+/// Apaga os arquivos de todos os assets do vídeo — os vínculos caem em
+/// cascata — e devolve os registros removidos.
+pub(crate) async fn delete_video_media_assets<C: ConnectionTrait>(
+    conn: &C,
+    video_id: i32,
+) -> Result<Vec<MediaAssetModel>, DbErr> {
+    let media_assets: Vec<MediaAssetModel> = video_assets::Entity::find()
+        .find_also_related(media_assets::Entity)
+        .filter(video_assets::Column::VideoId.eq(video_id))
+        .all(conn)
+        .await?
+        .into_iter()
+        .filter_map(|(_, media_asset)| media_asset)
+        .collect();
+
+    if !media_assets.is_empty() {
+        media_assets::Entity::delete_many()
+            .filter(media_assets::Column::Id.is_in(media_assets.iter().map(|m| m.id)))
+            .exec(conn)
+            .await?;
+    }
+
+    Ok(media_assets)
+}
+
+/// Bitrate médio do arquivo inteiro, quando a duração é conhecida.
+fn bitrate_kbps(asset: &MediaAssetModel) -> Option<i32> {
+    asset
+        .duration_ms
+        .filter(|ms| *ms > 0)
+        .map(|ms| (asset.size_bytes as i128 * 8 / ms as i128).min(i32::MAX as i128) as i32)
+}
@@ -1,21 +1,42 @@
+use crate::enums::availability_owner_enum::AvailabilityOwnerEnum;
+use crate::enums::image_owner_enum::ImageOwnerEnum;
+use crate::modules::database::repositories::availability_repository::delete_windows;
 use crate::modules::database::repositories::dto::find_many_video_output_dto::FindManyVideoOutputDTO;
-use crate::modules::database::schema::videos;
+use crate::modules::database::repositories::events_repository::EventsRepository;
+use crate::modules::database::repositories::images_repository::delete_owner_images;
+use crate::modules::database::repositories::search_repository::SEARCH_CONFIG;
+use crate::modules::database::repositories::video_assets_repository::delete_video_media_assets;
+use crate::modules::database::schema::images::Model as ImageModel;
+use crate::modules::database::schema::media_assets::Model as MediaAssetModel;
 use crate::modules::database::schema::videos::Model as VideoModel;
-use chrono::Utc;
+use crate::modules::database::schema::{video_actors, video_categories, video_directors, videos};
+use crate::modules::events::DomainEvent;
+use chrono::{NaiveDateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{DatabaseConnection, DbErr, Set};
+use sea_orm::sea_query::{Expr, SimpleExpr};
+use sea_orm::{DatabaseConnection, DbErr, Order, Set, SqlErr, TransactionTrait};
 use sea_orm::{QueryFilter, QueryOrder, QuerySelect};
 use serde::Deserialize;
 
+/// Registros removidos junto com o vídeo, cujos arquivos ainda precisam sair
+/// do storage.
+#[derive(Debug, Default)]
+pub struct PurgedVideo {
+    pub media_assets: Vec<MediaAssetModel>,
+    pub images: Vec<ImageModel>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateVideoRequest {
     pub title: String,
     pub description: Option<String>,
-    pub duration_seconds: i32,
+    /// Sem duração informada o vídeo fica com 0 até o upload trazer a do arquivo.
+    pub duration_seconds: Option<i32>,
     pub is_available: bool,
     pub release_year: Option<i32>,
     pub video_url: Option<String>,
     pub trailer_url: Option<String>,
+    pub thumbnail_url: Option<String>,
     pub series_id: Option<i32>,
     pub episode_number: Option<i32>,
     pub season_number: Option<i32>,
@@ -30,12 +51,47 @@ pub struct UpdateVideoRequest {
     pub rating: Option<f64>,
     pub video_url: Option<String>,
     pub trailer_url: Option<String>,
+    pub thumbnail_url: Option<String>,
     pub is_available: Option<bool>,
     pub series_id: Option<i32>,
     pub episode_number: Option<i32>,
     pub season_number: Option<i32>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct VideoCastMember {
+    pub actor_id: i32,
+    pub role_name: Option<String>,
+    pub is_lead: bool,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct SetVideoCreditsRequest {
+    pub category_ids: Option<Vec<i32>>,
+    pub cast: Option<Vec<VideoCastMember>>,
+    pub director_ids: Option<Vec<i32>>,
+}
+
+/// Posição do episódio para mensagens: "T2E05", ou "E05" sem temporada.
+pub fn episode_label(season_number: Option<i32>, episode_number: i32) -> String {
+    match season_number {
+        Some(season_number) => format!("T{}E{:02}", season_number, episode_number),
+        None => format!("E{:02}", episode_number),
+    }
+}
+
+/// Índice que impede dois episódios na mesma posição da série.
+const EPISODE_UNIQUE_INDEX: &str = "idx_videos_series_episode_unique";
+
+/// Gravação recusada pelo índice de episódios únicos: outro vídeo ocupou a
+/// posição entre a checagem do serviço e a escrita.
+pub fn is_episode_taken(err: &DbErr) -> bool {
+    matches!(
+        err.sql_err(),
+        Some(SqlErr::UniqueConstraintViolation(msg)) if msg.contains(EPISODE_UNIQUE_INDEX)
+    )
+}
+
 pub struct VideosRepository {
     db: DatabaseConnection,
 }
@@ -51,24 +107,179 @@ impl VideosRepository {
             id: sea_orm::ActiveValue::NotSet,
             title: Set(request.title),
             description: Set(request.description),
-            duration_seconds: Set(request.duration_seconds),
+            duration_seconds: Set(request.duration_seconds.unwrap_or(0)),
             release_year: Set(request.release_year),
             rating: Set(0.0),
             video_url: Set(request.video_url),
             trailer_url: Set(request.trailer_url),
+            thumbnail_url: Set(request.thumbnail_url),
             is_available: Set(request.is_available),
             created_at: Set(now),
             updated_at: Set(now),
+            deleted_at: Set(None),
             series_id: Set(request.series_id),
             episode_number: Set(request.episode_number),
             season_number: Set(request.season_number),
+            external_id: sea_orm::ActiveValue::NotSet,
+            available_from: sea_orm::ActiveValue::NotSet,
+            available_until: sea_orm::ActiveValue::NotSet,
+            release_state: sea_orm::ActiveValue::NotSet,
+            maturity_rating: sea_orm::ActiveValue::NotSet,
+            content_descriptors: sea_orm::ActiveValue::NotSet,
         };
 
-        video.insert(&self.db).await
+        let txn = self.db.begin().await?;
+        let video = video.insert(&txn).await?;
+
+        EventsRepository::record(
+            &txn,
+            &DomainEvent::VideoCreated {
+                video_id: video.id,
+                title: video.title.clone(),
+                series_id: video.series_id,
+            },
+        )
+        .await?;
+
+        txn.commit().await?;
+
+        Ok(video)
     }
 
+    /// Vídeos na lixeira são tratados como inexistentes; use
+    /// [`Self::find_trashed_by_id`] para encontrá-los.
     pub async fn find_by_id(&self, video_id: i32) -> Result<Option<VideoModel>, DbErr> {
-        videos::Entity::find_by_id(video_id).one(&self.db).await
+        videos::Entity::find_by_id(video_id)
+            .filter(videos::Column::DeletedAt.is_null())
+            .one(&self.db)
+            .await
+    }
+
+    /// Vídeos fora da lixeira com os ids informados, sem ordem definida.
+    pub async fn find_by_ids(&self, video_ids: &[i32]) -> Result<Vec<VideoModel>, DbErr> {
+        if video_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        videos::Entity::find()
+            .filter(videos::Column::Id.is_in(video_ids.to_vec()))
+            .filter(videos::Column::DeletedAt.is_null())
+            .all(&self.db)
+            .await
+    }
+
+    /// Episódios visíveis da série, na ordem de exibição.
+    pub async fn find_episodes(&self, serie_id: i32) -> Result<Vec<VideoModel>, DbErr> {
+        videos::Entity::find()
+            .filter(videos::Column::SeriesId.eq(serie_id))
+            .filter(videos::Column::DeletedAt.is_null())
+            .order_by_asc(videos::Column::SeasonNumber)
+            .order_by_asc(videos::Column::EpisodeNumber)
+            .order_by_asc(videos::Column::Id)
+            .all(&self.db)
+            .await
+    }
+
+    /// Episódio visível que ocupa a posição informada na série.
+    pub async fn find_episode(
+        &self,
+        serie_id: i32,
+        season_number: Option<i32>,
+        episode_number: i32,
+    ) -> Result<Option<VideoModel>, DbErr> {
+        let season = match season_number {
+            Some(season_number) => videos::Column::SeasonNumber.eq(season_number),
+            None => videos::Column::SeasonNumber.is_null(),
+        };
+
+        videos::Entity::find()
+            .filter(videos::Column::SeriesId.eq(serie_id))
+            .filter(season)
+            .filter(videos::Column::EpisodeNumber.eq(episode_number))
+            .filter(videos::Column::DeletedAt.is_null())
+            .one(&self.db)
+            .await
+    }
+
+    pub async fn find_trashed_by_id(&self, video_id: i32) -> Result<Option<VideoModel>, DbErr> {
+        videos::Entity::find_by_id(video_id)
+            .filter(videos::Column::DeletedAt.is_not_null())
+            .one(&self.db)
+            .await
+    }
+
+    /// Itens da lixeira, os apagados mais recentemente primeiro.
+    pub async fn find_trashed(
+        &self,
+        skip: Option<u64>,
+        take: Option<u64>,
+    ) -> Result<Vec<VideoModel>, DbErr> {
+        let mut query = videos::Entity::find()
+            .filter(videos::Column::DeletedAt.is_not_null())
+            .order_by_desc(videos::Column::DeletedAt);
+
+        if let Some(skip) = skip {
+            query = query.offset(skip);
+        }
+        if let Some(take) = take {
+            query = query.limit(take);
+        }
+
+        query.all(&self.db).await
+    }
+
+    /// Vídeos na lixeira desde antes de `deleted_before`, prontos para a remoção definitiva.
+    pub async fn find_purgeable(
+        &self,
+        deleted_before: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<VideoModel>, DbErr> {
+        videos::Entity::find()
+            .filter(videos::Column::DeletedAt.lt(deleted_before))
+            .order_by_asc(videos::Column::DeletedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+    }
+
+    /// Episódios de uma série, inclusive os que estão na lixeira.
+    pub async fn find_all_by_serie(&self, serie_id: i32) -> Result<Vec<VideoModel>, DbErr> {
+        videos::Entity::find()
+            .filter(videos::Column::SeriesId.eq(serie_id))
+            .all(&self.db)
+            .await
+    }
+
+    /// Move o vídeo para a lixeira. Retorna `false` se ele não existir ou já estiver lá.
+    pub async fn soft_delete(&self, video_id: i32) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+        let result = videos::Entity::update_many()
+            .col_expr(videos::Column::DeletedAt, Expr::value(now))
+            .col_expr(videos::Column::UpdatedAt, Expr::value(now))
+            .filter(videos::Column::Id.eq(video_id))
+            .filter(videos::Column::DeletedAt.is_null())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    pub async fn restore(&self, video_id: i32) -> Result<bool, DbErr> {
+        let result = videos::Entity::update_many()
+            .col_expr(
+                videos::Column::DeletedAt,
+                Expr::value(None::<NaiveDateTime>),
+            )
+            .col_expr(
+                videos::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(videos::Column::Id.eq(video_id))
+            .filter(videos::Column::DeletedAt.is_not_null())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
     }
 
     pub async fn find_all(
@@ -76,7 +287,9 @@ impl VideosRepository {
         limit: Option<u64>,
         offset: Option<u64>,
     ) -> Result<Vec<VideoModel>, DbErr> {
-        let mut query = videos::Entity::find().filter(videos::Column::IsAvailable.eq(true));
+        let mut query = videos::Entity::find()
+            .filter(videos::Column::IsAvailable.eq(true))
+            .filter(videos::Column::DeletedAt.is_null());
         if let Some(offset) = offset {
             query = query.offset(offset);
         }
@@ -96,7 +309,9 @@ impl VideosRepository {
     ) -> Result<Vec<VideoModel>, DbErr> {
         let mut query = videos::Entity::find()
             .filter(videos::Column::IsAvailable.eq(true))
-            .filter(videos::Column::Title.contains(title))
+            .filter(videos::Column::DeletedAt.is_null())
+            .filter(search_match(title))
+            .order_by(search_rank(title), Order::Desc)
             .order_by_desc(videos::Column::Rating)
             .order_by_desc(videos::Column::CreatedAt);
 
@@ -127,17 +342,22 @@ impl VideosRepository {
                 videos::Column::EpisodeNumber,
                 videos::Column::SeasonNumber,
                 videos::Column::ReleaseYear,
+                videos::Column::ThumbnailUrl,
             ])
-            .order_by_desc(videos::Column::EpisodeNumber);
+            .filter(videos::Column::DeletedAt.is_null());
 
         if let Some(serie_id) = serie_id {
             query = query.filter(videos::Column::SeriesId.eq(serie_id))
         }
 
-        if let Some(title) = name {
-            query = query.filter(videos::Column::Title.contains(title));
+        if let Some(text) = name {
+            query = query
+                .filter(search_match(text))
+                .order_by(search_rank(text), Order::Desc);
         }
 
+        query = query.order_by_desc(videos::Column::EpisodeNumber);
+
         if let Some(skip) = skip {
             query = query.offset(skip as u64);
         }
@@ -153,6 +373,35 @@ impl VideosRepository {
     }
 
     pub async fn update(&self, video_id: i32, request: UpdateVideoRequest) -> Result<i32, DbErr> {
+        let active_model = Self::update_model(video_id, request);
+        let updated = active_model.update(&self.db).await?;
+
+        Ok(updated.id)
+    }
+
+    /// Atualiza só se `updated_at` ainda for `expected_updated_at`, numa única
+    /// instrução. Retorna `None` quando outra edição chegou antes.
+    pub async fn update_if_unmodified(
+        &self,
+        video_id: i32,
+        expected_updated_at: NaiveDateTime,
+        request: UpdateVideoRequest,
+    ) -> Result<Option<VideoModel>, DbErr> {
+        let active_model = Self::update_model(video_id, request);
+
+        match videos::Entity::update(active_model)
+            .filter(videos::Column::UpdatedAt.eq(expected_updated_at))
+            .filter(videos::Column::DeletedAt.is_null())
+            .exec(&self.db)
+            .await
+        {
+            Ok(updated) => Ok(Some(updated)),
+            Err(DbErr::RecordNotUpdated) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn update_model(video_id: i32, request: UpdateVideoRequest) -> videos::ActiveModel {
         let mut active_model = videos::ActiveModel {
             id: Set(video_id),
             updated_at: Set(Utc::now().naive_utc()),
@@ -180,6 +429,9 @@ impl VideosRepository {
         if request.trailer_url.is_some() {
             active_model.trailer_url = Set(request.trailer_url);
         }
+        if request.thumbnail_url.is_some() {
+            active_model.thumbnail_url = Set(request.thumbnail_url);
+        }
         if let Some(is_available) = request.is_available {
             active_model.is_available = Set(is_available);
         }
@@ -193,28 +445,253 @@ impl VideosRepository {
             active_model.season_number = Set(request.season_number);
         }
 
-        let updated = active_model.update(&self.db).await?;
+        active_model
+    }
 
-        Ok(updated.id)
+    /// URLs de arquivos gravadas direto no vídeo (`video_url`, `trailer_url`,
+    /// `thumbnail_url`), anteriores aos media assets.
+    pub async fn find_media_urls(&self) -> Result<Vec<String>, DbErr> {
+        let rows = videos::Entity::find()
+            .select_only()
+            .columns([
+                videos::Column::VideoUrl,
+                videos::Column::TrailerUrl,
+                videos::Column::ThumbnailUrl,
+            ])
+            .into_tuple::<(Option<String>, Option<String>, Option<String>)>()
+            .all(&self.db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .flat_map(|(video_url, trailer_url, thumbnail_url)| {
+                [video_url, trailer_url, thumbnail_url]
+            })
+            .flatten()
+            .collect())
     }
 
-    pub async fn delete(&self, video_id: i32) -> Result<bool, DbErr> {
-        if let Some(video) = videos::Entity::find_by_id(video_id).one(&self.db).await? {
-            let active_model: videos::ActiveModel = video.into();
-            active_model
-                .delete(&self.db)
-                .await
-                .map(|res| res.rows_affected > 0)
-        } else {
-            Ok(false)
-        }
+    /// Remove o vídeo definitivamente, numa única transação (histórico,
+    /// avaliações e favoritos vão junto por cascata), com seus arquivos,
+    /// janelas e imagens.
+    pub async fn purge(&self, video_id: i32) -> Result<PurgedVideo, DbErr> {
+        let txn = self.db.begin().await?;
+        let purged = purge_video_rows(&txn, video_id).await?;
+        txn.commit().await?;
+
+        Ok(purged)
+    }
+
+    /// Substitui categorias, elenco e direção do vídeo numa única transação.
+    /// Listas ausentes (`None`) ficam como estão; listas vazias limpam.
+    pub async fn set_credits(
+        &self,
+        video_id: i32,
+        request: SetVideoCreditsRequest,
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        replace_credits(&txn, video_id, request).await?;
+        txn.commit().await
     }
 
     pub async fn count(&self) -> Result<u64, DbErr> {
         let count = videos::Entity::find()
             .filter(videos::Column::IsAvailable.eq(true))
+            .filter(videos::Column::DeletedAt.is_null())
             .count(&self.db)
             .await?;
         Ok(count)
     }
 }
+
+/// Apaga o vídeo e tudo o que depende dele dentro da conexão ou transação
+/// recebida, para que a remoção de uma série leve os episódios junto.
+pub(crate) async fn purge_video_rows<C: ConnectionTrait>(
+    conn: &C,
+    video_id: i32,
+) -> Result<PurgedVideo, DbErr> {
+    let media_assets = delete_video_media_assets(conn, video_id).await?;
+
+    videos::Entity::delete_by_id(video_id).exec(conn).await?;
+    delete_windows(conn, AvailabilityOwnerEnum::VIDEO, video_id).await?;
+    let images = delete_owner_images(conn, ImageOwnerEnum::VIDEO, video_id).await?;
+
+    Ok(PurgedVideo {
+        media_assets,
+        images,
+    })
+}
+
+/// Troca os créditos do vídeo dentro da conexão ou transação recebida, para
+/// que a importação do catálogo grave tudo numa transação só.
+pub(crate) async fn replace_credits<C: ConnectionTrait>(
+    conn: &C,
+    video_id: i32,
+    request: SetVideoCreditsRequest,
+) -> Result<(), DbErr> {
+    let now = Utc::now().naive_utc();
+
+    if let Some(category_ids) = request.category_ids {
+        video_categories::Entity::delete_many()
+            .filter(video_categories::Column::VideoId.eq(video_id))
+            .exec(conn)
+            .await?;
+
+        if !category_ids.is_empty() {
+            video_categories::Entity::insert_many(category_ids.into_iter().map(|category_id| {
+                video_categories::ActiveModel {
+                    id: sea_orm::ActiveValue::NotSet,
+                    video_id: Set(video_id),
+                    category_id: Set(category_id),
+                    created_at: Set(now),
+                }
+            }))
+            .exec(conn)
+            .await?;
+        }
+    }
+
+    if let Some(cast) = request.cast {
+        video_actors::Entity::delete_many()
+            .filter(video_actors::Column::VideoId.eq(video_id))
+            .exec(conn)
+            .await?;
+
+        if !cast.is_empty() {
+            video_actors::Entity::insert_many(cast.into_iter().map(|member| {
+                video_actors::ActiveModel {
+                    id: sea_orm::ActiveValue::NotSet,
+                    video_id: Set(video_id),
+                    actor_id: Set(member.actor_id),
+                    role_name: Set(member.role_name),
+                    is_lead: Set(member.is_lead),
+                    created_at: Set(now),
+                }
+            }))
+            .exec(conn)
+            .await?;
+        }
+    }
+
+    if let Some(director_ids) = request.director_ids {
+        video_directors::Entity::delete_many()
+            .filter(video_directors::Column::VideoId.eq(video_id))
+            .exec(conn)
+            .await?;
+
+        if !director_ids.is_empty() {
+            video_directors::Entity::insert_many(director_ids.into_iter().map(|director_id| {
+                video_directors::ActiveModel {
+                    id: sea_orm::ActiveValue::NotSet,
+                    video_id: Set(video_id),
+                    director_id: Set(director_id),
+                    created_at: Set(now),
+                }
+            }))
+            .exec(conn)
+            .await?;
+        }
+    }
+
+    // muda a ETag do vídeo, já que o detalhe inclui os créditos
+    videos::Entity::update_many()
+        .col_expr(videos::Column::UpdatedAt, Expr::value(now))
+        .filter(videos::Column::Id.eq(video_id))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Termos do `websearch_to_tsquery` contra o `search_vector` (título, série,
+/// elenco, direção e descrição), sem diferenciar maiúsculas nem acentos.
+fn search_match(text: &str) -> SimpleExpr {
+    Expr::cust_with_values(
+        format!("videos.search_vector @@ websearch_to_tsquery('{SEARCH_CONFIG}', $1)"),
+        [text],
+    )
+}
+
+fn search_rank(text: &str) -> SimpleExpr {
+    Expr::cust_with_values(
+        format!("ts_rank(videos.search_vector, websearch_to_tsquery('{SEARCH_CONFIG}', $1))"),
+        [text],
+    )
+}
+
+/// Os testes com banco ficam com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::database::test_support::test_db;
+    use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+    fn episode(series_id: i32, title: &str) -> CreateVideoRequest {
+        CreateVideoRequest {
+            title: title.to_string(),
+            description: None,
+            release_year: None,
+            duration_seconds: None,
+            is_available: false,
+            trailer_url: None,
+            thumbnail_url: None,
+            series_id: Some(series_id),
+            episode_number: Some(1),
+            season_number: Some(1),
+            video_url: None,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn duplicate_episode_is_reported_as_taken() {
+        let db = test_db().await;
+        let repo = VideosRepository::new(db.clone());
+
+        let serie_id: i32 = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "INSERT INTO series (title, is_featured, created_at, updated_at)
+                 VALUES ('Episódios únicos', false, now(), now()) RETURNING id",
+            ))
+            .await
+            .unwrap()
+            .unwrap()
+            .try_get("", "id")
+            .unwrap();
+
+        let first = repo.create(episode(serie_id, "Piloto")).await.unwrap();
+        let err = repo
+            .create(episode(serie_id, "Piloto de novo"))
+            .await
+            .unwrap_err();
+        assert!(is_episode_taken(&err));
+
+        // outras violações não contam como episódio repetido
+        let err = db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "INSERT INTO videos (id, title, is_available, rating, created_at, updated_at)
+                 VALUES ($1, 'Mesmo id', false, 0, now(), now())",
+                [first.id.into()],
+            ))
+            .await
+            .unwrap_err();
+        assert!(!is_episode_taken(&err));
+
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM videos WHERE series_id = $1",
+            [serie_id.into()],
+        ))
+        .await
+        .unwrap();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM series WHERE id = $1",
+            [serie_id.into()],
+        ))
+        .await
+        .unwrap();
+    }
+}
@@ -11,14 +11,24 @@ pub struct Model {
     pub name: String,
     pub description: Option<String>,
     pub created_at: DateTime,
+    #[sea_orm(unique)]
+    pub external_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::home_rows::Entity")]
+    HomeRows,
     #[sea_orm(has_many = "super::video_categories::Entity")]
     VideoCategories,
 }
 
+impl Related<super::home_rows::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::HomeRows.def()
+    }
+}
+
 impl Related<super::video_categories::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::VideoCategories.def()
@@ -12,6 +12,8 @@ pub struct Model {
     pub birth_date: Option<DateTime>,
     pub profile_picture_url: Option<String>,
     pub created_at: DateTime,
+    #[sea_orm(unique)]
+    pub external_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
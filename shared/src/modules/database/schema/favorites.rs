@@ -8,12 +8,21 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub user_id: String,
+    pub profile_id: i32,
     pub video_id: i32,
     pub added_at: DateTime,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::profiles::Entity",
+        from = "Column::ProfileId",
+        to = "super::profiles::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Profiles,
     #[sea_orm(
         belongs_to = "super::users::Entity",
         from = "Column::UserId",
@@ -32,6 +41,12 @@ pub enum Relation {
     Videos,
 }
 
+impl Related<super::profiles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Profiles.def()
+    }
+}
+
 impl Related<super::users::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Users.def()
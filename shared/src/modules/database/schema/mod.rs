@@ -4,15 +4,27 @@ pub mod prelude;
 
 pub mod access_groups;
 pub mod actors;
+pub mod auth_sessions;
+pub mod availability_windows;
 pub mod categories;
+pub mod dead_letter_events;
 pub mod directors;
+pub mod events;
 pub mod favorites;
+pub mod home_rows;
+pub mod images;
+pub mod media_assets;
+pub mod profiles;
 pub mod ratings;
 pub mod series;
+pub mod transcode_jobs;
+pub mod upload_sessions;
 pub mod users;
 pub mod users_access_groups;
 pub mod video_actors;
+pub mod video_assets;
 pub mod video_categories;
 pub mod video_directors;
+pub mod video_similarities;
 pub mod videos;
 pub mod watch_history;
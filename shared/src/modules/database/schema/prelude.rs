@@ -2,15 +2,27 @@
 
 pub use super::access_groups::Entity as AccessGroups;
 pub use super::actors::Entity as Actors;
+pub use super::auth_sessions::Entity as AuthSessions;
+pub use super::availability_windows::Entity as AvailabilityWindows;
 pub use super::categories::Entity as Categories;
+pub use super::dead_letter_events::Entity as DeadLetterEvents;
 pub use super::directors::Entity as Directors;
+pub use super::events::Entity as Events;
 pub use super::favorites::Entity as Favorites;
+pub use super::home_rows::Entity as HomeRows;
+pub use super::images::Entity as Images;
+pub use super::media_assets::Entity as MediaAssets;
+pub use super::profiles::Entity as Profiles;
 pub use super::ratings::Entity as Ratings;
 pub use super::series::Entity as Series;
+pub use super::transcode_jobs::Entity as TranscodeJobs;
+pub use super::upload_sessions::Entity as UploadSessions;
 pub use super::users::Entity as Users;
 pub use super::users_access_groups::Entity as UsersAccessGroups;
 pub use super::video_actors::Entity as VideoActors;
+pub use super::video_assets::Entity as VideoAssets;
 pub use super::video_categories::Entity as VideoCategories;
 pub use super::video_directors::Entity as VideoDirectors;
+pub use super::video_similarities::Entity as VideoSimilarities;
 pub use super::videos::Entity as Videos;
 pub use super::watch_history::Entity as WatchHistory;
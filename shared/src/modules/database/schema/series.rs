@@ -14,6 +14,15 @@ pub struct Model {
     pub is_featured: bool,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub deleted_at: Option<DateTime>,
+    #[sea_orm(unique)]
+    pub external_id: Option<String>,
+    pub available_from: Option<DateTime>,
+    pub available_until: Option<DateTime>,
+    pub release_state: String,
+    pub maturity_rating: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub content_descriptors: Json,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
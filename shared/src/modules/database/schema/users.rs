@@ -21,8 +21,12 @@ pub struct Model {
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::auth_sessions::Entity")]
+    AuthSessions,
     #[sea_orm(has_many = "super::favorites::Entity")]
     Favorites,
+    #[sea_orm(has_many = "super::profiles::Entity")]
+    Profiles,
     #[sea_orm(has_many = "super::ratings::Entity")]
     Ratings,
     #[sea_orm(has_many = "super::users_access_groups::Entity")]
@@ -31,12 +35,24 @@ pub enum Relation {
     WatchHistory,
 }
 
+impl Related<super::auth_sessions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthSessions.def()
+    }
+}
+
 impl Related<super::favorites::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Favorites.def()
     }
 }
 
+impl Related<super::profiles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Profiles.def()
+    }
+}
+
 impl Related<super::ratings::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Ratings.def()
@@ -12,6 +12,7 @@ pub struct Model {
     pub duration_seconds: i32,
     pub video_url: Option<String>,
     pub trailer_url: Option<String>,
+    pub thumbnail_url: Option<String>,
     pub is_available: bool,
     #[sea_orm(column_type = "Double")]
     pub rating: f64,
@@ -21,6 +22,15 @@ pub struct Model {
     pub release_year: Option<i32>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub deleted_at: Option<DateTime>,
+    #[sea_orm(unique)]
+    pub external_id: Option<String>,
+    pub available_from: Option<DateTime>,
+    pub available_until: Option<DateTime>,
+    pub release_state: String,
+    pub maturity_rating: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub content_descriptors: Json,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -37,8 +47,14 @@ pub enum Relation {
         on_delete = "SetNull"
     )]
     Series,
+    #[sea_orm(has_many = "super::transcode_jobs::Entity")]
+    TranscodeJobs,
+    #[sea_orm(has_many = "super::upload_sessions::Entity")]
+    UploadSessions,
     #[sea_orm(has_many = "super::video_actors::Entity")]
     VideoActors,
+    #[sea_orm(has_many = "super::video_assets::Entity")]
+    VideoAssets,
     #[sea_orm(has_many = "super::video_categories::Entity")]
     VideoCategories,
     #[sea_orm(has_many = "super::video_directors::Entity")]
@@ -65,12 +81,30 @@ impl Related<super::series::Entity> for Entity {
     }
 }
 
+impl Related<super::transcode_jobs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TranscodeJobs.def()
+    }
+}
+
+impl Related<super::upload_sessions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UploadSessions.def()
+    }
+}
+
 impl Related<super::video_actors::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::VideoActors.def()
     }
 }
 
+impl Related<super::video_assets::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VideoAssets.def()
+    }
+}
+
 impl Related<super::video_categories::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::VideoCategories.def()
@@ -0,0 +1,17 @@
+//! Banco dos testes de integração.
+//!
+//! Os testes que usam o banco ficam com `#[ignore]` e rodam com
+//! `cargo test -- --ignored`, contra o Postgres descartável de
+//! `TEST_DATABASE_URL` com as migrações aplicadas.
+
+use sea_orm::{Database, DatabaseConnection};
+
+/// Conecta ao banco de teste. Sem `TEST_DATABASE_URL` o teste falha, para que
+/// um teste com banco nunca passe sem ter rodado.
+pub async fn test_db() -> DatabaseConnection {
+    let url = std::env::var("TEST_DATABASE_URL")
+        .expect("TEST_DATABASE_URL deve apontar para um Postgres descartável com as migrações");
+    Database::connect(url)
+        .await
+        .expect("conexão com o banco de teste")
+}
@@ -0,0 +1,63 @@
+//! ETags derivadas de `updated_at`, usadas para controle de concorrência
+//! otimista: quem edita envia `If-Match` com a ETag que leu, e a gravação só
+//! acontece se o registro não mudou desde então.
+
+use chrono::NaiveDateTime;
+
+/// ETag forte do registro; muda a cada gravação.
+pub fn from_updated_at(id: i32, updated_at: NaiveDateTime) -> String {
+    format!("\"{}-{}\"", id, updated_at.and_utc().timestamp_micros())
+}
+
+/// Confere o cabeçalho `If-Match` contra a ETag atual. Aceita `*` e listas
+/// separadas por vírgula; ETags fracas (`W/`) nunca satisfazem `If-Match`.
+pub fn if_match_satisfied(if_match: &str, current: &str) -> bool {
+    if_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate == current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(micros: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 1, 2)
+            .unwrap()
+            .and_hms_micro_opt(3, 4, 5, micros)
+            .unwrap()
+    }
+
+    #[test]
+    fn etag_is_quoted_and_changes_with_every_write() {
+        let etag = from_updated_at(7, at(1));
+
+        assert_eq!(etag, "\"7-1767323045000001\"");
+        assert_ne!(etag, from_updated_at(7, at(2)));
+        assert_ne!(etag, from_updated_at(8, at(1)));
+    }
+
+    #[test]
+    fn if_match_accepts_the_current_etag_in_a_list_or_a_wildcard() {
+        let current = from_updated_at(7, at(1));
+
+        assert!(if_match_satisfied(&current, &current));
+        assert!(if_match_satisfied("*", &current));
+        assert!(if_match_satisfied(
+            &format!("\"7-1\", {} ,\"7-2\"", current),
+            &current
+        ));
+    }
+
+    #[test]
+    fn if_match_rejects_stale_weak_and_unquoted_etags() {
+        let current = from_updated_at(7, at(1));
+
+        assert!(!if_match_satisfied(&from_updated_at(7, at(0)), &current));
+        assert!(!if_match_satisfied(&format!("W/{}", current), &current));
+        assert!(!if_match_satisfied(current.trim_matches('"'), &current));
+        assert!(!if_match_satisfied("", &current));
+    }
+}
@@ -0,0 +1,498 @@
+//! Eventos de domínio com outbox transacional.
+//!
+//! Os repositórios gravam o evento em `events` na mesma transação da mudança
+//! ([`EventsRepository::record`]). O relay do `media_worker` entrega cada
+//! evento ao Redis Stream [`EVENT_STREAM`] e aos assinantes do [`EventBus`].
+//!
+//! A entrega é pelo menos uma vez: qualquer falha faz o evento inteiro ser
+//! entregue de novo, então assinantes e consumidores do stream precisam ser
+//! idempotentes (o `id` do evento serve para descartar repetidos). Depois de
+//! [`MAX_ATTEMPTS`] o evento vai para `dead_letter_events`.
+//!
+//! [`EventsRepository::record`]: crate::modules::database::repositories::events_repository::EventsRepository::record
+
+pub mod subscribers;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+use redis::RedisError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::modules::app_state::AppState;
+use crate::modules::database::repositories::events_repository::EventsRepository;
+use crate::modules::database::schema::events::Model as EventModel;
+
+/// Redis Stream onde o relay publica todos os eventos.
+pub const EVENT_STREAM: &str = "events:domain";
+
+/// Tamanho aproximado mantido no stream; os mais antigos são descartados.
+pub const EVENT_STREAM_MAX_LEN: usize = 100_000;
+
+/// Eventos reservados por rodada do relay.
+pub const RELAY_BATCH_SIZE: u64 = 100;
+
+/// Tempo que um evento fica reservado para o relay que o pegou.
+pub const RELAY_LEASE_SECS: i64 = 60;
+
+/// Tentativas antes de o evento ir para `dead_letter_events`.
+pub const MAX_ATTEMPTS: i32 = 10;
+
+/// Por quantos dias os eventos entregues ficam no outbox.
+pub const PUBLISHED_RETENTION_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum DomainEvent {
+    VideoCreated {
+        video_id: i32,
+        title: String,
+        series_id: Option<i32>,
+    },
+    /// Novo arquivo original enviado; as renditions serão refeitas.
+    VideoSourceReplaced {
+        video_id: i32,
+        media_asset_id: i32,
+    },
+    /// A janela de disponibilidade do vídeo abriu (`region` vazia é a global).
+    VideoPublished {
+        video_id: i32,
+        region: Option<String>,
+    },
+    SeriePublished {
+        serie_id: i32,
+        region: Option<String>,
+    },
+    UserRegistered {
+        user_id: String,
+        email: String,
+    },
+    /// Classificação indicativa atribuída em lote.
+    RatingChanged {
+        video_ids: Vec<i32>,
+        serie_ids: Vec<i32>,
+        maturity_rating: String,
+        content_descriptors: Option<Vec<String>>,
+    },
+}
+
+impl DomainEvent {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::VideoCreated { .. } => "VideoCreated",
+            DomainEvent::VideoSourceReplaced { .. } => "VideoSourceReplaced",
+            DomainEvent::VideoPublished { .. } => "VideoPublished",
+            DomainEvent::SeriePublished { .. } => "SeriePublished",
+            DomainEvent::UserRegistered { .. } => "UserRegistered",
+            DomainEvent::RatingChanged { .. } => "RatingChanged",
+        }
+    }
+
+    /// Tipo e id do registro a que o evento se refere.
+    pub fn aggregate(&self) -> (&'static str, String) {
+        match self {
+            DomainEvent::VideoCreated { video_id, .. }
+            | DomainEvent::VideoSourceReplaced { video_id, .. }
+            | DomainEvent::VideoPublished { video_id, .. } => ("video", video_id.to_string()),
+            DomainEvent::SeriePublished { serie_id, .. } => ("serie", serie_id.to_string()),
+            DomainEvent::UserRegistered { user_id, .. } => ("user", user_id.clone()),
+            DomainEvent::RatingChanged {
+                maturity_rating, ..
+            } => ("maturity_rating", maturity_rating.clone()),
+        }
+    }
+
+    /// Dados do evento, sem o tipo, como ficam na coluna `payload`.
+    pub fn payload(&self) -> Result<Value, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        Ok(value
+            .get_mut("data")
+            .map(Value::take)
+            .unwrap_or(Value::Null))
+    }
+
+    /// Reconstrói o evento a partir das colunas do outbox.
+    pub fn from_parts(event_type: &str, payload: &Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(serde_json::json!({ "type": event_type, "data": payload }))
+    }
+}
+
+/// Assinante em processo dos eventos entregues pelo relay.
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn handle(&self, state: &AppState, event: &DomainEvent) -> Result<()>;
+}
+
+#[derive(Default, Clone)]
+pub struct EventBus {
+    subscribers: Vec<Arc<dyn EventSubscriber>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subscribe(mut self, subscriber: Arc<dyn EventSubscriber>) -> Self {
+        self.subscribers.push(subscriber);
+        self
+    }
+
+    /// Entrega a todos os assinantes, mesmo que algum falhe; o erro junta as
+    /// falhas de todos.
+    pub async fn dispatch(&self, state: &AppState, event: &DomainEvent) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        for subscriber in &self.subscribers {
+            if let Err(e) = subscriber.handle(state, event).await {
+                errors.push(format!("{}: {}", subscriber.name(), e));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+}
+
+/// Assinantes padrão do relay.
+pub fn default_bus() -> EventBus {
+    EventBus::new()
+        .subscribe(Arc::new(subscribers::CatalogCacheSubscriber))
+        .subscribe(Arc::new(subscribers::SearchIndexSubscriber))
+        .subscribe(Arc::new(subscribers::TranscodeSubscriber))
+}
+
+#[derive(Debug, Default)]
+pub struct RelayReport {
+    pub published: usize,
+    pub retried: usize,
+    pub dead_lettered: usize,
+    pub purged: u64,
+}
+
+impl RelayReport {
+    /// Eventos reservados na rodada, qualquer que tenha sido o resultado.
+    pub fn claimed(&self) -> usize {
+        self.published + self.retried + self.dead_lettered
+    }
+}
+
+/// Entrega os eventos pendentes até `now`. Falhas no banco interrompem a
+/// rodada; falhas na entrega só reagendam o evento.
+pub async fn relay_once(
+    state: &AppState,
+    bus: &EventBus,
+    now: NaiveDateTime,
+) -> Result<RelayReport, sea_orm::DbErr> {
+    relay_due(&state.event_repo, now, |event| async move {
+        deliver(state, bus, &event).await
+    })
+    .await
+}
+
+/// [`relay_once`] com a entrega separada do outbox.
+async fn relay_due<F, Fut>(
+    events: &EventsRepository,
+    now: NaiveDateTime,
+    mut deliver: F,
+) -> Result<RelayReport, sea_orm::DbErr>
+where
+    F: FnMut(EventModel) -> Fut,
+    Fut: Future<Output = Result<(), Delivery>>,
+{
+    let mut report = RelayReport::default();
+
+    let claimed = events
+        .claim_due(now, RELAY_BATCH_SIZE, RELAY_LEASE_SECS)
+        .await?;
+
+    for event in claimed {
+        match deliver(event.clone()).await {
+            Ok(()) => {
+                events.mark_published(event.id, now).await?;
+                report.published += 1;
+            }
+            Err(Delivery::Permanent(error)) => {
+                tracing::error!("Evento {} descartado: {}", event.id, error);
+                events.dead_letter(&event, &error).await?;
+                report.dead_lettered += 1;
+            }
+            Err(Delivery::Failed(error)) if event.attempts >= MAX_ATTEMPTS => {
+                tracing::error!(
+                    "Evento {} ({}) falhou {} vezes: {}",
+                    event.id,
+                    event.event_type,
+                    event.attempts,
+                    error
+                );
+                events.dead_letter(&event, &error).await?;
+                report.dead_lettered += 1;
+            }
+            Err(Delivery::Failed(error)) => {
+                tracing::warn!(
+                    "Falha ao entregar o evento {} ({}), tentativa {}: {}",
+                    event.id,
+                    event.event_type,
+                    event.attempts,
+                    error
+                );
+                events
+                    .schedule_retry(event.id, &error, now + retry_delay(event.attempts))
+                    .await?;
+                report.retried += 1;
+            }
+        }
+    }
+
+    report.purged = events
+        .purge_published(now - Duration::days(PUBLISHED_RETENTION_DAYS))
+        .await?;
+
+    Ok(report)
+}
+
+enum Delivery {
+    /// Não adianta tentar de novo, como um tipo de evento desconhecido.
+    Permanent(String),
+    Failed(String),
+}
+
+async fn deliver(state: &AppState, bus: &EventBus, event: &EventModel) -> Result<(), Delivery> {
+    let domain_event = decode(event)?;
+
+    publish_to_stream(state, event)
+        .await
+        .map_err(|e| Delivery::Failed(format!("Redis Stream: {}", e)))?;
+
+    bus.dispatch(state, &domain_event)
+        .await
+        .map_err(Delivery::Failed)
+}
+
+/// Evento do outbox que não corresponde a nenhum [`DomainEvent`] nunca vai ser entregue.
+fn decode(event: &EventModel) -> Result<DomainEvent, Delivery> {
+    DomainEvent::from_parts(&event.event_type, &event.payload)
+        .map_err(|e| Delivery::Permanent(format!("Evento '{}' inválido: {}", event.event_type, e)))
+}
+
+async fn publish_to_stream(state: &AppState, event: &EventModel) -> Result<(), RedisError> {
+    state
+        .cache
+        .stream_add(
+            EVENT_STREAM,
+            EVENT_STREAM_MAX_LEN,
+            &[
+                ("id", event.id.to_string()),
+                ("type", event.event_type.clone()),
+                ("aggregate_type", event.aggregate_type.clone()),
+                ("aggregate_id", event.aggregate_id.clone()),
+                ("payload", event.payload.to_string()),
+                ("created_at", event.created_at.and_utc().to_rfc3339()),
+            ],
+        )
+        .await
+        .map(|_| ())
+}
+
+/// Espera exponencial a partir de 5 segundos, até uma hora.
+fn retry_delay(attempts: i32) -> Duration {
+    let secs = 5_i64.saturating_mul(1 << attempts.clamp(0, 20));
+    Duration::seconds(secs.min(60 * 60))
+}
+
+/// O teste do relay usa o banco e fica com `#[ignore]`; veja `test_support`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::database::test_support::test_db;
+    use chrono::NaiveDate;
+    use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+
+    /// Grava um evento `VideoCreated` com o `aggregate_id` que o teste usa
+    /// para decidir a entrega.
+    async fn insert_event(
+        db: &DatabaseConnection,
+        aggregate_id: &str,
+        event_type: &str,
+        attempts: i32,
+        available_at: NaiveDateTime,
+        published_at: Option<NaiveDateTime>,
+    ) -> i64 {
+        db.query_one(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "INSERT INTO events (event_type, aggregate_type, aggregate_id, payload, attempts,
+                available_at, published_at, created_at)
+             VALUES ($1, 'video', $2, '{\"video_id\": 1, \"title\": \"Relay\", \"series_id\": null}',
+                $3, $4, $5, $4)
+             RETURNING id",
+            [
+                event_type.into(),
+                aggregate_id.into(),
+                attempts.into(),
+                available_at.into(),
+                published_at.into(),
+            ],
+        ))
+        .await
+        .unwrap()
+        .unwrap()
+        .try_get("", "id")
+        .unwrap()
+    }
+
+    #[test]
+    fn retry_delay_doubles_from_five_seconds_up_to_an_hour() {
+        assert_eq!(retry_delay(0), Duration::seconds(5));
+        assert_eq!(retry_delay(1), Duration::seconds(10));
+        assert_eq!(retry_delay(3), Duration::seconds(40));
+        assert_eq!(retry_delay(9), Duration::seconds(2560));
+        assert_eq!(retry_delay(10), Duration::hours(1));
+        assert_eq!(retry_delay(i32::MAX), Duration::hours(1));
+        assert_eq!(retry_delay(-1), Duration::seconds(5));
+    }
+
+    #[test]
+    fn events_round_trip_through_the_outbox_columns() {
+        let event = DomainEvent::VideoSourceReplaced {
+            video_id: 7,
+            media_asset_id: 42,
+        };
+
+        let payload = event.payload().unwrap();
+        assert_eq!(
+            payload,
+            serde_json::json!({ "video_id": 7, "media_asset_id": 42 })
+        );
+        assert_eq!(
+            DomainEvent::from_parts(event.event_type(), &payload).unwrap(),
+            event
+        );
+        assert!(DomainEvent::from_parts("VideoDeleted", &payload).is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "precisa de TEST_DATABASE_URL"]
+    async fn relay_publishes_retries_dead_letters_and_purges() {
+        let db = test_db().await;
+        let repo = EventsRepository::new(db.clone());
+
+        // Bem no passado, para que nenhum outro evento pendente entre na rodada.
+        let now = NaiveDate::from_ymd_opt(1990, 1, 10)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let due = now - Duration::minutes(1);
+
+        let ok = insert_event(&db, "ok", "VideoCreated", 0, due, None).await;
+        let failing = insert_event(&db, "fail", "VideoCreated", 2, due, None).await;
+        let exhausted =
+            insert_event(&db, "fail", "VideoCreated", MAX_ATTEMPTS - 1, due, None).await;
+        let invalid = insert_event(&db, "ok", "VideoDeleted", 0, due, None).await;
+        let later =
+            insert_event(&db, "ok", "VideoCreated", 0, now + Duration::hours(1), None).await;
+        let old = insert_event(
+            &db,
+            "ok",
+            "VideoCreated",
+            1,
+            due,
+            Some(now - Duration::days(PUBLISHED_RETENTION_DAYS + 1)),
+        )
+        .await;
+
+        let report = relay_due(&repo, now, |event| async move {
+            decode(&event)?;
+            match event.aggregate_id.as_str() {
+                "ok" => Ok(()),
+                _ => Err(Delivery::Failed("assinante fora do ar".to_string())),
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(report.published, 1);
+        assert_eq!(report.retried, 1);
+        assert_eq!(report.dead_lettered, 2);
+        assert!(report.purged >= 1);
+
+        let find = |id: i64| {
+            let db = db.clone();
+            async move {
+                db.query_one(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    "SELECT attempts, available_at, published_at, last_error
+                     FROM events WHERE id = $1",
+                    [id.into()],
+                ))
+                .await
+                .unwrap()
+            }
+        };
+
+        let row = find(ok).await.unwrap();
+        let published_at: Option<NaiveDateTime> = row.try_get("", "published_at").unwrap();
+        assert_eq!(published_at, Some(now));
+
+        // a tentativa da rodada conta para a espera seguinte
+        let row = find(failing).await.unwrap();
+        assert_eq!(row.try_get::<i32>("", "attempts").unwrap(), 3);
+        assert_eq!(
+            row.try_get::<NaiveDateTime>("", "available_at").unwrap(),
+            now + retry_delay(3)
+        );
+        assert_eq!(
+            row.try_get::<Option<String>>("", "last_error").unwrap(),
+            Some("assinante fora do ar".to_string())
+        );
+
+        // fora do prazo não é reservado
+        let row = find(later).await.unwrap();
+        assert_eq!(row.try_get::<i32>("", "attempts").unwrap(), 0);
+
+        assert!(find(exhausted).await.is_none());
+        assert!(find(invalid).await.is_none());
+        assert!(find(old).await.is_none());
+
+        let dead = db
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT event_id, attempts, last_error FROM dead_letter_events
+                 WHERE event_id IN ($1, $2) ORDER BY event_id",
+                [exhausted.into(), invalid.into()],
+            ))
+            .await
+            .unwrap();
+        assert_eq!(dead.len(), 2);
+        assert_eq!(
+            dead[0].try_get::<i32>("", "attempts").unwrap(),
+            MAX_ATTEMPTS
+        );
+        assert!(dead[1]
+            .try_get::<String>("", "last_error")
+            .unwrap()
+            .contains("VideoDeleted"));
+
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM events WHERE id IN ($1, $2, $3)",
+            [ok.into(), failing.into(), later.into()],
+        ))
+        .await
+        .unwrap();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM dead_letter_events WHERE event_id IN ($1, $2)",
+            [exhausted.into(), invalid.into()],
+        ))
+        .await
+        .unwrap();
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::modules::app_state::AppState;
+use crate::modules::availability::CATALOG_CACHE_PATTERN;
+use crate::modules::database::repositories::search_repository::IndexScope;
+use crate::modules::events::{DomainEvent, EventSubscriber};
+use crate::modules::search;
+
+/// Limpa as respostas em cache do catálogo (home, busca e sugestões) quando a
+/// classificação de algum título muda, já que todas filtram por ela.
+///
+/// Ao contrário de `availability::invalidate_catalog_cache`, devolve a falha
+/// do Redis para que o relay tente de novo.
+pub struct CatalogCacheSubscriber;
+
+#[async_trait]
+impl EventSubscriber for CatalogCacheSubscriber {
+    fn name(&self) -> &'static str {
+        "catalog_cache"
+    }
+
+    async fn handle(&self, state: &AppState, event: &DomainEvent) -> Result<()> {
+        if let DomainEvent::RatingChanged { .. } = event {
+            state.cache.clear_pattern(CATALOG_CACHE_PATTERN).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Regrava no índice de busca os vídeos criados, com original novo ou com
+/// classificação alterada (inclusive os episódios das séries classificadas).
+pub struct SearchIndexSubscriber;
+
+#[async_trait]
+impl EventSubscriber for SearchIndexSubscriber {
+    fn name(&self) -> &'static str {
+        "search_index"
+    }
+
+    async fn handle(&self, state: &AppState, event: &DomainEvent) -> Result<()> {
+        let Some((mut video_ids, serie_ids)) = indexed_changes(event) else {
+            return Ok(());
+        };
+
+        for serie_id in serie_ids {
+            video_ids.extend(
+                state
+                    .search_repo
+                    .find_video_ids(IndexScope::Serie(serie_id))
+                    .await?,
+            );
+        }
+        video_ids.sort_unstable();
+        video_ids.dedup();
+
+        search::index::try_sync_videos(state, &video_ids).await
+    }
+}
+
+/// Vídeos e séries cujos documentos no índice mudam com o evento. Vale para
+/// todo caminho que grava o evento, como os dois uploads do original.
+fn indexed_changes(event: &DomainEvent) -> Option<(Vec<i32>, Vec<i32>)> {
+    match event {
+        DomainEvent::VideoCreated { video_id, .. }
+        | DomainEvent::VideoSourceReplaced { video_id, .. } => Some((vec![*video_id], Vec::new())),
+        DomainEvent::RatingChanged {
+            video_ids,
+            serie_ids,
+            ..
+        } => Some((video_ids.clone(), serie_ids.clone())),
+        DomainEvent::VideoPublished { .. }
+        | DomainEvent::SeriePublished { .. }
+        | DomainEvent::UserRegistered { .. } => None,
+    }
+}
+
+/// Enfileira a transcodificação de todo original novo, que precisa de
+/// renditions próprias.
+pub struct TranscodeSubscriber;
+
+#[async_trait]
+impl EventSubscriber for TranscodeSubscriber {
+    fn name(&self) -> &'static str {
+        "transcode"
+    }
+
+    async fn handle(&self, state: &AppState, event: &DomainEvent) -> Result<()> {
+        if let DomainEvent::VideoSourceReplaced {
+            video_id,
+            media_asset_id,
+        } = event
+        {
+            // original já trocado de novo ou job já criado numa entrega anterior
+            if state
+                .transcode_job_repo
+                .enqueue_for_source(*video_id, *media_asset_id)
+                .await?
+                .is_none()
+            {
+                tracing::debug!(
+                    "Sem job novo para o original {} do vídeo {}",
+                    media_asset_id,
+                    video_id
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_uploads_and_catalog_changes_reach_the_search_index() {
+        assert_eq!(
+            indexed_changes(&DomainEvent::VideoSourceReplaced {
+                video_id: 3,
+                media_asset_id: 9,
+            }),
+            Some((vec![3], vec![]))
+        );
+        assert_eq!(
+            indexed_changes(&DomainEvent::VideoCreated {
+                video_id: 4,
+                title: "Novo".to_string(),
+                series_id: Some(1),
+            }),
+            Some((vec![4], vec![]))
+        );
+        assert_eq!(
+            indexed_changes(&DomainEvent::RatingChanged {
+                video_ids: vec![1, 2],
+                serie_ids: vec![5],
+                maturity_rating: "14".to_string(),
+                content_descriptors: None,
+            }),
+            Some((vec![1, 2], vec![5]))
+        );
+        assert_eq!(
+            indexed_changes(&DomainEvent::UserRegistered {
+                user_id: "u".to_string(),
+                email: "u@example.com".to_string(),
+            }),
+            None
+        );
+    }
+}
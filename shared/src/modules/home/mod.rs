@@ -0,0 +1,45 @@
+//! Home do espectador: linhas configuradas pelo admin em `home_rows`,
+//! montadas pela viewer_api com cache por linha no Redis.
+//!
+//! As chaves ficam sob `catalog:` para serem limpas junto com o resto do
+//! catálogo quando algo entra ou sai do ar.
+
+use crate::enums::maturity_rating_enum::MaturityRatingEnum;
+use crate::modules::app_state::AppState;
+
+/// Prefixo das linhas montadas no cache.
+pub const HOME_CACHE_PREFIX: &str = "catalog:home";
+
+/// Chave da linha para a região e a classificação máxima; linhas pessoais
+/// levam também o perfil.
+pub fn row_cache_key(
+    row_id: i32,
+    region: Option<&str>,
+    max_rating: Option<MaturityRatingEnum>,
+    profile_id: Option<i32>,
+) -> String {
+    let key = format!(
+        "{}:row:{}:{}:{}",
+        HOME_CACHE_PREFIX,
+        row_id,
+        region.unwrap_or("GLOBAL"),
+        max_rating.map_or("ALL", |rating| rating.as_str())
+    );
+
+    match profile_id {
+        Some(profile_id) => format!("{}:profile:{}", key, profile_id),
+        None => key,
+    }
+}
+
+/// Descarta o que está no cache de uma linha, depois de alterada ou removida.
+pub async fn invalidate_row(state: &AppState, row_id: i32) {
+    let pattern = format!("{}:row:{}:*", HOME_CACHE_PREFIX, row_id);
+    if let Err(e) = state.cache.clear_pattern(&pattern).await {
+        tracing::warn!(
+            "Falha ao limpar o cache da linha {} da home: {:?}",
+            row_id,
+            e
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Imagens de catálogo (thumbnails, posters e fotos): validação do arquivo
+//! enviado e geração das variantes redimensionadas servidas aos clientes.
+
+use std::fmt;
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat, ImageReader, Limits};
+
+use crate::enums::image_format_enum::ImageFormatEnum;
+
+/// Tamanho máximo aceito para um arquivo de imagem.
+pub const MAX_IMAGE_SIZE: usize = 10 * 1024 * 1024;
+
+/// Menor lado aceito, em pixels.
+pub const MIN_IMAGE_DIMENSION: u32 = 64;
+
+/// Maior lado aceito, em pixels. Barra imagens que explodiriam a memória
+/// ao serem decodificadas.
+pub const MAX_IMAGE_DIMENSION: u32 = 8192;
+
+const JPEG_QUALITY: u8 = 85;
+
+/// Uma variante limita apenas a largura; a altura segue a proporção original.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageVariantSpec {
+    pub name: &'static str,
+    pub max_width: u32,
+}
+
+pub const IMAGE_VARIANTS: [ImageVariantSpec; 3] = [
+    ImageVariantSpec {
+        name: "small",
+        max_width: 320,
+    },
+    ImageVariantSpec {
+        name: "medium",
+        max_width: 640,
+    },
+    ImageVariantSpec {
+        name: "large",
+        max_width: 1280,
+    },
+];
+
+#[derive(Debug)]
+pub enum ImageError {
+    Empty,
+    TooLarge,
+    UnsupportedFormat,
+    InvalidDimensions { width: u32, height: u32 },
+    Decode(String),
+    Encode(String),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Empty => write!(f, "O arquivo de imagem está vazio"),
+            ImageError::TooLarge => {
+                write!(f, "O arquivo de imagem excede {} bytes", MAX_IMAGE_SIZE)
+            }
+            ImageError::UnsupportedFormat => {
+                write!(
+                    f,
+                    "Formato de imagem não suportado. Envie JPEG, PNG ou WEBP"
+                )
+            }
+            ImageError::InvalidDimensions { width, height } => write!(
+                f,
+                "Dimensões {}x{} fora do permitido ({} a {} pixels por lado)",
+                width, height, MIN_IMAGE_DIMENSION, MAX_IMAGE_DIMENSION
+            ),
+            ImageError::Decode(e) => write!(f, "Não foi possível ler a imagem: {}", e),
+            ImageError::Encode(e) => write!(f, "Não foi possível gerar a variante: {}", e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EncodedVariant {
+    pub name: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub format: ImageFormatEnum,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct ProcessedImage {
+    pub format: ImageFormatEnum,
+    pub width: u32,
+    pub height: u32,
+    pub variants: Vec<EncodedVariant>,
+}
+
+/// Identifica o formato pelos bytes iniciais, ignorando extensão e
+/// `Content-Type` informados pelo cliente.
+pub fn detect_format(bytes: &[u8]) -> Option<ImageFormatEnum> {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some(ImageFormatEnum::JPEG);
+    }
+
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return Some(ImageFormatEnum::PNG);
+    }
+
+    if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return Some(ImageFormatEnum::WEBP);
+    }
+
+    None
+}
+
+fn image_format(format: ImageFormatEnum) -> ImageFormat {
+    match format {
+        ImageFormatEnum::JPEG => ImageFormat::Jpeg,
+        ImageFormatEnum::PNG => ImageFormat::Png,
+        ImageFormatEnum::WEBP => ImageFormat::WebP,
+    }
+}
+
+fn fits_limits(width: u32, height: u32) -> bool {
+    (MIN_IMAGE_DIMENSION..=MAX_IMAGE_DIMENSION).contains(&width)
+        && (MIN_IMAGE_DIMENSION..=MAX_IMAGE_DIMENSION).contains(&height)
+}
+
+/// Valida a imagem e gera as variantes de [`IMAGE_VARIANTS`].
+///
+/// As dimensões são conferidas no cabeçalho antes da decodificação. As
+/// variantes nunca ampliam a original e saem em JPEG, ou em PNG quando a
+/// imagem tem transparência. O trabalho é todo síncrono; chame dentro de
+/// `spawn_blocking`.
+pub fn process(bytes: &[u8]) -> Result<ProcessedImage, ImageError> {
+    if bytes.is_empty() {
+        return Err(ImageError::Empty);
+    }
+
+    if bytes.len() > MAX_IMAGE_SIZE {
+        return Err(ImageError::TooLarge);
+    }
+
+    let format = detect_format(bytes).ok_or(ImageError::UnsupportedFormat)?;
+
+    // O cabeçalho é lido sem limites para que uma imagem grande demais seja
+    // reportada com as suas dimensões, e não como erro de leitura.
+    let (width, height) = ImageReader::with_format(Cursor::new(bytes), image_format(format))
+        .into_dimensions()
+        .map_err(|e| ImageError::Decode(e.to_string()))?;
+
+    if !fits_limits(width, height) {
+        return Err(ImageError::InvalidDimensions { width, height });
+    }
+
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), image_format(format));
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+    limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+    reader.limits(limits);
+
+    let image = reader.decode().map_err(|e| match e {
+        image::ImageError::Limits(_) => ImageError::InvalidDimensions { width, height },
+        e => ImageError::Decode(e.to_string()),
+    })?;
+
+    let variants = IMAGE_VARIANTS
+        .iter()
+        .map(|spec| encode_variant(&image, spec))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ProcessedImage {
+        format,
+        width,
+        height,
+        variants,
+    })
+}
+
+fn encode_variant(
+    image: &DynamicImage,
+    spec: &ImageVariantSpec,
+) -> Result<EncodedVariant, ImageError> {
+    let resized = if image.width() > spec.max_width {
+        image.resize(spec.max_width, image.height(), FilterType::Lanczos3)
+    } else {
+        image.clone()
+    };
+
+    let mut data = Vec::new();
+
+    let format = if resized.color().has_alpha() {
+        resized
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .map_err(|e| ImageError::Encode(e.to_string()))?;
+        ImageFormatEnum::PNG
+    } else {
+        JpegEncoder::new_with_quality(&mut data, JPEG_QUALITY)
+            .encode_image(&resized.to_rgb8())
+            .map_err(|e| ImageError::Encode(e.to_string()))?;
+        ImageFormatEnum::JPEG
+    };
+
+    Ok(EncodedVariant {
+        name: spec.name,
+        width: resized.width(),
+        height: resized.height(),
+        format,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Rgb, RgbImage, Rgba, RgbaImage};
+
+    use super::*;
+
+    fn png(image: DynamicImage) -> Vec<u8> {
+        let mut data = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn detects_format_from_magic_bytes() {
+        assert_eq!(
+            detect_format(&[0xFF, 0xD8, 0xFF, 0xE0]),
+            Some(ImageFormatEnum::JPEG)
+        );
+        assert_eq!(
+            detect_format(&png(RgbImage::new(1, 1).into())),
+            Some(ImageFormatEnum::PNG)
+        );
+        assert_eq!(
+            detect_format(b"RIFF\0\0\0\0WEBPVP8 "),
+            Some(ImageFormatEnum::WEBP)
+        );
+        assert_eq!(detect_format(b"GIF89a"), None);
+    }
+
+    #[test]
+    fn generates_variants_without_upscaling() {
+        let bytes = png(RgbImage::from_pixel(800, 400, Rgb([10, 20, 30])).into());
+
+        let processed = process(&bytes).unwrap();
+
+        assert_eq!((processed.width, processed.height), (800, 400));
+        let sizes: Vec<_> = processed
+            .variants
+            .iter()
+            .map(|v| (v.name, v.width, v.height, v.format))
+            .collect();
+        assert_eq!(
+            sizes,
+            vec![
+                ("small", 320, 160, ImageFormatEnum::JPEG),
+                ("medium", 640, 320, ImageFormatEnum::JPEG),
+                ("large", 800, 400, ImageFormatEnum::JPEG),
+            ]
+        );
+    }
+
+    #[test]
+    fn transparent_images_keep_png_variants() {
+        let bytes = png(RgbaImage::from_pixel(100, 100, Rgba([0, 0, 0, 0])).into());
+
+        let processed = process(&bytes).unwrap();
+        assert!(processed
+            .variants
+            .iter()
+            .all(|v| v.format == ImageFormatEnum::PNG));
+    }
+
+    #[test]
+    fn images_beyond_the_dimension_limit_report_their_size() {
+        let width = MAX_IMAGE_DIMENSION + 1;
+        let bytes = png(RgbImage::new(width, MIN_IMAGE_DIMENSION).into());
+
+        assert!(matches!(
+            process(&bytes),
+            Err(ImageError::InvalidDimensions { width: w, height: h })
+                if w == width && h == MIN_IMAGE_DIMENSION
+        ));
+    }
+
+    #[test]
+    fn images_below_the_dimension_limit_are_rejected() {
+        let bytes = png(RgbImage::new(32, 100).into());
+
+        assert!(matches!(
+            process(&bytes),
+            Err(ImageError::InvalidDimensions {
+                width: 32,
+                height: 100
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_empty_unknown_and_corrupted_files() {
+        assert!(matches!(process(&[]), Err(ImageError::Empty)));
+        assert!(matches!(
+            process(b"GIF89a......"),
+            Err(ImageError::UnsupportedFormat)
+        ));
+
+        let mut bytes = png(RgbImage::new(100, 100).into());
+        bytes.truncate(40);
+        assert!(matches!(process(&bytes), Err(ImageError::Decode(_))));
+    }
+}
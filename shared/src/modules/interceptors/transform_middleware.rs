@@ -1,6 +1,6 @@
 use axum::{
     body::{to_bytes, Body},
-    http::Response,
+    http::{header, Response},
     middleware::Next,
     response::IntoResponse,
 };
@@ -18,6 +18,17 @@ struct HttpResponse {
 pub async fn transform_middleware(req: axum::http::Request<Body>, next: Next) -> impl IntoResponse {
     let response = next.run(req).await;
 
+    // Respostas binárias (streams de mídia) passam sem o envelope JSON.
+    let is_binary = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| !ct.starts_with("application/json") && !ct.starts_with("text/plain"));
+
+    if is_binary {
+        return response;
+    }
+
     let status = response.status();
     let body_bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
 
@@ -0,0 +1,137 @@
+//! Classificação indicativa: o limite de cada perfil e a liberação de um
+//! título acima dele com o PIN do perfil principal da conta.
+//!
+//! Títulos sem classificação só aparecem para perfis sem restrição (18).
+//! Episódios sem classificação própria herdam a da série.
+
+use sea_orm::DbErr;
+use serde_json::Value;
+
+use crate::enums::maturity_rating_enum::MaturityRatingEnum;
+use crate::modules::app_state::AppState;
+use crate::modules::database::schema::profiles::Model as ProfileModel;
+use crate::modules::database::schema::videos::Model as VideoModel;
+
+/// Prefixo das liberações feitas com PIN.
+pub const UNLOCK_KEY_PREFIX: &str = "parental:unlock";
+
+/// Quanto tempo um título liberado com PIN fica liberado para o perfil.
+pub const UNLOCK_TTL_SECS: u64 = 4 * 60 * 60;
+
+/// Limite de classificação do perfil em uso.
+#[derive(Debug, Clone, Copy)]
+pub struct ParentalControl {
+    pub profile_id: i32,
+    /// `None` libera tudo, inclusive o que não tem classificação.
+    pub max_rating: Option<MaturityRatingEnum>,
+}
+
+impl From<&ProfileModel> for ParentalControl {
+    fn from(profile: &ProfileModel) -> Self {
+        // valor inválido no banco conta como o mais restrito
+        let mut level = MaturityRatingEnum::try_from(profile.maturity_level.as_str())
+            .unwrap_or(MaturityRatingEnum::L);
+        if profile.is_kids {
+            level = level.min(MaturityRatingEnum::KIDS_MAX);
+        }
+
+        Self {
+            profile_id: profile.id,
+            max_rating: (level != MaturityRatingEnum::A18).then_some(level),
+        }
+    }
+}
+
+impl ParentalControl {
+    /// Indica se o perfil pode ver um título com a classificação `rating`.
+    pub fn allows(&self, rating: Option<MaturityRatingEnum>) -> bool {
+        match self.max_rating {
+            None => true,
+            Some(max) => rating.is_some_and(|rating| rating <= max),
+        }
+    }
+}
+
+/// Lê a classificação gravada; valores inválidos contam como ausentes.
+pub fn parse_rating(value: Option<&str>) -> Option<MaturityRatingEnum> {
+    value.and_then(|value| MaturityRatingEnum::try_from(value).ok())
+}
+
+/// Descritores gravados em `content_descriptors`.
+pub fn descriptors(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Classificação do vídeo, ou a da série quando ele não tem a sua.
+pub async fn video_rating(
+    state: &AppState,
+    video: &VideoModel,
+) -> Result<Option<MaturityRatingEnum>, DbErr> {
+    if let Some(rating) = parse_rating(video.maturity_rating.as_deref()) {
+        return Ok(Some(rating));
+    }
+
+    let Some(series_id) = video.series_id else {
+        return Ok(None);
+    };
+
+    Ok(state
+        .serie_repo
+        .find_by_id(series_id)
+        .await?
+        .and_then(|serie| parse_rating(serie.maturity_rating.as_deref())))
+}
+
+/// Indica se o perfil pode assistir ao vídeo: pela classificação ou por uma
+/// liberação com PIN ainda válida.
+pub async fn can_watch(
+    state: &AppState,
+    control: &ParentalControl,
+    video: &VideoModel,
+) -> Result<bool, DbErr> {
+    if control.allows(video_rating(state, video).await?) {
+        return Ok(true);
+    }
+
+    Ok(is_unlocked(state, control.profile_id, video.id).await)
+}
+
+fn unlock_key(profile_id: i32, video_id: i32) -> String {
+    format!("{}:{}:{}", UNLOCK_KEY_PREFIX, profile_id, video_id)
+}
+
+/// Libera o vídeo para o perfil por [`UNLOCK_TTL_SECS`].
+pub async fn unlock_video(
+    state: &AppState,
+    profile_id: i32,
+    video_id: i32,
+) -> Result<(), redis::RedisError> {
+    state
+        .cache
+        .set_with_ttl(unlock_key(profile_id, video_id), &true, UNLOCK_TTL_SECS)
+        .await
+}
+
+/// Sem Redis, nada fica liberado.
+pub async fn is_unlocked(state: &AppState, profile_id: i32, video_id: i32) -> bool {
+    match state.cache.exists(unlock_key(profile_id, video_id)).await {
+        Ok(unlocked) => unlocked,
+        Err(e) => {
+            tracing::warn!(
+                "Falha ao consultar a liberação do vídeo {} para o perfil {}: {:?}",
+                video_id,
+                profile_id,
+                e
+            );
+            false
+        }
+    }
+}
@@ -0,0 +1,555 @@
+//! Matroska/WebM: lê o cabeçalho EBML e percorre os filhos do `Segment` até o
+//! primeiro `Cluster`, carregando apenas `Info`, `Tracks` e `SeekHead`.
+
+use super::{read_exact, read_uint, ByteSource, ProbeError, ProbeResult};
+use crate::enums::container_format_enum::ContainerFormatEnum;
+
+pub(super) const EBML_MAGIC: [u8; 4] = [0x1A, 0x45, 0xDF, 0xA3];
+
+const EBML_HEADER: u32 = 0x1A45DFA3;
+const DOC_TYPE: u32 = 0x4282;
+const SEGMENT: u32 = 0x18538067;
+const SEEK_HEAD: u32 = 0x114D9B74;
+const SEEK: u32 = 0x4DBB;
+const SEEK_ID: u32 = 0x53AB;
+const SEEK_POSITION: u32 = 0x53AC;
+const INFO: u32 = 0x1549A966;
+const TIMECODE_SCALE: u32 = 0x2AD7B1;
+const DURATION: u32 = 0x4489;
+const TRACKS: u32 = 0x1654AE6B;
+const TRACK_ENTRY: u32 = 0xAE;
+const TRACK_TYPE: u32 = 0x83;
+const CODEC_ID: u32 = 0x86;
+const VIDEO: u32 = 0xE0;
+const PIXEL_WIDTH: u32 = 0xB0;
+const PIXEL_HEIGHT: u32 = 0xBA;
+const CLUSTER: u32 = 0x1F43B675;
+
+const TRACK_TYPE_VIDEO: u64 = 1;
+const TRACK_TYPE_AUDIO: u64 = 2;
+const DEFAULT_TIMECODE_SCALE: u64 = 1_000_000;
+const MAX_SEGMENT_CHILDREN: usize = 10_000;
+
+struct ElementHeader {
+    id: u32,
+    header_len: u64,
+    /// `None` para elementos de tamanho desconhecido (streaming).
+    size: Option<u64>,
+}
+
+pub(super) async fn probe<S: ByteSource + ?Sized>(source: &S) -> Result<ProbeResult, ProbeError> {
+    let total = source.size();
+
+    let ebml = read_header(source, 0).await?;
+    if ebml.id != EBML_HEADER {
+        return Err(ProbeError::UnsupportedFormat);
+    }
+    let ebml_size = ebml
+        .size
+        .ok_or_else(|| ProbeError::Malformed("cabeçalho EBML sem tamanho".to_string()))?;
+    let ebml_payload = read_exact(source, ebml.header_len, ebml_size).await?;
+
+    let format = match elements(&ebml_payload)?
+        .into_iter()
+        .find(|(id, _)| *id == DOC_TYPE)
+        .map(|(_, value)| read_string(value))
+        .as_deref()
+    {
+        Some("webm") => ContainerFormatEnum::WEBM,
+        Some("matroska") => ContainerFormatEnum::MATROSKA,
+        _ => return Err(ProbeError::UnsupportedFormat),
+    };
+
+    let segment_offset = ebml.header_len + ebml_size;
+    let segment = read_header(source, segment_offset).await?;
+    if segment.id != SEGMENT {
+        return Err(ProbeError::Malformed(
+            "elemento 'Segment' ausente".to_string(),
+        ));
+    }
+    let segment_data = segment_offset + segment.header_len;
+    let segment_end = segment
+        .size
+        .map_or(total, |size| (segment_data + size).min(total));
+
+    let mut info: Option<Vec<u8>> = None;
+    let mut tracks: Option<Vec<u8>> = None;
+    let mut seek_targets: Vec<(u32, u64)> = Vec::new();
+    let mut offset = segment_data;
+
+    for _ in 0..MAX_SEGMENT_CHILDREN {
+        if offset >= segment_end || (info.is_some() && tracks.is_some()) {
+            break;
+        }
+
+        let element = read_header(source, offset).await?;
+        if element.id == CLUSTER {
+            break;
+        }
+        let Some(size) = element.size else {
+            break;
+        };
+        let payload_offset = offset + element.header_len;
+
+        match element.id {
+            INFO => info = Some(read_exact(source, payload_offset, size).await?),
+            TRACKS => tracks = Some(read_exact(source, payload_offset, size).await?),
+            SEEK_HEAD => {
+                let payload = read_exact(source, payload_offset, size).await?;
+                seek_targets = parse_seek_head(&payload)?;
+            }
+            _ => {}
+        }
+
+        offset = payload_offset + size;
+    }
+
+    // Info e Tracks podem estar depois dos clusters; o SeekHead aponta onde.
+    for (id, position) in seek_targets {
+        let missing = match id {
+            INFO => info.is_none(),
+            TRACKS => tracks.is_none(),
+            _ => false,
+        };
+        if !missing {
+            continue;
+        }
+
+        let Some(element_offset) = segment_data.checked_add(position) else {
+            continue;
+        };
+        let element = read_header(source, element_offset).await?;
+        let Some(size) = element.size.filter(|_| element.id == id) else {
+            continue;
+        };
+        let payload = read_exact(source, element_offset + element.header_len, size).await?;
+
+        if id == INFO {
+            info = Some(payload);
+        } else {
+            tracks = Some(payload);
+        }
+    }
+
+    let mut result = ProbeResult {
+        format,
+        duration_ms: None,
+        width: None,
+        height: None,
+        video_codec: None,
+        audio_codec: None,
+        faststart: None,
+        segment_index: None,
+    };
+
+    if let Some(info) = info {
+        result.duration_ms = parse_duration(&info)?;
+    }
+
+    if let Some(tracks) = tracks {
+        parse_tracks(&tracks, &mut result)?;
+    }
+
+    Ok(result)
+}
+
+async fn read_header<S: ByteSource + ?Sized>(
+    source: &S,
+    offset: u64,
+) -> Result<ElementHeader, ProbeError> {
+    let data = source.read_at(offset, 12).await?;
+    let (id, id_len) =
+        read_vint(&data, true).ok_or_else(|| ProbeError::Malformed("ID EBML inválido".into()))?;
+    let (size, size_len) = read_vint(&data[id_len..], false)
+        .ok_or_else(|| ProbeError::Malformed("tamanho EBML inválido".into()))?;
+
+    // tamanho com todos os bits de valor em 1 significa "desconhecido"
+    let unknown = size == (1u64 << (7 * size_len)) - 1;
+
+    Ok(ElementHeader {
+        id: id as u32,
+        header_len: (id_len + size_len) as u64,
+        size: (!unknown).then_some(size),
+    })
+}
+
+/// Lê um inteiro de tamanho variável EBML. IDs mantêm o bit marcador; tamanhos não.
+fn read_vint(data: &[u8], keep_marker: bool) -> Option<(u64, usize)> {
+    let first = *data.first()?;
+    let len = first.leading_zeros() as usize + 1;
+    let max_len = if keep_marker { 4 } else { 8 };
+    if len > max_len || data.len() < len {
+        return None;
+    }
+
+    let first = if keep_marker {
+        first as u64
+    } else {
+        first as u64 & (0xFF >> len)
+    };
+
+    let value = data[1..len]
+        .iter()
+        .fold(first, |acc, b| (acc << 8) | *b as u64);
+
+    Some((value, len))
+}
+
+/// Divide o conteúdo de um elemento mestre em pares (ID, conteúdo).
+fn elements(data: &[u8]) -> Result<Vec<(u32, &[u8])>, ProbeError> {
+    let mut children = Vec::new();
+    let mut offset = 0usize;
+
+    while offset < data.len() {
+        let rest = &data[offset..];
+        let (id, id_len) = read_vint(rest, true)
+            .ok_or_else(|| ProbeError::Malformed("ID EBML inválido".into()))?;
+        let (size, size_len) = read_vint(&rest[id_len..], false)
+            .ok_or_else(|| ProbeError::Malformed("tamanho EBML inválido".into()))?;
+
+        let start = id_len + size_len;
+        let end = start
+            .checked_add(size as usize)
+            .filter(|end| *end <= rest.len())
+            .ok_or_else(|| ProbeError::Malformed("elemento EBML truncado".into()))?;
+
+        children.push((id as u32, &rest[start..end]));
+        offset += end;
+    }
+
+    Ok(children)
+}
+
+fn read_string(data: &[u8]) -> String {
+    String::from_utf8_lossy(data)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn read_float(data: &[u8]) -> Option<f64> {
+    match data.len() {
+        4 => Some(f32::from_be_bytes(data.try_into().ok()?) as f64),
+        8 => Some(f64::from_be_bytes(data.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+fn parse_seek_head(data: &[u8]) -> Result<Vec<(u32, u64)>, ProbeError> {
+    let mut targets = Vec::new();
+
+    for (id, seek) in elements(data)? {
+        if id != SEEK {
+            continue;
+        }
+
+        let mut target_id = None;
+        let mut position = None;
+        for (child_id, value) in elements(seek)? {
+            match child_id {
+                SEEK_ID => target_id = Some(read_uint(value) as u32),
+                SEEK_POSITION => position = Some(read_uint(value)),
+                _ => {}
+            }
+        }
+
+        if let (Some(target_id), Some(position)) = (target_id, position) {
+            targets.push((target_id, position));
+        }
+    }
+
+    Ok(targets)
+}
+
+fn parse_duration(info: &[u8]) -> Result<Option<i64>, ProbeError> {
+    let mut timecode_scale = DEFAULT_TIMECODE_SCALE;
+    let mut duration = None;
+
+    for (id, value) in elements(info)? {
+        match id {
+            TIMECODE_SCALE => timecode_scale = read_uint(value),
+            DURATION => duration = read_float(value),
+            _ => {}
+        }
+    }
+
+    // Duration vem em unidades de TimecodeScale (nanossegundos por tick)
+    Ok(duration
+        .filter(|d| d.is_finite() && *d > 0.0)
+        .map(|d| (d * timecode_scale as f64 / 1_000_000.0) as i64))
+}
+
+fn parse_tracks(tracks: &[u8], result: &mut ProbeResult) -> Result<(), ProbeError> {
+    for (id, entry) in elements(tracks)? {
+        if id != TRACK_ENTRY {
+            continue;
+        }
+
+        let mut track_type = None;
+        let mut codec_id = None;
+        let mut dimensions = (None, None);
+
+        for (child_id, value) in elements(entry)? {
+            match child_id {
+                TRACK_TYPE => track_type = Some(read_uint(value)),
+                CODEC_ID => codec_id = Some(read_string(value)),
+                VIDEO => {
+                    for (video_id, video_value) in elements(value)? {
+                        match video_id {
+                            PIXEL_WIDTH => dimensions.0 = Some(read_uint(video_value) as i32),
+                            PIXEL_HEIGHT => dimensions.1 = Some(read_uint(video_value) as i32),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match track_type {
+            Some(TRACK_TYPE_VIDEO) if result.video_codec.is_none() => {
+                result.video_codec = codec_id.as_deref().map(codec_name);
+                result.width = dimensions.0;
+                result.height = dimensions.1;
+            }
+            Some(TRACK_TYPE_AUDIO) if result.audio_codec.is_none() => {
+                result.audio_codec = codec_id.as_deref().map(codec_name);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn codec_name(codec_id: &str) -> String {
+    match codec_id {
+        "V_MPEG4/ISO/AVC" => "h264".to_string(),
+        "V_MPEGH/ISO/HEVC" => "hevc".to_string(),
+        "V_AV1" => "av1".to_string(),
+        "V_VP9" => "vp9".to_string(),
+        "V_VP8" => "vp8".to_string(),
+        "A_OPUS" => "opus".to_string(),
+        "A_VORBIS" => "vorbis".to_string(),
+        "A_AC3" => "ac3".to_string(),
+        "A_EAC3" => "eac3".to_string(),
+        "A_FLAC" => "flac".to_string(),
+        aac if aac.starts_with("A_AAC") => "aac".to_string(),
+        other => other.to_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use futures::executor::block_on;
+
+    use super::*;
+
+    struct Bytes(Vec<u8>);
+
+    #[async_trait]
+    impl ByteSource for Bytes {
+        fn size(&self) -> u64 {
+            self.0.len() as u64
+        }
+
+        async fn read_at(&self, offset: u64, len: u64) -> anyhow::Result<Vec<u8>> {
+            let start = (offset as usize).min(self.0.len());
+            let end = start.saturating_add(len as usize).min(self.0.len());
+            Ok(self.0[start..end].to_vec())
+        }
+    }
+
+    /// Elemento com o ID sem zeros à esquerda e tamanho em vint de 8 bytes,
+    /// para que o tamanho do cabeçalho não dependa do conteúdo.
+    fn element(id: u32, payload: &[u8]) -> Vec<u8> {
+        let id = id.to_be_bytes();
+        let mut data = id[id.iter().position(|b| *b != 0).unwrap_or(3)..].to_vec();
+        let mut size = (payload.len() as u64).to_be_bytes();
+        size[0] = 0x01;
+        data.extend_from_slice(&size);
+        data.extend_from_slice(payload);
+        data
+    }
+
+    fn header(doc_type: &str) -> Vec<u8> {
+        element(EBML_HEADER, &element(DOC_TYPE, doc_type.as_bytes()))
+    }
+
+    fn segment(children: &[Vec<u8>]) -> Vec<u8> {
+        element(SEGMENT, &children.concat())
+    }
+
+    fn info(duration: f64) -> Vec<u8> {
+        element(
+            INFO,
+            &[
+                element(TIMECODE_SCALE, &[0x0F, 0x42, 0x40]),
+                element(DURATION, &duration.to_be_bytes()),
+            ]
+            .concat(),
+        )
+    }
+
+    fn tracks() -> Vec<u8> {
+        let video = element(
+            TRACK_ENTRY,
+            &[
+                element(TRACK_TYPE, &[TRACK_TYPE_VIDEO as u8]),
+                element(CODEC_ID, b"V_VP9"),
+                element(
+                    VIDEO,
+                    &[
+                        element(PIXEL_WIDTH, &1920u16.to_be_bytes()),
+                        element(PIXEL_HEIGHT, &1080u16.to_be_bytes()),
+                    ]
+                    .concat(),
+                ),
+            ]
+            .concat(),
+        );
+        let audio = element(
+            TRACK_ENTRY,
+            &[
+                element(TRACK_TYPE, &[TRACK_TYPE_AUDIO as u8]),
+                element(CODEC_ID, b"A_OPUS"),
+            ]
+            .concat(),
+        );
+        element(TRACKS, &[video, audio].concat())
+    }
+
+    fn seek(id: u32, position: u64) -> Vec<u8> {
+        element(
+            SEEK,
+            &[
+                element(SEEK_ID, &id.to_be_bytes()),
+                element(SEEK_POSITION, &position.to_be_bytes()),
+            ]
+            .concat(),
+        )
+    }
+
+    fn webm() -> Vec<u8> {
+        [
+            header("webm"),
+            segment(&[info(12_345.0), tracks(), element(CLUSTER, &[0; 16])]),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn read_vint_keeps_the_marker_only_for_ids() {
+        assert_eq!(read_vint(&[0x81], false), Some((1, 1)));
+        assert_eq!(read_vint(&[0x40, 0x02], false), Some((2, 2)));
+        assert_eq!(read_vint(&EBML_MAGIC, true), Some((EBML_HEADER as u64, 4)));
+        assert_eq!(read_vint(&[0x00], false), None);
+        assert_eq!(read_vint(&[0x08, 0, 0, 0, 0], true), None);
+        assert_eq!(read_vint(&[0x40], false), None);
+    }
+
+    #[test]
+    fn truncated_child_is_malformed() {
+        let mut data = element(DOC_TYPE, b"webm");
+        data.pop();
+        assert!(matches!(elements(&data), Err(ProbeError::Malformed(_))));
+    }
+
+    #[test]
+    fn reads_duration_and_tracks_of_webm() {
+        let result = block_on(probe(&Bytes(webm()))).unwrap();
+
+        assert_eq!(result.format, ContainerFormatEnum::WEBM);
+        assert_eq!(result.duration_ms, Some(12_345));
+        assert_eq!(result.video_codec.as_deref(), Some("vp9"));
+        assert_eq!(result.audio_codec.as_deref(), Some("opus"));
+        assert_eq!((result.width, result.height), (Some(1920), Some(1080)));
+    }
+
+    #[test]
+    fn doc_type_decides_the_format() {
+        let data = [header("matroska"), segment(&[info(1000.0), tracks()])].concat();
+        assert_eq!(
+            block_on(probe(&Bytes(data))).unwrap().format,
+            ContainerFormatEnum::MATROSKA
+        );
+
+        let data = [header("mkv3d"), segment(&[])].concat();
+        assert!(matches!(
+            block_on(probe(&Bytes(data))),
+            Err(ProbeError::UnsupportedFormat)
+        ));
+    }
+
+    #[test]
+    fn segment_of_unknown_size_runs_to_the_end_of_file() {
+        let children = [info(2000.0), tracks()].concat();
+        let data = [
+            header("webm"),
+            vec![
+                0x18, 0x53, 0x80, 0x67, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+            ],
+            children,
+        ]
+        .concat();
+
+        let result = block_on(probe(&Bytes(data))).unwrap();
+        assert_eq!(result.duration_ms, Some(2000));
+        assert_eq!(result.video_codec.as_deref(), Some("vp9"));
+    }
+
+    #[test]
+    fn seek_head_finds_info_and_tracks_after_the_clusters() {
+        let cluster = element(CLUSTER, &[0; 16]);
+        let seek_head_len = element(SEEK_HEAD, &[seek(INFO, 0), seek(TRACKS, 0)].concat()).len();
+        let info_position = (seek_head_len + cluster.len()) as u64;
+        let tracks_position = info_position + info(1.0).len() as u64;
+        let seek_head = element(
+            SEEK_HEAD,
+            &[seek(INFO, info_position), seek(TRACKS, tracks_position)].concat(),
+        );
+
+        let data = [
+            header("webm"),
+            segment(&[seek_head, cluster, info(4500.0), tracks()]),
+        ]
+        .concat();
+
+        let result = block_on(probe(&Bytes(data))).unwrap();
+        assert_eq!(result.duration_ms, Some(4500));
+        assert_eq!(result.audio_codec.as_deref(), Some("opus"));
+    }
+
+    #[test]
+    fn huge_seek_position_is_ignored() {
+        let seek_head = element(SEEK_HEAD, &seek(INFO, u64::MAX));
+        let data = [
+            header("webm"),
+            segment(&[seek_head, tracks(), element(CLUSTER, &[0; 4])]),
+        ]
+        .concat();
+
+        let result = block_on(probe(&Bytes(data))).unwrap();
+        assert_eq!(result.duration_ms, None);
+        assert_eq!(result.video_codec.as_deref(), Some("vp9"));
+    }
+
+    #[test]
+    fn corrupted_bytes_never_panic() {
+        let original = webm();
+        for position in 0..original.len() {
+            for value in [0x00, 0x01, 0x7F, 0xFF] {
+                let mut data = original.clone();
+                data[position] = value;
+                let _ = block_on(probe(&Bytes(data)));
+            }
+        }
+
+        for position in (0..original.len()).step_by(4) {
+            let mut data = original.clone();
+            let end = (position + 8).min(data.len());
+            data[position..end].fill(0xFF);
+            let _ = block_on(probe(&Bytes(data)));
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Leitura de metadados de containers de vídeo (MP4 e Matroska/WebM) sem
+//! dependências externas. O prober lê apenas os trechos necessários do
+//! arquivo, então funciona direto sobre blobs via leituras por intervalo.
+
+pub mod matroska;
+pub mod mp4;
+
+use std::fmt;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::container_format_enum::ContainerFormatEnum;
+
+/// Bytes lidos do início do arquivo para identificar o container.
+pub const MAGIC_BYTES_LEN: usize = 12;
+
+/// Limite para estruturas de metadados carregadas inteiras em memória.
+const MAX_METADATA_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Marcas de `ftyp` que indicam imagens (HEIF/AVIF), não vídeos.
+const IMAGE_BRANDS: [&[u8; 4]; 4] = [b"heic", b"heix", b"mif1", b"avif"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProbeResult {
+    pub format: ContainerFormatEnum,
+    pub duration_ms: Option<i64>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    /// Apenas MP4: `moov` antes de `mdat`, permitindo reprodução antes do download completo.
+    pub faststart: Option<bool>,
+    /// Apenas MP4 fragmentado com `sidx`: permite servir o arquivo em segmentos HLS/DASH.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segment_index: Option<SegmentIndex>,
+}
+
+/// Índice de segmentos de um MP4 fragmentado. O segmento de inicialização
+/// (`ftyp` + `moov`) ocupa os bytes `0..init_size`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentIndex {
+    pub init_size: u64,
+    pub timescale: u32,
+    pub earliest_presentation_time: u64,
+    pub segments: Vec<MediaSegment>,
+    /// Codecs das faixas no formato RFC 6381 (`avc1.64001f,mp4a.40.2`), para
+    /// o `CODECS` do HLS e o `codecs` do DASH. Ausente em índices antigos ou
+    /// quando alguma faixa tem codec não reconhecido.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub codecs: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaSegment {
+    pub offset: u64,
+    pub size: u64,
+    /// Em unidades de `timescale`.
+    pub duration: u64,
+}
+
+impl SegmentIndex {
+    pub fn duration_ms(&self) -> i64 {
+        let total: u64 = self.segments.iter().map(|s| s.duration).sum();
+        (total as u128 * 1000 / self.timescale.max(1) as u128) as i64
+    }
+
+    pub fn segment_seconds(&self, segment: &MediaSegment) -> f64 {
+        segment.duration as f64 / self.timescale.max(1) as f64
+    }
+}
+
+impl ProbeResult {
+    pub fn duration_seconds(&self) -> Option<i32> {
+        self.duration_ms
+            .map(|ms| ((ms + 500) / 1000).clamp(0, i32::MAX as i64) as i32)
+    }
+}
+
+#[derive(Debug)]
+pub enum ProbeError {
+    UnsupportedFormat,
+    NoVideoTrack,
+    Malformed(String),
+    Io(anyhow::Error),
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProbeError::UnsupportedFormat => {
+                write!(f, "O arquivo não é um vídeo MP4, Matroska ou WebM")
+            }
+            ProbeError::NoVideoTrack => write!(f, "O arquivo não contém faixa de vídeo"),
+            ProbeError::Malformed(msg) => write!(f, "Container de vídeo inválido: {}", msg),
+            ProbeError::Io(e) => write!(f, "Erro ao ler o arquivo: {}", e),
+        }
+    }
+}
+
+impl From<anyhow::Error> for ProbeError {
+    fn from(e: anyhow::Error) -> Self {
+        ProbeError::Io(e)
+    }
+}
+
+/// Origem com acesso aleatório aos bytes do arquivo sendo inspecionado.
+#[async_trait]
+pub trait ByteSource: Send + Sync {
+    fn size(&self) -> u64;
+
+    /// Lê até `len` bytes a partir de `offset`; pode devolver menos no fim do arquivo.
+    async fn read_at(&self, offset: u64, len: u64) -> anyhow::Result<Vec<u8>>;
+}
+
+/// Identifica o container pelos primeiros bytes do arquivo.
+///
+/// Matroska e WebM compartilham a assinatura EBML; a distinção entre os dois
+/// só é feita em [`probe`], ao ler o `DocType`.
+pub fn detect_format(header: &[u8]) -> Option<ContainerFormatEnum> {
+    if header.len() >= 12 && &header[4..8] == b"ftyp" {
+        let brand = &header[8..12];
+        if IMAGE_BRANDS.iter().any(|b| b.as_slice() == brand) {
+            return None;
+        }
+        return Some(ContainerFormatEnum::MP4);
+    }
+
+    if header.len() >= 4 && header[..4] == matroska::EBML_MAGIC {
+        return Some(ContainerFormatEnum::MATROSKA);
+    }
+
+    None
+}
+
+pub async fn probe<S: ByteSource + ?Sized>(source: &S) -> Result<ProbeResult, ProbeError> {
+    let header = source.read_at(0, MAGIC_BYTES_LEN as u64).await?;
+
+    let result = match detect_format(&header) {
+        Some(ContainerFormatEnum::MP4) => mp4::probe(source).await?,
+        Some(_) => matroska::probe(source).await?,
+        None => return Err(ProbeError::UnsupportedFormat),
+    };
+
+    if result.video_codec.is_none() {
+        return Err(ProbeError::NoVideoTrack);
+    }
+
+    Ok(result)
+}
+
+async fn read_exact<S: ByteSource + ?Sized>(
+    source: &S,
+    offset: u64,
+    len: u64,
+) -> Result<Vec<u8>, ProbeError> {
+    if len > MAX_METADATA_SIZE {
+        return Err(ProbeError::Malformed(format!(
+            "estrutura de metadados com {} bytes excede o limite",
+            len
+        )));
+    }
+
+    let data = source.read_at(offset, len).await?;
+    if (data.len() as u64) < len {
+        return Err(ProbeError::Malformed("arquivo truncado".to_string()));
+    }
+
+    Ok(data)
+}
+
+fn read_uint(data: &[u8]) -> u64 {
+    data.iter().fold(0, |acc, b| (acc << 8) | *b as u64)
+}
@@ -0,0 +1,668 @@
+//! MP4/ISO BMFF: percorre os boxes de topo por leituras pontuais e carrega
+//! apenas o `moov`, de onde saem duração (`mvhd`) e faixas (`trak`), e o
+//! `sidx` de arquivos fragmentados, que indexa os segmentos.
+
+use super::{
+    read_exact, read_uint, ByteSource, MediaSegment, ProbeError, ProbeResult, SegmentIndex,
+};
+use crate::enums::container_format_enum::ContainerFormatEnum;
+
+const MAX_TOP_LEVEL_BOXES: usize = 10_000;
+
+/// Tipo (fourcc) e conteúdo de um box.
+type Mp4Box<'a> = (&'a [u8; 4], &'a [u8]);
+
+pub(super) async fn probe<S: ByteSource + ?Sized>(source: &S) -> Result<ProbeResult, ProbeError> {
+    let total = source.size();
+    let mut offset = 0u64;
+    let mut moov: Option<(u64, u64)> = None;
+    let mut mdat_offset: Option<u64> = None;
+    let mut sidx: Option<(u64, u64)> = None;
+
+    for _ in 0..MAX_TOP_LEVEL_BOXES {
+        if total - offset < 8 {
+            break;
+        }
+
+        let header = read_exact(source, offset, 16.min(total - offset)).await?;
+        let (header_len, box_size) = match read_uint(&header[0..4]) {
+            0 => (8, total - offset),
+            1 if header.len() >= 16 => (16, read_uint(&header[8..16])),
+            1 => {
+                return Err(ProbeError::Malformed(
+                    "box com tamanho estendido truncado".into(),
+                ))
+            }
+            size => (8, size),
+        };
+
+        if box_size < header_len || offset.checked_add(box_size).is_none_or(|end| end > total) {
+            return Err(ProbeError::Malformed(format!(
+                "box '{}' com tamanho inválido",
+                String::from_utf8_lossy(&header[4..8])
+            )));
+        }
+
+        match &header[4..8] {
+            b"moov" => moov = Some((offset + header_len, box_size - header_len)),
+            b"mdat" if mdat_offset.is_none() => mdat_offset = Some(offset),
+            b"sidx" if sidx.is_none() => sidx = Some((offset + header_len, box_size - header_len)),
+            _ => {}
+        }
+
+        if moov.is_some() && mdat_offset.is_some() {
+            break;
+        }
+
+        offset += box_size;
+    }
+
+    let (moov_offset, moov_size) =
+        moov.ok_or_else(|| ProbeError::Malformed("box 'moov' ausente".to_string()))?;
+    let moov_data = read_exact(source, moov_offset, moov_size).await?;
+
+    let mut result = ProbeResult {
+        format: ContainerFormatEnum::MP4,
+        duration_ms: None,
+        width: None,
+        height: None,
+        video_codec: None,
+        audio_codec: None,
+        faststart: Some(mdat_offset.is_none_or(|mdat| moov_offset < mdat)),
+        segment_index: None,
+    };
+
+    // `None` marca uma faixa cujo codec não dá para descrever no RFC 6381
+    let mut codecs: Vec<Option<String>> = Vec::new();
+    for (kind, payload) in boxes(&moov_data)? {
+        match kind {
+            b"mvhd" => result.duration_ms = parse_mvhd(payload)?,
+            b"trak" => parse_trak(payload, &mut result, &mut codecs)?,
+            _ => {}
+        }
+    }
+
+    // O segmento de inicialização termina no fim do `moov`, que precisa vir antes do índice.
+    let init_size = moov_offset + moov_size;
+    if let Some((sidx_offset, sidx_size)) = sidx.filter(|(offset, _)| *offset > init_size) {
+        let payload = read_exact(source, sidx_offset, sidx_size).await?;
+        result.segment_index = parse_sidx(&payload, init_size, sidx_offset + sidx_size, total)?;
+        if let Some(index) = result.segment_index.as_mut() {
+            index.codecs = codecs
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .filter(|codecs| !codecs.is_empty())
+                .map(|codecs| codecs.join(","));
+        }
+    }
+
+    // Com `empty_moov` o `mvhd` não traz duração; o índice de segmentos traz.
+    if result.duration_ms.is_none() {
+        result.duration_ms = result.segment_index.as_ref().map(|i| i.duration_ms());
+    }
+
+    Ok(result)
+}
+
+/// Lê um `sidx` de nível único. Índices hierárquicos (referências a outros
+/// `sidx`) não são suportados e resultam em `None`.
+fn parse_sidx(
+    payload: &[u8],
+    init_size: u64,
+    sidx_end: u64,
+    total: u64,
+) -> Result<Option<SegmentIndex>, ProbeError> {
+    let truncated = || ProbeError::Malformed("box 'sidx' truncado".to_string());
+
+    let timescale = read_uint(payload.get(8..12).ok_or_else(truncated)?) as u32;
+    // versão 0 usa 32 bits para o tempo inicial e o offset, versão 1 usa 64
+    let (earliest_presentation_time, first_offset, mut cursor) = match payload.first() {
+        Some(0) => (
+            read_uint(payload.get(12..16).ok_or_else(truncated)?),
+            read_uint(payload.get(16..20).ok_or_else(truncated)?),
+            20,
+        ),
+        Some(_) => (
+            read_uint(payload.get(12..20).ok_or_else(truncated)?),
+            read_uint(payload.get(20..28).ok_or_else(truncated)?),
+            28,
+        ),
+        None => return Err(truncated()),
+    };
+
+    // reservado (2) + quantidade de referências (2)
+    let count = read_uint(payload.get(cursor + 2..cursor + 4).ok_or_else(truncated)?);
+    cursor += 4;
+
+    if timescale == 0 || count == 0 {
+        return Ok(None);
+    }
+
+    let mut offset = sidx_end
+        .checked_add(first_offset)
+        .ok_or_else(|| ProbeError::Malformed("offset do 'sidx' inválido".into()))?;
+    let mut segments = Vec::with_capacity(count as usize);
+
+    for _ in 0..count {
+        let reference = payload.get(cursor..cursor + 12).ok_or_else(truncated)?;
+        cursor += 12;
+
+        let size_field = read_uint(&reference[0..4]);
+        if size_field & 0x8000_0000 != 0 {
+            return Ok(None);
+        }
+
+        let size = size_field & 0x7FFF_FFFF;
+        if offset.checked_add(size).is_none_or(|end| end > total) {
+            return Err(ProbeError::Malformed(
+                "segmento do 'sidx' além do fim do arquivo".into(),
+            ));
+        }
+
+        segments.push(MediaSegment {
+            offset,
+            size,
+            duration: read_uint(&reference[4..8]),
+        });
+        offset += size;
+    }
+
+    Ok(Some(SegmentIndex {
+        init_size,
+        timescale,
+        earliest_presentation_time,
+        segments,
+        codecs: None,
+    }))
+}
+
+/// Divide um buffer em boxes filhos, devolvendo tipo e conteúdo de cada um.
+fn boxes(data: &[u8]) -> Result<Vec<Mp4Box<'_>>, ProbeError> {
+    let mut children = Vec::new();
+    let mut offset = 0usize;
+
+    while offset + 8 <= data.len() {
+        let (header_len, size) = match read_uint(&data[offset..offset + 4]) {
+            0 => (8, data.len() - offset),
+            1 if offset + 16 <= data.len() => {
+                (16, read_uint(&data[offset + 8..offset + 16]) as usize)
+            }
+            1 => {
+                return Err(ProbeError::Malformed(
+                    "box com tamanho estendido truncado".into(),
+                ))
+            }
+            size => (8, size as usize),
+        };
+
+        if size < header_len || offset.checked_add(size).is_none_or(|end| end > data.len()) {
+            return Err(ProbeError::Malformed(
+                "box filho com tamanho inválido".into(),
+            ));
+        }
+
+        let kind: &[u8; 4] = data[offset + 4..offset + 8].try_into().unwrap_or(&[0; 4]);
+        children.push((kind, &data[offset + header_len..offset + size]));
+        offset += size;
+    }
+
+    Ok(children)
+}
+
+fn child<'a>(data: &'a [u8], kind: &[u8; 4]) -> Result<Option<&'a [u8]>, ProbeError> {
+    Ok(boxes(data)?
+        .into_iter()
+        .find(|(k, _)| *k == kind)
+        .map(|(_, payload)| payload))
+}
+
+fn parse_mvhd(payload: &[u8]) -> Result<Option<i64>, ProbeError> {
+    let truncated = || ProbeError::Malformed("box 'mvhd' truncado".to_string());
+
+    // versão 1 usa campos de 64 bits para datas e duração
+    let (timescale, duration) = match payload.first() {
+        Some(1) => (
+            read_uint(payload.get(20..24).ok_or_else(truncated)?),
+            read_uint(payload.get(24..32).ok_or_else(truncated)?),
+        ),
+        Some(_) => {
+            let duration = read_uint(payload.get(16..20).ok_or_else(truncated)?);
+            // 0xFFFFFFFF indica duração desconhecida
+            let duration = if duration == u32::MAX as u64 {
+                0
+            } else {
+                duration
+            };
+            (
+                read_uint(payload.get(12..16).ok_or_else(truncated)?),
+                duration,
+            )
+        }
+        None => return Err(truncated()),
+    };
+
+    if timescale == 0 || duration == 0 || duration == u64::MAX {
+        return Ok(None);
+    }
+
+    Ok(Some((duration as u128 * 1000 / timescale as u128) as i64))
+}
+
+fn parse_trak(
+    trak: &[u8],
+    result: &mut ProbeResult,
+    codecs: &mut Vec<Option<String>>,
+) -> Result<(), ProbeError> {
+    let Some(mdia) = child(trak, b"mdia")? else {
+        return Ok(());
+    };
+
+    let handler = child(mdia, b"hdlr")?
+        .and_then(|hdlr| hdlr.get(8..12))
+        .unwrap_or_default();
+
+    let stsd = child(mdia, b"minf")?
+        .map(|minf| child(minf, b"stbl"))
+        .transpose()?
+        .flatten()
+        .map(|stbl| child(stbl, b"stsd"))
+        .transpose()?
+        .flatten();
+
+    // stsd: versão/flags (4) + quantidade (4), seguido da primeira sample entry
+    let Some(entry) = stsd.and_then(|stsd| stsd.get(8..)) else {
+        return Ok(());
+    };
+    let Some(format) = entry.get(4..8) else {
+        return Ok(());
+    };
+
+    match handler {
+        b"vide" => codecs.push(video_codec_string(format, entry)),
+        b"soun" => codecs.push(audio_codec_string(format, entry)),
+        _ => {}
+    }
+
+    match handler {
+        b"vide" if result.video_codec.is_none() => {
+            result.video_codec = Some(codec_name(format));
+            // visual sample entry: largura e altura logo após 24 bytes de campos reservados
+            if let (Some(w), Some(h)) = (entry.get(32..34), entry.get(34..36)) {
+                result.width = Some(read_uint(w) as i32);
+                result.height = Some(read_uint(h) as i32);
+            }
+        }
+        b"soun" if result.audio_codec.is_none() => {
+            result.audio_codec = Some(codec_name(format));
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Bytes do cabeçalho da visual sample entry antes dos boxes filhos.
+const VISUAL_SAMPLE_ENTRY_LEN: usize = 86;
+/// Bytes do cabeçalho da audio sample entry (versão 0) antes dos boxes filhos.
+const AUDIO_SAMPLE_ENTRY_LEN: usize = 36;
+
+/// `avc1.PPCCLL`, com perfil, compatibilidade e nível do `avcC`.
+fn video_codec_string(format: &[u8], entry: &[u8]) -> Option<String> {
+    if format != b"avc1" && format != b"avc3" {
+        return None;
+    }
+
+    let avcc = child(entry.get(VISUAL_SAMPLE_ENTRY_LEN..)?, b"avcC").ok()??;
+    let profile = avcc.get(1..4)?;
+    Some(format!(
+        "{}.{:02x}{:02x}{:02x}",
+        String::from_utf8_lossy(format),
+        profile[0],
+        profile[1],
+        profile[2]
+    ))
+}
+
+/// `mp4a.OO.A`, com o tipo de objeto do `DecoderConfigDescriptor` e, para
+/// AAC, o audio object type do `DecoderSpecificInfo` do `esds`.
+fn audio_codec_string(format: &[u8], entry: &[u8]) -> Option<String> {
+    if format != b"mp4a" {
+        return None;
+    }
+
+    let esds = child(entry.get(AUDIO_SAMPLE_ENTRY_LEN..)?, b"esds").ok()??;
+    // versão/flags (4), depois o ES_Descriptor
+    let (tag, es) = descriptor(esds.get(4..)?)?;
+    if tag != 0x03 {
+        return None;
+    }
+
+    // ES_ID (2) + flags (1), seguidos dos campos opcionais que as flags ligam
+    let flags = *es.get(2)?;
+    let mut cursor = 3;
+    if flags & 0x80 != 0 {
+        cursor += 2;
+    }
+    if flags & 0x40 != 0 {
+        cursor += 1 + *es.get(cursor)? as usize;
+    }
+    if flags & 0x20 != 0 {
+        cursor += 2;
+    }
+
+    let (tag, config) = descriptor(es.get(cursor..)?)?;
+    if tag != 0x04 {
+        return None;
+    }
+    let object_type = *config.first()?;
+    if object_type != 0x40 {
+        return Some(format!("mp4a.{:02x}", object_type));
+    }
+
+    // tipo (1) + stream type (1) + buffer (3) + bitrates (8)
+    let (tag, specific) = descriptor(config.get(13..)?)?;
+    let audio_object_type = match tag {
+        0x05 => specific.first()? >> 3,
+        _ => return None,
+    };
+    Some(format!("mp4a.40.{}", audio_object_type))
+}
+
+/// Lê um descritor MPEG-4 (tag + tamanho em até 4 bytes de 7 bits).
+fn descriptor(data: &[u8]) -> Option<(u8, &[u8])> {
+    let tag = *data.first()?;
+    let mut size = 0usize;
+    let mut cursor = 1;
+    loop {
+        let byte = *data.get(cursor)?;
+        cursor += 1;
+        size = (size << 7) | (byte & 0x7F) as usize;
+        if byte & 0x80 == 0 || cursor > 4 {
+            break;
+        }
+    }
+    Some((tag, data.get(cursor..cursor.checked_add(size)?)?))
+}
+
+fn codec_name(fourcc: &[u8]) -> String {
+    match fourcc {
+        b"avc1" | b"avc3" => "h264".to_string(),
+        b"hvc1" | b"hev1" => "hevc".to_string(),
+        b"av01" => "av1".to_string(),
+        b"vp09" => "vp9".to_string(),
+        b"mp4v" => "mpeg4".to_string(),
+        b"mp4a" => "aac".to_string(),
+        b"ac-3" => "ac3".to_string(),
+        b"ec-3" => "eac3".to_string(),
+        b"Opus" => "opus".to_string(),
+        b"fLaC" => "flac".to_string(),
+        other => String::from_utf8_lossy(other).trim().to_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use futures::executor::block_on;
+
+    use super::*;
+
+    struct Bytes(Vec<u8>);
+
+    #[async_trait]
+    impl ByteSource for Bytes {
+        fn size(&self) -> u64 {
+            self.0.len() as u64
+        }
+
+        async fn read_at(&self, offset: u64, len: u64) -> anyhow::Result<Vec<u8>> {
+            let start = (offset as usize).min(self.0.len());
+            let end = start.saturating_add(len as usize).min(self.0.len());
+            Ok(self.0[start..end].to_vec())
+        }
+    }
+
+    fn mp4_box(kind: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut data = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(payload);
+        data
+    }
+
+    /// Box com tamanho estendido de 64 bits.
+    fn large_box(kind: &[u8; 4], size: u64) -> Vec<u8> {
+        let mut data = 1u32.to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(&size.to_be_bytes());
+        data
+    }
+
+    fn ftyp() -> Vec<u8> {
+        mp4_box(b"ftyp", b"isom\0\0\0\0isomavc1")
+    }
+
+    fn mvhd(timescale: u32, duration: u32) -> Vec<u8> {
+        let mut payload = vec![0; 100];
+        payload[12..16].copy_from_slice(&timescale.to_be_bytes());
+        payload[16..20].copy_from_slice(&duration.to_be_bytes());
+        mp4_box(b"mvhd", &payload)
+    }
+
+    fn trak(handler: &[u8; 4], format: &[u8; 4], width: u16, height: u16) -> Vec<u8> {
+        let mut entry = vec![0; VISUAL_SAMPLE_ENTRY_LEN];
+        entry[4..8].copy_from_slice(format);
+        entry[32..34].copy_from_slice(&width.to_be_bytes());
+        entry[34..36].copy_from_slice(&height.to_be_bytes());
+        trak_with_entry(handler, entry)
+    }
+
+    /// Faixa cuja sample entry tem cabeçalho de `header_len` bytes e os boxes
+    /// `children` (como `avcC` ou `esds`).
+    fn codec_trak(
+        handler: &[u8; 4],
+        format: &[u8; 4],
+        header_len: usize,
+        children: &[u8],
+    ) -> Vec<u8> {
+        let mut entry = vec![0; header_len];
+        entry[4..8].copy_from_slice(format);
+        entry.extend_from_slice(children);
+        trak_with_entry(handler, entry)
+    }
+
+    fn trak_with_entry(handler: &[u8; 4], entry: Vec<u8>) -> Vec<u8> {
+        let mut hdlr = vec![0; 24];
+        hdlr[8..12].copy_from_slice(handler);
+
+        let mut stsd = vec![0, 0, 0, 0, 0, 0, 0, 1];
+        stsd.extend_from_slice(&entry);
+
+        let stbl = mp4_box(b"stbl", &mp4_box(b"stsd", &stsd));
+        let minf = mp4_box(b"minf", &stbl);
+        let mdia = mp4_box(b"mdia", &[mp4_box(b"hdlr", &hdlr), minf].concat());
+        mp4_box(b"trak", &mdia)
+    }
+
+    fn movie() -> Vec<u8> {
+        let moov = [
+            mvhd(1000, 90_500),
+            trak(b"vide", b"avc1", 1920, 1080),
+            trak(b"soun", b"mp4a", 0, 0),
+        ]
+        .concat();
+        [ftyp(), mp4_box(b"moov", &moov), mp4_box(b"mdat", &[0; 32])].concat()
+    }
+
+    fn sidx_payload(first_offset: u64, references: &[(u32, u32)]) -> Vec<u8> {
+        let mut payload = vec![1, 0, 0, 0];
+        payload.extend_from_slice(&1u32.to_be_bytes());
+        payload.extend_from_slice(&1000u32.to_be_bytes());
+        payload.extend_from_slice(&0u64.to_be_bytes());
+        payload.extend_from_slice(&first_offset.to_be_bytes());
+        payload.extend_from_slice(&[0, 0]);
+        payload.extend_from_slice(&(references.len() as u16).to_be_bytes());
+        for (size, duration) in references {
+            payload.extend_from_slice(&size.to_be_bytes());
+            payload.extend_from_slice(&duration.to_be_bytes());
+            payload.extend_from_slice(&[0x90, 0, 0, 0]);
+        }
+        payload
+    }
+
+    #[test]
+    fn reads_duration_and_tracks_from_moov() {
+        let result = block_on(probe(&Bytes(movie()))).unwrap();
+
+        assert_eq!(result.duration_ms, Some(90_500));
+        assert_eq!(result.video_codec.as_deref(), Some("h264"));
+        assert_eq!(result.audio_codec.as_deref(), Some("aac"));
+        assert_eq!((result.width, result.height), (Some(1920), Some(1080)));
+        assert_eq!(result.faststart, Some(true));
+        assert!(result.segment_index.is_none());
+    }
+
+    #[test]
+    fn moov_after_mdat_is_not_faststart() {
+        let moov = [mvhd(1000, 1000), trak(b"vide", b"hvc1", 640, 360)].concat();
+        let data = [ftyp(), mp4_box(b"mdat", &[0; 8]), mp4_box(b"moov", &moov)].concat();
+
+        let result = block_on(probe(&Bytes(data))).unwrap();
+        assert_eq!(result.faststart, Some(false));
+        assert_eq!(result.video_codec.as_deref(), Some("hevc"));
+    }
+
+    #[test]
+    fn fragmented_file_uses_sidx_for_segments_and_duration() {
+        let moov = mp4_box(
+            b"moov",
+            &[mvhd(1000, 0), trak(b"vide", b"avc1", 1280, 720)].concat(),
+        );
+        let init = [ftyp(), moov].concat();
+        let sidx = mp4_box(b"sidx", &sidx_payload(0, &[(40, 2000), (24, 1500)]));
+        let data = [init.clone(), sidx, vec![0; 64]].concat();
+        let sidx_end = (data.len() - 64) as u64;
+
+        let index = block_on(probe(&Bytes(data)))
+            .unwrap()
+            .segment_index
+            .unwrap();
+
+        assert_eq!(index.init_size, init.len() as u64);
+        assert_eq!(index.timescale, 1000);
+        assert_eq!(index.segments.len(), 2);
+        assert_eq!(index.segments[0].offset, sidx_end);
+        assert_eq!(index.segments[1].offset, sidx_end + 40);
+        assert_eq!(index.duration_ms(), 3500);
+    }
+
+    /// `esds` de AAC-LC: ES_Descriptor > DecoderConfigDescriptor > DecoderSpecificInfo.
+    fn aac_lc_esds() -> Vec<u8> {
+        let specific = [0x05, 2, 0x12, 0x10];
+        let mut config = vec![0x04, (13 + specific.len()) as u8, 0x40, 0x15];
+        config.extend_from_slice(&[0; 11]);
+        config.extend_from_slice(&specific);
+        let mut es = vec![0x03, (3 + config.len()) as u8, 0, 1, 0];
+        es.extend_from_slice(&config);
+        mp4_box(b"esds", &[vec![0; 4], es].concat())
+    }
+
+    fn fragmented(tracks: &[Vec<u8>]) -> Vec<u8> {
+        let moov = mp4_box(
+            b"moov",
+            &[vec![mvhd(1000, 0)], tracks.to_vec()].concat().concat(),
+        );
+        let sidx = mp4_box(b"sidx", &sidx_payload(0, &[(8, 1000)]));
+        [ftyp(), moov, sidx, vec![0; 8]].concat()
+    }
+
+    #[test]
+    fn fragmented_file_reports_rfc6381_codecs() {
+        let data = fragmented(&[
+            codec_trak(
+                b"vide",
+                b"avc1",
+                VISUAL_SAMPLE_ENTRY_LEN,
+                &mp4_box(b"avcC", &[1, 0x64, 0x00, 0x1f, 0xff]),
+            ),
+            codec_trak(b"soun", b"mp4a", AUDIO_SAMPLE_ENTRY_LEN, &aac_lc_esds()),
+        ]);
+
+        let index = block_on(probe(&Bytes(data)))
+            .unwrap()
+            .segment_index
+            .unwrap();
+        assert_eq!(index.codecs.as_deref(), Some("avc1.64001f,mp4a.40.2"));
+    }
+
+    #[test]
+    fn unknown_track_codec_leaves_codecs_out() {
+        let data = fragmented(&[
+            trak(b"vide", b"hvc1", 1280, 720),
+            codec_trak(b"soun", b"mp4a", AUDIO_SAMPLE_ENTRY_LEN, &aac_lc_esds()),
+        ]);
+
+        let index = block_on(probe(&Bytes(data)))
+            .unwrap()
+            .segment_index
+            .unwrap();
+        assert_eq!(index.codecs, None);
+    }
+
+    #[test]
+    fn missing_moov_is_malformed() {
+        let data = [ftyp(), mp4_box(b"mdat", &[0; 8])].concat();
+        assert!(matches!(
+            block_on(probe(&Bytes(data))),
+            Err(ProbeError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn huge_top_level_box_size_is_rejected() {
+        let data = [ftyp(), large_box(b"free", u64::MAX), vec![0; 16]].concat();
+        assert!(matches!(
+            block_on(probe(&Bytes(data))),
+            Err(ProbeError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn huge_child_box_size_is_rejected() {
+        let data = [mp4_box(b"free", &[]), large_box(b"trak", u64::MAX)].concat();
+        assert!(matches!(boxes(&data), Err(ProbeError::Malformed(_))));
+    }
+
+    #[test]
+    fn huge_sidx_offsets_are_rejected() {
+        let payload = sidx_payload(u64::MAX, &[(16, 1000)]);
+        assert!(matches!(
+            parse_sidx(&payload, 0, 100, 1000),
+            Err(ProbeError::Malformed(_))
+        ));
+
+        let payload = sidx_payload(0, &[(16, 1000)]);
+        assert!(matches!(
+            parse_sidx(&payload, 0, u64::MAX - 8, u64::MAX),
+            Err(ProbeError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn corrupted_bytes_never_panic() {
+        let original = movie();
+        for position in 0..original.len() {
+            for value in [0x00, 0x01, 0x7F, 0xFF] {
+                let mut data = original.clone();
+                data[position] = value;
+                let _ = block_on(probe(&Bytes(data)));
+            }
+        }
+
+        for position in (0..original.len()).step_by(4) {
+            let mut data = original.clone();
+            let end = (position + 8).min(data.len());
+            data[position..end].fill(0xFF);
+            let _ = block_on(probe(&Bytes(data)));
+        }
+    }
+}
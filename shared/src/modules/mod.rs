@@ -1,9 +1,24 @@
 pub mod app_state;
 pub mod auth;
+pub mod availability;
 pub mod azure_storage;
+pub mod blob_gc;
 pub mod cache;
+pub mod catalog;
 pub mod config;
 pub mod database;
+pub mod etag;
+pub mod events;
+pub mod home;
+pub mod image_processing;
 pub mod interceptors;
+pub mod maturity;
+pub mod media_probe;
+pub mod playback_session;
+pub mod profile;
 pub mod response_interface;
+pub mod search;
+pub mod subtitles;
+pub mod trash;
+pub mod trending;
 pub mod validation;
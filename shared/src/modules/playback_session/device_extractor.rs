@@ -0,0 +1,54 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{request::Parts, StatusCode},
+    Json,
+};
+
+use crate::modules::auth::jwt_extractor::AuthenticatedUser;
+use crate::modules::playback_session::{Device, DEVICE_NAME_HEADER};
+use crate::modules::validation::validation_layer::ValidationErrorResponse;
+
+const MAX_DEVICE_NAME_LEN: usize = 64;
+
+/// Dispositivo do espectador. O identificador é a sessão de login do token,
+/// emitida pelo servidor; o cabeçalho `X-Device-Name` é só para exibição.
+#[derive(Debug, Clone)]
+pub struct ViewerDevice(pub Device);
+
+impl<S> FromRequestParts<S> for ViewerDevice
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, Json<ValidationErrorResponse>);
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let AuthenticatedUser(user) = AuthenticatedUser::from_request_parts(parts, state).await?;
+
+        // tokens emitidos antes das sessões de login não identificam o dispositivo
+        let id = user.session_id.ok_or_else(|| {
+            (
+                StatusCode::UNAUTHORIZED,
+                Json(ValidationErrorResponse {
+                    message: "Sessão inválida".to_string(),
+                    errors: serde_json::json!(["Faça login novamente para reproduzir"]),
+                }),
+            )
+        })?;
+
+        // nome ilegível não impede a reprodução
+        let name = parts
+            .headers
+            .get(DEVICE_NAME_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| {
+                value
+                    .trim()
+                    .chars()
+                    .take(MAX_DEVICE_NAME_LEN)
+                    .collect::<String>()
+            })
+            .filter(|name| !name.is_empty());
+
+        Ok(Self(Device { id, name }))
+    }
+}
@@ -0,0 +1,225 @@
+//! Telas simultâneas: cada requisição ao stream, aos manifestos HLS/DASH e
+//! aos segmentos renova a sessão do dispositivo, e a conta só abre sessões novas enquanto estiver abaixo do
+//! limite do plano.
+//!
+//! As sessões de uma conta ficam num hash no Redis, uma por dispositivo. O
+//! dispositivo é a sessão de login do token, emitida pelo servidor, e não um
+//! valor escolhido pelo cliente. Sem heartbeat por [`SESSION_TIMEOUT_SECS`],
+//! a tela volta a ficar livre.
+
+pub mod device_extractor;
+
+use chrono::Utc;
+use redis::{RedisError, Script};
+use serde::{Deserialize, Serialize};
+
+use crate::modules::app_state::AppState;
+
+/// Cabeçalho opcional com o nome do dispositivo mostrado ao usuário.
+pub const DEVICE_NAME_HEADER: &str = "x-device-name";
+
+/// Prefixo do hash de sessões de cada conta.
+pub const SESSION_KEY_PREFIX: &str = "playback:sessions";
+
+/// Prefixo das sessões de login desconectadas pelo dono da conta.
+pub const KICKED_KEY_PREFIX: &str = "playback:kicked";
+
+/// Tempo sem heartbeat para a sessão deixar de contar como ativa.
+pub const SESSION_TIMEOUT_SECS: i64 = 90;
+
+/// Remove as sessões vencidas e grava a do dispositivo se ele já estava
+/// assistindo ou se ainda há tela livre. Tudo num script para que duas telas
+/// não ocupem a última vaga ao mesmo tempo.
+///
+/// KEYS: sessões da conta, bloqueio da sessão de login.
+/// ARGV: dispositivo, sessão em JSON, corte de inatividade, limite, TTL.
+/// Retorna 1 se a sessão foi gravada, 0 no limite e -1 se bloqueado.
+const CLAIM_SCRIPT: &str = r#"
+if redis.call('EXISTS', KEYS[2]) == 1 then
+    return -1
+end
+
+local entries = redis.call('HGETALL', KEYS[1])
+local others = 0
+for i = 1, #entries, 2 do
+    local session = cjson.decode(entries[i + 1])
+    if session.last_seen < tonumber(ARGV[3]) then
+        redis.call('HDEL', KEYS[1], entries[i])
+    elseif entries[i] ~= ARGV[1] then
+        others = others + 1
+    end
+end
+
+if others >= tonumber(ARGV[4]) then
+    return 0
+end
+
+redis.call('HSET', KEYS[1], ARGV[1], ARGV[2])
+redis.call('EXPIRE', KEYS[1], ARGV[5])
+return 1
+"#;
+
+/// Dispositivo que pede a reprodução. O `id` é a sessão de login do token.
+#[derive(Debug, Clone)]
+pub struct Device {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// Sessão de reprodução ativa de um dispositivo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaybackSession {
+    pub device_id: String,
+    pub device_name: Option<String>,
+    pub profile_id: i32,
+    pub video_id: i32,
+    /// Último heartbeat, em segundos desde a época Unix.
+    pub last_seen: i64,
+}
+
+/// Quem está pedindo uma tela, e quantas o plano da conta libera.
+#[derive(Debug, Clone)]
+pub struct StreamClaim {
+    pub user_id: String,
+    pub profile_id: i32,
+    pub device: Device,
+    pub limit: usize,
+}
+
+pub enum HeartbeatOutcome {
+    Accepted,
+    /// O dispositivo foi desconectado pelo dono da conta.
+    Kicked,
+    /// Todas as telas estão em uso; traz as sessões dos outros dispositivos.
+    LimitReached(Vec<PlaybackSession>),
+}
+
+/// Por que o dispositivo não pode reproduzir agora.
+pub enum ScreenDenied {
+    Kicked,
+    /// Limite do plano e as sessões dos outros dispositivos.
+    LimitReached(usize, Vec<PlaybackSession>),
+    /// Sem Redis não há como contar as telas, então a reprodução é recusada.
+    Unavailable,
+}
+
+/// [`heartbeat`] para quem entrega mídia: stream, manifestos e segmentos.
+/// Sem Redis a reprodução é recusada, senão o limite do plano deixaria de valer.
+pub async fn claim_screen(
+    state: &AppState,
+    claim: &StreamClaim,
+    video_id: i32,
+) -> Result<(), ScreenDenied> {
+    match heartbeat(state, claim, video_id).await {
+        Ok(HeartbeatOutcome::Accepted) => Ok(()),
+        Ok(HeartbeatOutcome::Kicked) => Err(ScreenDenied::Kicked),
+        Ok(HeartbeatOutcome::LimitReached(sessions)) => {
+            Err(ScreenDenied::LimitReached(claim.limit, sessions))
+        }
+        Err(e) => {
+            tracing::error!(
+                "Falha ao registrar a sessão do dispositivo {}: {:?}",
+                claim.device.id,
+                e
+            );
+            Err(ScreenDenied::Unavailable)
+        }
+    }
+}
+
+fn sessions_key(user_id: &str) -> String {
+    format!("{}:{}", SESSION_KEY_PREFIX, user_id)
+}
+
+fn kicked_key(user_id: &str, session_id: &str) -> String {
+    format!("{}:{}:{}", KICKED_KEY_PREFIX, user_id, session_id)
+}
+
+/// Renova a sessão do dispositivo ou abre uma nova, se houver tela livre.
+pub async fn heartbeat(
+    state: &AppState,
+    claim: &StreamClaim,
+    video_id: i32,
+) -> Result<HeartbeatOutcome, RedisError> {
+    let now = Utc::now().timestamp();
+    let session = PlaybackSession {
+        device_id: claim.device.id.clone(),
+        device_name: claim.device.name.clone(),
+        profile_id: claim.profile_id,
+        video_id,
+        last_seen: now,
+    };
+    let payload = serde_json::to_string(&session).map_err(|e| {
+        RedisError::from((
+            redis::ErrorKind::TypeError,
+            "Serialization failed",
+            e.to_string(),
+        ))
+    })?;
+
+    let sessions_key = sessions_key(&claim.user_id);
+    let kicked_key = kicked_key(&claim.user_id, &claim.device.id);
+    let result: i32 = state
+        .cache
+        .run_script(
+            &Script::new(CLAIM_SCRIPT),
+            &[&sessions_key, &kicked_key],
+            &[
+                claim.device.id.clone(),
+                payload,
+                (now - SESSION_TIMEOUT_SECS).to_string(),
+                claim.limit.to_string(),
+                SESSION_TIMEOUT_SECS.to_string(),
+            ],
+        )
+        .await?;
+
+    match result {
+        1 => Ok(HeartbeatOutcome::Accepted),
+        -1 => Ok(HeartbeatOutcome::Kicked),
+        _ => {
+            let mut sessions = active_sessions(state, &claim.user_id).await?;
+            sessions.retain(|session| session.device_id != claim.device.id);
+            Ok(HeartbeatOutcome::LimitReached(sessions))
+        }
+    }
+}
+
+/// Sessões ativas da conta, da mais recente para a mais antiga.
+pub async fn active_sessions(
+    state: &AppState,
+    user_id: &str,
+) -> Result<Vec<PlaybackSession>, RedisError> {
+    let cutoff = Utc::now().timestamp() - SESSION_TIMEOUT_SECS;
+
+    let mut sessions: Vec<PlaybackSession> = state
+        .cache
+        .get_all_hash::<_, PlaybackSession>(sessions_key(user_id))
+        .await?
+        .into_values()
+        .filter(|session| session.last_seen >= cutoff)
+        .collect();
+    sessions.sort_by_key(|session| std::cmp::Reverse(session.last_seen));
+
+    Ok(sessions)
+}
+
+/// Tira o dispositivo da tela e o impede de reproduzir enquanto o access
+/// token dele ainda for válido. A sessão de login já deve estar revogada,
+/// para que o refresh não gere um token novo.
+pub async fn kick(state: &AppState, user_id: &str, session_id: &str) -> Result<(), RedisError> {
+    state
+        .cache
+        .set_with_ttl(
+            kicked_key(user_id, session_id),
+            &true,
+            state.auth_service.access_token_ttl_secs(),
+        )
+        .await?;
+    state
+        .cache
+        .delete_hash(sessions_key(user_id), session_id)
+        .await?;
+
+    Ok(())
+}
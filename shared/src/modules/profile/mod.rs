@@ -0,0 +1,75 @@
+//! Perfis de uma conta: histórico, lista e avaliações são de cada perfil.
+//!
+//! O token traz o perfil escolhido em `/auth/profiles/{id}/select`; tokens
+//! sem perfil, de antes dos perfis existirem, valem pelo perfil principal.
+
+pub mod profile_extractor;
+
+use chrono::{Duration, Utc};
+use sea_orm::DbErr;
+
+use crate::modules::app_state::AppState;
+use crate::modules::auth::User;
+use crate::modules::database::schema::profiles::Model as ProfileModel;
+
+/// Erros de PIN seguidos antes de o PIN ser bloqueado.
+pub const MAX_PIN_ATTEMPTS: i32 = 5;
+/// Duração do bloqueio depois de `MAX_PIN_ATTEMPTS` erros seguidos.
+pub const PIN_LOCKOUT_MINUTES: i64 = 15;
+
+/// Resultado de uma conferência de PIN limitada por [`limit_pin_attempts`].
+pub enum PinAttempt<E> {
+    /// O PIN do perfil está bloqueado; nada foi conferido.
+    Locked,
+    /// Resultado da conferência, que conta como tentativa.
+    Checked(Result<(), E>),
+}
+
+/// Confere o PIN de `profile` com `check` contando uma tentativa, e zera a
+/// contagem quando `check` passa. A tentativa é contada antes de conferir,
+/// então pedidos simultâneos não passam de `MAX_PIN_ATTEMPTS` chutes por
+/// bloqueio. Vale para todo PIN: o do próprio perfil e o da conta, que é o do
+/// perfil principal.
+pub async fn limit_pin_attempts<E>(
+    state: &AppState,
+    profile: &ProfileModel,
+    check: impl FnOnce() -> Result<(), E>,
+) -> Result<PinAttempt<E>, DbErr> {
+    let now = Utc::now().naive_utc();
+    let reserved = state
+        .profile_repo
+        .reserve_pin_attempt(
+            profile.id,
+            MAX_PIN_ATTEMPTS,
+            now,
+            now + Duration::minutes(PIN_LOCKOUT_MINUTES),
+        )
+        .await?;
+    if !reserved {
+        return Ok(PinAttempt::Locked);
+    }
+
+    let result = check();
+    if result.is_ok() {
+        state.profile_repo.reset_pin_attempts(profile.id).await?;
+    }
+
+    Ok(PinAttempt::Checked(result))
+}
+
+/// Mensagem para quem tenta um PIN bloqueado.
+pub fn pin_locked_message() -> String {
+    format!(
+        "PIN bloqueado por excesso de tentativas. Tente novamente em até {} minutos",
+        PIN_LOCKOUT_MINUTES
+    )
+}
+
+/// Perfil em uso pelo token. `None` se o perfil foi apagado depois de
+/// escolhido, ou se a conta não tem perfil principal.
+pub async fn active_profile(state: &AppState, user: &User) -> Result<Option<ProfileModel>, DbErr> {
+    match user.profile_id {
+        Some(profile_id) => state.profile_repo.find_owned(&user.id, profile_id).await,
+        None => state.profile_repo.find_default(&user.id).await,
+    }
+}
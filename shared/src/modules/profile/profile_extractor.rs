@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{request::Parts, StatusCode},
+    Json,
+};
+
+use crate::modules::app_state::AppState;
+use crate::modules::auth::jwt_extractor::AuthenticatedUser;
+use crate::modules::database::schema::profiles::Model as ProfileModel;
+use crate::modules::profile::active_profile;
+use crate::modules::validation::validation_layer::ValidationErrorResponse;
+
+/// Perfil em uso pelo espectador, resolvido a partir do token. Depende do
+/// `Extension<Arc<AppState>>` já aplicado no router.
+#[derive(Debug, Clone)]
+pub struct ViewerProfile(pub ProfileModel);
+
+impl<S> FromRequestParts<S> for ViewerProfile
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, Json<ValidationErrorResponse>);
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let AuthenticatedUser(user) = AuthenticatedUser::from_request_parts(parts, state).await?;
+
+        let app_state = parts
+            .extensions
+            .get::<Arc<AppState>>()
+            .cloned()
+            .ok_or_else(|| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ValidationErrorResponse {
+                        message: "Configuração inválida".to_string(),
+                        errors: serde_json::json!(["Estado da aplicação ausente"]),
+                    }),
+                )
+            })?;
+
+        let profile = active_profile(&app_state, &user).await.map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ValidationErrorResponse {
+                    message: "Erro ao buscar o perfil".to_string(),
+                    errors: serde_json::json!([e.to_string()]),
+                }),
+            )
+        })?;
+
+        profile.map(Self).ok_or_else(|| {
+            (
+                StatusCode::FORBIDDEN,
+                Json(ValidationErrorResponse {
+                    message: "Perfil inválido".to_string(),
+                    errors: serde_json::json!([
+                        "O perfil do token não existe mais. Escolha um perfil"
+                    ]),
+                }),
+            )
+        })
+    }
+}
@@ -0,0 +1,120 @@
+//! Índice de busca dos vídeos, com backend escolhido por `SEARCH_BACKEND`:
+//! `postgres` (o `search_vector` mantido pelos gatilhos) ou `tantivy` (índice
+//! embutido em `SEARCH_INDEX_PATH`).
+//!
+//! O índice é derivado do banco: as escritas do catálogo chamam [`sync_videos`]
+//! ou [`sync_scope`], ou deixam a sincronização com o assinante do evento que
+//! gravaram, e, se algo se perder, `media_worker reindex-search` reconstrói tudo.
+
+pub mod postgres_index;
+pub mod tantivy_index;
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::modules::app_state::AppState;
+use crate::modules::config::Config;
+use crate::modules::database::repositories::search_repository::{
+    IndexQuery, IndexScope, IndexSearchResult, SearchDocument,
+};
+
+#[async_trait]
+pub trait SearchIndex: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Grava os documentos, substituindo os que já existirem.
+    async fn upsert(&self, documents: Vec<SearchDocument>) -> Result<()>;
+
+    async fn remove(&self, video_ids: &[i32]) -> Result<()>;
+
+    /// Troca todo o conteúdo do índice por `documents`.
+    async fn rebuild(&self, documents: Vec<SearchDocument>) -> Result<()>;
+
+    async fn search(&self, query: &IndexQuery) -> Result<IndexSearchResult>;
+}
+
+pub fn build_search_index(config: &Config, db: DatabaseConnection) -> Result<Arc<dyn SearchIndex>> {
+    match config.search_backend.as_str() {
+        "postgres" => Ok(Arc::new(postgres_index::PostgresSearchIndex::new(db))),
+        "tantivy" => Ok(Arc::new(tantivy_index::TantivySearchIndex::open(
+            Path::new(&config.search_index_path),
+            db,
+        )?)),
+        other => bail!("Backend de busca desconhecido: '{}'", other),
+    }
+}
+
+/// Regrava os documentos dos vídeos; os que não existem mais ou estão na
+/// lixeira saem do índice. Falhas só geram aviso no log: o backend Tantivy
+/// guarda os vídeos e os regrava depois, e a reindexação acerta o resto.
+pub async fn sync_videos(state: &AppState, video_ids: &[i32]) {
+    if video_ids.is_empty() {
+        return;
+    }
+
+    if let Err(e) = try_sync_videos(state, video_ids).await {
+        tracing::warn!(
+            "Falha ao atualizar o índice de busca ({}) para os vídeos {:?}: {:?}",
+            state.search_index.name(),
+            video_ids,
+            e
+        );
+    }
+}
+
+/// Vídeos ligados ao item, para sincronizar depois da escrita. Em exclusões,
+/// chame antes de apagar, enquanto os vínculos ainda existem.
+pub async fn affected_videos(state: &AppState, scope: IndexScope) -> Vec<i32> {
+    state
+        .search_repo
+        .find_video_ids(scope)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Falha ao buscar os vídeos de {:?}: {:?}", scope, e);
+            Vec::new()
+        })
+}
+
+/// Sincroniza os vídeos ligados a uma série, pessoa ou categoria alterada.
+pub async fn sync_scope(state: &AppState, scope: IndexScope) {
+    let video_ids = affected_videos(state, scope).await;
+    sync_videos(state, &video_ids).await;
+}
+
+/// Reconstrói o índice a partir do banco. Retorna quantos vídeos foram indexados.
+pub async fn reindex(state: &AppState) -> Result<usize> {
+    let documents = state.search_repo.find_documents(None).await?;
+    let count = documents.len();
+    state.search_index.rebuild(documents).await?;
+
+    Ok(count)
+}
+
+/// [`sync_videos`] devolvendo a falha, para quem pode tentar de novo.
+pub async fn try_sync_videos(state: &AppState, video_ids: &[i32]) -> Result<()> {
+    if video_ids.is_empty() {
+        return Ok(());
+    }
+
+    let documents = state.search_repo.find_documents(Some(video_ids)).await?;
+
+    let found: HashSet<i32> = documents.iter().map(|doc| doc.video_id).collect();
+    let gone: Vec<i32> = video_ids
+        .iter()
+        .copied()
+        .filter(|id| !found.contains(id))
+        .collect();
+
+    if !gone.is_empty() {
+        state.search_index.remove(&gone).await?;
+    }
+    if !documents.is_empty() {
+        state.search_index.upsert(documents).await?;
+    }
+
+    Ok(())
+}
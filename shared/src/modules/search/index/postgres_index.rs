@@ -0,0 +1,51 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+
+use crate::modules::database::repositories::search_repository::{
+    IndexQuery, IndexSearchResult, SearchDocument, SearchRepository,
+};
+use crate::modules::search::index::SearchIndex;
+
+/// Busca no `videos.search_vector`. Os gatilhos do banco já mantêm o vetor,
+/// então a sincronização só o recalcula; vídeos apagados ou na lixeira ficam
+/// de fora pela própria consulta.
+pub struct PostgresSearchIndex {
+    repo: SearchRepository,
+}
+
+impl PostgresSearchIndex {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self {
+            repo: SearchRepository::new(db),
+        }
+    }
+}
+
+#[async_trait]
+impl SearchIndex for PostgresSearchIndex {
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+
+    async fn upsert(&self, documents: Vec<SearchDocument>) -> Result<()> {
+        let video_ids: Vec<i32> = documents.iter().map(|doc| doc.video_id).collect();
+        self.repo.refresh_search_vectors(Some(&video_ids)).await?;
+
+        Ok(())
+    }
+
+    async fn remove(&self, _video_ids: &[i32]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn rebuild(&self, _documents: Vec<SearchDocument>) -> Result<()> {
+        self.repo.refresh_search_vectors(None).await?;
+
+        Ok(())
+    }
+
+    async fn search(&self, query: &IndexQuery) -> Result<IndexSearchResult> {
+        Ok(self.repo.search(query).await?)
+    }
+}
@@ -0,0 +1,753 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use std::collections::HashSet;
+use std::ops::Bound;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tantivy::collector::{Count, TopDocs};
+use tantivy::directory::error::LockError;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{AllQuery, BooleanQuery, Occur, Query, QueryParser, RangeQuery, TermQuery};
+use tantivy::schema::{
+    Field, IndexRecordOption, NumericOptions, Schema, TextFieldIndexing, TextOptions, Value,
+    INDEXED,
+};
+use tantivy::snippet::SnippetGenerator;
+use tantivy::tokenizer::{
+    AsciiFoldingFilter, Language, LowerCaser, RemoveLongFilter, SimpleTokenizer, Stemmer,
+    TextAnalyzer,
+};
+use tantivy::{
+    DocAddress, Index, IndexReader, IndexWriter, Order, ReloadPolicy, Searcher, TantivyDocument,
+    TantivyError, Term,
+};
+
+use crate::enums::video_type_enum::VideoTypeEnum;
+use crate::modules::database::repositories::search_repository::{
+    Highlights, IndexHit, IndexQuery, IndexSearchResult, SearchDocument, SearchRepository,
+};
+use crate::modules::search::index::SearchIndex;
+
+/// Português em minúsculas, sem acentos e reduzido ao radical.
+const TOKENIZER: &str = "pt_unaccent";
+
+const WRITER_MEMORY_BYTES: usize = 50_000_000;
+
+/// Tentativas de abrir o writer enquanto outro processo segura o lock do
+/// diretório, com espera dobrando a partir de [`WRITER_LOCK_BACKOFF`].
+const WRITER_LOCK_ATTEMPTS: u32 = 6;
+const WRITER_LOCK_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Espera até regravar os vídeos de escritas que falharam.
+const PENDING_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Candidatos lidos do índice quando a busca filtra pelo que está no ar. As
+/// janelas dependem do instante e da região, então esse filtro é feito no
+/// Postgres sobre os candidatos, e o total fica limitado a esse número.
+const MAX_CANDIDATES: usize = 1000;
+
+/// Campos com trecho destacado no resultado.
+const HIGHLIGHT_FIELDS: &[&str] = &["title", "series_title", "people", "description"];
+
+#[derive(Clone, Copy)]
+struct Fields {
+    id: Field,
+    title: Field,
+    series_title: Field,
+    people: Field,
+    description: Field,
+    category_id: Field,
+    release_year: Field,
+    is_episode: Field,
+    premium: Field,
+    is_available: Field,
+    created_at: Field,
+}
+
+impl Fields {
+    fn from_schema(schema: &Schema) -> Result<Self> {
+        let field = |name: &str| {
+            schema
+                .get_field(name)
+                .with_context(|| format!("Campo '{}' ausente no índice de busca", name))
+        };
+
+        Ok(Self {
+            id: field("id")?,
+            title: field("title")?,
+            series_title: field("series_title")?,
+            people: field("people")?,
+            description: field("description")?,
+            category_id: field("category_id")?,
+            release_year: field("release_year")?,
+            is_episode: field("is_episode")?,
+            premium: field("premium")?,
+            is_available: field("is_available")?,
+            created_at: field("created_at")?,
+        })
+    }
+}
+
+struct Inner {
+    index: Index,
+    reader: IndexReader,
+    fields: Fields,
+    /// Único writer do processo. Escritas simultâneas esperam na fila do
+    /// mutex em vez de disputar o lock do diretório.
+    writer: Mutex<Option<IndexWriter>>,
+    /// Escritas na fila do writer; a última a sair o fecha.
+    waiting: AtomicUsize,
+    /// Vídeos de escritas que falharam, regravados a partir do banco.
+    pending: Mutex<HashSet<i32>>,
+    retry_scheduled: AtomicBool,
+    repo: SearchRepository,
+}
+
+/// Índice Tantivy em disco. O processo mantém um só writer, aberto enquanto
+/// houver escritas na fila e fechado em seguida, para que admin_api e
+/// media_worker possam escrever no mesmo diretório; a viewer_api só lê e
+/// recarrega a cada commit.
+///
+/// Se outro processo segura o lock, a abertura do writer é repetida por alguns
+/// segundos. Se mesmo assim a escrita falhar, os vídeos dela ficam pendentes e
+/// são regravados a partir do banco na escrita seguinte ou após
+/// [`PENDING_RETRY_DELAY`].
+#[derive(Clone)]
+pub struct TantivySearchIndex {
+    inner: Arc<Inner>,
+}
+
+impl TantivySearchIndex {
+    pub fn open(path: &Path, db: DatabaseConnection) -> Result<Self> {
+        std::fs::create_dir_all(path)
+            .with_context(|| format!("Falha ao criar o diretório do índice {}", path.display()))?;
+
+        let directory = MmapDirectory::open(path)?;
+        let index = Index::open_or_create(directory, schema())?;
+        index.tokenizers().register(
+            TOKENIZER,
+            TextAnalyzer::builder(SimpleTokenizer::default())
+                .filter(RemoveLongFilter::limit(40))
+                .filter(LowerCaser)
+                .filter(AsciiFoldingFilter)
+                .filter(Stemmer::new(Language::Portuguese))
+                .build(),
+        );
+
+        let fields = Fields::from_schema(&index.schema())?;
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::OnCommitWithDelay)
+            .try_into()?;
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                index,
+                reader,
+                fields,
+                writer: Mutex::new(None),
+                waiting: AtomicUsize::new(0),
+                pending: Mutex::new(HashSet::new()),
+                retry_scheduled: AtomicBool::new(false),
+                repo: SearchRepository::new(db),
+            }),
+        })
+    }
+
+    async fn write<F>(&self, apply: F) -> Result<()>
+    where
+        F: FnOnce(&mut IndexWriter, &Fields) -> Result<()> + Send + 'static,
+    {
+        let inner = self.inner.clone();
+        inner.waiting.fetch_add(1, Ordering::SeqCst);
+
+        tokio::task::spawn_blocking(move || {
+            let mut slot = inner.writer.lock().unwrap_or_else(PoisonError::into_inner);
+
+            let result = commit_with(&inner, &mut slot, apply);
+            // o que não foi confirmado é descartado junto com o writer
+            if result.is_err() {
+                *slot = None;
+            }
+
+            // sem outra escrita na fila, libera o lock para os outros processos
+            if inner.waiting.fetch_sub(1, Ordering::SeqCst) == 1 {
+                if let Some(writer) = slot.take() {
+                    writer.wait_merging_threads()?;
+                }
+            }
+
+            result
+        })
+        .await?
+    }
+
+    /// Grava `documents` e remove `gone`, junto com os vídeos pendentes de
+    /// escritas anteriores. Se falhar, todos ficam pendentes.
+    async fn write_videos(&self, documents: Vec<SearchDocument>, gone: Vec<i32>) -> Result<()> {
+        let mut video_ids: HashSet<i32> = documents.iter().map(|doc| doc.video_id).collect();
+        video_ids.extend(&gone);
+
+        let pending: Vec<i32> = self
+            .take_pending()
+            .into_iter()
+            .filter(|id| !video_ids.contains(id))
+            .collect();
+        let (mut documents, mut gone) = (documents, gone);
+        if !pending.is_empty() {
+            match self.inner.repo.find_documents(Some(&pending)).await {
+                Ok(found) => {
+                    let found_ids: HashSet<i32> = found.iter().map(|doc| doc.video_id).collect();
+                    gone.extend(pending.iter().filter(|id| !found_ids.contains(id)));
+                    documents.extend(found);
+                    video_ids.extend(&pending);
+                }
+                Err(e) => {
+                    tracing::warn!("Falha ao buscar os vídeos pendentes do índice: {:?}", e);
+                    self.queue(pending);
+                }
+            }
+        }
+
+        let result = self
+            .write(move |writer, fields| {
+                for video_id in gone {
+                    writer.delete_term(Term::from_field_i64(fields.id, video_id as i64));
+                }
+                for document in &documents {
+                    writer.delete_term(Term::from_field_i64(fields.id, document.video_id as i64));
+                    writer.add_document(to_document(fields, document))?;
+                }
+                Ok(())
+            })
+            .await;
+
+        if result.is_err() {
+            self.queue(video_ids);
+        }
+
+        result
+    }
+
+    fn take_pending(&self) -> HashSet<i32> {
+        std::mem::take(
+            &mut *self
+                .inner
+                .pending
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    /// Guarda os vídeos para a próxima escrita e agenda uma regravação, caso
+    /// ela demore.
+    fn queue(&self, video_ids: impl IntoIterator<Item = i32>) {
+        self.inner
+            .pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(video_ids);
+
+        if self.inner.retry_scheduled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let index = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(PENDING_RETRY_DELAY).await;
+            index.inner.retry_scheduled.store(false, Ordering::SeqCst);
+
+            // se falhar de novo, `write_videos` devolve os vídeos para a fila
+            if index.has_pending() {
+                if let Err(e) = index.write_videos(Vec::new(), Vec::new()).await {
+                    tracing::warn!("Falha ao regravar os vídeos pendentes do índice: {:?}", e);
+                }
+            }
+        });
+    }
+
+    fn has_pending(&self) -> bool {
+        !self
+            .inner
+            .pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_empty()
+    }
+}
+
+/// Aplica a escrita no writer do processo, abrindo-o se preciso, e confirma.
+fn commit_with<F>(inner: &Inner, slot: &mut Option<IndexWriter>, apply: F) -> Result<()>
+where
+    F: FnOnce(&mut IndexWriter, &Fields) -> Result<()>,
+{
+    let writer = match slot {
+        Some(writer) => writer,
+        None => slot.insert(open_writer(&inner.index)?),
+    };
+
+    apply(writer, &inner.fields)?;
+    writer.commit()?;
+    inner.reader.reload()?;
+
+    Ok(())
+}
+
+/// Abre o writer, esperando enquanto outro processo segura o lock do diretório.
+fn open_writer(index: &Index) -> Result<IndexWriter> {
+    let mut delay = WRITER_LOCK_BACKOFF;
+    let mut attempt = 1;
+
+    loop {
+        match index.writer(WRITER_MEMORY_BYTES) {
+            Err(TantivyError::LockFailure(LockError::LockBusy, _))
+                if attempt < WRITER_LOCK_ATTEMPTS =>
+            {
+                std::thread::sleep(delay);
+                delay *= 2;
+                attempt += 1;
+            }
+            result => return result.context("Falha ao abrir o writer do índice de busca"),
+        }
+    }
+}
+
+#[async_trait]
+impl SearchIndex for TantivySearchIndex {
+    fn name(&self) -> &'static str {
+        "tantivy"
+    }
+
+    async fn upsert(&self, documents: Vec<SearchDocument>) -> Result<()> {
+        self.write_videos(documents, Vec::new()).await
+    }
+
+    async fn remove(&self, video_ids: &[i32]) -> Result<()> {
+        self.write_videos(Vec::new(), video_ids.to_vec()).await
+    }
+
+    async fn rebuild(&self, documents: Vec<SearchDocument>) -> Result<()> {
+        // a reconstrução já leu tudo do banco
+        let pending = self.take_pending();
+
+        let result = self
+            .write(move |writer, fields| {
+                writer.delete_all_documents()?;
+                for document in &documents {
+                    writer.add_document(to_document(fields, document))?;
+                }
+                Ok(())
+            })
+            .await;
+
+        if result.is_err() {
+            self.queue(pending);
+        }
+
+        result
+    }
+
+    async fn search(&self, query: &IndexQuery) -> Result<IndexSearchResult> {
+        let inner = self.inner.clone();
+        let request = query.clone();
+        let filter_live = query.live.is_some();
+
+        let (searcher, text_query, candidates, total) = tokio::task::spawn_blocking(move || {
+            let searcher = inner.reader.searcher();
+            let (full_query, text_query) = build_query(&inner, &request);
+
+            // sem o filtro de disponibilidade o índice já pagina; com ele, a
+            // página sai dos candidatos que continuarem no ar
+            let (limit, offset) = if filter_live {
+                (MAX_CANDIDATES, 0)
+            } else {
+                (request.take as usize, request.skip as usize)
+            };
+
+            let candidates = collect(
+                &searcher,
+                &inner.fields,
+                &*full_query,
+                text_query.is_some(),
+                limit,
+                offset,
+            )?;
+            let total = if filter_live {
+                0
+            } else {
+                searcher.search(&*full_query, &Count)? as u64
+            };
+
+            anyhow::Ok((searcher, text_query, candidates, total))
+        })
+        .await??;
+
+        let (page, total) = match &query.live {
+            Some(live) => {
+                let video_ids: Vec<i32> = candidates.iter().map(|(id, _, _)| *id).collect();
+                let live_ids = self.inner.repo.find_live_ids(&video_ids, live).await?;
+                let available: Vec<_> = candidates
+                    .into_iter()
+                    .filter(|(id, _, _)| live_ids.contains(id))
+                    .collect();
+                let total = available.len() as u64;
+                let page = available
+                    .into_iter()
+                    .skip(query.skip as usize)
+                    .take(query.take as usize)
+                    .collect();
+                (page, total)
+            }
+            None => (candidates, total),
+        };
+
+        let fields = self.inner.fields;
+        let hits = tokio::task::spawn_blocking(move || {
+            highlight(&searcher, &fields, text_query.as_deref(), page)
+        })
+        .await??;
+
+        Ok(IndexSearchResult { hits, total })
+    }
+}
+
+fn schema() -> Schema {
+    let text = TextOptions::default()
+        .set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(TOKENIZER)
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        )
+        .set_stored();
+    let numeric = NumericOptions::default().set_indexed().set_fast();
+
+    let mut builder = Schema::builder();
+    builder.add_i64_field("id", numeric.clone().set_stored());
+    builder.add_text_field("title", text.clone());
+    builder.add_text_field("series_title", text.clone());
+    builder.add_text_field("people", text.clone());
+    builder.add_text_field("description", text);
+    builder.add_i64_field("category_id", INDEXED);
+    builder.add_i64_field("release_year", numeric.clone());
+    builder.add_bool_field("is_episode", INDEXED);
+    builder.add_bool_field("premium", INDEXED);
+    builder.add_bool_field("is_available", INDEXED);
+    builder.add_i64_field("created_at", numeric);
+    builder.build()
+}
+
+fn to_document(fields: &Fields, document: &SearchDocument) -> TantivyDocument {
+    let mut doc = TantivyDocument::default();
+    doc.add_i64(fields.id, document.video_id as i64);
+    doc.add_text(fields.title, &document.title);
+    if let Some(series_title) = &document.series_title {
+        doc.add_text(fields.series_title, series_title);
+    }
+    for name in &document.people {
+        doc.add_text(fields.people, name);
+    }
+    if let Some(description) = &document.description {
+        doc.add_text(fields.description, description);
+    }
+    for category_id in &document.category_ids {
+        doc.add_i64(fields.category_id, *category_id as i64);
+    }
+    if let Some(release_year) = document.release_year {
+        doc.add_i64(fields.release_year, release_year as i64);
+    }
+    doc.add_bool(fields.is_episode, document.is_episode);
+    doc.add_bool(fields.premium, document.premium);
+    doc.add_bool(fields.is_available, document.is_available);
+    doc.add_i64(
+        fields.created_at,
+        document.created_at.and_utc().timestamp_micros(),
+    );
+    doc
+}
+
+/// Consulta completa (texto e filtros) e, separada, a parte textual, usada
+/// para os trechos destacados.
+fn build_query(inner: &Inner, query: &IndexQuery) -> (Box<dyn Query>, Option<Box<dyn Query>>) {
+    let fields = &inner.fields;
+    let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+
+    let text_query = query.text.as_deref().map(|text| {
+        let mut parser = QueryParser::for_index(
+            &inner.index,
+            vec![
+                fields.title,
+                fields.series_title,
+                fields.people,
+                fields.description,
+            ],
+        );
+        parser.set_conjunction_by_default();
+        parser.set_field_boost(fields.title, 3.0);
+        parser.set_field_boost(fields.series_title, 2.0);
+        parser.set_field_boost(fields.people, 1.5);
+        // erros de sintaxe do usuário viram termos comuns
+        parser.parse_query_lenient(text).0
+    });
+
+    match &text_query {
+        Some(text_query) => clauses.push((Occur::Must, text_query.box_clone())),
+        None => clauses.push((Occur::Must, Box::new(AllQuery))),
+    }
+
+    let term =
+        |term: Term| -> Box<dyn Query> { Box::new(TermQuery::new(term, IndexRecordOption::Basic)) };
+
+    if let Some(category_id) = query.category_id {
+        clauses.push((
+            Occur::Must,
+            term(Term::from_field_i64(fields.category_id, category_id as i64)),
+        ));
+    }
+
+    if query.year_from.is_some() || query.year_to.is_some() {
+        let bound =
+            |year: Option<i32>| year.map_or(Bound::Unbounded, |y| Bound::Included(y as i64));
+        clauses.push((
+            Occur::Must,
+            Box::new(RangeQuery::new_i64_bounds(
+                "release_year".to_string(),
+                bound(query.year_from),
+                bound(query.year_to),
+            )),
+        ));
+    }
+
+    if let Some(video_type) = query.video_type {
+        clauses.push((
+            Occur::Must,
+            term(Term::from_field_bool(
+                fields.is_episode,
+                video_type == VideoTypeEnum::EPISODE,
+            )),
+        ));
+    }
+
+    if let Some(premium) = query.premium {
+        clauses.push((
+            Occur::Must,
+            term(Term::from_field_bool(fields.premium, premium)),
+        ));
+    }
+
+    if query.live.is_some() {
+        clauses.push((
+            Occur::Must,
+            term(Term::from_field_bool(fields.is_available, true)),
+        ));
+    }
+
+    (Box::new(BooleanQuery::new(clauses)), text_query)
+}
+
+/// `(video_id, score, endereço)` na ordem do resultado: relevância com texto,
+/// mais recentes primeiro sem ele.
+fn collect(
+    searcher: &Searcher,
+    fields: &Fields,
+    query: &dyn Query,
+    has_text: bool,
+    limit: usize,
+    offset: usize,
+) -> Result<Vec<(i32, f32, DocAddress)>> {
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let ranked: Vec<(f32, DocAddress)> = if has_text {
+        searcher.search(query, &TopDocs::with_limit(limit).and_offset(offset))?
+    } else {
+        searcher
+            .search(
+                query,
+                &TopDocs::with_limit(limit)
+                    .and_offset(offset)
+                    .order_by_fast_field::<i64>("created_at", Order::Desc),
+            )?
+            .into_iter()
+            .map(|(_, address)| (0.0, address))
+            .collect()
+    };
+
+    ranked
+        .into_iter()
+        .map(|(score, address)| {
+            let doc: TantivyDocument = searcher.doc(address)?;
+            let video_id = doc
+                .get_first(fields.id)
+                .and_then(|value| value.as_i64())
+                .context("Documento sem id no índice de busca")?;
+            Ok((video_id as i32, score, address))
+        })
+        .collect()
+}
+
+fn highlight(
+    searcher: &Searcher,
+    fields: &Fields,
+    text_query: Option<&dyn Query>,
+    page: Vec<(i32, f32, DocAddress)>,
+) -> Result<Vec<IndexHit>> {
+    let generators = match text_query {
+        Some(text_query) => HIGHLIGHT_FIELDS
+            .iter()
+            .map(|name| {
+                let field = match *name {
+                    "title" => fields.title,
+                    "series_title" => fields.series_title,
+                    "people" => fields.people,
+                    _ => fields.description,
+                };
+                Ok((
+                    *name,
+                    SnippetGenerator::create(searcher, text_query, field)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?,
+        None => Vec::new(),
+    };
+
+    page.into_iter()
+        .map(|(video_id, score, address)| {
+            let mut highlights = Highlights::new();
+            if !generators.is_empty() {
+                let doc: TantivyDocument = searcher.doc(address)?;
+                for (name, generator) in &generators {
+                    let snippet = generator.snippet_from_doc(&doc);
+                    if !snippet.highlighted().is_empty() {
+                        highlights.insert(name.to_string(), snippet.to_html());
+                    }
+                }
+            }
+
+            Ok(IndexHit {
+                video_id,
+                score,
+                highlights,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use std::path::PathBuf;
+
+    /// Diretório descartável do índice, apagado no fim do teste.
+    struct TempIndex(PathBuf);
+
+    impl TempIndex {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("tantivy-test-{}", uuid::Uuid::new_v4())))
+        }
+
+        fn open(&self) -> TantivySearchIndex {
+            TantivySearchIndex::open(&self.0, DatabaseConnection::Disconnected).unwrap()
+        }
+    }
+
+    impl Drop for TempIndex {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn document(video_id: i32) -> SearchDocument {
+        SearchDocument {
+            video_id,
+            title: format!("Vídeo {}", video_id),
+            description: None,
+            series_title: None,
+            people: Vec::new(),
+            category_ids: Vec::new(),
+            release_year: None,
+            is_episode: false,
+            premium: false,
+            is_available: true,
+            created_at: NaiveDate::from_ymd_opt(2025, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        }
+    }
+
+    fn num_docs(index: &TantivySearchIndex) -> u64 {
+        index.inner.reader.reload().unwrap();
+        index.inner.reader.searcher().num_docs()
+    }
+
+    /// Writer de outro processo sobre o mesmo diretório.
+    fn foreign_writer(dir: &TempIndex) -> IndexWriter {
+        Index::open_in_dir(&dir.0)
+            .unwrap()
+            .writer(WRITER_MEMORY_BYTES)
+            .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_writes_share_the_process_writer() {
+        let dir = TempIndex::new();
+        let index = dir.open();
+
+        let writes = (1..=8).map(|video_id| {
+            let index = index.clone();
+            tokio::spawn(async move { index.upsert(vec![document(video_id)]).await })
+        });
+        for write in futures::future::join_all(writes).await {
+            write.unwrap().unwrap();
+        }
+
+        assert_eq!(num_docs(&index), 8);
+        // sem escritas na fila, o lock fica livre para outro processo
+        drop(foreign_writer(&dir));
+
+        index.remove(&[1, 2]).await.unwrap();
+        assert_eq!(num_docs(&index), 6);
+    }
+
+    #[tokio::test]
+    async fn write_waits_for_the_lock_of_another_process() {
+        let dir = TempIndex::new();
+        let index = dir.open();
+
+        let held = foreign_writer(&dir);
+        let release = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(250));
+            drop(held);
+        });
+
+        index.upsert(vec![document(1)]).await.unwrap();
+        release.join().unwrap();
+
+        assert_eq!(num_docs(&index), 1);
+        assert!(!index.has_pending());
+    }
+
+    #[tokio::test]
+    async fn failed_writes_stay_pending() {
+        let dir = TempIndex::new();
+        let index = dir.open();
+
+        let held = foreign_writer(&dir);
+        assert!(index
+            .write_videos(vec![document(1)], vec![2])
+            .await
+            .is_err());
+        drop(held);
+
+        assert_eq!(index.take_pending(), HashSet::from([1, 2]));
+        assert_eq!(num_docs(&index), 0);
+
+        index.upsert(vec![document(3)]).await.unwrap();
+        assert_eq!(num_docs(&index), 1);
+    }
+}
@@ -0,0 +1,53 @@
+//! Busca do catálogo: o índice dos vídeos ([`index`]) e as sugestões enquanto o
+//! usuário digita, guardadas no Redis por termo.
+//!
+//! As chaves de sugestões ficam sob `catalog:`, então também somem quando o
+//! agendador de lançamentos limpa o cache do catálogo.
+
+pub mod index;
+
+use crate::enums::maturity_rating_enum::MaturityRatingEnum;
+use crate::modules::app_state::AppState;
+
+/// Prefixo das chaves de sugestões no Redis.
+pub const SUGGEST_CACHE_PREFIX: &str = "catalog:suggest";
+
+/// Por quanto tempo uma lista de sugestões fica no cache.
+pub const SUGGEST_CACHE_TTL_SECS: u64 = 60;
+
+/// Termo em minúsculas e com espaços simples, para que `Brekin  Bad` e
+/// `brekin bad` caiam na mesma chave. Os acentos são tratados pelo banco.
+pub fn normalize_term(term: &str) -> String {
+    term.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+pub fn suggest_cache_key(
+    term: &str,
+    region: Option<&str>,
+    max_rating: Option<MaturityRatingEnum>,
+    limit: u64,
+) -> String {
+    format!(
+        "{}:{}:{}:{}:{}",
+        SUGGEST_CACHE_PREFIX,
+        region.unwrap_or("-"),
+        max_rating.map_or("-", |rating| rating.as_str()),
+        limit,
+        term
+    )
+}
+
+/// Descarta as sugestões em cache; chamado quando títulos, séries ou nomes do
+/// elenco e da direção mudam. Falhas do Redis só geram aviso no log.
+pub async fn invalidate_suggestions(state: &AppState) {
+    if let Err(e) = state
+        .cache
+        .clear_pattern(&format!("{}:*", SUGGEST_CACHE_PREFIX))
+        .await
+    {
+        tracing::warn!("Falha ao limpar o cache de sugestões: {:?}", e);
+    }
+}
@@ -0,0 +1,295 @@
+//! Legendas: validação de arquivos SRT e WebVTT e normalização para WebVTT,
+//! único formato servido aos players.
+
+pub mod srt;
+pub mod webvtt;
+
+use std::fmt;
+
+pub const WEBVTT_CONTENT_TYPE: &str = "text/vtt";
+
+/// Tamanho máximo aceito para um arquivo de legenda.
+pub const MAX_SUBTITLE_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub identifier: Option<String>,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    /// Configurações de posicionamento do WebVTT (`line:0 align:start`...).
+    pub settings: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub enum SubtitleError {
+    Empty,
+    TooLarge,
+    Malformed { line: usize, message: String },
+}
+
+impl fmt::Display for SubtitleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubtitleError::Empty => write!(f, "O arquivo de legenda não contém falas"),
+            SubtitleError::TooLarge => {
+                write!(f, "O arquivo de legenda excede {} bytes", MAX_SUBTITLE_SIZE)
+            }
+            SubtitleError::Malformed { line, message } => {
+                write!(f, "Legenda inválida na linha {}: {}", line, message)
+            }
+        }
+    }
+}
+
+/// Resultado da normalização: o WebVTT final e o fim da última fala.
+#[derive(Debug)]
+pub struct NormalizedSubtitle {
+    pub webvtt: String,
+    pub cue_count: usize,
+    pub duration_ms: u64,
+}
+
+/// Valida um arquivo SRT ou WebVTT e devolve o conteúdo em WebVTT.
+///
+/// O formato é identificado pelo cabeçalho `WEBVTT`. Arquivos que não são
+/// UTF-8 válido são lidos como Latin-1, codificação comum em SRTs antigos.
+pub fn normalize(bytes: &[u8]) -> Result<NormalizedSubtitle, SubtitleError> {
+    if bytes.len() > MAX_SUBTITLE_SIZE {
+        return Err(SubtitleError::TooLarge);
+    }
+
+    let text = decode(bytes);
+    let text = text
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .replace('\r', "\n");
+
+    let cues = if webvtt::is_webvtt(&text) {
+        webvtt::parse(&text)?
+    } else {
+        srt::parse(&text)?
+    };
+
+    if cues.is_empty() {
+        return Err(SubtitleError::Empty);
+    }
+
+    Ok(NormalizedSubtitle {
+        webvtt: webvtt::render(&cues),
+        cue_count: cues.len(),
+        duration_ms: cues.iter().map(|cue| cue.end_ms).max().unwrap_or(0),
+    })
+}
+
+fn decode(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|b| *b as char).collect(),
+    }
+}
+
+/// Lê um timestamp `[HH:]MM:SS(.|,)mmm`. `require_hours` segue a exigência do SRT.
+fn parse_timestamp(value: &str, require_hours: bool) -> Option<u64> {
+    let (clock, millis) = value.trim().split_once(['.', ','])?;
+    if millis.len() != 3 {
+        return None;
+    }
+
+    let parts: Vec<&str> = clock.split(':').collect();
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [h, m, s] => (*h, *m, *s),
+        [m, s] if !require_hours => ("0", *m, *s),
+        _ => return None,
+    };
+
+    let number = |v: &str| -> Option<u64> {
+        if v.is_empty() || !v.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        v.parse().ok()
+    };
+
+    let (hours, minutes, seconds, millis) = (
+        number(hours)?,
+        number(minutes)?,
+        number(seconds)?,
+        number(millis)?,
+    );
+    if minutes > 59 || seconds > 59 {
+        return None;
+    }
+
+    hours
+        .checked_mul(3_600_000)?
+        .checked_add((minutes * 60 + seconds) * 1000 + millis)
+}
+
+pub fn format_timestamp(ms: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        (ms / 60_000) % 60,
+        (ms / 1000) % 60,
+        ms % 1000
+    )
+}
+
+/// Lê a linha de tempo `início --> fim [configurações]`.
+fn parse_timing(
+    line: &str,
+    line_number: usize,
+    require_hours: bool,
+) -> Result<(u64, u64, Option<String>), SubtitleError> {
+    let malformed = |message: &str| SubtitleError::Malformed {
+        line: line_number,
+        message: message.to_string(),
+    };
+
+    let (start, rest) = line
+        .split_once("-->")
+        .ok_or_else(|| malformed("linha de tempo sem '-->'"))?;
+    let rest = rest.trim();
+    let (end, settings) = match rest.split_once(char::is_whitespace) {
+        Some((end, settings)) => (end, Some(settings.trim().to_string())),
+        None => (rest, None),
+    };
+
+    let start =
+        parse_timestamp(start, require_hours).ok_or_else(|| malformed("tempo inicial inválido"))?;
+    let end =
+        parse_timestamp(end, require_hours).ok_or_else(|| malformed("tempo final inválido"))?;
+
+    if end < start {
+        return Err(malformed("a fala termina antes de começar"));
+    }
+
+    Ok((start, end, settings.filter(|s| !s.is_empty())))
+}
+
+/// Valida e normaliza uma tag de idioma BCP 47 simples (`pt`, `pt-BR`, `zh-Hant`).
+pub fn normalize_language(tag: &str) -> Option<String> {
+    let mut parts = tag.trim().split(['-', '_']);
+
+    let primary = parts.next()?;
+    if !(2..=3).contains(&primary.len()) || !primary.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut normalized = primary.to_ascii_lowercase();
+
+    for subtag in parts {
+        if !(2..=8).contains(&subtag.len()) || !subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return None;
+        }
+
+        normalized.push('-');
+        match subtag.len() {
+            // região (BR, US)
+            2 => normalized.push_str(&subtag.to_ascii_uppercase()),
+            // script (Hant, Latn)
+            4 => {
+                normalized.push_str(&subtag[..1].to_ascii_uppercase());
+                normalized.push_str(&subtag[1..].to_ascii_lowercase());
+            }
+            _ => normalized.push_str(&subtag.to_ascii_lowercase()),
+        }
+    }
+
+    Some(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_timestamps_with_and_without_hours() {
+        assert_eq!(parse_timestamp("01:02:03,456", true), Some(3_723_456));
+        assert_eq!(parse_timestamp("01:02:03.456", true), Some(3_723_456));
+        assert_eq!(parse_timestamp("02:03.456", false), Some(123_456));
+        assert_eq!(parse_timestamp("02:03.456", true), None);
+        assert_eq!(parse_timestamp("00:60:00.000", false), None);
+        assert_eq!(parse_timestamp("00:00:01.5", false), None);
+        assert_eq!(parse_timestamp("00:-1:01.500", false), None);
+    }
+
+    #[test]
+    fn huge_hours_are_rejected() {
+        assert_eq!(
+            parse_timestamp("18446744073709551615:00:00.000", true),
+            None
+        );
+        assert_eq!(parse_timestamp("9999999999999999:00:00.000", true), None);
+    }
+
+    #[test]
+    fn formats_timestamps_as_webvtt() {
+        assert_eq!(format_timestamp(0), "00:00:00.000");
+        assert_eq!(format_timestamp(3_723_456), "01:02:03.456");
+        assert_eq!(format_timestamp(360_000_000), "100:00:00.000");
+    }
+
+    #[test]
+    fn timing_line_keeps_settings_and_rejects_inverted_ranges() {
+        assert_eq!(
+            parse_timing("00:01.000 --> 00:02.000 line:0 align:start", 3, false).unwrap(),
+            (1000, 2000, Some("line:0 align:start".to_string()))
+        );
+        assert!(matches!(
+            parse_timing("00:02.000 --> 00:01.000", 3, false),
+            Err(SubtitleError::Malformed { line: 3, .. })
+        ));
+        assert!(matches!(
+            parse_timing("00:01.000 00:02.000", 7, false),
+            Err(SubtitleError::Malformed { line: 7, .. })
+        ));
+    }
+
+    #[test]
+    fn normalizes_language_tags() {
+        assert_eq!(normalize_language("pt_br").as_deref(), Some("pt-BR"));
+        assert_eq!(normalize_language(" EN ").as_deref(), Some("en"));
+        assert_eq!(normalize_language("zh-hant").as_deref(), Some("zh-Hant"));
+        assert_eq!(normalize_language("es-419").as_deref(), Some("es-419"));
+        assert_eq!(normalize_language("p"), None);
+        assert_eq!(normalize_language("pt-"), None);
+        assert_eq!(normalize_language("pt-B@"), None);
+    }
+
+    #[test]
+    fn normalizes_srt_to_webvtt() {
+        let srt = "\u{feff}1\r\n00:00:01,000 --> 00:00:02,500\r\nOlá\r\n\r\n\
+                   2\r\n00:00:03,000 --> 00:00:04,000\r\nTchau\r\n";
+
+        let normalized = normalize(srt.as_bytes()).unwrap();
+
+        assert_eq!(normalized.cue_count, 2);
+        assert_eq!(normalized.duration_ms, 4000);
+        assert_eq!(
+            normalized.webvtt,
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.500\nOlá\n\n00:00:03.000 --> 00:00:04.000\nTchau\n"
+        );
+    }
+
+    #[test]
+    fn latin1_files_are_decoded() {
+        let srt = b"1\n00:00:01,000 --> 00:00:02,000\nS\xe3o Paulo\n";
+
+        let normalized = normalize(srt).unwrap();
+        assert!(normalized.webvtt.contains("São Paulo"));
+    }
+
+    #[test]
+    fn rejects_empty_and_oversized_files() {
+        assert!(matches!(
+            normalize(b"WEBVTT\n\n"),
+            Err(SubtitleError::Empty)
+        ));
+        assert!(matches!(normalize(b""), Err(SubtitleError::Empty)));
+        assert!(matches!(
+            normalize(&vec![b'a'; MAX_SUBTITLE_SIZE + 1]),
+            Err(SubtitleError::TooLarge)
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+//! SubRip (`.srt`): blocos separados por linha em branco com número
+//! sequencial, linha de tempo (`00:00:01,000 --> 00:00:02,500`) e texto.
+
+use super::{parse_timing, Cue, SubtitleError};
+
+pub fn parse(text: &str) -> Result<Vec<Cue>, SubtitleError> {
+    let mut cues = Vec::new();
+    let mut lines = text.lines().enumerate().peekable();
+
+    loop {
+        // pula linhas em branco entre blocos
+        while lines.next_if(|(_, line)| line.trim().is_empty()).is_some() {}
+
+        let Some((index, first)) = lines.next() else {
+            break;
+        };
+
+        // o número sequencial é opcional na prática; alguns geradores o omitem
+        let (timing_index, timing) = if first.contains("-->") {
+            (index, first)
+        } else {
+            if !first.trim().bytes().all(|b| b.is_ascii_digit()) {
+                return Err(SubtitleError::Malformed {
+                    line: index + 1,
+                    message: "esperado o número da fala".to_string(),
+                });
+            }
+            lines.next().ok_or(SubtitleError::Malformed {
+                line: index + 2,
+                message: "fala sem linha de tempo".to_string(),
+            })?
+        };
+
+        // SRT não tem configurações de posicionamento; o que vier depois do tempo é ignorado
+        let (start_ms, end_ms, _) = parse_timing(timing, timing_index + 1, true)?;
+
+        let mut text_lines = Vec::new();
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            text_lines.push(clean_text(line));
+        }
+
+        cues.push(Cue {
+            identifier: None,
+            start_ms,
+            end_ms,
+            settings: None,
+            text: text_lines.join("\n"),
+        });
+    }
+
+    Ok(cues)
+}
+
+/// Remove marcações que o WebVTT não entende: overrides do ASS (`{\an8}`) e `<font>`.
+fn clean_text(line: &str) -> String {
+    let mut output = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['{', '<']) {
+        output.push_str(&rest[..start]);
+        let tail = &rest[start..];
+
+        let skip = if tail.starts_with("{\\") {
+            tail.find('}').map(|end| end + 1)
+        } else if tail.starts_with("<font") || tail.starts_with("</font") {
+            tail.find('>').map(|end| end + 1)
+        } else {
+            None
+        };
+
+        match skip {
+            Some(len) => rest = &tail[len..],
+            None => {
+                output.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbered_and_unnumbered_blocks() {
+        let text = "1\n00:00:01,000 --> 00:00:02,000 X1:0\nPrimeira\nlinha\n\n\n\
+                    00:00:03,000 --> 00:00:04,000\nSegunda\n";
+
+        let cues = parse(text).unwrap();
+
+        assert_eq!(cues.len(), 2);
+        assert_eq!((cues[0].start_ms, cues[0].end_ms), (1000, 2000));
+        assert_eq!(cues[0].text, "Primeira\nlinha");
+        assert_eq!(cues[0].settings, None);
+        assert_eq!(cues[1].text, "Segunda");
+    }
+
+    #[test]
+    fn reports_the_line_of_the_error() {
+        let text = "1\n00:00:01,000 --> 00:00:02,000\nOk\n\nabc\n00:00:03,000 --> 00:00:04,000\n";
+        assert!(matches!(
+            parse(text),
+            Err(SubtitleError::Malformed { line: 5, .. })
+        ));
+
+        assert!(matches!(
+            parse("1\n00:01,000 --> 00:02,000\nSem horas\n"),
+            Err(SubtitleError::Malformed { line: 2, .. })
+        ));
+
+        assert!(matches!(
+            parse("1\n"),
+            Err(SubtitleError::Malformed { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn strips_ass_overrides_and_font_tags() {
+        assert_eq!(
+            clean_text("{\\an8}<font color=\"red\">Oi</font> <i>você</i> {ok}"),
+            "Oi <i>você</i> {ok}"
+        );
+        assert_eq!(clean_text("{\\sem fim"), "{\\sem fim");
+    }
+}
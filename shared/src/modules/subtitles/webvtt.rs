@@ -0,0 +1,142 @@
+//! WebVTT: cabeçalho `WEBVTT` seguido de blocos de falas, com identificador
+//! opcional e configurações de posicionamento. Blocos `NOTE`, `STYLE` e
+//! `REGION` são descartados na normalização.
+
+use super::{format_timestamp, parse_timing, Cue, SubtitleError};
+
+pub fn is_webvtt(text: &str) -> bool {
+    text.strip_prefix("WEBVTT")
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t', '\n']))
+}
+
+pub fn parse(text: &str) -> Result<Vec<Cue>, SubtitleError> {
+    let mut cues = Vec::new();
+    let mut lines = text.lines().enumerate().peekable();
+
+    // cabeçalho: tudo até a primeira linha em branco
+    while lines.next_if(|(_, line)| !line.trim().is_empty()).is_some() {}
+
+    loop {
+        while lines.next_if(|(_, line)| line.trim().is_empty()).is_some() {}
+
+        let Some((index, first)) = lines.next() else {
+            break;
+        };
+
+        let is_metadata_block = ["NOTE", "STYLE", "REGION"]
+            .iter()
+            .any(|kind| first == *kind || first.starts_with(&format!("{} ", kind)));
+        if is_metadata_block {
+            while lines.next_if(|(_, line)| !line.trim().is_empty()).is_some() {}
+            continue;
+        }
+
+        let (identifier, timing_index, timing) = if first.contains("-->") {
+            (None, index, first)
+        } else {
+            let (timing_index, timing) = lines.next().ok_or(SubtitleError::Malformed {
+                line: index + 2,
+                message: "fala sem linha de tempo".to_string(),
+            })?;
+            (Some(first.trim().to_string()), timing_index, timing)
+        };
+
+        let (start_ms, end_ms, settings) = parse_timing(timing, timing_index + 1, false)?;
+
+        let mut text_lines = Vec::new();
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            text_lines.push(line);
+        }
+
+        cues.push(Cue {
+            identifier,
+            start_ms,
+            end_ms,
+            settings,
+            text: text_lines.join("\n"),
+        });
+    }
+
+    Ok(cues)
+}
+
+pub fn render(cues: &[Cue]) -> String {
+    let mut output = String::from("WEBVTT\n");
+
+    for cue in cues {
+        output.push('\n');
+        if let Some(identifier) = &cue.identifier {
+            output.push_str(identifier);
+            output.push('\n');
+        }
+        output.push_str(&format_timestamp(cue.start_ms));
+        output.push_str(" --> ");
+        output.push_str(&format_timestamp(cue.end_ms));
+        if let Some(settings) = &cue.settings {
+            output.push(' ');
+            output.push_str(settings);
+        }
+        output.push('\n');
+        output.push_str(&cue.text);
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_the_header() {
+        assert!(is_webvtt("WEBVTT"));
+        assert!(is_webvtt("WEBVTT - Legendas\n\n"));
+        assert!(!is_webvtt("WEBVTTX\n"));
+        assert!(!is_webvtt("1\n00:00:01,000 --> 00:00:02,000\n"));
+    }
+
+    #[test]
+    fn skips_metadata_blocks_and_keeps_identifiers_and_settings() {
+        let text = "WEBVTT\nKind: captions\n\nNOTE comentário\nqualquer coisa\n\n\
+                    STYLE\n::cue { color: red }\n\n\
+                    intro\n00:01.000 --> 00:02.000 align:start\nOi\n\n\
+                    01:00:00.000 --> 01:00:01.000\nTchau\n";
+
+        let cues = parse(text).unwrap();
+
+        assert_eq!(
+            cues,
+            vec![
+                Cue {
+                    identifier: Some("intro".to_string()),
+                    start_ms: 1000,
+                    end_ms: 2000,
+                    settings: Some("align:start".to_string()),
+                    text: "Oi".to_string(),
+                },
+                Cue {
+                    identifier: None,
+                    start_ms: 3_600_000,
+                    end_ms: 3_601_000,
+                    settings: None,
+                    text: "Tchau".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn identifier_without_timing_is_malformed() {
+        assert!(matches!(
+            parse("WEBVTT\n\nintro\n"),
+            Err(SubtitleError::Malformed { line: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn render_round_trips() {
+        let text = "WEBVTT\n\nintro\n00:00:01.000 --> 00:00:02.000 line:0\nOi\nde novo\n";
+        assert_eq!(render(&parse(text).unwrap()), text);
+    }
+}
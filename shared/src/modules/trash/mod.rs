@@ -0,0 +1,123 @@
+//! Lixeira de vídeos e séries: a remoção definitiva, com os arquivos no
+//! storage, acontece aqui, seja por ação do admin ou ao fim da retenção.
+
+use chrono::{Duration, Utc};
+use sea_orm::DbErr;
+
+use crate::modules::app_state::AppState;
+use crate::modules::database::repositories::images_repository::image_storage_keys;
+use crate::modules::database::repositories::videos_repository::PurgedVideo;
+use crate::modules::database::schema::images::Model as ImageModel;
+use crate::modules::database::schema::series::Model as SerieModel;
+use crate::modules::database::schema::videos::Model as VideoModel;
+
+/// Quantos itens de cada tipo são removidos por rodada da limpeza automática.
+const PURGE_BATCH_SIZE: u64 = 100;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PurgeReport {
+    pub videos: usize,
+    pub series: usize,
+}
+
+/// Remove o vídeo do banco numa única transação (histórico, avaliações e
+/// favoritos vão junto por cascata) e depois apaga seus arquivos e imagens.
+///
+/// Falhas ao apagar blobs não revertem a remoção: ficam no log e os arquivos
+/// restantes são recolhidos pela limpeza de órfãos do media_worker.
+pub async fn purge_video(state: &AppState, video: &VideoModel) -> Result<(), DbErr> {
+    let purged = state.video_repo.purge(video.id).await?;
+    delete_video_blobs(state, video, &purged).await;
+
+    Ok(())
+}
+
+/// Remove numa única transação a série e todos os episódios, inclusive os
+/// que não estão na lixeira, e depois apaga os arquivos. Retorna quantos
+/// episódios foram removidos.
+pub async fn purge_serie(state: &AppState, serie: &SerieModel) -> Result<usize, DbErr> {
+    let purged = state.serie_repo.purge(serie.id).await?;
+
+    for (episode, episode_purged) in &purged.episodes {
+        delete_video_blobs(state, episode, episode_purged).await;
+    }
+    delete_image_blobs(state, &purged.images).await;
+
+    Ok(purged.episodes.len())
+}
+
+/// Remove definitivamente o que está na lixeira há mais de `retention`.
+pub async fn purge_expired(state: &AppState, retention: Duration) -> Result<PurgeReport, DbErr> {
+    let deleted_before = Utc::now().naive_utc() - retention;
+    let mut report = PurgeReport::default();
+
+    loop {
+        let series = state
+            .serie_repo
+            .find_purgeable(deleted_before, PURGE_BATCH_SIZE)
+            .await?;
+        if series.is_empty() {
+            break;
+        }
+        for serie in &series {
+            report.videos += purge_serie(state, serie).await?;
+            report.series += 1;
+        }
+    }
+
+    loop {
+        let videos = state
+            .video_repo
+            .find_purgeable(deleted_before, PURGE_BATCH_SIZE)
+            .await?;
+        if videos.is_empty() {
+            break;
+        }
+        for video in &videos {
+            purge_video(state, video).await?;
+            report.videos += 1;
+        }
+    }
+
+    Ok(report)
+}
+
+async fn delete_video_blobs(state: &AppState, video: &VideoModel, purged: &PurgedVideo) {
+    if !purged.media_assets.is_empty() {
+        for asset in &purged.media_assets {
+            if let Err(e) = state.video_storage_service.delete_asset_blob(asset).await {
+                tracing::warn!(
+                    "Falha ao deletar arquivo '{}' do vídeo {}: {:?}",
+                    asset.storage_key,
+                    video.title,
+                    e
+                );
+            }
+        }
+    } else if let Some(old_url) = &video.video_url {
+        if let Ok(old_blob_name) = state.video_storage_service.get_blob_name_from_url(old_url) {
+            if let Err(e) = state
+                .video_storage_service
+                .delete_video(&old_blob_name)
+                .await
+            {
+                tracing::warn!(
+                    "Falha ao deletar vídeo {} antigo '{}': {:?}",
+                    video.title,
+                    old_blob_name,
+                    e
+                );
+            }
+        }
+    }
+
+    delete_image_blobs(state, &purged.images).await;
+}
+
+async fn delete_image_blobs(state: &AppState, images: &[ImageModel]) {
+    for blob_name in images.iter().flat_map(image_storage_keys) {
+        if let Err(e) = state.image_storage_service.delete_image(&blob_name).await {
+            tracing::warn!("Falha ao deletar imagem '{}': {:?}", blob_name, e);
+        }
+    }
+}
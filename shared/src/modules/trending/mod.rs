@@ -0,0 +1,402 @@
+//! Listas de em alta. Cada visualização do `watch_history` soma um peso que
+//! decai com o tempo, dentro das janelas de 24 horas, 7 dias e 30 dias; as
+//! pontuações são agregadas por vídeo, por série, por título (filmes e
+//! séries) e por categoria e guardadas em sorted sets do Redis.
+//!
+//! O Redis é só uma cópia: o cálculo depende apenas do Postgres e do instante
+//! de referência, e a leitura recalcula do banco quando as chaves somem.
+
+use anyhow::Result;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::DbErr;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::enums::trending_kind_enum::TrendingKindEnum;
+use crate::enums::trending_window_enum::TrendingWindowEnum;
+use crate::modules::app_state::AppState;
+use crate::modules::database::repositories::trending_repository::VideoTrendScore;
+
+/// Prefixo das chaves no Redis. Fica fora de `catalog:` para não ser limpo a
+/// cada lançamento.
+pub const TRENDING_PREFIX: &str = "trending";
+
+/// Quantos itens cada lista guarda.
+pub const RANKING_SIZE: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TrendingScope {
+    /// Filmes e episódios.
+    Videos,
+    Series,
+    /// Filmes e séries, sem episódios soltos: o "Top 10".
+    Titles,
+    /// Títulos da categoria; uma série conta só com os episódios da categoria.
+    Category(i32),
+}
+
+impl TrendingScope {
+    fn key_suffix(&self) -> String {
+        match self {
+            TrendingScope::Videos => "VIDEO".to_string(),
+            TrendingScope::Series => "SERIE".to_string(),
+            TrendingScope::Titles => "TITLE".to_string(),
+            TrendingScope::Category(id) => format!("CATEGORY:{}", id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TrendingEntry {
+    pub kind: TrendingKindEnum,
+    pub id: i32,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TrendingReport {
+    pub computed_at: NaiveDateTime,
+    pub rankings: usize,
+}
+
+pub fn ranking_key(window: TrendingWindowEnum, scope: TrendingScope) -> String {
+    format!(
+        "{}:{}:{}",
+        TRENDING_PREFIX,
+        window.as_str(),
+        scope.key_suffix()
+    )
+}
+
+/// Instante usado no último cálculo gravado no Redis.
+pub fn computed_at_key() -> String {
+    format!("{}:computed_at", TRENDING_PREFIX)
+}
+
+fn member(entry: &TrendingEntry) -> String {
+    format!("{}:{}", entry.kind.as_str(), entry.id)
+}
+
+fn parse_member(member: &str, score: f64) -> Option<TrendingEntry> {
+    let (kind, id) = member.split_once(':')?;
+
+    Some(TrendingEntry {
+        kind: TrendingKindEnum::try_from(kind).ok()?,
+        id: id.parse().ok()?,
+        score,
+    })
+}
+
+/// Monta todas as listas de uma janela a partir das pontuações por vídeo,
+/// do maior para o menor score (empates pelo tipo e pelo id).
+pub fn build_rankings(scores: &[VideoTrendScore]) -> BTreeMap<TrendingScope, Vec<TrendingEntry>> {
+    let mut totals: BTreeMap<TrendingScope, HashMap<(TrendingKindEnum, i32), f64>> =
+        BTreeMap::new();
+    let mut add = |scope: TrendingScope, kind: TrendingKindEnum, id: i32, score: f64| {
+        *totals
+            .entry(scope)
+            .or_default()
+            .entry((kind, id))
+            .or_default() += score;
+    };
+
+    for video in scores {
+        add(
+            TrendingScope::Videos,
+            TrendingKindEnum::VIDEO,
+            video.video_id,
+            video.score,
+        );
+
+        let title = match video.series_id {
+            Some(series_id) => {
+                add(
+                    TrendingScope::Series,
+                    TrendingKindEnum::SERIE,
+                    series_id,
+                    video.score,
+                );
+                (TrendingKindEnum::SERIE, series_id)
+            }
+            None => (TrendingKindEnum::VIDEO, video.video_id),
+        };
+
+        add(TrendingScope::Titles, title.0, title.1, video.score);
+        for category_id in video.category_ids() {
+            add(
+                TrendingScope::Category(category_id),
+                title.0,
+                title.1,
+                video.score,
+            );
+        }
+    }
+
+    totals
+        .into_iter()
+        .map(|(scope, items)| {
+            let mut entries: Vec<TrendingEntry> = items
+                .into_iter()
+                .map(|((kind, id), score)| TrendingEntry { kind, id, score })
+                .collect();
+            entries.sort_by(|a, b| {
+                b.score
+                    .total_cmp(&a.score)
+                    .then(a.kind.cmp(&b.kind))
+                    .then(a.id.cmp(&b.id))
+            });
+            entries.truncate(RANKING_SIZE);
+
+            (scope, entries)
+        })
+        .collect()
+}
+
+/// Recalcula todas as janelas para o instante `at` e troca as listas do
+/// Redis. Listas de categorias que ficaram vazias são removidas.
+pub async fn refresh(state: &AppState, at: NaiveDateTime, ttl_secs: u64) -> Result<TrendingReport> {
+    let mut written = 0;
+
+    for window in TrendingWindowEnum::ALL {
+        let scores = state.trending_repo.find_video_scores(window, at).await?;
+        let mut rankings = build_rankings(&scores);
+        for scope in [
+            TrendingScope::Videos,
+            TrendingScope::Series,
+            TrendingScope::Titles,
+        ] {
+            rankings.entry(scope).or_default();
+        }
+
+        let keys: HashSet<String> = rankings
+            .keys()
+            .map(|scope| ranking_key(window, *scope))
+            .collect();
+
+        for (scope, entries) in &rankings {
+            let members: Vec<(String, f64)> = entries
+                .iter()
+                .map(|entry| (member(entry), entry.score))
+                .collect();
+            state
+                .cache
+                .replace_sorted_set(ranking_key(window, *scope), &members, ttl_secs)
+                .await?;
+            written += 1;
+        }
+
+        let pattern = format!("{}:{}:CATEGORY:*", TRENDING_PREFIX, window.as_str());
+        for stale in state.cache.keys(&pattern).await? {
+            if !keys.contains(&stale) && !stale.ends_with(":staging") {
+                state.cache.delete(&stale).await?;
+            }
+        }
+    }
+
+    state
+        .cache
+        .set_with_ttl(computed_at_key(), &at, ttl_secs)
+        .await?;
+
+    Ok(TrendingReport {
+        computed_at: at,
+        rankings: written,
+    })
+}
+
+/// Os `count` primeiros da lista. Lê do Redis; se ele falhar ou ainda não
+/// houver cálculo gravado, recalcula do Postgres para o instante atual.
+pub async fn ranking(
+    state: &AppState,
+    window: TrendingWindowEnum,
+    scope: TrendingScope,
+    count: usize,
+) -> Result<Vec<TrendingEntry>, DbErr> {
+    match cached_ranking(state, window, scope, count).await {
+        Ok(Some(entries)) => return Ok(entries),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Falha ao ler as listas de em alta do Redis: {:?}", e),
+    }
+
+    let scores = state
+        .trending_repo
+        .find_video_scores(window, Utc::now().naive_utc())
+        .await?;
+    let mut entries = build_rankings(&scores).remove(&scope).unwrap_or_default();
+    entries.truncate(count);
+
+    Ok(entries)
+}
+
+/// Instante do último cálculo gravado no Redis, se houver.
+pub async fn computed_at(state: &AppState) -> Option<NaiveDateTime> {
+    state
+        .cache
+        .get::<_, NaiveDateTime>(computed_at_key())
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Falha ao ler o cálculo de em alta do Redis: {:?}", e);
+            None
+        })
+}
+
+async fn cached_ranking(
+    state: &AppState,
+    window: TrendingWindowEnum,
+    scope: TrendingScope,
+    count: usize,
+) -> Result<Option<Vec<TrendingEntry>>, redis::RedisError> {
+    let members = state
+        .cache
+        .get_sorted_set_desc(ranking_key(window, scope), count)
+        .await?;
+
+    if members.is_empty() && !state.cache.exists(computed_at_key()).await? {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        members
+            .iter()
+            .filter_map(|(member, score)| parse_member(member, *score))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(
+        video_id: i32,
+        series_id: Option<i32>,
+        score: f64,
+        categories: &str,
+    ) -> VideoTrendScore {
+        VideoTrendScore {
+            video_id,
+            series_id,
+            score,
+            category_ids: (!categories.is_empty()).then(|| categories.to_string()),
+        }
+    }
+
+    fn ids(entries: &[TrendingEntry]) -> Vec<(TrendingKindEnum, i32, f64)> {
+        entries.iter().map(|e| (e.kind, e.id, e.score)).collect()
+    }
+
+    #[test]
+    fn episodes_add_up_into_their_serie() {
+        let rankings = build_rankings(&[
+            score(1, None, 3.0, "10"),
+            score(2, Some(7), 1.5, "10,11"),
+            score(3, Some(7), 2.0, "11"),
+        ]);
+
+        assert_eq!(
+            ids(&rankings[&TrendingScope::Videos]),
+            vec![
+                (TrendingKindEnum::VIDEO, 1, 3.0),
+                (TrendingKindEnum::VIDEO, 3, 2.0),
+                (TrendingKindEnum::VIDEO, 2, 1.5),
+            ]
+        );
+        assert_eq!(
+            ids(&rankings[&TrendingScope::Series]),
+            vec![(TrendingKindEnum::SERIE, 7, 3.5)]
+        );
+        assert_eq!(
+            ids(&rankings[&TrendingScope::Titles]),
+            vec![
+                (TrendingKindEnum::SERIE, 7, 3.5),
+                (TrendingKindEnum::VIDEO, 1, 3.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn categories_count_only_their_episodes() {
+        let rankings = build_rankings(&[
+            score(1, None, 3.0, "10"),
+            score(2, Some(7), 1.5, "10,11"),
+            score(3, Some(7), 2.0, "11"),
+        ]);
+
+        assert_eq!(
+            ids(&rankings[&TrendingScope::Category(10)]),
+            vec![
+                (TrendingKindEnum::VIDEO, 1, 3.0),
+                (TrendingKindEnum::SERIE, 7, 1.5),
+            ]
+        );
+        assert_eq!(
+            ids(&rankings[&TrendingScope::Category(11)]),
+            vec![(TrendingKindEnum::SERIE, 7, 3.5)]
+        );
+    }
+
+    #[test]
+    fn ties_are_ordered_by_kind_then_id() {
+        let rankings = build_rankings(&[
+            score(9, None, 1.0, ""),
+            score(4, Some(2), 1.0, ""),
+            score(5, None, 1.0, ""),
+        ]);
+
+        assert_eq!(
+            ids(&rankings[&TrendingScope::Titles]),
+            vec![
+                (TrendingKindEnum::VIDEO, 5, 1.0),
+                (TrendingKindEnum::VIDEO, 9, 1.0),
+                (TrendingKindEnum::SERIE, 2, 1.0),
+            ]
+        );
+        assert!(!rankings.contains_key(&TrendingScope::Category(0)));
+    }
+
+    #[test]
+    fn rankings_are_capped() {
+        let scores: Vec<_> = (0..RANKING_SIZE as i32 + 50)
+            .map(|id| score(id, None, id as f64, ""))
+            .collect();
+
+        let videos = &build_rankings(&scores)[&TrendingScope::Videos];
+
+        assert_eq!(videos.len(), RANKING_SIZE);
+        assert_eq!(videos[0].id, RANKING_SIZE as i32 + 49);
+    }
+
+    #[test]
+    fn no_views_means_no_rankings() {
+        assert!(build_rankings(&[]).is_empty());
+    }
+
+    #[test]
+    fn keys_and_members_round_trip() {
+        assert_eq!(
+            ranking_key(TrendingWindowEnum::WEEK, TrendingScope::Category(3)),
+            "trending:WEEK:CATEGORY:3"
+        );
+        assert_eq!(
+            ranking_key(TrendingWindowEnum::DAY, TrendingScope::Titles),
+            "trending:DAY:TITLE"
+        );
+
+        let entry = TrendingEntry {
+            kind: TrendingKindEnum::SERIE,
+            id: 42,
+            score: 1.25,
+        };
+        assert_eq!(member(&entry), "SERIE:42");
+        assert_eq!(parse_member(&member(&entry), 1.25), Some(entry));
+        assert_eq!(parse_member("FILME:1", 1.0), None);
+        assert_eq!(parse_member("VIDEO:abc", 1.0), None);
+        assert_eq!(parse_member("VIDEO", 1.0), None);
+    }
+
+    #[test]
+    fn category_ids_ignore_garbage() {
+        assert_eq!(score(1, None, 1.0, "3,,x,5").category_ids(), vec![3, 5]);
+        assert!(score(1, None, 1.0, "").category_ids().is_empty());
+    }
+}